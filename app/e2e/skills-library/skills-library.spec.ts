@@ -81,7 +81,7 @@ test.describe("Skills Library", { tag: "@skills" }, () => {
   test("delete plugin triggers delete and refreshes list", async ({ page }) => {
     await reloadWithOverrides(page, {
       ...BASE_OVERRIDES,
-      delete_plugin: undefined,
+      delete_plugin: [],
     });
     await page.goto("/settings");
     await waitForAppReady(page);
@@ -34,6 +34,9 @@ pub enum SkillCreatorIntent {
     WorkflowStep { step: WorkflowStepKind },
     AnswerEvaluator,
     Eval,
+    TriggerEval,
+    ScenarioRun,
+    RubricGrade,
     ScopeReview,
     ModelValidation,
 }
@@ -67,6 +70,9 @@ fn intent_task_kind(intent: &SkillCreatorIntent) -> &'static str {
         },
         SkillCreatorIntent::AnswerEvaluator => "workflow.answer_evaluator",
         SkillCreatorIntent::Eval => "scenario-suggest",
+        SkillCreatorIntent::TriggerEval => "eval.trigger",
+        SkillCreatorIntent::ScenarioRun => "eval.scenario_run",
+        SkillCreatorIntent::RubricGrade => "eval.rubric_grade",
         SkillCreatorIntent::ScopeReview => "scope_review",
         SkillCreatorIntent::ModelValidation => "settings.model_connection_test",
     }
@@ -79,6 +85,9 @@ fn intent_run_source(intent: &SkillCreatorIntent) -> Option<&'static str> {
         SkillCreatorIntent::WorkflowStep { .. } => Some("workflow"),
         SkillCreatorIntent::AnswerEvaluator => Some("gate-eval"),
         SkillCreatorIntent::Eval => Some("scenario-suggest"),
+        SkillCreatorIntent::TriggerEval => Some("trigger-eval"),
+        SkillCreatorIntent::ScenarioRun => Some("scenario-run"),
+        SkillCreatorIntent::RubricGrade => Some("rubric-grade"),
         SkillCreatorIntent::ScopeReview => None,
         SkillCreatorIntent::ModelValidation => Some("test"),
     }
@@ -109,13 +118,15 @@ fn intent_allowed_tools(intent: &SkillCreatorIntent) -> Vec<String> {
         SkillCreatorIntent::AnswerEvaluator => {
             vec!["file_editor".to_string()]
         }
-        SkillCreatorIntent::Eval => {
+        SkillCreatorIntent::Eval | SkillCreatorIntent::ScenarioRun => {
             vec!["file_editor".to_string(), "terminal".to_string()]
         }
         SkillCreatorIntent::ScopeReview => {
             vec!["file_editor".to_string()]
         }
-        SkillCreatorIntent::ModelValidation => {
+        SkillCreatorIntent::TriggerEval
+        | SkillCreatorIntent::RubricGrade
+        | SkillCreatorIntent::ModelValidation => {
             vec![]
         }
     }
@@ -133,6 +144,9 @@ fn intent_max_turns(intent: &SkillCreatorIntent) -> u32 {
         },
         SkillCreatorIntent::AnswerEvaluator => 20,
         SkillCreatorIntent::Eval => 10,
+        SkillCreatorIntent::TriggerEval => 2,
+        SkillCreatorIntent::ScenarioRun => 30,
+        SkillCreatorIntent::RubricGrade => 2,
         SkillCreatorIntent::ScopeReview => 4,
         SkillCreatorIntent::ModelValidation => 1,
     }
@@ -150,6 +164,9 @@ fn intent_step_id(intent: &SkillCreatorIntent) -> i32 {
         },
         SkillCreatorIntent::AnswerEvaluator => -1,
         SkillCreatorIntent::Eval => -11,
+        SkillCreatorIntent::TriggerEval => -13,
+        SkillCreatorIntent::ScenarioRun => -14,
+        SkillCreatorIntent::RubricGrade => -15,
         SkillCreatorIntent::ScopeReview => -30,
         SkillCreatorIntent::ModelValidation => -40,
     }
@@ -171,6 +188,9 @@ fn intent_output_format(intent: &SkillCreatorIntent) -> Option<serde_json::Value
         },
         SkillCreatorIntent::AnswerEvaluator => Some(answer_evaluator_output_format()),
         SkillCreatorIntent::Eval => Some(suggested_scenario_output_format()),
+        SkillCreatorIntent::TriggerEval => Some(trigger_selection_output_format()),
+        SkillCreatorIntent::ScenarioRun => None,
+        SkillCreatorIntent::RubricGrade => Some(rubric_verdicts_output_format()),
         SkillCreatorIntent::ScopeReview => Some(scope_review_output_format()),
         SkillCreatorIntent::ModelValidation => None,
     }
@@ -183,6 +203,9 @@ fn intent_mode(intent: &SkillCreatorIntent) -> Option<OpenHandsRuntimeMode> {
         SkillCreatorIntent::WorkflowStep { .. } => None,
         SkillCreatorIntent::AnswerEvaluator => None,
         SkillCreatorIntent::Eval => Some(OpenHandsRuntimeMode::Throwaway),
+        SkillCreatorIntent::TriggerEval => Some(OpenHandsRuntimeMode::Throwaway),
+        SkillCreatorIntent::ScenarioRun => Some(OpenHandsRuntimeMode::Throwaway),
+        SkillCreatorIntent::RubricGrade => Some(OpenHandsRuntimeMode::Throwaway),
         SkillCreatorIntent::ScopeReview => Some(OpenHandsRuntimeMode::Throwaway),
         SkillCreatorIntent::ModelValidation => Some(OpenHandsRuntimeMode::Throwaway),
     }
@@ -237,6 +260,59 @@ fn suggested_scenario_output_format() -> serde_json::Value {
     })
 }
 
+/// One selection per trigger case: the skill the model would load, or null.
+fn trigger_selection_output_format() -> serde_json::Value {
+    serde_json::json!({
+        "type": "json_schema",
+        "schema": {
+            "type": "object",
+            "required": ["selections"],
+            "properties": {
+                "selections": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "required": ["id", "skill"],
+                        "properties": {
+                            "id": { "type": "string" },
+                            "skill": { "type": ["string", "null"] }
+                        },
+                        "additionalProperties": false
+                    }
+                }
+            },
+            "additionalProperties": false
+        }
+    })
+}
+
+/// One verdict per rubric, keyed by the rubric's index in the scenario.
+fn rubric_verdicts_output_format() -> serde_json::Value {
+    serde_json::json!({
+        "type": "json_schema",
+        "schema": {
+            "type": "object",
+            "required": ["verdicts"],
+            "properties": {
+                "verdicts": {
+                    "type": "array",
+                    "items": {
+                        "type": "object",
+                        "required": ["index", "passed", "reason"],
+                        "properties": {
+                            "index": { "type": "integer" },
+                            "passed": { "type": "boolean" },
+                            "reason": { "type": "string" }
+                        },
+                        "additionalProperties": false
+                    }
+                }
+            },
+            "additionalProperties": false
+        }
+    })
+}
+
 fn scope_review_output_format() -> serde_json::Value {
     serde_json::json!({
         "type": "json_schema",
@@ -547,6 +623,78 @@ mod tests {
         assert!(config.output_format.is_some());
     }
 
+    #[test]
+    fn test_build_skill_creator_config_trigger_eval_intent() {
+        let config = build_skill_creator_config(SkillCreatorRuntimeContext {
+            app_data_root: "/tmp/app-data".to_string(),
+            skills_root: "/tmp/skills".to_string(),
+            skill_name: "my-skill".to_string(),
+            plugin_slug: "default".to_string(),
+            prompt: "pick skills".to_string(),
+            llm: test_llm_config(),
+            intent: SkillCreatorIntent::TriggerEval,
+            skill_dir_override: Some(
+                "/tmp/skill-builder/throwaway/eval-workbench/run-1".to_string(),
+            ),
+        });
+
+        assert_eq!(config.task_kind, Some("eval.trigger".to_string()));
+        assert_eq!(config.step_id, Some(-13));
+        assert_eq!(config.run_source, Some("trigger-eval".to_string()));
+        assert_eq!(config.mode.as_deref(), Some("throwaway"));
+        assert_eq!(config.allowed_tools, Some(vec![]));
+        assert!(config.user_message_suffix.is_none());
+        assert!(config.output_format.is_some());
+    }
+
+    #[test]
+    fn test_build_skill_creator_config_scenario_run_intent() {
+        let config = build_skill_creator_config(SkillCreatorRuntimeContext {
+            app_data_root: "/tmp/app-data".to_string(),
+            skills_root: "/tmp/skills".to_string(),
+            skill_name: "my-skill".to_string(),
+            plugin_slug: "default".to_string(),
+            prompt: "forecast Q3".to_string(),
+            llm: test_llm_config(),
+            intent: SkillCreatorIntent::ScenarioRun,
+            skill_dir_override: Some(
+                "/tmp/skill-builder/throwaway/eval-workbench/run-1".to_string(),
+            ),
+        });
+
+        assert_eq!(config.task_kind, Some("eval.scenario_run".to_string()));
+        assert_eq!(config.step_id, Some(-14));
+        assert_eq!(config.run_source, Some("scenario-run".to_string()));
+        assert_eq!(config.mode.as_deref(), Some("throwaway"));
+        assert_eq!(config.max_turns, Some(30));
+        assert!(config.user_message_suffix.is_none());
+        assert!(config.output_format.is_none());
+    }
+
+    #[test]
+    fn test_build_skill_creator_config_rubric_grade_intent() {
+        let config = build_skill_creator_config(SkillCreatorRuntimeContext {
+            app_data_root: "/tmp/app-data".to_string(),
+            skills_root: "/tmp/skills".to_string(),
+            skill_name: "my-skill".to_string(),
+            plugin_slug: "default".to_string(),
+            prompt: "grade rubrics".to_string(),
+            llm: test_llm_config(),
+            intent: SkillCreatorIntent::RubricGrade,
+            skill_dir_override: Some(
+                "/tmp/skill-builder/throwaway/eval-workbench/run-1".to_string(),
+            ),
+        });
+
+        assert_eq!(config.task_kind, Some("eval.rubric_grade".to_string()));
+        assert_eq!(config.step_id, Some(-15));
+        assert_eq!(config.run_source, Some("rubric-grade".to_string()));
+        assert_eq!(config.mode.as_deref(), Some("throwaway"));
+        assert_eq!(config.allowed_tools, Some(vec![]));
+        assert!(config.user_message_suffix.is_none());
+        assert!(config.output_format.is_some());
+    }
+
     #[test]
     fn test_skill_creator_user_suffix_is_non_empty() {
         assert!(!SKILL_CREATOR_USER_SUFFIX.trim().is_empty());
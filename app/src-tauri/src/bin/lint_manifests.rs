@@ -0,0 +1,49 @@
+//! Lint a skills root's marketplace.json and plugin.json manifests.
+//!
+//! Usage:
+//!   cargo run --bin lint-manifests -- path/to/skills
+//!   cargo run --bin lint-manifests -- path/to/skills --json
+//!
+//! Exit 0 = no errors (warnings allowed), exit 1 = errors found, exit 2 = usage.
+
+use app_lib::marketplace_manifest::lint_manifests;
+use std::path::Path;
+use std::process;
+
+fn main() {
+    let args: Vec<String> = std::env::args().skip(1).collect();
+    let json = args.iter().any(|a| a == "--json");
+    let root = args
+        .iter()
+        .find(|a| !a.starts_with("--"))
+        .unwrap_or_else(|| {
+            eprintln!("Usage: lint-manifests <skills-root> [--json]");
+            process::exit(2);
+        });
+    if !Path::new(root).is_dir() {
+        eprintln!("Not a directory: {}", root);
+        process::exit(2);
+    }
+
+    let report = lint_manifests(Path::new(root));
+    if json {
+        println!("{}", serde_json::to_string_pretty(&report).unwrap());
+    } else {
+        for finding in &report.findings {
+            let severity = serde_json::to_value(finding.severity)
+                .ok()
+                .and_then(|v| v.as_str().map(ToString::to_string))
+                .unwrap_or_default();
+            println!(
+                "{}: {}: [{}] {}",
+                severity, finding.path, finding.code, finding.message
+            );
+        }
+        println!(
+            "{} plugin(s) checked: {} error(s), {} warning(s)",
+            report.plugins_checked, report.error_count, report.warning_count
+        );
+    }
+
+    process::exit(if report.error_count > 0 { 1 } else { 0 });
+}
@@ -0,0 +1,658 @@
+//! Crawl a documentation site into the document library.
+//!
+//! Starting from one URL, pages are fetched breadth-first following
+//! same-origin links up to a depth and page limit. Each page is converted to
+//! Markdown and stored as its own `url` document named `<set>/<page path>`, so
+//! pages can later be refreshed individually.
+
+use std::collections::{HashSet, VecDeque};
+use std::future::Future;
+
+use serde::Deserialize;
+use url::Url;
+
+use super::{documents_dir, extract, refresh, store_document};
+use crate::db::{db_get_document, db_set_document_fetch_state, Db, DocumentRecord};
+use crate::DataDir;
+
+const DEFAULT_MAX_DEPTH: u32 = 2;
+const DEFAULT_MAX_PAGES: u32 = 50;
+/// Hard ceiling on pages per crawl regardless of the requested limit.
+const MAX_PAGES_LIMIT: u32 = 500;
+
+fn default_max_depth() -> u32 {
+    DEFAULT_MAX_DEPTH
+}
+
+fn default_max_pages() -> u32 {
+    DEFAULT_MAX_PAGES
+}
+
+/// Crawl limits and URL filters.
+///
+/// Patterns are comma- or newline-separated lists matched against the full
+/// page URL; `*` matches any run of characters and a pattern without `*`
+/// matches anywhere in the URL. The start page is always crawled; the
+/// patterns decide which discovered links are followed.
+#[derive(Debug, Clone, Deserialize)]
+pub struct CrawlOptions {
+    #[serde(default = "default_max_depth")]
+    pub max_depth: u32,
+    #[serde(default = "default_max_pages")]
+    pub max_pages: u32,
+    #[serde(default)]
+    pub include_pattern: Option<String>,
+    #[serde(default)]
+    pub exclude_pattern: Option<String>,
+}
+
+impl Default for CrawlOptions {
+    fn default() -> Self {
+        Self {
+            max_depth: DEFAULT_MAX_DEPTH,
+            max_pages: DEFAULT_MAX_PAGES,
+            include_pattern: None,
+            exclude_pattern: None,
+        }
+    }
+}
+
+/// A fetched response, before conversion.
+pub(super) struct FetchedPage {
+    /// URL after redirects.
+    pub url: Url,
+    pub content_type: String,
+    pub body: Vec<u8>,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+/// A page converted to Markdown and ready to store.
+pub(super) struct CrawledPage {
+    pub url: Url,
+    pub markdown: String,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+/// `*`-wildcard match of `pattern` against the whole of `text`.
+fn wildcard_match(pattern: &str, text: &str) -> bool {
+    let p: Vec<char> = pattern.chars().collect();
+    let t: Vec<char> = text.chars().collect();
+    let (mut pi, mut ti) = (0, 0);
+    let mut backtrack: Option<(usize, usize)> = None;
+    while ti < t.len() {
+        if pi < p.len() && p[pi] == '*' {
+            backtrack = Some((pi, ti));
+            pi += 1;
+        } else if pi < p.len() && p[pi] == t[ti] {
+            pi += 1;
+            ti += 1;
+        } else if let Some((star, matched)) = backtrack {
+            pi = star + 1;
+            ti = matched + 1;
+            backtrack = Some((star, matched + 1));
+        } else {
+            return false;
+        }
+    }
+    p[pi..].iter().all(|&c| c == '*')
+}
+
+fn matches_any(patterns: &str, url: &str) -> bool {
+    patterns
+        .split([',', '\n'])
+        .map(str::trim)
+        .filter(|p| !p.is_empty())
+        .any(|p| {
+            if p.contains('*') {
+                wildcard_match(p, url)
+            } else {
+                url.contains(p)
+            }
+        })
+}
+
+fn has_patterns(patterns: Option<&str>) -> bool {
+    patterns.is_some_and(|p| p.split([',', '\n']).any(|p| !p.trim().is_empty()))
+}
+
+/// Whether a discovered link passes the include/exclude filters.
+fn url_allowed(url: &Url, options: &CrawlOptions) -> bool {
+    let url = url.as_str();
+    let include = options.include_pattern.as_deref();
+    if has_patterns(include) && !matches_any(include.unwrap_or_default(), url) {
+        return false;
+    }
+    let exclude = options.exclude_pattern.as_deref();
+    !(has_patterns(exclude) && matches_any(exclude.unwrap_or_default(), url))
+}
+
+/// Value of attribute `name` within the inside of a start tag. `lower` is the
+/// ASCII-lowercased copy of `tag` (same byte offsets).
+fn attribute_value<'a>(tag: &'a str, lower: &str, name: &str) -> Option<&'a str> {
+    let mut search = 0;
+    while let Some(rel) = lower[search..].find(name) {
+        let idx = search + rel;
+        search = idx + name.len();
+        if idx == 0 || !lower.as_bytes()[idx - 1].is_ascii_whitespace() {
+            continue;
+        }
+        let rest = lower[search..].trim_start();
+        let Some(after_eq) = rest.strip_prefix('=') else {
+            continue;
+        };
+        let value_start = lower.len() - after_eq.trim_start().len();
+        let value = &tag[value_start..];
+        return match value.chars().next() {
+            Some(quote @ ('"' | '\'')) => value[1..].find(quote).map(|end| &value[1..=end]),
+            Some(_) => {
+                let end = value
+                    .find(|c: char| c.is_ascii_whitespace() || c == '>')
+                    .unwrap_or(value.len());
+                Some(&value[..end])
+            }
+            None => None,
+        };
+    }
+    None
+}
+
+/// Absolute http(s) URLs of the `<a href>` links in `html`, fragments removed.
+fn extract_links(html: &str, base: &Url) -> Vec<Url> {
+    let lower = html.to_ascii_lowercase();
+    let mut links = Vec::new();
+    let mut pos = 0;
+    while let Some(rel) = lower[pos..].find("<a") {
+        let start = pos + rel + 2;
+        pos = start;
+        if !lower[start..].starts_with(|c: char| c.is_ascii_whitespace()) {
+            continue;
+        }
+        let Some(end) = lower[start..].find('>').map(|e| start + e) else {
+            break;
+        };
+        if let Some(href) = attribute_value(&html[start..end], &lower[start..end], "href") {
+            let href = href.trim().replace("&amp;", "&");
+            if let Ok(mut url) = base.join(&href) {
+                url.set_fragment(None);
+                if matches!(url.scheme(), "http" | "https") {
+                    links.push(url);
+                }
+            }
+        }
+        pos = end;
+    }
+    links
+}
+
+fn is_html(content_type: &str) -> bool {
+    let ct = content_type.to_lowercase();
+    ct.is_empty() || ct.contains("html")
+}
+
+fn is_document(content_type: &str) -> bool {
+    let ct = content_type.to_lowercase();
+    is_html(&ct)
+        || ct.contains("application/pdf")
+        || ct.contains("wordprocessingml")
+        || ct.starts_with("text/")
+}
+
+/// Breadth-first crawl from `start`. `fetch` performs the HTTP request so the
+/// traversal can be exercised without a network.
+pub(super) async fn crawl_site<F, Fut>(
+    start: &Url,
+    options: &CrawlOptions,
+    mut fetch: F,
+) -> Vec<CrawledPage>
+where
+    F: FnMut(Url) -> Fut,
+    Fut: Future<Output = Result<FetchedPage, String>>,
+{
+    let max_pages = options.max_pages.clamp(1, MAX_PAGES_LIMIT) as usize;
+    let mut start = start.clone();
+    start.set_fragment(None);
+
+    let mut seen: HashSet<String> = HashSet::from([start.to_string()]);
+    let mut queue: VecDeque<(Url, u32)> = VecDeque::from([(start.clone(), 0)]);
+    let mut pages = Vec::new();
+    let mut fetched = 0;
+
+    while let Some((url, depth)) = queue.pop_front() {
+        if fetched >= max_pages {
+            break;
+        }
+        fetched += 1;
+
+        let page = match fetch(url.clone()).await {
+            Ok(page) => page,
+            Err(e) => {
+                log::warn!("crawl_site: skipping {}: {}", url, e);
+                continue;
+            }
+        };
+        if page.url.origin() != start.origin() {
+            log::debug!("crawl_site: {} redirected off-site to {}", url, page.url);
+            continue;
+        }
+        let mut final_url = page.url.clone();
+        final_url.set_fragment(None);
+        if final_url != url && !seen.insert(final_url.to_string()) {
+            log::debug!(
+                "crawl_site: {} redirected to already seen {}",
+                url,
+                final_url
+            );
+            continue;
+        }
+        if !is_document(&page.content_type) {
+            log::debug!("crawl_site: skipping {} ({})", page.url, page.content_type);
+            continue;
+        }
+
+        if is_html(&page.content_type) && depth < options.max_depth {
+            let html = String::from_utf8_lossy(&page.body);
+            for link in extract_links(&html, &page.url) {
+                if link.origin() == start.origin()
+                    && url_allowed(&link, options)
+                    && seen.insert(link.to_string())
+                {
+                    queue.push_back((link, depth + 1));
+                }
+            }
+        }
+
+        match extract::response_to_markdown(&page.content_type, &page.body) {
+            Ok(markdown) if !markdown.trim().is_empty() => pages.push(CrawledPage {
+                url: page.url,
+                markdown,
+                etag: page.etag,
+                last_modified: page.last_modified,
+            }),
+            Ok(_) => log::debug!("crawl_site: {} has no text", page.url),
+            Err(e) => log::warn!("crawl_site: skipping {}: {}", page.url, e),
+        }
+    }
+    pages
+}
+
+/// Document name for a crawled page: `<set>/<path>` with `.html` stripped,
+/// `index` for the site root, de-duplicated against `used`.
+fn page_document_name(set_name: &str, url: &Url, used: &mut HashSet<String>) -> String {
+    let path = url
+        .path_segments()
+        .map(|segments| {
+            segments
+                .filter(|s| !s.is_empty())
+                .collect::<Vec<_>>()
+                .join("/")
+        })
+        .unwrap_or_default();
+    let path = path
+        .strip_suffix(".html")
+        .or_else(|| path.strip_suffix(".htm"))
+        .unwrap_or(&path);
+    let base = if path.is_empty() {
+        format!("{}/index", set_name)
+    } else {
+        format!("{}/{}", set_name, path)
+    };
+
+    let mut name = base.clone();
+    let mut n = 2;
+    while !used.insert(name.clone()) {
+        name = format!("{}-{}", base, n);
+        n += 1;
+    }
+    name
+}
+
+async fn fetch_page(client: &reqwest::Client, url: Url) -> Result<FetchedPage, String> {
+    let response = client
+        .get(url)
+        .send()
+        .await
+        .map_err(|e| format!("request failed: {}", e))?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("HTTP {}", status));
+    }
+    let header = |name: reqwest::header::HeaderName| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .map(str::to_string)
+    };
+    let content_type = header(reqwest::header::CONTENT_TYPE).unwrap_or_default();
+    let etag = header(reqwest::header::ETAG);
+    let last_modified = header(reqwest::header::LAST_MODIFIED);
+    let url = response.url().clone();
+    let body = response
+        .bytes()
+        .await
+        .map_err(|e| format!("failed to read body: {}", e))?;
+    Ok(FetchedPage {
+        url,
+        content_type,
+        body: body.to_vec(),
+        etag,
+        last_modified,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Add documents by crawling a site
+// ---------------------------------------------------------------------------
+
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub async fn add_document_site(
+    db: tauri::State<'_, Db>,
+    data_dir: tauri::State<'_, DataDir>,
+    name: String,
+    url: String,
+    options: CrawlOptions,
+    scope: String,
+    skill_ids: Vec<i64>,
+) -> Result<Vec<DocumentRecord>, String> {
+    log::info!(
+        "add_document_site: name={} url={} depth={} max_pages={} scope={}",
+        name,
+        url,
+        options.max_depth,
+        options.max_pages,
+        scope
+    );
+
+    let start = Url::parse(&url).map_err(|e| format!("Invalid URL '{}': {}", url, e))?;
+    if !matches!(start.scheme(), "http" | "https") {
+        return Err(format!("Unsupported URL scheme '{}'", start.scheme()));
+    }
+    let dir = documents_dir(&data_dir.0).map_err(|e| e.to_string())?;
+
+    let client = reqwest::Client::builder()
+        .user_agent("SkillBuilder")
+        .timeout(std::time::Duration::from_secs(60))
+        .connect_timeout(std::time::Duration::from_secs(10))
+        .build()
+        .unwrap_or_else(|_| reqwest::Client::new());
+    let pages = crawl_site(&start, &options, |page_url| {
+        let client = client.clone();
+        async move { fetch_page(&client, page_url).await }
+    })
+    .await;
+
+    if pages.is_empty() {
+        return Err(format!("No pages with text content found at {}", url));
+    }
+
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    let fetched_at = chrono::Utc::now().to_rfc3339();
+    let mut used_names = HashSet::new();
+    let mut records = Vec::new();
+    for page in pages {
+        let doc_name = page_document_name(&name, &page.url, &mut used_names);
+        let doc_id = match store_document(
+            &conn,
+            &dir,
+            &doc_name,
+            "url",
+            Some(page.url.as_str()),
+            &page.markdown,
+            &scope,
+            &skill_ids,
+        ) {
+            Ok(id) => id,
+            Err(e) => {
+                log::error!("add_document_site: {}", e);
+                continue;
+            }
+        };
+        if let Err(e) = db_set_document_fetch_state(
+            &conn,
+            doc_id,
+            page.etag.as_deref(),
+            page.last_modified.as_deref(),
+            &refresh::content_hash(&page.markdown),
+            &fetched_at,
+        ) {
+            log::warn!("add_document_site: failed to record fetch state: {}", e);
+        }
+        if let Ok(Some(rec)) = db_get_document(&conn, doc_id) {
+            records.push(rec);
+        }
+    }
+
+    log::info!(
+        "add_document_site: ingested {} pages from {}",
+        records.len(),
+        url
+    );
+    Ok(records)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::collections::HashMap;
+
+    fn html_page(url: &str, body: &str) -> FetchedPage {
+        FetchedPage {
+            url: Url::parse(url).unwrap(),
+            content_type: "text/html; charset=utf-8".to_string(),
+            body: body.as_bytes().to_vec(),
+            etag: None,
+            last_modified: None,
+        }
+    }
+
+    async fn crawl(
+        site: &HashMap<&'static str, &'static str>,
+        start: &str,
+        options: CrawlOptions,
+    ) -> (Vec<String>, Vec<String>) {
+        let requested = std::cell::RefCell::new(Vec::new());
+        let pages = crawl_site(&Url::parse(start).unwrap(), &options, |url| {
+            requested.borrow_mut().push(url.to_string());
+            let result = site
+                .get(url.as_str())
+                .map(|body| html_page(url.as_str(), body))
+                .ok_or_else(|| "HTTP 404".to_string());
+            async move { result }
+        })
+        .await;
+        let stored = pages.into_iter().map(|p| p.url.to_string()).collect();
+        (requested.into_inner(), stored)
+    }
+
+    fn docs_site() -> HashMap<&'static str, &'static str> {
+        HashMap::from([
+            (
+                "https://docs.example.com/",
+                r##"<h1>Home</h1>
+                <a href="/guide/intro.html">Intro</a>
+                <A HREF='guide/setup.html#install'>Setup</A>
+                <a href="https://other.example.com/x">Elsewhere</a>
+                <a href="mailto:team@example.com">Mail</a>
+                <a class="nav" href=/api/ref>API</a>"##,
+            ),
+            (
+                "https://docs.example.com/guide/intro.html",
+                r#"<p>Intro text</p><a href="deep.html">Deep</a><a href="/">Home</a>"#,
+            ),
+            (
+                "https://docs.example.com/guide/setup.html",
+                "<p>Setup text</p>",
+            ),
+            ("https://docs.example.com/api/ref", "<p>API text</p>"),
+            (
+                "https://docs.example.com/guide/deep.html",
+                "<p>Deep text</p>",
+            ),
+        ])
+    }
+
+    #[test]
+    fn test_extract_links_resolves_relative_and_strips_fragments() {
+        let base = Url::parse("https://docs.example.com/guide/").unwrap();
+        let links = extract_links(
+            r#"<a href="a.html#x">A</a><abbr>no</abbr><a title="t" href='/b?x=1&amp;y=2'>B</a><a name="top">"#,
+            &base,
+        );
+        let links: Vec<String> = links.iter().map(Url::to_string).collect();
+        assert_eq!(
+            links,
+            vec![
+                "https://docs.example.com/guide/a.html",
+                "https://docs.example.com/b?x=1&y=2"
+            ]
+        );
+    }
+
+    #[test]
+    fn test_patterns_support_wildcards_and_substrings() {
+        assert!(wildcard_match("https://*/guide/*", "https://d.com/guide/a"));
+        assert!(!wildcard_match("https://*/guide/*", "https://d.com/api/a"));
+        assert!(wildcard_match("*a*b*", "xxaxxbxx"));
+
+        let url = Url::parse("https://d.com/guide/changelog").unwrap();
+        let options = CrawlOptions {
+            include_pattern: Some("*/guide/*, */api/*".to_string()),
+            exclude_pattern: Some("changelog".to_string()),
+            ..CrawlOptions::default()
+        };
+        assert!(!url_allowed(&url, &options));
+        let options = CrawlOptions {
+            include_pattern: Some("  ".to_string()),
+            ..CrawlOptions::default()
+        };
+        assert!(url_allowed(&url, &options));
+    }
+
+    #[tokio::test]
+    async fn test_crawl_follows_same_origin_links_to_depth() {
+        let site = docs_site();
+        let options = CrawlOptions {
+            max_depth: 1,
+            ..CrawlOptions::default()
+        };
+        let (requested, stored) = crawl(&site, "https://docs.example.com/", options).await;
+
+        assert_eq!(
+            stored,
+            vec![
+                "https://docs.example.com/",
+                "https://docs.example.com/guide/intro.html",
+                "https://docs.example.com/guide/setup.html",
+                "https://docs.example.com/api/ref",
+            ]
+        );
+        assert!(requested
+            .iter()
+            .all(|u| u.starts_with("https://docs.example.com/")));
+    }
+
+    #[tokio::test]
+    async fn test_crawl_skips_redirects_to_seen_pages() {
+        let site = HashMap::from([
+            (
+                "https://docs.example.com/",
+                r#"<a href="/guide/intro.html">Intro</a><a href="/old-intro">Old</a>
+                <a href="/latest">Latest</a><a href="/current">Current</a><a href="/docs">Docs</a>"#,
+            ),
+            ("https://docs.example.com/guide/intro.html", "<p>Intro</p>"),
+            ("https://docs.example.com/v2/", "<p>Version 2</p>"),
+        ]);
+        let redirects = HashMap::from([
+            (
+                "https://docs.example.com/old-intro",
+                "https://docs.example.com/guide/intro.html",
+            ),
+            (
+                "https://docs.example.com/latest",
+                "https://docs.example.com/v2/",
+            ),
+            (
+                "https://docs.example.com/current",
+                "https://docs.example.com/v2/#top",
+            ),
+            ("https://docs.example.com/docs", "https://docs.example.com/"),
+        ]);
+        let options = CrawlOptions {
+            max_depth: 1,
+            ..CrawlOptions::default()
+        };
+        let pages = crawl_site(
+            &Url::parse("https://docs.example.com/").unwrap(),
+            &options,
+            |url| {
+                let target = redirects.get(url.as_str()).copied().unwrap_or(url.as_str());
+                let lookup = target.split('#').next().unwrap_or(target);
+                let result = site
+                    .get(lookup)
+                    .map(|body| html_page(target, body))
+                    .ok_or_else(|| "HTTP 404".to_string());
+                async move { result }
+            },
+        )
+        .await;
+
+        let stored: Vec<String> = pages.into_iter().map(|p| p.url.to_string()).collect();
+        assert_eq!(
+            stored,
+            vec![
+                "https://docs.example.com/",
+                "https://docs.example.com/guide/intro.html",
+                "https://docs.example.com/v2/",
+            ]
+        );
+    }
+
+    #[tokio::test]
+    async fn test_crawl_applies_filters_and_page_limit() {
+        let site = docs_site();
+        let options = CrawlOptions {
+            max_depth: 3,
+            max_pages: 10,
+            include_pattern: Some("*/guide/*".to_string()),
+            exclude_pattern: Some("setup".to_string()),
+        };
+        let (_, stored) = crawl(&site, "https://docs.example.com/", options).await;
+        assert_eq!(
+            stored,
+            vec![
+                "https://docs.example.com/",
+                "https://docs.example.com/guide/intro.html",
+                "https://docs.example.com/guide/deep.html",
+            ]
+        );
+
+        let options = CrawlOptions {
+            max_depth: 3,
+            max_pages: 2,
+            ..CrawlOptions::default()
+        };
+        let (requested, stored) = crawl(&site, "https://docs.example.com/", options).await;
+        assert_eq!(requested.len(), 2);
+        assert_eq!(stored.len(), 2);
+    }
+
+    #[test]
+    fn test_page_document_name() {
+        let mut used = HashSet::new();
+        let name = |url: &str, used: &mut HashSet<String>| {
+            page_document_name("Data Docs", &Url::parse(url).unwrap(), used)
+        };
+        assert_eq!(name("https://d.com/", &mut used), "Data Docs/index");
+        assert_eq!(
+            name("https://d.com/guide/intro.html", &mut used),
+            "Data Docs/guide/intro"
+        );
+        assert_eq!(
+            name("https://d.com/guide/intro.htm", &mut used),
+            "Data Docs/guide/intro-2"
+        );
+    }
+}
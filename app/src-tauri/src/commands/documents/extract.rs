@@ -0,0 +1,481 @@
+//! Text extraction for ingested documents.
+//!
+//! Every document is stored as Markdown so it can be injected into prompts
+//! verbatim. This module turns the source formats we accept (plain text,
+//! Markdown, HTML, PDF text layer, DOCX) into clean Markdown before storage.
+
+use std::io::Read;
+use std::path::Path;
+
+use quick_xml::events::Event;
+use quick_xml::Reader;
+
+/// File extensions accepted by document ingestion (lowercase, without dot).
+pub(crate) const SUPPORTED_EXTENSIONS: &[&str] =
+    &["md", "markdown", "txt", "html", "htm", "pdf", "docx"];
+
+/// Return the lowercase extension of `path`, or an empty string.
+fn extension_of(path: &Path) -> String {
+    path.extension()
+        .and_then(|e| e.to_str())
+        .unwrap_or("")
+        .to_lowercase()
+}
+
+/// Whether `path` has an extension that ingestion knows how to extract.
+pub(crate) fn is_supported(path: &Path) -> bool {
+    SUPPORTED_EXTENSIONS.contains(&extension_of(path).as_str())
+}
+
+/// Read `path` from disk and convert it to Markdown based on its extension.
+pub(crate) fn extract_markdown(path: &Path) -> Result<String, String> {
+    let bytes =
+        std::fs::read(path).map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+    extract_markdown_from_bytes(&extension_of(path), &bytes)
+        .map_err(|e| format!("{}: {}", path.display(), e))
+}
+
+/// Convert raw file bytes to Markdown. `extension` is lowercase without the dot.
+pub(crate) fn extract_markdown_from_bytes(extension: &str, bytes: &[u8]) -> Result<String, String> {
+    let markdown = match extension {
+        "md" | "markdown" | "txt" => String::from_utf8_lossy(bytes).into_owned(),
+        "html" | "htm" => html_to_markdown(&String::from_utf8_lossy(bytes)),
+        "pdf" => pdf_to_markdown(bytes)?,
+        "docx" => docx_to_markdown(bytes)?,
+        other => return Err(format!("Unsupported document type '.{}'", other)),
+    };
+    let markdown = normalise_whitespace(&markdown);
+    if markdown.is_empty() {
+        return Err("Document contains no extractable text".to_string());
+    }
+    Ok(markdown)
+}
+
+/// Convert a fetched response body to Markdown based on its `Content-Type`;
+/// anything that is not PDF or DOCX is treated as HTML.
+pub(crate) fn response_to_markdown(content_type: &str, body: &[u8]) -> Result<String, String> {
+    let content_type = content_type.to_lowercase();
+    if content_type.contains("application/pdf") {
+        extract_markdown_from_bytes("pdf", body)
+    } else if content_type.contains("wordprocessingml") {
+        extract_markdown_from_bytes("docx", body)
+    } else {
+        Ok(html_to_markdown(&String::from_utf8_lossy(body)))
+    }
+}
+
+/// Convert an HTML page to Markdown, falling back to the raw body on failure.
+fn html_to_markdown(html: &str) -> String {
+    htmd::convert(html).unwrap_or_else(|_| html.to_string())
+}
+
+/// Trim trailing whitespace per line and collapse runs of blank lines.
+fn normalise_whitespace(text: &str) -> String {
+    let mut out = String::with_capacity(text.len());
+    let mut blank_run = 0usize;
+    for line in text.replace("\r\n", "\n").replace('\u{c}', "\n").lines() {
+        let line = line.trim_end();
+        if line.trim().is_empty() {
+            blank_run += 1;
+            if blank_run > 1 {
+                continue;
+            }
+            out.push('\n');
+        } else {
+            blank_run = 0;
+            out.push_str(line);
+            out.push('\n');
+        }
+    }
+    out.trim().to_string()
+}
+
+// ---------------------------------------------------------------------------
+// PDF
+// ---------------------------------------------------------------------------
+
+/// Extract the text layer of a PDF, one section per page.
+///
+/// `pdf-extract` can panic on malformed fonts, so extraction runs under
+/// `catch_unwind` and surfaces a regular error instead.
+fn pdf_to_markdown(bytes: &[u8]) -> Result<String, String> {
+    let pages = std::panic::catch_unwind(|| pdf_extract::extract_text_from_mem_by_pages(bytes))
+        .map_err(|_| "PDF parser crashed while reading the document".to_string())?
+        .map_err(|e| format!("Failed to read PDF: {}", e))?;
+
+    let pages: Vec<String> = pages
+        .iter()
+        .map(|p| join_wrapped_lines(p))
+        .filter(|p| !p.trim().is_empty())
+        .collect();
+    if pages.is_empty() {
+        return Err("PDF has no text layer (scanned images are not supported)".to_string());
+    }
+    Ok(pages.join("\n\n"))
+}
+
+/// Re-flow hard-wrapped PDF lines into paragraphs.
+///
+/// Lines inside a paragraph are joined with a space (or without one when the
+/// previous line ends in a hyphenated word break); blank lines remain
+/// paragraph separators.
+fn join_wrapped_lines(page: &str) -> String {
+    let mut paragraphs: Vec<String> = Vec::new();
+    let mut current = String::new();
+    for line in page.lines() {
+        let line = line.trim();
+        if line.is_empty() {
+            if !current.is_empty() {
+                paragraphs.push(std::mem::take(&mut current));
+            }
+            continue;
+        }
+        if current.is_empty() {
+            current.push_str(line);
+        } else if current.ends_with('-')
+            && current[..current.len() - 1]
+                .chars()
+                .last()
+                .is_some_and(|c| c.is_alphabetic())
+        {
+            current.pop();
+            current.push_str(line);
+        } else {
+            current.push(' ');
+            current.push_str(line);
+        }
+    }
+    if !current.is_empty() {
+        paragraphs.push(current);
+    }
+    paragraphs.join("\n\n")
+}
+
+// ---------------------------------------------------------------------------
+// DOCX
+// ---------------------------------------------------------------------------
+
+/// Paragraph being assembled while walking `word/document.xml`.
+#[derive(Default)]
+struct DocxParagraph {
+    text: String,
+    heading_level: Option<usize>,
+    is_list_item: bool,
+}
+
+impl DocxParagraph {
+    fn into_markdown(self) -> Option<String> {
+        let text = self.text.trim().to_string();
+        if text.is_empty() {
+            return None;
+        }
+        Some(match (self.heading_level, self.is_list_item) {
+            (Some(level), _) => format!("{} {}", "#".repeat(level), text),
+            (None, true) => format!("- {}", text),
+            (None, false) => text,
+        })
+    }
+}
+
+/// Map a Word paragraph style id (`Heading1`, `Title`, ...) to a heading level.
+fn heading_level_for_style(style: &str) -> Option<usize> {
+    let lower = style.to_ascii_lowercase();
+    if lower == "title" {
+        return Some(1);
+    }
+    let level = lower
+        .strip_prefix("heading")?
+        .trim()
+        .parse::<usize>()
+        .ok()?;
+    Some(level.clamp(1, 6))
+}
+
+/// Render collected table rows as a Markdown table; the first row is the header.
+fn table_to_markdown(rows: &[Vec<String>]) -> Option<String> {
+    let width = rows.iter().map(|r| r.len()).max().unwrap_or(0);
+    if width == 0 {
+        return None;
+    }
+    let render_row = |row: &Vec<String>| {
+        let cells: Vec<String> = (0..width)
+            .map(|i| {
+                row.get(i)
+                    .map(|c| c.replace('|', "\\|").replace('\n', " "))
+                    .unwrap_or_default()
+            })
+            .collect();
+        format!("| {} |", cells.join(" | "))
+    };
+    let mut lines = vec![render_row(&rows[0])];
+    lines.push(format!("|{}", " --- |".repeat(width)));
+    lines.extend(rows[1..].iter().map(render_row));
+    Some(lines.join("\n"))
+}
+
+/// Extract paragraphs, headings, list items and tables from a DOCX package.
+fn docx_to_markdown(bytes: &[u8]) -> Result<String, String> {
+    let mut archive = zip::ZipArchive::new(std::io::Cursor::new(bytes))
+        .map_err(|e| format!("Not a valid DOCX archive: {}", e))?;
+    let mut xml = String::new();
+    archive
+        .by_name("word/document.xml")
+        .map_err(|_| "DOCX archive has no word/document.xml".to_string())?
+        .read_to_string(&mut xml)
+        .map_err(|e| format!("Failed to read word/document.xml: {}", e))?;
+
+    let mut reader = Reader::from_str(&xml);
+    reader.config_mut().trim_text(false);
+
+    let mut blocks: Vec<String> = Vec::new();
+    let mut paragraph: Option<DocxParagraph> = None;
+    let mut in_text = false;
+    // Table state: nesting depth, rows of the outermost table, and the cell
+    // currently being filled. Nested tables are flattened into the outer cell.
+    let mut table_depth = 0usize;
+    let mut rows: Vec<Vec<String>> = Vec::new();
+    let mut cell: Option<String> = None;
+
+    loop {
+        let event = reader
+            .read_event()
+            .map_err(|e| format!("Malformed DOCX XML: {}", e))?;
+        match event {
+            Event::Start(e) | Event::Empty(e) => match e.name().as_ref() {
+                b"w:p" => paragraph = Some(DocxParagraph::default()),
+                b"w:pStyle" => {
+                    if let (Some(p), Ok(Some(val))) =
+                        (paragraph.as_mut(), e.try_get_attribute("w:val"))
+                    {
+                        let style = val.unescape_value().unwrap_or_default();
+                        p.heading_level = heading_level_for_style(&style);
+                    }
+                }
+                b"w:numPr" => {
+                    if let Some(p) = paragraph.as_mut() {
+                        p.is_list_item = true;
+                    }
+                }
+                b"w:t" => in_text = true,
+                b"w:tab" => {
+                    if let Some(p) = paragraph.as_mut() {
+                        p.text.push(' ');
+                    }
+                }
+                b"w:br" | b"w:cr" => {
+                    if let Some(p) = paragraph.as_mut() {
+                        p.text.push('\n');
+                    }
+                }
+                b"w:tbl" => table_depth += 1,
+                b"w:tr" if table_depth == 1 => rows.push(Vec::new()),
+                b"w:tc" if table_depth == 1 => cell = Some(String::new()),
+                _ => {}
+            },
+            Event::Text(t) if in_text => {
+                if let Some(p) = paragraph.as_mut() {
+                    p.text.push_str(&t.decode().unwrap_or_default());
+                }
+            }
+            Event::GeneralRef(r) if in_text => {
+                if let Some(p) = paragraph.as_mut() {
+                    if let Ok(Some(c)) = r.resolve_char_ref() {
+                        p.text.push(c);
+                    } else if let Some(s) = quick_xml::escape::resolve_predefined_entity(
+                        &r.decode().unwrap_or_default(),
+                    ) {
+                        p.text.push_str(s);
+                    }
+                }
+            }
+            Event::End(e) => match e.name().as_ref() {
+                b"w:t" => in_text = false,
+                b"w:p" => {
+                    let Some(text) = paragraph.take().and_then(DocxParagraph::into_markdown) else {
+                        continue;
+                    };
+                    match cell.as_mut() {
+                        Some(c) => {
+                            if !c.is_empty() {
+                                c.push(' ');
+                            }
+                            c.push_str(&text);
+                        }
+                        None => blocks.push(text),
+                    }
+                }
+                b"w:tc" if table_depth == 1 => {
+                    if let (Some(c), Some(row)) = (cell.take(), rows.last_mut()) {
+                        row.push(c);
+                    }
+                }
+                b"w:tbl" => {
+                    table_depth = table_depth.saturating_sub(1);
+                    if table_depth == 0 {
+                        if let Some(table) = table_to_markdown(&std::mem::take(&mut rows)) {
+                            blocks.push(table);
+                        }
+                    }
+                }
+                _ => {}
+            },
+            Event::Eof => break,
+            _ => {}
+        }
+    }
+
+    Ok(blocks.join("\n\n"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::io::Write;
+
+    fn build_docx(body: &str) -> Vec<u8> {
+        let mut buf = std::io::Cursor::new(Vec::new());
+        {
+            let mut zip = zip::ZipWriter::new(&mut buf);
+            let opts = zip::write::SimpleFileOptions::default();
+            zip.start_file("word/document.xml", opts).unwrap();
+            write!(
+                zip,
+                r#"<?xml version="1.0" encoding="UTF-8"?><w:document xmlns:w="http://schemas.openxmlformats.org/wordprocessingml/2006/main"><w:body>{}</w:body></w:document>"#,
+                body
+            )
+            .unwrap();
+            zip.finish().unwrap();
+        }
+        buf.into_inner()
+    }
+
+    /// Build a minimal single-page PDF whose content stream draws `text`.
+    fn build_pdf(text: &str) -> Vec<u8> {
+        let stream = format!("BT /F1 12 Tf 72 720 Td ({}) Tj ET", text);
+        let objects = [
+            "<< /Type /Catalog /Pages 2 0 R >>".to_string(),
+            "<< /Type /Pages /Kids [3 0 R] /Count 1 >>".to_string(),
+            "<< /Type /Page /Parent 2 0 R /MediaBox [0 0 612 792] /Contents 4 0 R /Resources << /Font << /F1 5 0 R >> >> >>".to_string(),
+            format!("<< /Length {} >>\nstream\n{}\nendstream", stream.len(), stream),
+            "<< /Type /Font /Subtype /Type1 /BaseFont /Helvetica /Encoding /WinAnsiEncoding >>".to_string(),
+        ];
+        let mut pdf = b"%PDF-1.4\n".to_vec();
+        let mut offsets = Vec::new();
+        for (i, obj) in objects.iter().enumerate() {
+            offsets.push(pdf.len());
+            pdf.extend(format!("{} 0 obj\n{}\nendobj\n", i + 1, obj).into_bytes());
+        }
+        let xref_at = pdf.len();
+        pdf.extend(format!("xref\n0 {}\n0000000000 65535 f \n", objects.len() + 1).into_bytes());
+        for off in offsets {
+            pdf.extend(format!("{:010} 00000 n \n", off).into_bytes());
+        }
+        pdf.extend(
+            format!(
+                "trailer\n<< /Size {} /Root 1 0 R >>\nstartxref\n{}\n%%EOF\n",
+                objects.len() + 1,
+                xref_at
+            )
+            .into_bytes(),
+        );
+        pdf
+    }
+
+    #[test]
+    fn test_supported_extensions() {
+        assert!(is_supported(Path::new("a/b/Spec.PDF")));
+        assert!(is_supported(Path::new("policy.docx")));
+        assert!(is_supported(Path::new("page.html")));
+        assert!(!is_supported(Path::new("image.png")));
+        assert!(!is_supported(Path::new("README")));
+    }
+
+    #[test]
+    fn test_plain_text_is_normalised() {
+        let md =
+            extract_markdown_from_bytes("txt", b"line one   \r\n\r\n\r\n\r\nline two\n").unwrap();
+        assert_eq!(md, "line one\n\nline two");
+    }
+
+    #[test]
+    fn test_empty_document_is_rejected() {
+        let err = extract_markdown_from_bytes("md", b"  \n\n ").unwrap_err();
+        assert!(err.contains("no extractable text"));
+    }
+
+    #[test]
+    fn test_unsupported_extension_is_rejected() {
+        let err = extract_markdown_from_bytes("png", b"\x89PNG").unwrap_err();
+        assert!(err.contains("Unsupported"));
+    }
+
+    #[test]
+    fn test_html_converted_to_markdown() {
+        let md =
+            extract_markdown_from_bytes("html", b"<h1>Title</h1><p>Body <b>bold</b></p>").unwrap();
+        assert!(md.starts_with("# Title"), "got: {md}");
+        assert!(md.contains("**bold**"));
+    }
+
+    #[test]
+    fn test_pdf_text_layer_extracted() {
+        let md =
+            extract_markdown_from_bytes("pdf", &build_pdf("Retention policy applies")).unwrap();
+        assert!(md.contains("Retention policy applies"), "got: {md}");
+    }
+
+    #[test]
+    fn test_invalid_pdf_returns_error() {
+        assert!(extract_markdown_from_bytes("pdf", b"not a pdf").is_err());
+    }
+
+    #[test]
+    fn test_join_wrapped_lines_rejoins_hyphenation() {
+        let text = "The customer identi-\nfier is stable\nacross systems.\n\nNext paragraph";
+        assert_eq!(
+            join_wrapped_lines(text),
+            "The customer identifier is stable across systems.\n\nNext paragraph"
+        );
+    }
+
+    #[test]
+    fn test_docx_paragraphs_headings_and_lists() {
+        let body = r#"
+            <w:p><w:pPr><w:pStyle w:val="Heading2"/></w:pPr><w:r><w:t>Scope</w:t></w:r></w:p>
+            <w:p><w:r><w:t xml:space="preserve">Revenue &amp; margin </w:t></w:r><w:r><w:t>rules.</w:t></w:r></w:p>
+            <w:p><w:pPr><w:numPr><w:ilvl w:val="0"/></w:numPr></w:pPr><w:r><w:t>First item</w:t></w:r></w:p>
+            <w:p></w:p>
+        "#;
+        let md = extract_markdown_from_bytes("docx", &build_docx(body)).unwrap();
+        assert_eq!(md, "## Scope\n\nRevenue & margin rules.\n\n- First item");
+    }
+
+    #[test]
+    fn test_docx_tables_rendered_as_markdown() {
+        let body = r#"
+            <w:tbl>
+              <w:tr><w:tc><w:p><w:r><w:t>Field</w:t></w:r></w:p></w:tc><w:tc><w:p><w:r><w:t>Type</w:t></w:r></w:p></w:tc></w:tr>
+              <w:tr><w:tc><w:p><w:r><w:t>id</w:t></w:r></w:p></w:tc><w:tc><w:p><w:r><w:t>int|null</w:t></w:r></w:p></w:tc></w:tr>
+            </w:tbl>
+            <w:p><w:r><w:t>After table</w:t></w:r></w:p>
+        "#;
+        let md = extract_markdown_from_bytes("docx", &build_docx(body)).unwrap();
+        assert_eq!(
+            md,
+            "| Field | Type |\n| --- | --- |\n| id | int\\|null |\n\nAfter table"
+        );
+    }
+
+    #[test]
+    fn test_docx_without_document_xml_is_rejected() {
+        let mut buf = std::io::Cursor::new(Vec::new());
+        {
+            let mut zip = zip::ZipWriter::new(&mut buf);
+            zip.start_file("other.xml", zip::write::SimpleFileOptions::default())
+                .unwrap();
+            zip.finish().unwrap();
+        }
+        let err = extract_markdown_from_bytes("docx", &buf.into_inner()).unwrap_err();
+        assert!(err.contains("word/document.xml"));
+    }
+}
@@ -1,8 +1,15 @@
+pub mod crawl;
+mod extract;
+pub mod refresh;
+
 use std::path::{Path, PathBuf};
 
+use rusqlite::Connection;
+
 use crate::db::{
-    db_delete_document, db_get_document, db_insert_document, db_list_documents,
-    db_set_document_skills, db_update_document_scope, Db, DocumentRecord,
+    db_delete_document, db_get_document, db_index_document_chunks, db_insert_document,
+    db_list_documents, db_set_document_fetch_state, db_set_document_skills,
+    db_update_document_scope, Db, DocumentRecord,
 };
 use crate::DataDir;
 
@@ -69,6 +76,72 @@ fn documents_dir(data_dir: &Path) -> std::io::Result<PathBuf> {
     Ok(dir)
 }
 
+/// Write `markdown` into the documents directory, register it in the DB and
+/// index its chunks for retrieval.
+///
+/// The file is written under a temporary name first, then renamed to a stable
+/// `<id>-<name>.md` path once the row id is known.
+#[allow(clippy::too_many_arguments)]
+fn store_document(
+    conn: &Connection,
+    dir: &Path,
+    name: &str,
+    source_type: &str,
+    source_url: Option<&str>,
+    markdown: &str,
+    scope: &str,
+    skill_ids: &[i64],
+) -> Result<i64, String> {
+    let temp_path = dir.join(format!("tmp-{}.md", uuid::Uuid::new_v4()));
+    std::fs::write(&temp_path, markdown)
+        .map_err(|e| format!("Failed to write {}: {}", temp_path.display(), e))?;
+
+    let doc_id = db_insert_document(
+        conn,
+        name,
+        source_type,
+        source_url,
+        temp_path.to_str().unwrap_or(""),
+        scope,
+    )
+    .map_err(|e| {
+        let _ = std::fs::remove_file(&temp_path);
+        format!("Failed to insert document '{}': {}", name, e)
+    })?;
+
+    let final_path = dir.join(format!("{}-{}.md", doc_id, sanitise_filename(name)));
+    if let Err(e) = std::fs::rename(&temp_path, &final_path) {
+        let _ = std::fs::remove_file(&temp_path);
+        let _ = db_delete_document(conn, doc_id);
+        return Err(format!("Failed to rename {}: {}", temp_path.display(), e));
+    }
+
+    conn.execute(
+        "UPDATE documents SET file_path = ?1, content_hash = ?2 WHERE id = ?3",
+        rusqlite::params![
+            final_path.to_str().unwrap_or(""),
+            refresh::content_hash(markdown),
+            doc_id
+        ],
+    )
+    .map_err(|e| e.to_string())?;
+
+    if scope == "skill" && !skill_ids.is_empty() {
+        db_set_document_skills(conn, doc_id, skill_ids).map_err(|e| e.to_string())?;
+    }
+
+    let chunk_count = db_index_document_chunks(conn, doc_id, markdown)
+        .map_err(|e| format!("Failed to index document '{}': {}", name, e))?;
+    log::debug!(
+        "store_document: indexed '{}' into {} chunks (doc_id={})",
+        name,
+        chunk_count,
+        doc_id
+    );
+
+    Ok(doc_id)
+}
+
 // ---------------------------------------------------------------------------
 // List
 // ---------------------------------------------------------------------------
@@ -104,46 +177,73 @@ pub fn add_document_file(
     })?;
 
     let conn = db.0.lock().map_err(|e| e.to_string())?;
-
-    // Use a placeholder id pattern: insert first to get the id, then rename
-    // For simplicity, insert with temp path then update.
-    let temp_path = dir.join(format!("tmp-{}.md", uuid::Uuid::new_v4()));
-    std::fs::write(&temp_path, &content).map_err(|e| {
-        log::error!("add_document_file: write failed: {}", e);
-        e.to_string()
-    })?;
-
-    let doc_id = db_insert_document(
-        &conn,
-        &name,
-        "file",
-        None,
-        temp_path.to_str().unwrap_or(""),
-        &scope,
+    let doc_id = store_document(
+        &conn, &dir, &name, "file", None, &content, &scope, &skill_ids,
     )
     .map_err(|e| {
-        let _ = std::fs::remove_file(&temp_path);
-        log::error!("add_document_file: db insert failed: {}", e);
-        e.to_string()
+        log::error!("add_document_file: {}", e);
+        e
     })?;
 
-    // Rename to stable path incorporating the id
-    let final_path = dir.join(format!("{}-{}.md", doc_id, sanitise_filename(&name)));
-    if let Err(e) = std::fs::rename(&temp_path, &final_path) {
-        let _ = std::fs::remove_file(&temp_path);
-        log::error!("add_document_file: rename failed: {}", e);
-        return Err(e.to_string());
+    db_get_document(&conn, doc_id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Document not found after insert".to_string())
+}
+
+// ---------------------------------------------------------------------------
+// Add document from a file on disk (PDF, DOCX, HTML, Markdown, text)
+// ---------------------------------------------------------------------------
+
+#[tauri::command]
+pub fn add_document_path(
+    db: tauri::State<'_, Db>,
+    data_dir: tauri::State<'_, DataDir>,
+    name: String,
+    file_path: String,
+    scope: String,
+    skill_ids: Vec<i64>,
+) -> Result<DocumentRecord, String> {
+    log::info!(
+        "add_document_path: name={} path={} scope={}",
+        name,
+        file_path,
+        scope
+    );
+
+    let path = Path::new(&file_path);
+    if !extract::is_supported(path) {
+        return Err(format!(
+            "Unsupported document type: '{}' (supported: {})",
+            file_path,
+            extract::SUPPORTED_EXTENSIONS.join(", ")
+        ));
     }
 
-    conn.execute(
-        "UPDATE documents SET file_path = ?1 WHERE id = ?2",
-        rusqlite::params![final_path.to_str().unwrap_or(""), doc_id],
+    let markdown = extract::extract_markdown(path).map_err(|e| {
+        log::error!("add_document_path: extraction failed: {}", e);
+        e
+    })?;
+
+    let dir = documents_dir(&data_dir.0).map_err(|e| {
+        log::error!("add_document_path: failed to create documents dir: {}", e);
+        e.to_string()
+    })?;
+
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    let doc_id = store_document(
+        &conn, &dir, &name, "file", None, &markdown, &scope, &skill_ids,
     )
-    .map_err(|e| e.to_string())?;
+    .map_err(|e| {
+        log::error!("add_document_path: {}", e);
+        e
+    })?;
 
-    if scope == "skill" && !skill_ids.is_empty() {
-        db_set_document_skills(&conn, doc_id, &skill_ids).map_err(|e| e.to_string())?;
-    }
+    log::info!(
+        "add_document_path: extracted {} chars of markdown from {} (doc_id={})",
+        markdown.len(),
+        file_path,
+        doc_id
+    );
 
     db_get_document(&conn, doc_id)
         .map_err(|e| e.to_string())?
@@ -175,63 +275,48 @@ pub async fn add_document_url(
         e.to_string()
     })?;
 
-    // Fetch the URL
-    let response = reqwest::get(&url).await.map_err(|e| {
-        log::error!("add_document_url: fetch failed for {}: {}", url, e);
-        format!("Failed to fetch URL: {}", e)
-    })?;
-
-    let body = response.text().await.map_err(|e| {
-        log::error!("add_document_url: failed to read response body: {}", e);
-        e.to_string()
-    })?;
-
-    // Convert HTML to Markdown
-    let markdown = htmd::convert(&body).unwrap_or_else(|_| body.clone());
+    // Fetch the URL and convert the body to Markdown based on its content type
+    let fetched = match refresh::fetch_url_markdown(&url, None, None).await {
+        Ok(refresh::FetchOutcome::Fetched(fetched)) => fetched,
+        Ok(refresh::FetchOutcome::NotModified) => {
+            return Err("Server returned 304 Not Modified for an unconditional request".into())
+        }
+        Err(e) => {
+            log::error!("add_document_url: fetch failed for {}: {}", url, e);
+            return Err(e);
+        }
+    };
+    let markdown = fetched.markdown;
 
     let conn = db.0.lock().map_err(|e| e.to_string())?;
-
-    let temp_path = dir.join(format!("tmp-{}.md", uuid::Uuid::new_v4()));
-    std::fs::write(&temp_path, &markdown).map_err(|e| {
-        log::error!("add_document_url: write failed: {}", e);
-        e.to_string()
-    })?;
-
-    let doc_id = db_insert_document(
+    let doc_id = store_document(
         &conn,
+        &dir,
         &name,
         "url",
         Some(&url),
-        temp_path.to_str().unwrap_or(""),
+        &markdown,
         &scope,
+        &skill_ids,
     )
     .map_err(|e| {
-        let _ = std::fs::remove_file(&temp_path);
-        log::error!("add_document_url: db insert failed: {}", e);
-        e.to_string()
+        log::error!("add_document_url: {}", e);
+        e
     })?;
 
-    let final_path = dir.join(format!("{}-{}.md", doc_id, sanitise_filename(&name)));
-    if let Err(e) = std::fs::rename(&temp_path, &final_path) {
-        let _ = std::fs::remove_file(&temp_path);
-        log::error!("add_document_url: rename failed: {}", e);
-        return Err(e.to_string());
-    }
-
-    conn.execute(
-        "UPDATE documents SET file_path = ?1 WHERE id = ?2",
-        rusqlite::params![final_path.to_str().unwrap_or(""), doc_id],
+    db_set_document_fetch_state(
+        &conn,
+        doc_id,
+        fetched.etag.as_deref(),
+        fetched.last_modified.as_deref(),
+        &refresh::content_hash(&markdown),
+        &chrono::Utc::now().to_rfc3339(),
     )
     .map_err(|e| e.to_string())?;
 
-    if scope == "skill" && !skill_ids.is_empty() {
-        db_set_document_skills(&conn, doc_id, &skill_ids).map_err(|e| e.to_string())?;
-    }
-
     log::info!(
-        "add_document_url: saved {} chars of markdown to {} (doc_id={})",
+        "add_document_url: saved {} chars of markdown (doc_id={})",
         markdown.len(),
-        final_path.display(),
         doc_id
     );
 
@@ -241,7 +326,7 @@ pub async fn add_document_url(
 }
 
 // ---------------------------------------------------------------------------
-// Add documents from folder (walk, ingest .md, .txt, .pdf text layer)
+// Add documents from folder (walk, extract PDF/DOCX/HTML/Markdown/text)
 // ---------------------------------------------------------------------------
 
 #[tauri::command]
@@ -277,12 +362,7 @@ pub fn add_document_folder(
 
     for entry in entries {
         let path = entry.path();
-        let ext = path
-            .extension()
-            .and_then(|e| e.to_str())
-            .unwrap_or("")
-            .to_lowercase();
-        if !["md", "txt", "pdf"].contains(&ext.as_str()) {
+        if !extract::is_supported(path) {
             continue;
         }
 
@@ -292,64 +372,24 @@ pub fn add_document_folder(
             .unwrap_or("document");
         let doc_name = format!("{}/{}", name, file_name);
 
-        let content = match std::fs::read_to_string(path) {
+        let content = match extract::extract_markdown(path) {
             Ok(s) => s,
             Err(e) => {
-                log::warn!(
-                    "add_document_folder: skipping unreadable file {}: {}",
-                    path.display(),
-                    e
-                );
+                log::warn!("add_document_folder: skipping {}", e);
                 continue;
             }
         };
 
-        let temp_path = dir.join(format!("tmp-{}.md", uuid::Uuid::new_v4()));
-        if let Err(e) = std::fs::write(&temp_path, &content) {
-            log::warn!(
-                "add_document_folder: failed to write {}: {}",
-                temp_path.display(),
-                e
-            );
-            continue;
-        }
-
-        let doc_id = match db_insert_document(
-            &conn,
-            &doc_name,
-            "folder",
-            None,
-            temp_path.to_str().unwrap_or(""),
-            &scope,
+        let doc_id = match store_document(
+            &conn, &dir, &doc_name, "folder", None, &content, &scope, &skill_ids,
         ) {
             Ok(id) => id,
             Err(e) => {
-                let _ = std::fs::remove_file(&temp_path);
-                log::error!(
-                    "add_document_folder: db insert failed for {}: {}",
-                    doc_name,
-                    e
-                );
+                log::error!("add_document_folder: {}", e);
                 continue;
             }
         };
 
-        let final_path = dir.join(format!("{}-{}.md", doc_id, sanitise_filename(&doc_name)));
-        if let Err(e) = std::fs::rename(&temp_path, &final_path) {
-            let _ = std::fs::remove_file(&temp_path);
-            log::error!("add_document_folder: rename failed: {}", e);
-            continue;
-        }
-
-        let _ = conn.execute(
-            "UPDATE documents SET file_path = ?1 WHERE id = ?2",
-            rusqlite::params![final_path.to_str().unwrap_or(""), doc_id],
-        );
-
-        if scope == "skill" && !skill_ids.is_empty() {
-            let _ = db_set_document_skills(&conn, doc_id, &skill_ids);
-        }
-
         if let Ok(Some(rec)) = db_get_document(&conn, doc_id) {
             records.push(rec);
         }
@@ -394,7 +434,8 @@ pub fn delete_document(db: tauri::State<'_, Db>, id: i64) -> Result<(), String>
     log::info!("delete_document: id={}", id);
     let conn = db.0.lock().map_err(|e| e.to_string())?;
 
-    // Remove the file from disk before deleting the DB record
+    // Remove the file from disk before deleting the DB record; archived
+    // versions are removed with their rows.
     if let Ok(Some(doc)) = db_get_document(&conn, id) {
         if !doc.file_path.is_empty() {
             if let Err(e) = std::fs::remove_file(&doc.file_path) {
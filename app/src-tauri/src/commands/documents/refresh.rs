@@ -0,0 +1,450 @@
+use std::path::Path;
+
+use rusqlite::Connection;
+use sha2::Digest;
+
+use super::{documents_dir, extract, sanitise_filename};
+use crate::db::{
+    db_get_document, db_index_document_chunks, db_list_document_versions, db_list_documents,
+    db_record_document_change, db_set_document_fetch_state, db_skills_with_changed_documents, Db,
+    DocumentRecord, DocumentVersionRecord, StaleDocumentSkill,
+};
+use crate::DataDir;
+
+/// Markdown converted from a fetched URL plus the HTTP validators to send on
+/// the next conditional request.
+pub(super) struct FetchedDocument {
+    pub markdown: String,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+}
+
+/// Result of a conditional fetch.
+pub(super) enum FetchOutcome {
+    /// The server answered 304 Not Modified.
+    NotModified,
+    Fetched(FetchedDocument),
+}
+
+/// Outcome of refreshing a single URL document.
+#[derive(Debug, serde::Serialize)]
+pub struct DocumentRefreshResult {
+    pub document: DocumentRecord,
+    pub changed: bool,
+    /// Generated skills that use this document and predate its latest change.
+    pub stale_skills: Vec<StaleDocumentSkill>,
+}
+
+/// A URL document that could not be refreshed during a bulk refresh.
+#[derive(Debug, serde::Serialize)]
+pub struct DocumentRefreshFailure {
+    pub document_id: i64,
+    pub name: String,
+    pub error: String,
+}
+
+/// Outcome of refreshing every URL document.
+#[derive(Debug, serde::Serialize)]
+pub struct RefreshAllDocumentsResult {
+    pub refreshed: Vec<DocumentRefreshResult>,
+    pub failed: Vec<DocumentRefreshFailure>,
+    /// All generated skills with documents changed since their last generation.
+    pub stale_skills: Vec<StaleDocumentSkill>,
+}
+
+pub(super) fn content_hash(markdown: &str) -> String {
+    hex::encode(sha2::Sha256::digest(markdown.as_bytes()))
+}
+
+fn header_value(response: &reqwest::Response, name: reqwest::header::HeaderName) -> Option<String> {
+    response
+        .headers()
+        .get(name)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string)
+}
+
+/// Fetch `url` and convert the body to Markdown based on the served content
+/// type. `etag` / `last_modified` from a previous fetch are sent as
+/// `If-None-Match` / `If-Modified-Since`.
+pub(super) async fn fetch_url_markdown(
+    url: &str,
+    etag: Option<&str>,
+    last_modified: Option<&str>,
+) -> Result<FetchOutcome, String> {
+    let client = reqwest::Client::builder()
+        .timeout(std::time::Duration::from_secs(60))
+        .connect_timeout(std::time::Duration::from_secs(10))
+        .build()
+        .unwrap_or_else(|_| reqwest::Client::new());
+    let mut request = client.get(url);
+    if let Some(etag) = etag {
+        request = request.header(reqwest::header::IF_NONE_MATCH, etag);
+    }
+    if let Some(last_modified) = last_modified {
+        request = request.header(reqwest::header::IF_MODIFIED_SINCE, last_modified);
+    }
+
+    let response = request
+        .send()
+        .await
+        .map_err(|e| format!("Failed to fetch URL: {}", e))?;
+    let status = response.status();
+    if status == reqwest::StatusCode::NOT_MODIFIED {
+        return Ok(FetchOutcome::NotModified);
+    }
+    if !status.is_success() {
+        return Err(format!("Failed to fetch URL: HTTP {}", status));
+    }
+
+    let content_type = header_value(&response, reqwest::header::CONTENT_TYPE).unwrap_or_default();
+    let new_etag = header_value(&response, reqwest::header::ETAG);
+    let new_last_modified = header_value(&response, reqwest::header::LAST_MODIFIED);
+
+    let body = response
+        .bytes()
+        .await
+        .map_err(|e| format!("Failed to read response body: {}", e))?;
+
+    let markdown = extract::response_to_markdown(&content_type, &body)?;
+
+    Ok(FetchOutcome::Fetched(FetchedDocument {
+        markdown,
+        etag: new_etag,
+        last_modified: new_last_modified,
+    }))
+}
+
+/// Apply a fetch result to a stored document. Returns `true` when the content
+/// changed, in which case the previous file is archived as a version and the
+/// chunk index is rebuilt.
+fn apply_fetch(
+    conn: &Connection,
+    dir: &Path,
+    doc: &DocumentRecord,
+    outcome: FetchOutcome,
+) -> Result<bool, String> {
+    let now = chrono::Utc::now().to_rfc3339();
+    let fetched = match outcome {
+        FetchOutcome::NotModified => {
+            let hash = doc.content_hash.clone().unwrap_or_else(|| {
+                content_hash(&std::fs::read_to_string(&doc.file_path).unwrap_or_default())
+            });
+            db_set_document_fetch_state(
+                conn,
+                doc.id,
+                doc.etag.as_deref(),
+                doc.last_modified.as_deref(),
+                &hash,
+                &now,
+            )
+            .map_err(|e| e.to_string())?;
+            return Ok(false);
+        }
+        FetchOutcome::Fetched(fetched) => fetched,
+    };
+
+    let new_hash = content_hash(&fetched.markdown);
+    let previous_content = std::fs::read_to_string(&doc.file_path).ok();
+    let old_hash = doc
+        .content_hash
+        .clone()
+        .or_else(|| previous_content.as_deref().map(content_hash));
+
+    if old_hash.as_deref() == Some(new_hash.as_str()) {
+        db_set_document_fetch_state(
+            conn,
+            doc.id,
+            fetched.etag.as_deref(),
+            fetched.last_modified.as_deref(),
+            &new_hash,
+            &now,
+        )
+        .map_err(|e| e.to_string())?;
+        return Ok(false);
+    }
+
+    let version = db_list_document_versions(conn, doc.id)
+        .map_err(|e| e.to_string())?
+        .len()
+        + 1;
+    let archived_path = dir.join(format!(
+        "{}-{}.v{}.md",
+        doc.id,
+        sanitise_filename(&doc.name),
+        version
+    ));
+    std::fs::write(&archived_path, previous_content.unwrap_or_default())
+        .map_err(|e| format!("Failed to write {}: {}", archived_path.display(), e))?;
+    std::fs::write(&doc.file_path, &fetched.markdown)
+        .map_err(|e| format!("Failed to write {}: {}", doc.file_path, e))?;
+
+    let previous = DocumentRecord {
+        content_hash: old_hash,
+        ..doc.clone()
+    };
+    db_record_document_change(
+        conn,
+        &previous,
+        archived_path.to_str().unwrap_or(""),
+        fetched.etag.as_deref(),
+        fetched.last_modified.as_deref(),
+        &new_hash,
+        &now,
+    )
+    .map_err(|e| e.to_string())?;
+    db_index_document_chunks(conn, doc.id, &fetched.markdown)
+        .map_err(|e| format!("Failed to index document '{}': {}", doc.name, e))?;
+    Ok(true)
+}
+
+/// Re-fetch one URL document and apply the result.
+async fn refresh_one(
+    db: &Db,
+    dir: &Path,
+    doc: DocumentRecord,
+) -> Result<DocumentRefreshResult, String> {
+    let url = doc
+        .source_url
+        .clone()
+        .filter(|_| doc.source_type == "url")
+        .ok_or_else(|| format!("Document '{}' was not added from a URL", doc.name))?;
+
+    let outcome =
+        fetch_url_markdown(&url, doc.etag.as_deref(), doc.last_modified.as_deref()).await?;
+
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    let changed = apply_fetch(&conn, dir, &doc, outcome)?;
+    let stale_skills =
+        db_skills_with_changed_documents(&conn, Some(&[doc.id])).map_err(|e| e.to_string())?;
+    let document = db_get_document(&conn, doc.id)
+        .map_err(|e| e.to_string())?
+        .ok_or_else(|| "Document not found after refresh".to_string())?;
+    Ok(DocumentRefreshResult {
+        document,
+        changed,
+        stale_skills,
+    })
+}
+
+// ---------------------------------------------------------------------------
+// Commands
+// ---------------------------------------------------------------------------
+
+#[tauri::command]
+pub async fn refresh_document(
+    db: tauri::State<'_, Db>,
+    data_dir: tauri::State<'_, DataDir>,
+    id: i64,
+) -> Result<DocumentRefreshResult, String> {
+    log::info!("refresh_document: id={}", id);
+    let dir = documents_dir(&data_dir.0).map_err(|e| e.to_string())?;
+    let doc = {
+        let conn = db.0.lock().map_err(|e| e.to_string())?;
+        db_get_document(&conn, id)
+            .map_err(|e| e.to_string())?
+            .ok_or_else(|| "Document not found".to_string())?
+    };
+
+    let result = refresh_one(&db, &dir, doc).await.map_err(|e| {
+        log::error!("refresh_document: id={}: {}", id, e);
+        e
+    })?;
+    log::info!(
+        "refresh_document: id={} changed={} stale_skills={}",
+        id,
+        result.changed,
+        result.stale_skills.len()
+    );
+    Ok(result)
+}
+
+#[tauri::command]
+pub async fn refresh_url_documents(
+    db: tauri::State<'_, Db>,
+    data_dir: tauri::State<'_, DataDir>,
+) -> Result<RefreshAllDocumentsResult, String> {
+    log::info!("refresh_url_documents");
+    let dir = documents_dir(&data_dir.0).map_err(|e| e.to_string())?;
+    let docs: Vec<DocumentRecord> = {
+        let conn = db.0.lock().map_err(|e| e.to_string())?;
+        db_list_documents(&conn)
+            .map_err(|e| e.to_string())?
+            .into_iter()
+            .filter(|d| d.source_type == "url" && d.source_url.is_some())
+            .collect()
+    };
+
+    let mut refreshed = Vec::new();
+    let mut failed = Vec::new();
+    for doc in docs {
+        let (document_id, name) = (doc.id, doc.name.clone());
+        match refresh_one(&db, &dir, doc).await {
+            Ok(result) => refreshed.push(result),
+            Err(error) => {
+                log::warn!("refresh_url_documents: '{}' failed: {}", name, error);
+                failed.push(DocumentRefreshFailure {
+                    document_id,
+                    name,
+                    error,
+                });
+            }
+        }
+    }
+
+    let stale_skills = {
+        let conn = db.0.lock().map_err(|e| e.to_string())?;
+        db_skills_with_changed_documents(&conn, None).map_err(|e| e.to_string())?
+    };
+    log::info!(
+        "refresh_url_documents: refreshed={} changed={} failed={} stale_skills={}",
+        refreshed.len(),
+        refreshed.iter().filter(|r| r.changed).count(),
+        failed.len(),
+        stale_skills.len()
+    );
+    Ok(RefreshAllDocumentsResult {
+        refreshed,
+        failed,
+        stale_skills,
+    })
+}
+
+#[tauri::command]
+pub fn list_document_versions(
+    db: tauri::State<'_, Db>,
+    id: i64,
+) -> Result<Vec<DocumentVersionRecord>, String> {
+    log::info!("list_document_versions: id={}", id);
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    db_list_document_versions(&conn, id).map_err(|e| e.to_string())
+}
+
+#[tauri::command]
+pub fn list_skills_with_changed_documents(
+    db: tauri::State<'_, Db>,
+) -> Result<Vec<StaleDocumentSkill>, String> {
+    log::info!("list_skills_with_changed_documents");
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    db_skills_with_changed_documents(&conn, None).map_err(|e| e.to_string())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::{create_test_db_for_tests, db_insert_document};
+
+    fn url_document(conn: &Connection, dir: &Path, content: &str) -> DocumentRecord {
+        let path = dir.join("1-guide.md");
+        std::fs::write(&path, content).unwrap();
+        let id = db_insert_document(
+            conn,
+            "Guide",
+            "url",
+            Some("https://example.com/guide"),
+            path.to_str().unwrap(),
+            "all",
+        )
+        .unwrap();
+        db_set_document_fetch_state(
+            conn,
+            id,
+            Some("\"v1\""),
+            None,
+            &content_hash(content),
+            "2026-01-01T00:00:00+00:00",
+        )
+        .unwrap();
+        db_get_document(conn, id).unwrap().unwrap()
+    }
+
+    fn fetched(markdown: &str, etag: &str) -> FetchOutcome {
+        FetchOutcome::Fetched(FetchedDocument {
+            markdown: markdown.to_string(),
+            etag: Some(etag.to_string()),
+            last_modified: None,
+        })
+    }
+
+    #[test]
+    fn test_apply_fetch_unchanged_content_updates_validators_only() {
+        let conn = create_test_db_for_tests();
+        let dir = tempfile::tempdir().unwrap();
+        let doc = url_document(&conn, dir.path(), "# Guide\n\nSame body.");
+
+        let changed = apply_fetch(
+            &conn,
+            dir.path(),
+            &doc,
+            fetched("# Guide\n\nSame body.", "\"v2\""),
+        )
+        .unwrap();
+
+        assert!(!changed);
+        let after = db_get_document(&conn, doc.id).unwrap().unwrap();
+        assert_eq!(after.etag.as_deref(), Some("\"v2\""));
+        assert!(after.content_changed_at.is_none());
+        assert!(db_list_document_versions(&conn, doc.id).unwrap().is_empty());
+    }
+
+    #[test]
+    fn test_apply_fetch_not_modified_keeps_content() {
+        let conn = create_test_db_for_tests();
+        let dir = tempfile::tempdir().unwrap();
+        let doc = url_document(&conn, dir.path(), "Original");
+
+        let changed = apply_fetch(&conn, dir.path(), &doc, FetchOutcome::NotModified).unwrap();
+
+        assert!(!changed);
+        let after = db_get_document(&conn, doc.id).unwrap().unwrap();
+        assert_eq!(after.etag.as_deref(), Some("\"v1\""));
+        assert_ne!(after.fetched_at, doc.fetched_at);
+        assert_eq!(std::fs::read_to_string(&doc.file_path).unwrap(), "Original");
+    }
+
+    #[test]
+    fn test_apply_fetch_changed_content_archives_previous_version() {
+        let conn = create_test_db_for_tests();
+        let dir = tempfile::tempdir().unwrap();
+        let doc = url_document(&conn, dir.path(), "# Guide\n\nOld rules.");
+
+        let changed = apply_fetch(
+            &conn,
+            dir.path(),
+            &doc,
+            fetched("# Guide\n\nNew rules.", "\"v2\""),
+        )
+        .unwrap();
+
+        assert!(changed);
+        assert_eq!(
+            std::fs::read_to_string(&doc.file_path).unwrap(),
+            "# Guide\n\nNew rules."
+        );
+        let after = db_get_document(&conn, doc.id).unwrap().unwrap();
+        assert_eq!(
+            after.content_hash.as_deref(),
+            Some(content_hash("# Guide\n\nNew rules.").as_str())
+        );
+        assert_eq!(after.content_changed_at, after.fetched_at);
+
+        let versions = db_list_document_versions(&conn, doc.id).unwrap();
+        assert_eq!(versions.len(), 1);
+        assert_eq!(versions[0].etag.as_deref(), Some("\"v1\""));
+        assert_eq!(versions[0].content_hash, doc.content_hash);
+        assert_eq!(
+            std::fs::read_to_string(&versions[0].file_path).unwrap(),
+            "# Guide\n\nOld rules."
+        );
+
+        let chunks: String = conn
+            .query_row(
+                "SELECT group_concat(content, ' ') FROM document_chunks WHERE document_id = ?1",
+                [doc.id],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert!(chunks.contains("New rules."));
+        assert!(!chunks.contains("Old rules."));
+    }
+}
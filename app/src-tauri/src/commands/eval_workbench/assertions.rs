@@ -0,0 +1,559 @@
+use std::path::{Component, Path};
+
+use serde::{Deserialize, Deserializer, Serialize, Serializer};
+
+/// One check on a scenario run.
+///
+/// Every kind except `LlmRubric` is decided in Rust from the run's final
+/// output, output files, and usage. A rubric is free text that only an LLM
+/// grader can judge; it serializes as a bare string so scenario files and
+/// assertion rows written before typed assertions existed still load.
+#[derive(Debug, Clone, PartialEq)]
+pub enum Assertion {
+    LlmRubric(String),
+    Typed(TypedAssertion),
+}
+
+/// Deterministic assertion kinds, tagged by `type` in kebab-case.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(tag = "type", rename_all = "kebab-case")]
+pub enum TypedAssertion {
+    /// Explicit spelling of a rubric; normalized to [`Assertion::LlmRubric`].
+    LlmRubric {
+        value: String,
+    },
+    Contains {
+        value: String,
+    },
+    NotContains {
+        value: String,
+    },
+    Regex {
+        value: String,
+    },
+    /// The output parses as JSON and matches this JSON Schema.
+    JsonSchema {
+        value: serde_json::Value,
+    },
+    /// `path` is relative to the run's output directory.
+    FileExists {
+        path: String,
+    },
+    FileContains {
+        path: String,
+        value: String,
+    },
+    MaxTurns {
+        value: u32,
+    },
+    /// Upper bound on the run's cost in USD.
+    MaxCost {
+        value: f64,
+    },
+}
+
+impl Serialize for Assertion {
+    fn serialize<S: Serializer>(&self, serializer: S) -> Result<S::Ok, S::Error> {
+        match self {
+            Self::LlmRubric(text) => serializer.serialize_str(text),
+            Self::Typed(typed) => typed.serialize(serializer),
+        }
+    }
+}
+
+impl<'de> Deserialize<'de> for Assertion {
+    fn deserialize<D: Deserializer<'de>>(deserializer: D) -> Result<Self, D::Error> {
+        #[derive(Deserialize)]
+        #[serde(untagged)]
+        enum Repr {
+            Text(String),
+            Typed(TypedAssertion),
+        }
+        Ok(match Repr::deserialize(deserializer)? {
+            Repr::Text(text) => Self::LlmRubric(text),
+            Repr::Typed(typed) => Self::from(typed),
+        })
+    }
+}
+
+impl From<TypedAssertion> for Assertion {
+    fn from(typed: TypedAssertion) -> Self {
+        match typed {
+            TypedAssertion::LlmRubric { value } => Self::LlmRubric(value),
+            other => Self::Typed(other),
+        }
+    }
+}
+
+impl From<String> for Assertion {
+    fn from(text: String) -> Self {
+        Self::LlmRubric(text)
+    }
+}
+
+impl From<&str> for Assertion {
+    fn from(text: &str) -> Self {
+        Self::LlmRubric(text.to_string())
+    }
+}
+
+impl Assertion {
+    pub fn kind(&self) -> &'static str {
+        match self {
+            Self::LlmRubric(_) => "llm-rubric",
+            Self::Typed(typed) => match typed {
+                TypedAssertion::LlmRubric { .. } => "llm-rubric",
+                TypedAssertion::Contains { .. } => "contains",
+                TypedAssertion::NotContains { .. } => "not-contains",
+                TypedAssertion::Regex { .. } => "regex",
+                TypedAssertion::JsonSchema { .. } => "json-schema",
+                TypedAssertion::FileExists { .. } => "file-exists",
+                TypedAssertion::FileContains { .. } => "file-contains",
+                TypedAssertion::MaxTurns { .. } => "max-turns",
+                TypedAssertion::MaxCost { .. } => "max-cost",
+            },
+        }
+    }
+
+    /// Whether an LLM grader is needed to decide this assertion.
+    pub fn is_llm_graded(&self) -> bool {
+        self.kind() == "llm-rubric"
+    }
+
+    /// Text for a single-column store: rubrics as-is, typed kinds as JSON.
+    pub fn to_storage_text(&self) -> String {
+        match self {
+            Self::LlmRubric(text) => text.clone(),
+            Self::Typed(typed) => serde_json::to_string(typed).unwrap_or_default(),
+        }
+    }
+
+    /// Inverse of [`Assertion::to_storage_text`]. Text that is not a typed
+    /// assertion object is a rubric.
+    pub fn from_storage_text(text: &str) -> Self {
+        if text.trim_start().starts_with('{') {
+            if let Ok(typed) = serde_json::from_str::<TypedAssertion>(text) {
+                return Self::from(typed);
+            }
+        }
+        Self::LlmRubric(text.to_string())
+    }
+}
+
+fn validate_output_path(path: &str) -> Result<(), String> {
+    let relative = Path::new(path);
+    if path.trim().is_empty() {
+        return Err("File assertions need a path".to_string());
+    }
+    if relative.is_absolute()
+        || relative
+            .components()
+            .any(|c| !matches!(c, Component::Normal(_) | Component::CurDir))
+    {
+        return Err(format!(
+            "File assertion path '{}' must stay inside the output directory",
+            path
+        ));
+    }
+    Ok(())
+}
+
+/// Rejects assertions that could never be evaluated.
+pub fn validate_assertion(assertion: &Assertion) -> Result<(), String> {
+    let Assertion::Typed(typed) = assertion else {
+        return Ok(());
+    };
+    match typed {
+        TypedAssertion::LlmRubric { .. } | TypedAssertion::MaxTurns { .. } => Ok(()),
+        TypedAssertion::Contains { value } | TypedAssertion::NotContains { value } => {
+            if value.is_empty() {
+                Err(format!("{} assertions need a value", assertion.kind()))
+            } else {
+                Ok(())
+            }
+        }
+        TypedAssertion::Regex { value } => regex::Regex::new(value)
+            .map(|_| ())
+            .map_err(|e| format!("Invalid regex '{}': {}", value, e)),
+        TypedAssertion::JsonSchema { value } => jsonschema::validator_for(value)
+            .map(|_| ())
+            .map_err(|e| format!("Invalid JSON schema: {}", e)),
+        TypedAssertion::FileExists { path } | TypedAssertion::FileContains { path, .. } => {
+            validate_output_path(path)
+        }
+        TypedAssertion::MaxCost { value } => {
+            if value.is_finite() && *value >= 0.0 {
+                Ok(())
+            } else {
+                Err("max-cost must be a non-negative number".to_string())
+            }
+        }
+    }
+}
+
+/// What a scenario run produced, as far as assertions are concerned.
+#[derive(Debug, Clone, Default)]
+pub struct RunEvidence<'a> {
+    /// Final response text of the run.
+    pub output: &'a str,
+    /// Directory the run wrote its files to, if any.
+    pub output_dir: Option<&'a Path>,
+    pub num_turns: Option<u32>,
+    pub total_cost_usd: Option<f64>,
+}
+
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct AssertionOutcome {
+    pub assertion: Assertion,
+    /// `None` when Rust cannot decide: rubrics awaiting the LLM grader, or a
+    /// usage limit the run did not report.
+    pub passed: Option<bool>,
+    pub reason: String,
+}
+
+fn outcome(assertion: &Assertion, passed: Option<bool>, reason: String) -> AssertionOutcome {
+    AssertionOutcome {
+        assertion: assertion.clone(),
+        passed,
+        reason,
+    }
+}
+
+fn needs_llm_grading(assertion: &Assertion) -> AssertionOutcome {
+    outcome(assertion, None, "Needs LLM grading".to_string())
+}
+
+fn strip_code_fence(text: &str) -> &str {
+    let trimmed = text.trim();
+    let Some(rest) = trimmed.strip_prefix("```") else {
+        return trimmed;
+    };
+    let rest = rest.split_once('\n').map_or("", |(_, body)| body);
+    rest.trim_end().trim_end_matches("```").trim()
+}
+
+pub fn evaluate_assertion(assertion: &Assertion, run: &RunEvidence<'_>) -> AssertionOutcome {
+    let typed = match assertion {
+        Assertion::LlmRubric(_) => return needs_llm_grading(assertion),
+        Assertion::Typed(typed) => typed,
+    };
+    if let Err(error) = validate_assertion(assertion) {
+        return outcome(assertion, Some(false), error);
+    }
+    match typed {
+        TypedAssertion::LlmRubric { .. } => needs_llm_grading(assertion),
+        TypedAssertion::Contains { value } => {
+            let passed = run.output.contains(value.as_str());
+            let reason = if passed {
+                format!("Output contains '{}'", value)
+            } else {
+                format!("Output does not contain '{}'", value)
+            };
+            outcome(assertion, Some(passed), reason)
+        }
+        TypedAssertion::NotContains { value } => {
+            let passed = !run.output.contains(value.as_str());
+            let reason = if passed {
+                format!("Output does not contain '{}'", value)
+            } else {
+                format!("Output contains '{}'", value)
+            };
+            outcome(assertion, Some(passed), reason)
+        }
+        TypedAssertion::Regex { value } => {
+            let passed = regex::Regex::new(value).is_ok_and(|re| re.is_match(run.output));
+            let reason = if passed {
+                format!("Output matches /{}/", value)
+            } else {
+                format!("Output does not match /{}/", value)
+            };
+            outcome(assertion, Some(passed), reason)
+        }
+        TypedAssertion::JsonSchema { value } => {
+            let instance: serde_json::Value =
+                match serde_json::from_str(strip_code_fence(run.output)) {
+                    Ok(instance) => instance,
+                    Err(e) => {
+                        return outcome(
+                            assertion,
+                            Some(false),
+                            format!("Output is not JSON: {}", e),
+                        )
+                    }
+                };
+            let validator = match jsonschema::validator_for(value) {
+                Ok(validator) => validator,
+                Err(e) => {
+                    return outcome(
+                        assertion,
+                        Some(false),
+                        format!("Invalid JSON schema: {}", e),
+                    )
+                }
+            };
+            let errors: Vec<String> = validator
+                .iter_errors(&instance)
+                .map(|e| {
+                    let at = e.instance_path.to_string();
+                    if at.is_empty() {
+                        e.to_string()
+                    } else {
+                        format!("{}: {}", at, e)
+                    }
+                })
+                .collect();
+            if errors.is_empty() {
+                outcome(
+                    assertion,
+                    Some(true),
+                    "Output matches the schema".to_string(),
+                )
+            } else {
+                outcome(assertion, Some(false), errors.join("; "))
+            }
+        }
+        TypedAssertion::FileExists { path } => {
+            let Some(output_dir) = run.output_dir else {
+                return outcome(assertion, Some(false), "Run has no output directory".into());
+            };
+            let passed = output_dir.join(path).is_file();
+            let reason = if passed {
+                format!("{} exists", path)
+            } else {
+                format!("{} was not written", path)
+            };
+            outcome(assertion, Some(passed), reason)
+        }
+        TypedAssertion::FileContains { path, value } => {
+            let Some(output_dir) = run.output_dir else {
+                return outcome(assertion, Some(false), "Run has no output directory".into());
+            };
+            match std::fs::read_to_string(output_dir.join(path)) {
+                Ok(content) if content.contains(value.as_str()) => outcome(
+                    assertion,
+                    Some(true),
+                    format!("{} contains '{}'", path, value),
+                ),
+                Ok(_) => outcome(
+                    assertion,
+                    Some(false),
+                    format!("{} does not contain '{}'", path, value),
+                ),
+                Err(e) => outcome(
+                    assertion,
+                    Some(false),
+                    format!("Could not read {}: {}", path, e),
+                ),
+            }
+        }
+        TypedAssertion::MaxTurns { value } => match run.num_turns {
+            Some(turns) => outcome(
+                assertion,
+                Some(turns <= *value),
+                format!("Run took {} turn(s); limit is {}", turns, value),
+            ),
+            None => outcome(assertion, None, "Run did not report its turn count".into()),
+        },
+        TypedAssertion::MaxCost { value } => match run.total_cost_usd {
+            Some(cost) => outcome(
+                assertion,
+                Some(cost <= *value),
+                format!("Run cost ${:.4}; limit is ${:.4}", cost, value),
+            ),
+            None => outcome(assertion, None, "Run did not report its cost".into()),
+        },
+    }
+}
+
+pub fn evaluate_assertions(
+    assertions: &[Assertion],
+    run: &RunEvidence<'_>,
+) -> Vec<AssertionOutcome> {
+    assertions
+        .iter()
+        .map(|assertion| evaluate_assertion(assertion, run))
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn typed(value: serde_json::Value) -> Assertion {
+        serde_json::from_value(value).unwrap()
+    }
+
+    #[test]
+    fn plain_strings_stay_llm_rubrics_in_yaml_and_storage() {
+        let assertions: Vec<Assertion> = serde_yaml::from_str(
+            "- Explains the forecast.\n- type: contains\n  value: ARR\n- type: llm-rubric\n  value: Mentions risk.\n",
+        )
+        .unwrap();
+
+        assert_eq!(
+            assertions,
+            vec![
+                Assertion::LlmRubric("Explains the forecast.".into()),
+                Assertion::Typed(TypedAssertion::Contains {
+                    value: "ARR".into()
+                }),
+                Assertion::LlmRubric("Mentions risk.".into()),
+            ]
+        );
+        assert_eq!(
+            serde_json::to_value(&assertions).unwrap(),
+            serde_json::json!([
+                "Explains the forecast.",
+                { "type": "contains", "value": "ARR" },
+                "Mentions risk."
+            ])
+        );
+        for assertion in &assertions {
+            assert_eq!(
+                &Assertion::from_storage_text(&assertion.to_storage_text()),
+                assertion
+            );
+        }
+        assert_eq!(
+            Assertion::from_storage_text("{not json"),
+            Assertion::LlmRubric("{not json".into())
+        );
+    }
+
+    #[test]
+    fn evaluates_text_assertions_against_the_output() {
+        let run = RunEvidence {
+            output: "Q3 ARR grew 12% to $4.1M.",
+            ..Default::default()
+        };
+        let results = evaluate_assertions(
+            &[
+                typed(serde_json::json!({ "type": "contains", "value": "ARR" })),
+                typed(serde_json::json!({ "type": "not-contains", "value": "churn" })),
+                typed(serde_json::json!({ "type": "regex", "value": r"\d+%" })),
+                typed(serde_json::json!({ "type": "contains", "value": "NRR" })),
+                Assertion::from("Explains the growth driver."),
+            ],
+            &run,
+        );
+        let passed: Vec<Option<bool>> = results.iter().map(|r| r.passed).collect();
+        assert_eq!(
+            passed,
+            vec![Some(true), Some(true), Some(true), Some(false), None]
+        );
+        assert_eq!(results[3].reason, "Output does not contain 'NRR'");
+    }
+
+    #[test]
+    fn evaluates_json_schema_against_fenced_output() {
+        let schema = typed(serde_json::json!({
+            "type": "json-schema",
+            "value": {
+                "type": "object",
+                "required": ["total"],
+                "properties": { "total": { "type": "number" } }
+            }
+        }));
+        let ok = RunEvidence {
+            output: "```json\n{\"total\": 41}\n```",
+            ..Default::default()
+        };
+        let wrong_type = RunEvidence {
+            output: "{\"total\": \"41\"}",
+            ..Default::default()
+        };
+        let prose = RunEvidence {
+            output: "The total is 41.",
+            ..Default::default()
+        };
+
+        assert_eq!(evaluate_assertion(&schema, &ok).passed, Some(true));
+        let failed = evaluate_assertion(&schema, &wrong_type);
+        assert_eq!(failed.passed, Some(false));
+        assert!(failed.reason.starts_with("/total"));
+        let not_json = evaluate_assertion(&schema, &prose);
+        assert_eq!(not_json.passed, Some(false));
+        assert!(not_json.reason.starts_with("Output is not JSON"));
+    }
+
+    #[test]
+    fn evaluates_file_assertions_inside_the_output_dir() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::create_dir_all(tmp.path().join("out")).unwrap();
+        std::fs::write(tmp.path().join("out/report.md"), "# Revenue\nARR: 4.1M\n").unwrap();
+        let run = RunEvidence {
+            output: "",
+            output_dir: Some(tmp.path()),
+            ..Default::default()
+        };
+
+        let results = evaluate_assertions(
+            &[
+                typed(serde_json::json!({ "type": "file-exists", "path": "out/report.md" })),
+                typed(serde_json::json!({ "type": "file-exists", "path": "out/missing.md" })),
+                typed(serde_json::json!({
+                    "type": "file-contains", "path": "out/report.md", "value": "ARR"
+                })),
+                typed(serde_json::json!({ "type": "file-exists", "path": "../escape.md" })),
+            ],
+            &run,
+        );
+        let passed: Vec<Option<bool>> = results.iter().map(|r| r.passed).collect();
+        assert_eq!(
+            passed,
+            vec![Some(true), Some(false), Some(true), Some(false)]
+        );
+        assert!(results[3].reason.contains("inside the output directory"));
+    }
+
+    #[test]
+    fn evaluates_usage_limits_only_when_reported() {
+        let turns = typed(serde_json::json!({ "type": "max-turns", "value": 5 }));
+        let cost = typed(serde_json::json!({ "type": "max-cost", "value": 0.05 }));
+        let reported = RunEvidence {
+            num_turns: Some(7),
+            total_cost_usd: Some(0.02),
+            ..Default::default()
+        };
+
+        assert_eq!(evaluate_assertion(&turns, &reported).passed, Some(false));
+        assert_eq!(evaluate_assertion(&cost, &reported).passed, Some(true));
+        assert_eq!(
+            evaluate_assertion(&turns, &RunEvidence::default()).passed,
+            None
+        );
+    }
+
+    #[test]
+    fn rejects_assertions_that_cannot_be_evaluated() {
+        for (value, message) in [
+            (
+                serde_json::json!({ "type": "regex", "value": "(" }),
+                "Invalid regex",
+            ),
+            (
+                serde_json::json!({ "type": "json-schema", "value": { "type": 12 } }),
+                "Invalid JSON schema",
+            ),
+            (
+                serde_json::json!({ "type": "file-exists", "path": "/etc/passwd" }),
+                "inside the output directory",
+            ),
+            (
+                serde_json::json!({ "type": "max-cost", "value": -1.0 }),
+                "non-negative",
+            ),
+            (
+                serde_json::json!({ "type": "contains", "value": "" }),
+                "need a value",
+            ),
+        ] {
+            let err = validate_assertion(&typed(value)).unwrap_err();
+            assert!(err.contains(message), "{err}");
+        }
+        assert!(
+            serde_json::from_value::<Assertion>(serde_json::json!({ "type": "bogus" })).is_err()
+        );
+    }
+}
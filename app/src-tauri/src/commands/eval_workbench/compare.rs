@@ -0,0 +1,595 @@
+use std::path::Path;
+
+use tauri::Manager;
+
+use super::run::{run_scenario_turn, ScenarioRunContext};
+use super::scenarios::{self, Scenario, ScenarioTag};
+use super::types::{
+    BenchmarkComparisonDto, BenchmarkRunDto, BenchmarkScenarioComparisonDto, BenchmarkSideDto,
+};
+use super::validate_plugin_slug;
+use crate::commands::imported_skills::validate_skill_name;
+use crate::commands::skill_session::resolve_skills_path;
+use crate::commands::workflow::{ensure_workspace_prompts, read_initialized_runtime_context};
+use crate::db::scenario_runs::{self, NewScenarioRun};
+use crate::db::Db;
+
+/// Ref that runs scenarios with no skill installed.
+pub(crate) const NO_SKILL_REF: &str = "no-skill";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) enum ComparisonRef {
+    NoSkill,
+    /// A version, version tag, branch or commit SHA of the skill repo.
+    Git(String),
+}
+
+pub(crate) fn parse_comparison_ref(reference: &str) -> Result<ComparisonRef, String> {
+    let reference = reference.trim();
+    if reference.is_empty() {
+        return Err("Choose a version, tag or commit to compare".to_string());
+    }
+    if reference.eq_ignore_ascii_case(NO_SKILL_REF) {
+        return Ok(ComparisonRef::NoSkill);
+    }
+    Ok(ComparisonRef::Git(reference.to_string()))
+}
+
+/// A resolved side: its report header plus, for git refs, the extracted files.
+struct PreparedSide {
+    side: BenchmarkSideDto,
+    snapshot: Option<tempfile::TempDir>,
+}
+
+fn prepare_side(
+    skill_dir: &Path,
+    plugin_slug: &str,
+    skill_name: &str,
+    reference: &ComparisonRef,
+) -> Result<PreparedSide, String> {
+    match reference {
+        ComparisonRef::NoSkill => Ok(PreparedSide {
+            side: BenchmarkSideDto {
+                reference: NO_SKILL_REF.to_string(),
+                label: "No skill".to_string(),
+                commit: None,
+                version: None,
+            },
+            snapshot: None,
+        }),
+        ComparisonRef::Git(reference) => {
+            let snapshot_dir =
+                tempfile::tempdir().map_err(|e| format!("Failed to create snapshot dir: {}", e))?;
+            let snapshot = crate::git::extract_skill_at_ref(
+                skill_dir,
+                plugin_slug,
+                skill_name,
+                reference,
+                snapshot_dir.path(),
+            )?;
+            // The agent must not see the scenarios it is graded on.
+            let evals_dir = snapshot_dir.path().join("evals");
+            if evals_dir.is_dir() {
+                std::fs::remove_dir_all(&evals_dir)
+                    .map_err(|e| format!("Failed to drop evals from snapshot: {}", e))?;
+            }
+            let label = match &snapshot.version {
+                Some(version) => format!("v{version}"),
+                None => format!("{} ({})", reference, &snapshot.commit[..7]),
+            };
+            Ok(PreparedSide {
+                side: BenchmarkSideDto {
+                    reference: reference.clone(),
+                    label,
+                    commit: Some(snapshot.commit),
+                    version: snapshot.version,
+                },
+                snapshot: Some(snapshot_dir),
+            })
+        }
+    }
+}
+
+/// Loads the performance scenarios of a skill, skipping ones with an empty prompt.
+pub(crate) fn load_performance_scenarios(eval_dir: &Path) -> Result<Vec<Scenario>, String> {
+    let mut loaded = Vec::new();
+    for summary in scenarios::list_scenarios(eval_dir)? {
+        if !summary.tags.contains(&ScenarioTag::Performance) {
+            continue;
+        }
+        if let Some(scenario) = scenarios::load_scenario(eval_dir, &summary.name)? {
+            if !scenario.prompt.trim().is_empty() {
+                loaded.push(scenario);
+            }
+        }
+    }
+    Ok(loaded)
+}
+
+pub(crate) fn compare_runs(
+    scenario: &Scenario,
+    baseline: BenchmarkRunDto,
+    candidate: BenchmarkRunDto,
+) -> BenchmarkScenarioComparisonDto {
+    BenchmarkScenarioComparisonDto {
+        scenario_name: scenario.name.clone(),
+        prompt: scenario.prompt.clone(),
+        pass_rate_delta: candidate
+            .pass_rate
+            .zip(baseline.pass_rate)
+            .map(|(c, b)| c - b),
+        token_delta: candidate
+            .total_tokens
+            .zip(baseline.total_tokens)
+            .map(|(c, b)| c as i64 - b as i64),
+        cost_delta_usd: candidate
+            .total_cost_usd
+            .zip(baseline.total_cost_usd)
+            .map(|(c, b)| c - b),
+        duration_delta_ms: candidate.duration_ms - baseline.duration_ms,
+        baseline,
+        candidate,
+    }
+}
+
+/// Share of graded scenarios that passed; `None` when none were graded.
+pub(crate) fn overall_pass_rate<'a>(
+    runs: impl Iterator<Item = &'a BenchmarkRunDto>,
+) -> Option<f64> {
+    let verdicts: Vec<bool> = runs.filter_map(|run| run.passed).collect();
+    (!verdicts.is_empty())
+        .then(|| verdicts.iter().filter(|p| **p).count() as f64 / verdicts.len() as f64)
+}
+
+fn format_rate(value: Option<f64>) -> String {
+    value.map_or_else(|| "n/a".to_string(), |v| format!("{:.0}%", v * 100.0))
+}
+
+fn format_rate_delta(value: Option<f64>) -> String {
+    value.map_or_else(|| "n/a".to_string(), |v| format!("{:+.0} pts", v * 100.0))
+}
+
+fn format_tokens(value: Option<u64>) -> String {
+    value.map_or_else(|| "not reported".to_string(), |v| v.to_string())
+}
+
+fn format_cost(value: Option<f64>) -> String {
+    value.map_or_else(|| "not reported".to_string(), |v| format!("${v:.4}"))
+}
+
+fn format_side(side: &BenchmarkSideDto) -> String {
+    match &side.commit {
+        Some(commit) => format!("{} — `{}`", side.label, commit),
+        None => side.label.clone(),
+    }
+}
+
+fn markdown_cell(text: &str) -> String {
+    text.replace('|', "\\|").replace('\n', " ")
+}
+
+fn format_run_result(run: &BenchmarkRunDto) -> String {
+    if run.error.is_some() {
+        return "error".to_string();
+    }
+    let verdict = match run.passed {
+        Some(true) => "pass",
+        Some(false) => "fail",
+        None => "ungraded",
+    };
+    format!(
+        "{} ({}/{})",
+        verdict, run.passed_assertions, run.decided_assertions
+    )
+}
+
+pub(crate) fn render_comparison_markdown(report: &BenchmarkComparisonDto) -> String {
+    let mut out = format!(
+        "# Benchmark comparison: {}\n\n- Plugin: {}\n- Model: {}\n- Generated: {}\n- Baseline: {}\n- Candidate: {}\n\n",
+        report.skill_name,
+        report.plugin_slug,
+        report.model,
+        report.generated_at,
+        format_side(&report.baseline),
+        format_side(&report.candidate),
+    );
+    let overall_delta = report
+        .candidate_pass_rate
+        .zip(report.baseline_pass_rate)
+        .map(|(c, b)| c - b);
+    out.push_str(&format!(
+        "Scenario pass rate: {} → {} ({})\n\n",
+        format_rate(report.baseline_pass_rate),
+        format_rate(report.candidate_pass_rate),
+        format_rate_delta(overall_delta),
+    ));
+    out.push_str(
+        "| Scenario | Baseline | Candidate | Δ pass rate | Baseline tokens | Candidate tokens | Δ tokens | Baseline cost | Candidate cost | Δ cost | Δ duration |\n",
+    );
+    out.push_str("|---|---|---|---|---|---|---|---|---|---|---|\n");
+    for scenario in &report.scenarios {
+        out.push_str(&format!(
+            "| {} | {} | {} | {} | {} | {} | {} | {} | {} | {} | {:+.1}s |\n",
+            markdown_cell(&scenario.scenario_name),
+            format_run_result(&scenario.baseline),
+            format_run_result(&scenario.candidate),
+            format_rate_delta(scenario.pass_rate_delta),
+            format_tokens(scenario.baseline.total_tokens),
+            format_tokens(scenario.candidate.total_tokens),
+            scenario
+                .token_delta
+                .map_or_else(|| "n/a".to_string(), |v| format!("{v:+}")),
+            format_cost(scenario.baseline.total_cost_usd),
+            format_cost(scenario.candidate.total_cost_usd),
+            scenario
+                .cost_delta_usd
+                .map_or_else(|| "n/a".to_string(), |v| format!("{v:+.4}")),
+            scenario.duration_delta_ms as f64 / 1000.0,
+        ));
+    }
+    let errors: Vec<String> = report
+        .scenarios
+        .iter()
+        .flat_map(|scenario| {
+            [
+                ("Baseline", &scenario.baseline),
+                ("Candidate", &scenario.candidate),
+            ]
+            .into_iter()
+            .filter_map(|(side, run)| {
+                run.error.as_ref().map(|e| {
+                    format!(
+                        "- {} on {}: {}",
+                        scenario.scenario_name,
+                        side.to_lowercase(),
+                        e
+                    )
+                })
+            })
+        })
+        .collect();
+    if !errors.is_empty() {
+        out.push_str("\n## Errors\n\n");
+        out.push_str(&errors.join("\n"));
+        out.push('\n');
+    }
+    out
+}
+
+async fn run_scenario_on_side(
+    ctx: &ScenarioRunContext<'_>,
+    side: &PreparedSide,
+    scenario: &Scenario,
+) -> BenchmarkRunDto {
+    run_scenario_turn(ctx, side.snapshot.as_ref().map(|dir| dir.path()), scenario).await
+}
+
+/// Runs every performance scenario against two refs of the skill and reports
+/// the per-scenario deltas. Each ref is a version, tag, commit SHA or
+/// `no-skill`. Runs on versioned snapshots are added to the run history.
+#[tauri::command]
+pub async fn run_benchmark_comparison(
+    app: tauri::AppHandle,
+    plugin_slug: String,
+    skill_name: String,
+    baseline_ref: String,
+    candidate_ref: String,
+    db: tauri::State<'_, Db>,
+) -> Result<BenchmarkComparisonDto, String> {
+    log::info!(
+        "[run_benchmark_comparison] plugin={} skill={} baseline={} candidate={}",
+        plugin_slug,
+        skill_name,
+        baseline_ref,
+        candidate_ref
+    );
+    validate_plugin_slug(&plugin_slug)?;
+    validate_skill_name(&skill_name)?;
+    let baseline_ref = parse_comparison_ref(&baseline_ref)?;
+    let candidate_ref = parse_comparison_ref(&candidate_ref)?;
+    if baseline_ref == candidate_ref {
+        return Err("Pick two different refs to compare".to_string());
+    }
+    let skills_path = resolve_skills_path(&db)?;
+    let eval_dir =
+        crate::skill_paths::resolve_eval_dir(Path::new(&skills_path), &plugin_slug, &skill_name);
+    let cases = load_performance_scenarios(&eval_dir)?;
+    if cases.is_empty() {
+        return Err("Add at least one performance scenario with a prompt first".to_string());
+    }
+    let skill_dir =
+        crate::skill_paths::resolve_skill_dir(Path::new(&skills_path), &plugin_slug, &skill_name);
+    let baseline = prepare_side(&skill_dir, &plugin_slug, &skill_name, &baseline_ref)?;
+    let candidate = prepare_side(&skill_dir, &plugin_slug, &skill_name, &candidate_ref)?;
+
+    let runtime_ctx = read_initialized_runtime_context(&db)?;
+    ensure_workspace_prompts(&app, &runtime_ctx.skills_root).await?;
+    let app_data_root = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("failed to resolve app data dir: {e}"))?
+        .to_string_lossy()
+        .replace('\\', "/");
+    let ctx = ScenarioRunContext {
+        app: &app,
+        app_data_root: &app_data_root,
+        plugin_slug: &plugin_slug,
+        skill_name: &skill_name,
+        runtime_ctx: &runtime_ctx,
+    };
+
+    let mut compared = Vec::with_capacity(cases.len());
+    for scenario in &cases {
+        let baseline_run = run_scenario_on_side(&ctx, &baseline, scenario).await;
+        let candidate_run = run_scenario_on_side(&ctx, &candidate, scenario).await;
+        for (side, run) in [(&baseline, &baseline_run), (&candidate, &candidate_run)] {
+            if side.snapshot.is_none() || run.error.is_some() {
+                continue;
+            }
+            let mut conn = db.0.lock().map_err(|e| e.to_string())?;
+            if let Err(e) = scenario_runs::record_scenario_run(
+                &mut conn,
+                NewScenarioRun {
+                    plugin_slug: plugin_slug.clone(),
+                    skill_name: skill_name.clone(),
+                    scenario_name: scenario.name.clone(),
+                    skill_version: side.side.version.clone(),
+                    skill_commit: side.side.commit.clone(),
+                    working_copy: false,
+                    model: runtime_ctx.llm.model.clone(),
+                    total_cost_usd: run.total_cost_usd,
+                    duration_ms: Some(run.duration_ms),
+                    num_turns: run.num_turns,
+                    outcomes: run.outcomes.clone(),
+                },
+            ) {
+                log::warn!(
+                    "[run_benchmark_comparison] could not record run of '{}': {}",
+                    scenario.name,
+                    e
+                );
+            }
+        }
+        compared.push(compare_runs(scenario, baseline_run, candidate_run));
+    }
+
+    Ok(BenchmarkComparisonDto {
+        baseline_pass_rate: overall_pass_rate(compared.iter().map(|s| &s.baseline)),
+        candidate_pass_rate: overall_pass_rate(compared.iter().map(|s| &s.candidate)),
+        plugin_slug,
+        skill_name,
+        model: runtime_ctx.llm.model.clone(),
+        generated_at: chrono::Utc::now().to_rfc3339(),
+        baseline: baseline.side,
+        candidate: candidate.side,
+        scenarios: compared,
+    })
+}
+
+/// Writes a comparison report to `dest_path` as `markdown` or `json`.
+#[tauri::command]
+pub fn export_benchmark_comparison(
+    report: BenchmarkComparisonDto,
+    format: String,
+    dest_path: String,
+) -> Result<(), String> {
+    log::info!(
+        "[export_benchmark_comparison] skill={} format={} dest={}",
+        report.skill_name,
+        format,
+        dest_path
+    );
+    let content = match format.as_str() {
+        "markdown" => render_comparison_markdown(&report),
+        "json" => serde_json::to_string_pretty(&report)
+            .map_err(|e| format!("Failed to serialize comparison: {}", e))?,
+        other => return Err(format!("Unsupported export format: {other}")),
+    };
+    std::fs::write(&dest_path, content)
+        .map_err(|e| format!("Failed to write '{}': {}", dest_path, e))
+        .inspect_err(|e| log::error!("[export_benchmark_comparison] {}", e))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::eval_workbench::assertions::{self, Assertion};
+    use crate::commands::eval_workbench::run::{summarize_run, RunUsage};
+
+    fn performance_case(name: &str, expectations: Vec<Assertion>) -> Scenario {
+        Scenario {
+            id: name.to_lowercase(),
+            name: name.to_string(),
+            tags: vec![ScenarioTag::Performance],
+            prompt: "Forecast Q3 ARR".to_string(),
+            expectations,
+            should_trigger: None,
+        }
+    }
+
+    fn contains(value: &str) -> Assertion {
+        serde_json::from_value(serde_json::json!({ "type": "contains", "value": value })).unwrap()
+    }
+
+    fn run_with(
+        expectations: &[Assertion],
+        output: &str,
+        tokens: Option<u64>,
+        cost: Option<f64>,
+        duration_ms: i64,
+    ) -> BenchmarkRunDto {
+        let outcomes = assertions::evaluate_assertions(
+            expectations,
+            &assertions::RunEvidence {
+                output,
+                ..Default::default()
+            },
+        );
+        summarize_run(
+            outcomes,
+            RunUsage {
+                total_tokens: tokens,
+                total_cost_usd: cost,
+                num_turns: None,
+            },
+            duration_ms,
+            None,
+        )
+    }
+
+    #[test]
+    fn parses_no_skill_and_git_refs() {
+        assert_eq!(
+            parse_comparison_ref(" No-Skill ").unwrap(),
+            ComparisonRef::NoSkill
+        );
+        assert_eq!(
+            parse_comparison_ref("v1.2.0").unwrap(),
+            ComparisonRef::Git("v1.2.0".to_string())
+        );
+        assert!(parse_comparison_ref("  ").is_err());
+    }
+
+    #[test]
+    fn compares_pass_rates_tokens_and_cost_per_scenario() {
+        let expectations = vec![
+            contains("ARR"),
+            contains("assumptions"),
+            Assertion::from("Is clear"),
+        ];
+        let case = performance_case("Forecast", expectations.clone());
+        let baseline = run_with(&expectations, "ARR will grow", Some(1200), Some(0.02), 4000);
+        let candidate = run_with(
+            &expectations,
+            "ARR grows; assumptions: flat churn",
+            None,
+            Some(0.05),
+            2500,
+        );
+
+        assert_eq!(
+            (baseline.passed_assertions, baseline.decided_assertions),
+            (1, 2)
+        );
+        assert_eq!(baseline.passed, Some(false));
+        assert_eq!(candidate.passed, None);
+
+        let compared = compare_runs(&case, baseline, candidate);
+        assert_eq!(compared.pass_rate_delta, Some(0.5));
+        assert_eq!(compared.token_delta, None);
+        assert!((compared.cost_delta_usd.unwrap() - 0.03).abs() < 1e-9);
+        assert_eq!(compared.duration_delta_ms, -1500);
+    }
+
+    #[test]
+    fn failed_runs_count_as_failures() {
+        let run = summarize_run(
+            Vec::new(),
+            RunUsage::default(),
+            10,
+            Some("timed out".to_string()),
+        );
+        assert_eq!(run.passed, Some(false));
+        assert_eq!(run.pass_rate, None);
+
+        let passing = run_with(&[contains("ARR")], "ARR", None, None, 10);
+        let ungraded = run_with(&[Assertion::from("Is clear")], "ARR", None, None, 10);
+        assert_eq!(
+            overall_pass_rate([&run, &passing, &ungraded].into_iter()),
+            Some(0.5)
+        );
+        assert_eq!(overall_pass_rate([&ungraded].into_iter()), None);
+    }
+
+    #[test]
+    fn renders_markdown_report() {
+        let expectations = vec![contains("ARR")];
+        let case = performance_case("Forecast | Q3", expectations.clone());
+        let baseline = summarize_run(
+            Vec::new(),
+            RunUsage::default(),
+            1000,
+            Some("timed out".to_string()),
+        );
+        let candidate = run_with(&expectations, "ARR", Some(800), Some(0.01), 3000);
+        let scenarios = vec![compare_runs(&case, baseline, candidate)];
+        let report = BenchmarkComparisonDto {
+            plugin_slug: "analytics".to_string(),
+            skill_name: "revenue".to_string(),
+            model: "test-model".to_string(),
+            generated_at: "2026-01-01T00:00:00Z".to_string(),
+            baseline: BenchmarkSideDto {
+                reference: NO_SKILL_REF.to_string(),
+                label: "No skill".to_string(),
+                commit: None,
+                version: None,
+            },
+            candidate: BenchmarkSideDto {
+                reference: "1.1.0".to_string(),
+                label: "v1.1.0".to_string(),
+                commit: Some("abc1234".to_string()),
+                version: Some("1.1.0".to_string()),
+            },
+            baseline_pass_rate: overall_pass_rate(scenarios.iter().map(|s| &s.baseline)),
+            candidate_pass_rate: overall_pass_rate(scenarios.iter().map(|s| &s.candidate)),
+            scenarios,
+        };
+
+        let markdown = render_comparison_markdown(&report);
+        assert!(markdown.contains("- Candidate: v1.1.0 — `abc1234`"));
+        assert!(markdown.contains("Scenario pass rate: 0% → 100% (+100 pts)"));
+        assert!(markdown.contains(
+            "| Forecast \\| Q3 | error | pass (1/1) | n/a | not reported | 800 | n/a | not reported | $0.0100 | n/a | +2.0s |"
+        ));
+        assert!(markdown.contains("## Errors\n\n- Forecast | Q3 on baseline: timed out"));
+    }
+
+    #[test]
+    fn export_writes_markdown_or_json() {
+        let tmp = tempfile::tempdir().unwrap();
+        let report = BenchmarkComparisonDto {
+            plugin_slug: "analytics".to_string(),
+            skill_name: "revenue".to_string(),
+            model: "test-model".to_string(),
+            generated_at: "2026-01-01T00:00:00Z".to_string(),
+            baseline: BenchmarkSideDto {
+                reference: NO_SKILL_REF.to_string(),
+                label: "No skill".to_string(),
+                commit: None,
+                version: None,
+            },
+            candidate: BenchmarkSideDto {
+                reference: "HEAD".to_string(),
+                label: "HEAD (abc1234)".to_string(),
+                commit: Some("abc1234".to_string()),
+                version: None,
+            },
+            scenarios: vec![],
+            baseline_pass_rate: None,
+            candidate_pass_rate: None,
+        };
+        let json_path = tmp.path().join("report.json");
+        export_benchmark_comparison(
+            report.clone(),
+            "json".to_string(),
+            json_path.to_string_lossy().to_string(),
+        )
+        .unwrap();
+        let parsed: BenchmarkComparisonDto =
+            serde_json::from_str(&std::fs::read_to_string(&json_path).unwrap()).unwrap();
+        assert_eq!(parsed, report);
+
+        let md_path = tmp.path().join("report.md");
+        export_benchmark_comparison(
+            report.clone(),
+            "markdown".to_string(),
+            md_path.to_string_lossy().to_string(),
+        )
+        .unwrap();
+        assert!(std::fs::read_to_string(&md_path)
+            .unwrap()
+            .starts_with("# Benchmark comparison: revenue"));
+
+        assert!(export_benchmark_comparison(report, "csv".to_string(), String::new()).is_err());
+    }
+}
@@ -0,0 +1,124 @@
+use super::assertions::AssertionOutcome;
+use super::types::RubricVerdictDto;
+use super::validate_plugin_slug;
+use crate::commands::imported_skills::validate_skill_name;
+use crate::db::scenario_runs::{self, ScenarioRun, VersionPassRate, VersionRegression};
+use crate::db::Db;
+
+const DEFAULT_RUN_HISTORY_LIMIT: u32 = 50;
+
+/// Fills in the LLM grader's verdicts on rubric outcomes, which Rust leaves
+/// undecided.
+pub(crate) fn apply_rubric_verdicts(
+    outcomes: &mut [AssertionOutcome],
+    verdicts: &[RubricVerdictDto],
+) -> Result<(), String> {
+    for verdict in verdicts {
+        let outcome = outcomes
+            .get_mut(verdict.index)
+            .ok_or_else(|| format!("No assertion at index {}", verdict.index))?;
+        if !outcome.assertion.is_llm_graded() {
+            return Err(format!(
+                "Assertion {} is a {} check, not an LLM rubric",
+                verdict.index,
+                outcome.assertion.kind()
+            ));
+        }
+        outcome.passed = Some(verdict.passed);
+        outcome.reason = verdict.reason.clone();
+    }
+    Ok(())
+}
+
+#[tauri::command]
+pub fn list_scenario_runs(
+    plugin_slug: String,
+    skill_name: String,
+    scenario_name: Option<String>,
+    limit: Option<u32>,
+    db: tauri::State<'_, Db>,
+) -> Result<Vec<ScenarioRun>, String> {
+    validate_plugin_slug(&plugin_slug)?;
+    validate_skill_name(&skill_name)?;
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    scenario_runs::list_scenario_runs(
+        &conn,
+        &plugin_slug,
+        &skill_name,
+        scenario_name.as_deref(),
+        limit.unwrap_or(DEFAULT_RUN_HISTORY_LIMIT),
+    )
+}
+
+/// Pass rate per model and released version, oldest first. `model` keeps
+/// only that model's runs.
+#[tauri::command]
+pub fn get_scenario_pass_rates(
+    plugin_slug: String,
+    skill_name: String,
+    model: Option<String>,
+    db: tauri::State<'_, Db>,
+) -> Result<Vec<VersionPassRate>, String> {
+    validate_plugin_slug(&plugin_slug)?;
+    validate_skill_name(&skill_name)?;
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    scenario_runs::version_pass_rates(&conn, &plugin_slug, &skill_name, model.as_deref())
+}
+
+/// `Some` when the newest released version passes less often than the one
+/// before it with the same model. Defaults to the most recently used model.
+#[tauri::command]
+pub fn detect_scenario_regression(
+    plugin_slug: String,
+    skill_name: String,
+    model: Option<String>,
+    db: tauri::State<'_, Db>,
+) -> Result<Option<VersionRegression>, String> {
+    log::info!(
+        "[detect_scenario_regression] plugin={} skill={} model={:?}",
+        plugin_slug,
+        skill_name,
+        model
+    );
+    validate_plugin_slug(&plugin_slug)?;
+    validate_skill_name(&skill_name)?;
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    scenario_runs::detect_version_regression(&conn, &plugin_slug, &skill_name, model.as_deref())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::eval_workbench::assertions::{self, Assertion};
+
+    #[test]
+    fn rubric_verdicts_only_decide_rubrics() {
+        let rubric = Assertion::from("Explains the forecast");
+        let typed: Assertion =
+            serde_json::from_value(serde_json::json!({ "type": "contains", "value": "ARR" }))
+                .unwrap();
+        let mut outcomes = assertions::evaluate_assertions(
+            &[rubric, typed],
+            &assertions::RunEvidence {
+                output: "ARR grew",
+                ..Default::default()
+            },
+        );
+        assert_eq!(outcomes[0].passed, None);
+
+        let verdict = |index| RubricVerdictDto {
+            index,
+            passed: false,
+            reason: "No assumptions listed".to_string(),
+        };
+        apply_rubric_verdicts(&mut outcomes, &[verdict(0)]).unwrap();
+        assert_eq!(outcomes[0].passed, Some(false));
+        assert_eq!(outcomes[0].reason, "No assumptions listed");
+        assert_eq!(scenario_runs::run_verdict(&outcomes), Some(false));
+
+        assert!(apply_rubric_verdicts(&mut outcomes, &[verdict(1)])
+            .unwrap_err()
+            .contains("contains check"));
+        assert!(apply_rubric_verdicts(&mut outcomes, &[verdict(5)]).is_err());
+    }
+}
@@ -1,4 +1,10 @@
+pub mod assertions;
+pub mod compare;
+pub mod history;
+pub mod promptfoo;
+pub mod run;
 pub mod scenarios;
+pub mod trigger;
 pub mod types;
 
 use crate::agents::skill_creator::{
@@ -49,7 +55,8 @@ fn parse_scenario_tags(tags: &[String]) -> Result<Vec<scenarios::ScenarioTag>, S
     for tag in tags {
         let next = match tag.trim() {
             "performance" => scenarios::ScenarioTag::Performance,
-            other => return Err(format!("Unsupported performance scenario tag: {other}")),
+            "trigger" => scenarios::ScenarioTag::Trigger,
+            other => return Err(format!("Unsupported scenario tag: {other}")),
         };
         if !parsed.contains(&next) {
             parsed.push(next);
@@ -62,6 +69,7 @@ fn scenario_tag_strings(tags: &[scenarios::ScenarioTag]) -> Vec<String> {
     tags.iter()
         .map(|tag| match tag {
             scenarios::ScenarioTag::Performance => "performance",
+            scenarios::ScenarioTag::Trigger => "trigger",
         })
         .map(str::to_string)
         .collect()
@@ -79,6 +87,7 @@ fn scenario_from_dto(dto: ScenarioDto) -> Result<scenarios::Scenario, String> {
         tags,
         prompt: dto.prompt,
         expectations: dto.assertions,
+        should_trigger: dto.should_trigger,
     };
     scenarios::validate_scenario(&scenario)?;
     Ok(scenario)
@@ -91,6 +100,7 @@ fn scenario_to_dto(scenario: scenarios::Scenario) -> ScenarioDto {
         tags: scenario_tag_strings(&scenario.tags),
         prompt: scenario.prompt,
         assertions: scenario.expectations,
+        should_trigger: scenario.should_trigger,
     }
 }
 
@@ -142,8 +152,7 @@ fn persist_scenario_file(
     Ok(())
 }
 
-fn next_default_scenario_name(eval_dir: &std::path::Path) -> Result<String, String> {
-    let prefix = "Performance";
+fn next_default_scenario_name(eval_dir: &std::path::Path, prefix: &str) -> Result<String, String> {
     let existing = scenarios::list_scenarios(eval_dir)?;
     let mut index = 1;
     loop {
@@ -158,6 +167,27 @@ fn next_default_scenario_name(eval_dir: &std::path::Path) -> Result<String, Stri
     }
 }
 
+fn new_default_scenario(
+    eval_dir: &std::path::Path,
+    mode: crate::db::eval_workbench::EvalWorkbenchMode,
+) -> Result<scenarios::Scenario, String> {
+    use crate::db::eval_workbench::EvalWorkbenchMode;
+    let (prefix, tag, should_trigger) = match mode {
+        EvalWorkbenchMode::Performance => {
+            ("Performance", scenarios::ScenarioTag::Performance, None)
+        }
+        EvalWorkbenchMode::Trigger => ("Trigger", scenarios::ScenarioTag::Trigger, Some(true)),
+    };
+    Ok(scenarios::Scenario {
+        id: format!("case-{}", uuid::Uuid::new_v4().simple()),
+        name: next_default_scenario_name(eval_dir, prefix)?,
+        tags: vec![tag],
+        prompt: String::new(),
+        expectations: vec![],
+        should_trigger,
+    })
+}
+
 fn build_suggest_scenario_prompt(
     skill_name: &str,
     existing_scenario: &scenarios::Scenario,
@@ -233,9 +263,17 @@ fn parse_suggested_scenario_response(
         .get("expectations")
         .and_then(|v| v.as_array())
         .map(|arr| {
-            arr.iter()
-                .filter_map(|v| v.as_str().map(|s| s.to_string()))
-                .collect()
+            let mut suggested: Vec<assertions::Assertion> = arr
+                .iter()
+                .filter_map(|v| serde_json::from_value(v.clone()).ok())
+                .collect();
+            // Suggestions are rubrics; hand-written deterministic checks survive them.
+            for existing in &existing_scenario.expectations {
+                if !existing.is_llm_graded() && !suggested.contains(existing) {
+                    suggested.push(existing.clone());
+                }
+            }
+            suggested
         })
         .unwrap_or_else(|| existing_scenario.expectations.clone());
     Ok(scenarios::Scenario {
@@ -244,10 +282,12 @@ fn parse_suggested_scenario_response(
         tags: existing_scenario.tags.clone(),
         prompt,
         expectations,
+        should_trigger: existing_scenario.should_trigger,
     })
 }
 
-async fn run_define_eval_scenario_throwaway_turn<
+#[allow(clippy::too_many_arguments)]
+async fn run_eval_workbench_throwaway_turn<
     EnsureRuntimeDir,
     EnsureRuntimeDirFuture,
     RunTurn,
@@ -257,6 +297,7 @@ async fn run_define_eval_scenario_throwaway_turn<
     plugin_slug: &str,
     skill_name: &str,
     prompt: &str,
+    intent: SkillCreatorIntent,
     runtime_ctx: &crate::commands::workflow::settings::InitializedRuntimeContext,
     ensure_runtime_dir: EnsureRuntimeDir,
     run_turn: RunTurn,
@@ -285,16 +326,24 @@ where
         plugin_slug: plugin_slug.to_string(),
         prompt: prompt.to_string(),
         llm: runtime_ctx.llm.clone(),
-        intent: SkillCreatorIntent::Eval,
+        intent: intent.clone(),
         skill_dir_override: Some(runtime_run_dir.to_string_lossy().replace('\\', "/")),
     });
     run_turn(OpenHandsThrowawayRunParams {
         config,
-        timeout: std::time::Duration::from_secs(90),
+        timeout: eval_workbench_turn_timeout(&intent),
     })
     .await
 }
 
+/// Single-shot turns answer in seconds; scenario runs may use tools for minutes.
+fn eval_workbench_turn_timeout(intent: &SkillCreatorIntent) -> std::time::Duration {
+    match intent {
+        SkillCreatorIntent::ScenarioRun => std::time::Duration::from_secs(600),
+        _ => std::time::Duration::from_secs(90),
+    }
+}
+
 #[tauri::command]
 pub fn list_scenarios(
     plugin_slug: String,
@@ -331,21 +380,19 @@ pub fn load_scenario(
 pub fn create_scenario(
     plugin_slug: String,
     skill_name: String,
+    mode: Option<String>,
     db: tauri::State<'_, Db>,
 ) -> Result<ScenarioDto, String> {
     validate_plugin_slug(&plugin_slug)?;
     validate_skill_name(&skill_name)?;
+    let mode = match mode.as_deref() {
+        Some(mode) => crate::db::eval_workbench::EvalWorkbenchMode::parse(mode)?,
+        None => crate::db::eval_workbench::EvalWorkbenchMode::Performance,
+    };
     let skills_path = resolve_skills_path(&db)?;
     let eval_dir =
         crate::skill_paths::resolve_eval_dir(Path::new(&skills_path), &plugin_slug, &skill_name);
-    let name = next_default_scenario_name(&eval_dir)?;
-    let scenario = scenarios::Scenario {
-        id: format!("case-{}", uuid::Uuid::new_v4().simple()),
-        name,
-        tags: vec![scenarios::ScenarioTag::Performance],
-        prompt: String::new(),
-        expectations: vec![],
-    };
+    let scenario = new_default_scenario(&eval_dir, mode)?;
     let path = scenarios::scenario_file_path(&eval_dir, &scenario.name);
     scenarios::write_scenario_file(&path, &scenario)?;
     Ok(scenario_to_dto(scenario))
@@ -436,11 +483,12 @@ pub async fn define_eval_scenario(
         .to_string_lossy()
         .replace('\\', "/");
     let run =
-        run_define_eval_scenario_throwaway_turn(
+        run_eval_workbench_throwaway_turn(
             &app_data_root,
             &plugin_slug,
             &skill_name,
             &prompt,
+            SkillCreatorIntent::Eval,
             &runtime_ctx,
             |runtime_run_dir| {
                 let runtime_run_dir = runtime_run_dir.to_path_buf();
@@ -497,11 +545,12 @@ mod tests {
         };
 
         let result = tokio::runtime::Runtime::new().unwrap().block_on(
-            run_define_eval_scenario_throwaway_turn(
+            run_eval_workbench_throwaway_turn(
                 "/tmp/app-data",
                 "default",
                 "lead-conversion",
                 "prompt",
+                SkillCreatorIntent::Eval,
                 &runtime_ctx,
                 |runtime_run_dir| {
                     let runtime_run_dir = runtime_run_dir.to_path_buf();
@@ -530,4 +579,31 @@ mod tests {
 
         assert!(result.is_ok());
     }
+
+    #[test]
+    fn new_trigger_scenarios_are_labeled_and_numbered_separately() {
+        let eval_dir = tempfile::tempdir().unwrap();
+        let performance = new_default_scenario(
+            eval_dir.path(),
+            crate::db::eval_workbench::EvalWorkbenchMode::Performance,
+        )
+        .unwrap();
+        let path = scenarios::scenario_file_path(eval_dir.path(), &performance.name);
+        scenarios::write_scenario_file(&path, &performance).unwrap();
+
+        let trigger = new_default_scenario(
+            eval_dir.path(),
+            crate::db::eval_workbench::EvalWorkbenchMode::Trigger,
+        )
+        .unwrap();
+
+        assert_eq!(performance.name, "Performance 1");
+        assert_eq!(performance.should_trigger, None);
+        assert_eq!(trigger.name, "Trigger 1");
+        assert_eq!(trigger.tags, vec![scenarios::ScenarioTag::Trigger]);
+        assert_eq!(trigger.should_trigger, Some(true));
+        let dto = scenario_to_dto(trigger);
+        assert_eq!(dto.tags, vec!["trigger".to_string()]);
+        assert!(scenario_from_dto(dto).is_ok());
+    }
 }
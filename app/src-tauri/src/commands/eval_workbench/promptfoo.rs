@@ -0,0 +1,652 @@
+use std::path::Path;
+
+use serde_json::{json, Value};
+
+use super::assertions::{Assertion, TypedAssertion};
+use super::scenarios::{self, Scenario, ScenarioTag};
+use super::types::{PromptfooExportResultDto, PromptfooImportResultDto};
+use super::{persist_scenario_file, scenario_summary_to_dto, validate_plugin_slug};
+use crate::commands::imported_skills::validate_skill_name;
+use crate::commands::skill_session::resolve_skills_path;
+use crate::db::Db;
+
+/// Tier the `tests/evals` harness uses to pick a provider for exported configs.
+const EXPORT_EVAL_TIER: &str = "standard";
+
+/// The exported prompt: the skill's instructions, then the scenario prompt.
+const EXPORT_PROMPT_TEMPLATE: &str = "You have the following skill loaded. Follow it wherever it applies.\n\n<skill>\n{{skill}}\n</skill>\n\n{{prompt}}";
+
+fn template_placeholder() -> &'static regex::Regex {
+    static PLACEHOLDER: std::sync::OnceLock<regex::Regex> = std::sync::OnceLock::new();
+    PLACEHOLDER
+        .get_or_init(|| regex::Regex::new(r"\{\{\s*([A-Za-z_][A-Za-z0-9_]*)\s*\}\}").unwrap())
+}
+
+fn read_structured_file(path: &Path) -> Result<Value, String> {
+    let content = std::fs::read_to_string(path)
+        .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+    if path.extension().and_then(|ext| ext.to_str()) == Some("json") {
+        serde_json::from_str(&content)
+            .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+    } else {
+        serde_yaml::from_str(&content)
+            .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))
+    }
+}
+
+/// Resolves a promptfoo `file://` reference relative to the config's directory.
+fn resolve_file_ref(value: &str, base_dir: &Path) -> Option<std::path::PathBuf> {
+    value
+        .strip_prefix("file://")
+        .map(|relative| base_dir.join(relative))
+}
+
+fn var_text(value: &Value) -> String {
+    match value {
+        Value::String(text) => text.clone(),
+        other => other.to_string(),
+    }
+}
+
+/// Substitutes `{{ var }}` placeholders. Fails on any placeholder the vars do
+/// not cover, or on Nunjucks syntax beyond plain variables.
+pub(crate) fn render_prompt_template(
+    template: &str,
+    vars: &serde_json::Map<String, Value>,
+) -> Result<String, String> {
+    let mut missing = Vec::new();
+    let rendered =
+        template_placeholder().replace_all(template, |caps: &regex::Captures| {
+            match vars.get(&caps[1]) {
+                Some(value) => var_text(value),
+                None => {
+                    missing.push(caps[1].to_string());
+                    String::new()
+                }
+            }
+        });
+    if !missing.is_empty() {
+        return Err(format!("prompt uses unset vars: {}", missing.join(", ")));
+    }
+    if rendered.contains("{{") || rendered.contains("{%") {
+        return Err("prompt uses template syntax beyond plain vars".to_string());
+    }
+    Ok(last_user_message(&rendered).unwrap_or(rendered.into_owned()))
+}
+
+/// A chat-format prompt renders to a JSON message list; its last user
+/// message is the scenario prompt.
+fn last_user_message(rendered: &str) -> Option<String> {
+    let messages: Vec<Value> = serde_json::from_str(rendered.trim()).ok()?;
+    messages
+        .iter()
+        .rev()
+        .find(|message| message.get("role").and_then(|v| v.as_str()) == Some("user"))
+        .and_then(|message| message.get("content")?.as_str().map(str::to_string))
+}
+
+/// The config's first prompt template, read from disk for `file://` prompts.
+fn first_prompt_template(config: &Value, base_dir: &Path) -> Result<Option<String>, String> {
+    let first = match config.get("prompts") {
+        Some(Value::Array(prompts)) => prompts.first(),
+        Some(prompt @ Value::String(_)) => Some(prompt),
+        _ => None,
+    };
+    let Some(first) = first else {
+        return Ok(None);
+    };
+    let raw = match first {
+        Value::String(text) => text.as_str(),
+        Value::Object(prompt) => match prompt.get("raw").and_then(|v| v.as_str()) {
+            Some(raw) => raw,
+            None => return Ok(None),
+        },
+        _ => return Ok(None),
+    };
+    match resolve_file_ref(raw, base_dir) {
+        Some(path) => {
+            if !matches!(
+                path.extension().and_then(|ext| ext.to_str()),
+                Some("txt") | Some("md")
+            ) {
+                return Err(format!(
+                    "Prompt {} is not a text file; only .txt and .md prompts can be imported",
+                    raw
+                ));
+            }
+            std::fs::read_to_string(&path)
+                .map(Some)
+                .map_err(|e| format!("Failed to read prompt {}: {}", path.display(), e))
+        }
+        None => Ok(Some(raw.to_string())),
+    }
+}
+
+fn config_tests(config: &Value, base_dir: &Path) -> Result<Vec<Value>, String> {
+    match config.get("tests") {
+        None | Some(Value::Null) => Ok(Vec::new()),
+        Some(Value::Array(tests)) => Ok(tests.clone()),
+        Some(Value::String(reference)) => {
+            let path = resolve_file_ref(reference, base_dir)
+                .filter(|path| {
+                    matches!(
+                        path.extension().and_then(|ext| ext.to_str()),
+                        Some("yaml") | Some("yml") | Some("json")
+                    )
+                })
+                .ok_or_else(|| {
+                    format!(
+                        "Tests from {} cannot be imported; use a YAML or JSON file",
+                        reference
+                    )
+                })?;
+            match read_structured_file(&path)? {
+                Value::Array(tests) => Ok(tests),
+                _ => Err(format!(
+                    "{} does not contain a list of tests",
+                    path.display()
+                )),
+            }
+        }
+        Some(_) => Err("Promptfoo 'tests' must be a list or a file:// reference".to_string()),
+    }
+}
+
+fn typed(value: Value) -> Result<Assertion, String> {
+    serde_json::from_value(value).map_err(|e| format!("invalid assertion: {}", e))
+}
+
+/// Maps one promptfoo assertion onto workbench assertions. `Err` explains why
+/// it was left out.
+pub(crate) fn assertion_from_promptfoo(assert: &Value) -> Result<Vec<Assertion>, String> {
+    let kind = assert
+        .get("type")
+        .and_then(|v| v.as_str())
+        .ok_or_else(|| "assertion has no type".to_string())?;
+    let value = assert.get("value");
+    let text = || {
+        value
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .ok_or_else(|| format!("{kind} assertion needs a string value"))
+    };
+    Ok(match kind {
+        "llm-rubric" => vec![Assertion::LlmRubric(text()?)],
+        "contains" | "not-contains" | "regex" => {
+            vec![typed(json!({ "type": kind, "value": text()? }))?]
+        }
+        "icontains" => vec![typed(
+            json!({ "type": "regex", "value": format!("(?i){}", regex::escape(&text()?)) }),
+        )?],
+        "contains-all" => value
+            .and_then(|v| v.as_array())
+            .ok_or_else(|| "contains-all assertion needs a list value".to_string())?
+            .iter()
+            .map(|item| typed(json!({ "type": "contains", "value": var_text(item) })))
+            .collect::<Result<_, _>>()?,
+        "is-json" => {
+            let schema = match value {
+                None | Some(Value::Null) => json!({}),
+                Some(Value::Object(schema)) => Value::Object(schema.clone()),
+                Some(_) => return Err("is-json schemas from files are not supported".to_string()),
+            };
+            vec![typed(json!({ "type": "json-schema", "value": schema }))?]
+        }
+        "cost" => {
+            let threshold = assert
+                .get("threshold")
+                .and_then(|v| v.as_f64())
+                .ok_or_else(|| "cost assertion needs a threshold".to_string())?;
+            vec![typed(json!({ "type": "max-cost", "value": threshold }))?]
+        }
+        other => return Err(format!("{other} assertions have no workbench equivalent")),
+    })
+}
+
+fn scenario_name_from_description(description: &str) -> String {
+    description
+        .replace(['/', '\\'], "-")
+        .replace("..", ".")
+        .trim()
+        .to_string()
+}
+
+/// Converts a promptfoo config into performance scenarios. Tests that cannot
+/// be converted are skipped with a warning, as are unsupported assertions.
+pub(crate) fn scenarios_from_promptfoo(
+    config: &Value,
+    base_dir: &Path,
+) -> Result<(Vec<Scenario>, Vec<String>), String> {
+    let tests = config_tests(config, base_dir)?;
+    if tests.is_empty() {
+        return Err("The promptfoo config has no tests".to_string());
+    }
+    let template = first_prompt_template(config, base_dir);
+    let default_test = config.get("defaultTest");
+    let default_vars = default_test
+        .and_then(|t| t.get("vars"))
+        .and_then(|v| v.as_object())
+        .cloned()
+        .unwrap_or_default();
+    let default_asserts = default_test
+        .and_then(|t| t.get("assert"))
+        .and_then(|v| v.as_array())
+        .cloned()
+        .unwrap_or_default();
+
+    let mut imported: Vec<Scenario> = Vec::new();
+    let mut warnings = Vec::new();
+    for (index, test) in tests.iter().enumerate() {
+        let fallback_name = format!("Promptfoo {}", index + 1);
+        let base_name = test
+            .get("description")
+            .and_then(|v| v.as_str())
+            .map(scenario_name_from_description)
+            .filter(|name| !name.is_empty())
+            .unwrap_or(fallback_name);
+        let mut vars = default_vars.clone();
+        if let Some(test_vars) = test.get("vars").and_then(|v| v.as_object()) {
+            vars.extend(test_vars.clone());
+        }
+
+        let prompt = match vars.get("prompt") {
+            Some(prompt) => Ok(var_text(prompt)),
+            None => match &template {
+                Ok(Some(template)) => render_prompt_template(template, &vars),
+                Ok(None) => {
+                    Err("config has no prompt and the test has no 'prompt' var".to_string())
+                }
+                Err(e) => Err(e.clone()),
+            },
+        };
+        let prompt = match prompt {
+            Ok(prompt) if !prompt.trim().is_empty() => prompt,
+            Ok(_) => {
+                warnings.push(format!("Skipped '{}': the prompt is empty", base_name));
+                continue;
+            }
+            Err(e) => {
+                warnings.push(format!("Skipped '{}': {}", base_name, e));
+                continue;
+            }
+        };
+
+        let mut expectations = Vec::new();
+        let test_asserts = test
+            .get("assert")
+            .and_then(|v| v.as_array())
+            .cloned()
+            .unwrap_or_default();
+        for assert in default_asserts.iter().chain(test_asserts.iter()) {
+            match assertion_from_promptfoo(assert) {
+                Ok(mapped) => expectations.extend(mapped),
+                Err(e) => warnings.push(format!("'{}': skipped assertion: {}", base_name, e)),
+            }
+        }
+
+        let mut name = base_name.clone();
+        let mut suffix = 2;
+        while imported.iter().any(|s| s.name == name) {
+            name = format!("{base_name} {suffix}");
+            suffix += 1;
+        }
+        let id = test
+            .pointer("/metadata/scenario_id")
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+            .unwrap_or_else(|| format!("case-{}", uuid::Uuid::new_v4().simple()));
+        let scenario = Scenario {
+            id,
+            name,
+            tags: vec![ScenarioTag::Performance],
+            prompt,
+            expectations,
+            should_trigger: None,
+        };
+        match scenarios::validate_scenario(&scenario) {
+            Ok(()) => imported.push(scenario),
+            Err(e) => warnings.push(format!("Skipped '{}': {}", scenario.name, e)),
+        }
+    }
+    Ok((imported, warnings))
+}
+
+/// The promptfoo assertion for a workbench assertion, or `None` when promptfoo
+/// has no equivalent.
+pub(crate) fn assertion_to_promptfoo(assertion: &Assertion) -> Option<Value> {
+    match assertion {
+        Assertion::LlmRubric(text) => Some(json!({ "type": "llm-rubric", "value": text })),
+        Assertion::Typed(typed) => match typed {
+            TypedAssertion::LlmRubric { value } => {
+                Some(json!({ "type": "llm-rubric", "value": value }))
+            }
+            TypedAssertion::Contains { value } => {
+                Some(json!({ "type": "contains", "value": value }))
+            }
+            TypedAssertion::NotContains { value } => {
+                Some(json!({ "type": "not-contains", "value": value }))
+            }
+            TypedAssertion::Regex { value } => Some(json!({ "type": "regex", "value": value })),
+            TypedAssertion::JsonSchema { value } => {
+                Some(json!({ "type": "is-json", "value": value }))
+            }
+            TypedAssertion::MaxCost { value } => {
+                Some(json!({ "type": "cost", "threshold": value }))
+            }
+            TypedAssertion::FileExists { .. }
+            | TypedAssertion::FileContains { .. }
+            | TypedAssertion::MaxTurns { .. } => None,
+        },
+    }
+}
+
+/// Builds a promptfoo config for the skill's performance scenarios, shaped
+/// for the `tests/evals` harness: no providers, an `eval_tier`, and the
+/// skill's text (SKILL.md plus references) inlined as a var.
+pub(crate) fn promptfoo_config_from_scenarios(
+    plugin_slug: &str,
+    skill_name: &str,
+    skill_text: &str,
+    scenarios: &[Scenario],
+) -> (Value, usize, Vec<String>) {
+    let mut warnings = Vec::new();
+    let mut tests = Vec::new();
+    for scenario in scenarios {
+        if !scenario.tags.contains(&ScenarioTag::Performance) {
+            warnings.push(format!(
+                "Skipped '{}': trigger scenarios are not exported",
+                scenario.name
+            ));
+            continue;
+        }
+        if scenario.prompt.trim().is_empty() {
+            warnings.push(format!("Skipped '{}': the prompt is empty", scenario.name));
+            continue;
+        }
+        let mut asserts = Vec::new();
+        for assertion in &scenario.expectations {
+            match assertion_to_promptfoo(assertion) {
+                Some(assert) => asserts.push(assert),
+                None => warnings.push(format!(
+                    "'{}': {} assertions have no promptfoo equivalent and were left out",
+                    scenario.name,
+                    assertion.kind()
+                )),
+            }
+        }
+        tests.push(json!({
+            "description": scenario.name,
+            "vars": { "prompt": scenario.prompt },
+            "assert": asserts,
+            "metadata": { "scenario_id": scenario.id },
+        }));
+    }
+    let count = tests.len();
+    let config = json!({
+        "description": format!("{skill_name} scenarios exported from Skill Builder"),
+        "metadata": {
+            "eval_tier": EXPORT_EVAL_TIER,
+            "plugin": plugin_slug,
+            "skill": skill_name,
+        },
+        "prompts": [EXPORT_PROMPT_TEMPLATE],
+        "defaultTest": { "vars": { "skill": skill_text } },
+        "tests": tests,
+    });
+    (config, count, warnings)
+}
+
+/// Imports a promptfoo config's tests as performance scenarios. Scenarios
+/// with the same name are replaced.
+#[tauri::command]
+pub fn import_promptfoo_config(
+    plugin_slug: String,
+    skill_name: String,
+    source_path: String,
+    db: tauri::State<'_, Db>,
+) -> Result<PromptfooImportResultDto, String> {
+    log::info!(
+        "[import_promptfoo_config] plugin={} skill={} source={}",
+        plugin_slug,
+        skill_name,
+        source_path
+    );
+    validate_plugin_slug(&plugin_slug)?;
+    validate_skill_name(&skill_name)?;
+    let source = Path::new(&source_path);
+    let config = read_structured_file(source)?;
+    let base_dir = source.parent().unwrap_or(Path::new("."));
+    let (imported, mut warnings) = scenarios_from_promptfoo(&config, base_dir)?;
+
+    let skills_path = resolve_skills_path(&db)?;
+    let eval_dir =
+        crate::skill_paths::resolve_eval_dir(Path::new(&skills_path), &plugin_slug, &skill_name);
+    let mut saved = Vec::new();
+    for scenario in imported {
+        let existing = scenarios::load_scenario(&eval_dir, &scenario.name)?;
+        let previous = existing.as_ref().map(|s| s.name.as_str());
+        match persist_scenario_file(&eval_dir, &scenario, previous) {
+            Ok(()) => saved.push(scenario_summary_to_dto(scenarios::ScenarioSummary {
+                name: scenario.name,
+                tags: scenario.tags,
+            })),
+            Err(e) => warnings.push(format!("Skipped '{}': {}", scenario.name, e)),
+        }
+    }
+    log::info!(
+        "[import_promptfoo_config] imported={} warnings={}",
+        saved.len(),
+        warnings.len()
+    );
+    Ok(PromptfooImportResultDto {
+        scenarios: saved,
+        warnings,
+    })
+}
+
+/// Writes the skill's performance scenarios to `dest_path` as a promptfoo
+/// config, JSON for `.json` paths and YAML otherwise.
+#[tauri::command]
+pub fn export_promptfoo_config(
+    plugin_slug: String,
+    skill_name: String,
+    dest_path: String,
+    db: tauri::State<'_, Db>,
+) -> Result<PromptfooExportResultDto, String> {
+    log::info!(
+        "[export_promptfoo_config] plugin={} skill={} dest={}",
+        plugin_slug,
+        skill_name,
+        dest_path
+    );
+    validate_plugin_slug(&plugin_slug)?;
+    validate_skill_name(&skill_name)?;
+    let skills_path = resolve_skills_path(&db)?;
+    let skill_dir =
+        crate::skill_paths::resolve_skill_dir(Path::new(&skills_path), &plugin_slug, &skill_name);
+    let skill_text = crate::commands::skill::skill_with_inlined_references(&skill_dir)?;
+    let eval_dir =
+        crate::skill_paths::resolve_eval_dir(Path::new(&skills_path), &plugin_slug, &skill_name);
+    let mut loaded = Vec::new();
+    for summary in scenarios::list_scenarios(&eval_dir)? {
+        if let Some(scenario) = scenarios::load_scenario(&eval_dir, &summary.name)? {
+            loaded.push(scenario);
+        }
+    }
+    let (config, scenario_count, warnings) =
+        promptfoo_config_from_scenarios(&plugin_slug, &skill_name, &skill_text, &loaded);
+    if scenario_count == 0 {
+        return Err("Add at least one performance scenario with a prompt first".to_string());
+    }
+    let content = if dest_path.ends_with(".json") {
+        serde_json::to_string_pretty(&config)
+            .map_err(|e| format!("Failed to serialize promptfoo config: {}", e))?
+    } else {
+        serde_yaml::to_string(&config)
+            .map_err(|e| format!("Failed to serialize promptfoo config: {}", e))?
+    };
+    std::fs::write(&dest_path, content)
+        .map_err(|e| format!("Failed to write '{}': {}", dest_path, e))
+        .inspect_err(|e| log::error!("[export_promptfoo_config] {}", e))?;
+    Ok(PromptfooExportResultDto {
+        scenario_count,
+        warnings,
+    })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn contains(value: &str) -> Assertion {
+        typed(json!({ "type": "contains", "value": value })).unwrap()
+    }
+
+    #[test]
+    fn renders_file_prompts_with_test_vars() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join("prompt.txt"),
+            "Suggest a scope for {{ skill_name }} in {{industry}}.",
+        )
+        .unwrap();
+        let config = json!({
+            "prompts": ["file://prompt.txt"],
+            "defaultTest": { "vars": { "industry": "B2B SaaS" } },
+            "tests": [
+                {
+                    "description": "[smoke] too broad",
+                    "vars": { "skill_name": "sales-analysis" },
+                    "assert": [{ "type": "javascript", "value": "output.length > 0" }]
+                },
+                { "description": "missing var", "vars": {} }
+            ]
+        });
+
+        let (imported, warnings) = scenarios_from_promptfoo(&config, tmp.path()).unwrap();
+
+        assert_eq!(imported.len(), 1);
+        assert_eq!(imported[0].name, "[smoke] too broad");
+        assert_eq!(
+            imported[0].prompt,
+            "Suggest a scope for sales-analysis in B2B SaaS."
+        );
+        assert!(imported[0].expectations.is_empty());
+        assert_eq!(imported[0].tags, vec![ScenarioTag::Performance]);
+        assert!(warnings.iter().any(|w| w.contains("javascript")));
+        assert!(warnings
+            .iter()
+            .any(|w| w.contains("'missing var'") && w.contains("skill_name")));
+    }
+
+    #[test]
+    fn maps_assertions_and_merges_default_asserts() {
+        let config = json!({
+            "prompts": ["[{\"role\":\"system\",\"content\":\"Be brief\"},{\"role\":\"user\",\"content\":\"{{question}}\"}]"],
+            "defaultTest": { "assert": [{ "type": "cost", "threshold": 0.5 }] },
+            "tests": [{
+                "vars": { "question": "What was Q3 ARR?" },
+                "assert": [
+                    { "type": "icontains", "value": "a.r.r" },
+                    { "type": "contains-all", "value": ["Q3", "ARR"] },
+                    { "type": "is-json" },
+                    { "type": "llm-rubric", "value": "States assumptions" }
+                ]
+            }]
+        });
+
+        let (imported, warnings) = scenarios_from_promptfoo(&config, Path::new(".")).unwrap();
+
+        assert!(warnings.is_empty(), "{warnings:?}");
+        let scenario = &imported[0];
+        assert_eq!(scenario.name, "Promptfoo 1");
+        assert_eq!(scenario.prompt, "What was Q3 ARR?");
+        assert_eq!(
+            scenario.expectations,
+            vec![
+                typed(json!({ "type": "max-cost", "value": 0.5 })).unwrap(),
+                typed(json!({ "type": "regex", "value": "(?i)a\\.r\\.r" })).unwrap(),
+                contains("Q3"),
+                contains("ARR"),
+                typed(json!({ "type": "json-schema", "value": {} })).unwrap(),
+                Assertion::from("States assumptions"),
+            ]
+        );
+    }
+
+    #[test]
+    fn export_round_trips_through_import() {
+        let performance = Scenario {
+            id: "case-1".into(),
+            name: "Forecast".into(),
+            tags: vec![ScenarioTag::Performance],
+            prompt: "Forecast Q3 ARR".into(),
+            expectations: vec![
+                Assertion::from("Lists assumptions"),
+                contains("ARR"),
+                typed(json!({ "type": "file-exists", "path": "report.md" })).unwrap(),
+            ],
+            should_trigger: None,
+        };
+        let trigger = Scenario {
+            id: "case-2".into(),
+            name: "Trigger 1".into(),
+            tags: vec![ScenarioTag::Trigger],
+            prompt: "Write a haiku".into(),
+            expectations: vec![],
+            should_trigger: Some(false),
+        };
+
+        let (config, count, warnings) = promptfoo_config_from_scenarios(
+            "analytics",
+            "revenue",
+            "# Revenue",
+            &[performance.clone(), trigger],
+        );
+
+        assert_eq!(count, 1);
+        assert!(config.get("providers").is_none());
+        assert_eq!(config["metadata"]["eval_tier"], "standard");
+        assert_eq!(config["defaultTest"]["vars"]["skill"], "# Revenue");
+        assert_eq!(warnings.len(), 2);
+        assert!(warnings[0].contains("file-exists"));
+        assert!(warnings[1].contains("Trigger 1"));
+
+        let yaml = serde_yaml::to_string(&config).unwrap();
+        let reparsed: Value = serde_yaml::from_str(&yaml).unwrap();
+        let (imported, warnings) = scenarios_from_promptfoo(&reparsed, Path::new(".")).unwrap();
+        assert!(warnings.is_empty(), "{warnings:?}");
+        assert_eq!(
+            imported,
+            vec![Scenario {
+                expectations: performance.expectations[..2].to_vec(),
+                ..performance
+            }]
+        );
+    }
+
+    #[test]
+    fn reads_tests_from_referenced_files() {
+        let tmp = tempfile::tempdir().unwrap();
+        std::fs::write(
+            tmp.path().join("tests.yaml"),
+            "- description: Churn\n  vars:\n    prompt: Who churned?\n",
+        )
+        .unwrap();
+        let config_path = tmp.path().join("promptfooconfig.yaml");
+        std::fs::write(
+            &config_path,
+            "prompts: ['{{prompt}}']\ntests: file://tests.yaml\n",
+        )
+        .unwrap();
+
+        let config = read_structured_file(&config_path).unwrap();
+        let (imported, _) = scenarios_from_promptfoo(&config, tmp.path()).unwrap();
+        assert_eq!(imported[0].name, "Churn");
+        assert_eq!(imported[0].prompt, "Who churned?");
+
+        let err = scenarios_from_promptfoo(&json!({ "tests": "file://tests.csv" }), tmp.path())
+            .unwrap_err();
+        assert!(err.contains("tests.csv"));
+    }
+}
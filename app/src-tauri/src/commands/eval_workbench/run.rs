@@ -0,0 +1,544 @@
+use std::path::{Path, PathBuf};
+use std::time::Instant;
+
+use tauri::Manager;
+
+use super::assertions::{self, AssertionOutcome};
+use super::history::apply_rubric_verdicts;
+use super::scenarios::{self, Scenario, ScenarioTag};
+use super::types::{BenchmarkRunDto, RubricVerdictDto};
+use super::{run_eval_workbench_throwaway_turn, validate_plugin_slug};
+use crate::agents::skill_creator::SkillCreatorIntent;
+use crate::commands::imported_skills::validate_skill_name;
+use crate::commands::skill_session::resolve_skills_path;
+use crate::commands::workflow::{ensure_workspace_prompts, read_initialized_runtime_context};
+use crate::db::scenario_runs::{self, NewScenarioRun};
+use crate::db::Db;
+
+const SCENARIO_RUN_PROMPT_TEMPLATE: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../agent-sources/prompts/eval-workbench-scenario-run.txt"
+));
+
+const RUBRIC_GRADE_PROMPT_TEMPLATE: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../agent-sources/prompts/eval-workbench-grade-rubrics.txt"
+));
+
+/// Where the agent is asked to save files, relative to its workspace.
+/// File assertions resolve against this directory of the run's own workspace.
+pub(crate) const OUTPUT_DIR_NAME: &str = "outputs";
+
+pub(crate) struct ScenarioRunContext<'a> {
+    pub app: &'a tauri::AppHandle,
+    pub app_data_root: &'a str,
+    pub plugin_slug: &'a str,
+    pub skill_name: &'a str,
+    pub runtime_ctx: &'a crate::commands::workflow::settings::InitializedRuntimeContext,
+}
+
+pub(crate) fn build_scenario_run_prompt(
+    skill_name: &str,
+    with_skill: bool,
+    scenario: &Scenario,
+) -> String {
+    let skill_instructions = if with_skill {
+        format!("The `{skill_name}` skill is installed under `.agents/skills/{skill_name}/`. Read its SKILL.md first and follow it wherever it applies.")
+    } else {
+        "No skill is installed for this task; rely on your own knowledge.".to_string()
+    };
+    SCENARIO_RUN_PROMPT_TEMPLATE
+        .replace("{{skill_instructions}}", &skill_instructions)
+        .replace("{{output_dir}}", OUTPUT_DIR_NAME)
+        .replace("{{prompt}}", scenario.prompt.trim())
+}
+
+/// What the conversation stats say about a run; `None` where they are silent.
+#[derive(Debug, Clone, Copy, Default, PartialEq)]
+pub(crate) struct RunUsage {
+    pub total_tokens: Option<u64>,
+    pub total_cost_usd: Option<f64>,
+    /// LLM responses in the run, one per agent turn.
+    pub num_turns: Option<u32>,
+}
+
+/// Token, cost and turn totals from the conversation stats, when the runtime
+/// sent them.
+pub(crate) fn parse_run_usage(state: &serde_json::Value) -> RunUsage {
+    let Some(metrics) = [
+        "/raw_event/stats/usage_to_metrics",
+        "/stats/usage_to_metrics",
+    ]
+    .iter()
+    .find_map(|pointer| state.pointer(pointer))
+    .and_then(|v| v.as_object()) else {
+        return RunUsage::default();
+    };
+    let mut usage = RunUsage::default();
+    for metric in metrics.values() {
+        if let Some(tokens) = metric.get("accumulated_token_usage") {
+            let count = |key: &str| tokens.get(key).and_then(|v| v.as_u64()).unwrap_or(0);
+            *usage.total_tokens.get_or_insert(0) +=
+                count("prompt_tokens") + count("completion_tokens");
+        }
+        if let Some(value) = metric.get("accumulated_cost").and_then(|v| v.as_f64()) {
+            *usage.total_cost_usd.get_or_insert(0.0) += value;
+        }
+        if let Some(responses) = metric.get("response_latencies").and_then(|v| v.as_array()) {
+            let responses = u32::try_from(responses.len()).unwrap_or(u32::MAX);
+            *usage.num_turns.get_or_insert(0) += responses;
+        }
+    }
+    usage
+}
+
+/// Asks the grader to judge every rubric still undecided in `outcomes`.
+/// Returns `None` when there is nothing to grade.
+pub(crate) fn build_rubric_grade_prompt(
+    scenario: &Scenario,
+    output: &str,
+    outcomes: &[AssertionOutcome],
+) -> Option<String> {
+    let rubrics: Vec<String> = outcomes
+        .iter()
+        .enumerate()
+        .filter(|(_, outcome)| outcome.assertion.is_llm_graded() && outcome.passed.is_none())
+        .map(|(index, outcome)| {
+            format!(
+                "- index: {}\n  rubric: {}",
+                index,
+                outcome.assertion.to_storage_text().trim()
+            )
+        })
+        .collect();
+    if rubrics.is_empty() {
+        return None;
+    }
+    Some(
+        RUBRIC_GRADE_PROMPT_TEMPLATE
+            .replace("{{prompt}}", scenario.prompt.trim())
+            .replace("{{output}}", output.trim())
+            .replace("{{rubrics}}", &rubrics.join("\n")),
+    )
+}
+
+pub(crate) fn parse_rubric_verdicts(
+    state: &serde_json::Value,
+) -> Result<Vec<RubricVerdictDto>, String> {
+    #[derive(serde::Deserialize)]
+    struct Verdicts {
+        verdicts: Vec<RubricVerdictDto>,
+    }
+    let text = state
+        .get("result_text")
+        .and_then(|v| v.as_str())
+        .map(super::clean_openhands_structured_result_text)
+        .ok_or_else(|| "Missing result_text in OpenHands state".to_string())?;
+    serde_json::from_str::<Verdicts>(text)
+        .map(|parsed| parsed.verdicts)
+        .map_err(|e| format!("Failed to parse rubric verdicts JSON: {}", e))
+}
+
+pub(crate) fn summarize_run(
+    outcomes: Vec<AssertionOutcome>,
+    usage: RunUsage,
+    duration_ms: i64,
+    error: Option<String>,
+) -> BenchmarkRunDto {
+    let decided_assertions = outcomes.iter().filter(|o| o.passed.is_some()).count();
+    let passed_assertions = outcomes.iter().filter(|o| o.passed == Some(true)).count();
+    BenchmarkRunDto {
+        passed: if error.is_some() {
+            Some(false)
+        } else {
+            scenario_runs::run_verdict(&outcomes)
+        },
+        passed_assertions,
+        decided_assertions,
+        pass_rate: (decided_assertions > 0)
+            .then(|| passed_assertions as f64 / decided_assertions as f64),
+        total_tokens: usage.total_tokens,
+        total_cost_usd: usage.total_cost_usd,
+        num_turns: usage.num_turns,
+        duration_ms,
+        error,
+        outcomes,
+    }
+}
+
+/// Copies the skill's working copy, minus its repo metadata, so a run sees
+/// unsaved edits without touching the skill itself.
+pub(crate) fn snapshot_working_copy(skill_dir: &Path) -> Result<tempfile::TempDir, String> {
+    let snapshot =
+        tempfile::tempdir().map_err(|e| format!("Failed to create snapshot dir: {}", e))?;
+    crate::fs_utils::copy_dir_recursive(skill_dir, snapshot.path())?;
+    let entries = std::fs::read_dir(snapshot.path())
+        .map_err(|e| format!("Failed to read snapshot dir: {}", e))?;
+    for entry in entries.flatten() {
+        let name = entry.file_name().to_string_lossy().to_string();
+        if name.starts_with('.') || name == "evals" {
+            let path = entry.path();
+            let removed = if path.is_dir() {
+                std::fs::remove_dir_all(&path)
+            } else {
+                std::fs::remove_file(&path)
+            };
+            removed.map_err(|e| format!("Failed to drop {} from snapshot: {}", name, e))?;
+        }
+    }
+    Ok(snapshot)
+}
+
+/// Fills in rubric verdicts from one grader turn. On failure the rubrics stay
+/// undecided and say why.
+async fn grade_rubrics(
+    ctx: &ScenarioRunContext<'_>,
+    scenario: &Scenario,
+    output: &str,
+    outcomes: &mut [AssertionOutcome],
+) {
+    let Some(prompt) = build_rubric_grade_prompt(scenario, output, outcomes) else {
+        return;
+    };
+    let graded =
+        run_eval_workbench_throwaway_turn(
+            ctx.app_data_root,
+            ctx.plugin_slug,
+            ctx.skill_name,
+            &prompt,
+            SkillCreatorIntent::RubricGrade,
+            ctx.runtime_ctx,
+            |runtime_run_dir| {
+                let runtime_run_dir = runtime_run_dir.to_path_buf();
+                let app = ctx.app.clone();
+                async move {
+                    crate::commands::workflow::deploy::ensure_openhands_runtime_dir(
+                        &app,
+                        &runtime_run_dir,
+                    )
+                    .await
+                }
+            },
+            |params| {
+                let app = ctx.app.clone();
+                async move {
+                    crate::agents::tracked_openhands::send_tracked_throwaway(&app, params).await
+                }
+            },
+        )
+        .await
+        .and_then(|run| parse_rubric_verdicts(&run.conversation_state))
+        .and_then(|verdicts| apply_rubric_verdicts(outcomes, &verdicts));
+    if let Err(e) = graded {
+        log::warn!(
+            "[run_scenario] grading rubrics of '{}' failed: {}",
+            scenario.name,
+            e
+        );
+        for outcome in outcomes
+            .iter_mut()
+            .filter(|o| o.assertion.is_llm_graded() && o.passed.is_none())
+        {
+            outcome.reason = format!("LLM grading failed: {}", e);
+        }
+    }
+}
+
+/// Runs one scenario in a throwaway workspace, with the skill at `skill`
+/// installed when given, then grades it: typed assertions against the run's
+/// output and its `outputs/` directory, rubrics with the LLM grader.
+pub(crate) async fn run_scenario_turn(
+    ctx: &ScenarioRunContext<'_>,
+    skill: Option<&Path>,
+    scenario: &Scenario,
+) -> BenchmarkRunDto {
+    let prompt = build_scenario_run_prompt(ctx.skill_name, skill.is_some(), scenario);
+    let skill = skill.map(Path::to_path_buf);
+    let skill_name = ctx.skill_name.to_string();
+    let mut runtime_dir: Option<PathBuf> = None;
+    let runtime_dir_slot = &mut runtime_dir;
+    let started = Instant::now();
+    let result =
+        run_eval_workbench_throwaway_turn(
+            ctx.app_data_root,
+            ctx.plugin_slug,
+            ctx.skill_name,
+            &prompt,
+            SkillCreatorIntent::ScenarioRun,
+            ctx.runtime_ctx,
+            |runtime_run_dir| {
+                let runtime_run_dir = runtime_run_dir.to_path_buf();
+                *runtime_dir_slot = Some(runtime_run_dir.clone());
+                let app = ctx.app.clone();
+                async move {
+                    crate::commands::workflow::deploy::ensure_openhands_scenario_runtime_dir(
+                        &app,
+                        &runtime_run_dir,
+                    )
+                    .await?;
+                    std::fs::create_dir_all(runtime_run_dir.join(OUTPUT_DIR_NAME))
+                        .map_err(|e| format!("Failed to create outputs dir: {e}"))?;
+                    if let Some(skill) = skill {
+                        let installed = runtime_run_dir
+                            .join(".agents")
+                            .join("skills")
+                            .join(&skill_name);
+                        crate::fs_utils::copy_dir_recursive(&skill, &installed)?;
+                    }
+                    Ok(())
+                }
+            },
+            |params| {
+                let app = ctx.app.clone();
+                async move {
+                    crate::agents::tracked_openhands::send_tracked_throwaway(&app, params).await
+                }
+            },
+        )
+        .await;
+    let duration_ms = started.elapsed().as_millis().min(i64::MAX as u128) as i64;
+
+    let state = match result {
+        Ok(run) => run.conversation_state,
+        Err(e) => {
+            log::error!("[run_scenario] scenario '{}' failed: {}", scenario.name, e);
+            return summarize_run(Vec::new(), RunUsage::default(), duration_ms, Some(e));
+        }
+    };
+    let error = match state.get("status").and_then(|v| v.as_str()) {
+        Some("completed") => None,
+        status => Some(
+            state
+                .get("error_detail")
+                .and_then(|v| v.as_str())
+                .filter(|detail| !detail.trim().is_empty())
+                .map(str::to_string)
+                .unwrap_or_else(|| {
+                    format!("Run ended with status {}", status.unwrap_or("unknown"))
+                }),
+        ),
+    };
+    let output = state
+        .get("result_text")
+        .and_then(|v| v.as_str())
+        .unwrap_or_default();
+    let usage = parse_run_usage(&state);
+    let output_dir = runtime_dir.map(|dir| dir.join(OUTPUT_DIR_NAME));
+    let mut outcomes = assertions::evaluate_assertions(
+        &scenario.expectations,
+        &assertions::RunEvidence {
+            output,
+            output_dir: output_dir.as_deref(),
+            num_turns: usage.num_turns,
+            total_cost_usd: usage.total_cost_usd,
+        },
+    );
+    if error.is_none() {
+        grade_rubrics(ctx, scenario, output, &mut outcomes).await;
+    }
+    summarize_run(outcomes, usage, duration_ms, error)
+}
+
+/// Runs one performance scenario against the skill's working copy, grades it
+/// and adds it to the run history under the revision it measured.
+#[tauri::command]
+pub async fn run_scenario(
+    app: tauri::AppHandle,
+    plugin_slug: String,
+    skill_name: String,
+    scenario_name: String,
+    db: tauri::State<'_, Db>,
+) -> Result<BenchmarkRunDto, String> {
+    log::info!(
+        "[run_scenario] plugin={} skill={} scenario={}",
+        plugin_slug,
+        skill_name,
+        scenario_name
+    );
+    validate_plugin_slug(&plugin_slug)?;
+    validate_skill_name(&skill_name)?;
+    scenarios::validate_scenario_name(&scenario_name)?;
+    let skills_path = resolve_skills_path(&db)?;
+    let eval_dir =
+        crate::skill_paths::resolve_eval_dir(Path::new(&skills_path), &plugin_slug, &skill_name);
+    let scenario = scenarios::load_scenario(&eval_dir, &scenario_name)?
+        .ok_or_else(|| format!("Scenario '{}' not found", scenario_name))?;
+    if !scenario.tags.contains(&ScenarioTag::Performance) {
+        return Err(format!(
+            "Scenario '{}' is not a performance scenario",
+            scenario.name
+        ));
+    }
+    if scenario.prompt.trim().is_empty() {
+        return Err(format!("Scenario '{}' has no prompt", scenario.name));
+    }
+    let skill_dir =
+        crate::skill_paths::resolve_skill_dir(Path::new(&skills_path), &plugin_slug, &skill_name);
+    let revision = crate::git::skill_revision_at_head(&skill_dir, &plugin_slug, &skill_name)
+        .unwrap_or_else(|e| {
+            log::warn!(
+                "[run_scenario] could not resolve skill revision for '{}': {}",
+                skill_name,
+                e
+            );
+            crate::git::SkillRevision {
+                working_copy: true,
+                ..Default::default()
+            }
+        });
+    let snapshot = snapshot_working_copy(&skill_dir)?;
+
+    let runtime_ctx = read_initialized_runtime_context(&db)?;
+    ensure_workspace_prompts(&app, &runtime_ctx.skills_root).await?;
+    let app_data_root = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("failed to resolve app data dir: {e}"))?
+        .to_string_lossy()
+        .replace('\\', "/");
+    let ctx = ScenarioRunContext {
+        app: &app,
+        app_data_root: &app_data_root,
+        plugin_slug: &plugin_slug,
+        skill_name: &skill_name,
+        runtime_ctx: &runtime_ctx,
+    };
+    let run = run_scenario_turn(&ctx, Some(snapshot.path()), &scenario).await;
+    if run.error.is_none() {
+        let mut conn = db.0.lock().map_err(|e| e.to_string())?;
+        if let Err(e) = scenario_runs::record_scenario_run(
+            &mut conn,
+            NewScenarioRun {
+                plugin_slug: plugin_slug.clone(),
+                skill_name: skill_name.clone(),
+                scenario_name: scenario.name.clone(),
+                skill_version: revision.version,
+                skill_commit: revision.commit,
+                working_copy: revision.working_copy,
+                model: runtime_ctx.llm.model.clone(),
+                total_cost_usd: run.total_cost_usd,
+                duration_ms: Some(run.duration_ms),
+                num_turns: run.num_turns,
+                outcomes: run.outcomes.clone(),
+            },
+        ) {
+            log::warn!(
+                "[run_scenario] could not record run of '{}': {}",
+                scenario.name,
+                e
+            );
+        }
+    }
+    Ok(run)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::commands::eval_workbench::assertions::Assertion;
+
+    fn performance_case(name: &str, expectations: Vec<Assertion>) -> Scenario {
+        Scenario {
+            id: name.to_lowercase(),
+            name: name.to_string(),
+            tags: vec![ScenarioTag::Performance],
+            prompt: "Forecast Q3 ARR".to_string(),
+            expectations,
+            should_trigger: None,
+        }
+    }
+
+    fn contains(value: &str) -> Assertion {
+        serde_json::from_value(serde_json::json!({ "type": "contains", "value": value })).unwrap()
+    }
+
+    #[test]
+    fn prompt_mentions_the_skill_only_when_installed() {
+        let case = performance_case("Forecast", vec![]);
+        let with_skill = build_scenario_run_prompt("revenue", true, &case);
+        assert!(with_skill.contains(".agents/skills/revenue/"));
+        assert!(with_skill.contains("Forecast Q3 ARR"));
+        assert!(!with_skill.contains("{{"));
+
+        let without = build_scenario_run_prompt("revenue", false, &case);
+        assert!(!without.contains("revenue"));
+        assert!(without.contains("No skill is installed"));
+    }
+
+    #[test]
+    fn reads_usage_and_turns_from_conversation_stats_when_present() {
+        let state = serde_json::json!({
+            "raw_event": { "stats": { "usage_to_metrics": {
+                "agent": {
+                    "accumulated_cost": 0.25,
+                    "accumulated_token_usage": { "prompt_tokens": 900, "completion_tokens": 100 },
+                    "response_latencies": [{ "latency": 1.2 }, { "latency": 0.8 }, { "latency": 2.0 }]
+                },
+                "condenser": { "accumulated_cost": 0.05 }
+            } } }
+        });
+        let usage = parse_run_usage(&state);
+        assert_eq!(usage.total_tokens, Some(1000));
+        assert!((usage.total_cost_usd.unwrap() - 0.30).abs() < 1e-9);
+        assert_eq!(usage.num_turns, Some(3));
+
+        assert_eq!(
+            parse_run_usage(&serde_json::json!({ "result_text": "done" })),
+            RunUsage::default()
+        );
+    }
+
+    #[test]
+    fn grades_only_the_undecided_rubrics_by_index() {
+        let case = performance_case(
+            "Forecast",
+            vec![
+                contains("ARR"),
+                Assertion::from("States its assumptions"),
+                Assertion::from("Is concise"),
+            ],
+        );
+        let mut outcomes = assertions::evaluate_assertions(
+            &case.expectations,
+            &assertions::RunEvidence {
+                output: "ARR grows 12%",
+                ..Default::default()
+            },
+        );
+        let prompt = build_rubric_grade_prompt(&case, "ARR grows 12%", &outcomes).unwrap();
+        assert!(prompt.contains("- index: 1\n  rubric: States its assumptions"));
+        assert!(prompt.contains("- index: 2\n  rubric: Is concise"));
+        assert!(!prompt.contains("index: 0"));
+        assert!(!prompt.contains("{{"));
+
+        let verdicts = parse_rubric_verdicts(&serde_json::json!({
+            "result_text": "```json\n{\"verdicts\":[{\"index\":1,\"passed\":false,\"reason\":\"No assumptions\"},{\"index\":2,\"passed\":true,\"reason\":\"Short\"}]}\n```"
+        }))
+        .unwrap();
+        apply_rubric_verdicts(&mut outcomes, &verdicts).unwrap();
+        let passed: Vec<Option<bool>> = outcomes.iter().map(|o| o.passed).collect();
+        assert_eq!(passed, vec![Some(true), Some(false), Some(true)]);
+        assert_eq!(outcomes[1].reason, "No assumptions");
+        assert!(build_rubric_grade_prompt(&case, "ARR grows 12%", &outcomes).is_none());
+
+        assert!(parse_rubric_verdicts(&serde_json::json!({ "result_text": "{}" })).is_err());
+    }
+
+    #[test]
+    fn working_copy_snapshot_leaves_out_repo_metadata_and_evals() {
+        let skill_dir = tempfile::tempdir().unwrap();
+        std::fs::write(skill_dir.path().join("SKILL.md"), "# Revenue").unwrap();
+        std::fs::create_dir_all(skill_dir.path().join("references")).unwrap();
+        std::fs::write(skill_dir.path().join("references/glossary.md"), "ARR").unwrap();
+        std::fs::create_dir_all(skill_dir.path().join(".git")).unwrap();
+        std::fs::write(skill_dir.path().join(".git/HEAD"), "ref").unwrap();
+        std::fs::create_dir_all(skill_dir.path().join("evals")).unwrap();
+        std::fs::write(skill_dir.path().join("evals/smoke.yaml"), "name: Smoke").unwrap();
+
+        let snapshot = snapshot_working_copy(skill_dir.path()).unwrap();
+        assert!(snapshot.path().join("SKILL.md").is_file());
+        assert!(snapshot.path().join("references/glossary.md").is_file());
+        assert!(!snapshot.path().join(".git").exists());
+        assert!(!snapshot.path().join("evals").exists());
+    }
+}
@@ -3,10 +3,15 @@ use std::path::{Path, PathBuf};
 
 use serde::{Deserialize, Serialize};
 
+use super::assertions::{validate_assertion, Assertion};
+
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum ScenarioTag {
     Performance,
+    /// The prompt is run against the skill's name and description only, to
+    /// check whether the model would pick the skill at all.
+    Trigger,
 }
 
 #[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
@@ -16,7 +21,11 @@ pub struct Scenario {
     pub tags: Vec<ScenarioTag>,
     pub prompt: String,
     #[serde(default)]
-    pub expectations: Vec<String>,
+    pub expectations: Vec<Assertion>,
+    /// Label for trigger scenarios: whether the prompt should make the model
+    /// pick this skill.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub should_trigger: Option<bool>,
 }
 
 #[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
@@ -76,12 +85,21 @@ pub fn validate_scenario(scenario: &Scenario) -> Result<(), String> {
     if scenario.tags.is_empty() {
         return Err("Scenario tags cannot be empty".to_string());
     }
+    if scenario.tags.contains(&ScenarioTag::Trigger) && scenario.should_trigger.is_none() {
+        return Err(format!(
+            "Trigger scenario '{}' must set should_trigger",
+            scenario.name
+        ));
+    }
     if scenario.id.trim().is_empty() {
         return Err("Scenario id cannot be empty".to_string());
     }
     if scenario.id.contains('/') || scenario.id.contains('\\') || scenario.id.contains("..") {
         return Err("Scenario id contains invalid path characters".to_string());
     }
+    for assertion in &scenario.expectations {
+        validate_assertion(assertion)?;
+    }
     Ok(())
 }
 
@@ -108,8 +126,17 @@ pub fn scenario_file_path(eval_dir: &Path, scenario_name: &str) -> PathBuf {
 pub fn read_scenario_file(path: &Path) -> Result<Scenario, String> {
     let content = fs::read_to_string(path)
         .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
-    let scenario: Scenario = serde_yaml::from_str(&content)
+    let mut scenario: Scenario = serde_yaml::from_str(&content)
         .map_err(|e| format!("Failed to parse {}: {}", path.display(), e))?;
+    // Trigger scenarios written before the label existed were all positive
+    // cases; default them rather than failing the whole skill's listing.
+    if scenario.tags.contains(&ScenarioTag::Trigger) && scenario.should_trigger.is_none() {
+        log::warn!(
+            "[read_scenario_file] {} has no should_trigger label, assuming true",
+            path.display()
+        );
+        scenario.should_trigger = Some(true);
+    }
     validate_scenario(&scenario)?;
     Ok(scenario)
 }
@@ -228,6 +255,7 @@ mod tests {
             tags: vec![ScenarioTag::Performance],
             prompt: "Show me Q3 booking trends".into(),
             expectations: vec!["Explains the regional booking trends.".into()],
+            should_trigger: None,
         }
     }
 
@@ -310,7 +338,7 @@ mod tests {
     }
 
     #[test]
-    fn surfaces_unsupported_legacy_trigger_tags() {
+    fn unlabelled_trigger_scenarios_default_to_should_trigger() {
         let tmp = tempfile::tempdir().unwrap();
         fs::write(
             tmp.path().join("legacy-trigger.yaml"),
@@ -325,7 +353,57 @@ expectations:
         )
         .unwrap();
 
-        let error = list_scenarios(tmp.path()).unwrap_err();
-        assert!(error.contains("trigger"));
+        write_scenario_file(&tmp.path().join("regression.yaml"), &sample_scenario()).unwrap();
+
+        let scenarios = list_scenarios(tmp.path()).unwrap();
+        assert_eq!(
+            scenarios,
+            vec![
+                ScenarioSummary {
+                    name: "Legacy trigger".into(),
+                    tags: vec![ScenarioTag::Trigger],
+                },
+                ScenarioSummary {
+                    name: "Regression".into(),
+                    tags: vec![ScenarioTag::Performance],
+                },
+            ]
+        );
+        let loaded = load_scenario(tmp.path(), "Legacy trigger")
+            .unwrap()
+            .unwrap();
+        assert_eq!(loaded.should_trigger, Some(true));
+
+        // Saving still requires an explicit label.
+        let mut unlabelled = loaded;
+        unlabelled.should_trigger = None;
+        let error =
+            write_scenario_file(&tmp.path().join("legacy-trigger.yaml"), &unlabelled).unwrap_err();
+        assert!(error.contains("should_trigger"), "got: {}", error);
+    }
+
+    #[test]
+    fn trigger_scenarios_round_trip_their_label() {
+        let tmp = tempfile::tempdir().unwrap();
+        let path = tmp.path().join("renewal.yaml");
+        let scenario = Scenario {
+            id: "case-2".into(),
+            name: "Renewal question".into(),
+            tags: vec![ScenarioTag::Trigger],
+            prompt: "When does the Acme contract renew?".into(),
+            expectations: vec![],
+            should_trigger: Some(false),
+        };
+        write_scenario_file(&path, &scenario).unwrap();
+
+        assert!(fs::read_to_string(&path)
+            .unwrap()
+            .contains("should_trigger: false"));
+        assert_eq!(read_scenario_file(&path).unwrap(), scenario);
+        // Performance scenarios keep their files free of the trigger label.
+        write_scenario_file(&path, &sample_scenario()).unwrap();
+        assert!(!fs::read_to_string(&path)
+            .unwrap()
+            .contains("should_trigger"));
     }
 }
@@ -0,0 +1,415 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use tauri::Manager;
+
+use super::scenarios::{self, Scenario, ScenarioTag};
+use super::types::{TriggerEvalCaseDto, TriggerEvalReportDto};
+use super::{run_eval_workbench_throwaway_turn, validate_plugin_slug};
+use crate::agents::skill_creator::SkillCreatorIntent;
+use crate::commands::imported_skills::frontmatter::parse_frontmatter;
+use crate::commands::imported_skills::validate_skill_name;
+use crate::commands::skill_session::resolve_skills_path;
+use crate::commands::workflow::{ensure_workspace_prompts, read_initialized_runtime_context};
+use crate::db::Db;
+
+const TRIGGER_EVAL_PROMPT_TEMPLATE: &str = include_str!(concat!(
+    env!("CARGO_MANIFEST_DIR"),
+    "/../../agent-sources/prompts/eval-workbench-trigger.txt"
+));
+
+/// A skill as the router sees it: only its name and description.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct TriggerCandidate {
+    pub name: String,
+    pub description: String,
+}
+
+/// Reads the target skill and, as distractors, every other skill of the same
+/// plugin that has a description. The target comes first; distractors are
+/// sorted by name.
+pub(crate) fn read_trigger_candidates(
+    skills_root: &Path,
+    plugin_slug: &str,
+    skill_name: &str,
+) -> Result<Vec<TriggerCandidate>, String> {
+    let target_dir = crate::skill_paths::resolve_skill_dir(skills_root, plugin_slug, skill_name);
+    let target = read_candidate(&target_dir.join("SKILL.md"), skill_name)?.ok_or_else(|| {
+        format!(
+            "Skill '{}' has no description in its SKILL.md frontmatter",
+            skill_name
+        )
+    })?;
+
+    let mut distractors = Vec::new();
+    if let Some(skills_dir) = target_dir.parent() {
+        let entries = std::fs::read_dir(skills_dir)
+            .map_err(|e| format!("Failed to read {}: {}", skills_dir.display(), e))?;
+        for entry in entries.flatten() {
+            let dir_name = entry.file_name().to_string_lossy().to_string();
+            if dir_name == skill_name || dir_name.starts_with('.') || !entry.path().is_dir() {
+                continue;
+            }
+            if let Some(candidate) = read_candidate(&entry.path().join("SKILL.md"), &dir_name)? {
+                distractors.push(candidate);
+            }
+        }
+    }
+    distractors.sort_by(|a, b| a.name.cmp(&b.name));
+
+    let mut candidates = vec![target];
+    candidates.extend(distractors);
+    Ok(candidates)
+}
+
+fn read_candidate(skill_md: &Path, dir_name: &str) -> Result<Option<TriggerCandidate>, String> {
+    if !skill_md.is_file() {
+        return Ok(None);
+    }
+    let content = std::fs::read_to_string(skill_md)
+        .map_err(|e| format!("Failed to read {}: {}", skill_md.display(), e))?;
+    let (name, description) = parse_frontmatter(&content);
+    Ok(description
+        .filter(|d| !d.trim().is_empty())
+        .map(|description| TriggerCandidate {
+            name: name.unwrap_or_else(|| dir_name.to_string()),
+            description: description.trim().to_string(),
+        }))
+}
+
+/// Loads the trigger scenarios of a skill, skipping ones with an empty prompt.
+pub(crate) fn load_trigger_scenarios(eval_dir: &Path) -> Result<Vec<Scenario>, String> {
+    let mut loaded = Vec::new();
+    for summary in scenarios::list_scenarios(eval_dir)? {
+        if !summary.tags.contains(&ScenarioTag::Trigger) {
+            continue;
+        }
+        if let Some(scenario) = scenarios::load_scenario(eval_dir, &summary.name)? {
+            if !scenario.prompt.trim().is_empty() {
+                loaded.push(scenario);
+            }
+        }
+    }
+    Ok(loaded)
+}
+
+pub(crate) fn build_trigger_eval_prompt(
+    candidates: &[TriggerCandidate],
+    cases: &[Scenario],
+) -> String {
+    let skills = candidates
+        .iter()
+        .map(|c| format!("- {}: {}", c.name, c.description))
+        .collect::<Vec<_>>()
+        .join("\n");
+    let cases = cases
+        .iter()
+        .map(|c| format!("- id: {}\n  request: {}", c.id, c.prompt.trim()))
+        .collect::<Vec<_>>()
+        .join("\n");
+    TRIGGER_EVAL_PROMPT_TEMPLATE
+        .replace("{{skills}}", &skills)
+        .replace("{{cases}}", &cases)
+}
+
+/// Maps case id to the skill the model picked (`None` for no skill).
+pub(crate) fn parse_trigger_eval_response(
+    state: &serde_json::Value,
+) -> Result<HashMap<String, Option<String>>, String> {
+    let text = state
+        .get("result_text")
+        .and_then(|v| v.as_str())
+        .map(super::clean_openhands_structured_result_text)
+        .ok_or_else(|| "Missing result_text in OpenHands state".to_string())?;
+    let parsed: serde_json::Value = serde_json::from_str(text)
+        .map_err(|e| format!("Failed to parse trigger selections JSON: {}", e))?;
+    let selections = parsed
+        .get("selections")
+        .and_then(|v| v.as_array())
+        .ok_or_else(|| "Trigger selections JSON is missing 'selections'".to_string())?;
+    Ok(selections
+        .iter()
+        .filter_map(|item| {
+            let id = item.get("id")?.as_str()?.to_string();
+            let skill = item
+                .get("skill")
+                .and_then(|v| v.as_str())
+                .map(str::trim)
+                .filter(|s| !s.is_empty() && !s.eq_ignore_ascii_case("null"))
+                .map(str::to_string);
+            Some((id, skill))
+        })
+        .collect())
+}
+
+/// Scores each labeled case against `candidates` as read by
+/// [`read_trigger_candidates`]: the target first, then the distractors. The
+/// model answers with frontmatter names, so a case triggered when it picked
+/// the target's name, which may differ from its directory. A case the model
+/// left out counts as not triggered.
+pub(crate) fn score_trigger_results(
+    skill_name: &str,
+    candidates: &[TriggerCandidate],
+    cases: &[Scenario],
+    selections: &HashMap<String, Option<String>>,
+) -> TriggerEvalReportDto {
+    let target_name = candidates
+        .first()
+        .map(|c| c.name.as_str())
+        .unwrap_or(skill_name);
+    let mut report = TriggerEvalReportDto {
+        skill_name: skill_name.to_string(),
+        distractors: candidates.iter().skip(1).map(|c| c.name.clone()).collect(),
+        cases: Vec::with_capacity(cases.len()),
+        true_positives: 0,
+        false_positives: 0,
+        false_negatives: 0,
+        true_negatives: 0,
+        precision: None,
+        recall: None,
+    };
+    for case in cases {
+        let should_trigger = case.should_trigger.unwrap_or(false);
+        let selected_skill = selections.get(&case.id).cloned().flatten();
+        let triggered = selected_skill.as_deref() == Some(target_name);
+        match (should_trigger, triggered) {
+            (true, true) => report.true_positives += 1,
+            (false, true) => report.false_positives += 1,
+            (true, false) => report.false_negatives += 1,
+            (false, false) => report.true_negatives += 1,
+        }
+        report.cases.push(TriggerEvalCaseDto {
+            scenario_name: case.name.clone(),
+            prompt: case.prompt.clone(),
+            should_trigger,
+            selected_skill,
+            triggered,
+            passed: should_trigger == triggered,
+        });
+    }
+    let ratio = |hits: usize, misses: usize| {
+        (hits + misses > 0).then(|| hits as f64 / (hits + misses) as f64)
+    };
+    report.precision = ratio(report.true_positives, report.false_positives);
+    report.recall = ratio(report.true_positives, report.false_negatives);
+    report
+}
+
+#[tauri::command]
+pub async fn run_trigger_eval(
+    app: tauri::AppHandle,
+    plugin_slug: String,
+    skill_name: String,
+    db: tauri::State<'_, Db>,
+) -> Result<TriggerEvalReportDto, String> {
+    log::info!(
+        "[run_trigger_eval] plugin={} skill={}",
+        plugin_slug,
+        skill_name
+    );
+    validate_plugin_slug(&plugin_slug)?;
+    validate_skill_name(&skill_name)?;
+    let skills_path = resolve_skills_path(&db)?;
+    let eval_dir =
+        crate::skill_paths::resolve_eval_dir(Path::new(&skills_path), &plugin_slug, &skill_name);
+    let cases = load_trigger_scenarios(&eval_dir)?;
+    if cases.is_empty() {
+        return Err("Add at least one trigger scenario with a prompt first".to_string());
+    }
+    let candidates = read_trigger_candidates(Path::new(&skills_path), &plugin_slug, &skill_name)?;
+
+    let runtime_ctx = read_initialized_runtime_context(&db)?;
+    ensure_workspace_prompts(&app, &runtime_ctx.skills_root).await?;
+    let prompt = build_trigger_eval_prompt(&candidates, &cases);
+    let app_data_root = app
+        .path()
+        .app_data_dir()
+        .map_err(|e| format!("failed to resolve app data dir: {e}"))?
+        .to_string_lossy()
+        .replace('\\', "/");
+    let run =
+        run_eval_workbench_throwaway_turn(
+            &app_data_root,
+            &plugin_slug,
+            &skill_name,
+            &prompt,
+            SkillCreatorIntent::TriggerEval,
+            &runtime_ctx,
+            |runtime_run_dir| {
+                let runtime_run_dir = runtime_run_dir.to_path_buf();
+                let app = app.clone();
+                async move {
+                    crate::commands::workflow::deploy::ensure_openhands_runtime_dir(
+                        &app,
+                        &runtime_run_dir,
+                    )
+                    .await
+                }
+            },
+            |params| {
+                let app = app.clone();
+                async move {
+                    crate::agents::tracked_openhands::send_tracked_throwaway(&app, params).await
+                }
+            },
+        )
+        .await
+        .inspect_err(|e| log::error!("[run_trigger_eval] run failed: {}", e))?;
+
+    let selections = parse_trigger_eval_response(&run.conversation_state)?;
+    Ok(score_trigger_results(
+        &skill_name,
+        &candidates,
+        &cases,
+        &selections,
+    ))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_skill(skills_root: &Path, name: &str, description: Option<&str>) {
+        let dir = crate::skill_paths::resolve_skill_dir(skills_root, "analytics", name);
+        std::fs::create_dir_all(&dir).unwrap();
+        let frontmatter = description
+            .map(|d| format!("---\nname: {name}\ndescription: {d}\n---\n"))
+            .unwrap_or_default();
+        std::fs::write(dir.join("SKILL.md"), format!("{frontmatter}# {name}\n")).unwrap();
+    }
+
+    fn candidate(name: &str) -> TriggerCandidate {
+        TriggerCandidate {
+            name: name.to_string(),
+            description: format!("{name} description"),
+        }
+    }
+
+    fn trigger_case(id: &str, prompt: &str, should_trigger: bool) -> Scenario {
+        Scenario {
+            id: id.to_string(),
+            name: format!("Trigger {id}"),
+            tags: vec![ScenarioTag::Trigger],
+            prompt: prompt.to_string(),
+            expectations: vec![],
+            should_trigger: Some(should_trigger),
+        }
+    }
+
+    #[test]
+    fn reads_target_first_and_plugin_siblings_as_distractors() {
+        let tmp = tempfile::tempdir().unwrap();
+        write_skill(tmp.path(), "revenue", Some("Revenue metrics"));
+        write_skill(tmp.path(), "churn", Some("Churn forecasting"));
+        write_skill(tmp.path(), "draft", None);
+
+        let candidates = read_trigger_candidates(tmp.path(), "analytics", "revenue").unwrap();
+        let names: Vec<&str> = candidates.iter().map(|c| c.name.as_str()).collect();
+        assert_eq!(names, vec!["revenue", "churn"]);
+        assert_eq!(candidates[0].description, "Revenue metrics");
+
+        let err = read_trigger_candidates(tmp.path(), "analytics", "draft").unwrap_err();
+        assert!(err.contains("no description"));
+    }
+
+    #[test]
+    fn prompt_lists_skills_and_cases_by_id() {
+        let candidates = vec![
+            TriggerCandidate {
+                name: "revenue".into(),
+                description: "Revenue metrics".into(),
+            },
+            TriggerCandidate {
+                name: "churn".into(),
+                description: "Churn forecasting".into(),
+            },
+        ];
+        let prompt = build_trigger_eval_prompt(
+            &candidates,
+            &[trigger_case("case-1", "What was Q3 ARR?", true)],
+        );
+        assert!(prompt.contains("- revenue: Revenue metrics\n- churn: Churn forecasting"));
+        assert!(prompt.contains("- id: case-1\n  request: What was Q3 ARR?"));
+        assert!(!prompt.contains("{{"));
+    }
+
+    #[test]
+    fn parses_selections_including_null_and_fenced_output() {
+        let state = serde_json::json!({
+            "result_text": "```json\n{\"selections\":[{\"id\":\"a\",\"skill\":\"revenue\"},{\"id\":\"b\",\"skill\":null}]}\n```"
+        });
+        let selections = parse_trigger_eval_response(&state).unwrap();
+        assert_eq!(selections["a"], Some("revenue".to_string()));
+        assert_eq!(selections["b"], None);
+
+        let err =
+            parse_trigger_eval_response(&serde_json::json!({"result_text": "{}"})).unwrap_err();
+        assert!(err.contains("selections"));
+    }
+
+    #[test]
+    fn scores_precision_and_recall() {
+        let cases = vec![
+            trigger_case("tp", "Q3 ARR?", true),
+            trigger_case("fn", "Net revenue retention?", true),
+            trigger_case("fp", "Who churned?", false),
+            trigger_case("tn", "Write a haiku", false),
+        ];
+        let selections = HashMap::from([
+            ("tp".to_string(), Some("revenue".to_string())),
+            ("fn".to_string(), Some("churn".to_string())),
+            ("fp".to_string(), Some("revenue".to_string())),
+            ("tn".to_string(), None),
+        ]);
+
+        let report = score_trigger_results(
+            "revenue",
+            &[candidate("revenue"), candidate("churn")],
+            &cases,
+            &selections,
+        );
+
+        assert_eq!(
+            (
+                report.true_positives,
+                report.false_positives,
+                report.false_negatives,
+                report.true_negatives
+            ),
+            (1, 1, 1, 1)
+        );
+        assert_eq!(report.precision, Some(0.5));
+        assert_eq!(report.recall, Some(0.5));
+        let passed: Vec<bool> = report.cases.iter().map(|c| c.passed).collect();
+        assert_eq!(passed, vec![true, false, false, true]);
+        assert_eq!(report.cases[1].selected_skill.as_deref(), Some("churn"));
+    }
+
+    #[test]
+    fn precision_is_undefined_when_the_skill_never_triggers() {
+        let cases = vec![trigger_case("a", "Write a haiku", false)];
+        let report =
+            score_trigger_results("revenue", &[candidate("revenue")], &cases, &HashMap::new());
+        assert_eq!(report.precision, None);
+        assert_eq!(report.recall, None);
+        assert!(report.cases[0].passed);
+    }
+
+    #[test]
+    fn matches_the_frontmatter_name_not_the_directory() {
+        let cases = vec![trigger_case("a", "Q3 ARR?", true)];
+        let selections = HashMap::from([("a".to_string(), Some("Revenue Metrics".to_string()))]);
+
+        let report = score_trigger_results(
+            "revenue",
+            &[candidate("Revenue Metrics"), candidate("churn")],
+            &cases,
+            &selections,
+        );
+
+        assert_eq!(report.skill_name, "revenue");
+        assert_eq!(report.distractors, vec!["churn"]);
+        assert_eq!(report.true_positives, 1);
+        assert!(report.cases[0].triggered);
+    }
+}
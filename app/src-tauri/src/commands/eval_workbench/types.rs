@@ -6,7 +6,10 @@ pub struct ScenarioDto {
     #[serde(default)]
     pub tags: Vec<String>,
     pub prompt: String,
-    pub assertions: Vec<String>,
+    /// Plain strings are LLM-graded rubrics; objects are typed assertions.
+    pub assertions: Vec<super::assertions::Assertion>,
+    #[serde(default)]
+    pub should_trigger: Option<bool>,
 }
 
 #[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
@@ -16,3 +19,134 @@ pub struct ScenarioSummaryDto {
     #[serde(default)]
     pub tags: Vec<String>,
 }
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerEvalCaseDto {
+    pub scenario_name: String,
+    pub prompt: String,
+    pub should_trigger: bool,
+    /// Skill the model picked for the prompt, if any.
+    pub selected_skill: Option<String>,
+    pub triggered: bool,
+    pub passed: bool,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct TriggerEvalReportDto {
+    pub skill_name: String,
+    /// Other skills of the plugin offered alongside the target.
+    pub distractors: Vec<String>,
+    pub cases: Vec<TriggerEvalCaseDto>,
+    pub true_positives: usize,
+    pub false_positives: usize,
+    pub false_negatives: usize,
+    pub true_negatives: usize,
+    /// `None` when the skill never triggered.
+    pub precision: Option<f64>,
+    /// `None` when there are no should-trigger scenarios.
+    pub recall: Option<f64>,
+}
+
+/// The LLM grader's verdict on the rubric at `index` in the scenario's assertions.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct RubricVerdictDto {
+    pub index: usize,
+    pub passed: bool,
+    #[serde(default)]
+    pub reason: String,
+}
+
+/// One side of a benchmark comparison: a skill snapshot or no skill at all.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkSideDto {
+    /// The ref as the user entered it, or `no-skill`.
+    pub reference: String,
+    pub label: String,
+    #[serde(default)]
+    pub commit: Option<String>,
+    /// Semver when the ref named a version tag.
+    #[serde(default)]
+    pub version: Option<String>,
+}
+
+/// One graded scenario run, alone or on one side of a comparison.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkRunDto {
+    /// `None` while any assertion is undecided, e.g. an ungraded rubric.
+    #[serde(default)]
+    pub passed: Option<bool>,
+    pub passed_assertions: usize,
+    pub decided_assertions: usize,
+    /// Share of decided assertions that passed.
+    #[serde(default)]
+    pub pass_rate: Option<f64>,
+    /// `None` when the runtime did not report usage.
+    #[serde(default)]
+    pub total_tokens: Option<u64>,
+    #[serde(default)]
+    pub total_cost_usd: Option<f64>,
+    /// `None` when the runtime did not report its LLM responses.
+    #[serde(default)]
+    pub num_turns: Option<u32>,
+    pub duration_ms: i64,
+    #[serde(default)]
+    pub error: Option<String>,
+    pub outcomes: Vec<super::assertions::AssertionOutcome>,
+}
+
+/// Candidate minus baseline for one scenario. Deltas are `None` when either
+/// side lacks the value.
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkScenarioComparisonDto {
+    pub scenario_name: String,
+    pub prompt: String,
+    pub baseline: BenchmarkRunDto,
+    pub candidate: BenchmarkRunDto,
+    #[serde(default)]
+    pub pass_rate_delta: Option<f64>,
+    #[serde(default)]
+    pub token_delta: Option<i64>,
+    #[serde(default)]
+    pub cost_delta_usd: Option<f64>,
+    pub duration_delta_ms: i64,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct BenchmarkComparisonDto {
+    pub plugin_slug: String,
+    pub skill_name: String,
+    pub model: String,
+    pub generated_at: String,
+    pub baseline: BenchmarkSideDto,
+    pub candidate: BenchmarkSideDto,
+    pub scenarios: Vec<BenchmarkScenarioComparisonDto>,
+    /// Share of graded scenarios that passed on each side.
+    #[serde(default)]
+    pub baseline_pass_rate: Option<f64>,
+    #[serde(default)]
+    pub candidate_pass_rate: Option<f64>,
+}
+
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptfooImportResultDto {
+    /// Scenarios written, including ones that replaced a scenario of the same name.
+    pub scenarios: Vec<ScenarioSummaryDto>,
+    /// Tests and assertions that could not be imported, with the reason.
+    pub warnings: Vec<String>,
+}
+
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+#[serde(rename_all = "camelCase")]
+pub struct PromptfooExportResultDto {
+    pub scenario_count: usize,
+    /// Scenarios and assertions left out of the config, with the reason.
+    pub warnings: Vec<String>,
+}
@@ -1,6 +1,11 @@
 use serde::{Deserialize, Serialize};
 
-const GITHUB_REPO: &str = "hbanerjee74/skill-builder";
+use crate::github_host::GitHubHost;
+use crate::types::AppSettings;
+
+/// Where feedback goes when no feedback repository is configured. Only exists
+/// on github.com.
+const DEFAULT_FEEDBACK_REPO: &str = "hbanerjee74/skill-builder";
 
 #[derive(Debug, Deserialize)]
 pub struct CreateGithubIssueRequest {
@@ -22,8 +27,8 @@ pub async fn create_github_issue(
     request: CreateGithubIssueRequest,
 ) -> Result<CreateGithubIssueResponse, String> {
     log::info!("[create_github_issue] title={}", request.title);
-    // 1. Get GitHub OAuth token from settings
-    let github_token = {
+    // 1. Get GitHub OAuth token, API base and target repo from settings
+    let (github_token, api_base, repo) = {
         let conn = db.0.lock().map_err(|e| {
             log::error!("[create_github_issue] Failed to acquire DB lock: {}", e);
             e.to_string()
@@ -32,21 +37,45 @@ pub async fn create_github_issue(
             log::error!("[create_github_issue] Failed to read settings: {}", e);
             e.to_string()
         })?;
-        settings.github_oauth_token.ok_or_else(|| {
+        let (api_base, repo) = feedback_target(&settings)?;
+        let token = settings.github_oauth_token.ok_or_else(|| {
             "Not signed in to GitHub. Sign in with GitHub in Settings.".to_string()
-        })?
+        })?;
+        (token, api_base, repo)
     };
 
     let client = reqwest::Client::new();
 
     // 2. Ensure labels exist (create if needed, best-effort)
     for label in &request.labels {
-        ensure_label_inner(&client, &github_token, label, "https://api.github.com")
+        ensure_label_inner(&client, &github_token, label, &api_base, &repo)
             .await
             .ok();
     }
 
-    create_github_issue_inner(&client, &github_token, &request, "https://api.github.com").await
+    create_github_issue_inner(&client, &github_token, &request, &api_base, &repo).await
+}
+
+/// Resolve the API base and `owner/repo` that feedback issues are filed in.
+/// The default repository lives on github.com, so GitHub Enterprise needs an
+/// explicit feedback repository on that host.
+pub(crate) fn feedback_target(settings: &AppSettings) -> Result<(String, String), String> {
+    let host = GitHubHost::from_settings(settings);
+    let repo = match settings.feedback_repo.as_deref() {
+        Some(repo) => repo.to_string(),
+        None if host.is_public() => DEFAULT_FEEDBACK_REPO.to_string(),
+        None => {
+            log::warn!(
+                "[create_github_issue] no feedback repository configured for {}",
+                host.host
+            );
+            return Err(format!(
+                "No feedback repository configured for {}. Set one in Settings > GitHub.",
+                host.host
+            ));
+        }
+    };
+    Ok((host.api_base, repo))
 }
 
 /// Inner testable function: create a GitHub issue using the given base URL.
@@ -55,9 +84,10 @@ pub(crate) async fn create_github_issue_inner(
     github_token: &str,
     request: &CreateGithubIssueRequest,
     base_url: &str,
+    repo: &str,
 ) -> Result<CreateGithubIssueResponse, String> {
     let response = client
-        .post(format!("{}/repos/{}/issues", base_url, GITHUB_REPO))
+        .post(format!("{}/repos/{}/issues", base_url, repo))
         .header("Authorization", format!("Bearer {}", github_token))
         .header("Accept", "application/vnd.github+json")
         .header("User-Agent", "SkillBuilder")
@@ -108,9 +138,10 @@ pub(crate) async fn ensure_label_inner(
     token: &str,
     label: &str,
     base_url: &str,
+    repo: &str,
 ) -> Result<(), String> {
     let response = client
-        .post(format!("{}/repos/{}/labels", base_url, GITHUB_REPO))
+        .post(format!("{}/repos/{}/labels", base_url, repo))
         .header("Authorization", format!("Bearer {}", token))
         .header("Accept", "application/vnd.github+json")
         .header("User-Agent", "SkillBuilder")
@@ -164,14 +195,24 @@ mod tests {
     async fn test_ensure_label_already_exists() {
         let mut server = mockito::Server::new_async().await;
         let _mock = server
-            .mock("POST", format!("/repos/{}/labels", GITHUB_REPO).as_str())
+            .mock(
+                "POST",
+                format!("/repos/{}/labels", DEFAULT_FEEDBACK_REPO).as_str(),
+            )
             .with_status(422)
             .with_body("{}")
             .create_async()
             .await;
 
         let client = reqwest::Client::new();
-        let result = ensure_label_inner(&client, "token", "bug", &server.url()).await;
+        let result = ensure_label_inner(
+            &client,
+            "token",
+            "bug",
+            &server.url(),
+            DEFAULT_FEEDBACK_REPO,
+        )
+        .await;
         assert!(result.is_ok());
     }
 
@@ -179,14 +220,24 @@ mod tests {
     async fn test_ensure_label_success() {
         let mut server = mockito::Server::new_async().await;
         let _mock = server
-            .mock("POST", format!("/repos/{}/labels", GITHUB_REPO).as_str())
+            .mock(
+                "POST",
+                format!("/repos/{}/labels", DEFAULT_FEEDBACK_REPO).as_str(),
+            )
             .with_status(201)
             .with_body(r#"{"name":"bug"}"#)
             .create_async()
             .await;
 
         let client = reqwest::Client::new();
-        let result = ensure_label_inner(&client, "token", "bug", &server.url()).await;
+        let result = ensure_label_inner(
+            &client,
+            "token",
+            "bug",
+            &server.url(),
+            DEFAULT_FEEDBACK_REPO,
+        )
+        .await;
         assert!(result.is_ok());
     }
 
@@ -194,7 +245,10 @@ mod tests {
     async fn test_create_github_issue_api_error() {
         let mut server = mockito::Server::new_async().await;
         let _mock = server
-            .mock("POST", format!("/repos/{}/issues", GITHUB_REPO).as_str())
+            .mock(
+                "POST",
+                format!("/repos/{}/issues", DEFAULT_FEEDBACK_REPO).as_str(),
+            )
             .with_status(403)
             .with_body(r#"{"message":"Forbidden"}"#)
             .create_async()
@@ -202,7 +256,14 @@ mod tests {
 
         let client = reqwest::Client::new();
         let req = make_request("Test Issue");
-        let result = create_github_issue_inner(&client, "bad-token", &req, &server.url()).await;
+        let result = create_github_issue_inner(
+            &client,
+            "bad-token",
+            &req,
+            &server.url(),
+            DEFAULT_FEEDBACK_REPO,
+        )
+        .await;
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(err.contains("GitHub API error"), "got: {}", err);
@@ -212,7 +273,7 @@ mod tests {
     async fn test_create_github_issue_success() {
         let mut server = mockito::Server::new_async().await;
         let _mock = server
-            .mock("POST", format!("/repos/{}/issues", GITHUB_REPO).as_str())
+            .mock("POST", format!("/repos/{}/issues", DEFAULT_FEEDBACK_REPO).as_str())
             .with_status(201)
             .with_body(
                 r#"{"html_url":"https://github.com/hbanerjee74/skill-builder/issues/42","number":42}"#,
@@ -222,10 +283,73 @@ mod tests {
 
         let client = reqwest::Client::new();
         let req = make_request("Test Issue");
-        let result = create_github_issue_inner(&client, "token", &req, &server.url()).await;
+        let result =
+            create_github_issue_inner(&client, "token", &req, &server.url(), DEFAULT_FEEDBACK_REPO)
+                .await;
         assert!(result.is_ok());
         let resp = result.unwrap();
         assert_eq!(resp.number, 42);
         assert!(resp.url.contains("issues/42"));
     }
+
+    #[test]
+    fn test_feedback_target_defaults_to_public_repo() {
+        let settings = AppSettings::default();
+        let (api_base, repo) = feedback_target(&settings).unwrap();
+        assert_eq!(api_base, "https://api.github.com");
+        assert_eq!(repo, DEFAULT_FEEDBACK_REPO);
+    }
+
+    #[test]
+    fn test_feedback_target_requires_repo_on_ghes() {
+        let settings = AppSettings {
+            github_host: Some("ghe.corp.example".to_string()),
+            ..Default::default()
+        };
+        let err = feedback_target(&settings).unwrap_err();
+        assert!(err.contains("No feedback repository"), "got: {}", err);
+    }
+
+    #[tokio::test]
+    async fn test_create_github_issue_on_ghes_uses_feedback_repo() {
+        let settings = AppSettings {
+            github_host: Some("ghe.corp.example".to_string()),
+            feedback_repo: Some("platform/skill-feedback".to_string()),
+            ..Default::default()
+        };
+        let (api_base, repo) = feedback_target(&settings).unwrap();
+        assert_eq!(api_base, "https://ghe.corp.example/api/v3");
+        assert_eq!(repo, "platform/skill-feedback");
+
+        // Serve the same /api/v3 layout from the mock server.
+        let mut server = mockito::Server::new_async().await;
+        let label_mock = server
+            .mock("POST", "/api/v3/repos/platform/skill-feedback/labels")
+            .with_status(201)
+            .with_body(r#"{"name":"bug"}"#)
+            .create_async()
+            .await;
+        let issue_mock = server
+            .mock("POST", "/api/v3/repos/platform/skill-feedback/issues")
+            .match_header("authorization", "Bearer ghes-token")
+            .with_status(201)
+            .with_body(
+                r#"{"html_url":"https://ghe.corp.example/platform/skill-feedback/issues/7","number":7}"#,
+            )
+            .create_async()
+            .await;
+
+        let client = reqwest::Client::new();
+        let base = format!("{}/api/v3", server.url());
+        ensure_label_inner(&client, "ghes-token", "bug", &base, &repo)
+            .await
+            .unwrap();
+        let req = make_request("Test Issue");
+        let resp = create_github_issue_inner(&client, "ghes-token", &req, &base, &repo)
+            .await
+            .unwrap();
+        assert_eq!(resp.number, 7);
+        label_mock.assert_async().await;
+        issue_mock.assert_async().await;
+    }
 }
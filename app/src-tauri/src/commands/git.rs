@@ -1,7 +1,10 @@
 use std::path::Path;
 
 use crate::db::Db;
-use crate::types::{SkillCommit, SkillFileContent};
+use crate::types::{
+    SkillCommit, SkillDraft, SkillDraftMerge, SkillFileContent, SkillGitRemote, SkillSyncResult,
+    SkillVersionDiff, SkillVersionRelease, VersionBump, VersionBumpSuggestion,
+};
 
 /// Resolve the skill output root: skills_path if configured, else error.
 fn resolve_output_root(db: &Db) -> Result<String, String> {
@@ -39,13 +42,15 @@ pub fn restore_skill_version(
     skill_name: String,
     plugin_slug: String,
     sha: String,
+    bump: Option<VersionBump>,
     db: tauri::State<'_, Db>,
 ) -> Result<String, String> {
     log::info!(
-        "[restore_skill_version] skill={} plugin={} sha={}",
+        "[restore_skill_version] skill={} plugin={} sha={} bump={:?}",
         skill_name,
         plugin_slug,
-        sha
+        sha,
+        bump
     );
     let output_root = resolve_output_root(&db)?;
     let skill_dir =
@@ -56,26 +61,75 @@ pub fn restore_skill_version(
     let msg = format!("{}: restored to {}", skill_name, short_sha);
     let committed = crate::git::commit_all(&skill_dir, &msg)
         .map_err(|e| format!("Filesystem restored but git commit failed ({}): {}", msg, e))?;
-    // Tag the restore with the next patch version.
-    // Even if commit_all returned None (content identical to HEAD), we still
-    // bump and tag HEAD so the user always sees a new version number.
-    let current_version = crate::git::latest_skill_semver(&skill_dir, &plugin_slug, &skill_name)
-        .unwrap_or_else(|_| "0.0.0".to_string());
-    let new_version = crate::git::bump_patch(&current_version);
-    crate::git::create_skill_version_tag(&skill_dir, &plugin_slug, &skill_name, &new_version)
-        .map_err(|e| {
-            format!(
-                "Restore committed but version tag failed (v{}): {}",
-                new_version, e
-            )
-        })?;
+    // Tag the restore with the next version (patch unless the caller picks a
+    // bump). Even if commit_all returned None (content identical to HEAD), we
+    // still bump and tag so the user always sees a new version number.
+    let release = crate::git::release_skill_version(
+        &skill_dir,
+        &plugin_slug,
+        &skill_name,
+        Some(bump.unwrap_or(VersionBump::Patch)),
+    )
+    .map_err(|e| format!("Restore committed but version tag failed: {}", e))?;
     log::info!(
         "[restore_skill_version] skill={} new_version={} new_commit={}",
         skill_name,
-        new_version,
+        release.version,
         committed.is_some()
     );
-    Ok(new_version)
+    Ok(release.version)
+}
+
+#[tauri::command]
+pub fn suggest_skill_version_bump(
+    skill_name: String,
+    plugin_slug: String,
+    db: tauri::State<'_, Db>,
+) -> Result<VersionBumpSuggestion, String> {
+    log::info!(
+        "[suggest_skill_version_bump] skill={} plugin={}",
+        skill_name,
+        plugin_slug
+    );
+    let output_root = resolve_output_root(&db)?;
+    let skill_dir =
+        crate::skill_paths::resolve_skill_dir(Path::new(&output_root), &plugin_slug, &skill_name);
+    crate::git::suggest_version_bump(&skill_dir, &plugin_slug, &skill_name).map_err(|e| {
+        log::error!("[suggest_skill_version_bump] failed: {}", e);
+        e
+    })
+}
+
+/// Tag the next version of a skill and update its CHANGELOG.md. Omitting
+/// `bump` applies the suggested bump.
+#[tauri::command]
+pub fn release_skill_version(
+    skill_name: String,
+    plugin_slug: String,
+    bump: Option<VersionBump>,
+    db: tauri::State<'_, Db>,
+) -> Result<SkillVersionRelease, String> {
+    log::info!(
+        "[release_skill_version] skill={} plugin={} bump={:?}",
+        skill_name,
+        plugin_slug,
+        bump
+    );
+    let output_root = resolve_output_root(&db)?;
+    let skill_dir =
+        crate::skill_paths::resolve_skill_dir(Path::new(&output_root), &plugin_slug, &skill_name);
+    let release = crate::git::release_skill_version(&skill_dir, &plugin_slug, &skill_name, bump)
+        .map_err(|e| {
+            log::error!("[release_skill_version] failed: {}", e);
+            e
+        })?;
+    log::info!(
+        "[release_skill_version] skill={} version={} tag={}",
+        skill_name,
+        release.version,
+        release.tag
+    );
+    Ok(release)
 }
 
 #[tauri::command]
@@ -105,6 +159,278 @@ pub fn get_skill_files_at_sha(
         .collect())
 }
 
+#[tauri::command]
+pub fn diff_skill_versions(
+    skill_name: String,
+    plugin_slug: String,
+    from_ref: String,
+    to_ref: String,
+    db: tauri::State<'_, Db>,
+) -> Result<SkillVersionDiff, String> {
+    log::info!(
+        "[diff_skill_versions] skill={} plugin={} from={} to={}",
+        skill_name,
+        plugin_slug,
+        from_ref,
+        to_ref
+    );
+    let output_root = resolve_output_root(&db)?;
+    let skill_dir =
+        crate::skill_paths::resolve_skill_dir(Path::new(&output_root), &plugin_slug, &skill_name);
+    crate::git::diff_skill_versions(&skill_dir, &plugin_slug, &skill_name, &from_ref, &to_ref)
+        .map_err(|e| {
+            log::error!("[diff_skill_versions] git diff failed: {}", e);
+            e
+        })
+}
+
+/// Check out a draft branch so refine turns and regenerations commit to it
+/// instead of the skill's main history. Defaults to a timestamped name.
+#[tauri::command]
+pub fn start_skill_draft(
+    skill_name: String,
+    plugin_slug: String,
+    draft_name: Option<String>,
+    db: tauri::State<'_, Db>,
+) -> Result<SkillDraft, String> {
+    log::info!(
+        "[start_skill_draft] skill={} plugin={} draft={:?}",
+        skill_name,
+        plugin_slug,
+        draft_name
+    );
+    let output_root = resolve_output_root(&db)?;
+    let skill_dir =
+        crate::skill_paths::resolve_skill_dir(Path::new(&output_root), &plugin_slug, &skill_name);
+    let draft_name = draft_name
+        .filter(|name| !name.trim().is_empty())
+        .unwrap_or_else(|| format!("refine-{}", chrono::Utc::now().format("%Y%m%d-%H%M%S")));
+    crate::git::start_skill_draft(&skill_dir, &plugin_slug, &skill_name, &draft_name).map_err(|e| {
+        log::error!("[start_skill_draft] failed: {}", e);
+        e
+    })
+}
+
+#[tauri::command]
+pub fn get_skill_draft(
+    skill_name: String,
+    plugin_slug: String,
+    db: tauri::State<'_, Db>,
+) -> Result<Option<SkillDraft>, String> {
+    log::info!(
+        "[get_skill_draft] skill={} plugin={}",
+        skill_name,
+        plugin_slug
+    );
+    let output_root = resolve_output_root(&db)?;
+    let skill_dir =
+        crate::skill_paths::resolve_skill_dir(Path::new(&output_root), &plugin_slug, &skill_name);
+    crate::git::skill_draft_status(&skill_dir, &plugin_slug, &skill_name).map_err(|e| {
+        log::error!("[get_skill_draft] failed: {}", e);
+        e
+    })
+}
+
+/// Merge the current draft into its base branch and tag a new version.
+/// Omitting `bump` applies the suggested bump.
+#[tauri::command]
+pub fn merge_skill_draft(
+    skill_name: String,
+    plugin_slug: String,
+    bump: Option<VersionBump>,
+    db: tauri::State<'_, Db>,
+) -> Result<SkillDraftMerge, String> {
+    log::info!(
+        "[merge_skill_draft] skill={} plugin={} bump={:?}",
+        skill_name,
+        plugin_slug,
+        bump
+    );
+    let output_root = resolve_output_root(&db)?;
+    let skill_dir =
+        crate::skill_paths::resolve_skill_dir(Path::new(&output_root), &plugin_slug, &skill_name);
+    let merge = crate::git::merge_skill_draft(&skill_dir, &plugin_slug, &skill_name, bump)
+        .map_err(|e| {
+            log::error!("[merge_skill_draft] failed: {}", e);
+            e
+        })?;
+    log::info!(
+        "[merge_skill_draft] skill={} version={} fast_forward={}",
+        skill_name,
+        merge.release.version,
+        merge.fast_forward
+    );
+    Ok(merge)
+}
+
+#[tauri::command]
+pub fn discard_skill_draft(
+    skill_name: String,
+    plugin_slug: String,
+    db: tauri::State<'_, Db>,
+) -> Result<(), String> {
+    log::info!(
+        "[discard_skill_draft] skill={} plugin={}",
+        skill_name,
+        plugin_slug
+    );
+    let output_root = resolve_output_root(&db)?;
+    let skill_dir =
+        crate::skill_paths::resolve_skill_dir(Path::new(&output_root), &plugin_slug, &skill_name);
+    crate::git::discard_skill_draft(&skill_dir).map_err(|e| {
+        log::error!("[discard_skill_draft] failed: {}", e);
+        e
+    })?;
+    Ok(())
+}
+
+#[tauri::command]
+pub fn get_skill_git_remote(
+    skill_name: String,
+    plugin_slug: String,
+    db: tauri::State<'_, Db>,
+) -> Result<SkillGitRemote, String> {
+    log::info!(
+        "[get_skill_git_remote] skill={} plugin={}",
+        skill_name,
+        plugin_slug
+    );
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    Ok(SkillGitRemote {
+        skill_url: crate::db::db_get_skill_git_remote(&conn, &plugin_slug, Some(&skill_name))?,
+        plugin_url: crate::db::db_get_skill_git_remote(&conn, &plugin_slug, None)?,
+        resolved_url: crate::db::db_resolve_skill_git_remote(&conn, &plugin_slug, &skill_name)?,
+    })
+}
+
+/// Set the remote for one skill, or for the whole plugin when `skill_name` is
+/// omitted. An empty `remote_url` clears it.
+#[tauri::command]
+pub fn set_skill_git_remote(
+    plugin_slug: String,
+    skill_name: Option<String>,
+    remote_url: String,
+    db: tauri::State<'_, Db>,
+) -> Result<(), String> {
+    log::info!(
+        "[set_skill_git_remote] plugin={} skill={:?} url={}",
+        plugin_slug,
+        skill_name,
+        remote_url
+    );
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    crate::db::db_set_skill_git_remote(&conn, &plugin_slug, skill_name.as_deref(), &remote_url)
+}
+
+#[tauri::command]
+pub async fn push_skill_remote(
+    skill_name: String,
+    plugin_slug: String,
+    db: tauri::State<'_, Db>,
+) -> Result<SkillSyncResult, String> {
+    log::info!(
+        "[push_skill_remote] skill={} plugin={}",
+        skill_name,
+        plugin_slug
+    );
+    sync_skill_remote(RemoteSync::Push, &skill_name, &plugin_slug, &db).await
+}
+
+#[tauri::command]
+pub async fn fetch_skill_remote(
+    skill_name: String,
+    plugin_slug: String,
+    db: tauri::State<'_, Db>,
+) -> Result<SkillSyncResult, String> {
+    log::info!(
+        "[fetch_skill_remote] skill={} plugin={}",
+        skill_name,
+        plugin_slug
+    );
+    sync_skill_remote(RemoteSync::Fetch, &skill_name, &plugin_slug, &db).await
+}
+
+#[tauri::command]
+pub async fn pull_skill_remote(
+    skill_name: String,
+    plugin_slug: String,
+    db: tauri::State<'_, Db>,
+) -> Result<SkillSyncResult, String> {
+    log::info!(
+        "[pull_skill_remote] skill={} plugin={}",
+        skill_name,
+        plugin_slug
+    );
+    sync_skill_remote(RemoteSync::Pull, &skill_name, &plugin_slug, &db).await
+}
+
+#[derive(Debug, Clone, Copy)]
+enum RemoteSync {
+    Push,
+    Fetch,
+    Pull,
+}
+
+/// Resolve the skill repo and remote under the DB lock, then run the network
+/// operation on a blocking thread.
+async fn sync_skill_remote(
+    operation: RemoteSync,
+    skill_name: &str,
+    plugin_slug: &str,
+    db: &Db,
+) -> Result<SkillSyncResult, String> {
+    let (skill_dir, remote_url, github_token) = {
+        let conn = db.0.lock().map_err(|e| e.to_string())?;
+        let settings = crate::db::read_settings(&conn)?;
+        let github = crate::github_host::GitHubAccess::from_settings(&settings);
+        let output_root = settings
+            .skills_path
+            .ok_or_else(|| "Skills path not configured".to_string())?;
+        let remote_url = crate::db::db_resolve_skill_git_remote(&conn, plugin_slug, skill_name)?
+            .ok_or_else(|| format!("No git remote configured for skill '{}'", skill_name))?;
+        let skill_dir =
+            crate::skill_paths::resolve_skill_dir(Path::new(&output_root), plugin_slug, skill_name);
+        let github_token = github.token_for_url(&remote_url).map(str::to_string);
+        (skill_dir, remote_url, github_token)
+    };
+
+    let result = tokio::task::spawn_blocking(move || {
+        let token = github_token.as_deref();
+        match operation {
+            RemoteSync::Push => {
+                crate::git_remote::push_skill_remote(&skill_dir, &remote_url, token)
+            }
+            RemoteSync::Fetch => {
+                crate::git_remote::fetch_skill_remote(&skill_dir, &remote_url, token)
+            }
+            RemoteSync::Pull => {
+                crate::git_remote::pull_skill_remote(&skill_dir, &remote_url, token)
+            }
+        }
+    })
+    .await
+    .map_err(|e| format!("Git remote task failed: {}", e))?;
+
+    match &result {
+        Ok(sync) => log::info!(
+            "[sync_skill_remote] {:?} skill={} status={} ahead={} behind={} conflicts={}",
+            operation,
+            skill_name,
+            sync.status,
+            sync.ahead,
+            sync.behind,
+            sync.conflicts.len()
+        ),
+        Err(e) => log::error!(
+            "[sync_skill_remote] {:?} skill={} failed: {}",
+            operation,
+            skill_name,
+            e
+        ),
+    }
+    result
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
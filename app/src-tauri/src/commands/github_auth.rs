@@ -1,26 +1,61 @@
 use crate::db::Db;
-use crate::types::{DeviceFlowResponse, GitHubAuthResult, GitHubUser};
+use crate::github_host::GitHubHost;
+use crate::types::{AppSettings, DeviceFlowResponse, GitHubAuthResult, GitHubUser};
 
 // Public client ID — intentional for OAuth device flow; not a secret.
 const GITHUB_CLIENT_ID: &str = "Ov23lioPbQz4gAFxEfhM";
 
+/// OAuth client ID for the configured host. The built-in app only exists on
+/// github.com, so GitHub Enterprise needs its own app's client ID in settings.
+pub(crate) fn oauth_client_id(settings: &AppSettings, host: &GitHubHost) -> Result<String, String> {
+    match settings
+        .github_oauth_client_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+    {
+        Some(id) => Ok(id.to_string()),
+        None if host.is_public() => Ok(GITHUB_CLIENT_ID.to_string()),
+        None => Err(format!(
+            "Set the OAuth app client ID for {} in Settings before signing in.",
+            host.host
+        )),
+    }
+}
+
+/// Read the configured GitHub host and the client ID to use with it.
+fn device_flow_config(db: &Db) -> Result<(GitHubHost, String), String> {
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    let settings = crate::db::read_settings(&conn)?;
+    let host = GitHubHost::from_settings(&settings);
+    let client_id = oauth_client_id(&settings, &host)?;
+    Ok((host, client_id))
+}
+
 /// Start the GitHub Device Flow by requesting a device code.
 #[tauri::command]
-pub async fn github_start_device_flow() -> Result<DeviceFlowResponse, String> {
-    log::info!("[github_start_device_flow] starting device flow");
+pub async fn github_start_device_flow(
+    db: tauri::State<'_, Db>,
+) -> Result<DeviceFlowResponse, String> {
+    let (host, client_id) = device_flow_config(&db)?;
+    log::info!(
+        "[github_start_device_flow] starting device flow on {}",
+        host.host
+    );
     let client = reqwest::Client::new();
-    github_start_device_flow_inner(&client, "https://github.com").await
+    github_start_device_flow_inner(&client, &host.web_base, &client_id).await
 }
 
 /// Inner testable function: start device flow using the given base URL.
 pub(crate) async fn github_start_device_flow_inner(
     client: &reqwest::Client,
     base_url: &str,
+    client_id: &str,
 ) -> Result<DeviceFlowResponse, String> {
     let response = client
         .post(format!("{}/login/device/code", base_url))
         .header("Accept", "application/json")
-        .form(&[("client_id", GITHUB_CLIENT_ID), ("scope", "repo,read:user")])
+        .form(&[("client_id", client_id), ("scope", "repo,read:user")])
         .send()
         .await
         .map_err(|e| format!("Failed to start device flow: {e}"))?;
@@ -77,13 +112,15 @@ pub async fn github_poll_for_token(
     device_code: String,
 ) -> Result<GitHubAuthResult, String> {
     log::info!("[github_poll_for_token] polling for token");
+    let (host, client_id) = device_flow_config(&db)?;
     let client = reqwest::Client::new();
 
     let (result, maybe_token_and_user) = github_poll_for_token_full(
         &client,
         &device_code,
-        "https://github.com",
-        "https://api.github.com",
+        &client_id,
+        &host.web_base,
+        &host.api_base,
     )
     .await?;
 
@@ -105,6 +142,7 @@ pub async fn github_poll_for_token(
 pub(crate) async fn github_poll_for_token_full(
     client: &reqwest::Client,
     device_code: &str,
+    client_id: &str,
     github_base_url: &str,
     api_base_url: &str,
 ) -> Result<(GitHubAuthResult, Option<(String, GitHubUser)>), String> {
@@ -112,7 +150,7 @@ pub(crate) async fn github_poll_for_token_full(
         .post(format!("{}/login/oauth/access_token", github_base_url))
         .header("Accept", "application/json")
         .form(&[
-            ("client_id", GITHUB_CLIENT_ID),
+            ("client_id", client_id),
             ("device_code", device_code),
             ("grant_type", "urn:ietf:params:oauth:grant-type:device_code"),
         ])
@@ -159,8 +197,14 @@ pub(crate) async fn github_poll_for_token_inner(
     github_base_url: &str,
     api_base_url: &str,
 ) -> Result<GitHubAuthResult, String> {
-    let (result, _) =
-        github_poll_for_token_full(client, device_code, github_base_url, api_base_url).await?;
+    let (result, _) = github_poll_for_token_full(
+        client,
+        device_code,
+        GITHUB_CLIENT_ID,
+        github_base_url,
+        api_base_url,
+    )
+    .await?;
     Ok(result)
 }
 
@@ -404,7 +448,7 @@ mod tests {
             .await;
 
         let client = reqwest::Client::new();
-        let result = github_start_device_flow_inner(&client, &server.url()).await;
+        let result = github_start_device_flow_inner(&client, &server.url(), GITHUB_CLIENT_ID).await;
         assert!(result.is_ok());
         let resp = result.unwrap();
         assert_eq!(resp.device_code, "abc123");
@@ -424,7 +468,7 @@ mod tests {
             .await;
 
         let client = reqwest::Client::new();
-        let result = github_start_device_flow_inner(&client, &server.url()).await;
+        let result = github_start_device_flow_inner(&client, &server.url(), GITHUB_CLIENT_ID).await;
         assert!(result.is_err());
         let err = result.unwrap_err();
         assert!(err.contains("GitHub device flow error"), "got: {}", err);
@@ -449,9 +493,14 @@ mod tests {
             .await;
 
         let client = reqwest::Client::new();
-        let result =
-            github_poll_for_token_full(&client, "test-device-code", &server.url(), &server.url())
-                .await;
+        let result = github_poll_for_token_full(
+            &client,
+            "test-device-code",
+            GITHUB_CLIENT_ID,
+            &server.url(),
+            &server.url(),
+        )
+        .await;
         assert!(result.is_ok(), "expected Ok, got: {:?}", result);
 
         let (auth_result, token_user) = result.unwrap();
@@ -466,4 +515,77 @@ mod tests {
         assert_eq!(user.avatar_url, "https://avatar.example.com");
         assert_eq!(user.email, Some("octo@test.com".to_string()));
     }
+
+    #[test]
+    fn test_oauth_client_id_requires_setting_on_enterprise() {
+        let mut settings = AppSettings::default();
+        assert_eq!(
+            oauth_client_id(&settings, &GitHubHost::public()).unwrap(),
+            GITHUB_CLIENT_ID
+        );
+
+        let ghes = GitHubHost::enterprise("ghe.corp.example");
+        let err = oauth_client_id(&settings, &ghes).unwrap_err();
+        assert!(err.contains("ghe.corp.example"), "got: {}", err);
+
+        settings.github_oauth_client_id = Some(" Iv1.corp ".to_string());
+        assert_eq!(oauth_client_id(&settings, &ghes).unwrap(), "Iv1.corp");
+    }
+
+    #[tokio::test]
+    async fn test_enterprise_device_flow_uses_host_and_client_id() {
+        let mut server = mockito::Server::new_async().await;
+        let _code_mock = server
+            .mock("POST", "/login/device/code")
+            .match_body(mockito::Matcher::UrlEncoded(
+                "client_id".into(),
+                "Iv1.corp".into(),
+            ))
+            .with_status(200)
+            .with_body(
+                r#"{"device_code":"ghe-code","user_code":"WXYZ-9876","verification_uri":"https://ghe.corp.example/login/device","expires_in":900}"#,
+            )
+            .create_async()
+            .await;
+        let _token_mock = server
+            .mock("POST", "/login/oauth/access_token")
+            .match_body(mockito::Matcher::UrlEncoded(
+                "client_id".into(),
+                "Iv1.corp".into(),
+            ))
+            .with_status(200)
+            .with_body(r#"{"access_token":"ghe_token"}"#)
+            .create_async()
+            .await;
+        let _user_mock = server
+            .mock("GET", "/api/v3/user")
+            .match_header("authorization", "Bearer ghe_token")
+            .with_status(200)
+            .with_body(r#"{"login":"corp-dev","avatar_url":"https://ghe.corp.example/avatar"}"#)
+            .create_async()
+            .await;
+
+        let client = reqwest::Client::new();
+        let flow = github_start_device_flow_inner(&client, &server.url(), "Iv1.corp")
+            .await
+            .unwrap();
+        assert_eq!(
+            flow.verification_uri,
+            "https://ghe.corp.example/login/device"
+        );
+
+        let api_base = format!("{}/api/v3", server.url());
+        let (_, token_user) = github_poll_for_token_full(
+            &client,
+            &flow.device_code,
+            "Iv1.corp",
+            &server.url(),
+            &api_base,
+        )
+        .await
+        .unwrap();
+        let (token, user) = token_user.unwrap();
+        assert_eq!(token, "ghe_token");
+        assert_eq!(user.login, "corp-dev");
+    }
 }
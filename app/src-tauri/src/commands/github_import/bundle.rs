@@ -0,0 +1,448 @@
+//! Plugin bundles: a whole plugin exported as one zip archive.
+//!
+//! The archive is laid out as a single-plugin marketplace, so Claude Code can
+//! add the extracted folder as a marketplace and another Skill Builder can
+//! import it through the regular marketplace flow:
+//!
+//! ```text
+//! .claude-plugin/marketplace.json   lists only ./{slug}
+//! {slug}/.claude-plugin/plugin.json
+//! {slug}/skills/{skill}/...
+//! evals/scenarios.json              optional eval workbench scenarios
+//! ```
+
+use std::io::Write;
+use std::path::Path;
+
+use serde::{Deserialize, Serialize};
+
+use crate::db::eval_workbench::{EvalWorkbenchMode, SaveScenario, Scenario};
+use crate::db::Db;
+
+use super::commands::{import_plugin_inner, MarketplaceImportResult};
+use super::dependencies::resolve_plugin_dependencies;
+
+const MARKETPLACE_JSON_PATH: &str = ".claude-plugin/marketplace.json";
+const SCENARIOS_PATH: &str = "evals/scenarios.json";
+
+/// Eval scenario as stored in a bundle; the plugin slug is implied.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(rename_all = "camelCase")]
+struct BundleScenario {
+    skill_name: String,
+    name: String,
+    mode: EvalWorkbenchMode,
+    prompt: String,
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    should_trigger: Option<bool>,
+    #[serde(default)]
+    assertions: Vec<crate::commands::eval_workbench::assertions::Assertion>,
+}
+
+pub(crate) fn export_plugin_bundle_inner(
+    plugin_dir: &Path,
+    plugin_slug: &str,
+    scenarios: &[Scenario],
+    dest_path: &str,
+) -> Result<(), String> {
+    if !plugin_dir.is_dir() {
+        return Err(format!(
+            "Plugin directory not found: {}",
+            plugin_dir.display()
+        ));
+    }
+    if !plugin_dir.join("skills").is_dir() {
+        return Err("Not a valid plugin: skills/ missing".to_string());
+    }
+    let marketplace_json =
+        crate::marketplace_manifest::single_plugin_marketplace_json(plugin_dir, plugin_slug)?;
+
+    let file = std::fs::File::create(dest_path)
+        .map_err(|e| format!("Failed to create export file '{}': {}", dest_path, e))?;
+    let mut zip = zip::ZipWriter::new(file);
+    let options = zip::write::SimpleFileOptions::default()
+        .compression_method(zip::CompressionMethod::Deflated);
+    let mut add = |name: &str, content: &[u8]| -> Result<(), String> {
+        zip.start_file(name, options)
+            .map_err(|e| format!("Failed to add '{}' to archive: {}", name, e))?;
+        zip.write_all(content)
+            .map_err(|e| format!("Failed to write '{}' to archive: {}", name, e))
+    };
+
+    add(MARKETPLACE_JSON_PATH, marketplace_json.as_bytes())?;
+
+    // Per-skill git repos stay behind; the importer creates fresh ones.
+    for entry in walkdir::WalkDir::new(plugin_dir)
+        .follow_links(false)
+        .into_iter()
+        .filter_entry(|e| e.file_name() != ".git")
+        .filter_map(|e| e.ok())
+    {
+        if entry.file_type().is_symlink() || entry.file_type().is_dir() {
+            continue;
+        }
+        let path = entry.path();
+        let relative = path
+            .strip_prefix(plugin_dir)
+            .map_err(|e| e.to_string())?
+            .to_string_lossy()
+            .replace('\\', "/");
+        let content = std::fs::read(path)
+            .map_err(|e| format!("Failed to read '{}': {}", path.display(), e))?;
+        add(&format!("{}/{}", plugin_slug, relative), &content)?;
+    }
+
+    if !scenarios.is_empty() {
+        let bundled: Vec<BundleScenario> = scenarios
+            .iter()
+            .map(|s| BundleScenario {
+                skill_name: s.skill_name.clone(),
+                name: s.name.clone(),
+                mode: s.mode,
+                prompt: s.prompt.clone(),
+                should_trigger: s.should_trigger,
+                assertions: s.assertions.clone(),
+            })
+            .collect();
+        let json = serde_json::to_string_pretty(&bundled)
+            .map_err(|e| format!("Failed to serialize scenarios: {}", e))?;
+        add(SCENARIOS_PATH, json.as_bytes())?;
+    }
+
+    zip.finish()
+        .map_err(|e| format!("Failed to finalize archive: {}", e))?;
+    Ok(())
+}
+
+/// Export a library plugin — its skills, `plugin.json`, a single-plugin
+/// `marketplace.json` and optionally its eval scenarios — as one zip archive.
+#[tauri::command]
+pub fn export_plugin_bundle(
+    plugin_slug: String,
+    dest_path: String,
+    include_evals: bool,
+    db: tauri::State<'_, Db>,
+) -> Result<(), String> {
+    log::info!(
+        "[export_plugin_bundle] plugin_slug={} dest={} include_evals={}",
+        plugin_slug,
+        dest_path,
+        include_evals
+    );
+    let conn = db.0.lock().map_err(|e| {
+        log::error!("[export_plugin_bundle] failed to acquire DB lock: {}", e);
+        e.to_string()
+    })?;
+    let settings = crate::db::read_settings(&conn).map_err(|e| {
+        log::error!("[export_plugin_bundle] failed to read settings: {}", e);
+        e
+    })?;
+    let skills_path = settings
+        .skills_path
+        .ok_or_else(|| "Skills path not configured. Set it in Settings.".to_string())?;
+    let scenarios = if include_evals {
+        crate::db::eval_workbench::list_plugin_scenarios(&conn, &plugin_slug)?
+    } else {
+        Vec::new()
+    };
+    let plugin_dir = Path::new(&skills_path).join(&plugin_slug);
+    let result = export_plugin_bundle_inner(&plugin_dir, &plugin_slug, &scenarios, &dest_path);
+    if result.is_err() {
+        let _ = std::fs::remove_file(&dest_path);
+    }
+    result.map_err(|e| {
+        log::error!("[export_plugin_bundle] export failed: {}", e);
+        e
+    })?;
+    log::info!(
+        "[export_plugin_bundle] exported '{}' ({} scenario(s)) to '{}'",
+        plugin_slug,
+        scenarios.len(),
+        dest_path
+    );
+    Ok(())
+}
+
+/// Name and relative path of the one plugin listed in a bundle's marketplace.json.
+fn read_bundle_plugin(root: &Path) -> Result<(String, String), String> {
+    let content = std::fs::read_to_string(root.join(MARKETPLACE_JSON_PATH))
+        .map_err(|_| format!("Not a plugin bundle: {} missing", MARKETPLACE_JSON_PATH))?;
+    let manifest: serde_json::Value = serde_json::from_str(&content)
+        .map_err(|e| format!("Invalid {}: {}", MARKETPLACE_JSON_PATH, e))?;
+    let plugins = manifest
+        .get("plugins")
+        .and_then(|p| p.as_array())
+        .map(Vec::as_slice)
+        .unwrap_or_default();
+    let [plugin] = plugins else {
+        return Err(format!(
+            "Not a plugin bundle: expected exactly one plugin in {}, found {}",
+            MARKETPLACE_JSON_PATH,
+            plugins.len()
+        ));
+    };
+    let name = plugin.get("name").and_then(|v| v.as_str());
+    let source = plugin.get("source").and_then(|v| v.as_str());
+    match (name, source) {
+        (Some(name), Some(source)) if !name.is_empty() => {
+            let path = source
+                .strip_prefix("./")
+                .unwrap_or(source)
+                .trim_end_matches('/');
+            Ok((name.to_string(), path.to_string()))
+        }
+        _ => Err(format!(
+            "Not a plugin bundle: the plugin in {} needs a name and a relative source",
+            MARKETPLACE_JSON_PATH
+        )),
+    }
+}
+
+fn read_bundle_scenarios(root: &Path) -> Result<Vec<BundleScenario>, String> {
+    let path = root.join(SCENARIOS_PATH);
+    if !path.is_file() {
+        return Ok(Vec::new());
+    }
+    let content = std::fs::read_to_string(&path)
+        .map_err(|e| format!("Failed to read {}: {}", SCENARIOS_PATH, e))?;
+    serde_json::from_str(&content).map_err(|e| format!("Invalid {}: {}", SCENARIOS_PATH, e))
+}
+
+pub(crate) async fn import_plugin_bundle_inner(
+    db: &Db,
+    file_path: &str,
+) -> Result<Vec<MarketplaceImportResult>, String> {
+    let zip_file =
+        std::fs::File::open(file_path).map_err(|e| format!("Failed to open file: {}", e))?;
+    let mut archive =
+        zip::ZipArchive::new(zip_file).map_err(|_| "Not a valid plugin bundle".to_string())?;
+    let staging =
+        tempfile::tempdir().map_err(|e| format!("Failed to create staging directory: {}", e))?;
+    crate::commands::imported_skills::extract_archive(&mut archive, "", staging.path())?;
+
+    let (plugin_name, plugin_path) = read_bundle_plugin(staging.path())?;
+    let scenarios = read_bundle_scenarios(staging.path())?;
+    let staging_url = staging.path().to_string_lossy().to_string();
+
+    let (plugin_slug, mut results) =
+        import_plugin_inner(db, &staging_url, &plugin_path, &plugin_name, None).await?;
+    if !results.iter().any(|r| r.success) {
+        return Ok(results);
+    }
+
+    {
+        let mut conn = db.0.lock().map_err(|e| e.to_string())?;
+        // The staging directory is about to disappear; the restored plugin
+        // has no upstream to update from.
+        crate::db::mark_plugin_local(&conn, &plugin_slug)?;
+        let imported: Vec<&str> = results
+            .iter()
+            .filter(|r| r.success)
+            .map(|r| r.skill_name.as_str())
+            .collect();
+        let mut restored = 0;
+        for scenario in scenarios {
+            if !imported.contains(&scenario.skill_name.as_str()) {
+                log::warn!(
+                    "[import_plugin_bundle] skipping scenario '{}' for unknown skill '{}'",
+                    scenario.name,
+                    scenario.skill_name
+                );
+                continue;
+            }
+            crate::db::eval_workbench::save_scenario(
+                &mut conn,
+                SaveScenario {
+                    id: None,
+                    plugin_slug: plugin_slug.clone(),
+                    skill_name: scenario.skill_name,
+                    name: scenario.name,
+                    mode: scenario.mode,
+                    prompt: scenario.prompt,
+                    should_trigger: scenario.should_trigger,
+                    assertions: scenario.assertions,
+                },
+            )?;
+            restored += 1;
+        }
+        log::info!(
+            "[import_plugin_bundle] restored '{}' with {} scenario(s)",
+            plugin_slug,
+            restored
+        );
+    }
+
+    // Dependencies aren't bundled; fetch missing ones from enabled registries.
+    results.extend(resolve_plugin_dependencies(db, &plugin_slug, &staging_url).await);
+    Ok(results)
+}
+
+/// Import a plugin bundle written by `export_plugin_bundle` (or any zip laid
+/// out as a single-plugin marketplace) into the Skill Library.
+#[tauri::command]
+pub async fn import_plugin_bundle(
+    db: tauri::State<'_, Db>,
+    file_path: String,
+) -> Result<Vec<MarketplaceImportResult>, String> {
+    log::info!("[import_plugin_bundle] file_path={}", file_path);
+    import_plugin_bundle_inner(&db, &file_path)
+        .await
+        .map_err(|e| {
+            log::error!("[import_plugin_bundle] import failed: {}", e);
+            e
+        })
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use std::fs;
+
+    fn write_plugin(dir: &Path) {
+        fs::create_dir_all(dir.join(".claude-plugin")).unwrap();
+        fs::write(
+            dir.join(".claude-plugin/plugin.json"),
+            r#"{"name":"analytics","version":"1.2.0"}"#,
+        )
+        .unwrap();
+        let skill = dir.join("skills/revenue");
+        fs::create_dir_all(skill.join("references")).unwrap();
+        fs::write(
+            skill.join("SKILL.md"),
+            "---\nname: revenue\ndescription: Revenue metrics\n---\n# Body\n",
+        )
+        .unwrap();
+        fs::write(skill.join("references/glossary.md"), "# Glossary").unwrap();
+        crate::git::ensure_repo(&skill).unwrap();
+    }
+
+    fn test_db(skills_path: &Path) -> Db {
+        let conn = crate::db::create_test_db_for_tests();
+        let mut settings = crate::db::read_settings(&conn).unwrap();
+        settings.skills_path = Some(skills_path.to_string_lossy().to_string());
+        crate::db::write_settings(&conn, &settings).unwrap();
+        Db(std::sync::Arc::new(std::sync::Mutex::new(conn)))
+    }
+
+    fn scenario() -> Scenario {
+        Scenario {
+            id: "s1".to_string(),
+            plugin_slug: "analytics".to_string(),
+            skill_name: "revenue".to_string(),
+            name: "quarterly".to_string(),
+            mode: EvalWorkbenchMode::Performance,
+            prompt: "Summarize Q3 revenue".to_string(),
+            should_trigger: None,
+            sort_order: 0,
+            created_at: String::new(),
+            updated_at: String::new(),
+            assertions: vec!["mentions ARR".into()],
+        }
+    }
+
+    #[test]
+    fn export_lays_out_a_single_plugin_marketplace() {
+        let tmp = tempfile::tempdir().unwrap();
+        let plugin_dir = tmp.path().join("analytics");
+        write_plugin(&plugin_dir);
+        let dest = tmp.path().join("analytics.zip");
+
+        export_plugin_bundle_inner(&plugin_dir, "analytics", &[], dest.to_str().unwrap()).unwrap();
+
+        let mut archive = zip::ZipArchive::new(fs::File::open(&dest).unwrap()).unwrap();
+        let names: Vec<String> = archive.file_names().map(String::from).collect();
+        assert!(names.contains(&"analytics/.claude-plugin/plugin.json".to_string()));
+        assert!(names.contains(&"analytics/skills/revenue/SKILL.md".to_string()));
+        assert!(names.contains(&"analytics/skills/revenue/references/glossary.md".to_string()));
+        assert!(
+            !names.iter().any(|n| n.contains("/.git/")),
+            "git internals leaked: {:?}",
+            names
+        );
+        assert!(!names.contains(&SCENARIOS_PATH.to_string()));
+
+        let mut manifest = String::new();
+        std::io::Read::read_to_string(
+            &mut archive.by_name(MARKETPLACE_JSON_PATH).unwrap(),
+            &mut manifest,
+        )
+        .unwrap();
+        let manifest: serde_json::Value = serde_json::from_str(&manifest).unwrap();
+        assert_eq!(manifest["plugins"][0]["source"], "./analytics");
+        assert_eq!(manifest["plugins"][0]["version"], "1.2.0");
+    }
+
+    #[test]
+    fn export_fails_without_skills_dir() {
+        let tmp = tempfile::tempdir().unwrap();
+        let dest = tmp.path().join("out.zip");
+        let err = export_plugin_bundle_inner(tmp.path(), "empty", &[], dest.to_str().unwrap())
+            .unwrap_err();
+        assert!(err.contains("skills/ missing"), "got: {}", err);
+    }
+
+    #[tokio::test]
+    async fn bundle_round_trips_plugin_and_scenarios() {
+        let tmp = tempfile::tempdir().unwrap();
+        let plugin_dir = tmp.path().join("source/analytics");
+        write_plugin(&plugin_dir);
+        let dest = tmp.path().join("analytics.zip");
+        export_plugin_bundle_inner(
+            &plugin_dir,
+            "analytics",
+            &[scenario()],
+            dest.to_str().unwrap(),
+        )
+        .unwrap();
+
+        let skills_path = tmp.path().join("library");
+        let db = test_db(&skills_path);
+        let results = import_plugin_bundle_inner(&db, dest.to_str().unwrap())
+            .await
+            .unwrap();
+
+        assert_eq!(results.len(), 1, "got: {:?}", results);
+        assert!(results[0].success);
+        assert_eq!(results[0].skill_name, "revenue");
+        assert!(skills_path
+            .join("analytics/skills/revenue/references/glossary.md")
+            .is_file());
+
+        let conn = db.0.lock().unwrap();
+        let plugin = crate::db::list_plugins(&conn)
+            .unwrap()
+            .into_iter()
+            .find(|p| p.slug == "analytics")
+            .unwrap();
+        assert_eq!(plugin.source_type, "local");
+        assert_eq!(plugin.source_url, None);
+        assert_eq!(plugin.version.as_deref(), Some("1.2.0"));
+
+        let scenarios =
+            crate::db::eval_workbench::list_plugin_scenarios(&conn, "analytics").unwrap();
+        assert_eq!(scenarios.len(), 1);
+        assert_eq!(scenarios[0].name, "quarterly");
+        assert_eq!(scenarios[0].assertions, vec!["mentions ARR".into()]);
+    }
+
+    #[tokio::test]
+    async fn import_rejects_archive_without_marketplace_json() {
+        let tmp = tempfile::tempdir().unwrap();
+        let skill_dir = tmp.path().join("skill");
+        fs::create_dir_all(&skill_dir).unwrap();
+        fs::write(skill_dir.join("SKILL.md"), "---\nname: solo\n---\n").unwrap();
+        let dest = tmp.path().join("solo.skill");
+        crate::commands::skill::export_skill_as_file_inner(
+            &skill_dir,
+            dest.to_str().unwrap(),
+            false,
+        )
+        .unwrap();
+
+        let db = test_db(&tmp.path().join("library"));
+        let err = import_plugin_bundle_inner(&db, dest.to_str().unwrap())
+            .await
+            .unwrap_err();
+        assert!(err.contains("Not a plugin bundle"), "got: {}", err);
+    }
+}
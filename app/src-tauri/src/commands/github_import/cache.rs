@@ -0,0 +1,413 @@
+//! Cached GETs for marketplace catalog reads.
+//!
+//! Browsing a registry (repo info, the recursive tree, `marketplace.json`,
+//! `SKILL.md` frontmatter) is repeated on every settings visit and update
+//! check. Responses are kept in the `http_cache` table keyed by URL and a
+//! hash of the credentials they were fetched with, and revalidated with
+//! `If-None-Match`, so an unchanged catalog costs 304s that don't count
+//! against GitHub's rate limit. When the limit is exhausted the host is backed
+//! off until its reset time and the cached body is served; in offline mode the
+//! network is never touched. Entries not confirmed fresh for
+//! [`CACHE_TTL_DAYS`] are pruned.
+//!
+//! Imports stay uncached: they always download the files they write.
+
+use std::collections::HashMap;
+use std::sync::{Mutex, OnceLock};
+
+use reqwest::header::{ETAG, IF_NONE_MATCH, RETRY_AFTER};
+use reqwest::StatusCode;
+
+use sha2::Digest;
+
+use crate::db::{
+    db_get_http_cache, db_prune_http_cache, db_put_http_cache, db_touch_http_cache, Db,
+    HttpCacheEntry,
+};
+use crate::types::AppSettings;
+
+/// Backoff when a host reports an exhausted limit without saying when it resets.
+const DEFAULT_BACKOFF_SECS: i64 = 60;
+
+/// Days a cached response survives without being fetched or revalidated.
+const CACHE_TTL_DAYS: i64 = 30;
+
+/// How a rate-limited GitHub user gets a higher limit.
+pub(crate) const GITHUB_SIGN_IN_HINT: &str = "Sign in to GitHub for a higher limit";
+
+/// Host (`host[:port]`) → epoch seconds until which requests are not sent.
+fn backoffs() -> &'static Mutex<HashMap<String, i64>> {
+    static BACKOFFS: OnceLock<Mutex<HashMap<String, i64>>> = OnceLock::new();
+    BACKOFFS.get_or_init(|| Mutex::new(HashMap::new()))
+}
+
+/// Where catalog responses are cached, and whether the network may be used.
+#[derive(Clone)]
+pub(crate) struct CatalogCache {
+    db: Db,
+    offline: bool,
+    /// Hash of the token requests are sent with; `None` when anonymous.
+    identity: Option<String>,
+    /// Appended to rate-limit errors to say how to get a higher limit.
+    sign_in_hint: Option<String>,
+}
+
+impl CatalogCache {
+    pub(crate) fn new(db: Db, offline: bool) -> Self {
+        CatalogCache {
+            db,
+            offline,
+            identity: None,
+            sign_in_hint: None,
+        }
+    }
+
+    pub(crate) fn from_settings(db: &Db, settings: &AppSettings) -> Self {
+        Self::new(db.clone(), settings.marketplace_offline)
+    }
+
+    /// This cache for requests sent with `token`. Entries are keyed by a hash
+    /// of it, so a response fetched with one account's access is never served
+    /// to another account or to anonymous requests.
+    pub(crate) fn scoped(&self, token: Option<&str>, sign_in_hint: impl Into<String>) -> Self {
+        CatalogCache {
+            identity: token.map(|t| hex::encode(&sha2::Sha256::digest(t.as_bytes())[..8])),
+            sign_in_hint: Some(sign_in_hint.into()),
+            ..self.clone()
+        }
+    }
+
+    fn key(&self, url: &str) -> String {
+        format!(
+            "{}#auth={}",
+            url,
+            self.identity.as_deref().unwrap_or("none")
+        )
+    }
+
+    fn lookup(&self, url: &str) -> Result<Option<HttpCacheEntry>, String> {
+        let conn = self.db.0.lock().map_err(|e| e.to_string())?;
+        db_get_http_cache(&conn, &self.key(url))
+    }
+
+    fn store(&self, url: &str, etag: Option<&str>, body: &[u8]) {
+        let result = self
+            .db
+            .0
+            .lock()
+            .map_err(|e| e.to_string())
+            .and_then(|conn| {
+                db_put_http_cache(&conn, &self.key(url), etag, body)?;
+                db_prune_http_cache(&conn, CACHE_TTL_DAYS)
+            });
+        match result {
+            Ok(0) => {}
+            Ok(pruned) => log::debug!("[catalog_cache] pruned {} stale entries", pruned),
+            Err(e) => log::warn!("[catalog_cache] failed to store {}: {}", url, e),
+        }
+    }
+
+    fn touch(&self, url: &str) {
+        let result = self
+            .db
+            .0
+            .lock()
+            .map_err(|e| e.to_string())
+            .and_then(|conn| db_touch_http_cache(&conn, &self.key(url)));
+        if let Err(e) = result {
+            log::warn!("[catalog_cache] failed to refresh {}: {}", url, e);
+        }
+    }
+}
+
+/// Send `request` for `url`, revalidating against and filling `cache` when given.
+///
+/// Returns the status and body. A 304 (or a cache hit while offline or backed
+/// off) is reported as `200 OK` with the cached body. Without a cache this is a
+/// plain send, apart from rate-limit awareness.
+pub(crate) async fn send_cached(
+    request: reqwest::RequestBuilder,
+    url: &str,
+    cache: Option<&CatalogCache>,
+) -> Result<(StatusCode, Vec<u8>), String> {
+    let cached = match cache {
+        Some(cache) => cache.lookup(url).unwrap_or_else(|e| {
+            log::warn!("[catalog_cache] failed to read {}: {}", url, e);
+            None
+        }),
+        None => None,
+    };
+
+    if cache.is_some_and(|c| c.offline) {
+        return match cached {
+            Some(entry) => Ok((StatusCode::OK, entry.body)),
+            None => Err(format!(
+                "Offline mode: {} has not been cached yet. Turn off offline mode in Settings to fetch it.",
+                url
+            )),
+        };
+    }
+
+    let host = url_host(url);
+    if let Some(reset) = backoff_until(&host) {
+        return match cached {
+            Some(entry) => {
+                log::info!(
+                    "[catalog_cache] {} is rate limited; serving cached {}",
+                    host,
+                    url
+                );
+                Ok((StatusCode::OK, entry.body))
+            }
+            None => Err(rate_limit_message(&host, reset, cache)),
+        };
+    }
+
+    let request = match cached.as_ref().and_then(|e| e.etag.as_deref()) {
+        Some(etag) => request.header(IF_NONE_MATCH, etag),
+        None => request,
+    };
+    let response = match request.send().await {
+        Ok(response) => response,
+        Err(e) => {
+            return match cached {
+                Some(entry) => {
+                    log::warn!(
+                        "[catalog_cache] request to {} failed ({}); serving cached copy from {}",
+                        url,
+                        e,
+                        entry.fetched_at
+                    );
+                    Ok((StatusCode::OK, entry.body))
+                }
+                None => Err(e.to_string()),
+            };
+        }
+    };
+
+    let status = response.status();
+    if status == StatusCode::NOT_MODIFIED {
+        if let (Some(cache), Some(entry)) = (cache, cached.as_ref()) {
+            cache.touch(url);
+            return Ok((StatusCode::OK, entry.body.clone()));
+        }
+    }
+
+    if let Some(reset) = exhausted_until(&response) {
+        log::warn!(
+            "[catalog_cache] rate limit exhausted for {} (HTTP {}); backing off until {}",
+            host,
+            status,
+            reset
+        );
+        backoffs()
+            .lock()
+            .map_err(|e| e.to_string())?
+            .insert(host.clone(), reset);
+        return match cached {
+            Some(entry) => Ok((StatusCode::OK, entry.body)),
+            None => Err(rate_limit_message(&host, reset, cache)),
+        };
+    }
+
+    let etag = response
+        .headers()
+        .get(ETAG)
+        .and_then(|v| v.to_str().ok())
+        .map(str::to_string);
+    let body = response.bytes().await.map_err(|e| e.to_string())?.to_vec();
+    if status.is_success() {
+        if let Some(cache) = cache {
+            cache.store(url, etag.as_deref(), &body);
+        }
+    }
+    Ok((status, body))
+}
+
+fn url_host(url: &str) -> String {
+    url.split_once("://")
+        .map_or(url, |(_, rest)| rest)
+        .split('/')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase()
+}
+
+/// The active backoff for `host`, clearing it once it has passed.
+fn backoff_until(host: &str) -> Option<i64> {
+    let mut backoffs = backoffs().lock().ok()?;
+    match backoffs.get(host) {
+        Some(&reset) if reset > chrono::Utc::now().timestamp() => Some(reset),
+        Some(_) => {
+            backoffs.remove(host);
+            None
+        }
+        None => None,
+    }
+}
+
+/// When a 403/429 says the rate limit is exhausted, the epoch second it resets.
+fn exhausted_until(response: &reqwest::Response) -> Option<i64> {
+    let status = response.status();
+    if status != StatusCode::FORBIDDEN && status != StatusCode::TOO_MANY_REQUESTS {
+        return None;
+    }
+    let header = |name: &str| {
+        response
+            .headers()
+            .get(name)
+            .and_then(|v| v.to_str().ok())
+            .and_then(|v| v.trim().parse::<i64>().ok())
+    };
+    let now = chrono::Utc::now().timestamp();
+    if let Some(secs) = header(RETRY_AFTER.as_str()) {
+        return Some(now + secs.max(1));
+    }
+    if header("x-ratelimit-remaining") == Some(0) {
+        return Some(header("x-ratelimit-reset").unwrap_or(now + DEFAULT_BACKOFF_SECS));
+    }
+    (status == StatusCode::TOO_MANY_REQUESTS).then_some(now + DEFAULT_BACKOFF_SECS)
+}
+
+fn rate_limit_message(host: &str, reset: i64, cache: Option<&CatalogCache>) -> String {
+    let at = chrono::DateTime::from_timestamp(reset, 0)
+        .map(|t| t.format("%H:%M:%S UTC").to_string())
+        .unwrap_or_else(|| "later".to_string());
+    let hint = cache
+        .and_then(|c| c.sign_in_hint.clone())
+        .unwrap_or_else(|| format!("Use an access token for {} for a higher limit", host));
+    format!(
+        "API rate limit exhausted for {}; it resets at {}. {}, or turn on offline mode in Settings to browse cached catalogs.",
+        host, at, hint
+    )
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn test_cache(offline: bool) -> CatalogCache {
+        let conn = crate::db::create_test_db_for_tests();
+        CatalogCache::new(Db(std::sync::Arc::new(Mutex::new(conn))), offline)
+    }
+
+    async fn get(url: &str, cache: Option<&CatalogCache>) -> Result<(StatusCode, Vec<u8>), String> {
+        send_cached(reqwest::Client::new().get(url), url, cache).await
+    }
+
+    #[tokio::test]
+    async fn revalidates_with_etag_and_serves_cached_body_on_304() {
+        let mut server = mockito::Server::new_async().await;
+        let url = format!("{}/repos/acme/skills", server.url());
+        let first = server
+            .mock("GET", "/repos/acme/skills")
+            .match_header("if-none-match", mockito::Matcher::Missing)
+            .with_status(200)
+            .with_header("etag", "\"v1\"")
+            .with_body(r#"{"default_branch":"main"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+        let revalidate = server
+            .mock("GET", "/repos/acme/skills")
+            .match_header("if-none-match", "\"v1\"")
+            .with_status(304)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let cache = test_cache(false);
+        let (status, body) = get(&url, Some(&cache)).await.unwrap();
+        assert_eq!(status, StatusCode::OK);
+        let (status, again) = get(&url, Some(&cache)).await.unwrap();
+        assert_eq!(status, StatusCode::OK);
+        assert_eq!(again, body);
+        first.assert_async().await;
+        revalidate.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn offline_mode_serves_cache_without_network() {
+        let mut server = mockito::Server::new_async().await;
+        let url = format!("{}/catalog.json", server.url());
+        let online = server
+            .mock("GET", "/catalog.json")
+            .with_status(200)
+            .with_body("{}")
+            .expect(1)
+            .create_async()
+            .await;
+
+        let cache = test_cache(false);
+        get(&url, Some(&cache)).await.unwrap();
+        let offline = CatalogCache::new(cache.db.clone(), true);
+        assert_eq!(get(&url, Some(&offline)).await.unwrap().1, b"{}");
+        let missing = format!("{}/other.json", server.url());
+        let err = get(&missing, Some(&offline)).await.unwrap_err();
+        assert!(err.contains("Offline mode"), "got: {err}");
+        online.assert_async().await;
+    }
+
+    #[tokio::test]
+    async fn exhausted_rate_limit_backs_off_until_reset() {
+        let mut server = mockito::Server::new_async().await;
+        let reset = chrono::Utc::now().timestamp() + 3600;
+        let limited = server
+            .mock("GET", mockito::Matcher::Any)
+            .with_status(403)
+            .with_header("x-ratelimit-remaining", "0")
+            .with_header("x-ratelimit-reset", &reset.to_string())
+            .with_body(r#"{"message":"API rate limit exceeded"}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let url = format!("{}/repos/acme/skills", server.url());
+        let github = test_cache(false).scoped(None, GITHUB_SIGN_IN_HINT);
+        let err = get(&url, Some(&github)).await.unwrap_err();
+        assert!(err.contains("rate limit exhausted"), "got: {err}");
+        // Backed off: later requests never reach the server.
+        let tree = format!("{}/repos/acme/skills/git/trees/main", server.url());
+        let err = get(&tree, Some(&github)).await.unwrap_err();
+        assert!(err.contains("Sign in to GitHub"), "got: {err}");
+        // Other hosts don't tell the user to sign in to GitHub.
+        let err = get(&tree, None).await.unwrap_err();
+        assert!(!err.contains("GitHub"), "got: {err}");
+        assert!(err.contains(&url_host(&url)), "got: {err}");
+        limited.assert_async().await;
+        // mockito reuses ports across tests; don't leave this host backed off.
+        backoffs().lock().unwrap().remove(&url_host(&url));
+    }
+
+    #[tokio::test]
+    async fn responses_are_not_shared_across_tokens() {
+        let mut server = mockito::Server::new_async().await;
+        let url = format!("{}/repos/acme/private", server.url());
+        let private = server
+            .mock("GET", "/repos/acme/private")
+            .with_status(200)
+            .with_body(r#"{"private":true}"#)
+            .expect(1)
+            .create_async()
+            .await;
+
+        let cache = test_cache(false);
+        get(
+            &url,
+            Some(&cache.scoped(Some("alice-token"), GITHUB_SIGN_IN_HINT)),
+        )
+        .await
+        .unwrap();
+        private.assert_async().await;
+
+        let offline = CatalogCache::new(cache.db.clone(), true);
+        let alice = offline.scoped(Some("alice-token"), GITHUB_SIGN_IN_HINT);
+        assert_eq!(
+            get(&url, Some(&alice)).await.unwrap().1,
+            br#"{"private":true}"#
+        );
+        let bob = offline.scoped(Some("bob-token"), GITHUB_SIGN_IN_HINT);
+        assert!(get(&url, Some(&bob)).await.is_err());
+        assert!(get(&url, Some(&offline)).await.is_err());
+    }
+}
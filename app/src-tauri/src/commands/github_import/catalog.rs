@@ -26,6 +26,95 @@ pub(crate) fn resolve_plugin_path(
     }
 }
 
+/// Resolve an object-form plugin `source` to the repo that hosts the plugin,
+/// plus the plugin directory inside that repo (`""` = repo root).
+///
+/// Supported shapes:
+/// - `{"source": "github", "repo": "owner/repo"}`
+/// - `{"source": "git" | "url", "url": "<any marketplace source URL>"}` — GitHub,
+///   GitLab, Gitea or any git remote, parsed like a marketplace URL
+///
+/// Each may carry a `ref` (branch or tag) and/or a `sha`; the commit pin wins when
+/// both are present. An optional `path` selects a subdirectory. Package sources
+/// (`npm`, `pip`) and local folders are rejected.
+pub(crate) fn resolve_external_source(
+    source: &str,
+    extra: &serde_json::Value,
+) -> Result<(crate::types::ExternalPluginSource, String), String> {
+    use super::source::{parse_repo_source, RepoSource};
+
+    let field = |key: &str| extra[key].as_str().map(str::trim).filter(|v| !v.is_empty());
+
+    let (owner, repo, url) = match source {
+        "github" => {
+            let info = super::url::parse_github_url_inner(
+                field("repo").ok_or("github source is missing 'repo'")?,
+            )?;
+            (info.owner, info.repo, None)
+        }
+        "git" | "url" => {
+            let url = field("url").ok_or_else(|| format!("{} source is missing 'url'", source))?;
+            let normalized = url
+                .strip_prefix("git@github.com:")
+                .map(|rest| format!("https://github.com/{}", rest))
+                .unwrap_or_else(|| url.to_string());
+            match parse_repo_source(&normalized, &crate::github_host::GitHubHost::public())? {
+                RepoSource::GitHub(info, _) => (info.owner, info.repo, None),
+                RepoSource::Local { .. } => {
+                    return Err(format!("local plugin source '{}' is not supported", url))
+                }
+                other => {
+                    let (owner, repo) = repo_owner_and_name(&other);
+                    (owner, repo, Some(url.to_string()))
+                }
+            }
+        }
+        other => return Err(format!("unsupported source type '{}'", other)),
+    };
+
+    let path = field("path")
+        .map(|p| p.trim_start_matches("./").trim_matches('/').to_string())
+        .unwrap_or_default();
+    if path.split('/').any(|segment| segment == "..") {
+        return Err(format!("invalid plugin path '{}'", path));
+    }
+
+    Ok((
+        crate::types::ExternalPluginSource {
+            owner,
+            repo,
+            url,
+            git_ref: field("sha").or_else(|| field("ref")).map(str::to_string),
+        },
+        path,
+    ))
+}
+
+/// Namespace and repo name of a source, for display.
+fn repo_owner_and_name(source: &super::source::RepoSource) -> (String, String) {
+    use super::source::RepoSource;
+
+    match source {
+        RepoSource::GitLab { project, .. } => match project.rsplit_once('/') {
+            Some((namespace, name)) => (namespace.to_string(), name.to_string()),
+            None => (String::new(), project.clone()),
+        },
+        RepoSource::Gitea { owner, repo, .. } => (owner.clone(), repo.clone()),
+        RepoSource::Git { url, .. } => {
+            let path = url.split_once("://").map_or(url.as_str(), |(_, rest)| rest);
+            let owner = path
+                .trim_end_matches('/')
+                .rsplit(['/', ':'])
+                .nth(1)
+                .unwrap_or_default()
+                .to_string();
+            (owner, source.label())
+        }
+        RepoSource::GitHub(info, _) => (info.owner.clone(), info.repo.clone()),
+        RepoSource::Local { .. } => (String::new(), source.label()),
+    }
+}
+
 /// Pure plugin-discovery kernel: return marketplace plugins using only marketplace.json.
 /// No skill-level discovery or fallback naming is performed here.
 pub(crate) fn discover_plugins_from_catalog(
@@ -38,40 +127,75 @@ pub(crate) fn discover_plugins_from_catalog(
     let mut result = Vec::new();
 
     for plugin in plugins {
-        let source_str = match &plugin.source {
-            MarketplacePluginSource::Path(s) => s,
-            MarketplacePluginSource::External { source, .. } => {
-                let name = plugin.name.as_deref().unwrap_or("<unnamed>");
-                log::warn!(
-                    "[discover_plugins] skipping plugin '{}' — unsupported source type '{}'",
-                    name,
-                    source
-                );
-                continue;
+        let (path, external_source) = match &plugin.source {
+            MarketplacePluginSource::Path(s) => {
+                (resolve_plugin_path(s, plugin_root, subpath), None)
+            }
+            MarketplacePluginSource::External { source, extra } => {
+                match resolve_external_source(source, extra) {
+                    Ok((external, path)) => (path, Some(external)),
+                    Err(e) => {
+                        let name = plugin.name.as_deref().unwrap_or("<unnamed>");
+                        log::warn!("[discover_plugins] skipping plugin '{}' — {}", name, e);
+                        continue;
+                    }
+                }
             }
         };
 
         let Some(name) = plugin.name.as_ref().filter(|n| !n.trim().is_empty()) else {
             log::debug!(
                 "[discover_plugins] skipping plugin with source '{}' — missing marketplace entry name",
-                source_str
+                path
             );
             continue;
         };
 
         result.push(AvailablePlugin {
-            path: resolve_plugin_path(source_str, plugin_root, subpath),
+            path,
             name: name.clone(),
             description: plugin.description.clone(),
             version: plugin.version.clone(),
             skill_count: 0,
             skill_names: vec![],
+            external_source,
         });
     }
 
     result
 }
 
+/// Collect the `checksums` declared by marketplace plugins, keyed by
+/// repository-relative path. Plugins hosted in other repos are skipped — they
+/// are pinned by commit instead.
+pub(crate) fn declared_checksums(
+    plugins: &[crate::types::MarketplacePlugin],
+    plugin_root: Option<&str>,
+    subpath: Option<&str>,
+) -> std::collections::HashMap<String, String> {
+    use crate::types::MarketplacePluginSource;
+
+    let mut result = std::collections::HashMap::new();
+    for plugin in plugins {
+        let (MarketplacePluginSource::Path(source), Some(checksums)) =
+            (&plugin.source, &plugin.checksums)
+        else {
+            continue;
+        };
+        let plugin_path = resolve_plugin_path(source, plugin_root, subpath);
+        for (file, digest) in checksums {
+            let file = file.trim_start_matches("./").trim_start_matches('/');
+            let path = if plugin_path.is_empty() {
+                file.to_string()
+            } else {
+                format!("{}/{}", plugin_path, file)
+            };
+            result.insert(path, digest.clone());
+        }
+    }
+    result
+}
+
 /// Pure skill-discovery kernel: given a marketplace catalog and a pre-built set of
 /// repository-relative directory paths that contain a `SKILL.md` blob, return all
 /// importable [`AvailableSkill`] entries.
@@ -90,7 +214,8 @@ pub(crate) fn discover_plugins_from_catalog(
 ///    the same way.
 /// 3. `subpath` is always prepended to anchor sources to the repo root.
 ///
-/// External source types (`github`, `npm`, `pip`, `url`) are skipped with a warning.
+/// External source types (`github`, `npm`, `pip`, `url`) are skipped with a warning;
+/// those plugins live in other repositories and are imported at plugin level.
 /// Plugin entries that yield no skills are silently skipped (logged at `debug`).
 /// No fallback paths are attempted.
 pub(crate) fn discover_skills_from_catalog(
@@ -261,21 +386,27 @@ mod tests {
             author: None,
             category: None,
             tags: None,
+            checksums: None,
         }
     }
 
-    fn make_external_plugin(name: &str) -> crate::types::MarketplacePlugin {
+    fn make_external_plugin(
+        name: &str,
+        source: &str,
+        extra: serde_json::Value,
+    ) -> crate::types::MarketplacePlugin {
         crate::types::MarketplacePlugin {
             name: Some(name.to_string()),
             source: crate::types::MarketplacePluginSource::External {
-                source: "npm".to_string(),
-                extra: serde_json::json!({}),
+                source: source.to_string(),
+                extra,
             },
             description: None,
             version: None,
             author: None,
             category: None,
             tags: None,
+            checksums: None,
         }
     }
 
@@ -289,12 +420,120 @@ mod tests {
     }
 
     #[test]
-    fn discover_plugins_skips_external_source() {
-        let plugins = vec![make_external_plugin("npm-plugin")];
+    fn discover_plugins_skips_package_source() {
+        let plugins = vec![make_external_plugin(
+            "npm-plugin",
+            "npm",
+            serde_json::json!({"package": "@acme/plugin"}),
+        )];
         let result = discover_plugins_from_catalog(&plugins, None, None);
         assert!(result.is_empty());
     }
 
+    #[test]
+    fn discover_plugins_resolves_github_source_with_pin() {
+        let plugins = vec![make_external_plugin(
+            "remote",
+            "github",
+            serde_json::json!({"repo": "acme/remote-plugin", "ref": "v2", "sha": "abc123"}),
+        )];
+        let result = discover_plugins_from_catalog(&plugins, Some("plugins"), Some("sub"));
+        assert_eq!(result.len(), 1);
+        assert_eq!(result[0].path, "");
+        let external = result[0].external_source.as_ref().unwrap();
+        assert_eq!(external.owner, "acme");
+        assert_eq!(external.repo, "remote-plugin");
+        assert_eq!(external.git_ref.as_deref(), Some("abc123"));
+    }
+
+    #[test]
+    fn resolve_external_git_url_with_ref_and_path() {
+        let (external, path) = resolve_external_source(
+            "url",
+            &serde_json::json!({
+                "url": "https://github.com/acme/monorepo.git",
+                "ref": "release",
+                "path": "./plugins/remote/"
+            }),
+        )
+        .unwrap();
+        assert_eq!(external.owner, "acme");
+        assert_eq!(external.repo, "monorepo");
+        assert_eq!(external.git_ref.as_deref(), Some("release"));
+        assert_eq!(path, "plugins/remote");
+
+        let (ssh, _) = resolve_external_source(
+            "git",
+            &serde_json::json!({"url": "git@github.com:acme/remote.git"}),
+        )
+        .unwrap();
+        assert_eq!((ssh.owner.as_str(), ssh.repo.as_str()), ("acme", "remote"));
+        assert_eq!(ssh.url, None);
+        assert_eq!(ssh.git_ref, None);
+    }
+
+    #[test]
+    fn resolve_external_accepts_other_git_hosts() {
+        let (gitlab, path) = resolve_external_source(
+            "git",
+            &serde_json::json!({
+                "url": "https://gitlab.com/acme/tools/remote.git",
+                "sha": "0123456789abcdef0123456789abcdef01234567",
+                "path": "plugin"
+            }),
+        )
+        .unwrap();
+        assert_eq!(
+            (gitlab.owner.as_str(), gitlab.repo.as_str()),
+            ("acme/tools", "remote")
+        );
+        assert_eq!(
+            gitlab.url.as_deref(),
+            Some("https://gitlab.com/acme/tools/remote.git")
+        );
+        assert_eq!(
+            gitlab.git_ref.as_deref(),
+            Some("0123456789abcdef0123456789abcdef01234567")
+        );
+        assert_eq!(path, "plugin");
+
+        let (gitea, _) = resolve_external_source(
+            "url",
+            &serde_json::json!({"url": "https://codeberg.org/acme/remote"}),
+        )
+        .unwrap();
+        assert_eq!(
+            (gitea.owner.as_str(), gitea.repo.as_str()),
+            ("acme", "remote")
+        );
+
+        let (git, _) = resolve_external_source(
+            "git",
+            &serde_json::json!({"url": "git@git.example.com:acme/remote.git"}),
+        )
+        .unwrap();
+        assert_eq!((git.owner.as_str(), git.repo.as_str()), ("acme", "remote"));
+        assert_eq!(
+            git.url.as_deref(),
+            Some("git@git.example.com:acme/remote.git")
+        );
+    }
+
+    #[test]
+    fn resolve_external_rejects_local_paths_and_traversal() {
+        assert!(resolve_external_source(
+            "git",
+            &serde_json::json!({"url": "file:///home/me/plugin"})
+        )
+        .is_err());
+        assert!(resolve_external_source(
+            "github",
+            &serde_json::json!({"repo": "acme/remote", "path": "../escape"})
+        )
+        .is_err());
+        assert!(resolve_external_source("github", &serde_json::json!({})).is_err());
+    }
+
     #[test]
     fn discover_plugins_skips_missing_name() {
         let plugins = vec![make_plugin(None, "./orphan")];
@@ -2,128 +2,127 @@ use std::collections::{HashMap, HashSet};
 use std::path::Path;
 
 use crate::db::Db;
+use crate::github_host::GitHubAccess;
 use crate::skill_paths::DEFAULT_PLUGIN_SLUG;
-use crate::types::{AvailablePlugin, AvailableSkill};
+use crate::types::{AvailablePlugin, AvailableSkill, GitHubRepoInfo};
 
+use super::cache::{CatalogCache, GITHUB_SIGN_IN_HINT};
 use super::catalog::{
     discover_plugins_from_catalog, discover_skills_from_catalog, extract_plugin_path,
 };
-use super::http::{build_github_client, fetch_repo_tree, get_default_branch};
-use super::import::{compute_skill_content_hash, import_single_skill, merge_imported_fields};
-use super::url::{marketplace_manifest_path, parse_github_url_inner};
+use super::dependencies::resolve_plugin_dependencies;
+use super::http::{build_github_client, get_default_branch};
+use super::import::{
+    compute_skill_content_hash, compute_skill_file_manifest, import_single_skill,
+    merge_imported_fields,
+};
+use super::integrity::pin_for_import;
+use super::source::{parse_repo_source, RepoSource, SourceSnapshot};
+use super::url::marketplace_manifest_path;
 
 // ---------------------------------------------------------------------------
 // check_marketplace_url
 // ---------------------------------------------------------------------------
 
-/// Verify that a URL points to an accessible GitHub repository that contains
-/// a valid `.claude-plugin/marketplace.json` file.
-///
-/// Unlike `list_github_skills`, this uses the repos API (`GET /repos/{owner}/{repo}`)
-/// which succeeds regardless of the default branch name. This avoids the 404
-/// that occurs when the repo's default branch is not "main".
+/// Verify that a marketplace source (GitHub, GitLab, Gitea, any git remote or a
+/// local folder) is reachable and contains a valid `.claude-plugin/marketplace.json`.
 ///
-/// After confirming the repo is accessible it fetches
-/// `.claude-plugin/marketplace.json` via `raw.githubusercontent.com` and
-/// returns a clear error if the file is missing or not valid JSON.
+/// For GitHub this uses the repos API (`GET /repos/{owner}/{repo}`) to resolve the
+/// default branch, which avoids the 404 that occurs when it is not "main".
 /// Returns the `name` field from `.claude-plugin/marketplace.json`, falling back
-/// to `"{owner}/{repo}"` if the field is absent.
+/// to the source label (e.g. `"{owner}/{repo}"`) if the field is absent.
 #[tauri::command]
 pub async fn check_marketplace_url(
     db: tauri::State<'_, Db>,
     url: String,
 ) -> Result<String, String> {
     log::info!("[check_marketplace_url] url={}", url);
-    let repo_info = parse_github_url_inner(&url)?;
-    let token = {
+    let (github, cache) = {
         let conn = db.0.lock().map_err(|e| {
             log::error!("[check_marketplace_url] failed to acquire DB lock: {}", e);
             e.to_string()
         })?;
         let settings = crate::db::read_settings(&conn)?;
-        settings.github_oauth_token.clone()
+        (
+            GitHubAccess::from_settings(&settings),
+            CatalogCache::from_settings(&db, &settings),
+        )
     };
-    let client = build_github_client(token.as_deref());
-    let owner = &repo_info.owner;
-    let repo = &repo_info.repo;
-    let resolved_branch = get_default_branch(&client, owner, repo).await?;
-
-    // Verify that .claude-plugin/marketplace.json exists and is valid JSON.
-    // Respect any subpath in the URL (e.g. /tree/main/plugins → plugins/.claude-plugin/marketplace.json).
-    let manifest_path = marketplace_manifest_path(repo_info.subpath.as_deref());
-    let raw_url = format!(
-        "https://raw.githubusercontent.com/{}/{}/{}/{}",
-        owner, repo, resolved_branch, manifest_path
-    );
-    log::info!(
-        "[check_marketplace_url] fetching marketplace.json from {}/{} branch={}",
-        owner,
-        repo,
-        resolved_branch
-    );
-
-    let not_found_msg = format!(
-        "marketplace.json not found at {} in {}/{}. Ensure the repository has this file.",
-        manifest_path, owner, repo
-    );
+    let source = parse_repo_source(&url, &github.host)?;
 
-    let response = client.get(&raw_url).send().await.map_err(|e| {
-        log::error!(
-            "[check_marketplace_url] failed to fetch marketplace.json for {}/{}: {}",
-            owner,
-            repo,
-            e
-        );
-        format!("Failed to reach {}/{}: {}", owner, repo, e)
-    })?;
-
-    if !response.status().is_success() {
-        log::error!(
-            "[check_marketplace_url] marketplace.json not found for {}/{}: HTTP {}",
-            owner,
-            repo,
-            response.status()
-        );
-        return Err(not_found_msg);
+    // Listing falls back to the URL's branch when the repos API fails; here an
+    // auth or not-found error must surface instead of "marketplace.json not found".
+    if let RepoSource::GitHub(info, host) = &source {
+        let client = build_github_client(github.token_for(host));
+        get_default_branch(
+            &client,
+            &host.api_base,
+            &info.owner,
+            &info.repo,
+            Some(&cache.scoped(github.token_for(host), GITHUB_SIGN_IN_HINT)),
+        )
+        .await
+        .map_err(|e| {
+            log::error!("[check_marketplace_url] {}", e);
+            format!("Cannot access {}/{}: {}", info.owner, info.repo, e)
+        })?;
     }
 
-    let body = response.text().await.map_err(|e| {
-        log::error!(
-            "[check_marketplace_url] failed to read marketplace.json body for {}/{}: {}",
-            owner,
-            repo,
-            e
-        );
-        format!("Failed to read marketplace.json: {}", e)
-    })?;
-
-    let manifest = serde_json::from_str::<crate::types::MarketplaceJson>(&body).map_err(|e| {
-        log::error!(
-            "[check_marketplace_url] marketplace.json is not valid JSON for {}/{}: {}",
-            owner,
-            repo,
+    let (snapshot, manifest) = read_marketplace_manifest(&source, &github, Some(&cache))
+        .await
+        .map_err(|e| {
+            log::error!("[check_marketplace_url] {}", e);
             e
-        );
-        format!(
-            "marketplace.json at {} in {}/{} is not valid JSON.",
-            manifest_path, owner, repo
-        )
-    })?;
+        })?;
 
     let name = manifest
         .name
         .filter(|n| !n.trim().is_empty())
-        .unwrap_or_else(|| format!("{}/{}", owner, repo));
+        .unwrap_or_else(|| snapshot.label.clone());
 
     log::info!(
-        "[check_marketplace_url] marketplace.json validated for {}/{} name={}",
-        owner,
-        repo,
+        "[check_marketplace_url] marketplace.json validated for {} branch={} name={}",
+        snapshot.label,
+        snapshot.branch,
         name
     );
     Ok(name)
 }
 
+/// Open a marketplace source and parse its `.claude-plugin/marketplace.json`,
+/// honouring any subpath in the source URL.
+async fn read_marketplace_manifest(
+    source: &RepoSource,
+    github: &GitHubAccess,
+    cache: Option<&CatalogCache>,
+) -> Result<(SourceSnapshot, crate::types::MarketplaceJson), String> {
+    let snapshot = SourceSnapshot::open_cached(source, github, cache.cloned()).await?;
+    let manifest_path = marketplace_manifest_path(source.subpath());
+    log::info!(
+        "[read_marketplace_manifest] fetching {} from {} branch={}",
+        manifest_path,
+        snapshot.label,
+        snapshot.branch
+    );
+
+    let body = snapshot.read_text(&manifest_path).await.map_err(|e| {
+        log::debug!("[read_marketplace_manifest] {}", e);
+        format!(
+            "marketplace.json not found at {} in {}. Ensure the repository has this file.",
+            manifest_path, snapshot.label
+        )
+    })?;
+
+    let manifest = serde_json::from_str::<crate::types::MarketplaceJson>(&body).map_err(|e| {
+        log::debug!("[read_marketplace_manifest] parse error: {}", e);
+        format!(
+            "marketplace.json at {} in {} is not valid JSON.",
+            manifest_path, snapshot.label
+        )
+    })?;
+    Ok((snapshot, manifest))
+}
+
 // ---------------------------------------------------------------------------
 // list_github_skills
 // ---------------------------------------------------------------------------
@@ -144,19 +143,28 @@ pub async fn list_github_skills(
         branch,
         subpath
     );
-    // Read OAuth token if available
-    let token = {
+    // Read the GitHub host and OAuth token if available
+    let (github, cache) = {
         let conn = db.0.lock().map_err(|e| {
             log::error!("[list_github_skills] failed to acquire DB lock: {}", e);
             e.to_string()
         })?;
         let settings = crate::db::read_settings(&conn)?;
-        settings.github_oauth_token.clone()
+        (
+            GitHubAccess::from_settings(&settings),
+            CatalogCache::from_settings(&db, &settings),
+        )
     };
 
-    let (_, skills) =
-        list_github_skills_inner(&owner, &repo, &branch, subpath.as_deref(), token.as_deref())
-            .await?;
+    let (_, skills) = list_github_skills_inner(
+        &owner,
+        &repo,
+        &branch,
+        subpath.as_deref(),
+        &github,
+        Some(&cache),
+    )
+    .await?;
     Ok(skills)
 }
 
@@ -175,18 +183,27 @@ pub async fn list_github_plugins(
         branch,
         subpath
     );
-    let token = {
+    let (github, cache) = {
         let conn = db.0.lock().map_err(|e| {
             log::error!("[list_github_plugins] failed to acquire DB lock: {}", e);
             e.to_string()
         })?;
         let settings = crate::db::read_settings(&conn)?;
-        settings.github_oauth_token.clone()
+        (
+            GitHubAccess::from_settings(&settings),
+            CatalogCache::from_settings(&db, &settings),
+        )
     };
 
-    let (_, plugins) =
-        list_github_plugins_inner(&owner, &repo, &branch, subpath.as_deref(), token.as_deref())
-            .await?;
+    let (_, plugins) = list_github_plugins_inner(
+        &owner,
+        &repo,
+        &branch,
+        subpath.as_deref(),
+        &github,
+        Some(&cache),
+    )
+    .await?;
     Ok(plugins)
 }
 
@@ -195,80 +212,83 @@ pub(crate) async fn list_github_plugins_inner(
     repo: &str,
     branch: &str,
     subpath: Option<&str>,
-    token: Option<&str>,
+    github: &GitHubAccess,
+    cache: Option<&CatalogCache>,
 ) -> Result<(Option<String>, Vec<AvailablePlugin>), String> {
-    let client = build_github_client(token);
-    let resolved_branch = if branch.is_empty() {
-        get_default_branch(&client, owner, repo)
-            .await
-            .unwrap_or_else(|_| "main".to_string())
-    } else {
-        get_default_branch(&client, owner, repo)
-            .await
-            .unwrap_or_else(|_| branch.to_string())
-    };
-
-    let manifest_path = marketplace_manifest_path(subpath);
-    let raw_url = format!(
-        "https://raw.githubusercontent.com/{}/{}/{}/{}",
-        owner, repo, resolved_branch, manifest_path
-    );
+    let source = github_source(owner, repo, branch, subpath, github);
+    list_source_plugins_inner(&source, github, cache).await
+}
 
-    let response = client.get(&raw_url).send().await.map_err(|e| {
-        log::error!(
-            "[list_github_plugins_inner] failed to fetch marketplace.json for {}/{}: {}",
-            owner,
-            repo,
-            e
-        );
-        format!(
-            "marketplace.json not found at {} in {}/{}. Ensure the repository has this file.",
-            manifest_path, owner, repo
+/// List the plugins in any marketplace source, identified by its registry URL.
+#[tauri::command]
+pub async fn list_marketplace_plugins(
+    db: tauri::State<'_, Db>,
+    source_url: String,
+) -> Result<Vec<AvailablePlugin>, String> {
+    log::info!("[list_marketplace_plugins] source={}", source_url);
+    let (github, cache) = {
+        let conn = db.0.lock().map_err(|e| {
+            log::error!(
+                "[list_marketplace_plugins] failed to acquire DB lock: {}",
+                e
+            );
+            e.to_string()
+        })?;
+        let settings = crate::db::read_settings(&conn)?;
+        (
+            GitHubAccess::from_settings(&settings),
+            CatalogCache::from_settings(&db, &settings),
         )
-    })?;
+    };
+    let source = parse_repo_source(&source_url, &github.host)?;
 
-    let status = response.status();
-    if !status.is_success() {
-        log::error!(
-            "[list_github_plugins_inner] failed to fetch marketplace.json for {}/{}: HTTP {}",
-            owner,
-            repo,
-            status
-        );
-        return Err(format!(
-            "marketplace.json not found at {} in {}/{}. Ensure the repository has this file.",
-            manifest_path, owner, repo
-        ));
-    }
+    let (_, plugins) = list_source_plugins_inner(&source, &github, Some(&cache)).await?;
+    Ok(plugins)
+}
 
-    let body = response.text().await.map_err(|e| {
-        log::error!(
-            "[list_github_plugins_inner] failed to read marketplace.json body for {}/{}: {}",
-            owner,
-            repo,
-            e
-        );
-        format!("Failed to read marketplace.json: {}", e)
-    })?;
+/// Build the source used by the owner/repo-based list commands on the
+/// configured GitHub host. An empty `branch` is a placeholder for the default branch.
+fn github_source(
+    owner: &str,
+    repo: &str,
+    branch: &str,
+    subpath: Option<&str>,
+    github: &GitHubAccess,
+) -> RepoSource {
+    let info = GitHubRepoInfo {
+        owner: owner.to_string(),
+        repo: repo.to_string(),
+        branch: if branch.is_empty() {
+            "main".to_string()
+        } else {
+            branch.to_string()
+        },
+        subpath: subpath.map(str::to_string),
+    };
+    RepoSource::GitHub(info, github.host.clone())
+}
 
-    let marketplace: crate::types::MarketplaceJson = serde_json::from_str(&body).map_err(|e| {
-        log::error!(
-            "[list_github_plugins_inner] failed to parse marketplace.json for {}/{}: {}",
-            owner,
-            repo,
+pub(crate) async fn list_source_plugins_inner(
+    source: &RepoSource,
+    github: &GitHubAccess,
+    cache: Option<&CatalogCache>,
+) -> Result<(Option<String>, Vec<AvailablePlugin>), String> {
+    let (_, marketplace) = read_marketplace_manifest(source, github, cache)
+        .await
+        .map_err(|e| {
+            log::error!("[list_source_plugins_inner] {}", e);
             e
-        );
-        format!("Failed to parse marketplace.json: {}", e)
-    })?;
+        })?;
 
     let plugin_root = marketplace
         .metadata
         .as_ref()
         .and_then(|m| m.plugin_root.as_deref());
-    let plugins = discover_plugins_from_catalog(&marketplace.plugins, plugin_root, subpath);
+    let plugins =
+        discover_plugins_from_catalog(&marketplace.plugins, plugin_root, source.subpath());
 
     log::info!(
-        "[list_github_plugins_inner] returning {} plugins from marketplace {}",
+        "[list_source_plugins_inner] returning {} plugins from marketplace {}",
         plugins.len(),
         marketplace.name.as_deref().unwrap_or("unknown")
     );
@@ -281,85 +301,28 @@ pub(crate) async fn list_github_skills_inner(
     repo: &str,
     branch: &str,
     subpath: Option<&str>,
-    token: Option<&str>,
+    github: &GitHubAccess,
+    cache: Option<&CatalogCache>,
 ) -> Result<(Option<String>, Vec<AvailableSkill>), String> {
-    let client = build_github_client(token);
-
-    // Resolve the actual default branch when the caller passed a placeholder.
-    let resolved_branch = if branch.is_empty() {
-        get_default_branch(&client, owner, repo)
-            .await
-            .unwrap_or_else(|_| "main".to_string())
-    } else {
-        get_default_branch(&client, owner, repo)
-            .await
-            .unwrap_or_else(|_| branch.to_string())
-    };
-
-    // Fetch .claude-plugin/marketplace.json via raw.githubusercontent.com.
-    // Respect any subpath in the URL (e.g. /tree/main/plugins → plugins/.claude-plugin/marketplace.json).
-    let manifest_path = marketplace_manifest_path(subpath);
-    let raw_url = format!(
-        "https://raw.githubusercontent.com/{}/{}/{}/{}",
-        owner, repo, resolved_branch, manifest_path
-    );
-
-    log::info!(
-        "[list_github_skills_inner] fetching marketplace.json from {}/{} branch={}",
-        owner,
-        repo,
-        resolved_branch
-    );
-
-    let response = client.get(&raw_url).send().await.map_err(|e| {
-        log::error!(
-            "[list_github_skills_inner] failed to fetch marketplace.json for {}/{}: {}",
-            owner,
-            repo,
-            e
-        );
-        format!(
-            "marketplace.json not found at {} in {}/{}. Ensure the repository has this file.",
-            manifest_path, owner, repo
-        )
-    })?;
-
-    let status = response.status();
-    if !status.is_success() {
-        log::error!(
-            "[list_github_skills_inner] failed to fetch marketplace.json for {}/{}: HTTP {}",
-            owner,
-            repo,
-            status
-        );
-        return Err(format!(
-            "marketplace.json not found at {} in {}/{}. Ensure the repository has this file.",
-            manifest_path, owner, repo
-        ));
-    }
-
-    let body = response.text().await.map_err(|e| {
-        log::error!(
-            "[list_github_skills_inner] failed to read marketplace.json body for {}/{}: {}",
-            owner,
-            repo,
-            e
-        );
-        format!("Failed to read marketplace.json: {}", e)
-    })?;
+    let source = github_source(owner, repo, branch, subpath, github);
+    list_source_skills_inner(&source, github, cache).await
+}
 
-    let marketplace: crate::types::MarketplaceJson = serde_json::from_str(&body).map_err(|e| {
-        log::error!(
-            "[list_github_skills_inner] failed to parse marketplace.json for {}/{}: {}",
-            owner,
-            repo,
+pub(crate) async fn list_source_skills_inner(
+    source: &RepoSource,
+    github: &GitHubAccess,
+    cache: Option<&CatalogCache>,
+) -> Result<(Option<String>, Vec<AvailableSkill>), String> {
+    let subpath = source.subpath();
+    let (snapshot, marketplace) = read_marketplace_manifest(source, github, cache)
+        .await
+        .map_err(|e| {
+            log::error!("[list_source_skills_inner] {}", e);
             e
-        );
-        format!("Failed to parse marketplace.json: {}", e)
-    })?;
+        })?;
 
     // Fetch the repo tree to discover which skill directories exist.
-    let (_, tree) = fetch_repo_tree(&client, owner, repo, &resolved_branch).await?;
+    let tree = snapshot.tree().await?;
 
     // Build the set of directories that own a SKILL.md blob in the tree.
     let skill_dirs: std::collections::HashSet<String> = tree
@@ -381,10 +344,9 @@ pub(crate) async fn list_github_skills_inner(
         discover_skills_from_catalog(&marketplace.plugins, plugin_root, &skill_dirs, subpath);
 
     log::info!(
-        "[list_github_skills_inner] found {} candidate skills from catalog in {}/{} (registry={})",
+        "[list_source_skills_inner] found {} candidate skills from catalog in {} (registry={})",
         skills.len(),
-        owner,
-        repo,
+        snapshot.label,
         marketplace.name.as_deref().unwrap_or("unknown")
     );
 
@@ -392,23 +354,9 @@ pub(crate) async fn list_github_skills_inner(
     let fetch_fns: Vec<_> = skills
         .iter()
         .map(|skill| {
-            let client = client.clone();
-            let url = format!(
-                "https://raw.githubusercontent.com/{}/{}/{}/{}/SKILL.md",
-                owner, repo, resolved_branch, skill.path
-            );
-            async move {
-                match client
-                    .get(&url)
-                    .header("Cache-Control", "no-cache")
-                    .header("Pragma", "no-cache")
-                    .send()
-                    .await
-                {
-                    Ok(resp) if resp.status().is_success() => resp.text().await.ok(),
-                    _ => None,
-                }
-            }
+            let path = format!("{}/SKILL.md", skill.path);
+            let snapshot = &snapshot;
+            async move { snapshot.read_text(&path).await.ok() }
         })
         .collect();
 
@@ -434,7 +382,7 @@ pub(crate) async fn list_github_skills_inner(
                     }
                     None => {
                         log::debug!(
-                            "[list_github_skills_inner] skipping skill at '{}': no 'name' field in SKILL.md frontmatter",
+                            "[list_source_skills_inner] skipping skill at '{}': no 'name' field in SKILL.md frontmatter",
                             skill.path
                         );
                     }
@@ -442,7 +390,7 @@ pub(crate) async fn list_github_skills_inner(
             }
             None => {
                 log::debug!(
-                    "[list_github_skills_inner] skipping skill at '{}': SKILL.md could not be fetched",
+                    "[list_source_skills_inner] skipping skill at '{}': SKILL.md could not be fetched",
                     skill.path
                 );
             }
@@ -460,29 +408,24 @@ pub(crate) async fn list_github_skills_inner(
     let plugin_json_fns: Vec<_> = unique_plugin_paths
         .iter()
         .map(|pp| {
-            let client = client.clone();
             let plugin_json_path = if pp.is_empty() {
                 ".claude-plugin/plugin.json".to_string()
             } else {
                 format!("{}/.claude-plugin/plugin.json", pp)
             };
-            let url = format!(
-                "https://raw.githubusercontent.com/{}/{}/{}/{}",
-                owner, repo, resolved_branch, plugin_json_path
-            );
             let pp = pp.clone();
+            let snapshot = &snapshot;
             async move {
-                let name = match client.get(&url).send().await {
-                    Ok(resp) if resp.status().is_success() => {
-                        resp.text().await.ok().and_then(|body| {
-                            serde_json::from_str::<serde_json::Value>(&body)
-                                .ok()
-                                .and_then(|v| v["name"].as_str().map(|s| s.to_string()))
-                                .filter(|n| !n.trim().is_empty())
-                        })
-                    }
-                    _ => None,
-                };
+                let name = snapshot
+                    .read_text(&plugin_json_path)
+                    .await
+                    .ok()
+                    .and_then(|body| {
+                        serde_json::from_str::<serde_json::Value>(&body)
+                            .ok()
+                            .and_then(|v| v["name"].as_str().map(|s| s.to_string()))
+                            .filter(|n| !n.trim().is_empty())
+                    });
                 (pp, name)
             }
         })
@@ -500,7 +443,7 @@ pub(crate) async fn list_github_skills_inner(
     }
 
     log::info!(
-        "[list_github_skills_inner] returning {} skills after frontmatter filtering",
+        "[list_source_skills_inner] returning {} skills after frontmatter filtering",
         final_skills.len()
     );
 
@@ -524,7 +467,7 @@ async fn import_marketplace_entries_to_library(
     );
 
     // Read settings
-    let (skills_path, token) = {
+    let (skills_path, github) = {
         let conn = db.0.lock().map_err(|e| {
             log::error!(
                 "[import_marketplace_entries_to_library] failed to acquire DB lock: {}",
@@ -539,18 +482,17 @@ async fn import_marketplace_entries_to_library(
             );
             e
         })?;
+        let github = GitHubAccess::from_settings(&settings);
         let sp = settings
             .skills_path
             .ok_or_else(|| "Skills path not configured. Set it in Settings.".to_string())?;
-        (sp, settings.github_oauth_token.clone())
+        (sp, github)
     };
 
-    let repo_info = parse_github_url_inner(&source_url)?;
-    let owner = &repo_info.owner;
-    let repo = &repo_info.repo;
-
-    let client = build_github_client(token.as_deref());
-    let (branch, tree) = fetch_repo_tree(&client, owner, repo, &repo_info.branch).await?;
+    let source = parse_repo_source(&source_url, &github.host)?;
+    let mut snapshot = SourceSnapshot::open(&source, &github).await?;
+    let source_commit = pin_for_import(&mut snapshot, source.subpath()).await;
+    let tree = snapshot.tree().await?;
 
     let skills_root = Path::new(&skills_path);
     let mut results: Vec<MarketplaceImportResult> = Vec::new();
@@ -569,11 +511,7 @@ async fn import_marketplace_entries_to_library(
             .as_ref()
             .and_then(|m| m.get(skill_path.as_str()));
         match import_single_skill(
-            &client,
-            "https://raw.githubusercontent.com",
-            owner,
-            repo,
-            &branch,
+            &snapshot,
             skill_path,
             &tree,
             skills_root,
@@ -591,6 +529,8 @@ async fn import_marketplace_entries_to_library(
                         results.push(MarketplaceImportResult {
                             skill_name: missing_skill_name.clone(),
                             success: false,
+                            dependency_of: None,
+                            missing_dependency: None,
                             error: Some(format!(
                                 "Imported skill '{}' is missing a version after normalization",
                                 missing_skill_name
@@ -633,11 +573,14 @@ async fn import_marketplace_entries_to_library(
                         &skill.skill_name,
                         &final_version,
                     )?;
+                    crate::git::record_upstream_import(&skill_dir)?;
                     Ok(())
                 })() {
                     results.push(MarketplaceImportResult {
                         skill_name: skill.skill_name,
                         success: false,
+                        dependency_of: None,
+                        missing_dependency: None,
                         error: Some(e),
                     });
                     continue;
@@ -678,6 +621,8 @@ async fn import_marketplace_entries_to_library(
                     results.push(MarketplaceImportResult {
                         skill_name: skill.skill_name,
                         success: false,
+                        dependency_of: None,
+                        missing_dependency: None,
                         error: Some(e),
                     });
                     continue;
@@ -690,10 +635,24 @@ async fn import_marketplace_entries_to_library(
                         log::warn!("[import_marketplace_entries_to_library] failed to set content_hash for '{}': {}", skill.skill_name, e);
                     }
                 }
+                if let Err(e) = crate::db::set_imported_skill_integrity(
+                    &conn,
+                    skill.skill_id,
+                    source_commit.as_deref(),
+                    &compute_skill_file_manifest(&skill.disk_path),
+                ) {
+                    log::warn!(
+                        "[import_marketplace_entries_to_library] failed to record integrity for '{}': {}",
+                        skill.skill_name,
+                        e
+                    );
+                }
 
                 results.push(MarketplaceImportResult {
                     skill_name: skill.skill_name,
                     success: true,
+                    dependency_of: None,
+                    missing_dependency: None,
                     error: None,
                 });
             }
@@ -701,6 +660,8 @@ async fn import_marketplace_entries_to_library(
                 results.push(MarketplaceImportResult {
                     skill_name: skill_path.clone(),
                     success: false,
+                    dependency_of: None,
+                    missing_dependency: None,
                     error: Some(e),
                 });
             }
@@ -747,6 +708,13 @@ pub struct MarketplaceImportResult {
     pub skill_name: String,
     pub success: bool,
     pub error: Option<String>,
+    /// Set on results for a plugin pulled in as a dependency: the plugin that required it.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub dependency_of: Option<String>,
+    /// Set on a failed dependency result instead of `skill_name`: the plugin
+    /// that could not be installed.
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub missing_dependency: Option<String>,
 }
 
 /// Import one or more skills from a marketplace registry into the Skill Library.
@@ -772,44 +740,114 @@ pub async fn import_marketplace_to_library(
 /// 2. Read plugin.json from disk for metadata
 /// 3. Enumerate skills from the downloaded skills/ directory
 /// 4. Create DB rows: plugin → skills → imported_skills
+///
+/// Dependencies the plugin declares in `plugin.json` are then resolved and any
+/// missing ones imported; their results carry `dependency_of`.
 #[tauri::command]
 pub async fn import_marketplace_plugin_to_library(
     db: tauri::State<'_, Db>,
     source_url: String,
     plugin_path: String,
     plugin_name: String,
+    external_source: Option<crate::types::ExternalPluginSource>,
 ) -> Result<Vec<MarketplaceImportResult>, String> {
+    let (plugin_slug, mut results) = import_plugin_inner(
+        &db,
+        &source_url,
+        &plugin_path,
+        &plugin_name,
+        external_source,
+    )
+    .await?;
+    if results.iter().any(|r| r.success) {
+        results.extend(resolve_plugin_dependencies(&db, &plugin_slug, &source_url).await);
+    }
+    Ok(results)
+}
+
+/// Open the repo hosting an external plugin at its pinned ref. `owner/repo`
+/// entries live on the marketplace's GitHub host, or the configured one.
+async fn open_external_snapshot(
+    external: &crate::types::ExternalPluginSource,
+    marketplace: &RepoSource,
+    github: &GitHubAccess,
+) -> Result<SourceSnapshot, String> {
+    let (host, owner, repo) = match external.url.as_deref() {
+        Some(url) => match parse_repo_source(url, &github.host)? {
+            RepoSource::GitHub(info, host) => (host, info.owner, info.repo),
+            RepoSource::Local { .. } => {
+                return Err(format!("Local plugin source '{}' is not supported", url))
+            }
+            other => {
+                return SourceSnapshot::open(&other.at_ref(external.git_ref.as_deref()), github)
+                    .await
+            }
+        },
+        None => {
+            let host = match marketplace {
+                RepoSource::GitHub(_, host) => host.clone(),
+                _ => github.host.clone(),
+            };
+            (host, external.owner.clone(), external.repo.clone())
+        }
+    };
+    let client = build_github_client(github.token_for(&host));
+    let git_ref = match &external.git_ref {
+        Some(pinned) => pinned.clone(),
+        None => get_default_branch(&client, &host.api_base, &owner, &repo, None).await?,
+    };
+    Ok(SourceSnapshot::github(
+        client, &host, &owner, &repo, &git_ref,
+    ))
+}
+
+/// Import one plugin without resolving its dependencies. Returns the new
+/// plugin's slug with the per-skill results.
+pub(crate) async fn import_plugin_inner(
+    db: &Db,
+    source_url: &str,
+    plugin_path: &str,
+    plugin_name: &str,
+    external_source: Option<crate::types::ExternalPluginSource>,
+) -> Result<(String, Vec<MarketplaceImportResult>), String> {
     use super::import::{
         compute_skill_content_hash, download_plugin_directory, enumerate_plugin_skills,
         read_plugin_json,
     };
 
     log::info!(
-        "[import_marketplace_plugin_to_library] plugin='{}' path='{}' source='{}'",
+        "[import_marketplace_plugin_to_library] plugin='{}' path='{}' source='{}' external={:?}",
         plugin_name,
         plugin_path,
-        source_url
+        source_url,
+        external_source
     );
 
-    let repo_info = parse_github_url_inner(&source_url)?;
-    let (skills_path, token) = {
+    let (skills_path, github) = {
         let conn = db.0.lock().map_err(|e| e.to_string())?;
         let settings = crate::db::read_settings(&conn)?;
+        let github = GitHubAccess::from_settings(&settings);
         let sp = settings.skills_path.ok_or("Skills path not configured")?;
-        (sp, settings.github_oauth_token.clone())
+        (sp, github)
     };
+    let source = parse_repo_source(source_url, &github.host)?;
 
-    let client = build_github_client(token.as_deref());
-    let (branch, tree) = fetch_repo_tree(
-        &client,
-        &repo_info.owner,
-        &repo_info.repo,
-        &repo_info.branch,
-    )
-    .await?;
+    // Plugins hosted in another repo are downloaded from that repo at their pinned
+    // ref; everything else comes from the marketplace source itself.
+    let mut snapshot = match &external_source {
+        Some(external) => open_external_snapshot(external, &source, &github).await?,
+        None => SourceSnapshot::open(&source, &github).await?,
+    };
+    // Checksums in marketplace.json describe this repo, not external ones;
+    // those are pinned by their `sha` instead.
+    let source_commit = match &external_source {
+        Some(_) => snapshot.pin().await,
+        None => pin_for_import(&mut snapshot, source.subpath()).await,
+    };
+    let tree = snapshot.tree().await?;
 
     let skills_root = Path::new(&skills_path);
-    let plugin_slug = crate::db::slugify_plugin_name(&plugin_name);
+    let plugin_slug = crate::db::slugify_plugin_name(plugin_name);
 
     // Reject if a plugin with this slug already exists
     {
@@ -821,22 +859,12 @@ pub async fn import_marketplace_plugin_to_library(
 
     // --- Step 1: Download the entire plugin directory to disk ---
     let dest_plugin_dir = skills_root.join(&plugin_slug);
-    download_plugin_directory(
-        &client,
-        "https://raw.githubusercontent.com",
-        &repo_info.owner,
-        &repo_info.repo,
-        &branch,
-        &plugin_path,
-        &tree,
-        &dest_plugin_dir,
-    )
-    .await?;
+    download_plugin_directory(&snapshot, plugin_path, &tree, &dest_plugin_dir).await?;
 
     // --- Step 2: Read plugin.json from disk for metadata ---
     let (pj_name, _pj_description, pj_version) = read_plugin_json(&dest_plugin_dir);
     let display_name = if pj_name.is_empty() {
-        plugin_name.clone()
+        plugin_name.to_string()
     } else {
         pj_name
     };
@@ -848,10 +876,15 @@ pub async fn import_marketplace_plugin_to_library(
         &plugin_slug,
         &display_name,
         "marketplace",
-        Some(&source_url),
+        Some(source_url),
         pj_version.as_deref(),
         false,
     )?;
+    crate::db::set_plugin_dependencies(
+        &conn,
+        &plugin_slug,
+        &crate::marketplace_manifest::read_plugin_dependencies(&dest_plugin_dir),
+    )?;
 
     // --- Step 4: Enumerate skills from the downloaded plugin directory ---
     let skills = enumerate_plugin_skills(&dest_plugin_dir);
@@ -874,6 +907,8 @@ pub async fn import_marketplace_plugin_to_library(
                 results.push(MarketplaceImportResult {
                     skill_name: skill_name.clone(),
                     success: false,
+                    dependency_of: None,
+                    missing_dependency: None,
                     error: Some(format!("Failed to read SKILL.md: {}", e)),
                 });
                 continue;
@@ -899,6 +934,8 @@ pub async fn import_marketplace_plugin_to_library(
                 results.push(MarketplaceImportResult {
                     skill_name: skill_name.clone(),
                     success: false,
+                    dependency_of: None,
+                    missing_dependency: None,
                     error: Some(format!("Failed to create skill master: {}", e)),
                 });
                 continue;
@@ -933,7 +970,7 @@ pub async fn import_marketplace_plugin_to_library(
             version: Some(version.to_string()),
             user_invocable: fm.user_invocable,
             disable_model_invocation: fm.disable_model_invocation,
-            marketplace_source_url: Some(source_url.clone()),
+            marketplace_source_url: Some(source_url.to_string()),
             plugin_slug: Some(plugin_slug.clone()),
             plugin_display_name: Some(display_name.clone()),
             is_default_plugin: Some(false),
@@ -943,6 +980,8 @@ pub async fn import_marketplace_plugin_to_library(
             results.push(MarketplaceImportResult {
                 skill_name: skill_name.clone(),
                 success: false,
+                dependency_of: None,
+                missing_dependency: None,
                 error: Some(e),
             });
             continue;
@@ -965,6 +1004,7 @@ pub async fn import_marketplace_plugin_to_library(
             {
                 crate::git::create_skill_version_tag(skill_dir, &plugin_slug, skill_name, version)?;
             }
+            crate::git::record_upstream_import(skill_dir)?;
             Ok(())
         })() {
             log::warn!(
@@ -973,10 +1013,24 @@ pub async fn import_marketplace_plugin_to_library(
                 e
             );
         }
+        if let Err(e) = crate::db::set_imported_skill_integrity(
+            &conn,
+            skill_master_id,
+            source_commit.as_deref(),
+            &compute_skill_file_manifest(&skill_dir.to_string_lossy()),
+        ) {
+            log::warn!(
+                "[import_marketplace_plugin_to_library] failed to record integrity for '{}': {}",
+                skill_name,
+                e
+            );
+        }
 
         results.push(MarketplaceImportResult {
             skill_name: skill_name.clone(),
             success: true,
+            dependency_of: None,
+            missing_dependency: None,
             error: None,
         });
     }
@@ -991,7 +1045,7 @@ pub async fn import_marketplace_plugin_to_library(
         }
     }
 
-    Ok(results)
+    Ok((plugin_slug, results))
 }
 
 // ---------------------------------------------------------------------------
@@ -0,0 +1,311 @@
+//! Plugin-to-plugin dependencies.
+//!
+//! A plugin's `plugin.json` may declare `"dependencies": {"data-glossary": "^1.2"}`.
+//! After a plugin is imported each range is checked against the installed
+//! plugins, and missing dependencies are imported from the marketplace the
+//! plugin came from, then from the other enabled registries. Resolution is
+//! breadth-first over newly imported plugins and never imports a slug twice,
+//! so dependency cycles terminate.
+
+use std::collections::{HashSet, VecDeque};
+use std::path::Path;
+
+use crate::db::Db;
+use crate::github_host::GitHubAccess;
+use crate::marketplace_manifest::{dependency_satisfied, read_plugin_dependencies};
+use crate::types::AvailablePlugin;
+
+use super::cache::CatalogCache;
+use super::commands::{import_plugin_inner, list_source_plugins_inner, MarketplaceImportResult};
+use super::source::parse_repo_source;
+
+/// One unresolved edge: `required_by` needs plugin `name` at `version_req`.
+struct PendingDependency {
+    required_by: String,
+    source_url: String,
+    name: String,
+    version_req: String,
+}
+
+fn pending_for(
+    skills_root: &Path,
+    plugin_slug: &str,
+    source_url: &str,
+) -> impl Iterator<Item = PendingDependency> {
+    let required_by = plugin_slug.to_string();
+    let source_url = source_url.to_string();
+    read_plugin_dependencies(&skills_root.join(plugin_slug))
+        .into_iter()
+        .map(move |(name, version_req)| PendingDependency {
+            required_by: required_by.clone(),
+            source_url: source_url.clone(),
+            name,
+            version_req,
+        })
+}
+
+fn failure(dep: &PendingDependency, error: String) -> MarketplaceImportResult {
+    MarketplaceImportResult {
+        skill_name: String::new(),
+        success: false,
+        dependency_of: Some(dep.required_by.clone()),
+        missing_dependency: Some(dep.name.clone()),
+        error: Some(format!(
+            "Dependency '{}' ({}) of '{}': {}",
+            dep.name, dep.version_req, dep.required_by, error
+        )),
+    }
+}
+
+/// Resolve the dependencies of the just-imported `plugin_slug`, importing any
+/// that are missing. Returns the results of those imports plus one failed
+/// result per dependency that couldn't be satisfied.
+pub(crate) async fn resolve_plugin_dependencies(
+    db: &Db,
+    plugin_slug: &str,
+    source_url: &str,
+) -> Vec<MarketplaceImportResult> {
+    let settings = match db
+        .0
+        .lock()
+        .map_err(|e| e.to_string())
+        .and_then(|conn| crate::db::read_settings(&conn))
+    {
+        Ok(settings) => settings,
+        Err(e) => {
+            log::error!(
+                "[resolve_plugin_dependencies] failed to read settings: {}",
+                e
+            );
+            return Vec::new();
+        }
+    };
+    let Some(skills_path) = settings.skills_path.clone() else {
+        return Vec::new();
+    };
+    let skills_root = Path::new(&skills_path);
+    let github = GitHubAccess::from_settings(&settings);
+    let cache = CatalogCache::from_settings(db, &settings);
+    let registries: Vec<String> = settings
+        .marketplace_registries
+        .iter()
+        .filter(|r| r.enabled)
+        .map(|r| r.source_url.clone())
+        .collect();
+
+    let mut results = Vec::new();
+    let mut attempted: HashSet<String> = HashSet::from([plugin_slug.to_string()]);
+    let mut queue: VecDeque<PendingDependency> =
+        pending_for(skills_root, plugin_slug, source_url).collect();
+
+    while let Some(dep) = queue.pop_front() {
+        let dep_slug = crate::db::slugify_plugin_name(&dep.name);
+        let installed = match installed_version(db, &dep_slug) {
+            Ok(installed) => installed,
+            Err(e) => {
+                results.push(failure(&dep, e));
+                continue;
+            }
+        };
+        if let Some(version) = installed {
+            if !dependency_satisfied(&dep.version_req, version.as_deref()) {
+                results.push(failure(
+                    &dep,
+                    format!(
+                        "installed version {} does not satisfy the range",
+                        version.as_deref().unwrap_or("(unversioned)")
+                    ),
+                ));
+            }
+            continue;
+        }
+        if !attempted.insert(dep_slug.clone()) {
+            continue;
+        }
+
+        let mut sources = vec![dep.source_url.clone()];
+        sources.extend(registries.iter().filter(|r| **r != dep.source_url).cloned());
+        let (found_in, plugin) =
+            match find_dependency(&sources, &dep_slug, &dep.version_req, &github, &cache).await {
+                Ok(found) => found,
+                Err(e) => {
+                    results.push(failure(&dep, e));
+                    continue;
+                }
+            };
+        log::info!(
+            "[resolve_plugin_dependencies] importing '{}' {} from {} for '{}'",
+            plugin.name,
+            plugin.version.as_deref().unwrap_or("(unversioned)"),
+            found_in,
+            dep.required_by
+        );
+        match import_plugin_inner(
+            db,
+            &found_in,
+            &plugin.path,
+            &plugin.name,
+            plugin.external_source.clone(),
+        )
+        .await
+        {
+            Ok((slug, imported)) => {
+                results.extend(imported.into_iter().map(|mut r| {
+                    r.dependency_of = Some(dep.required_by.clone());
+                    r
+                }));
+                queue.extend(pending_for(skills_root, &slug, &found_in));
+            }
+            Err(e) => results.push(failure(&dep, e)),
+        }
+    }
+    results
+}
+
+/// `Some(version)` when a plugin with `slug` is installed.
+fn installed_version(db: &Db, slug: &str) -> Result<Option<Option<String>>, String> {
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    Ok(crate::db::list_plugins(&conn)?
+        .into_iter()
+        .find(|p| p.slug == slug)
+        .map(|p| p.version))
+}
+
+/// Find the first marketplace in `sources` listing a plugin named like
+/// `dep_slug` whose version satisfies `version_req`. Unversioned listings are
+/// accepted; `list_plugins` reports them once installed if they don't match.
+async fn find_dependency(
+    sources: &[String],
+    dep_slug: &str,
+    version_req: &str,
+    github: &GitHubAccess,
+    cache: &CatalogCache,
+) -> Result<(String, AvailablePlugin), String> {
+    let mut rejected: Vec<String> = Vec::new();
+    for source_url in sources {
+        let plugins = match parse_repo_source(source_url, &github.host) {
+            Ok(source) => list_source_plugins_inner(&source, github, Some(cache)).await,
+            Err(e) => Err(e),
+        };
+        let plugins = match plugins {
+            Ok((_, plugins)) => plugins,
+            Err(e) => {
+                log::warn!(
+                    "[resolve_plugin_dependencies] skipping {}: {}",
+                    source_url,
+                    e
+                );
+                continue;
+            }
+        };
+        for plugin in plugins {
+            if crate::db::slugify_plugin_name(&plugin.name) != dep_slug {
+                continue;
+            }
+            match plugin.version.as_deref() {
+                Some(version) if !dependency_satisfied(version_req, Some(version)) => {
+                    rejected.push(format!("{} in {}", version, source_url));
+                }
+                _ => return Ok((source_url.clone(), plugin)),
+            }
+        }
+    }
+    if rejected.is_empty() {
+        Err("not found in any enabled marketplace".to_string())
+    } else {
+        Err(format!(
+            "no matching version (available: {})",
+            rejected.join(", ")
+        ))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn write_plugin(root: &Path, slug: &str, version: &str, dependencies: &str) {
+        let dir = root.join(slug);
+        std::fs::create_dir_all(dir.join(".claude-plugin")).unwrap();
+        std::fs::write(
+            dir.join(".claude-plugin/plugin.json"),
+            format!(
+                r#"{{"name":"{}","version":"{}","dependencies":{}}}"#,
+                slug, version, dependencies
+            ),
+        )
+        .unwrap();
+        let skill = dir.join("skills").join(format!("{}-skill", slug));
+        std::fs::create_dir_all(&skill).unwrap();
+        std::fs::write(
+            skill.join("SKILL.md"),
+            format!("---\nname: {}-skill\n---\n", slug),
+        )
+        .unwrap();
+    }
+
+    #[tokio::test]
+    async fn imports_missing_dependencies_and_reports_unresolvable_ones() {
+        let tmp = tempfile::tempdir().unwrap();
+        let market = tmp.path().join("market");
+        write_plugin(
+            &market,
+            "analytics",
+            "1.0.0",
+            r#"{"data-glossary":"^1.2","charts":"^2"}"#,
+        );
+        write_plugin(&market, "data-glossary", "1.3.0", r#"{"analytics":"*"}"#);
+        std::fs::create_dir_all(market.join(".claude-plugin")).unwrap();
+        std::fs::write(
+            market.join(".claude-plugin/marketplace.json"),
+            r#"{"name":"team","plugins":[
+                {"name":"analytics","source":"./analytics","version":"1.0.0"},
+                {"name":"data-glossary","source":"./data-glossary","version":"1.3.0"},
+                {"name":"charts","source":"./charts","version":"1.0.0"}
+            ]}"#,
+        )
+        .unwrap();
+
+        let conn = crate::db::create_test_db_for_tests();
+        let mut settings = crate::db::read_settings(&conn).unwrap();
+        settings.skills_path = Some(tmp.path().join("skills").to_string_lossy().to_string());
+        crate::db::write_settings(&conn, &settings).unwrap();
+        let db = Db(std::sync::Arc::new(std::sync::Mutex::new(conn)));
+
+        let source_url = market.to_string_lossy().to_string();
+        let (slug, _) = import_plugin_inner(&db, &source_url, "analytics", "analytics", None)
+            .await
+            .unwrap();
+        let results = resolve_plugin_dependencies(&db, &slug, &source_url).await;
+
+        let glossary = results
+            .iter()
+            .find(|r| r.skill_name == "data-glossary-skill")
+            .expect("glossary skill imported");
+        assert!(glossary.success);
+        assert_eq!(glossary.dependency_of.as_deref(), Some("analytics"));
+        let charts = results
+            .iter()
+            .find(|r| r.missing_dependency.as_deref() == Some("charts"))
+            .expect("charts reported");
+        assert!(!charts.success);
+        assert!(
+            charts.error.as_deref().unwrap().contains("1.0.0 in"),
+            "got: {:?}",
+            charts.error
+        );
+
+        let plugins = crate::db::list_plugins(&db.0.lock().unwrap()).unwrap();
+        let analytics = plugins.iter().find(|p| p.slug == "analytics").unwrap();
+        let deps: Vec<(&str, bool)> = analytics
+            .dependencies
+            .iter()
+            .map(|d| (d.name.as_str(), d.satisfied))
+            .collect();
+        assert_eq!(deps, vec![("charts", false), ("data-glossary", true)]);
+        let glossary = plugins.iter().find(|p| p.slug == "data-glossary").unwrap();
+        assert_eq!(glossary.required_by, vec!["analytics"]);
+        // The cycle back to analytics is satisfied by the installed plugin.
+        assert_eq!(analytics.required_by, vec!["data-glossary"]);
+    }
+}
@@ -1,3 +1,5 @@
+use super::cache::{send_cached, CatalogCache};
+
 /// Build a `reqwest::Client` with standard GitHub API headers.
 /// If an OAuth token is available in settings, it is included as a Bearer token.
 pub(crate) fn build_github_client(token: Option<&str>) -> reqwest::Client {
@@ -18,22 +20,19 @@ pub(crate) fn build_github_client(token: Option<&str>) -> reqwest::Client {
         .unwrap_or_else(|_| reqwest::Client::new())
 }
 
-/// Fetch the default branch name for a GitHub repo via the API.
+/// Fetch the default branch name for a GitHub repo via the API at `api_base`.
 pub(crate) async fn get_default_branch(
     client: &reqwest::Client,
+    api_base: &str,
     owner: &str,
     repo: &str,
+    cache: Option<&CatalogCache>,
 ) -> Result<String, String> {
-    let url = format!("https://api.github.com/repos/{}/{}", owner, repo);
-    let response = client
-        .get(&url)
-        .send()
+    let url = format!("{}/repos/{}/{}", api_base, owner, repo);
+    let (status, bytes) = send_cached(client.get(&url), &url, cache)
         .await
         .map_err(|e| format!("Failed to fetch repo info: {}", e))?;
-    let status = response.status();
-    let body: serde_json::Value = response
-        .json()
-        .await
+    let body: serde_json::Value = serde_json::from_slice(&bytes)
         .map_err(|e| format!("Failed to parse repo response: {}", e))?;
     if !status.is_success() {
         let message = body["message"].as_str().unwrap_or("Unknown error");
@@ -45,36 +44,25 @@ pub(crate) async fn get_default_branch(
         .to_string())
 }
 
-/// Resolve the actual default branch and fetch the full recursive git tree.
-///
-/// Combines two API calls (repos + git/trees) that are repeated across
-/// `list_github_skills_inner`, `import_github_skills`, and
-/// `import_marketplace_to_library`.
-pub(crate) async fn fetch_repo_tree(
+/// Fetch the full recursive git tree at an exact branch, tag or commit SHA,
+/// without resolving the default branch first.
+pub(crate) async fn fetch_repo_tree_at(
     client: &reqwest::Client,
+    api_base: &str,
     owner: &str,
     repo: &str,
-    fallback_branch: &str,
-) -> Result<(String, Vec<serde_json::Value>), String> {
-    let branch = get_default_branch(client, owner, repo)
-        .await
-        .unwrap_or_else(|_| fallback_branch.to_string());
-
+    branch: &str,
+    cache: Option<&CatalogCache>,
+) -> Result<Vec<serde_json::Value>, String> {
     let tree_url = format!(
-        "https://api.github.com/repos/{}/{}/git/trees/{}?recursive=1",
-        owner, repo, branch
+        "{}/repos/{}/{}/git/trees/{}?recursive=1",
+        api_base, owner, repo, branch
     );
 
-    let response = client
-        .get(&tree_url)
-        .send()
+    let (status, bytes) = send_cached(client.get(&tree_url), &tree_url, cache)
         .await
         .map_err(|e| format!("Failed to fetch repo tree: {}", e))?;
-
-    let status = response.status();
-    let body: serde_json::Value = response
-        .json()
-        .await
+    let body: serde_json::Value = serde_json::from_slice(&bytes)
         .map_err(|e| format!("Failed to parse tree response: {}", e))?;
 
     if !status.is_success() {
@@ -87,5 +75,5 @@ pub(crate) async fn fetch_repo_tree(
         .ok_or("Invalid tree response: missing 'tree' array")?
         .clone();
 
-    Ok((branch, tree))
+    Ok(tree)
 }
@@ -1,3 +1,4 @@
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 
@@ -5,6 +6,8 @@ use sha2::Digest;
 
 use crate::types::ImportedSkill;
 
+use super::source::SourceSnapshot;
+
 /// Merge existing field values into a new `ImportedSkill`: each field on `skill`
 /// is left unchanged if already `Some`, otherwise falls back to the `existing` value.
 pub(crate) fn merge_imported_fields(skill: &mut ImportedSkill, existing: &ImportedSkill) {
@@ -79,13 +82,8 @@ pub(crate) fn rewrite_skill_md(
 /// When `overwrite` is `true`, an existing destination directory is removed before
 /// downloading. This is used by marketplace imports so that re-imports (e.g. after
 /// `skills_path` changed or files were manually deleted) always succeed.
-#[allow(clippy::too_many_arguments)]
 pub(crate) async fn import_single_skill(
-    client: &reqwest::Client,
-    raw_base_url: &str,
-    owner: &str,
-    repo: &str,
-    branch: &str,
+    source: &SourceSnapshot,
     skill_path: &str,
     tree: &[serde_json::Value],
     skills_root: &Path,
@@ -147,27 +145,10 @@ pub(crate) async fn import_single_skill(
         .unwrap_or(skill_path);
 
     // Download SKILL.md first to get frontmatter
-    let skill_md_url = format!(
-        "{}/{}/{}/{}/{}",
-        raw_base_url,
-        owner,
-        repo,
-        branch,
-        if prefix.is_empty() {
-            "SKILL.md".to_string()
-        } else {
-            format!("{}SKILL.md", prefix)
-        }
-    );
-
-    let skill_md_content = client
-        .get(&skill_md_url)
-        .send()
+    let skill_md_content = source
+        .read_text(&format!("{}SKILL.md", prefix))
         .await
-        .map_err(|e| format!("Failed to fetch SKILL.md: {}", e))?
-        .text()
-        .await
-        .map_err(|e| format!("Failed to read SKILL.md content: {}", e))?;
+        .map_err(|e| format!("Failed to fetch SKILL.md: {}", e))?;
 
     let mut fm = super::super::imported_skills::parse_frontmatter_full(&skill_md_content);
     let version_was_missing = fm.version.is_none();
@@ -177,7 +158,7 @@ pub(crate) async fn import_single_skill(
         );
     }
     if fm.author.is_none() {
-        fm.author = Some(owner.to_string());
+        fm.author = Some(source.author.clone());
     }
 
     // purpose is set by the caller at import time (DB-only), not read from frontmatter.
@@ -293,25 +274,11 @@ pub(crate) async fn import_single_skill(
             }
         }
 
-        let raw_url = format!(
-            "{}/{}/{}/{}/{}",
-            raw_base_url, owner, repo, branch, file_path
-        );
-
-        let response = client
-            .get(&raw_url)
-            .send()
-            .await
-            .map_err(|e| format!("Failed to download '{}': {}", file_path, e))?;
-
-        let content = response
-            .bytes()
-            .await
-            .map_err(|e| format!("Failed to read '{}': {}", file_path, e))?;
+        let content = source.read(file_path).await?;
 
         // Reject files larger than 10 MB. Check actual byte count after download
         // rather than Content-Length header, which is absent for chunked responses
-        // (the norm for raw.githubusercontent.com).
+        // (the norm for raw.githubusercontent.com) and for local reads.
         if content.len() > 10_000_000 {
             return Err(format!(
                 "File '{}' too large: {} bytes (max 10 MB)",
@@ -384,18 +351,44 @@ pub(crate) fn compute_skill_content_hash(disk_path: &str) -> Option<String> {
     Some(hex::encode(digest))
 }
 
-/// Download an entire plugin directory from GitHub into the local skills_path.
+/// SHA-256 hex digest of every file in a skill directory, keyed by relative
+/// `/`-separated path. Skips `.git` and anything the skill repo's `.gitignore`
+/// excludes (editor and OS droppings), so the manifest covers exactly what an
+/// import wrote.
+pub(crate) fn compute_skill_file_manifest(disk_path: &str) -> BTreeMap<String, String> {
+    let root = Path::new(disk_path);
+    let repo = git2::Repository::open(root).ok();
+    walkdir::WalkDir::new(root)
+        .into_iter()
+        .filter_entry(|e| e.file_name() != ".git")
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            let rel = e.path().strip_prefix(root).ok()?;
+            if repo
+                .as_ref()
+                .is_some_and(|r| r.is_path_ignored(rel).unwrap_or(false))
+            {
+                return None;
+            }
+            let bytes = fs::read(e.path()).ok()?;
+            let rel = rel
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            Some((rel, hex::encode(sha2::Sha256::digest(&bytes))))
+        })
+        .collect()
+}
+
+/// Download an entire plugin directory from a marketplace source into the local skills_path.
 ///
 /// Downloads all files under `plugin_path` in the repo tree to
 /// `{dest_plugin_dir}/` preserving the relative directory structure.
 /// This includes `.claude-plugin/plugin.json`, `skills/`, `agents/`, `hooks/`, etc.
-#[allow(clippy::too_many_arguments)]
 pub(crate) async fn download_plugin_directory(
-    client: &reqwest::Client,
-    raw_base_url: &str,
-    owner: &str,
-    repo: &str,
-    branch: &str,
+    source: &SourceSnapshot,
     plugin_path: &str,
     tree: &[serde_json::Value],
     dest_plugin_dir: &Path,
@@ -486,21 +479,7 @@ pub(crate) async fn download_plugin_directory(
             }
         }
 
-        let raw_url = format!(
-            "{}/{}/{}/{}/{}",
-            raw_base_url, owner, repo, branch, file_path
-        );
-
-        let response = client
-            .get(&raw_url)
-            .send()
-            .await
-            .map_err(|e| format!("Failed to download '{}': {}", file_path, e))?;
-
-        let content = response
-            .bytes()
-            .await
-            .map_err(|e| format!("Failed to read '{}': {}", file_path, e))?;
+        let content = source.read(file_path).await?;
 
         // Reject files larger than 10 MB
         if content.len() > 10_000_000 {
@@ -0,0 +1,228 @@
+//! Integrity pinning for marketplace imports.
+//!
+//! An import reads every file at one resolved commit, checks downloads against
+//! the `checksums` a plugin declares in `marketplace.json`, and records the
+//! commit plus a per-file SHA-256 manifest on the `imported_skills` row.
+//! `verify_imported_skills` compares each skill on disk against that manifest.
+
+use std::collections::BTreeMap;
+use std::path::Path;
+
+use crate::db::{Db, ImportedSkillIntegrity};
+use crate::types::{
+    FileIntegrityChange, FileIntegrityIssue, SkillIntegrityReport, SkillIntegrityStatus,
+};
+
+use super::catalog::declared_checksums;
+use super::import::compute_skill_file_manifest;
+use super::source::SourceSnapshot;
+use super::url::marketplace_manifest_path;
+
+/// Pin `snapshot` to a commit and load the checksums its marketplace declares.
+/// Returns the commit SHA when the source could name one.
+pub(crate) async fn pin_for_import(
+    snapshot: &mut SourceSnapshot,
+    subpath: Option<&str>,
+) -> Option<String> {
+    let commit = snapshot.pin().await;
+    let manifest_path = marketplace_manifest_path(subpath);
+    let manifest = match snapshot.read_text(&manifest_path).await {
+        Ok(body) => serde_json::from_str::<crate::types::MarketplaceJson>(&body)
+            .map_err(|e| format!("invalid JSON: {}", e)),
+        Err(e) => Err(e),
+    };
+    match manifest {
+        Ok(manifest) => {
+            let plugin_root = manifest
+                .metadata
+                .as_ref()
+                .and_then(|m| m.plugin_root.as_deref());
+            let checksums = declared_checksums(&manifest.plugins, plugin_root, subpath);
+            log::info!(
+                "[pin_for_import] {} at {:?}: {} declared checksum(s)",
+                snapshot.label,
+                commit,
+                checksums.len()
+            );
+            snapshot.expect_checksums(checksums);
+        }
+        Err(e) => log::debug!(
+            "[pin_for_import] no checksums for {}: {}: {}",
+            snapshot.label,
+            manifest_path,
+            e
+        ),
+    }
+    commit
+}
+
+/// Files that differ between the import `manifest` and the `current` digests.
+fn diff_file_manifest(
+    manifest: &BTreeMap<String, String>,
+    current: &BTreeMap<String, String>,
+) -> Vec<FileIntegrityIssue> {
+    let mut issues = Vec::new();
+    for (path, expected) in manifest {
+        match current.get(path) {
+            Some(actual) if actual == expected => {}
+            actual => issues.push(FileIntegrityIssue {
+                path: path.clone(),
+                change: if actual.is_some() {
+                    FileIntegrityChange::Modified
+                } else {
+                    FileIntegrityChange::Removed
+                },
+                expected: Some(expected.clone()),
+                actual: actual.cloned(),
+            }),
+        }
+    }
+    for (path, actual) in current {
+        if !manifest.contains_key(path) {
+            issues.push(FileIntegrityIssue {
+                path: path.clone(),
+                change: FileIntegrityChange::Added,
+                expected: None,
+                actual: Some(actual.clone()),
+            });
+        }
+    }
+    issues.sort_by(|a, b| a.path.cmp(&b.path));
+    issues
+}
+
+fn verify_imported_skill(skill: ImportedSkillIntegrity) -> SkillIntegrityReport {
+    let (status, files) = if !Path::new(&skill.disk_path).is_dir() {
+        (SkillIntegrityStatus::Missing, Vec::new())
+    } else if let Some(manifest) = &skill.file_manifest {
+        let files = diff_file_manifest(manifest, &compute_skill_file_manifest(&skill.disk_path));
+        let status = if files.is_empty() {
+            SkillIntegrityStatus::Verified
+        } else {
+            SkillIntegrityStatus::Drifted
+        };
+        (status, files)
+    } else {
+        (SkillIntegrityStatus::Unpinned, Vec::new())
+    };
+    SkillIntegrityReport {
+        skill_name: skill.skill_name,
+        plugin_slug: skill.plugin_slug,
+        source_url: skill.marketplace_source_url,
+        version: skill.version,
+        source_commit: skill.source_commit,
+        status,
+        files,
+    }
+}
+
+// ---------------------------------------------------------------------------
+// verify_imported_skills
+// ---------------------------------------------------------------------------
+
+/// Compare every marketplace-imported skill on disk with the file manifest
+/// recorded at import, reporting modified, removed and added files.
+#[tauri::command]
+pub fn verify_imported_skills(
+    db: tauri::State<'_, Db>,
+) -> Result<Vec<SkillIntegrityReport>, String> {
+    log::info!("[verify_imported_skills]");
+    let skills = {
+        let conn = db.0.lock().map_err(|e| {
+            log::error!("[verify_imported_skills] failed to acquire DB lock: {}", e);
+            e.to_string()
+        })?;
+        crate::db::list_imported_skill_integrity(&conn)?
+    };
+    let reports: Vec<SkillIntegrityReport> =
+        skills.into_iter().map(verify_imported_skill).collect();
+    log::info!(
+        "[verify_imported_skills] checked={} drifted={} missing={} unpinned={}",
+        reports.len(),
+        reports
+            .iter()
+            .filter(|r| r.status == SkillIntegrityStatus::Drifted)
+            .count(),
+        reports
+            .iter()
+            .filter(|r| r.status == SkillIntegrityStatus::Missing)
+            .count(),
+        reports
+            .iter()
+            .filter(|r| r.status == SkillIntegrityStatus::Unpinned)
+            .count()
+    );
+    Ok(reports)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn integrity(
+        disk_path: &str,
+        manifest: Option<BTreeMap<String, String>>,
+    ) -> ImportedSkillIntegrity {
+        ImportedSkillIntegrity {
+            skill_name: "standup".to_string(),
+            plugin_slug: "default".to_string(),
+            disk_path: disk_path.to_string(),
+            version: Some("1.0.0".to_string()),
+            marketplace_source_url: "acme/skills".to_string(),
+            source_commit: Some("abc".to_string()),
+            file_manifest: manifest,
+        }
+    }
+
+    #[test]
+    fn reports_modified_removed_and_added_files() {
+        let dir = tempfile::tempdir().unwrap();
+        let skill_dir = dir.path().join("standup");
+        std::fs::create_dir_all(skill_dir.join("references")).unwrap();
+        std::fs::write(skill_dir.join("SKILL.md"), "---\nname: standup\n---\n").unwrap();
+        std::fs::write(skill_dir.join("references/api.md"), "api\n").unwrap();
+        let disk_path = skill_dir.to_string_lossy().to_string();
+        let manifest = compute_skill_file_manifest(&disk_path);
+        assert_eq!(
+            manifest.keys().collect::<Vec<_>>(),
+            vec!["SKILL.md", "references/api.md"]
+        );
+
+        let report = verify_imported_skill(integrity(&disk_path, Some(manifest.clone())));
+        assert_eq!(report.status, SkillIntegrityStatus::Verified);
+
+        std::fs::write(skill_dir.join("SKILL.md"), "tampered\n").unwrap();
+        std::fs::remove_file(skill_dir.join("references/api.md")).unwrap();
+        std::fs::write(skill_dir.join("extra.sh"), "curl evil\n").unwrap();
+        let report = verify_imported_skill(integrity(&disk_path, Some(manifest)));
+        assert_eq!(report.status, SkillIntegrityStatus::Drifted);
+        let changes: Vec<(&str, FileIntegrityChange)> = report
+            .files
+            .iter()
+            .map(|f| (f.path.as_str(), f.change))
+            .collect();
+        assert_eq!(
+            changes,
+            vec![
+                ("SKILL.md", FileIntegrityChange::Modified),
+                ("extra.sh", FileIntegrityChange::Added),
+                ("references/api.md", FileIntegrityChange::Removed),
+            ]
+        );
+    }
+
+    #[test]
+    fn reports_missing_and_unpinned_skills() {
+        let dir = tempfile::tempdir().unwrap();
+        let gone = dir.path().join("gone").to_string_lossy().to_string();
+        assert_eq!(
+            verify_imported_skill(integrity(&gone, Some(BTreeMap::new()))).status,
+            SkillIntegrityStatus::Missing
+        );
+        let legacy = dir.path().to_string_lossy().to_string();
+        assert_eq!(
+            verify_imported_skill(integrity(&legacy, None)).status,
+            SkillIntegrityStatus::Unpinned
+        );
+    }
+}
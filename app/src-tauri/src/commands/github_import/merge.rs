@@ -0,0 +1,189 @@
+use std::collections::HashMap;
+use std::path::Path;
+
+use sha2::Digest;
+
+use crate::db::Db;
+use crate::github_host::GitHubAccess;
+use crate::types::{ImportedSkill, SkillUpdateApplied, SkillUpdatePreview};
+
+use super::import::import_single_skill;
+use super::integrity::pin_for_import;
+use super::source::{parse_repo_source, SourceSnapshot};
+
+fn imported_skill_from_source(
+    conn: &rusqlite::Connection,
+    skill_name: &str,
+    source_url: &str,
+) -> Result<ImportedSkill, String> {
+    crate::db::get_imported_skill_by_name_and_source(conn, skill_name, source_url)?
+        .ok_or_else(|| format!("'{}' was not imported from {}", skill_name, source_url))
+}
+
+fn plugin_slug_of(skill: &ImportedSkill) -> String {
+    skill
+        .plugin_slug
+        .clone()
+        .unwrap_or_else(|| crate::skill_paths::DEFAULT_PLUGIN_SLUG.to_string())
+}
+
+// ---------------------------------------------------------------------------
+// preview_imported_skill_update
+// ---------------------------------------------------------------------------
+
+/// Download `version` of an imported skill and compute a three-way merge with
+/// the local copy, uncommitted edits included. No ref, file or index changes
+/// until `apply_imported_skill_update` commits the result.
+#[tauri::command]
+pub async fn preview_imported_skill_update(
+    db: tauri::State<'_, Db>,
+    skill_name: String,
+    source_url: String,
+    skill_path: String,
+    version: String,
+) -> Result<SkillUpdatePreview, String> {
+    log::info!(
+        "[preview_imported_skill_update] skill='{}' path='{}' version={} source='{}'",
+        skill_name,
+        skill_path,
+        version,
+        source_url
+    );
+    let (skill, github) = {
+        let conn = db.0.lock().map_err(|e| e.to_string())?;
+        let settings = crate::db::read_settings(&conn)?;
+        (
+            imported_skill_from_source(&conn, &skill_name, &source_url)?,
+            GitHubAccess::from_settings(&settings),
+        )
+    };
+    let plugin_slug = plugin_slug_of(&skill);
+
+    let source = parse_repo_source(&source_url, &github.host)?;
+    let mut snapshot = SourceSnapshot::open(&source, &github).await?;
+    let source_commit = pin_for_import(&mut snapshot, source.subpath()).await;
+    let tree = snapshot.tree().await?;
+    let staging =
+        tempfile::tempdir().map_err(|e| format!("Failed to create staging dir: {}", e))?;
+    let incoming = import_single_skill(
+        &snapshot,
+        &skill_path,
+        &tree,
+        staging.path(),
+        &plugin_slug,
+        true,
+        None,
+    )
+    .await?;
+    if incoming.skill_name != skill_name {
+        return Err(format!(
+            "'{}' in {} is now named '{}'",
+            skill_path, source_url, incoming.skill_name
+        ));
+    }
+
+    let repo_path = Path::new(&skill.disk_path);
+    let upstream_sha = crate::git::stage_upstream_update(
+        repo_path,
+        &plugin_slug,
+        &skill_name,
+        Path::new(&incoming.disk_path),
+        &version,
+        skill.version.as_deref(),
+        source_commit.as_deref(),
+    )?;
+    let preview =
+        crate::git::preview_skill_update_merge(repo_path, &skill_name, &version, &upstream_sha)?;
+    log::info!(
+        "[preview_imported_skill_update] '{}': {} auto-merged, {} conflict(s)",
+        skill_name,
+        preview.auto_merged.len(),
+        preview.conflicts.len()
+    );
+    Ok(preview)
+}
+
+// ---------------------------------------------------------------------------
+// apply_imported_skill_update
+// ---------------------------------------------------------------------------
+
+/// Commit a previewed update with the reviewed conflict `resolutions` (path to
+/// final content, `null` to delete), tag it as `version` and record the new
+/// upstream SKILL.md as the customization baseline.
+#[tauri::command]
+#[allow(clippy::too_many_arguments)]
+pub fn apply_imported_skill_update(
+    db: tauri::State<'_, Db>,
+    skill_name: String,
+    source_url: String,
+    version: String,
+    head_sha: String,
+    upstream_sha: String,
+    resolutions: HashMap<String, Option<String>>,
+) -> Result<SkillUpdateApplied, String> {
+    log::info!(
+        "[apply_imported_skill_update] skill='{}' version={} resolutions={}",
+        skill_name,
+        version,
+        resolutions.len()
+    );
+    let conn = db.0.lock().map_err(|e| {
+        log::error!(
+            "[apply_imported_skill_update] failed to acquire DB lock: {}",
+            e
+        );
+        e.to_string()
+    })?;
+    let mut skill = imported_skill_from_source(&conn, &skill_name, &source_url)?;
+    let plugin_slug = plugin_slug_of(&skill);
+    let repo_path = Path::new(&skill.disk_path);
+
+    let applied = crate::git::apply_skill_update_merge(
+        repo_path,
+        &plugin_slug,
+        &skill_name,
+        &version,
+        &head_sha,
+        &upstream_sha,
+        &resolutions,
+    )?;
+
+    skill.version = Some(version);
+    let skill_md = repo_path.join("SKILL.md");
+    if let Ok(content) = std::fs::read_to_string(&skill_md) {
+        let fm = crate::commands::imported_skills::parse_frontmatter_full(&content);
+        skill.user_invocable = fm.user_invocable.or(skill.user_invocable);
+        skill.disable_model_invocation = fm
+            .disable_model_invocation
+            .or(skill.disable_model_invocation);
+    }
+    crate::db::upsert_imported_skill(&conn, &skill, skill.skill_id)?;
+    // Customization is measured against upstream, so local edits kept by the
+    // merge still count as customized.
+    if let Some(upstream_md) =
+        crate::git::read_file_at_commit(repo_path, &upstream_sha, "SKILL.md")?
+    {
+        let hash = hex::encode(sha2::Sha256::digest(&upstream_md));
+        crate::db::set_imported_skill_content_hash(&conn, &skill_name, &hash)?;
+    }
+    // Likewise the integrity manifest describes the upstream files.
+    crate::db::set_imported_skill_integrity(
+        &conn,
+        skill.skill_id,
+        crate::git::upstream_source_commit(repo_path, &upstream_sha)?.as_deref(),
+        &crate::git::file_digests_at_commit(repo_path, &upstream_sha)?,
+    )?;
+
+    let settings = crate::db::read_settings(&conn)?;
+    if let Some(skills_path) = settings.skills_path {
+        if let Err(e) =
+            crate::marketplace_manifest::regenerate_all_manifests(Path::new(&skills_path))
+        {
+            log::warn!(
+                "[apply_imported_skill_update] failed to regenerate manifests: {}",
+                e
+            );
+        }
+    }
+    Ok(applied)
+}
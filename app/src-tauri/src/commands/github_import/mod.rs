@@ -1,8 +1,14 @@
 #![allow(unused_imports)]
+pub mod bundle;
+mod cache;
 mod catalog;
 pub mod commands;
+mod dependencies;
 mod http;
 mod import;
+pub mod integrity;
+pub mod merge;
+mod source;
 pub mod updates;
 pub mod url;
 
@@ -12,14 +18,17 @@ pub use commands::{
 };
 pub(crate) use http::{build_github_client, get_default_branch};
 pub(crate) use import::compute_skill_content_hash;
+pub use integrity::verify_imported_skills;
+pub use merge::{apply_imported_skill_update, preview_imported_skill_update};
 pub use updates::{
     check_marketplace_updates, MarketplaceUpdateResult, RegistryNameInfo, SkillUpdateInfo,
 };
-pub use url::parse_github_url;
+pub use url::{parse_github_url, parse_skill_source};
 
-pub(crate) use catalog::discover_skills_from_catalog;
-pub(crate) use commands::list_github_skills_inner;
+pub(crate) use catalog::{discover_skills_from_catalog, resolve_external_source};
+pub(crate) use commands::{list_github_skills_inner, list_source_skills_inner};
 pub(crate) use import::import_single_skill;
+pub(crate) use source::{canonical_github_url, parse_repo_source, RepoSource};
 pub(crate) use url::parse_github_url_inner;
 
 #[cfg(test)]
@@ -940,6 +949,7 @@ mod tests {
             author: None,
             category: None,
             tags: None,
+            checksums: None,
         }
     }
 
@@ -1057,6 +1067,7 @@ mod tests {
             author: None,
             category: None,
             tags: None,
+            checksums: None,
         }];
         let skill_dirs = dirs(&["anything/skills/foo"]);
         let skills = discover_skills_from_catalog(&plugins, None, &skill_dirs, None);
@@ -1245,12 +1256,16 @@ mod tests {
         let tmp = tempfile::tempdir().unwrap();
         let tree = make_tree(&[("my-skill/SKILL.md", "blob")]);
 
-        let result = super::import::import_single_skill(
-            &client,
-            &server.url(),
+        let source = super::source::SourceSnapshot::github(
+            client,
+            &crate::github_host::GitHubHost::mock(&server.url()),
             "owner",
             "repo",
             "main",
+        );
+
+        let result = super::import::import_single_skill(
+            &source,
             "my-skill",
             &tree,
             tmp.path(),
@@ -1293,12 +1308,16 @@ mod tests {
             ..Default::default()
         };
 
-        let result = super::import::import_single_skill(
-            &client,
-            &server.url(),
+        let source = super::source::SourceSnapshot::github(
+            client,
+            &crate::github_host::GitHubHost::mock(&server.url()),
             "owner",
             "repo",
             "main",
+        );
+
+        let result = super::import::import_single_skill(
+            &source,
             "my-skill",
             &tree,
             tmp.path(),
@@ -1336,12 +1355,16 @@ mod tests {
         let tmp = tempfile::tempdir().unwrap();
         let tree = make_tree(&[("my-skill/SKILL.md", "blob")]);
 
-        let skill = super::import::import_single_skill(
-            &client,
-            &server.url(),
+        let source = super::source::SourceSnapshot::github(
+            client,
+            &crate::github_host::GitHubHost::mock(&server.url()),
             "owner",
             "repo",
             "main",
+        );
+
+        let skill = super::import::import_single_skill(
+            &source,
             "my-skill",
             &tree,
             tmp.path(),
@@ -0,0 +1,1405 @@
+//! Marketplace sources beyond github.com.
+//!
+//! Every import reads through a [`SourceSnapshot`]: a resolved branch, a
+//! GitHub-shaped tree listing (`{"path", "type"}` entries) and a reader for
+//! single files. GitHub, GitLab and Gitea are read over their HTTP APIs; any
+//! other git remote is cloned with git2 into a temp dir, and local folders are
+//! read in place.
+
+use std::collections::HashMap;
+use std::path::{Path, PathBuf};
+
+use sha2::Digest;
+
+use crate::github_host::{GitHubAccess, GitHubHost, PUBLIC_GITHUB_HOST};
+use crate::types::{GitHubRepoInfo, SkillSourceInfo, SkillSourceKind};
+
+use super::cache::{send_cached, CatalogCache, GITHUB_SIGN_IN_HINT};
+use super::http::{build_github_client, fetch_repo_tree_at, get_default_branch};
+use super::url::parse_github_url_inner;
+
+/// Upper bound on paginated tree requests so a misbehaving server can't loop forever.
+const MAX_TREE_PAGES: usize = 200;
+
+/// Where a marketplace lives, parsed from a registry URL or path.
+#[derive(Debug, Clone)]
+pub(crate) enum RepoSource {
+    /// A repo on github.com or on the configured GitHub Enterprise host.
+    GitHub(GitHubRepoInfo, GitHubHost),
+    GitLab {
+        base_url: String,
+        /// Full project path including namespaces, e.g. `group/sub/repo`.
+        project: String,
+        branch: Option<String>,
+        subpath: Option<String>,
+    },
+    Gitea {
+        base_url: String,
+        owner: String,
+        repo: String,
+        branch: Option<String>,
+        subpath: Option<String>,
+    },
+    Git {
+        url: String,
+        branch: Option<String>,
+        subpath: Option<String>,
+    },
+    Local {
+        root: PathBuf,
+        subpath: Option<String>,
+    },
+}
+
+impl RepoSource {
+    pub(crate) fn kind(&self) -> SkillSourceKind {
+        match self {
+            RepoSource::GitHub(..) => SkillSourceKind::Github,
+            RepoSource::GitLab { .. } => SkillSourceKind::Gitlab,
+            RepoSource::Gitea { .. } => SkillSourceKind::Gitea,
+            RepoSource::Git { .. } => SkillSourceKind::Git,
+            RepoSource::Local { .. } => SkillSourceKind::Local,
+        }
+    }
+
+    pub(crate) fn subpath(&self) -> Option<&str> {
+        match self {
+            RepoSource::GitHub(info, _) => info.subpath.as_deref(),
+            RepoSource::GitLab { subpath, .. }
+            | RepoSource::Gitea { subpath, .. }
+            | RepoSource::Git { subpath, .. }
+            | RepoSource::Local { subpath, .. } => subpath.as_deref(),
+        }
+    }
+
+    fn branch(&self) -> Option<&str> {
+        match self {
+            RepoSource::GitHub(info, _) => Some(info.branch.as_str()),
+            RepoSource::GitLab { branch, .. }
+            | RepoSource::Gitea { branch, .. }
+            | RepoSource::Git { branch, .. } => branch.as_deref(),
+            RepoSource::Local { .. } => None,
+        }
+    }
+
+    /// The same source read at `git_ref` instead of its own branch, when given.
+    pub(crate) fn at_ref(mut self, git_ref: Option<&str>) -> Self {
+        let Some(git_ref) = git_ref else {
+            return self;
+        };
+        match &mut self {
+            RepoSource::GitHub(info, _) => info.branch = git_ref.to_string(),
+            RepoSource::GitLab { branch, .. }
+            | RepoSource::Gitea { branch, .. }
+            | RepoSource::Git { branch, .. } => *branch = Some(git_ref.to_string()),
+            RepoSource::Local { .. } => {}
+        }
+        self
+    }
+
+    /// Short name for logs, errors and the registry list.
+    pub(crate) fn label(&self) -> String {
+        match self {
+            RepoSource::GitHub(info, _) => format!("{}/{}", info.owner, info.repo),
+            RepoSource::GitLab { project, .. } => project.clone(),
+            RepoSource::Gitea { owner, repo, .. } => format!("{}/{}", owner, repo),
+            RepoSource::Git { url, .. } => {
+                let last = url
+                    .trim_end_matches('/')
+                    .rsplit(['/', ':'])
+                    .next()
+                    .unwrap_or(url);
+                last.strip_suffix(".git").unwrap_or(last).to_string()
+            }
+            RepoSource::Local { root, .. } => root
+                .file_name()
+                .map(|n| n.to_string_lossy().to_string())
+                .unwrap_or_else(|| root.display().to_string()),
+        }
+    }
+}
+
+/// Classify a registry URL or path.
+///
+/// Supported forms:
+/// - GitHub: `owner/repo[#branch]`, `github.com/...`, `https://github.com/...`,
+///   and URLs on the configured GitHub Enterprise host
+/// - GitLab: `https://gitlab.com/group/repo`, `.../-/tree/branch/sub/path`
+/// - Gitea: `https://codeberg.org/owner/repo`, `.../src/branch/branch/sub/path`
+/// - Any git remote: other `https://` hosts, `git@host:path`, `ssh://`, `git://`
+/// - Local folders: absolute or `./` paths, `~/...`, `file://...`
+///
+/// Self-hosted GitLab and Gitea instances whose host name doesn't give them
+/// away can be forced with a `gitlab+` or `gitea+` prefix; `git+` forces a clone.
+/// A trailing `#branch` selects the branch for every remote kind. The
+/// `owner/repo` shorthand always refers to the configured `github` host.
+pub(crate) fn parse_repo_source(input: &str, github: &GitHubHost) -> Result<RepoSource, String> {
+    let input = input.trim();
+    if input.is_empty() {
+        return Err("Source URL cannot be empty".to_string());
+    }
+
+    if let Some(root) = local_path(input) {
+        return Ok(RepoSource::Local {
+            root,
+            subpath: None,
+        });
+    }
+
+    for (prefix, kind) in [
+        ("gitlab+", SkillSourceKind::Gitlab),
+        ("gitea+", SkillSourceKind::Gitea),
+        ("git+", SkillSourceKind::Git),
+    ] {
+        if let Some(rest) = input.strip_prefix(prefix) {
+            return parse_hosted(rest, kind);
+        }
+    }
+
+    if input.starts_with("git@") || input.starts_with("ssh://") || input.starts_with("git://") {
+        let (url, branch) = split_branch(input);
+        return Ok(RepoSource::Git {
+            url: url.to_string(),
+            branch,
+            subpath: None,
+        });
+    }
+
+    let url = if input.contains("://") {
+        input.to_string()
+    } else {
+        // `gitlab.com/group/repo` without a scheme; plain `owner/repo` is GitHub shorthand.
+        let first = input.split('/').next().unwrap_or_default();
+        if first.contains('.') || github.matches_host(first) {
+            format!("https://{}", input)
+        } else {
+            return parse_github_url_inner(input)
+                .map(|info| RepoSource::GitHub(info, github.clone()));
+        }
+    };
+
+    let (_, rest) = url.split_once("://").unwrap_or(("https", &url));
+    let host = rest
+        .split('/')
+        .next()
+        .unwrap_or_default()
+        .to_ascii_lowercase();
+    if host == PUBLIC_GITHUB_HOST || github.matches_host(&host) {
+        let host = if github.matches_host(&host) {
+            github.clone()
+        } else {
+            GitHubHost::public()
+        };
+        let path = rest.split_once('/').map_or("", |(_, path)| path);
+        return parse_github_url_inner(path).map(|info| RepoSource::GitHub(info, host));
+    }
+    let kind = if host == "gitlab.com" || host.starts_with("gitlab.") || rest.contains("/-/tree/") {
+        SkillSourceKind::Gitlab
+    } else if host == "gitea.com"
+        || host == "codeberg.org"
+        || host.starts_with("gitea.")
+        || rest.contains("/src/branch/")
+    {
+        SkillSourceKind::Gitea
+    } else {
+        SkillSourceKind::Git
+    };
+    parse_hosted(&url, kind)
+}
+
+/// Describe a source for the settings UI and for storing it canonically.
+pub(crate) fn skill_source_info(
+    input: &str,
+    github: &GitHubHost,
+) -> Result<SkillSourceInfo, String> {
+    let source = parse_repo_source(input, github)?;
+    let canonical_url = match &source {
+        RepoSource::GitHub(info, host) => canonical_github_url(info, host, github),
+        _ => input.trim().to_string(),
+    };
+    Ok(SkillSourceInfo {
+        kind: source.kind(),
+        canonical_url,
+        label: source.label(),
+        branch: source.branch().map(str::to_string),
+        subpath: source.subpath().map(str::to_string),
+    })
+}
+
+/// `owner/repo[#branch]` on the configured host; a full URL for any other host
+/// so the registry keeps pointing there if the configured host changes.
+pub(crate) fn canonical_github_url(
+    info: &GitHubRepoInfo,
+    host: &GitHubHost,
+    configured: &GitHubHost,
+) -> String {
+    let short = if info.branch == "main" {
+        format!("{}/{}", info.owner, info.repo)
+    } else {
+        format!("{}/{}#{}", info.owner, info.repo, info.branch)
+    };
+    if host == configured {
+        short
+    } else {
+        format!("https://{}/{}", host.host, short)
+    }
+}
+
+fn local_path(input: &str) -> Option<PathBuf> {
+    if let Some(path) = input.strip_prefix("file://") {
+        return Some(PathBuf::from(path));
+    }
+    if input == "~" || input.starts_with("~/") {
+        let home = dirs::home_dir()?;
+        return Some(home.join(input.trim_start_matches('~').trim_start_matches('/')));
+    }
+    let bytes = input.as_bytes();
+    let windows_drive = bytes.len() >= 3
+        && bytes[0].is_ascii_alphabetic()
+        && bytes[1] == b':'
+        && (bytes[2] == b'\\' || bytes[2] == b'/');
+    if input.starts_with('/')
+        || input.starts_with("./")
+        || input.starts_with("../")
+        || input.starts_with("\\\\")
+        || windows_drive
+    {
+        return Some(PathBuf::from(input));
+    }
+    None
+}
+
+fn split_branch(url: &str) -> (&str, Option<String>) {
+    match url.split_once('#') {
+        Some((base, branch)) if !branch.trim().is_empty() => {
+            (base, Some(branch.trim().to_string()))
+        }
+        Some((base, _)) => (base, None),
+        None => (url, None),
+    }
+}
+
+/// Split `{base}{marker}{branch}/{subpath}` into the base and the branch/subpath pair.
+fn split_tree_marker<'a>(path: &'a str, marker: &str) -> (&'a str, Option<String>, Option<String>) {
+    match path.split_once(marker) {
+        Some((base, tail)) => {
+            let mut segments = tail.splitn(2, '/');
+            let branch = segments
+                .next()
+                .filter(|b| !b.is_empty())
+                .map(str::to_string);
+            let subpath = segments
+                .next()
+                .map(|s| s.trim_matches('/'))
+                .filter(|s| !s.is_empty())
+                .map(str::to_string);
+            (base, branch, subpath)
+        }
+        None => (path, None, None),
+    }
+}
+
+fn parse_hosted(url: &str, kind: SkillSourceKind) -> Result<RepoSource, String> {
+    let (url, hash_branch) = split_branch(url.trim());
+    if kind == SkillSourceKind::Git {
+        return Ok(RepoSource::Git {
+            url: url.to_string(),
+            branch: hash_branch,
+            subpath: None,
+        });
+    }
+
+    let (scheme, rest) = url
+        .split_once("://")
+        .ok_or_else(|| format!("Expected an http(s) URL, got '{}'", url))?;
+    let (host, path) = rest.split_once('/').unwrap_or((rest, ""));
+    let base_url = format!("{}://{}", scheme, host);
+    let path = path.trim_end_matches('/');
+
+    let marker = if kind == SkillSourceKind::Gitlab {
+        "/-/tree/"
+    } else {
+        "/src/branch/"
+    };
+    let (repo_path, tree_branch, subpath) = split_tree_marker(path, marker);
+    let repo_path = repo_path.trim_matches('/');
+    let repo_path = repo_path.strip_suffix(".git").unwrap_or(repo_path);
+    let branch = tree_branch.or(hash_branch);
+
+    let segments: Vec<&str> = repo_path.split('/').filter(|s| !s.is_empty()).collect();
+    if segments.contains(&"..") {
+        return Err(format!("Invalid repository path in '{}'", url));
+    }
+
+    if kind == SkillSourceKind::Gitlab {
+        if segments.len() < 2 {
+            return Err(format!(
+                "Invalid GitLab URL '{}': expected at least group/project",
+                url
+            ));
+        }
+        Ok(RepoSource::GitLab {
+            base_url,
+            project: segments.join("/"),
+            branch,
+            subpath,
+        })
+    } else {
+        if segments.len() != 2 {
+            return Err(format!("Invalid Gitea URL '{}': expected owner/repo", url));
+        }
+        Ok(RepoSource::Gitea {
+            base_url,
+            owner: segments[0].to_string(),
+            repo: segments[1].to_string(),
+            branch,
+            subpath,
+        })
+    }
+}
+
+/// Percent-encode a single URL component, including `/`.
+fn encode_component(value: &str) -> String {
+    url::form_urlencoded::byte_serialize(value.as_bytes())
+        .collect::<String>()
+        .replace('+', "%20")
+}
+
+/// Client for a GitLab or Gitea host. Never carries the GitHub token; sends the
+/// token configured for `base_url`'s host, if any.
+fn build_source_client(
+    kind: SkillSourceKind,
+    base_url: &str,
+    github: &GitHubAccess,
+) -> reqwest::Client {
+    let mut headers = reqwest::header::HeaderMap::new();
+    if let Some(token) = github.source_token_for(source_host(base_url)) {
+        let (name, value) = match kind {
+            SkillSourceKind::Gitlab => ("PRIVATE-TOKEN", token.to_string()),
+            _ => ("Authorization", format!("token {}", token)),
+        };
+        if let Ok(mut value) = reqwest::header::HeaderValue::from_str(&value) {
+            value.set_sensitive(true);
+            headers.insert(name, value);
+        }
+    }
+    reqwest::Client::builder()
+        .user_agent("SkillBuilder")
+        .default_headers(headers)
+        .timeout(std::time::Duration::from_secs(30))
+        .connect_timeout(std::time::Duration::from_secs(10))
+        .build()
+        .unwrap_or_else(|_| reqwest::Client::new())
+}
+
+fn source_host(base_url: &str) -> &str {
+    base_url
+        .split_once("://")
+        .map_or(base_url, |(_, rest)| rest)
+}
+
+/// `cache` scoped to the token [`build_source_client`] sends to `base_url`.
+fn scoped_source_cache(
+    cache: Option<CatalogCache>,
+    base_url: &str,
+    github: &GitHubAccess,
+) -> Option<CatalogCache> {
+    let host = source_host(base_url);
+    cache.map(|c| {
+        c.scoped(
+            github.source_token_for(host),
+            format!(
+                "Add a token for {} under Settings → Marketplace → Host tokens for a higher limit",
+                host
+            ),
+        )
+    })
+}
+
+async fn get_json(
+    client: &reqwest::Client,
+    url: &str,
+    cache: Option<&CatalogCache>,
+) -> Result<serde_json::Value, String> {
+    let (status, body) = send_cached(client.get(url), url, cache)
+        .await
+        .map_err(|e| format!("Failed to reach {}: {}", url, e))?;
+    if !status.is_success() {
+        return Err(format!("Request to {} failed: HTTP {}", url, status));
+    }
+    serde_json::from_slice(&body)
+        .map_err(|e| format!("Failed to parse response from {}: {}", url, e))
+}
+
+enum HttpFiles {
+    GitHub {
+        api_base: String,
+        raw_base: String,
+        owner: String,
+        repo: String,
+    },
+    GitLab {
+        base_url: String,
+        project: String,
+    },
+    Gitea {
+        base_url: String,
+        owner: String,
+        repo: String,
+    },
+}
+
+enum SourceReader {
+    Http {
+        client: reqwest::Client,
+        files: HttpFiles,
+    },
+    Disk {
+        root: PathBuf,
+        /// Keeps a cloned checkout alive for as long as the snapshot is in use.
+        _checkout: Option<tempfile::TempDir>,
+    },
+}
+
+/// A marketplace source resolved to a concrete branch and ready to read.
+pub(crate) struct SourceSnapshot {
+    pub branch: String,
+    /// Fallback frontmatter `author` for imported skills (repo owner or folder name).
+    pub author: String,
+    /// Short name for logs and error messages.
+    pub label: String,
+    reader: SourceReader,
+    /// Catalog reads go through this cache when set; imports leave it unset.
+    cache: Option<CatalogCache>,
+    /// Repo-relative path → SHA-256 declared by the marketplace; checked on every read.
+    expected_checksums: HashMap<String, String>,
+}
+
+impl SourceSnapshot {
+    /// Read a GitHub repo at an exact branch, tag or commit.
+    pub(crate) fn github(
+        client: reqwest::Client,
+        host: &GitHubHost,
+        owner: &str,
+        repo: &str,
+        branch: &str,
+    ) -> Self {
+        SourceSnapshot {
+            branch: branch.to_string(),
+            author: owner.to_string(),
+            label: format!("{}/{}", owner, repo),
+            reader: SourceReader::Http {
+                client,
+                files: HttpFiles::GitHub {
+                    api_base: host.api_base.clone(),
+                    raw_base: host.raw_base.clone(),
+                    owner: owner.to_string(),
+                    repo: repo.to_string(),
+                },
+            },
+            cache: None,
+            expected_checksums: HashMap::new(),
+        }
+    }
+
+    /// Resolve the branch (default branch when none was given) and, for plain git
+    /// remotes, clone the repo. The GitHub token is only sent to the host that issued it.
+    pub(crate) async fn open(source: &RepoSource, github: &GitHubAccess) -> Result<Self, String> {
+        Self::open_cached(source, github, None).await
+    }
+
+    /// [`Self::open`] for catalog browsing: HTTP reads are revalidated against,
+    /// or served from, `cache`. Cloned and local sources are unaffected.
+    pub(crate) async fn open_cached(
+        source: &RepoSource,
+        github: &GitHubAccess,
+        cache: Option<CatalogCache>,
+    ) -> Result<Self, String> {
+        let label = source.label();
+        match source {
+            RepoSource::GitHub(info, host) => {
+                let client = build_github_client(github.token_for(host));
+                let cache = cache.map(|c| c.scoped(github.token_for(host), GITHUB_SIGN_IN_HINT));
+                let branch = get_default_branch(
+                    &client,
+                    &host.api_base,
+                    &info.owner,
+                    &info.repo,
+                    cache.as_ref(),
+                )
+                .await
+                .unwrap_or_else(|_| info.branch.clone());
+                Ok(SourceSnapshot {
+                    cache,
+                    ..Self::github(client, host, &info.owner, &info.repo, &branch)
+                })
+            }
+            RepoSource::GitLab {
+                base_url,
+                project,
+                branch,
+                ..
+            } => {
+                let client = build_source_client(SkillSourceKind::Gitlab, base_url, github);
+                let cache = scoped_source_cache(cache, base_url, github);
+                let branch = match branch {
+                    Some(b) => b.clone(),
+                    None => {
+                        let url =
+                            format!("{}/api/v4/projects/{}", base_url, encode_component(project));
+                        default_branch_from(&get_json(&client, &url, cache.as_ref()).await?)
+                    }
+                };
+                let author = project.split('/').next().unwrap_or(project).to_string();
+                Ok(SourceSnapshot {
+                    branch,
+                    author,
+                    label,
+                    reader: SourceReader::Http {
+                        client,
+                        files: HttpFiles::GitLab {
+                            base_url: base_url.clone(),
+                            project: project.clone(),
+                        },
+                    },
+                    cache,
+                    expected_checksums: HashMap::new(),
+                })
+            }
+            RepoSource::Gitea {
+                base_url,
+                owner,
+                repo,
+                branch,
+                ..
+            } => {
+                let client = build_source_client(SkillSourceKind::Gitea, base_url, github);
+                let cache = scoped_source_cache(cache, base_url, github);
+                let branch = match branch {
+                    Some(b) => b.clone(),
+                    None => {
+                        let url = format!("{}/api/v1/repos/{}/{}", base_url, owner, repo);
+                        default_branch_from(&get_json(&client, &url, cache.as_ref()).await?)
+                    }
+                };
+                Ok(SourceSnapshot {
+                    branch,
+                    author: owner.clone(),
+                    label,
+                    reader: SourceReader::Http {
+                        client,
+                        files: HttpFiles::Gitea {
+                            base_url: base_url.clone(),
+                            owner: owner.clone(),
+                            repo: repo.clone(),
+                        },
+                    },
+                    cache,
+                    expected_checksums: HashMap::new(),
+                })
+            }
+            RepoSource::Git { url, branch, .. } => {
+                let checkout = tempfile::tempdir()
+                    .map_err(|e| format!("Failed to create clone directory: {}", e))?;
+                let dest = checkout.path().to_path_buf();
+                let (url, branch, token) = (
+                    url.clone(),
+                    branch.clone(),
+                    github.token_for_url(url).map(str::to_string),
+                );
+                let resolved = tokio::task::spawn_blocking(move || {
+                    clone_repo(&url, branch.as_deref(), token.as_deref(), &dest)
+                })
+                .await
+                .map_err(|e| format!("Clone task failed: {}", e))??;
+                Ok(SourceSnapshot {
+                    branch: resolved,
+                    author: label.clone(),
+                    label,
+                    reader: SourceReader::Disk {
+                        root: checkout.path().to_path_buf(),
+                        _checkout: Some(checkout),
+                    },
+                    cache: None,
+                    expected_checksums: HashMap::new(),
+                })
+            }
+            RepoSource::Local { root, .. } => {
+                if !root.is_dir() {
+                    return Err(format!("Folder not found: {}", root.display()));
+                }
+                Ok(SourceSnapshot {
+                    branch: "local".to_string(),
+                    author: label.clone(),
+                    label,
+                    reader: SourceReader::Disk {
+                        root: root.clone(),
+                        _checkout: None,
+                    },
+                    cache: None,
+                    expected_checksums: HashMap::new(),
+                })
+            }
+        }
+    }
+
+    /// Recursive listing of the whole source as GitHub-style tree entries.
+    pub(crate) async fn tree(&self) -> Result<Vec<serde_json::Value>, String> {
+        match &self.reader {
+            SourceReader::Http { client, files } => match files {
+                HttpFiles::GitHub {
+                    api_base,
+                    owner,
+                    repo,
+                    ..
+                } => {
+                    fetch_repo_tree_at(
+                        client,
+                        api_base,
+                        owner,
+                        repo,
+                        &self.branch,
+                        self.cache.as_ref(),
+                    )
+                    .await
+                }
+                HttpFiles::GitLab { base_url, project } => {
+                    let mut tree = Vec::new();
+                    for page in 1..=MAX_TREE_PAGES {
+                        let url = format!(
+                            "{}/api/v4/projects/{}/repository/tree?recursive=true&per_page=100&page={}&ref={}",
+                            base_url,
+                            encode_component(project),
+                            page,
+                            encode_component(&self.branch)
+                        );
+                        let body = get_json(client, &url, self.cache.as_ref()).await?;
+                        let entries = body
+                            .as_array()
+                            .ok_or("Invalid GitLab tree response: expected an array")?;
+                        tree.extend(entries.iter().cloned());
+                        if entries.len() < 100 {
+                            break;
+                        }
+                    }
+                    Ok(tree)
+                }
+                HttpFiles::Gitea {
+                    base_url,
+                    owner,
+                    repo,
+                } => {
+                    let mut tree = Vec::new();
+                    for page in 1..=MAX_TREE_PAGES {
+                        let url = format!(
+                            "{}/api/v1/repos/{}/{}/git/trees/{}?recursive=true&per_page=1000&page={}",
+                            base_url,
+                            owner,
+                            repo,
+                            encode_component(&self.branch),
+                            page
+                        );
+                        let body = get_json(client, &url, self.cache.as_ref()).await?;
+                        let entries = body["tree"]
+                            .as_array()
+                            .ok_or("Invalid Gitea tree response: missing 'tree' array")?;
+                        tree.extend(entries.iter().cloned());
+                        if !body["truncated"].as_bool().unwrap_or(false) {
+                            break;
+                        }
+                    }
+                    Ok(tree)
+                }
+            },
+            SourceReader::Disk { root, .. } => Ok(disk_tree(root)),
+        }
+    }
+
+    /// Resolve the branch to a commit SHA and read everything at that commit
+    /// from now on, so an import sees a single revision. Returns `None` when
+    /// the source can't name a commit (plain folders, older servers); the
+    /// import then proceeds unpinned.
+    pub(crate) async fn pin(&mut self) -> Option<String> {
+        let resolved = match &self.reader {
+            SourceReader::Http { client, files } => {
+                let result = match files {
+                    HttpFiles::GitHub {
+                        api_base,
+                        owner,
+                        repo,
+                        ..
+                    } => {
+                        let url = format!(
+                            "{}/repos/{}/{}/commits/{}",
+                            api_base, owner, repo, self.branch
+                        );
+                        github_commit_sha(client, &url).await
+                    }
+                    HttpFiles::GitLab { base_url, project } => {
+                        let url = format!(
+                            "{}/api/v4/projects/{}/repository/commits/{}",
+                            base_url,
+                            encode_component(project),
+                            encode_component(&self.branch)
+                        );
+                        get_json(client, &url, None)
+                            .await
+                            .map(|body| body["id"].as_str().map(str::to_string))
+                    }
+                    HttpFiles::Gitea {
+                        base_url,
+                        owner,
+                        repo,
+                    } => {
+                        let url = format!(
+                            "{}/api/v1/repos/{}/{}/git/commits/{}",
+                            base_url,
+                            owner,
+                            repo,
+                            encode_component(&self.branch)
+                        );
+                        get_json(client, &url, None)
+                            .await
+                            .map(|body| body["sha"].as_str().map(str::to_string))
+                    }
+                };
+                result.unwrap_or_else(|e| {
+                    log::warn!(
+                        "[SourceSnapshot::pin] could not resolve {} at {}: {}",
+                        self.label,
+                        self.branch,
+                        e
+                    );
+                    None
+                })
+            }
+            SourceReader::Disk { root, .. } => git2::Repository::open(root)
+                .ok()
+                .and_then(|repo| Some(repo.head().ok()?.peel_to_commit().ok()?.id()))
+                .map(|oid| oid.to_string()),
+        };
+        if let (Some(sha), SourceReader::Http { .. }) = (&resolved, &self.reader) {
+            self.branch = sha.clone();
+        }
+        resolved
+    }
+
+    /// Fail reads of the given repo-relative paths whose SHA-256 doesn't match.
+    pub(crate) fn expect_checksums(&mut self, checksums: HashMap<String, String>) {
+        self.expected_checksums = checksums;
+    }
+
+    /// Read one repo-relative file, verifying it against any declared checksum.
+    pub(crate) async fn read(&self, path: &str) -> Result<Vec<u8>, String> {
+        let bytes = self.fetch(path).await?;
+        if let Some(expected) = self.expected_checksums.get(path) {
+            let expected = expected.trim().to_ascii_lowercase();
+            let expected = expected.strip_prefix("sha256:").unwrap_or(&expected);
+            let actual = hex::encode(sha2::Sha256::digest(&bytes));
+            if actual != expected {
+                return Err(format!(
+                    "Checksum mismatch for '{}' in {}: marketplace.json declares sha256:{}, downloaded file is sha256:{}",
+                    path, self.label, expected, actual
+                ));
+            }
+        }
+        Ok(bytes)
+    }
+
+    async fn fetch(&self, path: &str) -> Result<Vec<u8>, String> {
+        match &self.reader {
+            SourceReader::Http { client, files } => {
+                let url = match files {
+                    HttpFiles::GitHub {
+                        raw_base,
+                        owner,
+                        repo,
+                        ..
+                    } => format!("{}/{}/{}/{}/{}", raw_base, owner, repo, self.branch, path),
+                    HttpFiles::GitLab { base_url, project } => format!(
+                        "{}/api/v4/projects/{}/repository/files/{}/raw?ref={}",
+                        base_url,
+                        encode_component(project),
+                        encode_component(path),
+                        encode_component(&self.branch)
+                    ),
+                    HttpFiles::Gitea {
+                        base_url,
+                        owner,
+                        repo,
+                    } => format!(
+                        "{}/api/v1/repos/{}/{}/raw/{}?ref={}",
+                        base_url,
+                        owner,
+                        repo,
+                        path.split('/')
+                            .map(encode_component)
+                            .collect::<Vec<_>>()
+                            .join("/"),
+                        encode_component(&self.branch)
+                    ),
+                };
+                let request = client
+                    .get(&url)
+                    .header("Cache-Control", "no-cache")
+                    .header("Pragma", "no-cache");
+                let (status, body) = send_cached(request, &url, self.cache.as_ref())
+                    .await
+                    .map_err(|e| format!("Failed to download '{}': {}", path, e))?;
+                if !status.is_success() {
+                    return Err(format!("Failed to download '{}': HTTP {}", path, status));
+                }
+                Ok(body)
+            }
+            SourceReader::Disk { root, .. } => {
+                if Path::new(path).is_absolute() || path.split('/').any(|s| s == "..") {
+                    return Err(format!("Invalid path '{}'", path));
+                }
+                std::fs::read(root.join(path))
+                    .map_err(|e| format!("Failed to read '{}': {}", path, e))
+            }
+        }
+    }
+
+    pub(crate) async fn read_text(&self, path: &str) -> Result<String, String> {
+        let bytes = self.read(path).await?;
+        String::from_utf8(bytes).map_err(|_| format!("'{}' is not valid UTF-8", path))
+    }
+}
+
+/// `GET /repos/{owner}/{repo}/commits/{ref}` with the `sha` media type, which
+/// returns the bare commit SHA instead of the full commit with its diff.
+async fn github_commit_sha(client: &reqwest::Client, url: &str) -> Result<Option<String>, String> {
+    let response = client
+        .get(url)
+        .header("Accept", "application/vnd.github.sha")
+        .send()
+        .await
+        .map_err(|e| format!("Failed to reach {}: {}", url, e))?;
+    let status = response.status();
+    if !status.is_success() {
+        return Err(format!("Request to {} failed: HTTP {}", url, status));
+    }
+    let sha = response
+        .text()
+        .await
+        .map_err(|e| format!("Failed to read response from {}: {}", url, e))?;
+    let sha = sha.trim();
+    Ok(is_commit_sha(sha).then(|| sha.to_string()))
+}
+
+fn is_commit_sha(value: &str) -> bool {
+    value.len() == 40 && value.bytes().all(|b| b.is_ascii_hexdigit())
+}
+
+fn default_branch_from(repo_json: &serde_json::Value) -> String {
+    repo_json["default_branch"]
+        .as_str()
+        .filter(|b| !b.is_empty())
+        .unwrap_or("main")
+        .to_string()
+}
+
+/// Clone `url` into `dest` and return the checked-out branch name. A full
+/// commit SHA as `branch` checks that commit out instead and returns it.
+fn clone_repo(
+    url: &str,
+    branch: Option<&str>,
+    github_token: Option<&str>,
+    dest: &Path,
+) -> Result<String, String> {
+    let commit = branch.filter(|b| is_commit_sha(b));
+    let mut fetch = git2::FetchOptions::new();
+    fetch.remote_callbacks(crate::git_remote::remote_callbacks(github_token));
+    // libgit2's local transport doesn't support shallow fetches, and a pinned
+    // commit may be older than the branch tip.
+    if !(url.starts_with('/') || url.starts_with("file://")) && commit.is_none() {
+        fetch.depth(1);
+    }
+    let mut builder = git2::build::RepoBuilder::new();
+    builder.fetch_options(fetch);
+    if let Some(branch) = branch.filter(|_| commit.is_none()) {
+        builder.branch(branch);
+    }
+    log::info!("[clone_repo] cloning {} branch={:?}", url, branch);
+    let repo = builder
+        .clone(url, dest)
+        .map_err(|e| format!("Failed to clone {}: {}", url, e.message()))?;
+    if let Some(sha) = commit {
+        let target = git2::Oid::from_str(sha)
+            .and_then(|oid| repo.find_commit(oid))
+            .map_err(|e| format!("Commit {} not found in {}: {}", sha, url, e.message()))?;
+        repo.checkout_tree(
+            target.as_object(),
+            Some(git2::build::CheckoutBuilder::new().force()),
+        )
+        .and_then(|()| repo.set_head_detached(target.id()))
+        .map_err(|e| format!("Failed to check out {}: {}", sha, e.message()))?;
+        return Ok(sha.to_string());
+    }
+    let head = repo
+        .head()
+        .map_err(|e| format!("Cloned repository has no HEAD: {}", e.message()))?;
+    Ok(head.shorthand().unwrap_or("HEAD").to_string())
+}
+
+fn disk_tree(root: &Path) -> Vec<serde_json::Value> {
+    walkdir::WalkDir::new(root)
+        .into_iter()
+        .filter_entry(|e| e.file_name() != ".git")
+        .filter_map(Result::ok)
+        .filter(|e| e.file_type().is_file())
+        .filter_map(|e| {
+            let relative = e.path().strip_prefix(root).ok()?;
+            let path = relative
+                .components()
+                .map(|c| c.as_os_str().to_string_lossy())
+                .collect::<Vec<_>>()
+                .join("/");
+            Some(serde_json::json!({"path": path, "type": "blob"}))
+        })
+        .collect()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(input: &str) -> Result<RepoSource, String> {
+        parse_repo_source(input, &GitHubHost::public())
+    }
+
+    fn source_info(input: &str) -> Result<SkillSourceInfo, String> {
+        skill_source_info(input, &GitHubHost::public())
+    }
+
+    fn tree_paths(tree: &[serde_json::Value]) -> Vec<String> {
+        let mut paths: Vec<String> = tree
+            .iter()
+            .filter_map(|e| e["path"].as_str().map(str::to_string))
+            .collect();
+        paths.sort();
+        paths
+    }
+
+    fn write_marketplace(root: &Path) {
+        std::fs::create_dir_all(root.join(".claude-plugin")).unwrap();
+        std::fs::write(
+            root.join(".claude-plugin/marketplace.json"),
+            r#"{"name":"team","plugins":[{"name":"eng","source":"./eng"}]}"#,
+        )
+        .unwrap();
+        std::fs::create_dir_all(root.join("eng/skills/standup")).unwrap();
+        std::fs::write(
+            root.join("eng/skills/standup/SKILL.md"),
+            "---\nname: standup\ndescription: Daily standup\n---\n",
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn parse_classifies_each_source_kind() {
+        assert!(matches!(
+            parse("acme/skills").unwrap(),
+            RepoSource::GitHub(info, _) if info.owner == "acme" && info.repo == "skills"
+        ));
+        assert!(matches!(
+            parse("https://github.com/acme/skills/tree/dev").unwrap(),
+            RepoSource::GitHub(info, _) if info.branch == "dev"
+        ));
+
+        match parse("https://gitlab.example.com/team/ai/skills/-/tree/release/plugins").unwrap() {
+            RepoSource::GitLab {
+                base_url,
+                project,
+                branch,
+                subpath,
+            } => {
+                assert_eq!(base_url, "https://gitlab.example.com");
+                assert_eq!(project, "team/ai/skills");
+                assert_eq!(branch.as_deref(), Some("release"));
+                assert_eq!(subpath.as_deref(), Some("plugins"));
+            }
+            other => panic!("expected GitLab, got {:?}", other),
+        }
+        assert!(matches!(
+            parse("gitlab.com/team/skills.git").unwrap(),
+            RepoSource::GitLab { project, branch: None, .. } if project == "team/skills"
+        ));
+
+        match parse("gitea+https://git.corp.local/team/skills#stable").unwrap() {
+            RepoSource::Gitea {
+                base_url,
+                owner,
+                repo,
+                branch,
+                ..
+            } => {
+                assert_eq!(base_url, "https://git.corp.local");
+                assert_eq!((owner.as_str(), repo.as_str()), ("team", "skills"));
+                assert_eq!(branch.as_deref(), Some("stable"));
+            }
+            other => panic!("expected Gitea, got {:?}", other),
+        }
+        assert!(matches!(
+            parse("https://codeberg.org/team/skills/src/branch/main/sub").unwrap(),
+            RepoSource::Gitea { subpath: Some(s), .. } if s == "sub"
+        ));
+
+        assert!(matches!(
+            parse("git@git.corp.local:team/skills.git#dev").unwrap(),
+            RepoSource::Git { url, branch: Some(b), .. }
+                if url == "git@git.corp.local:team/skills.git" && b == "dev"
+        ));
+        assert!(matches!(
+            parse("https://git.corp.local/team/skills.git").unwrap(),
+            RepoSource::Git { .. }
+        ));
+
+        assert!(matches!(
+            parse("/mnt/shared/skills").unwrap(),
+            RepoSource::Local { root, .. } if root == Path::new("/mnt/shared/skills")
+        ));
+        assert!(matches!(
+            parse("file:///srv/skills").unwrap(),
+            RepoSource::Local { .. }
+        ));
+        assert!(parse("   ").is_err());
+        assert!(parse("https://gitlab.com/only-group").is_err());
+    }
+
+    #[test]
+    fn source_info_canonicalizes_github_and_keeps_other_urls() {
+        let github = source_info("https://github.com/acme/skills").unwrap();
+        assert_eq!(github.kind, SkillSourceKind::Github);
+        assert_eq!(github.canonical_url, "acme/skills");
+
+        let local = source_info(" /mnt/shared/team-skills ").unwrap();
+        assert_eq!(local.kind, SkillSourceKind::Local);
+        assert_eq!(local.canonical_url, "/mnt/shared/team-skills");
+        assert_eq!(local.label, "team-skills");
+
+        let git = source_info("ssh://git@git.corp.local/team/skills.git").unwrap();
+        assert_eq!(git.kind, SkillSourceKind::Git);
+        assert_eq!(git.label, "skills");
+    }
+
+    #[test]
+    fn enterprise_host_urls_and_shorthand_are_github() {
+        let ghes = GitHubHost::enterprise("ghe.corp.example");
+        match parse_repo_source(
+            "https://ghe.corp.example/acme/skills/tree/dev/plugins",
+            &ghes,
+        )
+        .unwrap()
+        {
+            RepoSource::GitHub(info, host) => {
+                assert_eq!(
+                    (info.owner.as_str(), info.repo.as_str()),
+                    ("acme", "skills")
+                );
+                assert_eq!(info.branch, "dev");
+                assert_eq!(info.subpath.as_deref(), Some("plugins"));
+                assert_eq!(host, ghes);
+            }
+            other => panic!("expected GitHub, got {:?}", other),
+        }
+        assert!(matches!(
+            parse_repo_source("acme/skills", &ghes).unwrap(),
+            RepoSource::GitHub(_, host) if host == ghes
+        ));
+        assert!(matches!(
+            parse_repo_source("github.com/acme/skills", &ghes).unwrap(),
+            RepoSource::GitHub(_, host) if host.is_public()
+        ));
+        // Without the setting, an enterprise URL is just another git remote.
+        assert!(matches!(
+            parse("https://ghe.corp.example/acme/skills").unwrap(),
+            RepoSource::Git { .. }
+        ));
+
+        assert_eq!(
+            skill_source_info("https://ghe.corp.example/acme/skills#dev", &ghes)
+                .unwrap()
+                .canonical_url,
+            "acme/skills#dev"
+        );
+        assert_eq!(
+            skill_source_info("https://github.com/acme/skills", &ghes)
+                .unwrap()
+                .canonical_url,
+            "https://github.com/acme/skills"
+        );
+    }
+
+    #[tokio::test]
+    async fn enterprise_snapshot_uses_configured_endpoints_and_token() {
+        let mut server = mockito::Server::new_async().await;
+        let _repo = server
+            .mock("GET", "/repos/acme/skills")
+            .match_header("authorization", "Bearer ghe-token")
+            .with_status(200)
+            .with_body(r#"{"default_branch":"trunk"}"#)
+            .create_async()
+            .await;
+        let _tree = server
+            .mock("GET", "/repos/acme/skills/git/trees/trunk?recursive=1")
+            .match_header("authorization", "Bearer ghe-token")
+            .with_status(200)
+            .with_body(r#"{"tree":[{"path":"eng/skills/standup/SKILL.md","type":"blob"}]}"#)
+            .create_async()
+            .await;
+        let _file = server
+            .mock("GET", "/acme/skills/trunk/eng/skills/standup/SKILL.md")
+            .with_status(200)
+            .with_body("---\nname: standup\n---\n")
+            .create_async()
+            .await;
+
+        let ghes = GitHubHost::mock(&server.url());
+        let source = parse_repo_source("acme/skills", &ghes).unwrap();
+        let access = GitHubAccess {
+            host: ghes,
+            token: Some("ghe-token".to_string()),
+            source_tokens: vec![],
+        };
+        let snapshot = SourceSnapshot::open(&source, &access).await.unwrap();
+        assert_eq!(snapshot.branch, "trunk");
+        assert_eq!(
+            tree_paths(&snapshot.tree().await.unwrap()),
+            vec!["eng/skills/standup/SKILL.md"]
+        );
+        assert!(snapshot
+            .read_text("eng/skills/standup/SKILL.md")
+            .await
+            .unwrap()
+            .contains("standup"));
+    }
+
+    #[tokio::test]
+    async fn pinned_snapshot_reads_at_commit_and_verifies_checksums() {
+        let sha = "0123456789abcdef0123456789abcdef01234567";
+        let body = "---\nname: standup\n---\n";
+        let mut server = mockito::Server::new_async().await;
+        let _repo = server
+            .mock("GET", "/repos/acme/skills")
+            .with_status(200)
+            .with_body(r#"{"default_branch":"main"}"#)
+            .create_async()
+            .await;
+        let _commit = server
+            .mock("GET", "/repos/acme/skills/commits/main")
+            .match_header("accept", "application/vnd.github.sha")
+            .with_status(200)
+            .with_body(sha)
+            .create_async()
+            .await;
+        let _file = server
+            .mock(
+                "GET",
+                format!("/acme/skills/{}/skills/standup/SKILL.md", sha).as_str(),
+            )
+            .with_status(200)
+            .with_body(body)
+            .create_async()
+            .await;
+
+        let host = GitHubHost::mock(&server.url());
+        let source = parse_repo_source("acme/skills", &host).unwrap();
+        let mut snapshot = SourceSnapshot::open(&source, &GitHubAccess::anonymous(host))
+            .await
+            .unwrap();
+        assert_eq!(snapshot.pin().await.as_deref(), Some(sha));
+        assert_eq!(snapshot.branch, sha);
+
+        let path = "skills/standup/SKILL.md".to_string();
+        let digest = hex::encode(sha2::Sha256::digest(body.as_bytes()));
+        snapshot.expect_checksums(HashMap::from([(
+            path.clone(),
+            format!("sha256:{}", digest),
+        )]));
+        assert_eq!(snapshot.read_text(&path).await.unwrap(), body);
+
+        snapshot.expect_checksums(HashMap::from([(path.clone(), "0".repeat(64))]));
+        let err = snapshot.read_text(&path).await.unwrap_err();
+        assert!(err.contains("Checksum mismatch"), "got: {err}");
+    }
+
+    #[tokio::test]
+    async fn local_folder_lists_and_reads_files() {
+        let dir = tempfile::tempdir().unwrap();
+        write_marketplace(dir.path());
+        std::fs::create_dir_all(dir.path().join(".git")).unwrap();
+        std::fs::write(dir.path().join(".git/HEAD"), "ref: refs/heads/main").unwrap();
+
+        let source = parse(dir.path().to_str().unwrap()).unwrap();
+        let snapshot =
+            SourceSnapshot::open(&source, &GitHubAccess::anonymous(GitHubHost::public()))
+                .await
+                .unwrap();
+        assert_eq!(
+            tree_paths(&snapshot.tree().await.unwrap()),
+            vec![
+                ".claude-plugin/marketplace.json",
+                "eng/skills/standup/SKILL.md"
+            ]
+        );
+        let manifest = snapshot
+            .read_text(".claude-plugin/marketplace.json")
+            .await
+            .unwrap();
+        assert!(manifest.contains("\"team\""));
+        assert!(snapshot.read("../outside").await.is_err());
+    }
+
+    #[tokio::test]
+    async fn git_remote_is_cloned_at_requested_branch() {
+        let origin = tempfile::tempdir().unwrap();
+        write_marketplace(origin.path());
+        crate::git::ensure_repo(origin.path()).unwrap();
+        crate::git::commit_all(origin.path(), "initial").unwrap();
+        let repo = git2::Repository::open(origin.path()).unwrap();
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        repo.branch("release", &head, false).unwrap();
+
+        let source = RepoSource::Git {
+            url: origin.path().to_string_lossy().to_string(),
+            branch: Some("release".to_string()),
+            subpath: None,
+        };
+        let snapshot =
+            SourceSnapshot::open(&source, &GitHubAccess::anonymous(GitHubHost::public()))
+                .await
+                .unwrap();
+        assert_eq!(snapshot.branch, "release");
+        assert!(tree_paths(&snapshot.tree().await.unwrap())
+            .contains(&"eng/skills/standup/SKILL.md".to_string()));
+        let skill = snapshot
+            .read_text("eng/skills/standup/SKILL.md")
+            .await
+            .unwrap();
+        assert!(skill.contains("name: standup"));
+    }
+
+    #[tokio::test]
+    async fn git_remote_is_checked_out_at_pinned_commit() {
+        let origin = tempfile::tempdir().unwrap();
+        write_marketplace(origin.path());
+        crate::git::ensure_repo(origin.path()).unwrap();
+        crate::git::commit_all(origin.path(), "initial").unwrap();
+        let pinned = git2::Repository::open(origin.path())
+            .unwrap()
+            .head()
+            .unwrap()
+            .peel_to_commit()
+            .unwrap()
+            .id()
+            .to_string();
+        std::fs::write(origin.path().join("later.md"), "later").unwrap();
+        crate::git::commit_all(origin.path(), "later").unwrap();
+
+        let source = RepoSource::Git {
+            url: origin.path().to_string_lossy().to_string(),
+            branch: None,
+            subpath: None,
+        }
+        .at_ref(Some(&pinned));
+        let mut snapshot =
+            SourceSnapshot::open(&source, &GitHubAccess::anonymous(GitHubHost::public()))
+                .await
+                .unwrap();
+        assert_eq!(snapshot.branch, pinned);
+        assert_eq!(snapshot.pin().await.as_deref(), Some(pinned.as_str()));
+        assert!(!tree_paths(&snapshot.tree().await.unwrap()).contains(&"later.md".to_string()));
+    }
+
+    #[tokio::test]
+    async fn private_hosts_get_their_configured_token() {
+        let mut server = mockito::Server::new_async().await;
+        let _gitlab = server
+            .mock("GET", "/api/v4/projects/team%2Fskills")
+            .match_header("PRIVATE-TOKEN", "gl-token")
+            .with_status(200)
+            .with_body(r#"{"default_branch":"trunk"}"#)
+            .create_async()
+            .await;
+        let _gitea = server
+            .mock("GET", "/api/v1/repos/team/skills")
+            .match_header("Authorization", "token gl-token")
+            .with_status(200)
+            .with_body(r#"{"default_branch":"main"}"#)
+            .create_async()
+            .await;
+
+        let host = server.host_with_port();
+        let access = GitHubAccess {
+            source_tokens: vec![crate::types::SourceHostToken {
+                host: host.to_uppercase(),
+                token: "gl-token".to_string(),
+            }],
+            ..GitHubAccess::anonymous(GitHubHost::public())
+        };
+        let gitlab = parse(&format!("gitlab+{}/team/skills", server.url())).unwrap();
+        assert_eq!(
+            SourceSnapshot::open(&gitlab, &access).await.unwrap().branch,
+            "trunk"
+        );
+        let gitea = parse(&format!("gitea+{}/team/skills", server.url())).unwrap();
+        assert_eq!(
+            SourceSnapshot::open(&gitea, &access).await.unwrap().branch,
+            "main"
+        );
+        assert!(
+            SourceSnapshot::open(&gitlab, &GitHubAccess::anonymous(GitHubHost::public()))
+                .await
+                .is_err()
+        );
+    }
+
+    #[tokio::test]
+    async fn gitlab_tree_and_files_use_the_v4_api() {
+        let mut server = mockito::Server::new_async().await;
+        let _project = server
+            .mock("GET", "/api/v4/projects/team%2Fskills")
+            .with_status(200)
+            .with_body(r#"{"default_branch":"trunk"}"#)
+            .create_async()
+            .await;
+        let _tree = server
+            .mock("GET", "/api/v4/projects/team%2Fskills/repository/tree")
+            .match_query(mockito::Matcher::AllOf(vec![
+                mockito::Matcher::UrlEncoded("ref".into(), "trunk".into()),
+                mockito::Matcher::UrlEncoded("page".into(), "1".into()),
+            ]))
+            .with_status(200)
+            .with_body(r#"[{"path":"eng/skills/standup/SKILL.md","type":"blob"}]"#)
+            .create_async()
+            .await;
+        let _file = server
+            .mock(
+                "GET",
+                "/api/v4/projects/team%2Fskills/repository/files/eng%2Fskills%2Fstandup%2FSKILL.md/raw",
+            )
+            .match_query(mockito::Matcher::UrlEncoded("ref".into(), "trunk".into()))
+            .with_status(200)
+            .with_body("---\nname: standup\n---\n")
+            .create_async()
+            .await;
+
+        let source = parse(&format!("gitlab+{}/team/skills", server.url())).unwrap();
+        let snapshot =
+            SourceSnapshot::open(&source, &GitHubAccess::anonymous(GitHubHost::public()))
+                .await
+                .unwrap();
+        assert_eq!(snapshot.branch, "trunk");
+        assert_eq!(
+            tree_paths(&snapshot.tree().await.unwrap()),
+            vec!["eng/skills/standup/SKILL.md"]
+        );
+        assert!(snapshot
+            .read_text("eng/skills/standup/SKILL.md")
+            .await
+            .unwrap()
+            .contains("standup"));
+    }
+}
@@ -3,8 +3,9 @@ use std::collections::{HashMap, HashSet};
 use crate::db::Db;
 use crate::types::AvailableSkill;
 
-use super::commands::list_github_skills_inner;
-use super::url::parse_github_url_inner;
+use super::cache::CatalogCache;
+use super::commands::list_source_skills_inner;
+use super::source::parse_repo_source;
 
 // ---------------------------------------------------------------------------
 // check_marketplace_updates
@@ -119,7 +120,7 @@ pub async fn check_marketplace_updates(
 ) -> Result<MarketplaceUpdateResult, String> {
     log::info!("[check_marketplace_updates] checking all enabled registries");
 
-    let (token, enabled_sources, installed_rows) = {
+    let (github, cache, enabled_sources, installed_rows) = {
         let conn = db.0.lock().map_err(|e| {
             log::error!(
                 "[check_marketplace_updates] failed to acquire DB lock: {}",
@@ -128,6 +129,8 @@ pub async fn check_marketplace_updates(
             e.to_string()
         })?;
         let settings = crate::db::read_settings(&conn)?;
+        let github = crate::github_host::GitHubAccess::from_settings(&settings);
+        let cache = CatalogCache::from_settings(&db, &settings);
         let enabled_sources: HashSet<String> = settings
             .marketplace_registries
             .into_iter()
@@ -135,11 +138,7 @@ pub async fn check_marketplace_updates(
             .map(|r| r.source_url)
             .collect();
         let installed_rows = load_installed_marketplace_skills(&conn)?;
-        (
-            settings.github_oauth_token.clone(),
-            enabled_sources,
-            installed_rows,
-        )
+        (github, cache, enabled_sources, installed_rows)
     };
 
     let mut by_source: HashMap<String, Vec<InstalledMarketplaceSkill>> = HashMap::new();
@@ -158,8 +157,8 @@ pub async fn check_marketplace_updates(
     let mut registry_names = Vec::new();
 
     for source_url in all_sources {
-        let repo_info = match parse_github_url_inner(&source_url) {
-            Ok(info) => info,
+        let source = match parse_repo_source(&source_url, &github.host) {
+            Ok(source) => source,
             Err(err) => {
                 log::warn!(
                     "[check_marketplace_updates] skipping source '{}' due to parse error: {}",
@@ -169,14 +168,7 @@ pub async fn check_marketplace_updates(
                 continue;
             }
         };
-        let list_result = list_github_skills_inner(
-            &repo_info.owner,
-            &repo_info.repo,
-            &repo_info.branch,
-            repo_info.subpath.as_deref(),
-            token.as_deref(),
-        )
-        .await;
+        let list_result = list_source_skills_inner(&source, &github, Some(&cache)).await;
         let (registry_name, available) = match list_result {
             Ok(v) => v,
             Err(err) => {
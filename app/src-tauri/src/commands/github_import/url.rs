@@ -1,4 +1,4 @@
-use crate::types::GitHubRepoInfo;
+use crate::types::{GitHubRepoInfo, SkillSourceInfo};
 
 /// Parse a GitHub URL or shorthand into structured repo info.
 ///
@@ -15,6 +15,21 @@ pub fn parse_github_url(url: String) -> Result<GitHubRepoInfo, String> {
     parse_github_url_inner(&url)
 }
 
+/// Classify a marketplace source: GitHub, GitLab, Gitea, any git remote or a
+/// local folder. See [`super::source::parse_repo_source`] for the accepted forms.
+#[tauri::command]
+pub fn parse_skill_source(
+    db: tauri::State<'_, crate::db::Db>,
+    url: String,
+) -> Result<SkillSourceInfo, String> {
+    log::info!("[parse_skill_source] url={}", url);
+    let github = {
+        let conn = db.0.lock().map_err(|e| e.to_string())?;
+        crate::github_host::GitHubHost::from_settings(&crate::db::read_settings(&conn)?)
+    };
+    super::source::skill_source_info(&url, &github)
+}
+
 pub(crate) fn parse_github_url_inner(url: &str) -> Result<GitHubRepoInfo, String> {
     let url = url.trim();
     if url.is_empty() {
@@ -83,6 +83,81 @@ pub(crate) fn find_skill_md(
     }
 }
 
+/// Find every skill in the archive: each `SKILL.md` at any depth, except those
+/// nested inside another skill and anything under a hidden or `__MACOSX/`
+/// directory. Returns (directory prefix, SKILL.md content) sorted by prefix;
+/// the prefix is `""` for a root-level skill and ends with `/` otherwise.
+pub(crate) fn find_all_skill_mds(
+    archive: &mut zip::ZipArchive<std::fs::File>,
+) -> Result<Vec<(String, String)>, String> {
+    let mut candidates: Vec<(usize, String)> = Vec::new();
+    for i in 0..archive.len() {
+        let file = archive.by_index(i).map_err(|e| e.to_string())?;
+        let name = file.name().trim_start_matches("./").to_string();
+        drop(file);
+
+        let Some(prefix) = name.strip_suffix("SKILL.md") else {
+            continue;
+        };
+        if !(prefix.is_empty() || prefix.ends_with('/')) {
+            continue;
+        }
+        let hidden = prefix
+            .split('/')
+            .any(|part| part.starts_with('.') || part == "__MACOSX");
+        if !hidden {
+            candidates.push((i, prefix.to_string()));
+        }
+    }
+    candidates.sort_by(|a, b| a.1.cmp(&b.1));
+
+    let mut skills: Vec<(String, String)> = Vec::new();
+    for (idx, prefix) in candidates {
+        // Sorted order puts a skill before anything nested inside it.
+        if skills
+            .iter()
+            .any(|(outer, _)| prefix.starts_with(outer.as_str()))
+        {
+            continue;
+        }
+        let mut content = String::new();
+        archive
+            .by_index(idx)
+            .map_err(|e| e.to_string())?
+            .read_to_string(&mut content)
+            .map_err(|e| e.to_string())?;
+        skills.push((prefix, content));
+    }
+    if skills.is_empty() {
+        return Err("Invalid skill package: no SKILL.md found".to_string());
+    }
+    Ok(skills)
+}
+
+/// Read `.claude-plugin/plugin.json` at the archive root or one level deep.
+pub(crate) fn find_plugin_json(
+    archive: &mut zip::ZipArchive<std::fs::File>,
+) -> Option<serde_json::Value> {
+    let index = (0..archive.len()).find(|&i| {
+        archive.by_index(i).is_ok_and(|file| {
+            let parts: Vec<&str> = file
+                .name()
+                .trim_start_matches("./")
+                .split('/')
+                .filter(|p| !p.is_empty())
+                .collect();
+            parts.ends_with(&[".claude-plugin", "plugin.json"]) && parts.len() <= 3
+        })
+    })?;
+    let mut content = String::new();
+    archive
+        .by_index(index)
+        .ok()?
+        .read_to_string(&mut content)
+        .ok()?;
+    serde_json::from_str(&content).ok()
+}
+
 /// Determine the prefix to strip when extracting files.
 /// If SKILL.md is at "dirname/SKILL.md", the prefix is "dirname/".
 /// If at root, the prefix is empty.
@@ -201,12 +201,61 @@ pub fn list_plugins(db: tauri::State<'_, Db>) -> Result<Vec<crate::types::Librar
     crate::db::list_plugins(&conn)
 }
 
+/// Slugs of installed plugins that declare a dependency on `plugin_slug`.
+/// The UI asks before deleting a plugin others still require.
 #[tauri::command]
-pub fn delete_plugin(plugin_slug: String, db: tauri::State<'_, Db>) -> Result<(), String> {
-    log::info!("[delete_plugin] slug={}", plugin_slug);
+pub fn plugin_dependents(
+    plugin_slug: String,
+    db: tauri::State<'_, Db>,
+) -> Result<Vec<String>, String> {
+    log::info!("[plugin_dependents] slug={}", plugin_slug);
+    let conn = db.0.lock().map_err(|e| e.to_string())?;
+    crate::db::list_plugin_dependents(&conn, &plugin_slug)
+}
+
+/// Refuse to delete a plugin other plugins depend on unless `force` is set.
+fn check_plugin_dependents(
+    conn: &rusqlite::Connection,
+    plugin_slug: &str,
+    force: bool,
+) -> Result<Vec<String>, String> {
+    let dependents = crate::db::list_plugin_dependents(conn, plugin_slug)?;
+    if dependents.is_empty() {
+        return Ok(dependents);
+    }
+    if !force {
+        return Err(format!(
+            "'{}' is required by {}; confirm to delete it anyway",
+            plugin_slug,
+            dependents.join(", ")
+        ));
+    }
+    log::warn!(
+        "[delete_plugin] slug={} is required by: {}",
+        plugin_slug,
+        dependents.join(", ")
+    );
+    Ok(dependents)
+}
+
+/// Delete a plugin with its skills and files. Fails while other installed
+/// plugins depend on it unless `force` is set; then returns their slugs,
+/// which are now left with a missing dependency.
+#[tauri::command]
+pub fn delete_plugin(
+    plugin_slug: String,
+    force: bool,
+    db: tauri::State<'_, Db>,
+) -> Result<Vec<String>, String> {
+    log::info!("[delete_plugin] slug={} force={}", plugin_slug, force);
     let conn = db.0.lock().map_err(|e| e.to_string())?;
     let settings = crate::db::read_settings(&conn)?;
 
+    let dependents = check_plugin_dependents(&conn, &plugin_slug, force).map_err(|e| {
+        log::error!("[delete_plugin] {}", e);
+        e
+    })?;
+
     let active_count: i64 = conn
         .query_row(
             "SELECT COUNT(*) FROM skills s JOIN plugins p ON s.plugin_id = p.id \
@@ -322,7 +371,7 @@ pub fn delete_plugin(plugin_slug: String, db: tauri::State<'_, Db>) -> Result<()
         );
     }
 
-    Ok(())
+    Ok(dependents)
 }
 
 #[tauri::command]
@@ -475,6 +524,28 @@ pub fn set_plugin_upgrade_lock(
     crate::db::set_plugin_upgrade_locked(&conn, &plugin_slug, locked)
 }
 
+/// Lint `marketplace.json` and every `plugin.json` under the skills path.
+#[tauri::command]
+pub fn lint_plugin_manifests(
+    db: tauri::State<'_, Db>,
+) -> Result<crate::types::ManifestLintReport, String> {
+    log::info!("[lint_plugin_manifests]");
+    let skills_path = {
+        let conn = db.0.lock().map_err(|e| e.to_string())?;
+        crate::db::read_settings(&conn)?
+            .skills_path
+            .ok_or_else(|| "Skills path not configured".to_string())?
+    };
+    let report = crate::marketplace_manifest::lint_manifests(Path::new(&skills_path));
+    log::info!(
+        "[lint_plugin_manifests] plugins={} errors={} warnings={}",
+        report.plugins_checked,
+        report.error_count,
+        report.warning_count
+    );
+    Ok(report)
+}
+
 #[tauri::command]
 pub fn delete_imported_skill(skill_id: String, db: tauri::State<'_, Db>) -> Result<(), String> {
     let skill_id = skill_id
@@ -639,6 +710,29 @@ mod tests {
         assert_eq!(skill_count, 0);
     }
 
+    #[test]
+    fn test_delete_plugin_requires_force_while_dependents_exist() {
+        let conn = create_test_db_for_tests();
+        crate::db::create_plugin(&conn, "Analytics", "local", None, None).unwrap();
+        crate::db::create_plugin(&conn, "Data Glossary", "local", None, Some("1.0.0")).unwrap();
+        crate::db::set_plugin_dependencies(
+            &conn,
+            "analytics",
+            &std::collections::BTreeMap::from([("data-glossary".to_string(), "*".to_string())]),
+        )
+        .unwrap();
+
+        let err = check_plugin_dependents(&conn, "data-glossary", false).unwrap_err();
+        assert!(err.contains("required by analytics"), "got: {err}");
+        assert_eq!(
+            check_plugin_dependents(&conn, "data-glossary", true).unwrap(),
+            vec!["analytics"]
+        );
+        assert!(check_plugin_dependents(&conn, "analytics", false)
+            .unwrap()
+            .is_empty());
+    }
+
     #[test]
     fn test_move_skill_to_plugin_db_operation() {
         let conn = create_test_db_for_tests();
@@ -5,8 +5,10 @@ use std::path::Path;
 
 use super::frontmatter::parse_frontmatter_full;
 use super::helpers::{
-    extract_archive, find_skill_md, generate_skill_id, get_archive_prefix, validate_skill_name,
+    extract_archive, find_all_skill_mds, find_plugin_json, find_skill_md, generate_skill_id,
+    get_archive_prefix, validate_skill_name,
 };
+use crate::commands::github_import::MarketplaceImportResult;
 
 // ---------------------------------------------------------------------------
 // parse_skill_file / import_skill_from_file
@@ -81,6 +83,260 @@ pub fn import_skill_from_file(
     Ok(skill_id)
 }
 
+// ---------------------------------------------------------------------------
+// preview_skill_archive / import_skill_archive
+// ---------------------------------------------------------------------------
+
+fn read_archive_preview(file_path: &str) -> Result<crate::types::SkillArchivePreview, String> {
+    let zip_file =
+        std::fs::File::open(file_path).map_err(|e| format!("Failed to open file: {}", e))?;
+    let mut archive =
+        zip::ZipArchive::new(zip_file).map_err(|_| "not a valid skill package".to_string())?;
+    let plugin_json = find_plugin_json(&mut archive);
+    let plugin_field = |key: &str| {
+        plugin_json
+            .as_ref()
+            .and_then(|pj| pj.get(key))
+            .and_then(|v| v.as_str())
+            .map(str::to_string)
+    };
+
+    let skills = find_all_skill_mds(&mut archive)?
+        .into_iter()
+        .map(|(path, content)| {
+            let fm = parse_frontmatter_full(&content);
+            let dir_name = path.trim_end_matches('/').rsplit('/').next().unwrap_or("");
+            crate::types::ArchiveSkillMeta {
+                meta: crate::types::SkillFileMeta {
+                    name: fm
+                        .name
+                        .or_else(|| (!dir_name.is_empty()).then(|| dir_name.to_string())),
+                    description: fm.description,
+                    version: fm.version,
+                    user_invocable: fm.user_invocable,
+                    disable_model_invocation: fm.disable_model_invocation,
+                },
+                path,
+            }
+        })
+        .collect();
+
+    Ok(crate::types::SkillArchivePreview {
+        plugin_name: plugin_field("name"),
+        plugin_description: plugin_field("description"),
+        plugin_version: plugin_field("version"),
+        skills,
+    })
+}
+
+/// List every skill in an uploaded archive — a single skill, several skills,
+/// or a whole plugin folder — with its parsed frontmatter.
+#[tauri::command]
+pub fn preview_skill_archive(
+    file_path: String,
+) -> Result<crate::types::SkillArchivePreview, String> {
+    log::info!("[preview_skill_archive] file_path={}", file_path);
+    read_archive_preview(&file_path).map_err(|e| {
+        log::error!("[preview_skill_archive] {}", e);
+        e
+    })
+}
+
+/// Resolve the plugin archive skills go into: an existing non-marketplace
+/// plugin by slug, or a new local plugin created from `new_plugin_name`.
+/// A new plugin is rolled back by [`discard_new_plugin`] if nothing imports.
+fn resolve_upload_target(
+    conn: &rusqlite::Connection,
+    skills_path: &str,
+    plugin_slug: Option<&str>,
+    new_plugin_name: Option<&str>,
+    preview: &crate::types::SkillArchivePreview,
+) -> Result<UploadTarget, String> {
+    match (plugin_slug, new_plugin_name.map(str::trim)) {
+        (Some(slug), None) => {
+            let plugin = crate::db::list_plugins(conn)?
+                .into_iter()
+                .find(|p| p.slug == slug)
+                .ok_or_else(|| format!("Plugin '{}' not found", slug))?;
+            if plugin.source_type == "marketplace" {
+                return Err(format!(
+                    "Cannot upload skills into marketplace plugin '{}'",
+                    plugin.display_name
+                ));
+            }
+            Ok(UploadTarget {
+                slug: plugin.slug,
+                display_name: plugin.display_name,
+                is_default: plugin.is_default,
+                is_new: false,
+            })
+        }
+        (None, Some(name)) if !name.is_empty() => {
+            if crate::db::get_plugin_id_by_slug(conn, &crate::db::slugify_plugin_name(name))?
+                .is_some()
+            {
+                return Err(format!("A plugin named '{}' already exists", name));
+            }
+            let (_, slug) = crate::db::create_plugin(
+                conn,
+                name,
+                "local",
+                None,
+                preview.plugin_version.as_deref(),
+            )?;
+            let skills_root = Path::new(skills_path);
+            std::fs::create_dir_all(skills_root.join(&slug).join("skills"))
+                .map_err(|e| format!("Failed to create plugin directory: {}", e))?;
+            crate::marketplace_manifest::write_plugin_json(
+                skills_root,
+                &slug,
+                name,
+                preview.plugin_description.as_deref(),
+                preview.plugin_version.as_deref(),
+            )?;
+            crate::marketplace_manifest::write_marketplace_json(skills_root)?;
+            Ok(UploadTarget {
+                slug,
+                display_name: name.to_string(),
+                is_default: false,
+                is_new: true,
+            })
+        }
+        _ => Err("Choose an existing plugin or name a new one".to_string()),
+    }
+}
+
+/// Remove a plugin [`resolve_upload_target`] created for an upload in which
+/// every skill failed, so a failed upload leaves no empty plugin behind.
+fn discard_new_plugin(
+    conn: &rusqlite::Connection,
+    skills_path: &str,
+    slug: &str,
+) -> Result<(), String> {
+    crate::db::delete_plugin_by_slug(conn, slug)?;
+    let skills_root = Path::new(skills_path);
+    let plugin_dir = skills_root.join(slug);
+    if plugin_dir.exists() {
+        std::fs::remove_dir_all(&plugin_dir)
+            .map_err(|e| format!("Failed to remove {}: {}", plugin_dir.display(), e))?;
+    }
+    crate::marketplace_manifest::write_marketplace_json(skills_root)
+}
+
+#[allow(clippy::too_many_arguments)]
+fn import_skill_archive_inner(
+    conn: &rusqlite::Connection,
+    file_path: &str,
+    skill_paths: &[String],
+    plugin_slug: Option<&str>,
+    new_plugin_name: Option<&str>,
+    skills_path: &str,
+    preferred_author: Option<&str>,
+) -> Result<Vec<MarketplaceImportResult>, String> {
+    let preview = read_archive_preview(file_path)?;
+    let selected: Vec<&crate::types::ArchiveSkillMeta> = preview
+        .skills
+        .iter()
+        .filter(|s| skill_paths.contains(&s.path))
+        .collect();
+    if selected.is_empty() {
+        return Err("No skills selected".to_string());
+    }
+    let target = resolve_upload_target(conn, skills_path, plugin_slug, new_plugin_name, &preview)?;
+
+    let mut results = Vec::new();
+    for skill in selected {
+        let name = skill.meta.name.clone().unwrap_or_default();
+        let result = import_archive_skill(
+            conn,
+            file_path,
+            &skill.path,
+            &target,
+            &SkillUpload {
+                name: &name,
+                description: skill.meta.description.as_deref(),
+                version: skill.meta.version.as_deref(),
+                user_invocable: skill.meta.user_invocable,
+                disable_model_invocation: skill.meta.disable_model_invocation,
+            },
+            skills_path,
+            preferred_author,
+        );
+        if let Err(e) = &result {
+            log::warn!("[import_skill_archive] '{}' failed: {}", name, e);
+        }
+        results.push(MarketplaceImportResult {
+            skill_name: name,
+            success: result.is_ok(),
+            dependency_of: None,
+            missing_dependency: None,
+            error: result.err(),
+        });
+    }
+    if target.is_new && !results.iter().any(|r| r.success) {
+        log::info!(
+            "[import_skill_archive] nothing imported; removing new plugin '{}'",
+            target.slug
+        );
+        if let Err(e) = discard_new_plugin(conn, skills_path, &target.slug) {
+            log::warn!(
+                "[import_skill_archive] failed to remove plugin '{}': {}",
+                target.slug,
+                e
+            );
+        }
+    }
+    Ok(results)
+}
+
+/// Import the skills at `skill_paths` (from `preview_skill_archive`) into an
+/// existing plugin (`plugin_slug`) or a new one (`new_plugin_name`).
+#[tauri::command]
+pub fn import_skill_archive(
+    file_path: String,
+    skill_paths: Vec<String>,
+    plugin_slug: Option<String>,
+    new_plugin_name: Option<String>,
+    db: tauri::State<'_, Db>,
+) -> Result<Vec<MarketplaceImportResult>, String> {
+    log::info!(
+        "[import_skill_archive] file_path={} skills={} plugin_slug={:?} new_plugin_name={:?}",
+        file_path,
+        skill_paths.len(),
+        plugin_slug,
+        new_plugin_name
+    );
+    let conn = db.0.lock().map_err(|e| {
+        log::error!("[import_skill_archive] failed to acquire DB lock: {}", e);
+        e.to_string()
+    })?;
+    let settings = crate::db::read_settings(&conn).map_err(|e| {
+        log::error!("[import_skill_archive] failed to read settings: {}", e);
+        e
+    })?;
+    let skills_path = settings
+        .skills_path
+        .clone()
+        .ok_or_else(|| "Skills path not configured. Set it in Settings.".to_string())?;
+    let preferred_author = settings
+        .github_user_email
+        .clone()
+        .or(settings.github_user_login.clone());
+    import_skill_archive_inner(
+        &conn,
+        &file_path,
+        &skill_paths,
+        plugin_slug.as_deref(),
+        new_plugin_name.as_deref(),
+        &skills_path,
+        preferred_author.as_deref(),
+    )
+    .map_err(|e| {
+        log::error!("[import_skill_archive] {}", e);
+        e
+    })
+}
+
 #[allow(clippy::too_many_arguments)]
 fn import_skill_from_file_inner(
     conn: &rusqlite::Connection,
@@ -103,47 +359,124 @@ fn import_skill_from_file_inner(
     let (skill_md_path, _) = find_skill_md(&mut archive)?;
     let prefix = get_archive_prefix(&skill_md_path);
 
+    import_archive_skill(
+        conn,
+        file_path,
+        &prefix,
+        &UploadTarget::default_plugin(),
+        &SkillUpload {
+            name,
+            description: Some(description),
+            version,
+            user_invocable,
+            disable_model_invocation,
+        },
+        skills_path,
+        preferred_author,
+    )
+}
+
+/// Plugin an uploaded skill is imported into.
+struct UploadTarget {
+    slug: String,
+    display_name: String,
+    is_default: bool,
+    /// Created for this upload; removed again if no skill imports.
+    is_new: bool,
+}
+
+impl UploadTarget {
+    fn default_plugin() -> Self {
+        Self {
+            slug: DEFAULT_PLUGIN_SLUG.to_string(),
+            display_name: crate::skill_paths::DEFAULT_PLUGIN_DISPLAY_NAME.to_string(),
+            is_default: true,
+            is_new: false,
+        }
+    }
+}
+
+/// Metadata the user confirmed for one uploaded skill.
+struct SkillUpload<'a> {
+    name: &'a str,
+    description: Option<&'a str>,
+    version: Option<&'a str>,
+    user_invocable: Option<bool>,
+    disable_model_invocation: Option<bool>,
+}
+
+/// Extract the skill under `prefix` in the archive into `target`, then commit,
+/// tag and record it like any other upload.
+fn import_archive_skill(
+    conn: &rusqlite::Connection,
+    file_path: &str,
+    prefix: &str,
+    target: &UploadTarget,
+    upload: &SkillUpload<'_>,
+    skills_path: &str,
+    preferred_author: Option<&str>,
+) -> Result<String, String> {
+    let name = upload.name;
+    validate_skill_name(name)?;
+    let plugin_slug = target.slug.as_str();
+
     // Conflict check: reject upload if a skill with this name already exists
-    // in the default plugin (skills folder)
-    let default_slug = crate::skill_paths::DEFAULT_PLUGIN_SLUG;
+    // in the target plugin
     let existing_source: Option<String> = conn
         .query_row(
             "SELECT s.skill_source
              FROM skills s
              JOIN plugins p ON p.id = s.plugin_id
              WHERE s.name = ?1 AND p.slug = ?2",
-            rusqlite::params![&name, default_slug],
+            rusqlite::params![&name, plugin_slug],
             |row| row.get::<_, String>(0),
         )
         .optional()
         .map_err(|e| e.to_string())?;
 
     if existing_source.is_some() {
+        let location = if target.is_default {
+            "the default plugin".to_string()
+        } else {
+            format!("plugin '{}'", target.display_name)
+        };
         return Err(format!(
-            "A skill named '{}' already exists in the default plugin. Rename or delete it first.",
-            name
+            "A skill named '{}' already exists in {}. Rename or delete it first.",
+            name, location
         ));
     }
 
-    // Extract to plugin-nested path: {skills_path}/{default_slug}/{name}/
-    let dest_dir =
-        crate::skill_paths::resolve_skill_dir(Path::new(skills_path), default_slug, name);
+    // Extract to plugin-nested path: {skills_path}/{plugin_slug}/{name}/
+    let dest_dir = crate::skill_paths::resolve_skill_dir(Path::new(skills_path), plugin_slug, name);
     if let Some(parent) = dest_dir.parent() {
         std::fs::create_dir_all(parent).map_err(|e| e.to_string())?;
     }
     std::fs::create_dir_all(&dest_dir).map_err(|e| e.to_string())?;
-    // Re-open archive (consumed during prefix scan)
-    let zip_file2 =
+    let zip_file =
         std::fs::File::open(file_path).map_err(|e| format!("Failed to re-open file: {}", e))?;
-    let mut archive2 =
-        zip::ZipArchive::new(zip_file2).map_err(|_| "not a valid skill package".to_string())?;
-    extract_archive(&mut archive2, &prefix, &dest_dir)?;
+    let mut archive =
+        zip::ZipArchive::new(zip_file).map_err(|_| "not a valid skill package".to_string())?;
+    extract_archive(&mut archive, prefix, &dest_dir)?;
+    if !dest_dir.join("SKILL.md").is_file() {
+        let _ = std::fs::remove_dir_all(&dest_dir);
+        return Err(format!("No SKILL.md under '{}' in the archive", prefix));
+    }
+
+    // Packages exported with history carry the skill repo as a git bundle
+    let bundle_path = dest_dir.join(crate::git::HISTORY_BUNDLE_FILE);
+    let restored_history = bundle_path.is_file();
+    if restored_history {
+        let restored = crate::git::restore_history_bundle(&dest_dir, &bundle_path);
+        let _ = std::fs::remove_file(&bundle_path);
+        if let Err(e) = restored {
+            let _ = std::fs::remove_dir_all(&dest_dir);
+            return Err(e);
+        }
+    }
 
-    let skill_dir_for_git =
-        crate::skill_paths::resolve_skill_dir(Path::new(skills_path), default_slug, name);
     let normalized_frontmatter = match super::frontmatter::ensure_skill_frontmatter_metadata(
         &dest_dir.join("SKILL.md"),
-        version,
+        upload.version,
         preferred_author,
     ) {
         Ok(normalized) => normalized,
@@ -155,27 +488,23 @@ fn import_skill_from_file_inner(
     let final_version = normalized_frontmatter.version.clone();
 
     let import_git_result = (|| -> Result<(), String> {
-        crate::git::ensure_repo(&skill_dir_for_git)
+        crate::git::ensure_repo(&dest_dir)
             .map_err(|e| format!("Failed to init git repo: {}", e))?;
-        if crate::git::skill_version_tag_exists(
-            &skill_dir_for_git,
-            default_slug,
-            name,
-            &final_version,
-        )? {
+        // A restored history may already carry the version tag; anywhere
+        // else an existing tag means a leftover repo and is a conflict
+        let tag_exists =
+            crate::git::skill_version_tag_exists(&dest_dir, plugin_slug, name, &final_version)?;
+        if tag_exists && !restored_history {
             return Err(format!(
                 "Tag '{}' already exists",
-                crate::git::skill_version_tag_name(default_slug, name, &final_version)
+                crate::git::skill_version_tag_name(plugin_slug, name, &final_version)
             ));
         }
 
-        crate::git::commit_all(&skill_dir_for_git, &format!("{}: import from upload", name))?;
-        crate::git::create_skill_version_tag(
-            &skill_dir_for_git,
-            default_slug,
-            name,
-            &final_version,
-        )?;
+        crate::git::commit_all(&dest_dir, &format!("{}: import from upload", name))?;
+        if !tag_exists {
+            crate::git::create_skill_version_tag(&dest_dir, plugin_slug, name, &final_version)?;
+        }
         Ok(())
     })();
     if let Err(e) = import_git_result {
@@ -183,19 +512,19 @@ fn import_skill_from_file_inner(
         return Err(e);
     }
 
-    // Step 1: Create/update skill master row (linked to default plugin)
+    // Step 1: Create/update skill master row (linked to the target plugin)
     let skill_master_id = crate::db::upsert_skill_with_source_in_plugin(
         conn,
         name,
         "imported",
         "domain",
-        DEFAULT_PLUGIN_SLUG,
+        plugin_slug,
     )?;
 
     // Update description on skill master
     conn.execute(
         "UPDATE skills SET description = ?2 WHERE id = ?1",
-        rusqlite::params![skill_master_id, description],
+        rusqlite::params![skill_master_id, upload.description],
     )
     .map_err(|e| e.to_string())?;
 
@@ -209,15 +538,15 @@ fn import_skill_from_file_inner(
         disk_path: dest_dir.to_string_lossy().to_string(),
         imported_at,
         is_bundled: false,
-        description: Some(description.to_string()),
+        description: upload.description.map(str::to_string),
         purpose: Some("domain".to_string()),
         version: Some(final_version),
-        user_invocable,
-        disable_model_invocation,
+        user_invocable: upload.user_invocable,
+        disable_model_invocation: upload.disable_model_invocation,
         marketplace_source_url: None,
-        plugin_slug: Some(DEFAULT_PLUGIN_SLUG.to_string()),
-        plugin_display_name: Some(crate::skill_paths::DEFAULT_PLUGIN_DISPLAY_NAME.to_string()),
-        is_default_plugin: Some(true),
+        plugin_slug: Some(plugin_slug.to_string()),
+        plugin_display_name: Some(target.display_name.clone()),
+        is_default_plugin: Some(target.is_default),
     };
     crate::db::upsert_imported_skill(conn, &skill, skill_master_id)?;
 
@@ -231,7 +560,7 @@ fn import_skill_from_file_inner(
 
 #[cfg(test)]
 mod tests {
-    use super::import_skill_from_file_inner;
+    use super::{import_skill_archive_inner, import_skill_from_file_inner, read_archive_preview};
     use tempfile::tempdir;
 
     fn write_skill_zip(zip_path: &std::path::Path, skill_md: &str) {
@@ -438,4 +767,249 @@ mod tests {
             err
         );
     }
+
+    fn write_archive_zip(zip_path: &std::path::Path, files: &[(&str, &str)]) {
+        let file = std::fs::File::create(zip_path).unwrap();
+        let mut writer = zip::ZipWriter::new(file);
+        let options = zip::write::SimpleFileOptions::default();
+        for (name, content) in files {
+            writer.start_file(*name, options).unwrap();
+            std::io::Write::write_all(&mut writer, content.as_bytes()).unwrap();
+        }
+        writer.finish().unwrap();
+    }
+
+    #[test]
+    fn preview_skill_archive_detects_plugin_folder_and_all_skills() {
+        let dir = tempdir().unwrap();
+        let zip_path = dir.path().join("plugin.zip");
+        write_archive_zip(
+            &zip_path,
+            &[
+                (
+                    "analytics/.claude-plugin/plugin.json",
+                    r#"{"name":"analytics","description":"Analytics skills","version":"2.1.0"}"#,
+                ),
+                (
+                    "analytics/skills/weekly-report/SKILL.md",
+                    "---\nname: weekly-report\ndescription: Weekly\n---\n# Body\n",
+                ),
+                ("analytics/skills/churn/SKILL.md", "# No frontmatter\n"),
+                ("__MACOSX/analytics/skills/churn/SKILL.md", "junk"),
+            ],
+        );
+
+        let preview = read_archive_preview(zip_path.to_str().unwrap()).unwrap();
+
+        assert_eq!(preview.plugin_name.as_deref(), Some("analytics"));
+        assert_eq!(
+            preview.plugin_description.as_deref(),
+            Some("Analytics skills")
+        );
+        assert_eq!(preview.plugin_version.as_deref(), Some("2.1.0"));
+        let names: Vec<_> = preview
+            .skills
+            .iter()
+            .map(|s| s.meta.name.as_deref().unwrap())
+            .collect();
+        // Sorted by path; the skill without frontmatter falls back to its directory name
+        assert_eq!(names, vec!["churn", "weekly-report"]);
+        assert_eq!(
+            preview.skills[1].meta.description.as_deref(),
+            Some("Weekly")
+        );
+    }
+
+    #[test]
+    fn import_skill_archive_creates_new_plugin_and_imports_selected_skills() {
+        let conn = crate::db::create_test_db_for_tests();
+        let dir = tempdir().unwrap();
+        let skills_path = dir.path().join("skills");
+        std::fs::create_dir_all(&skills_path).unwrap();
+
+        let zip_path = dir.path().join("skills.zip");
+        write_archive_zip(
+            &zip_path,
+            &[
+                (
+                    "alpha/SKILL.md",
+                    "---\nname: alpha\ndescription: A\n---\n# A\n",
+                ),
+                (
+                    "beta/SKILL.md",
+                    "---\nname: beta\ndescription: B\n---\n# B\n",
+                ),
+                (
+                    "gamma/SKILL.md",
+                    "---\nname: gamma\ndescription: C\n---\n# C\n",
+                ),
+            ],
+        );
+
+        let results = import_skill_archive_inner(
+            &conn,
+            zip_path.to_str().unwrap(),
+            &["alpha/".to_string(), "beta/".to_string()],
+            None,
+            Some("Team Skills"),
+            skills_path.to_str().unwrap(),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(results.len(), 2);
+        assert!(results.iter().all(|r| r.success), "{:?}", results);
+        let slug = crate::db::slugify_plugin_name("Team Skills");
+        assert!(crate::db::get_plugin_id_by_slug(&conn, &slug)
+            .unwrap()
+            .is_some());
+        assert!(skills_path
+            .join(&slug)
+            .join(".claude-plugin")
+            .join("plugin.json")
+            .exists());
+        for name in ["alpha", "beta"] {
+            let skill_dir = crate::skill_paths::resolve_skill_dir(&skills_path, &slug, name);
+            assert!(skill_dir.join("SKILL.md").exists(), "{} missing", name);
+            let imported = crate::db::get_imported_skill(&conn, name, &slug)
+                .unwrap()
+                .unwrap();
+            assert_eq!(imported.plugin_slug.as_deref(), Some(slug.as_str()));
+        }
+        assert!(!crate::skill_paths::resolve_skill_dir(&skills_path, &slug, "gamma").exists());
+
+        // A second import into the same plugin reports the conflict per skill
+        let again = import_skill_archive_inner(
+            &conn,
+            zip_path.to_str().unwrap(),
+            &["alpha/".to_string(), "gamma/".to_string()],
+            Some(&slug),
+            None,
+            skills_path.to_str().unwrap(),
+            None,
+        )
+        .unwrap();
+        assert!(!again[0].success);
+        assert!(again[0]
+            .error
+            .as_deref()
+            .unwrap()
+            .contains("already exists"));
+        assert!(again[1].success, "{:?}", again[1]);
+    }
+
+    #[test]
+    fn import_skill_archive_removes_new_plugin_when_nothing_imports() {
+        let conn = crate::db::create_test_db_for_tests();
+        let dir = tempdir().unwrap();
+        let skills_path = dir.path().join("skills");
+        std::fs::create_dir_all(&skills_path).unwrap();
+
+        let zip_path = dir.path().join("skills.zip");
+        write_archive_zip(
+            &zip_path,
+            &[(
+                "bad/SKILL.md",
+                "---\nname: ..bad\ndescription: B\n---\n# B\n",
+            )],
+        );
+
+        let results = import_skill_archive_inner(
+            &conn,
+            zip_path.to_str().unwrap(),
+            &["bad/".to_string()],
+            None,
+            Some("Team Skills"),
+            skills_path.to_str().unwrap(),
+            None,
+        )
+        .unwrap();
+
+        assert_eq!(results.len(), 1);
+        assert!(!results[0].success);
+        let slug = crate::db::slugify_plugin_name("Team Skills");
+        assert!(crate::db::get_plugin_id_by_slug(&conn, &slug)
+            .unwrap()
+            .is_none());
+        assert!(!skills_path.join(&slug).exists());
+    }
+
+    #[test]
+    fn import_skill_from_file_restores_bundled_history_and_tags() {
+        let conn = crate::db::create_test_db_for_tests();
+        let dir = tempdir().unwrap();
+        let plugin = crate::skill_paths::DEFAULT_PLUGIN_SLUG;
+
+        // Source machine: a skill with two commits and a v1.0.0 tag
+        let source = dir.path().join("source");
+        crate::git::ensure_repo(&source).unwrap();
+        std::fs::write(
+            source.join("SKILL.md"),
+            "---\nname: travelling-skill\ndescription: Moves\n---\n# V1\n",
+        )
+        .unwrap();
+        crate::git::commit_all(&source, "travelling-skill: created").unwrap();
+        crate::git::create_skill_version_tag(&source, plugin, "travelling-skill", "1.0.0").unwrap();
+        std::fs::write(
+            source.join("SKILL.md"),
+            "---\nname: travelling-skill\ndescription: Moves\n---\n# V2\n",
+        )
+        .unwrap();
+        crate::git::commit_all(&source, "travelling-skill: refined").unwrap();
+
+        let zip_path = dir.path().join("travelling-skill.skill");
+        crate::commands::skill::export_skill_as_file_inner(
+            &source,
+            zip_path.to_str().unwrap(),
+            true,
+        )
+        .unwrap();
+
+        let skills_path = dir.path().join("skills");
+        std::fs::create_dir_all(&skills_path).unwrap();
+        import_skill_from_file_inner(
+            &conn,
+            zip_path.to_str().unwrap(),
+            "travelling-skill",
+            "Moves",
+            Some("1.1.0"),
+            None,
+            None,
+            skills_path.to_str().unwrap(),
+            None,
+        )
+        .unwrap();
+
+        let skill_dir =
+            crate::skill_paths::resolve_skill_dir(&skills_path, plugin, "travelling-skill");
+        assert!(!skill_dir.join(crate::git::HISTORY_BUNDLE_FILE).exists());
+        let history = crate::git::get_history(&skill_dir, "travelling-skill", plugin, 50).unwrap();
+        let version_of = |message: &str| {
+            history
+                .iter()
+                .find(|c| c.message.trim() == message)
+                .unwrap_or_else(|| panic!("missing commit '{}' in {:?}", message, history))
+                .version
+                .clone()
+        };
+        assert_eq!(history.len(), 4);
+        assert_eq!(
+            version_of("travelling-skill: created").as_deref(),
+            Some("1.0.0")
+        );
+        assert_eq!(version_of("travelling-skill: refined"), None);
+        assert_eq!(
+            version_of("travelling-skill: import from upload").as_deref(),
+            Some("1.1.0")
+        );
+
+        let workspace = tempdir().unwrap();
+        let baseline = crate::git::resolve_benchmark_baseline(
+            &skill_dir,
+            plugin,
+            "travelling-skill",
+            workspace.path(),
+        );
+        assert_eq!(baseline.mode, "prior_version");
+    }
 }
@@ -23,17 +23,16 @@ pub(crate) fn run_settings_startup_migrations(conn: &rusqlite::Connection) -> Re
     let mut settings = crate::db::read_settings(conn)?;
     let mut dirty = false;
 
-    // Normalize all stored registry URLs to canonical shorthand (owner/repo or owner/repo#branch).
-    // This migrates existing entries that were saved as full HTTPS URLs.
+    // Normalize stored GitHub registry URLs to canonical shorthand (owner/repo or owner/repo#branch)
+    // on the configured GitHub host. This migrates existing entries that were saved as full
+    // HTTPS URLs. Other sources (GitLab, Gitea, git remotes, local folders) are kept as entered.
+    let github = crate::github_host::GitHubHost::from_settings(&settings);
     for registry in &mut settings.marketplace_registries {
-        if let Ok(info) =
-            crate::commands::github_import::parse_github_url_inner(&registry.source_url)
+        if let Ok(crate::commands::github_import::RepoSource::GitHub(info, host)) =
+            crate::commands::github_import::parse_repo_source(&registry.source_url, &github)
         {
-            let canonical = if info.branch == "main" {
-                format!("{}/{}", info.owner, info.repo)
-            } else {
-                format!("{}/{}#{}", info.owner, info.repo, info.branch)
-            };
+            let canonical =
+                crate::commands::github_import::canonical_github_url(&info, &host, &github);
             if canonical != registry.source_url {
                 log::info!(
                     "[startup] normalizing registry url: {} -> {}",
@@ -213,6 +212,10 @@ fn persist_settings(
         let normalized = normalize_path(sp);
         settings.skills_path = Some(normalized);
     }
+    settings.github_host =
+        crate::github_host::normalize_github_host(settings.github_host.as_deref())?;
+    settings.feedback_repo =
+        crate::github_host::normalize_feedback_repo(settings.feedback_repo.as_deref())?;
 
     // Handle skills_path changes: first set → init; changed → move
     let old_settings = crate::db::read_settings(conn)?;
@@ -225,6 +228,22 @@ fn persist_settings(
     // or startup migrations.
     preserve_backend_owned_settings(&mut settings, &old_settings, log_scope);
 
+    settings.github_oauth_client_id = settings
+        .github_oauth_client_id
+        .as_deref()
+        .map(str::trim)
+        .filter(|id| !id.is_empty())
+        .map(str::to_string);
+
+    // The OAuth token was issued by the previous host; never send it to a new one.
+    if settings.github_host != old_settings.github_host && settings.github_oauth_token.is_some() {
+        log::info!("[{}] GitHub host changed, signing out", log_scope);
+        settings.github_oauth_token = None;
+        settings.github_user_login = None;
+        settings.github_user_avatar = None;
+        settings.github_user_email = None;
+    }
+
     // Log what changed
     let changes = diff_settings(&old_settings, &settings);
     if changes.is_empty() {
@@ -295,11 +314,22 @@ fn diff_settings(old: &AppSettings, new: &AppSettings) -> Vec<String> {
             new.marketplace_registries.len()
         ));
     }
+    if old.source_host_tokens != new.source_host_tokens {
+        changes.push(format!(
+            "source_host_tokens={} hosts",
+            new.source_host_tokens.len()
+        ));
+    }
     cmp_val!(max_dimensions, "max_dimensions");
     cmp_opt!(industry, "industry");
+    cmp_opt!(github_host, "github_host");
+    cmp_opt!(github_oauth_client_id, "github_oauth_client_id");
+    cmp_opt!(feedback_repo, "feedback_repo");
     cmp_opt!(function_role, "function_role");
     cmp_opt!(dashboard_view_mode, "dashboard_view_mode");
     cmp_bool!(auto_update, "auto_update");
+    cmp_bool!(marketplace_offline, "marketplace_offline");
+    cmp_val!(document_token_budget, "document_token_budget");
     changes
 }
 
@@ -1042,6 +1072,32 @@ mod tests {
         assert_eq!(result.industry.as_deref(), Some("healthcare"));
     }
 
+    #[test]
+    fn test_persist_settings_github_host_change_signs_out() {
+        let conn = crate::db::create_test_db_for_tests();
+        let initial = crate::types::AppSettings {
+            github_oauth_token: Some("test-oauth-token".to_string()),
+            github_user_login: Some("dev".to_string()),
+            ..Default::default()
+        };
+        crate::db::write_settings(&conn, &initial).unwrap();
+
+        let new_settings = crate::types::AppSettings {
+            github_host: Some("https://GHE.example.com/api/v3/".to_string()),
+            ..Default::default()
+        };
+        persist_settings(&conn, new_settings, "settings-test").unwrap();
+
+        let result = crate::db::read_settings(&conn).unwrap();
+        assert_eq!(result.github_host.as_deref(), Some("ghe.example.com"));
+        assert!(result.github_oauth_token.is_none());
+        assert!(result.github_user_login.is_none());
+
+        let mut invalid = result.clone();
+        invalid.github_host = Some("ghe.example.com/org".to_string());
+        assert!(persist_settings(&conn, invalid, "settings-test").is_err());
+    }
+
     // ===== update_github_identity token-preservation tests =====
 
     #[test]
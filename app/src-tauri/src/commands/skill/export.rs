@@ -1,8 +1,17 @@
+use crate::commands::imported_skills::frontmatter::{parse_frontmatter_full, yaml_quote_scalar};
 use crate::db::Db;
+use crate::types::SkillExportTarget;
 use std::io::Write;
 use std::path::Path;
 
-pub(crate) fn export_skill_as_file_inner(skill_dir: &Path, dest_path: &str) -> Result<(), String> {
+/// Zip a skill directory. The per-skill `.git` repo is never copied as-is;
+/// with `include_history` its branches and tags go in as a git bundle
+/// (`HISTORY_BUNDLE_FILE`) that the upload import restores.
+pub(crate) fn export_skill_as_file_inner(
+    skill_dir: &Path,
+    dest_path: &str,
+    include_history: bool,
+) -> Result<(), String> {
     if !skill_dir.exists() {
         return Err(format!(
             "Skill directory not found: {}",
@@ -32,7 +41,11 @@ pub(crate) fn export_skill_as_file_inner(skill_dir: &Path, dest_path: &str) -> R
             .map_err(|e| e.to_string())?
             .to_string_lossy()
             .replace('\\', "/");
-        if relative.is_empty() {
+        if relative.is_empty()
+            || relative == ".git"
+            || relative.starts_with(".git/")
+            || relative == crate::git::HISTORY_BUNDLE_FILE
+        {
             continue;
         }
         zip.start_file(&relative, options)
@@ -43,6 +56,22 @@ pub(crate) fn export_skill_as_file_inner(skill_dir: &Path, dest_path: &str) -> R
             .map_err(|e| format!("Failed to write '{}' to archive: {}", relative, e))?;
     }
 
+    if include_history {
+        if !skill_dir.join(".git").exists() {
+            return Err("Skill has no git history to export".to_string());
+        }
+        let staging = tempfile::tempdir()
+            .map_err(|e| format!("Failed to create staging directory: {}", e))?;
+        let bundle_path = staging.path().join(crate::git::HISTORY_BUNDLE_FILE);
+        crate::git::write_history_bundle(skill_dir, &bundle_path)?;
+        let bundle = std::fs::read(&bundle_path)
+            .map_err(|e| format!("Failed to read history bundle: {}", e))?;
+        zip.start_file(crate::git::HISTORY_BUNDLE_FILE, options)
+            .map_err(|e| format!("Failed to add history bundle to archive: {}", e))?;
+        zip.write_all(&bundle)
+            .map_err(|e| format!("Failed to write history bundle to archive: {}", e))?;
+    }
+
     zip.finish()
         .map_err(|e| format!("Failed to finalize archive: {}", e))?;
     Ok(())
@@ -53,13 +82,15 @@ pub fn export_skill_as_file(
     skill_name: String,
     plugin_slug: String,
     dest_path: String,
+    include_history: bool,
     db: tauri::State<'_, Db>,
 ) -> Result<(), String> {
     log::info!(
-        "[export_skill_as_file] skill_name={} plugin_slug={} dest={}",
+        "[export_skill_as_file] skill_name={} plugin_slug={} dest={} include_history={}",
         skill_name,
         plugin_slug,
-        dest_path
+        dest_path,
+        include_history
     );
     let conn = db.0.lock().map_err(|e| {
         log::error!("[export_skill_as_file] failed to acquire DB lock: {}", e);
@@ -74,7 +105,7 @@ pub fn export_skill_as_file(
         .ok_or_else(|| "Skills path not configured. Set it in Settings.".to_string())?;
     let skill_dir =
         crate::skill_paths::resolve_skill_dir(Path::new(&skills_path), &plugin_slug, &skill_name);
-    let result = export_skill_as_file_inner(&skill_dir, &dest_path);
+    let result = export_skill_as_file_inner(&skill_dir, &dest_path, include_history);
     if result.is_err() {
         let _ = std::fs::remove_file(&dest_path);
     }
@@ -90,9 +121,291 @@ pub fn export_skill_as_file(
     Ok(())
 }
 
+/// A skill package read from disk, ready to be rendered for another tool.
+struct SkillSource {
+    name: String,
+    description: Option<String>,
+    body: String,
+    /// Every other text file in the package, as (relative path, content),
+    /// sorted by path.
+    references: Vec<(String, String)>,
+}
+
+fn read_skill_source(skill_dir: &Path, fallback_name: &str) -> Result<SkillSource, String> {
+    let skill_md_path = skill_dir.join("SKILL.md");
+    if !skill_md_path.is_file() {
+        return Err("Not a valid skill: SKILL.md missing".to_string());
+    }
+    let content = std::fs::read_to_string(&skill_md_path)
+        .map_err(|e| format!("Failed to read '{}': {}", skill_md_path.display(), e))?
+        .replace("\r\n", "\n");
+    let fm = parse_frontmatter_full(&content);
+
+    // Eval scenarios and the version changelog are authoring metadata, not
+    // skill content.
+    let skip = |e: &walkdir::DirEntry| {
+        let name = e.file_name().to_string_lossy();
+        name.starts_with('.')
+            || (e.depth() == 1 && (name == "evals" || name == crate::git::CHANGELOG_FILE))
+    };
+    let mut references = Vec::new();
+    for entry in walkdir::WalkDir::new(skill_dir)
+        .follow_links(false)
+        .sort_by_file_name()
+        .into_iter()
+        .filter_entry(|e| e.depth() == 0 || !skip(e))
+        .filter_map(|e| e.ok())
+    {
+        if !entry.file_type().is_file() {
+            continue;
+        }
+        let relative = entry
+            .path()
+            .strip_prefix(skill_dir)
+            .map_err(|e| e.to_string())?
+            .to_string_lossy()
+            .replace('\\', "/");
+        if relative == "SKILL.md" {
+            continue;
+        }
+        // Binary assets have no text form to inline.
+        match std::fs::read_to_string(entry.path()) {
+            Ok(text) => references.push((relative, text.replace("\r\n", "\n"))),
+            Err(_) => log::debug!("[export_skill_to_target] skipping non-text '{}'", relative),
+        }
+    }
+
+    Ok(SkillSource {
+        name: fm.name.unwrap_or_else(|| fallback_name.to_string()),
+        description: fm.description,
+        body: strip_frontmatter(&content).trim().to_string(),
+        references,
+    })
+}
+
+fn strip_frontmatter(content: &str) -> &str {
+    let trimmed = content.trim_start();
+    let Some(after_open) = trimmed.strip_prefix("---") else {
+        return content;
+    };
+    match after_open.find("\n---") {
+        Some(end) => {
+            let rest = &after_open[end + 4..];
+            rest.split_once('\n').map_or("", |(_, body)| body)
+        }
+        None => content,
+    }
+}
+
+/// Push every ATX heading outside fenced code down by `levels`, capped at `######`.
+fn demote_headings(markdown: &str, levels: usize) -> String {
+    let mut fence: Option<&str> = None;
+    let mut out: Vec<String> = Vec::new();
+    for line in markdown.lines() {
+        let trimmed = line.trim_start();
+        if let Some(open) = fence {
+            if trimmed.starts_with(open) {
+                fence = None;
+            }
+            out.push(line.to_string());
+            continue;
+        }
+        if trimmed.starts_with("```") || trimmed.starts_with("~~~") {
+            fence = Some(&trimmed[..3]);
+            out.push(line.to_string());
+            continue;
+        }
+        let hashes = line.chars().take_while(|c| *c == '#').count();
+        if levels > 0 && (1..=6).contains(&hashes) && line[hashes..].starts_with(' ') {
+            let level = (hashes + levels).min(6);
+            out.push(format!("{}{}", "#".repeat(level), &line[hashes..]));
+        } else {
+            out.push(line.to_string());
+        }
+    }
+    out.join("\n")
+}
+
+/// The skill body followed by its references, with the body's headings
+/// pushed down by `levels` and each reference under its own heading one
+/// level below that.
+fn flatten_skill(skill: &SkillSource, levels: usize) -> String {
+    let mut out = demote_headings(&skill.body, levels);
+    append_references(&mut out, &skill.references, levels);
+    out
+}
+
+/// Append each reference under a heading at `levels + 1`, Markdown demoted
+/// to sit below it and anything else fenced as code.
+fn append_references(out: &mut String, references: &[(String, String)], levels: usize) {
+    let reference_heading = "#".repeat((levels + 1).min(6));
+    for (path, content) in references {
+        out.push_str(&format!(
+            "\n\n{} Reference: {}\n\n",
+            reference_heading, path
+        ));
+        if path.ends_with(".md") {
+            out.push_str(demote_headings(content.trim(), levels + 1).as_str());
+        } else {
+            let language = Path::new(path)
+                .extension()
+                .map(|e| e.to_string_lossy().to_string())
+                .unwrap_or_default();
+            let fence = if content.contains("```") {
+                "````"
+            } else {
+                "```"
+            };
+            out.push_str(&format!(
+                "{}{}\n{}\n{}",
+                fence,
+                language,
+                content.trim_end(),
+                fence
+            ));
+        }
+    }
+}
+
+/// SKILL.md as written, followed by its references inlined, for prompts
+/// that cannot load the skill's other files.
+pub(crate) fn skill_with_inlined_references(skill_dir: &Path) -> Result<String, String> {
+    let skill = read_skill_source(skill_dir, "")?;
+    let skill_md_path = skill_dir.join("SKILL.md");
+    let mut out = std::fs::read_to_string(&skill_md_path)
+        .map_err(|e| format!("Failed to read '{}': {}", skill_md_path.display(), e))?
+        .replace("\r\n", "\n")
+        .trim_end()
+        .to_string();
+    append_references(&mut out, &skill.references, 1);
+    Ok(out)
+}
+
+fn agents_md_markers(name: &str) -> (String, String) {
+    (
+        format!("<!-- skill-builder:skill {} -->", name),
+        format!("<!-- /skill-builder:skill {} -->", name),
+    )
+}
+
+fn render_agents_md_section(skill: &SkillSource) -> String {
+    let (start, end) = agents_md_markers(&skill.name);
+    let mut section = format!("{}\n## {}\n\n", start, skill.name);
+    if let Some(description) = &skill.description {
+        section.push_str(&format!("**When to use:** {}\n\n", description));
+    }
+    section.push_str(&flatten_skill(skill, 2));
+    section.push_str(&format!("\n{}\n", end));
+    section
+}
+
+fn render_cursor_rule(skill: &SkillSource) -> String {
+    // "Agent Requested" rule: no globs, applied when the description matches.
+    format!(
+        "---\ndescription: {}\nglobs:\nalwaysApply: false\n---\n\n{}\n",
+        yaml_quote_scalar(skill.description.as_deref().unwrap_or(&skill.name)),
+        flatten_skill(skill, 0)
+    )
+}
+
+fn render_flattened_markdown(skill: &SkillSource) -> String {
+    let mut out = format!("# {}\n\n", skill.name);
+    if let Some(description) = &skill.description {
+        out.push_str(&format!("> {}\n\n", description));
+    }
+    out.push_str(&flatten_skill(skill, 1));
+    out.push('\n');
+    out
+}
+
+/// Replace this skill's marked section in an existing `AGENTS.md`, or append
+/// it when the file has none.
+fn merge_agents_md(existing: &str, name: &str, section: &str) -> String {
+    let (start, end) = agents_md_markers(name);
+    if let (Some(from), Some(to)) = (existing.find(&start), existing.find(&end)) {
+        if from < to {
+            let after = &existing[to + end.len()..];
+            let after = after.strip_prefix('\n').unwrap_or(after);
+            return format!("{}{}{}", &existing[..from], section, after);
+        }
+    }
+    if existing.trim().is_empty() {
+        return section.to_string();
+    }
+    format!("{}\n\n{}", existing.trim_end(), section)
+}
+
+pub(crate) fn export_skill_to_target_inner(
+    skill_dir: &Path,
+    skill_name: &str,
+    target: SkillExportTarget,
+    dest_path: &str,
+) -> Result<(), String> {
+    if !skill_dir.exists() {
+        return Err(format!(
+            "Skill directory not found: {}",
+            skill_dir.display()
+        ));
+    }
+    let skill = read_skill_source(skill_dir, skill_name)?;
+    let output = match target {
+        SkillExportTarget::AgentsMd => {
+            let existing = std::fs::read_to_string(dest_path).unwrap_or_default();
+            merge_agents_md(&existing, &skill.name, &render_agents_md_section(&skill))
+        }
+        SkillExportTarget::CursorRule => render_cursor_rule(&skill),
+        SkillExportTarget::FlattenedMarkdown => render_flattened_markdown(&skill),
+    };
+    std::fs::write(dest_path, output)
+        .map_err(|e| format!("Failed to write export file '{}': {}", dest_path, e))
+}
+
+/// Convert a skill package for another coding agent: an `AGENTS.md` section,
+/// a Cursor rule, or a single flattened Markdown prompt.
+#[tauri::command]
+pub fn export_skill_to_target(
+    skill_name: String,
+    plugin_slug: String,
+    target: SkillExportTarget,
+    dest_path: String,
+    db: tauri::State<'_, Db>,
+) -> Result<(), String> {
+    log::info!(
+        "[export_skill_to_target] skill_name={} plugin_slug={} target={:?} dest={}",
+        skill_name,
+        plugin_slug,
+        target,
+        dest_path
+    );
+    let conn = db.0.lock().map_err(|e| {
+        log::error!("[export_skill_to_target] failed to acquire DB lock: {}", e);
+        e.to_string()
+    })?;
+    let settings = crate::db::read_settings(&conn).map_err(|e| {
+        log::error!("[export_skill_to_target] failed to read settings: {}", e);
+        e
+    })?;
+    let skills_path = settings
+        .skills_path
+        .ok_or_else(|| "Skills path not configured. Set it in Settings.".to_string())?;
+    let skill_dir =
+        crate::skill_paths::resolve_skill_dir(Path::new(&skills_path), &plugin_slug, &skill_name);
+    export_skill_to_target_inner(&skill_dir, &skill_name, target, &dest_path).map_err(|e| {
+        log::error!("[export_skill_to_target] export failed: {}", e);
+        e
+    })?;
+    log::info!(
+        "[export_skill_to_target] exported '{}' as {:?} to '{}'",
+        skill_name,
+        target,
+        dest_path
+    );
+    Ok(())
+}
+
 #[cfg(test)]
 mod tests {
-    use super::export_skill_as_file_inner;
+    use super::*;
     use std::fs;
     use tempfile::tempdir;
 
@@ -112,7 +425,7 @@ mod tests {
         make_skill_dir(dir.path());
         let dest = dir.path().join("out.skill");
 
-        export_skill_as_file_inner(dir.path(), dest.to_str().unwrap()).unwrap();
+        export_skill_as_file_inner(dir.path(), dest.to_str().unwrap(), false).unwrap();
 
         let file = fs::File::open(&dest).unwrap();
         let mut archive = zip::ZipArchive::new(file).unwrap();
@@ -137,7 +450,7 @@ mod tests {
         make_skill_dir(dir.path());
         let dest = dir.path().join("out.skill");
 
-        export_skill_as_file_inner(dir.path(), dest.to_str().unwrap()).unwrap();
+        export_skill_as_file_inner(dir.path(), dest.to_str().unwrap(), false).unwrap();
 
         let file = fs::File::open(&dest).unwrap();
         let mut archive = zip::ZipArchive::new(file).unwrap();
@@ -157,7 +470,7 @@ mod tests {
         make_skill_dir(dir.path());
         let dest = dir.path().join("out.skill");
 
-        export_skill_as_file_inner(dir.path(), dest.to_str().unwrap()).unwrap();
+        export_skill_as_file_inner(dir.path(), dest.to_str().unwrap(), false).unwrap();
 
         let file = fs::File::open(&dest).unwrap();
         let mut archive = zip::ZipArchive::new(file).unwrap();
@@ -172,12 +485,40 @@ mod tests {
         assert!(content.contains("test-skill"));
     }
 
+    #[test]
+    fn export_leaves_out_git_repo_unless_history_is_requested() {
+        let dir = tempdir().unwrap();
+        make_skill_dir(dir.path());
+        crate::git::ensure_repo(dir.path()).unwrap();
+        crate::git::commit_all(dir.path(), "test-skill: created").unwrap();
+        let out = tempdir().unwrap();
+        let entry_names = |include_history: bool| {
+            let dest = out
+                .path()
+                .join(format!("history-{}.skill", include_history));
+            export_skill_as_file_inner(dir.path(), dest.to_str().unwrap(), include_history)
+                .unwrap();
+            let mut archive = zip::ZipArchive::new(fs::File::open(&dest).unwrap()).unwrap();
+            (0..archive.len())
+                .map(|i| archive.by_index(i).unwrap().name().to_string())
+                .collect::<Vec<_>>()
+        };
+
+        let plain = entry_names(false);
+        assert!(plain.iter().all(|n| !n.starts_with(".git/")), "{:?}", plain);
+        assert!(!plain.contains(&crate::git::HISTORY_BUNDLE_FILE.to_string()));
+
+        let with_history = entry_names(true);
+        assert!(with_history.iter().all(|n| !n.starts_with(".git/")));
+        assert!(with_history.contains(&crate::git::HISTORY_BUNDLE_FILE.to_string()));
+    }
+
     #[test]
     fn export_fails_when_skill_dir_missing() {
         let dir = tempdir().unwrap();
         let missing = dir.path().join("does-not-exist");
         let dest = dir.path().join("out.skill");
-        let err = export_skill_as_file_inner(&missing, dest.to_str().unwrap()).unwrap_err();
+        let err = export_skill_as_file_inner(&missing, dest.to_str().unwrap(), false).unwrap_err();
         assert!(
             err.contains("not found"),
             "expected 'not found' in: {}",
@@ -190,11 +531,148 @@ mod tests {
         let dir = tempdir().unwrap();
         // dir exists but has no SKILL.md
         let dest = dir.path().join("out.skill");
-        let err = export_skill_as_file_inner(dir.path(), dest.to_str().unwrap()).unwrap_err();
+        let err =
+            export_skill_as_file_inner(dir.path(), dest.to_str().unwrap(), false).unwrap_err();
         assert!(
             err.contains("SKILL.md missing"),
             "expected 'SKILL.md missing' in: {}",
             err
         );
     }
+
+    fn make_target_skill(dir: &std::path::Path) {
+        fs::write(
+            dir.join("SKILL.md"),
+            "---\nname: revenue\ndescription: Use when asked about revenue metrics\n---\n# Revenue\n\n## Steps\n\n```sql\n# not a heading\n```\n",
+        )
+        .unwrap();
+        fs::create_dir_all(dir.join("references")).unwrap();
+        fs::write(
+            dir.join("references/glossary.md"),
+            "# Glossary\n\nARR: annual",
+        )
+        .unwrap();
+        fs::write(dir.join("references/query.sql"), "select 1;\n").unwrap();
+        fs::write(dir.join(".gitignore"), "*.tmp\n").unwrap();
+        fs::create_dir_all(dir.join("evals")).unwrap();
+        fs::write(dir.join("evals/smoke.yaml"), "name: Smoke scenario\n").unwrap();
+        fs::write(
+            dir.join(crate::git::CHANGELOG_FILE),
+            "# Changelog\n\n## 1.0.0\n",
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn flattened_markdown_inlines_references_under_description() {
+        let dir = tempdir().unwrap();
+        make_target_skill(dir.path());
+        let dest = dir.path().join("revenue.md");
+
+        export_skill_to_target_inner(
+            dir.path(),
+            "revenue",
+            SkillExportTarget::FlattenedMarkdown,
+            dest.to_str().unwrap(),
+        )
+        .unwrap();
+
+        let out = fs::read_to_string(&dest).unwrap();
+        assert!(out.starts_with(
+            "# revenue\n\n> Use when asked about revenue metrics\n\n## Revenue\n\n### Steps"
+        ));
+        assert!(
+            out.contains("```sql\n# not a heading\n```"),
+            "got:\n{}",
+            out
+        );
+        assert!(out.contains("## Reference: references/glossary.md\n\n### Glossary"));
+        assert!(out.contains("## Reference: references/query.sql\n\n```sql\nselect 1;\n```"));
+        assert!(!out.contains("*.tmp"), "hidden files must be skipped");
+        assert!(!out.contains("Smoke scenario"), "evals must be skipped");
+        assert!(!out.contains("Changelog"), "the changelog must be skipped");
+        assert!(
+            !out.contains("name: revenue"),
+            "frontmatter must be dropped"
+        );
+    }
+
+    #[test]
+    fn cursor_rule_maps_description_to_rule_frontmatter() {
+        let dir = tempdir().unwrap();
+        make_target_skill(dir.path());
+        let dest = dir.path().join("revenue.mdc");
+
+        export_skill_to_target_inner(
+            dir.path(),
+            "revenue",
+            SkillExportTarget::CursorRule,
+            dest.to_str().unwrap(),
+        )
+        .unwrap();
+
+        let out = fs::read_to_string(&dest).unwrap();
+        assert!(out.starts_with(
+            "---\ndescription: \"Use when asked about revenue metrics\"\nglobs:\nalwaysApply: false\n---\n\n# Revenue"
+        ));
+        assert!(out.contains("# Reference: references/glossary.md\n\n## Glossary"));
+    }
+
+    #[test]
+    fn agents_md_section_is_replaced_in_place_on_re_export() {
+        let dir = tempdir().unwrap();
+        make_target_skill(dir.path());
+        let project = tempdir().unwrap();
+        let dest = project.path().join("AGENTS.md");
+        fs::write(&dest, "# Project\n\nBuild with make.\n").unwrap();
+
+        for _ in 0..2 {
+            export_skill_to_target_inner(
+                dir.path(),
+                "revenue",
+                SkillExportTarget::AgentsMd,
+                dest.to_str().unwrap(),
+            )
+            .unwrap();
+        }
+        fs::write(&dest, fs::read_to_string(&dest).unwrap() + "\n## Footer\n").unwrap();
+        export_skill_to_target_inner(
+            dir.path(),
+            "revenue",
+            SkillExportTarget::AgentsMd,
+            dest.to_str().unwrap(),
+        )
+        .unwrap();
+
+        let out = fs::read_to_string(&dest).unwrap();
+        assert!(out.starts_with("# Project\n\nBuild with make.\n\n<!-- skill-builder:skill revenue -->\n## revenue\n\n**When to use:** Use when asked about revenue metrics\n\n### Revenue"));
+        assert_eq!(
+            out.matches("<!-- skill-builder:skill revenue -->").count(),
+            1
+        );
+        assert!(
+            out.ends_with("<!-- /skill-builder:skill revenue -->\n\n## Footer\n"),
+            "got:\n{}",
+            out
+        );
+    }
+
+    #[test]
+    fn inlined_skill_keeps_skill_md_and_appends_references() {
+        let dir = tempdir().unwrap();
+        make_target_skill(dir.path());
+
+        let out = skill_with_inlined_references(dir.path()).unwrap();
+
+        assert!(out.starts_with("---\nname: revenue\n"), "got:\n{}", out);
+        assert!(out.contains("# Revenue\n\n## Steps"));
+        assert!(out.contains("## Reference: references/glossary.md\n\n### Glossary"));
+        assert!(out.contains("## Reference: references/query.sql"));
+        assert!(!out.contains("Smoke scenario"));
+    }
+
+    #[test]
+    fn demote_headings_caps_at_six_levels() {
+        assert_eq!(demote_headings("##### Deep\n#tag", 3), "###### Deep\n#tag");
+    }
 }
@@ -63,11 +63,7 @@ fn render_reference_documents(documents: &[(String, String)]) -> String {
 
     let parts = documents
         .iter()
-        .map(|(name, content)| {
-            let end = content.floor_char_boundary(2000);
-            let snippet = &content[..end];
-            format!("### {}\n{}", name, snippet)
-        })
+        .map(|(name, content)| format!("### {}\n{}", name, content))
         .collect::<Vec<_>>();
 
     format!(
@@ -95,28 +91,32 @@ pub async fn review_skill_scope(
 
     let runtime_context = crate::commands::workflow::read_initialized_runtime_context(&db)
         .inspect_err(|e| log::error!("[review_skill_scope] Runtime context unavailable: {}", e))?;
-    let document_paths = {
+    let documents = {
         let conn = db.0.lock().map_err(|e| {
             log::error!("[review_skill_scope] Failed to acquire DB lock: {}", e);
             e.to_string()
         })?;
-        crate::db::db_list_documents(&conn)
-            .unwrap_or_default()
+        let token_budget = crate::db::read_settings(&conn)
+            .map(|s| s.document_token_budget)
+            .unwrap_or(crate::db::DEFAULT_DOCUMENT_TOKEN_BUDGET);
+        let query = [
+            skill_name.replace('-', " "),
+            description.clone(),
+            purpose.clone(),
+            context_questions.clone().unwrap_or_default(),
+            industry.clone().unwrap_or_default(),
+        ]
+        .join("\n");
+        crate::db::db_retrieve_document_chunks(&conn, None, &query, token_budget as usize)
+            .unwrap_or_else(|e| {
+                log::warn!("[review_skill_scope] failed to load documents: {}", e);
+                vec![]
+            })
             .into_iter()
-            .filter(|d| d.scope == "all")
-            .map(|d| (d.name, d.file_path))
+            .map(|d| (d.name, d.content))
             .collect::<Vec<_>>()
     };
 
-    let documents = document_paths
-        .into_iter()
-        .filter_map(|(name, file_path)| {
-            std::fs::read_to_string(&file_path)
-                .ok()
-                .map(|content| (name, content))
-        })
-        .collect::<Vec<_>>();
-
     let prompt = render_scope_review_prompt(ScopeReviewPromptParams {
         skill_name: &skill_name,
         description: &description,
@@ -223,6 +223,19 @@ pub async fn ensure_openhands_runtime_dir(
     .map_err(|e| format!("Throwaway prompt copy task failed: {}", e))?
 }
 
+/// Like `ensure_openhands_runtime_dir`, but leaves `.agents/skills` empty so a
+/// scenario run sees only the skill under test, or no skill at all.
+pub async fn ensure_openhands_scenario_runtime_dir(
+    app_handle: &tauri::AppHandle,
+    runtime_dir: &Path,
+) -> Result<(), String> {
+    let agents_dir = resolve_prompt_source_dirs(app_handle);
+    let target_dir = runtime_dir.to_path_buf();
+    tokio::task::spawn_blocking(move || copy_agent_sources_without_skills(&agents_dir, &target_dir))
+        .await
+        .map_err(|e| format!("Throwaway prompt copy task failed: {}", e))?
+}
+
 /// SHA-gated deploy. Used by both async and sync entry points and is
 /// exposed to tests so callers can pass explicit source paths instead of
 /// resolving them through `tauri::AppHandle`.
@@ -306,6 +319,12 @@ fn copy_agent_sources_to_openhands_cwd(
     Ok(())
 }
 
+fn copy_agent_sources_without_skills(agents_src: &Path, target_dir: &Path) -> Result<(), String> {
+    copy_agent_sources_to_agents_dir(agents_src, target_dir)?;
+    reset_skills_dir(target_dir)?;
+    Ok(())
+}
+
 fn copy_agent_sources_to_agents_dir(agents_src: &Path, target_dir: &Path) -> Result<(), String> {
     let agents_dir = target_dir.join(".agents").join("agents");
     if agents_dir.is_dir() {
@@ -339,7 +358,7 @@ fn copy_agent_sources_to_agents_dir(agents_src: &Path, target_dir: &Path) -> Res
     Ok(())
 }
 
-fn copy_agent_sources_to_skills_dir(skills_src: &Path, target_dir: &Path) -> Result<(), String> {
+fn reset_skills_dir(target_dir: &Path) -> Result<PathBuf, String> {
     let skills_dir = target_dir.join(".agents").join("skills");
     if skills_dir.is_dir() {
         std::fs::remove_dir_all(&skills_dir)
@@ -347,6 +366,11 @@ fn copy_agent_sources_to_skills_dir(skills_src: &Path, target_dir: &Path) -> Res
     }
     std::fs::create_dir_all(&skills_dir)
         .map_err(|e| format!("Failed to create .agents/skills dir: {}", e))?;
+    Ok(skills_dir)
+}
+
+fn copy_agent_sources_to_skills_dir(skills_src: &Path, target_dir: &Path) -> Result<(), String> {
+    let skills_dir = reset_skills_dir(target_dir)?;
 
     if skills_src.is_dir() {
         for skill_entry in std::fs::read_dir(skills_src)
@@ -472,6 +496,23 @@ mod tests {
             .is_file());
     }
 
+    #[test]
+    fn copy_agent_sources_without_skills_leaves_skills_dir_empty() {
+        let tmp = tempfile::tempdir().unwrap();
+        let agents = bundled_workspace_agents_fixture(tmp.path());
+        let skills = bundled_workspace_skills_fixture(tmp.path());
+        let workspace = tmp.path().join("workspace");
+        std::fs::create_dir_all(&workspace).unwrap();
+        copy_agent_sources_to_full_layout(&agents, &skills, &workspace).unwrap();
+
+        copy_agent_sources_without_skills(&agents, &workspace).unwrap();
+
+        assert!(workspace.join(".agents/agents/skill-creator.md").is_file());
+        let skills_dir = workspace.join(".agents/skills");
+        assert!(skills_dir.is_dir());
+        assert_eq!(std::fs::read_dir(&skills_dir).unwrap().count(), 0);
+    }
+
     // ---- compute_dir_sha tests --------------------------------------------
 
     #[test]
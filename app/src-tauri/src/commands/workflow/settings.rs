@@ -129,7 +129,24 @@ pub(crate) fn read_workflow_settings_by_skill_id(
         .as_ref()
         .map(|m| m.id)
         .map(|sid| {
-            crate::db::db_documents_for_skill(&conn, sid).unwrap_or_else(|e| {
+            let open_questions =
+                crate::db::workflow_artifacts::read_open_question_texts(&conn, sid)
+                    .unwrap_or_default();
+            let query = document_retrieval_query(
+                skill_name,
+                &purpose,
+                description.as_deref(),
+                &tags,
+                intake_json.as_deref(),
+                &open_questions,
+            );
+            crate::db::db_retrieve_document_chunks(
+                &conn,
+                Some(sid),
+                &query,
+                settings.document_token_budget as usize,
+            )
+            .unwrap_or_else(|e| {
                 log::warn!(
                     "read_workflow_settings: failed to load documents for skill {}: {}",
                     skill_name,
@@ -160,6 +177,30 @@ pub(crate) fn read_workflow_settings_by_skill_id(
     })
 }
 
+/// Free-text retrieval query describing what a skill needs from its reference
+/// documents: identity, purpose, intake context and still-open questions.
+pub(crate) fn document_retrieval_query(
+    skill_name: &str,
+    purpose: &str,
+    description: Option<&str>,
+    tags: &[String],
+    intake_json: Option<&str>,
+    open_questions: &[String],
+) -> String {
+    let mut parts: Vec<String> = vec![skill_name.replace('-', " "), purpose.to_string()];
+    parts.extend(description.map(str::to_string));
+    parts.extend(tags.iter().cloned());
+    if let Some(intake) =
+        intake_json.and_then(|ij| serde_json::from_str::<serde_json::Value>(ij).ok())
+    {
+        if let Some(obj) = intake.as_object() {
+            parts.extend(obj.values().filter_map(|v| v.as_str()).map(str::to_string));
+        }
+    }
+    parts.extend(open_questions.iter().cloned());
+    parts.join("\n")
+}
+
 #[cfg_attr(not(test), allow(dead_code))]
 pub(crate) fn read_workflow_settings(
     db: &Db,
@@ -382,4 +423,33 @@ mod tests {
             "user override should win over catalog default"
         );
     }
+
+    #[test]
+    fn test_document_retrieval_query_collects_skill_context() {
+        let intake =
+            r#"{"audience":"finance analysts","challenges":"revenue recognition","count":3}"#;
+        let query = document_retrieval_query(
+            "deferred-revenue",
+            "domain",
+            Some("Model deferred revenue schedules"),
+            &["saas".to_string()],
+            Some(intake),
+            &["Cutoff rules Which cutoff applies?".to_string()],
+        );
+
+        assert!(query.contains("deferred revenue"));
+        assert!(query.contains("Model deferred revenue schedules"));
+        assert!(query.contains("saas"));
+        assert!(query.contains("finance analysts"));
+        assert!(query.contains("revenue recognition"));
+        assert!(query.contains("Which cutoff applies?"));
+        assert!(!query.contains('3'), "non-string intake values are skipped");
+    }
+
+    #[test]
+    fn test_document_retrieval_query_ignores_malformed_intake() {
+        let query =
+            document_retrieval_query("my-skill", "platform", None, &[], Some("{not json"), &[]);
+        assert_eq!(query, "my skill\nplatform");
+    }
 }
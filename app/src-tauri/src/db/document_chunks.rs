@@ -0,0 +1,650 @@
+use std::collections::{BTreeMap, HashSet};
+
+use rusqlite::{params, Connection};
+
+use super::DocumentContent;
+
+/// Target size of a single chunk, in estimated tokens.
+const CHUNK_TARGET_TOKENS: usize = 400;
+
+/// Default reference-document budget injected into a single prompt.
+pub const DEFAULT_DOCUMENT_TOKEN_BUDGET: u32 = 8_000;
+
+/// Maximum number of ranked chunks considered per retrieval query.
+const MAX_RANKED_CANDIDATES: i64 = 200;
+
+/// Separator inserted between non-contiguous chunks of the same document.
+const CHUNK_GAP_MARKER: &str = "\n\n[…]\n\n";
+
+/// A contiguous slice of a document, as produced by [`chunk_markdown`].
+#[derive(Debug, Clone, PartialEq)]
+pub struct DocumentChunk {
+    /// Heading trail (e.g. `"Orders > Refunds"`) the chunk sits under, if any.
+    pub heading: Option<String>,
+    pub content: String,
+    pub token_estimate: usize,
+}
+
+/// Rough token estimate (~4 characters per token) used for budgeting.
+pub fn estimate_tokens(text: &str) -> usize {
+    text.chars().count().div_ceil(4)
+}
+
+/// Parse an ATX heading line (`## Title`) into `(level, title)`.
+fn parse_heading(line: &str) -> Option<(usize, String)> {
+    let trimmed = line.trim_start();
+    let level = trimmed.chars().take_while(|c| *c == '#').count();
+    if level == 0 || level > 6 {
+        return None;
+    }
+    let rest = &trimmed[level..];
+    if !rest.is_empty() && !rest.starts_with(' ') {
+        return None;
+    }
+    let title = rest.trim().trim_end_matches('#').trim().to_string();
+    (!title.is_empty()).then_some((level, title))
+}
+
+/// Split a paragraph that is larger than one chunk on line/word boundaries.
+fn split_oversized(paragraph: &str) -> Vec<String> {
+    let max_chars = CHUNK_TARGET_TOKENS * 4;
+    let mut pieces = Vec::new();
+    let mut current = String::new();
+    for word in paragraph.split_inclusive(char::is_whitespace) {
+        if !current.is_empty() && current.chars().count() + word.chars().count() > max_chars {
+            pieces.push(std::mem::take(&mut current).trim().to_string());
+        }
+        current.push_str(word);
+    }
+    if !current.trim().is_empty() {
+        pieces.push(current.trim().to_string());
+    }
+    pieces
+}
+
+/// Split Markdown into retrieval chunks.
+///
+/// Chunks never straddle a heading: each section is packed paragraph by
+/// paragraph up to [`CHUNK_TARGET_TOKENS`] and carries its heading trail so
+/// a chunk read in isolation still says where it came from.
+pub fn chunk_markdown(markdown: &str) -> Vec<DocumentChunk> {
+    let mut chunks = Vec::new();
+    let mut trail: Vec<(usize, String)> = Vec::new();
+    let mut paragraphs: Vec<String> = Vec::new();
+    let mut paragraph = String::new();
+
+    fn flush_section(
+        chunks: &mut Vec<DocumentChunk>,
+        trail: &[(usize, String)],
+        paragraphs: &mut Vec<String>,
+    ) {
+        let heading = (!trail.is_empty()).then(|| {
+            trail
+                .iter()
+                .map(|(_, t)| t.as_str())
+                .collect::<Vec<_>>()
+                .join(" > ")
+        });
+        let mut current = String::new();
+        let push = |chunks: &mut Vec<DocumentChunk>, content: &mut String| {
+            if !content.trim().is_empty() {
+                let content = std::mem::take(content).trim().to_string();
+                chunks.push(DocumentChunk {
+                    heading: heading.clone(),
+                    token_estimate: estimate_tokens(&content),
+                    content,
+                });
+            }
+        };
+        for para in paragraphs.drain(..) {
+            let pieces = if estimate_tokens(&para) > CHUNK_TARGET_TOKENS {
+                split_oversized(&para)
+            } else {
+                vec![para]
+            };
+            for piece in pieces {
+                if !current.is_empty()
+                    && estimate_tokens(&current) + estimate_tokens(&piece) > CHUNK_TARGET_TOKENS
+                {
+                    push(chunks, &mut current);
+                }
+                if !current.is_empty() {
+                    current.push_str("\n\n");
+                }
+                current.push_str(&piece);
+            }
+        }
+        push(chunks, &mut current);
+    }
+
+    let mut in_fence = false;
+    for line in markdown.lines() {
+        if line.trim_start().starts_with("```") {
+            in_fence = !in_fence;
+        }
+        let heading = if in_fence { None } else { parse_heading(line) };
+        if let Some((level, title)) = heading {
+            if !paragraph.trim().is_empty() {
+                paragraphs.push(std::mem::take(&mut paragraph));
+            }
+            flush_section(&mut chunks, &trail, &mut paragraphs);
+            trail.retain(|(l, _)| *l < level);
+            trail.push((level, title));
+        } else if line.trim().is_empty() && !in_fence {
+            if !paragraph.trim().is_empty() {
+                paragraphs.push(std::mem::take(&mut paragraph).trim_end().to_string());
+            }
+        } else {
+            if !paragraph.is_empty() {
+                paragraph.push('\n');
+            }
+            paragraph.push_str(line);
+        }
+    }
+    if !paragraph.trim().is_empty() {
+        paragraphs.push(paragraph.trim_end().to_string());
+    }
+    flush_section(&mut chunks, &trail, &mut paragraphs);
+    chunks
+}
+
+/// Replace the chunk index for a document with chunks of `markdown`.
+pub fn db_index_document_chunks(
+    conn: &Connection,
+    document_id: i64,
+    markdown: &str,
+) -> Result<usize, rusqlite::Error> {
+    let chunks = chunk_markdown(markdown);
+    conn.execute(
+        "DELETE FROM document_chunks WHERE document_id = ?1",
+        params![document_id],
+    )?;
+    let mut stmt = conn.prepare(
+        "INSERT INTO document_chunks (document_id, chunk_index, heading, content, token_estimate)
+         VALUES (?1, ?2, ?3, ?4, ?5)",
+    )?;
+    for (i, chunk) in chunks.iter().enumerate() {
+        stmt.execute(params![
+            document_id,
+            i as i64,
+            chunk.heading,
+            chunk.content,
+            chunk.token_estimate as i64
+        ])?;
+    }
+    Ok(chunks.len())
+}
+
+/// Ids, names and file paths of documents applicable to a skill
+/// (scope = 'all' or explicitly assigned). `None` selects global documents only.
+fn applicable_documents(
+    conn: &Connection,
+    skill_id: Option<i64>,
+) -> Result<Vec<(i64, String, String)>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT d.id, d.name, d.file_path
+         FROM documents d
+         WHERE d.scope = 'all'
+            OR EXISTS (
+                SELECT 1 FROM document_skills ds
+                WHERE ds.document_id = d.id AND ds.skill_id = ?1
+            )
+         ORDER BY d.created_at ASC, d.id ASC",
+    )?;
+    let rows = stmt
+        .query_map(params![skill_id], |r| Ok((r.get(0)?, r.get(1)?, r.get(2)?)))?
+        .filter_map(|r| r.ok())
+        .collect();
+    Ok(rows)
+}
+
+/// Index documents that predate chunking (or whose index was lost) on demand.
+fn ensure_documents_indexed(
+    conn: &Connection,
+    documents: &[(i64, String, String)],
+) -> Result<(), rusqlite::Error> {
+    for (id, name, file_path) in documents {
+        let indexed: i64 = conn.query_row(
+            "SELECT COUNT(*) FROM document_chunks WHERE document_id = ?1",
+            params![id],
+            |r| r.get(0),
+        )?;
+        if indexed > 0 {
+            continue;
+        }
+        match std::fs::read_to_string(file_path) {
+            Ok(markdown) => {
+                let count = db_index_document_chunks(conn, *id, &markdown)?;
+                log::info!(
+                    "[documents] indexed '{}' into {} chunks (doc_id={})",
+                    name,
+                    count,
+                    id
+                );
+            }
+            Err(e) => log::warn!(
+                "[documents] skipping missing/unreadable document '{}' at {}: {}",
+                name,
+                file_path,
+                e
+            ),
+        }
+    }
+    Ok(())
+}
+
+const STOPWORDS: &[&str] = &[
+    "about", "after", "also", "and", "any", "are", "but", "can", "does", "for", "from", "has",
+    "have", "how", "into", "its", "more", "not", "our", "should", "such", "that", "the", "their",
+    "them", "then", "there", "these", "they", "this", "those", "use", "used", "was", "what",
+    "when", "where", "which", "while", "who", "why", "will", "with", "would", "you", "your",
+];
+
+/// Turn free text into an FTS5 `OR` query of distinct, quoted terms.
+pub fn build_fts_query(text: &str) -> Option<String> {
+    let mut seen = HashSet::new();
+    let terms: Vec<String> = text
+        .split(|c: char| !c.is_alphanumeric())
+        .map(|t| t.to_lowercase())
+        .filter(|t| t.chars().count() >= 3 && !STOPWORDS.contains(&t.as_str()))
+        .filter(|t| seen.insert(t.clone()))
+        .take(64)
+        .map(|t| format!("\"{}\"", t))
+        .collect();
+    (!terms.is_empty()).then(|| terms.join(" OR "))
+}
+
+/// A chunk selected for prompt injection.
+#[derive(Debug, Clone)]
+struct SelectedChunk {
+    document_id: i64,
+    chunk_index: i64,
+    heading: Option<String>,
+    content: String,
+}
+
+/// Return the most relevant document chunks for a skill within `token_budget`.
+///
+/// `skill_id = None` restricts retrieval to documents scoped to all skills
+/// (used before a skill exists, e.g. scope review).
+///
+/// Chunks are ranked with FTS5 BM25 against `query` (typically the skill's
+/// purpose, description and open clarification questions). Remaining budget is
+/// filled with leading chunks from each document in turn so short libraries are
+/// still passed through whole. Selected chunks are regrouped per document in
+/// their original order; gaps between them are marked with `[…]`.
+pub fn db_retrieve_document_chunks(
+    conn: &Connection,
+    skill_id: Option<i64>,
+    query: &str,
+    token_budget: usize,
+) -> Result<Vec<DocumentContent>, rusqlite::Error> {
+    let documents = applicable_documents(conn, skill_id)?;
+    if documents.is_empty() || token_budget == 0 {
+        return Ok(vec![]);
+    }
+    ensure_documents_indexed(conn, &documents)?;
+
+    let doc_ids: HashSet<i64> = documents.iter().map(|(id, _, _)| *id).collect();
+    let mut selected: Vec<SelectedChunk> = Vec::new();
+    let mut chosen: HashSet<(i64, i64)> = HashSet::new();
+    let mut used = 0usize;
+
+    let mut try_add = |row: (i64, i64, Option<String>, String, i64),
+                       selected: &mut Vec<SelectedChunk>| {
+        let (document_id, chunk_index, heading, content, tokens) = row;
+        if !doc_ids.contains(&document_id) || chosen.contains(&(document_id, chunk_index)) {
+            return;
+        }
+        let tokens = tokens.max(0) as usize;
+        if used + tokens > token_budget {
+            return;
+        }
+        used += tokens;
+        chosen.insert((document_id, chunk_index));
+        selected.push(SelectedChunk {
+            document_id,
+            chunk_index,
+            heading,
+            content,
+        });
+    };
+
+    if let Some(fts_query) = build_fts_query(query) {
+        // Rank only applicable documents so unrelated ones cannot crowd the
+        // candidate limit.
+        let id_placeholders = (0..documents.len())
+            .map(|i| format!("?{}", i + 3))
+            .collect::<Vec<_>>()
+            .join(", ");
+        let mut stmt = conn.prepare(&format!(
+            "SELECT c.document_id, c.chunk_index, c.heading, c.content, c.token_estimate
+             FROM document_chunks_fts f
+             JOIN document_chunks c ON c.id = f.rowid
+             WHERE document_chunks_fts MATCH ?1 AND c.document_id IN ({})
+             ORDER BY bm25(document_chunks_fts)
+             LIMIT ?2",
+            id_placeholders
+        ))?;
+        let mut args: Vec<&dyn rusqlite::ToSql> = vec![&fts_query, &MAX_RANKED_CANDIDATES];
+        args.extend(
+            documents
+                .iter()
+                .map(|(id, _, _)| id as &dyn rusqlite::ToSql),
+        );
+        let rows: Vec<_> = stmt
+            .query_map(args.as_slice(), |r| {
+                Ok((r.get(0)?, r.get(1)?, r.get(2)?, r.get(3)?, r.get(4)?))
+            })?
+            .filter_map(|r| r.ok())
+            .collect();
+        for row in rows {
+            try_add(row, &mut selected);
+        }
+    }
+
+    // Fill the remaining budget with document-order chunks, round-robin
+    // across documents so no single long document starves the others.
+    let mut stmt = conn.prepare(
+        "SELECT document_id, chunk_index, heading, content, token_estimate
+         FROM document_chunks WHERE document_id = ?1 ORDER BY chunk_index ASC",
+    )?;
+    let mut per_doc: Vec<std::collections::VecDeque<_>> = documents
+        .iter()
+        .map(|(id, _, _)| {
+            stmt.query_map(params![id], |r| {
+                Ok((r.get(0)?, r.get(1)?, r.get(2)?, r.get(3)?, r.get(4)?))
+            })
+            .map(|rows| rows.filter_map(|r| r.ok()).collect())
+        })
+        .collect::<Result<_, _>>()?;
+    while per_doc.iter().any(|q| !q.is_empty()) {
+        for queue in per_doc.iter_mut() {
+            if let Some(row) = queue.pop_front() {
+                try_add(row, &mut selected);
+            }
+        }
+    }
+
+    let mut by_doc: BTreeMap<i64, Vec<SelectedChunk>> = BTreeMap::new();
+    for chunk in selected {
+        by_doc.entry(chunk.document_id).or_default().push(chunk);
+    }
+
+    let mut contents = Vec::new();
+    for (id, name, file_path) in documents {
+        let Some(mut chunks) = by_doc.remove(&id) else {
+            continue;
+        };
+        chunks.sort_by_key(|c| c.chunk_index);
+        let mut content = String::new();
+        let mut prev_index: Option<i64> = None;
+        let mut prev_heading: Option<String> = None;
+        for chunk in chunks {
+            if let Some(prev) = prev_index {
+                content.push_str(if chunk.chunk_index == prev + 1 {
+                    "\n\n"
+                } else {
+                    CHUNK_GAP_MARKER
+                });
+            }
+            if chunk.heading.is_some() && chunk.heading != prev_heading {
+                content.push_str(&format!(
+                    "**{}**\n\n",
+                    chunk.heading.as_deref().unwrap_or("")
+                ));
+            }
+            content.push_str(&chunk.content);
+            prev_index = Some(chunk.chunk_index);
+            prev_heading = chunk.heading;
+        }
+        contents.push(DocumentContent {
+            name,
+            file_path,
+            content,
+        });
+    }
+
+    log::debug!(
+        "[documents] retrieved {} documents ({} of {} budget tokens) for skill_id={:?}",
+        contents.len(),
+        used,
+        token_budget,
+        skill_id
+    );
+    Ok(contents)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db;
+    use crate::skill_paths::DEFAULT_PLUGIN_SLUG;
+
+    fn insert_test_skill(conn: &Connection, name: &str) -> i64 {
+        let plugin_id: i64 = conn
+            .query_row(
+                "SELECT id FROM plugins WHERE slug = ?1",
+                [DEFAULT_PLUGIN_SLUG],
+                |r| r.get(0),
+            )
+            .unwrap();
+        conn.execute(
+            "INSERT INTO skills (name, skill_source, plugin_id) VALUES (?1, 'skill-builder', ?2)",
+            params![name, plugin_id],
+        )
+        .unwrap();
+        conn.last_insert_rowid()
+    }
+
+    fn insert_doc(conn: &Connection, dir: &std::path::Path, name: &str, body: &str) -> i64 {
+        let path = dir.join(format!("{}.md", name));
+        std::fs::write(&path, body).unwrap();
+        db::db_insert_document(conn, name, "file", None, path.to_str().unwrap(), "all").unwrap()
+    }
+
+    #[test]
+    fn test_chunk_markdown_tracks_heading_trail() {
+        let md = "Intro text.\n\n# Orders\n\nOrder basics.\n\n## Refunds\n\nRefund rules.\n\n# Customers\n\nCustomer data.";
+        let chunks = chunk_markdown(md);
+        let summary: Vec<(Option<&str>, &str)> = chunks
+            .iter()
+            .map(|c| (c.heading.as_deref(), c.content.as_str()))
+            .collect();
+        assert_eq!(
+            summary,
+            vec![
+                (None, "Intro text."),
+                (Some("Orders"), "Order basics."),
+                (Some("Orders > Refunds"), "Refund rules."),
+                (Some("Customers"), "Customer data."),
+            ]
+        );
+    }
+
+    #[test]
+    fn test_chunk_markdown_respects_target_size() {
+        let paragraph = "word ".repeat(300);
+        let md = format!("# Big\n\n{}\n\n{}\n\n{}", paragraph, paragraph, paragraph);
+        let chunks = chunk_markdown(&md);
+        assert!(
+            chunks.len() >= 3,
+            "expected several chunks, got {}",
+            chunks.len()
+        );
+        for chunk in &chunks {
+            assert!(chunk.token_estimate <= CHUNK_TARGET_TOKENS);
+            assert_eq!(chunk.heading.as_deref(), Some("Big"));
+        }
+    }
+
+    #[test]
+    fn test_chunk_markdown_ignores_hashes_in_code_fences() {
+        let md = "# Setup\n\n```bash\n# not a heading\nrun it\n```";
+        let chunks = chunk_markdown(md);
+        assert_eq!(chunks.len(), 1);
+        assert!(chunks[0].content.contains("# not a heading"));
+        assert_eq!(chunks[0].heading.as_deref(), Some("Setup"));
+    }
+
+    #[test]
+    fn test_build_fts_query_filters_and_quotes_terms() {
+        assert_eq!(
+            build_fts_query("What is the refund window for orders? Refund!").as_deref(),
+            Some("\"refund\" OR \"window\" OR \"orders\"")
+        );
+        assert!(build_fts_query("is it ok").is_none());
+    }
+
+    #[test]
+    fn test_retrieve_prefers_relevant_chunks_within_budget() {
+        let conn = db::create_test_db_for_tests();
+        let skill_id = insert_test_skill(&conn, "refunds-skill");
+        let dir = tempfile::tempdir().unwrap();
+
+        let filler = "Unrelated shipping logistics and warehouse notes. ".repeat(30);
+        let body = format!(
+            "# Shipping\n\n{filler}\n\n# Shipping detail\n\n{filler}\n\n# Refunds\n\nRefunds are issued within 30 days of purchase."
+        );
+        let doc_id = insert_doc(&conn, dir.path(), "policy", &body);
+        db_index_document_chunks(&conn, doc_id, &body).unwrap();
+
+        let refund_tokens = estimate_tokens("Refunds are issued within 30 days of purchase.");
+        let contents = db_retrieve_document_chunks(
+            &conn,
+            Some(skill_id),
+            "refund policy purchase",
+            refund_tokens,
+        )
+        .unwrap();
+        assert_eq!(contents.len(), 1);
+        assert!(contents[0].content.contains("Refunds are issued"));
+        assert!(!contents[0].content.contains("warehouse"));
+        assert!(contents[0].content.starts_with("**Refunds**"));
+    }
+
+    #[test]
+    fn test_retrieve_fills_budget_in_document_order_and_marks_gaps() {
+        let conn = db::create_test_db_for_tests();
+        let skill_id = insert_test_skill(&conn, "skill-a");
+        let dir = tempfile::tempdir().unwrap();
+
+        let body = "# A\n\nalpha section\n\n# B\n\nbravo section\n\n# C\n\ncharlie section";
+        let doc_id = insert_doc(&conn, dir.path(), "abc", body);
+        db_index_document_chunks(&conn, doc_id, body).unwrap();
+
+        // Budget for two chunks: the ranked "charlie" chunk plus the first chunk.
+        let budget = estimate_tokens("alpha section") + estimate_tokens("charlie section");
+        let contents =
+            db_retrieve_document_chunks(&conn, Some(skill_id), "charlie", budget).unwrap();
+        assert_eq!(
+            contents[0].content,
+            "**A**\n\nalpha section\n\n[…]\n\n**C**\n\ncharlie section"
+        );
+    }
+
+    #[test]
+    fn test_retrieve_indexes_legacy_documents_on_demand() {
+        let conn = db::create_test_db_for_tests();
+        let skill_id = insert_test_skill(&conn, "skill-legacy");
+        let dir = tempfile::tempdir().unwrap();
+        insert_doc(&conn, dir.path(), "legacy", "Glossary of revenue terms.");
+
+        let contents =
+            db_retrieve_document_chunks(&conn, Some(skill_id), "revenue", 1_000).unwrap();
+        assert_eq!(contents.len(), 1);
+        assert_eq!(contents[0].content, "Glossary of revenue terms.");
+
+        let count: i64 = conn
+            .query_row("SELECT COUNT(*) FROM document_chunks", [], |r| r.get(0))
+            .unwrap();
+        assert_eq!(count, 1);
+    }
+
+    #[test]
+    fn test_retrieve_excludes_documents_scoped_to_other_skills() {
+        let conn = db::create_test_db_for_tests();
+        let skill_a = insert_test_skill(&conn, "skill-a");
+        let skill_b = insert_test_skill(&conn, "skill-b");
+        let dir = tempfile::tempdir().unwrap();
+        let path = dir.path().join("scoped.md");
+        std::fs::write(&path, "Scoped revenue content").unwrap();
+        let doc_id = db::db_insert_document(
+            &conn,
+            "Scoped",
+            "file",
+            None,
+            path.to_str().unwrap(),
+            "skill",
+        )
+        .unwrap();
+        db::db_set_document_skills(&conn, doc_id, &[skill_a]).unwrap();
+
+        assert_eq!(
+            db_retrieve_document_chunks(&conn, Some(skill_a), "revenue", 1_000)
+                .unwrap()
+                .len(),
+            1
+        );
+        assert!(
+            db_retrieve_document_chunks(&conn, Some(skill_b), "revenue", 1_000)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn test_retrieve_ranks_only_applicable_documents() {
+        let conn = db::create_test_db_for_tests();
+        let skill_a = insert_test_skill(&conn, "skill-a");
+        let skill_b = insert_test_skill(&conn, "skill-b");
+        let dir = tempfile::tempdir().unwrap();
+
+        // Enough strongly matching chunks in another skill's document to fill
+        // the ranked candidate limit on their own.
+        let crowd: String = (0..MAX_RANKED_CANDIDATES + 50)
+            .map(|i| format!("# Section {i}\n\nrevenue revenue revenue\n\n"))
+            .collect();
+        let path = dir.path().join("crowd.md");
+        std::fs::write(&path, &crowd).unwrap();
+        let crowd_id = db::db_insert_document(
+            &conn,
+            "Crowd",
+            "file",
+            None,
+            path.to_str().unwrap(),
+            "skill",
+        )
+        .unwrap();
+        db::db_set_document_skills(&conn, crowd_id, &[skill_b]).unwrap();
+        db_index_document_chunks(&conn, crowd_id, &crowd).unwrap();
+
+        let body = "# Intro\n\nGeneral notes.\n\n# Revenue\n\nRevenue is recognized monthly.";
+        let doc_id = insert_doc(&conn, dir.path(), "finance", body);
+        db_index_document_chunks(&conn, doc_id, body).unwrap();
+
+        let budget = estimate_tokens("Revenue is recognized monthly.");
+        let contents =
+            db_retrieve_document_chunks(&conn, Some(skill_a), "revenue", budget).unwrap();
+        assert_eq!(contents.len(), 1);
+        assert!(contents[0].content.contains("recognized monthly"));
+    }
+
+    #[test]
+    fn test_chunks_removed_with_document() {
+        let conn = db::create_test_db_for_tests();
+        let dir = tempfile::tempdir().unwrap();
+        let doc_id = insert_doc(&conn, dir.path(), "gone", "Some text");
+        db_index_document_chunks(&conn, doc_id, "Some text").unwrap();
+        db::db_delete_document(&conn, doc_id).unwrap();
+
+        let fts_hits: i64 = conn
+            .query_row(
+                "SELECT COUNT(*) FROM document_chunks_fts WHERE document_chunks_fts MATCH 'text'",
+                [],
+                |r| r.get(0),
+            )
+            .unwrap();
+        assert_eq!(fts_hits, 0);
+    }
+}
@@ -13,9 +13,43 @@ pub struct DocumentRecord {
     pub skill_ids: Vec<i64>,
     pub created_at: String,
     pub updated_at: String,
+    /// HTTP validators from the last fetch (URL documents only).
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    /// SHA-256 of the stored Markdown.
+    pub content_hash: Option<String>,
+    pub fetched_at: Option<String>,
+    /// Set when a refresh replaced the content; compared against skill
+    /// generation times to find skills that need regenerating.
+    pub content_changed_at: Option<String>,
 }
 
-/// Lightweight document content used for injecting into agent prompts.
+/// A previous version of a document, archived when a refresh replaced it.
+#[derive(Debug, Clone, serde::Serialize, serde::Deserialize)]
+pub struct DocumentVersionRecord {
+    pub id: i64,
+    pub document_id: i64,
+    pub file_path: String,
+    pub content_hash: Option<String>,
+    pub etag: Option<String>,
+    pub last_modified: Option<String>,
+    pub fetched_at: Option<String>,
+    pub replaced_at: String,
+}
+
+/// A generated skill with assigned documents that changed after its last
+/// generation (step 3 completion).
+#[derive(Debug, Clone, PartialEq, serde::Serialize, serde::Deserialize)]
+pub struct StaleDocumentSkill {
+    pub skill_id: i64,
+    pub skill_name: String,
+    pub plugin_slug: String,
+    pub generated_at: String,
+    pub changed_documents: Vec<String>,
+}
+
+/// Document content injected into agent prompts: the retrieved chunks of one
+/// document, joined in original order (see `db_retrieve_document_chunks`).
 #[derive(Debug, Clone)]
 pub struct DocumentContent {
     pub name: String,
@@ -25,8 +59,6 @@ pub struct DocumentContent {
     pub content: String,
 }
 
-const MAX_DOCUMENT_CHARS: usize = 50_000;
-
 pub fn db_insert_document(
     conn: &Connection,
     name: &str,
@@ -61,27 +93,38 @@ pub fn db_set_document_skills(
     Ok(())
 }
 
+const DOCUMENT_COLUMNS: &str =
+    "id, name, source_type, source_url, file_path, scope, created_at, updated_at,
+     etag, last_modified, content_hash, fetched_at, content_changed_at";
+
+fn document_from_row(r: &rusqlite::Row<'_>) -> rusqlite::Result<DocumentRecord> {
+    Ok(DocumentRecord {
+        id: r.get(0)?,
+        name: r.get(1)?,
+        source_type: r.get(2)?,
+        source_url: r.get(3)?,
+        file_path: r.get(4)?,
+        scope: r.get(5)?,
+        skill_ids: vec![],
+        created_at: r.get(6)?,
+        updated_at: r.get(7)?,
+        etag: r.get(8)?,
+        last_modified: r.get(9)?,
+        content_hash: r.get(10)?,
+        fetched_at: r.get(11)?,
+        content_changed_at: r.get(12)?,
+    })
+}
+
 pub fn db_get_document(
     conn: &Connection,
     id: i64,
 ) -> Result<Option<DocumentRecord>, rusqlite::Error> {
-    let mut stmt = conn.prepare(
-        "SELECT id, name, source_type, source_url, file_path, scope, created_at, updated_at
-         FROM documents WHERE id = ?1",
-    )?;
-    let row = stmt.query_row(params![id], |r| {
-        Ok(DocumentRecord {
-            id: r.get(0)?,
-            name: r.get(1)?,
-            source_type: r.get(2)?,
-            source_url: r.get(3)?,
-            file_path: r.get(4)?,
-            scope: r.get(5)?,
-            skill_ids: vec![],
-            created_at: r.get(6)?,
-            updated_at: r.get(7)?,
-        })
-    });
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {} FROM documents WHERE id = ?1",
+        DOCUMENT_COLUMNS
+    ))?;
+    let row = stmt.query_row(params![id], document_from_row);
     match row {
         Ok(mut doc) => {
             doc.skill_ids = db_get_skill_ids_for_document(conn, doc.id)?;
@@ -93,23 +136,11 @@ pub fn db_get_document(
 }
 
 pub fn db_list_documents(conn: &Connection) -> Result<Vec<DocumentRecord>, rusqlite::Error> {
-    let mut stmt = conn.prepare(
-        "SELECT id, name, source_type, source_url, file_path, scope, created_at, updated_at
-         FROM documents ORDER BY created_at DESC",
-    )?;
-    let rows = stmt.query_map([], |r| {
-        Ok(DocumentRecord {
-            id: r.get(0)?,
-            name: r.get(1)?,
-            source_type: r.get(2)?,
-            source_url: r.get(3)?,
-            file_path: r.get(4)?,
-            scope: r.get(5)?,
-            skill_ids: vec![],
-            created_at: r.get(6)?,
-            updated_at: r.get(7)?,
-        })
-    })?;
+    let mut stmt = conn.prepare(&format!(
+        "SELECT {} FROM documents ORDER BY created_at DESC",
+        DOCUMENT_COLUMNS
+    ))?;
+    let rows = stmt.query_map([], document_from_row)?;
     let mut docs: Vec<DocumentRecord> = rows.filter_map(|r| r.ok()).collect();
     for doc in &mut docs {
         doc.skill_ids = db_get_skill_ids_for_document(conn, doc.id).unwrap_or_default();
@@ -143,75 +174,184 @@ pub fn db_update_document_scope(
     Ok(())
 }
 
-pub fn db_delete_document(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
-    conn.execute("DELETE FROM documents WHERE id = ?1", params![id])?;
+/// Record the validators and content hash seen on a fetch that left the
+/// stored content as-is (initial ingest or an unchanged refresh).
+pub fn db_set_document_fetch_state(
+    conn: &Connection,
+    id: i64,
+    etag: Option<&str>,
+    last_modified: Option<&str>,
+    content_hash: &str,
+    fetched_at: &str,
+) -> Result<(), rusqlite::Error> {
+    conn.execute(
+        "UPDATE documents
+         SET etag = ?1, last_modified = ?2, content_hash = ?3, fetched_at = ?4
+         WHERE id = ?5",
+        params![etag, last_modified, content_hash, fetched_at, id],
+    )?;
+    Ok(())
+}
+
+/// Archive `previous` as a document version (its content already moved to
+/// `archived_path`) and mark the document as changed at `fetched_at`.
+pub fn db_record_document_change(
+    conn: &Connection,
+    previous: &DocumentRecord,
+    archived_path: &str,
+    etag: Option<&str>,
+    last_modified: Option<&str>,
+    content_hash: &str,
+    fetched_at: &str,
+) -> Result<(), rusqlite::Error> {
+    conn.execute(
+        "INSERT INTO document_versions
+            (document_id, file_path, content_hash, etag, last_modified, fetched_at, replaced_at)
+         VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7)",
+        params![
+            previous.id,
+            archived_path,
+            previous.content_hash,
+            previous.etag,
+            previous.last_modified,
+            previous.fetched_at,
+            fetched_at
+        ],
+    )?;
+    conn.execute(
+        "UPDATE documents
+         SET etag = ?1, last_modified = ?2, content_hash = ?3, fetched_at = ?4,
+             content_changed_at = ?4, updated_at = datetime('now') || 'Z'
+         WHERE id = ?5",
+        params![etag, last_modified, content_hash, fetched_at, previous.id],
+    )?;
     Ok(())
 }
 
-/// Return document contents applicable for a given skill (scope=all OR skill_id in junction table).
-/// Truncates each document at MAX_DOCUMENT_CHARS and warns if truncated.
-/// If a document file is missing on disk, logs a warning and skips it.
-pub fn db_documents_for_skill(
+/// Archived versions of a document, newest first.
+pub fn db_list_document_versions(
     conn: &Connection,
-    skill_id: i64,
-) -> Result<Vec<DocumentContent>, rusqlite::Error> {
-    // Query documents that are either scoped to 'all' or explicitly assigned to this skill.
+    document_id: i64,
+) -> Result<Vec<DocumentVersionRecord>, rusqlite::Error> {
     let mut stmt = conn.prepare(
-        "SELECT d.name, d.file_path
-         FROM documents d
-         WHERE d.scope = 'all'
-            OR EXISTS (
-                SELECT 1 FROM document_skills ds
-                WHERE ds.document_id = d.id AND ds.skill_id = ?1
-            )
-         ORDER BY d.created_at ASC",
+        "SELECT id, document_id, file_path, content_hash, etag, last_modified, fetched_at, replaced_at
+         FROM document_versions WHERE document_id = ?1 ORDER BY id DESC",
     )?;
-    let rows: Vec<(String, String)> = stmt
-        .query_map(params![skill_id], |r| Ok((r.get(0)?, r.get(1)?)))?
-        .filter_map(|r| r.ok())
-        .collect();
+    let rows = stmt.query_map(params![document_id], |r| {
+        Ok(DocumentVersionRecord {
+            id: r.get(0)?,
+            document_id: r.get(1)?,
+            file_path: r.get(2)?,
+            content_hash: r.get(3)?,
+            etag: r.get(4)?,
+            last_modified: r.get(5)?,
+            fetched_at: r.get(6)?,
+            replaced_at: r.get(7)?,
+        })
+    })?;
+    rows.collect()
+}
+
+/// Skills whose last generation predates a content change in one of their
+/// applicable documents (scope `all`, or assigned via `document_skills`).
+///
+/// When `document_ids` is given only changes to those documents count.
+pub fn db_skills_with_changed_documents(
+    conn: &Connection,
+    document_ids: Option<&[i64]>,
+) -> Result<Vec<StaleDocumentSkill>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT s.id, s.name, p.slug, ws.completed_at, d.id, d.name, d.content_changed_at
+         FROM skills s
+         JOIN plugins p ON p.id = s.plugin_id
+         JOIN workflow_runs wr ON wr.skill_id = s.id
+         JOIN workflow_steps ws ON ws.workflow_run_id = wr.id
+             AND ws.step_id = 3 AND ws.status = 'completed' AND ws.completed_at IS NOT NULL
+         JOIN documents d ON d.content_changed_at IS NOT NULL
+             AND (d.scope = 'all' OR EXISTS (
+                 SELECT 1 FROM document_skills ds
+                 WHERE ds.document_id = d.id AND ds.skill_id = s.id))
+         WHERE COALESCE(s.deleted_at, '') = ''
+         ORDER BY p.slug, s.name, d.name",
+    )?;
+    let rows = stmt.query_map([], |r| {
+        Ok((
+            r.get::<_, i64>(0)?,
+            r.get::<_, String>(1)?,
+            r.get::<_, String>(2)?,
+            r.get::<_, String>(3)?,
+            r.get::<_, i64>(4)?,
+            r.get::<_, String>(5)?,
+            r.get::<_, String>(6)?,
+        ))
+    })?;
 
-    let mut contents = Vec::new();
-    for (name, file_path) in rows {
-        match std::fs::read_to_string(&file_path) {
-            Ok(raw) => {
-                let content = if raw.len() > MAX_DOCUMENT_CHARS {
-                    log::warn!(
-                        "[documents] document '{}' truncated at {} chars (was {} chars): {}",
-                        name,
-                        MAX_DOCUMENT_CHARS,
-                        raw.len(),
-                        file_path
-                    );
-                    let mut truncated = raw[..MAX_DOCUMENT_CHARS].to_string();
-                    truncated.push_str("\n\n[Document truncated at 50,000 characters]");
-                    truncated
-                } else {
-                    raw
-                };
-                contents.push(DocumentContent {
-                    name,
-                    file_path,
-                    content,
-                });
-            }
-            Err(e) => {
-                log::warn!(
-                    "[documents] skipping missing/unreadable document '{}' at {}: {}",
-                    name,
-                    file_path,
-                    e
-                );
-            }
+    let mut stale: Vec<StaleDocumentSkill> = Vec::new();
+    for row in rows {
+        let (skill_id, skill_name, plugin_slug, generated_at, doc_id, doc_name, changed_at) = row?;
+        if document_ids.is_some_and(|ids| !ids.contains(&doc_id)) {
+            continue;
+        }
+        if !is_later(&changed_at, &generated_at) {
+            continue;
+        }
+        match stale.last_mut() {
+            Some(last) if last.skill_id == skill_id => last.changed_documents.push(doc_name),
+            _ => stale.push(StaleDocumentSkill {
+                skill_id,
+                skill_name,
+                plugin_slug,
+                generated_at,
+                changed_documents: vec![doc_name],
+            }),
         }
     }
-    Ok(contents)
+    Ok(stale)
+}
+
+/// Compare two RFC 3339 timestamps, falling back to string order when either
+/// fails to parse.
+fn is_later(a: &str, b: &str) -> bool {
+    match (
+        chrono::DateTime::parse_from_rfc3339(a),
+        chrono::DateTime::parse_from_rfc3339(b),
+    ) {
+        (Ok(a), Ok(b)) => a > b,
+        _ => a > b,
+    }
+}
+
+/// Delete a document with its chunks and version history. The archived
+/// version files go too, since nothing else references them; the current
+/// file is left to the caller.
+pub fn db_delete_document(conn: &Connection, id: i64) -> Result<(), rusqlite::Error> {
+    let archived = db_list_document_versions(conn, id)?;
+    conn.execute(
+        "DELETE FROM document_chunks WHERE document_id = ?1",
+        params![id],
+    )?;
+    conn.execute(
+        "DELETE FROM document_versions WHERE document_id = ?1",
+        params![id],
+    )?;
+    conn.execute("DELETE FROM documents WHERE id = ?1", params![id])?;
+    for version in archived {
+        if let Err(e) = std::fs::remove_file(&version.file_path) {
+            log::warn!(
+                "db_delete_document: could not remove version file {}: {}",
+                version.file_path,
+                e
+            );
+        }
+    }
+    Ok(())
 }
 
 #[cfg(test)]
 mod tests {
     use super::*;
     use crate::db;
+    use crate::db::db_retrieve_document_chunks;
     use crate::skill_paths::DEFAULT_PLUGIN_SLUG;
 
     fn insert_test_skill(conn: &Connection, name: &str) -> i64 {
@@ -241,6 +381,22 @@ mod tests {
             db_insert_document(&conn, "My Doc", "file", None, "/tmp/my-doc.md", "all").unwrap();
         assert!(doc_id > 0);
 
+        // Archive one earlier version on disk
+        let dir = tempfile::tempdir().unwrap();
+        let archived = dir.path().join(format!("{}-my-doc.v1.md", doc_id));
+        std::fs::write(&archived, "old content").unwrap();
+        let doc = db_get_document(&conn, doc_id).unwrap().unwrap();
+        db_record_document_change(
+            &conn,
+            &doc,
+            archived.to_str().unwrap(),
+            None,
+            None,
+            "new-hash",
+            "2026-10-01T00:00:00Z",
+        )
+        .unwrap();
+
         // List — should include it
         let docs = db_list_documents(&conn).unwrap();
         assert_eq!(docs.len(), 1);
@@ -254,9 +410,11 @@ mod tests {
         assert_eq!(doc.scope, "skill");
         assert_eq!(doc.skill_ids, vec![skill_id]);
 
-        // Delete
+        // Delete removes the version history and its archived files
         db_delete_document(&conn, doc_id).unwrap();
         assert!(db_get_document(&conn, doc_id).unwrap().is_none());
+        assert!(db_list_document_versions(&conn, doc_id).unwrap().is_empty());
+        assert!(!archived.exists());
     }
 
     #[test]
@@ -279,7 +437,7 @@ mod tests {
         )
         .unwrap();
 
-        let contents = db_documents_for_skill(&conn, skill_id).unwrap();
+        let contents = db_retrieve_document_chunks(&conn, Some(skill_id), "", 10_000).unwrap();
         assert_eq!(contents.len(), 1);
         assert_eq!(contents[0].content, "Hello document");
         assert_eq!(contents[0].file_path, file_path.to_str().unwrap());
@@ -307,11 +465,76 @@ mod tests {
         db_set_document_skills(&conn, doc_id, &[skill_b]).unwrap();
 
         // skill_b sees the doc
-        let contents_b = db_documents_for_skill(&conn, skill_b).unwrap();
+        let contents_b = db_retrieve_document_chunks(&conn, Some(skill_b), "", 10_000).unwrap();
         assert_eq!(contents_b.len(), 1);
 
         // skill_c does not
-        let contents_c = db_documents_for_skill(&conn, skill_c).unwrap();
+        let contents_c = db_retrieve_document_chunks(&conn, Some(skill_c), "", 10_000).unwrap();
         assert!(contents_c.is_empty());
     }
+
+    fn generated_skill(conn: &Connection, name: &str, completed_at: &str) -> i64 {
+        let skill_id = db::upsert_skill(conn, name, "skill-builder", "domain").unwrap();
+        db::save_workflow_run_by_skill_id(conn, skill_id, 3, "completed", "domain").unwrap();
+        db::save_workflow_step_by_skill_id(conn, skill_id, 3, "completed").unwrap();
+        conn.execute(
+            "UPDATE workflow_steps SET completed_at = ?1 WHERE skill_name = ?2 AND step_id = 3",
+            params![completed_at, name],
+        )
+        .unwrap();
+        skill_id
+    }
+
+    fn mark_changed(conn: &Connection, doc_id: i64, changed_at: &str) {
+        conn.execute(
+            "UPDATE documents SET content_changed_at = ?1 WHERE id = ?2",
+            params![changed_at, doc_id],
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn test_skills_with_changed_documents_compares_generation_time() {
+        let conn = db::create_test_db_for_tests();
+        let old = generated_skill(&conn, "old-skill", "2026-03-01T10:00:00+00:00");
+        generated_skill(&conn, "fresh-skill", "2026-03-05T10:00:00.123456+00:00");
+        insert_test_skill(&conn, "never-generated");
+
+        let doc_id = db_insert_document(&conn, "Guide", "url", None, "/tmp/g.md", "all").unwrap();
+        assert!(db_skills_with_changed_documents(&conn, None)
+            .unwrap()
+            .is_empty());
+
+        mark_changed(&conn, doc_id, "2026-03-02T08:30:00.5+00:00");
+        let stale = db_skills_with_changed_documents(&conn, None).unwrap();
+        assert_eq!(stale.len(), 1);
+        assert_eq!(stale[0].skill_id, old);
+        assert_eq!(stale[0].changed_documents, vec!["Guide".to_string()]);
+
+        assert!(db_skills_with_changed_documents(&conn, Some(&[doc_id + 1]))
+            .unwrap()
+            .is_empty());
+    }
+
+    #[test]
+    fn test_skills_with_changed_documents_respects_skill_scope() {
+        let conn = db::create_test_db_for_tests();
+        let assigned = generated_skill(&conn, "assigned", "2026-03-01T10:00:00+00:00");
+        generated_skill(&conn, "unassigned", "2026-03-01T10:00:00+00:00");
+
+        let first = db_insert_document(&conn, "A", "url", None, "/tmp/a.md", "skill").unwrap();
+        let second = db_insert_document(&conn, "B", "url", None, "/tmp/b.md", "skill").unwrap();
+        db_set_document_skills(&conn, first, &[assigned]).unwrap();
+        db_set_document_skills(&conn, second, &[assigned]).unwrap();
+        mark_changed(&conn, first, "2026-03-02T00:00:00+00:00");
+        mark_changed(&conn, second, "2026-03-03T00:00:00+00:00");
+
+        let stale = db_skills_with_changed_documents(&conn, None).unwrap();
+        assert_eq!(stale.len(), 1);
+        assert_eq!(stale[0].skill_name, "assigned");
+        assert_eq!(
+            stale[0].changed_documents,
+            vec!["A".to_string(), "B".to_string()]
+        );
+    }
 }
@@ -3,23 +3,28 @@
 use rusqlite::{params, Connection};
 use serde::{Deserialize, Serialize};
 
+use crate::commands::eval_workbench::assertions::Assertion;
+
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
 #[serde(rename_all = "snake_case")]
 pub enum EvalWorkbenchMode {
     Performance,
+    Trigger,
 }
 
 impl EvalWorkbenchMode {
     pub fn as_str(self) -> &'static str {
         match self {
             Self::Performance => "performance",
+            Self::Trigger => "trigger",
         }
     }
 
     pub fn parse(value: &str) -> Result<Self, String> {
         match value {
             "performance" => Ok(Self::Performance),
-            _ => Err("mode must be 'performance'".to_string()),
+            "trigger" => Ok(Self::Trigger),
+            _ => Err("mode must be 'performance' or 'trigger'".to_string()),
         }
     }
 }
@@ -33,10 +38,11 @@ pub struct Scenario {
     pub name: String,
     pub mode: EvalWorkbenchMode,
     pub prompt: String,
+    pub should_trigger: Option<bool>,
     pub sort_order: i64,
     pub created_at: String,
     pub updated_at: String,
-    pub assertions: Vec<String>,
+    pub assertions: Vec<Assertion>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -48,7 +54,9 @@ pub struct SaveScenario {
     pub name: String,
     pub mode: EvalWorkbenchMode,
     pub prompt: String,
-    pub assertions: Vec<String>,
+    #[serde(default)]
+    pub should_trigger: Option<bool>,
+    pub assertions: Vec<Assertion>,
 }
 
 fn now() -> String {
@@ -62,14 +70,15 @@ pub fn save_scenario(conn: &mut Connection, input: SaveScenario) -> Result<Scena
 
     tx.execute(
         "INSERT INTO scenarios (
-            id, plugin_slug, skill_name, name, mode, prompt, sort_order, created_at, updated_at
-        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?8)
+            id, plugin_slug, skill_name, name, mode, prompt, should_trigger, sort_order, created_at, updated_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?9)
         ON CONFLICT(id) DO UPDATE SET
             plugin_slug = excluded.plugin_slug,
             skill_name = excluded.skill_name,
             name = excluded.name,
             mode = excluded.mode,
             prompt = excluded.prompt,
+            should_trigger = excluded.should_trigger,
             sort_order = excluded.sort_order,
             updated_at = excluded.updated_at",
         params![
@@ -79,6 +88,7 @@ pub fn save_scenario(conn: &mut Connection, input: SaveScenario) -> Result<Scena
             input.name,
             input.mode.as_str(),
             input.prompt,
+            input.should_trigger,
             0i64,
             timestamp,
         ],
@@ -96,7 +106,12 @@ pub fn save_scenario(conn: &mut Connection, input: SaveScenario) -> Result<Scena
         tx.execute(
             "INSERT INTO assertions (id, scenario_id, assertion, sort_order)
              VALUES (?1, ?2, ?3, ?4)",
-            params![assertion_id, scenario_id, assertion, index as i64,],
+            params![
+                assertion_id,
+                scenario_id,
+                assertion.to_storage_text(),
+                index as i64,
+            ],
         )
         .map_err(|e| e.to_string())?;
     }
@@ -113,7 +128,7 @@ pub fn list_scenarios(
 ) -> Result<Vec<Scenario>, String> {
     let mut stmt = conn
         .prepare(
-            "SELECT id, plugin_slug, skill_name, name, mode, prompt, sort_order, created_at, updated_at
+            "SELECT id, plugin_slug, skill_name, name, mode, prompt, should_trigger, sort_order, created_at, updated_at
              FROM scenarios
              WHERE plugin_slug = ?1 AND skill_name = ?2
              ORDER BY sort_order ASC, name ASC",
@@ -130,9 +145,10 @@ pub fn list_scenarios(
                 row.get::<_, String>(3)?,
                 mode_str,
                 row.get::<_, String>(5)?,
-                row.get::<_, i64>(6)?,
-                row.get::<_, String>(7)?,
+                row.get::<_, Option<bool>>(6)?,
+                row.get::<_, i64>(7)?,
                 row.get::<_, String>(8)?,
+                row.get::<_, String>(9)?,
             ))
         })
         .map_err(|e| e.to_string())?
@@ -142,7 +158,19 @@ pub fn list_scenarios(
     drop(stmt);
 
     let mut scenarios = Vec::with_capacity(rows.len());
-    for (id, pslug, sname, name, mode_str, prompt, sort_order, created_at, updated_at) in rows {
+    for (
+        id,
+        pslug,
+        sname,
+        name,
+        mode_str,
+        prompt,
+        should_trigger,
+        sort_order,
+        created_at,
+        updated_at,
+    ) in rows
+    {
         let assertions = read_assertions(conn, &id)?;
         scenarios.push(Scenario {
             id,
@@ -151,6 +179,7 @@ pub fn list_scenarios(
             name,
             mode: EvalWorkbenchMode::parse(&mode_str)?,
             prompt,
+            should_trigger,
             sort_order,
             created_at,
             updated_at,
@@ -161,7 +190,29 @@ pub fn list_scenarios(
     Ok(scenarios)
 }
 
-fn read_assertions(conn: &Connection, scenario_id: &str) -> Result<Vec<String>, String> {
+/// Scenarios for every skill of a plugin, ordered by skill name.
+pub fn list_plugin_scenarios(
+    conn: &Connection,
+    plugin_slug: &str,
+) -> Result<Vec<Scenario>, String> {
+    let skill_names = conn
+        .prepare(
+            "SELECT DISTINCT skill_name FROM scenarios WHERE plugin_slug = ?1 ORDER BY skill_name",
+        )
+        .map_err(|e| e.to_string())?
+        .query_map(params![plugin_slug], |row| row.get::<_, String>(0))
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    let mut scenarios = Vec::new();
+    for skill_name in skill_names {
+        scenarios.extend(list_scenarios(conn, plugin_slug, &skill_name)?);
+    }
+    Ok(scenarios)
+}
+
+fn read_assertions(conn: &Connection, scenario_id: &str) -> Result<Vec<Assertion>, String> {
     let mut stmt = conn
         .prepare(
             "SELECT assertion FROM assertions
@@ -175,7 +226,10 @@ fn read_assertions(conn: &Connection, scenario_id: &str) -> Result<Vec<String>,
         .map_err(|e| e.to_string())?
         .collect::<Result<Vec<_>, _>>()
         .map_err(|e| e.to_string())?;
-    Ok(result)
+    Ok(result
+        .iter()
+        .map(|text| Assertion::from_storage_text(text))
+        .collect())
 }
 
 pub fn read_scenario(
@@ -186,7 +240,7 @@ pub fn read_scenario(
 ) -> Result<Option<Scenario>, String> {
     let mut stmt = conn
         .prepare(
-            "SELECT id, plugin_slug, skill_name, name, mode, prompt, sort_order, created_at, updated_at
+            "SELECT id, plugin_slug, skill_name, name, mode, prompt, should_trigger, sort_order, created_at, updated_at
              FROM scenarios
              WHERE plugin_slug = ?1 AND skill_name = ?2 AND name = ?3",
         )
@@ -210,9 +264,10 @@ pub fn read_scenario(
         name: row.get(3).map_err(|e| e.to_string())?,
         mode: EvalWorkbenchMode::parse(&mode_str)?,
         prompt: row.get(5).map_err(|e| e.to_string())?,
-        sort_order: row.get(6).map_err(|e| e.to_string())?,
-        created_at: row.get(7).map_err(|e| e.to_string())?,
-        updated_at: row.get(8).map_err(|e| e.to_string())?,
+        should_trigger: row.get(6).map_err(|e| e.to_string())?,
+        sort_order: row.get(7).map_err(|e| e.to_string())?,
+        created_at: row.get(8).map_err(|e| e.to_string())?,
+        updated_at: row.get(9).map_err(|e| e.to_string())?,
         assertions,
     }))
 }
@@ -256,7 +311,8 @@ mod tests {
             name: name.to_string(),
             mode,
             prompt: prompt.to_string(),
-            assertions: assertions.into_iter().map(String::from).collect(),
+            should_trigger: None,
+            assertions: assertions.into_iter().map(Assertion::from).collect(),
         }
     }
 
@@ -283,7 +339,10 @@ mod tests {
         assert_eq!(read.name, "Smoke");
         assert_eq!(read.mode, EvalWorkbenchMode::Performance);
         assert_eq!(read.prompt, "Summarize revenue");
-        assert_eq!(read.assertions, vec!["Explains the forecast assumptions."]);
+        assert_eq!(
+            read.assertions,
+            vec![Assertion::from("Explains the forecast assumptions.")]
+        );
     }
 
     #[test]
@@ -355,7 +414,8 @@ mod tests {
                 name: "Update me".to_string(),
                 mode: EvalWorkbenchMode::Performance,
                 prompt: "Updated prompt".to_string(),
-                assertions: vec!["new assertion 1".to_string(), "new assertion 2".to_string()],
+                should_trigger: None,
+                assertions: vec!["new assertion 1".into(), "new assertion 2".into()],
             },
         )
         .unwrap();
@@ -363,13 +423,16 @@ mod tests {
         assert_eq!(updated.prompt, "Updated prompt");
         assert_eq!(
             updated.assertions,
-            vec!["new assertion 1", "new assertion 2"]
+            vec![
+                Assertion::from("new assertion 1"),
+                Assertion::from("new assertion 2")
+            ]
         );
 
         let read = read_scenario(&conn, "skills", "forecast", "Update me")
             .unwrap()
             .unwrap();
-        assert_eq!(read.assertions, vec!["new assertion 1", "new assertion 2"]);
+        assert_eq!(read.assertions, updated.assertions);
     }
 
     #[test]
@@ -420,6 +483,57 @@ mod tests {
         let read = read_scenario(&conn, "skills", "forecast", "Scenario smoke")
             .unwrap()
             .unwrap();
-        assert_eq!(read.assertions, vec!["Explains the forecast"]);
+        assert_eq!(
+            read.assertions,
+            vec![Assertion::from("Explains the forecast")]
+        );
+    }
+
+    #[test]
+    fn saves_trigger_scenario_with_label() {
+        let mut conn = test_db();
+        let mut input = save_scenario_input(
+            "skills",
+            "forecast",
+            "Off-topic",
+            EvalWorkbenchMode::Trigger,
+            "Write a haiku about autumn",
+            vec![],
+        );
+        input.should_trigger = Some(false);
+        save_scenario(&mut conn, input).unwrap();
+
+        let read = read_scenario(&conn, "skills", "forecast", "Off-topic")
+            .unwrap()
+            .unwrap();
+        assert_eq!(read.mode, EvalWorkbenchMode::Trigger);
+        assert_eq!(read.should_trigger, Some(false));
+        assert!(read.assertions.is_empty());
+    }
+
+    #[test]
+    fn round_trips_typed_assertions_next_to_rubrics() {
+        let mut conn = test_db();
+        let mut input = save_scenario_input(
+            "skills",
+            "forecast",
+            "Typed",
+            EvalWorkbenchMode::Performance,
+            "Forecast revenue as JSON",
+            vec!["Explains the forecast"],
+        );
+        let regex: Assertion =
+            serde_json::from_value(serde_json::json!({ "type": "regex", "value": "\\d+%" }))
+                .unwrap();
+        input.assertions.push(regex.clone());
+        save_scenario(&mut conn, input).unwrap();
+
+        let read = read_scenario(&conn, "skills", "forecast", "Typed")
+            .unwrap()
+            .unwrap();
+        assert_eq!(
+            read.assertions,
+            vec![Assertion::from("Explains the forecast"), regex]
+        );
     }
 }
@@ -0,0 +1,120 @@
+use rusqlite::{params, Connection, OptionalExtension};
+
+// --- Skill Git Remotes ---
+
+/// Placeholder in a plugin-wide remote URL that is replaced by the skill name.
+pub const SKILL_REMOTE_PLACEHOLDER: &str = "{skill}";
+
+/// Configure the remote for one skill (`skill_name = Some`) or for every skill
+/// in a plugin (`skill_name = None`). An empty URL removes the configuration.
+pub fn db_set_skill_git_remote(
+    conn: &Connection,
+    plugin_slug: &str,
+    skill_name: Option<&str>,
+    remote_url: &str,
+) -> Result<(), String> {
+    let skill_key = skill_name.unwrap_or("");
+    let remote_url = remote_url.trim();
+    if remote_url.is_empty() {
+        conn.execute(
+            "DELETE FROM skill_git_remotes WHERE plugin_slug = ?1 AND skill_name = ?2",
+            params![plugin_slug, skill_key],
+        )
+        .map_err(|e| e.to_string())?;
+        return Ok(());
+    }
+    conn.execute(
+        "INSERT INTO skill_git_remotes (plugin_slug, skill_name, remote_url)
+         VALUES (?1, ?2, ?3)
+         ON CONFLICT(plugin_slug, skill_name) DO UPDATE SET
+             remote_url = excluded.remote_url,
+             updated_at = datetime('now') || 'Z'",
+        params![plugin_slug, skill_key, remote_url],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// The raw configured URL for a skill (`Some`) or plugin (`None`) entry.
+pub fn db_get_skill_git_remote(
+    conn: &Connection,
+    plugin_slug: &str,
+    skill_name: Option<&str>,
+) -> Result<Option<String>, String> {
+    conn.query_row(
+        "SELECT remote_url FROM skill_git_remotes WHERE plugin_slug = ?1 AND skill_name = ?2",
+        params![plugin_slug, skill_name.unwrap_or("")],
+        |row| row.get(0),
+    )
+    .optional()
+    .map_err(|e| e.to_string())
+}
+
+/// Remote URL for a skill's repo: its own entry, else the plugin-wide entry
+/// expanded for this skill.
+pub fn db_resolve_skill_git_remote(
+    conn: &Connection,
+    plugin_slug: &str,
+    skill_name: &str,
+) -> Result<Option<String>, String> {
+    if let Some(url) = db_get_skill_git_remote(conn, plugin_slug, Some(skill_name))? {
+        return Ok(Some(url));
+    }
+    Ok(db_get_skill_git_remote(conn, plugin_slug, None)?
+        .map(|template| skill_git_remote_url(&template, skill_name)))
+}
+
+/// Expand a plugin-wide remote for one skill. `{skill}` is substituted;
+/// otherwise the URL is treated as a base and `<skill>.git` is appended.
+pub fn skill_git_remote_url(template: &str, skill_name: &str) -> String {
+    if template.contains(SKILL_REMOTE_PLACEHOLDER) {
+        template.replace(SKILL_REMOTE_PLACEHOLDER, skill_name)
+    } else {
+        format!("{}/{}.git", template.trim_end_matches('/'), skill_name)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::db::create_test_db_for_tests;
+
+    #[test]
+    fn test_skill_remote_overrides_plugin_remote() {
+        let conn = create_test_db_for_tests();
+        db_set_skill_git_remote(&conn, "tools", None, "git@example.com:team/{skill}.git").unwrap();
+
+        assert_eq!(
+            db_resolve_skill_git_remote(&conn, "tools", "lint").unwrap(),
+            Some("git@example.com:team/lint.git".to_string())
+        );
+
+        db_set_skill_git_remote(&conn, "tools", Some("lint"), "/srv/git/lint-fork.git").unwrap();
+        assert_eq!(
+            db_resolve_skill_git_remote(&conn, "tools", "lint").unwrap(),
+            Some("/srv/git/lint-fork.git".to_string())
+        );
+        assert_eq!(
+            db_resolve_skill_git_remote(&conn, "other", "lint").unwrap(),
+            None
+        );
+
+        db_set_skill_git_remote(&conn, "tools", Some("lint"), " ").unwrap();
+        assert_eq!(
+            db_get_skill_git_remote(&conn, "tools", Some("lint")).unwrap(),
+            None
+        );
+    }
+
+    #[test]
+    fn test_skill_git_remote_url_appends_skill_to_base() {
+        assert_eq!(
+            skill_git_remote_url("https://git.example.com/skills/", "lint"),
+            "https://git.example.com/skills/lint.git"
+        );
+        assert_eq!(
+            skill_git_remote_url("/srv/{skill}/repo.git", "lint"),
+            "/srv/lint/repo.git"
+        );
+    }
+}
@@ -0,0 +1,68 @@
+use rusqlite::{params, Connection, OptionalExtension};
+
+// --- HTTP Cache ---
+
+/// A cached response body and the validator to revalidate it with.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct HttpCacheEntry {
+    pub etag: Option<String>,
+    pub body: Vec<u8>,
+    /// When the body was last confirmed fresh (200 or 304), UTC with `Z`.
+    pub fetched_at: String,
+}
+
+pub fn db_get_http_cache(conn: &Connection, url: &str) -> Result<Option<HttpCacheEntry>, String> {
+    conn.query_row(
+        "SELECT etag, body, fetched_at FROM http_cache WHERE url = ?1",
+        params![url],
+        |row| {
+            Ok(HttpCacheEntry {
+                etag: row.get(0)?,
+                body: row.get(1)?,
+                fetched_at: row.get(2)?,
+            })
+        },
+    )
+    .optional()
+    .map_err(|e| e.to_string())
+}
+
+/// Store a fresh 200 response for `url`.
+pub fn db_put_http_cache(
+    conn: &Connection,
+    url: &str,
+    etag: Option<&str>,
+    body: &[u8],
+) -> Result<(), String> {
+    conn.execute(
+        "INSERT INTO http_cache (url, etag, body, fetched_at)
+         VALUES (?1, ?2, ?3, datetime('now') || 'Z')
+         ON CONFLICT(url) DO UPDATE SET
+             etag = excluded.etag,
+             body = excluded.body,
+             fetched_at = excluded.fetched_at",
+        params![url, etag, body],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Record that a 304 revalidated the cached body for `url`.
+pub fn db_touch_http_cache(conn: &Connection, url: &str) -> Result<(), String> {
+    conn.execute(
+        "UPDATE http_cache SET fetched_at = datetime('now') || 'Z' WHERE url = ?1",
+        params![url],
+    )
+    .map_err(|e| e.to_string())?;
+    Ok(())
+}
+
+/// Delete entries not fetched or revalidated in the last `max_age_days`.
+/// Returns how many were removed.
+pub fn db_prune_http_cache(conn: &Connection, max_age_days: i64) -> Result<usize, String> {
+    conn.execute(
+        "DELETE FROM http_cache WHERE fetched_at < datetime('now', ?1) || 'Z'",
+        params![format!("-{} days", max_age_days)],
+    )
+    .map_err(|e| e.to_string())
+}
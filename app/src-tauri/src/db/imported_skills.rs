@@ -1,5 +1,6 @@
 use crate::types::ImportedSkill;
 use rusqlite::{Connection, OptionalExtension};
+use std::collections::BTreeMap;
 use std::fs;
 
 use super::skills::get_skill_master_id_in_plugin;
@@ -321,6 +322,70 @@ pub fn set_imported_skill_content_hash(
     Ok(())
 }
 
+/// Integrity baseline recorded when a marketplace skill was imported.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct ImportedSkillIntegrity {
+    pub skill_name: String,
+    pub plugin_slug: String,
+    pub disk_path: String,
+    pub version: Option<String>,
+    pub marketplace_source_url: String,
+    pub source_commit: Option<String>,
+    /// Relative path → SHA-256 hex; `None` for skills imported before manifests were recorded.
+    pub file_manifest: Option<BTreeMap<String, String>>,
+}
+
+/// Record the source commit and per-file digests of a marketplace import.
+pub fn set_imported_skill_integrity(
+    conn: &Connection,
+    skill_master_id: i64,
+    source_commit: Option<&str>,
+    file_manifest: &BTreeMap<String, String>,
+) -> Result<(), String> {
+    let manifest = serde_json::to_string(file_manifest)
+        .map_err(|e| format!("set_imported_skill_integrity: {}", e))?;
+    conn.execute(
+        "UPDATE imported_skills SET source_commit = ?2, file_manifest = ?3
+         WHERE skill_master_id = ?1",
+        rusqlite::params![skill_master_id, source_commit, manifest],
+    )
+    .map_err(|e| format!("set_imported_skill_integrity: {}", e))?;
+    Ok(())
+}
+
+/// Integrity baselines of every skill imported from a marketplace.
+pub fn list_imported_skill_integrity(
+    conn: &Connection,
+) -> Result<Vec<ImportedSkillIntegrity>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT i.skill_name, pl.slug, i.disk_path, i.version, i.marketplace_source_url,
+                    i.source_commit, i.file_manifest
+             FROM imported_skills i
+             JOIN skills s ON s.id = i.skill_master_id AND COALESCE(s.deleted_at, '') = ''
+             JOIN plugins pl ON pl.id = s.plugin_id
+             WHERE i.marketplace_source_url IS NOT NULL
+             ORDER BY pl.slug, i.skill_name",
+        )
+        .map_err(|e| format!("list_imported_skill_integrity: {}", e))?;
+    let rows = stmt
+        .query_map([], |row| {
+            let manifest: Option<String> = row.get(6)?;
+            Ok(ImportedSkillIntegrity {
+                skill_name: row.get(0)?,
+                plugin_slug: row.get(1)?,
+                disk_path: row.get(2)?,
+                version: row.get(3)?,
+                marketplace_source_url: row.get(4)?,
+                source_commit: row.get(5)?,
+                file_manifest: manifest.and_then(|json| serde_json::from_str(&json).ok()),
+            })
+        })
+        .map_err(|e| format!("list_imported_skill_integrity query: {}", e))?;
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("list_imported_skill_integrity collect: {}", e))
+}
+
 /// Read disk_path and content_hash for an imported (marketplace/library) skill by name.
 pub fn get_imported_skill_hash_info(
     conn: &Connection,
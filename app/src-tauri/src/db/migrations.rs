@@ -63,6 +63,16 @@ pub(super) const NUMBERED_MIGRATIONS: &[(u32, MigrationFn)] = &[
     (59, run_drop_legacy_chat_tables_migration),
     (60, run_workflow_runtime_identity_migration),
     (61, run_conversation_run_usage_clean_break_migration),
+    (62, run_document_chunks_migration),
+    (63, run_document_refresh_migration),
+    (64, run_skill_git_remotes_migration),
+    (65, run_github_host_migration),
+    (66, run_http_cache_migration),
+    (67, run_imported_skill_integrity_migration),
+    (68, run_plugin_dependencies_migration),
+    (69, run_trigger_scenarios_migration),
+    (70, run_scenario_runs_migration),
+    (71, run_source_host_tokens_migration),
 ];
 
 pub(super) fn table_has_column(
@@ -134,7 +144,13 @@ pub(super) fn run_migrations(conn: &Connection) -> Result<(), rusqlite::Error> {
             industry TEXT,
             function_role TEXT,
             dashboard_view_mode TEXT,
-            auto_update INTEGER NOT NULL DEFAULT 0
+            auto_update INTEGER NOT NULL DEFAULT 0,
+            document_token_budget INTEGER NOT NULL DEFAULT 8000
+                CHECK (document_token_budget >= 0),
+            github_host TEXT,
+            github_oauth_client_id TEXT,
+            feedback_repo TEXT,
+            marketplace_offline INTEGER NOT NULL DEFAULT 0
         );
 
         CREATE TABLE IF NOT EXISTS model_provider_overrides (
@@ -160,6 +176,11 @@ pub(super) fn run_migrations(conn: &Connection) -> Result<(), rusqlite::Error> {
             enabled INTEGER NOT NULL DEFAULT 1
         );
 
+        CREATE TABLE IF NOT EXISTS source_host_tokens (
+            host TEXT PRIMARY KEY,
+            token TEXT NOT NULL
+        );
+
         CREATE TABLE IF NOT EXISTS plugins (
             id INTEGER PRIMARY KEY AUTOINCREMENT,
             slug TEXT NOT NULL UNIQUE,
@@ -430,8 +451,9 @@ pub(super) fn run_scenarios_migration(conn: &Connection) -> Result<(), rusqlite:
             plugin_slug TEXT NOT NULL,
             skill_name TEXT NOT NULL,
             name TEXT NOT NULL,
-            mode TEXT NOT NULL CHECK (mode IN ('performance')),
+            mode TEXT NOT NULL CHECK (mode IN ('performance', 'trigger')),
             prompt TEXT NOT NULL DEFAULT '',
+            should_trigger INTEGER,
             sort_order INTEGER NOT NULL DEFAULT 0,
             created_at TEXT NOT NULL,
             updated_at TEXT NOT NULL
@@ -3293,3 +3315,286 @@ pub(super) fn run_conversation_run_usage_clean_break_migration(
     );
     Ok(())
 }
+
+/// Migration 62: Chunk index for reference documents.
+///
+/// `document_chunks` holds ingest-time chunks of each document and is mirrored
+/// into the `document_chunks_fts` FTS5 index (external content, kept in sync by
+/// triggers). Documents ingested before this migration are indexed lazily on
+/// first retrieval. Also adds the per-prompt document token budget setting.
+pub(super) fn run_document_chunks_migration(conn: &Connection) -> Result<(), rusqlite::Error> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS document_chunks (
+            id             INTEGER PRIMARY KEY AUTOINCREMENT,
+            document_id    INTEGER NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+            chunk_index    INTEGER NOT NULL,
+            heading        TEXT,
+            content        TEXT NOT NULL,
+            token_estimate INTEGER NOT NULL,
+            UNIQUE (document_id, chunk_index)
+        );
+
+        CREATE VIRTUAL TABLE IF NOT EXISTS document_chunks_fts USING fts5(
+            heading,
+            content,
+            content = 'document_chunks',
+            content_rowid = 'id',
+            tokenize = 'porter unicode61'
+        );
+
+        CREATE TRIGGER IF NOT EXISTS document_chunks_ai AFTER INSERT ON document_chunks BEGIN
+            INSERT INTO document_chunks_fts (rowid, heading, content)
+            VALUES (new.id, new.heading, new.content);
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS document_chunks_ad AFTER DELETE ON document_chunks BEGIN
+            INSERT INTO document_chunks_fts (document_chunks_fts, rowid, heading, content)
+            VALUES ('delete', old.id, old.heading, old.content);
+        END;
+
+        CREATE TRIGGER IF NOT EXISTS document_chunks_au AFTER UPDATE ON document_chunks BEGIN
+            INSERT INTO document_chunks_fts (document_chunks_fts, rowid, heading, content)
+            VALUES ('delete', old.id, old.heading, old.content);
+            INSERT INTO document_chunks_fts (rowid, heading, content)
+            VALUES (new.id, new.heading, new.content);
+        END;",
+    )?;
+
+    if !table_has_column(conn, "app_settings", "document_token_budget")? {
+        conn.execute_batch(
+            "ALTER TABLE app_settings
+             ADD COLUMN document_token_budget INTEGER NOT NULL DEFAULT 8000
+             CHECK (document_token_budget >= 0);",
+        )?;
+    }
+
+    log::info!("migration 62: created document_chunks and document_chunks_fts");
+    Ok(())
+}
+
+/// Migration 63: Change tracking for refreshable documents.
+///
+/// Adds HTTP validators (`etag`, `last_modified`), a hash of the stored
+/// Markdown and fetch/change timestamps to `documents`, plus a
+/// `document_versions` table that keeps the content replaced by each refresh.
+pub(super) fn run_document_refresh_migration(conn: &Connection) -> Result<(), rusqlite::Error> {
+    for (column, ddl) in [
+        ("etag", "ALTER TABLE documents ADD COLUMN etag TEXT"),
+        (
+            "last_modified",
+            "ALTER TABLE documents ADD COLUMN last_modified TEXT",
+        ),
+        (
+            "content_hash",
+            "ALTER TABLE documents ADD COLUMN content_hash TEXT",
+        ),
+        (
+            "fetched_at",
+            "ALTER TABLE documents ADD COLUMN fetched_at TEXT",
+        ),
+        (
+            "content_changed_at",
+            "ALTER TABLE documents ADD COLUMN content_changed_at TEXT",
+        ),
+    ] {
+        if !table_has_column(conn, "documents", column)? {
+            conn.execute_batch(ddl)?;
+        }
+    }
+
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS document_versions (
+            id            INTEGER PRIMARY KEY AUTOINCREMENT,
+            document_id   INTEGER NOT NULL REFERENCES documents(id) ON DELETE CASCADE,
+            file_path     TEXT NOT NULL,
+            content_hash  TEXT,
+            etag          TEXT,
+            last_modified TEXT,
+            fetched_at    TEXT,
+            replaced_at   TEXT NOT NULL
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_document_versions_document
+            ON document_versions(document_id, id);",
+    )?;
+
+    log::info!("migration 63: added document refresh metadata and document_versions");
+    Ok(())
+}
+
+pub(super) fn run_skill_git_remotes_migration(conn: &Connection) -> Result<(), rusqlite::Error> {
+    // skill_name = '' is the plugin-wide remote; its URL is a template that
+    // each per-skill repo expands (see db::skill_git_remote_url).
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS skill_git_remotes (
+            id          INTEGER PRIMARY KEY AUTOINCREMENT,
+            plugin_slug TEXT NOT NULL,
+            skill_name  TEXT NOT NULL DEFAULT '',
+            remote_url  TEXT NOT NULL,
+            updated_at  TEXT NOT NULL DEFAULT (datetime('now') || 'Z'),
+            UNIQUE(plugin_slug, skill_name)
+        );",
+    )?;
+    log::info!("migration 64: added skill_git_remotes");
+    Ok(())
+}
+
+/// Migration 65: GitHub Enterprise Server host, OAuth app client ID and
+/// feedback repository. NULL means github.com and the built-in defaults.
+pub(super) fn run_github_host_migration(conn: &Connection) -> Result<(), rusqlite::Error> {
+    for column in ["github_host", "github_oauth_client_id", "feedback_repo"] {
+        if !table_has_column(conn, "app_settings", column)? {
+            conn.execute_batch(&format!(
+                "ALTER TABLE app_settings ADD COLUMN {} TEXT;",
+                column
+            ))?;
+        }
+    }
+    log::info!(
+        "migration 65: added app_settings.github_host, github_oauth_client_id and feedback_repo"
+    );
+    Ok(())
+}
+
+/// Migration 66: conditional-request cache for marketplace catalog fetches and
+/// the offline-mode setting that serves it without contacting the network.
+pub(super) fn run_http_cache_migration(conn: &Connection) -> Result<(), rusqlite::Error> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS http_cache (
+            url        TEXT PRIMARY KEY,
+            etag       TEXT,
+            body       BLOB NOT NULL,
+            fetched_at TEXT NOT NULL DEFAULT (datetime('now') || 'Z')
+        );",
+    )?;
+    if !table_has_column(conn, "app_settings", "marketplace_offline")? {
+        conn.execute_batch(
+            "ALTER TABLE app_settings ADD COLUMN marketplace_offline INTEGER NOT NULL DEFAULT 0;",
+        )?;
+    }
+    log::info!("migration 66: added http_cache and app_settings.marketplace_offline");
+    Ok(())
+}
+
+/// Migration 67: record the source commit and per-file SHA-256 manifest of
+/// each marketplace import, for `verify_imported_skills`.
+pub(super) fn run_imported_skill_integrity_migration(
+    conn: &Connection,
+) -> Result<(), rusqlite::Error> {
+    if !table_has_column(conn, "imported_skills", "source_commit")? {
+        conn.execute_batch("ALTER TABLE imported_skills ADD COLUMN source_commit TEXT;")?;
+    }
+    if !table_has_column(conn, "imported_skills", "file_manifest")? {
+        conn.execute_batch("ALTER TABLE imported_skills ADD COLUMN file_manifest TEXT;")?;
+    }
+    log::info!("migration 67: added imported_skills.source_commit and file_manifest");
+    Ok(())
+}
+
+/// Migration 68: plugin-to-plugin dependencies declared in `plugin.json`,
+/// stored as a JSON object of plugin name → semver range.
+pub(super) fn run_plugin_dependencies_migration(conn: &Connection) -> Result<(), rusqlite::Error> {
+    if !table_has_column(conn, "plugins", "dependencies")? {
+        conn.execute_batch("ALTER TABLE plugins ADD COLUMN dependencies TEXT;")?;
+    }
+    log::info!("migration 68: added plugins.dependencies");
+    Ok(())
+}
+
+/// Rebuilds `scenarios` so its mode CHECK admits trigger scenarios and adds
+/// their should-trigger label. SQLite cannot alter a CHECK constraint in place.
+pub(super) fn run_trigger_scenarios_migration(conn: &Connection) -> Result<(), rusqlite::Error> {
+    if table_has_column(conn, "scenarios", "should_trigger")? {
+        log::info!("migration 69: scenarios already support trigger mode");
+        return Ok(());
+    }
+    conn.execute_batch("BEGIN IMMEDIATE")?;
+    let result = conn.execute_batch(
+        "DROP TABLE IF EXISTS scenarios_v2;
+
+        CREATE TABLE scenarios_v2 (
+            id TEXT PRIMARY KEY,
+            plugin_slug TEXT NOT NULL,
+            skill_name TEXT NOT NULL,
+            name TEXT NOT NULL,
+            mode TEXT NOT NULL CHECK (mode IN ('performance', 'trigger')),
+            prompt TEXT NOT NULL DEFAULT '',
+            should_trigger INTEGER,
+            sort_order INTEGER NOT NULL DEFAULT 0,
+            created_at TEXT NOT NULL,
+            updated_at TEXT NOT NULL
+        );
+
+        INSERT INTO scenarios_v2 (
+            id, plugin_slug, skill_name, name, mode, prompt, should_trigger, sort_order, created_at, updated_at
+        )
+        SELECT id, plugin_slug, skill_name, name, mode, prompt, NULL, sort_order, created_at, updated_at
+        FROM scenarios;
+
+        DROP TABLE scenarios;
+        ALTER TABLE scenarios_v2 RENAME TO scenarios;
+
+        CREATE INDEX IF NOT EXISTS idx_scenarios_skill ON scenarios(plugin_slug, skill_name, sort_order);",
+    );
+
+    match result {
+        Ok(()) => {
+            conn.execute_batch("COMMIT")?;
+            log::info!("migration 69: scenarios accept trigger mode");
+            Ok(())
+        }
+        Err(error) => {
+            let _ = conn.execute_batch("ROLLBACK");
+            Err(error)
+        }
+    }
+}
+
+/// Migration 70: scenario run history. Runs are keyed by names rather than
+/// scenario ids so history outlives renamed or deleted scenario files.
+pub(super) fn run_scenario_runs_migration(conn: &Connection) -> Result<(), rusqlite::Error> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS scenario_runs (
+            id             TEXT PRIMARY KEY,
+            plugin_slug    TEXT NOT NULL,
+            skill_name     TEXT NOT NULL,
+            scenario_name  TEXT NOT NULL,
+            skill_version  TEXT,
+            skill_commit   TEXT,
+            working_copy   INTEGER NOT NULL DEFAULT 0,
+            model          TEXT NOT NULL,
+            passed         INTEGER,
+            total_cost_usd REAL,
+            duration_ms    INTEGER,
+            num_turns      INTEGER,
+            created_at     TEXT NOT NULL
+        );
+
+        CREATE INDEX IF NOT EXISTS idx_scenario_runs_skill
+            ON scenario_runs(plugin_slug, skill_name, created_at);
+
+        CREATE TABLE IF NOT EXISTS scenario_run_assertions (
+            run_id    TEXT NOT NULL REFERENCES scenario_runs(id) ON DELETE CASCADE,
+            position  INTEGER NOT NULL,
+            assertion TEXT NOT NULL,
+            kind      TEXT NOT NULL,
+            passed    INTEGER,
+            reason    TEXT NOT NULL DEFAULT '',
+            PRIMARY KEY (run_id, position)
+        );",
+    )?;
+    log::info!("migration 70: added scenario_runs and scenario_run_assertions");
+    Ok(())
+}
+
+/// Migration 71: per-host access tokens for private GitLab and Gitea marketplaces.
+pub(super) fn run_source_host_tokens_migration(conn: &Connection) -> Result<(), rusqlite::Error> {
+    conn.execute_batch(
+        "CREATE TABLE IF NOT EXISTS source_host_tokens (
+            host  TEXT PRIMARY KEY,
+            token TEXT NOT NULL
+        );",
+    )?;
+    log::info!("migration 71: added source_host_tokens");
+    Ok(())
+}
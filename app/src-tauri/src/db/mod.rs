@@ -3,12 +3,16 @@ use std::fs;
 use std::path::Path;
 use std::sync::Mutex;
 
+pub mod document_chunks;
 pub mod documents;
 pub mod eval_workbench;
+pub mod git_remotes;
+pub mod http_cache;
 pub mod imported_skills;
 pub mod locks;
 pub mod migrations;
 pub mod model_catalog;
+pub mod scenario_runs;
 pub mod settings;
 pub mod skill_identifier;
 pub mod skills;
@@ -16,7 +20,10 @@ pub mod usage;
 pub mod workflow;
 pub mod workflow_artifacts;
 
+pub use document_chunks::*;
 pub use documents::*;
+pub use git_remotes::*;
+pub use http_cache::*;
 pub use imported_skills::*;
 pub use locks::*;
 pub use model_catalog::*;
@@ -0,0 +1,573 @@
+use std::collections::BTreeMap;
+
+use rusqlite::{params, Connection, OptionalExtension};
+use serde::{Deserialize, Serialize};
+
+use crate::commands::eval_workbench::assertions::{Assertion, AssertionOutcome};
+use crate::git::parse_semver;
+
+/// One recorded scenario run with its per-assertion outcomes.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScenarioRun {
+    pub id: String,
+    pub plugin_slug: String,
+    pub skill_name: String,
+    pub scenario_name: String,
+    /// Released version the run measured; `None` for unreleased edits.
+    pub skill_version: Option<String>,
+    pub skill_commit: Option<String>,
+    /// The run measured uncommitted edits on top of `skill_commit`.
+    pub working_copy: bool,
+    pub model: String,
+    /// `None` while any assertion is undecided and none has failed.
+    pub passed: Option<bool>,
+    pub total_cost_usd: Option<f64>,
+    pub duration_ms: Option<i64>,
+    pub num_turns: Option<u32>,
+    pub created_at: String,
+    pub assertions: Vec<AssertionOutcome>,
+}
+
+#[derive(Debug, Clone)]
+pub struct NewScenarioRun {
+    pub plugin_slug: String,
+    pub skill_name: String,
+    pub scenario_name: String,
+    pub skill_version: Option<String>,
+    pub skill_commit: Option<String>,
+    pub working_copy: bool,
+    pub model: String,
+    pub total_cost_usd: Option<f64>,
+    pub duration_ms: Option<i64>,
+    pub num_turns: Option<u32>,
+    pub outcomes: Vec<AssertionOutcome>,
+}
+
+/// Pass rate of one scenario within a version.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScenarioPassRate {
+    pub scenario_name: String,
+    pub graded_runs: u32,
+    pub passed_runs: u32,
+    pub pass_rate: Option<f64>,
+}
+
+/// Aggregate of every run recorded against one released version with one model.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VersionPassRate {
+    pub skill_version: String,
+    pub model: String,
+    pub runs: u32,
+    /// Runs whose every assertion was decided.
+    pub graded_runs: u32,
+    pub passed_runs: u32,
+    pub pass_rate: Option<f64>,
+    pub avg_cost_usd: Option<f64>,
+    pub avg_duration_ms: Option<f64>,
+    pub last_run_at: String,
+    pub scenarios: Vec<ScenarioPassRate>,
+}
+
+/// A version that scored worse than the one released before it.
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct VersionRegression {
+    /// Both versions were graded with this model.
+    pub model: String,
+    pub previous_version: String,
+    pub current_version: String,
+    pub previous_pass_rate: f64,
+    pub current_pass_rate: f64,
+    /// Scenarios graded under both versions whose pass rate dropped.
+    pub scenarios: Vec<ScenarioRegression>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize, PartialEq)]
+#[serde(rename_all = "camelCase")]
+pub struct ScenarioRegression {
+    pub scenario_name: String,
+    pub previous_pass_rate: f64,
+    pub current_pass_rate: f64,
+}
+
+fn now() -> String {
+    chrono::Utc::now().to_rfc3339_opts(chrono::SecondsFormat::Secs, true)
+}
+
+/// A run fails on any failed assertion and is ungraded while any is undecided.
+pub fn run_verdict(outcomes: &[AssertionOutcome]) -> Option<bool> {
+    if outcomes.iter().any(|o| o.passed == Some(false)) {
+        Some(false)
+    } else if outcomes.iter().any(|o| o.passed.is_none()) {
+        None
+    } else {
+        Some(true)
+    }
+}
+
+pub fn record_scenario_run(
+    conn: &mut Connection,
+    input: NewScenarioRun,
+) -> Result<ScenarioRun, String> {
+    let run = ScenarioRun {
+        id: uuid::Uuid::new_v4().to_string(),
+        passed: run_verdict(&input.outcomes),
+        plugin_slug: input.plugin_slug,
+        skill_name: input.skill_name,
+        scenario_name: input.scenario_name,
+        skill_version: input.skill_version,
+        skill_commit: input.skill_commit,
+        working_copy: input.working_copy,
+        model: input.model,
+        total_cost_usd: input.total_cost_usd,
+        duration_ms: input.duration_ms,
+        num_turns: input.num_turns,
+        created_at: now(),
+        assertions: input.outcomes,
+    };
+
+    let tx = conn.transaction().map_err(|e| e.to_string())?;
+    tx.execute(
+        "INSERT INTO scenario_runs (
+            id, plugin_slug, skill_name, scenario_name, skill_version, skill_commit,
+            working_copy, model, passed, total_cost_usd, duration_ms, num_turns, created_at
+        ) VALUES (?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13)",
+        params![
+            run.id,
+            run.plugin_slug,
+            run.skill_name,
+            run.scenario_name,
+            run.skill_version,
+            run.skill_commit,
+            run.working_copy,
+            run.model,
+            run.passed,
+            run.total_cost_usd,
+            run.duration_ms,
+            run.num_turns,
+            run.created_at,
+        ],
+    )
+    .map_err(|e| e.to_string())?;
+    for (position, outcome) in run.assertions.iter().enumerate() {
+        tx.execute(
+            "INSERT INTO scenario_run_assertions (run_id, position, assertion, kind, passed, reason)
+             VALUES (?1, ?2, ?3, ?4, ?5, ?6)",
+            params![
+                run.id,
+                position as i64,
+                outcome.assertion.to_storage_text(),
+                outcome.assertion.kind(),
+                outcome.passed,
+                outcome.reason,
+            ],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+    tx.commit().map_err(|e| e.to_string())?;
+    Ok(run)
+}
+
+fn read_run_assertions(conn: &Connection, run_id: &str) -> Result<Vec<AssertionOutcome>, String> {
+    conn.prepare(
+        "SELECT assertion, passed, reason FROM scenario_run_assertions
+         WHERE run_id = ?1 ORDER BY position ASC",
+    )
+    .map_err(|e| e.to_string())?
+    .query_map(params![run_id], |row| {
+        Ok(AssertionOutcome {
+            assertion: Assertion::from_storage_text(&row.get::<_, String>(0)?),
+            passed: row.get(1)?,
+            reason: row.get(2)?,
+        })
+    })
+    .map_err(|e| e.to_string())?
+    .collect::<Result<Vec<_>, _>>()
+    .map_err(|e| e.to_string())
+}
+
+/// Runs of a skill, newest first, optionally narrowed to one scenario.
+pub fn list_scenario_runs(
+    conn: &Connection,
+    plugin_slug: &str,
+    skill_name: &str,
+    scenario_name: Option<&str>,
+    limit: u32,
+) -> Result<Vec<ScenarioRun>, String> {
+    let mut stmt = conn
+        .prepare(
+            "SELECT id, plugin_slug, skill_name, scenario_name, skill_version, skill_commit,
+                    working_copy, model, passed, total_cost_usd, duration_ms, num_turns, created_at
+             FROM scenario_runs
+             WHERE plugin_slug = ?1 AND skill_name = ?2 AND (?3 IS NULL OR scenario_name = ?3)
+             ORDER BY created_at DESC, rowid DESC
+             LIMIT ?4",
+        )
+        .map_err(|e| e.to_string())?;
+    let runs = stmt
+        .query_map(
+            params![plugin_slug, skill_name, scenario_name, limit],
+            |row| {
+                Ok(ScenarioRun {
+                    id: row.get(0)?,
+                    plugin_slug: row.get(1)?,
+                    skill_name: row.get(2)?,
+                    scenario_name: row.get(3)?,
+                    skill_version: row.get(4)?,
+                    skill_commit: row.get(5)?,
+                    working_copy: row.get(6)?,
+                    model: row.get(7)?,
+                    passed: row.get(8)?,
+                    total_cost_usd: row.get(9)?,
+                    duration_ms: row.get(10)?,
+                    num_turns: row.get(11)?,
+                    created_at: row.get(12)?,
+                    assertions: Vec::new(),
+                })
+            },
+        )
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+    drop(stmt);
+
+    runs.into_iter()
+        .map(|mut run| {
+            run.assertions = read_run_assertions(conn, &run.id)?;
+            Ok(run)
+        })
+        .collect()
+}
+
+fn rate(passed: u32, graded: u32) -> Option<f64> {
+    (graded > 0).then(|| f64::from(passed) / f64::from(graded))
+}
+
+/// Pass rate per model and released version, oldest version first within
+/// each model. Runs against unreleased edits are left out; `model` keeps only
+/// that model's runs.
+pub fn version_pass_rates(
+    conn: &Connection,
+    plugin_slug: &str,
+    skill_name: &str,
+    model: Option<&str>,
+) -> Result<Vec<VersionPassRate>, String> {
+    let mut versions = conn
+        .prepare(
+            "SELECT skill_version, model, COUNT(*), COUNT(passed), COALESCE(SUM(passed), 0),
+                    AVG(total_cost_usd), AVG(duration_ms), MAX(created_at)
+             FROM scenario_runs
+             WHERE plugin_slug = ?1 AND skill_name = ?2 AND skill_version IS NOT NULL
+               AND (?3 IS NULL OR model = ?3)
+             GROUP BY model, skill_version",
+        )
+        .map_err(|e| e.to_string())?
+        .query_map(params![plugin_slug, skill_name, model], |row| {
+            let graded_runs: u32 = row.get(3)?;
+            let passed_runs: u32 = row.get(4)?;
+            Ok(VersionPassRate {
+                skill_version: row.get(0)?,
+                model: row.get(1)?,
+                runs: row.get(2)?,
+                graded_runs,
+                passed_runs,
+                pass_rate: rate(passed_runs, graded_runs),
+                avg_cost_usd: row.get(5)?,
+                avg_duration_ms: row.get(6)?,
+                last_run_at: row.get(7)?,
+                scenarios: Vec::new(),
+            })
+        })
+        .map_err(|e| e.to_string())?
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())?;
+
+    let mut by_scenario: BTreeMap<(String, String), Vec<ScenarioPassRate>> = BTreeMap::new();
+    let mut stmt = conn
+        .prepare(
+            "SELECT model, skill_version, scenario_name, COUNT(passed), COALESCE(SUM(passed), 0)
+             FROM scenario_runs
+             WHERE plugin_slug = ?1 AND skill_name = ?2 AND skill_version IS NOT NULL
+               AND (?3 IS NULL OR model = ?3)
+             GROUP BY model, skill_version, scenario_name
+             ORDER BY scenario_name",
+        )
+        .map_err(|e| e.to_string())?;
+    let rows = stmt
+        .query_map(params![plugin_slug, skill_name, model], |row| {
+            let graded_runs: u32 = row.get(3)?;
+            let passed_runs: u32 = row.get(4)?;
+            Ok((
+                (row.get::<_, String>(0)?, row.get::<_, String>(1)?),
+                ScenarioPassRate {
+                    scenario_name: row.get(2)?,
+                    graded_runs,
+                    passed_runs,
+                    pass_rate: rate(passed_runs, graded_runs),
+                },
+            ))
+        })
+        .map_err(|e| e.to_string())?;
+    for row in rows {
+        let (key, scenario) = row.map_err(|e| e.to_string())?;
+        by_scenario.entry(key).or_default().push(scenario);
+    }
+
+    for version in &mut versions {
+        version.scenarios = by_scenario
+            .remove(&(version.model.clone(), version.skill_version.clone()))
+            .unwrap_or_default();
+    }
+    versions.sort_by(|a, b| {
+        a.model
+            .cmp(&b.model)
+            .then_with(|| parse_semver(&a.skill_version).cmp(&parse_semver(&b.skill_version)))
+    });
+    Ok(versions)
+}
+
+/// Compare the newest graded version with the graded version before it, both
+/// graded with the same model. Without `model`, the model of the latest
+/// graded release run is used. Returns `Some` only when the newest version's
+/// pass rate is lower.
+pub fn detect_version_regression(
+    conn: &Connection,
+    plugin_slug: &str,
+    skill_name: &str,
+    model: Option<&str>,
+) -> Result<Option<VersionRegression>, String> {
+    let model = match model {
+        Some(model) => model.to_string(),
+        None => match conn
+            .query_row(
+                "SELECT model FROM scenario_runs
+                 WHERE plugin_slug = ?1 AND skill_name = ?2
+                   AND skill_version IS NOT NULL AND passed IS NOT NULL
+                 ORDER BY created_at DESC, rowid DESC
+                 LIMIT 1",
+                params![plugin_slug, skill_name],
+                |row| row.get(0),
+            )
+            .optional()
+            .map_err(|e| e.to_string())?
+        {
+            Some(model) => model,
+            None => return Ok(None),
+        },
+    };
+    let graded: Vec<VersionPassRate> =
+        version_pass_rates(conn, plugin_slug, skill_name, Some(&model))?
+            .into_iter()
+            .filter(|v| v.pass_rate.is_some())
+            .collect();
+    let [.., previous, current] = graded.as_slice() else {
+        return Ok(None);
+    };
+    let (Some(previous_pass_rate), Some(current_pass_rate)) =
+        (previous.pass_rate, current.pass_rate)
+    else {
+        return Ok(None);
+    };
+    if current_pass_rate >= previous_pass_rate {
+        return Ok(None);
+    }
+
+    let scenarios = current
+        .scenarios
+        .iter()
+        .filter_map(|now| {
+            let before = previous
+                .scenarios
+                .iter()
+                .find(|s| s.scenario_name == now.scenario_name)?;
+            let (previous_pass_rate, current_pass_rate) = (before.pass_rate?, now.pass_rate?);
+            (current_pass_rate < previous_pass_rate).then(|| ScenarioRegression {
+                scenario_name: now.scenario_name.clone(),
+                previous_pass_rate,
+                current_pass_rate,
+            })
+        })
+        .collect();
+
+    Ok(Some(VersionRegression {
+        model,
+        previous_version: previous.skill_version.clone(),
+        current_version: current.skill_version.clone(),
+        previous_pass_rate,
+        current_pass_rate,
+        scenarios,
+    }))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn outcome(passed: Option<bool>) -> AssertionOutcome {
+        AssertionOutcome {
+            assertion: Assertion::from("Explains the forecast"),
+            passed,
+            reason: String::new(),
+        }
+    }
+
+    fn record(conn: &mut Connection, scenario: &str, version: Option<&str>, passed: Option<bool>) {
+        record_as(conn, "sonnet", scenario, version, passed);
+    }
+
+    fn record_as(
+        conn: &mut Connection,
+        model: &str,
+        scenario: &str,
+        version: Option<&str>,
+        passed: Option<bool>,
+    ) {
+        record_scenario_run(
+            conn,
+            NewScenarioRun {
+                plugin_slug: "skills".to_string(),
+                skill_name: "forecast".to_string(),
+                scenario_name: scenario.to_string(),
+                skill_version: version.map(String::from),
+                skill_commit: Some("abc123".to_string()),
+                working_copy: false,
+                model: model.to_string(),
+                total_cost_usd: Some(0.02),
+                duration_ms: Some(1500),
+                num_turns: Some(3),
+                outcomes: vec![outcome(passed)],
+            },
+        )
+        .unwrap();
+    }
+
+    #[test]
+    fn records_runs_with_assertion_outcomes() {
+        let mut conn = crate::db::create_test_db_for_tests();
+        let typed: Assertion =
+            serde_json::from_value(serde_json::json!({ "type": "contains", "value": "ARR" }))
+                .unwrap();
+        let saved = record_scenario_run(
+            &mut conn,
+            NewScenarioRun {
+                plugin_slug: "skills".to_string(),
+                skill_name: "forecast".to_string(),
+                scenario_name: "Smoke".to_string(),
+                skill_version: None,
+                skill_commit: Some("abc123".to_string()),
+                working_copy: true,
+                model: "sonnet".to_string(),
+                total_cost_usd: Some(0.05),
+                duration_ms: Some(2000),
+                num_turns: Some(4),
+                outcomes: vec![
+                    AssertionOutcome {
+                        assertion: typed,
+                        passed: Some(true),
+                        reason: "Output contains 'ARR'".to_string(),
+                    },
+                    outcome(None),
+                ],
+            },
+        )
+        .unwrap();
+        assert_eq!(saved.passed, None);
+        assert!(saved.working_copy);
+
+        let runs = list_scenario_runs(&conn, "skills", "forecast", Some("Smoke"), 10).unwrap();
+        assert_eq!(runs, vec![saved]);
+        assert!(
+            list_scenario_runs(&conn, "skills", "forecast", Some("Other"), 10)
+                .unwrap()
+                .is_empty()
+        );
+    }
+
+    #[test]
+    fn pass_rates_group_by_version_in_semver_order() {
+        let mut conn = crate::db::create_test_db_for_tests();
+        record(&mut conn, "Smoke", Some("1.10.0"), Some(true));
+        record(&mut conn, "Smoke", Some("1.9.0"), Some(false));
+        record(&mut conn, "Smoke", Some("1.9.0"), Some(true));
+        record(&mut conn, "Smoke", Some("1.9.0"), None);
+        record(&mut conn, "Smoke", None, Some(false));
+
+        let rates = version_pass_rates(&conn, "skills", "forecast", None).unwrap();
+        let versions: Vec<&str> = rates.iter().map(|v| v.skill_version.as_str()).collect();
+        assert_eq!(versions, vec!["1.9.0", "1.10.0"]);
+        assert_eq!(rates[0].runs, 3);
+        assert_eq!(rates[0].graded_runs, 2);
+        assert_eq!(rates[0].pass_rate, Some(0.5));
+        assert_eq!(rates[1].pass_rate, Some(1.0));
+        assert_eq!(rates[0].scenarios[0].pass_rate, Some(0.5));
+    }
+
+    #[test]
+    fn flags_a_new_version_that_scores_worse() {
+        let mut conn = crate::db::create_test_db_for_tests();
+        record(&mut conn, "Smoke", Some("1.0.0"), Some(true));
+        record(&mut conn, "Edge", Some("1.0.0"), Some(true));
+        record(&mut conn, "Smoke", Some("1.1.0"), Some(true));
+        record(&mut conn, "Edge", Some("1.1.0"), Some(true));
+        assert_eq!(
+            detect_version_regression(&conn, "skills", "forecast", None).unwrap(),
+            None
+        );
+
+        record(&mut conn, "Smoke", Some("1.2.0"), Some(true));
+        record(&mut conn, "Edge", Some("1.2.0"), Some(false));
+        let regression = detect_version_regression(&conn, "skills", "forecast", None)
+            .unwrap()
+            .unwrap();
+        assert_eq!(regression.model, "sonnet");
+        assert_eq!(regression.previous_version, "1.1.0");
+        assert_eq!(regression.current_version, "1.2.0");
+        assert_eq!(regression.current_pass_rate, 0.5);
+        assert_eq!(
+            regression.scenarios,
+            vec![ScenarioRegression {
+                scenario_name: "Edge".to_string(),
+                previous_pass_rate: 1.0,
+                current_pass_rate: 0.0,
+            }]
+        );
+    }
+
+    #[test]
+    fn pass_rates_and_regressions_keep_models_apart() {
+        let mut conn = crate::db::create_test_db_for_tests();
+        record_as(&mut conn, "opus", "Smoke", Some("1.0.0"), Some(true));
+        record_as(&mut conn, "haiku", "Smoke", Some("1.1.0"), Some(false));
+
+        let rates = version_pass_rates(&conn, "skills", "forecast", None).unwrap();
+        let keys: Vec<(&str, &str)> = rates
+            .iter()
+            .map(|v| (v.model.as_str(), v.skill_version.as_str()))
+            .collect();
+        assert_eq!(keys, vec![("haiku", "1.1.0"), ("opus", "1.0.0")]);
+        let opus = version_pass_rates(&conn, "skills", "forecast", Some("opus")).unwrap();
+        assert_eq!(opus.len(), 1);
+        assert_eq!(opus[0].scenarios[0].pass_rate, Some(1.0));
+
+        // A cheaper model on the new version is not a regression of the skill.
+        assert_eq!(
+            detect_version_regression(&conn, "skills", "forecast", None).unwrap(),
+            None
+        );
+        assert_eq!(
+            detect_version_regression(&conn, "skills", "forecast", Some("opus")).unwrap(),
+            None
+        );
+
+        record_as(&mut conn, "opus", "Smoke", Some("1.1.0"), Some(false));
+        let regression = detect_version_regression(&conn, "skills", "forecast", Some("opus"))
+            .unwrap()
+            .unwrap();
+        assert_eq!(regression.model, "opus");
+        assert_eq!(regression.previous_pass_rate, 1.0);
+        assert_eq!(regression.current_pass_rate, 0.0);
+    }
+}
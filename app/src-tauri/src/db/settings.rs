@@ -1,5 +1,6 @@
 use crate::types::{
-    AppSettings, MarketplaceRegistry, ModelSettings, ProviderOverride, WorkflowLlmConfig,
+    AppSettings, MarketplaceRegistry, ModelSettings, ProviderOverride, SourceHostToken,
+    WorkflowLlmConfig,
 };
 use rusqlite::{Connection, OptionalExtension};
 
@@ -152,6 +153,24 @@ fn read_marketplace_registries(conn: &Connection) -> Result<Vec<MarketplaceRegis
         .map_err(|e| e.to_string())
 }
 
+fn read_source_host_tokens(conn: &Connection) -> Result<Vec<SourceHostToken>, String> {
+    let mut stmt = conn
+        .prepare("SELECT host, token FROM source_host_tokens ORDER BY host")
+        .map_err(|e| e.to_string())?;
+
+    let rows = stmt
+        .query_map([], |row| {
+            Ok(SourceHostToken {
+                host: row.get(0)?,
+                token: row.get(1)?,
+            })
+        })
+        .map_err(|e| e.to_string())?;
+
+    rows.collect::<Result<Vec<_>, _>>()
+        .map_err(|e| e.to_string())
+}
+
 pub fn read_settings(conn: &Connection) -> Result<AppSettings, String> {
     let mut settings = conn
         .query_row(
@@ -171,7 +190,12 @@ pub fn read_settings(conn: &Connection) -> Result<AppSettings, String> {
                     industry,
                     function_role,
                     dashboard_view_mode,
-                    auto_update
+                    auto_update,
+                    document_token_budget,
+                    github_host,
+                    github_oauth_client_id,
+                    marketplace_offline,
+                    feedback_repo
              FROM app_settings
              WHERE id = 1",
             [],
@@ -193,11 +217,17 @@ pub fn read_settings(conn: &Connection) -> Result<AppSettings, String> {
                     github_user_avatar: row.get(10)?,
                     github_user_email: row.get(11)?,
                     marketplace_registries: vec![],
+                    source_host_tokens: vec![],
                     max_dimensions: row.get(12)?,
                     industry: row.get(13)?,
                     function_role: row.get(14)?,
                     dashboard_view_mode: row.get(15)?,
                     auto_update: row.get::<_, i64>(16)? != 0,
+                    document_token_budget: row.get(17)?,
+                    github_host: row.get(18)?,
+                    github_oauth_client_id: row.get(19)?,
+                    marketplace_offline: row.get::<_, i64>(20)? != 0,
+                    feedback_repo: row.get(21)?,
                 })
             },
         )
@@ -207,6 +237,7 @@ pub fn read_settings(conn: &Connection) -> Result<AppSettings, String> {
 
     settings.model_settings.provider_overrides = read_provider_overrides(conn)?;
     settings.marketplace_registries = read_marketplace_registries(conn)?;
+    settings.source_host_tokens = read_source_host_tokens(conn)?;
     Ok(normalize_model_settings(settings))
 }
 
@@ -230,9 +261,15 @@ fn upsert_app_settings(conn: &Connection, settings: &AppSettings) -> Result<(),
             industry,
             function_role,
             dashboard_view_mode,
-            auto_update
+            auto_update,
+            document_token_budget,
+            github_host,
+            github_oauth_client_id,
+            marketplace_offline,
+            feedback_repo
         ) VALUES (
-            1, ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17
+            1, ?1, ?2, ?3, ?4, ?5, ?6, ?7, ?8, ?9, ?10, ?11, ?12, ?13, ?14, ?15, ?16, ?17, ?18, ?19,
+            ?20, ?21, ?22
         )
         ON CONFLICT(id) DO UPDATE SET
             selected_provider_id = excluded.selected_provider_id,
@@ -251,7 +288,12 @@ fn upsert_app_settings(conn: &Connection, settings: &AppSettings) -> Result<(),
             industry = excluded.industry,
             function_role = excluded.function_role,
             dashboard_view_mode = excluded.dashboard_view_mode,
-            auto_update = excluded.auto_update",
+            auto_update = excluded.auto_update,
+            document_token_budget = excluded.document_token_budget,
+            github_host = excluded.github_host,
+            github_oauth_client_id = excluded.github_oauth_client_id,
+            marketplace_offline = excluded.marketplace_offline,
+            feedback_repo = excluded.feedback_repo",
         rusqlite::params![
             settings.model_settings.provider_id,
             settings.model_settings.model_id,
@@ -270,6 +312,11 @@ fn upsert_app_settings(conn: &Connection, settings: &AppSettings) -> Result<(),
             settings.function_role,
             settings.dashboard_view_mode,
             settings.auto_update as i64,
+            settings.document_token_budget,
+            settings.github_host,
+            settings.github_oauth_client_id,
+            settings.marketplace_offline as i64,
+            settings.feedback_repo,
         ],
     )
     .map(|_| ())
@@ -350,6 +397,26 @@ fn replace_marketplace_registries(conn: &Connection, settings: &AppSettings) ->
     Ok(())
 }
 
+fn replace_source_host_tokens(conn: &Connection, settings: &AppSettings) -> Result<(), String> {
+    conn.execute("DELETE FROM source_host_tokens", [])
+        .map_err(|e| e.to_string())?;
+
+    for entry in &settings.source_host_tokens {
+        let host = entry.host.trim().to_ascii_lowercase();
+        let token = entry.token.trim();
+        if host.is_empty() || token.is_empty() {
+            continue;
+        }
+        conn.execute(
+            "INSERT OR REPLACE INTO source_host_tokens (host, token) VALUES (?1, ?2)",
+            rusqlite::params![host, token],
+        )
+        .map_err(|e| e.to_string())?;
+    }
+
+    Ok(())
+}
+
 pub fn write_settings(conn: &Connection, settings: &AppSettings) -> Result<(), String> {
     let normalized = normalize_model_settings(settings.clone());
 
@@ -360,6 +427,7 @@ pub fn write_settings(conn: &Connection, settings: &AppSettings) -> Result<(), S
         upsert_app_settings(conn, &normalized)?;
         replace_provider_overrides(conn, &normalized)?;
         replace_marketplace_registries(conn, &normalized)?;
+        replace_source_host_tokens(conn, &normalized)?;
         Ok(())
     })();
 
@@ -434,6 +502,23 @@ mod tests {
         assert_eq!(read_back.skills_path.as_deref(), Some("/home/user/skills"));
     }
 
+    #[test]
+    fn test_document_token_budget_round_trip() {
+        let conn = create_test_db_for_tests();
+        assert_eq!(
+            read_settings(&conn).unwrap().document_token_budget,
+            crate::db::DEFAULT_DOCUMENT_TOKEN_BUDGET
+        );
+
+        let settings = AppSettings {
+            document_token_budget: 2_500,
+            ..make_settings(None)
+        };
+        write_settings(&conn, &settings).unwrap();
+
+        assert_eq!(read_settings(&conn).unwrap().document_token_budget, 2_500);
+    }
+
     #[test]
     fn test_write_settings_update_does_not_corrupt_data() {
         let conn = create_test_db_for_tests();
@@ -508,6 +593,34 @@ mod tests {
         assert_eq!(llm.usage_id.as_deref(), Some("workflow"));
     }
 
+    #[test]
+    fn source_host_tokens_round_trip_normalized() {
+        let conn = create_test_db_for_tests();
+        let settings = AppSettings {
+            source_host_tokens: vec![
+                SourceHostToken {
+                    host: " GitLab.Example.com ".to_string(),
+                    token: "glpat-1".to_string(),
+                },
+                SourceHostToken {
+                    host: "gitea.example.com".to_string(),
+                    token: "  ".to_string(),
+                },
+            ],
+            ..AppSettings::default()
+        };
+
+        write_settings(&conn, &settings).unwrap();
+
+        assert_eq!(
+            read_settings(&conn).unwrap().source_host_tokens,
+            vec![SourceHostToken {
+                host: "gitlab.example.com".to_string(),
+                token: "glpat-1".to_string(),
+            }]
+        );
+    }
+
     #[test]
     fn selected_workflow_llm_uses_backend_owned_usage_id() {
         let conn = create_test_db_for_tests();
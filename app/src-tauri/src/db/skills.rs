@@ -1,7 +1,7 @@
 use crate::skill_paths::{DEFAULT_PLUGIN_DISPLAY_NAME, DEFAULT_PLUGIN_SLUG};
 use crate::types::SkillMasterRow;
 use rusqlite::{Connection, OptionalExtension};
-use std::collections::HashMap;
+use std::collections::{BTreeMap, HashMap};
 
 /// Map a row from the standard skills+plugins join into a SkillMasterRow.
 /// Column order must match the SELECT used in list_all_skills, get_skill_master_*:
@@ -143,30 +143,109 @@ pub fn get_plugin_id_by_slug(conn: &Connection, slug: &str) -> Result<Option<i64
     .map_err(|e| format!("get_plugin_id_by_slug: {}", e))
 }
 
+/// List plugins with each declared dependency resolved against the installed
+/// plugins, and the reverse `required_by` edges.
 pub fn list_plugins(conn: &Connection) -> Result<Vec<crate::types::LibraryPlugin>, String> {
     let mut stmt = conn
         .prepare(
-            "SELECT id, slug, display_name, version, source_type, source_url, is_default, COALESCE(upgrade_locked, 0)
+            "SELECT id, slug, display_name, version, source_type, source_url, is_default, COALESCE(upgrade_locked, 0),
+                    dependencies
              FROM plugins
              ORDER BY is_default DESC, display_name ASC",
         )
         .map_err(|e| format!("list_plugins: {}", e))?;
     let rows = stmt
         .query_map([], |row| {
-            Ok(crate::types::LibraryPlugin {
-                id: row.get(0)?,
-                slug: row.get(1)?,
-                display_name: row.get(2)?,
-                version: row.get(3)?,
-                source_type: row.get(4)?,
-                source_url: row.get(5)?,
-                is_default: row.get::<_, i32>(6)? != 0,
-                upgrade_locked: row.get::<_, i32>(7)? != 0,
-            })
+            let dependencies: Option<String> = row.get(8)?;
+            Ok((
+                crate::types::LibraryPlugin {
+                    id: row.get(0)?,
+                    slug: row.get(1)?,
+                    display_name: row.get(2)?,
+                    version: row.get(3)?,
+                    source_type: row.get(4)?,
+                    source_url: row.get(5)?,
+                    is_default: row.get::<_, i32>(6)? != 0,
+                    upgrade_locked: row.get::<_, i32>(7)? != 0,
+                    dependencies: Vec::new(),
+                    required_by: Vec::new(),
+                },
+                parse_plugin_dependencies(dependencies.as_deref()),
+            ))
         })
         .map_err(|e| format!("list_plugins query: {}", e))?;
-    rows.collect::<Result<Vec<_>, _>>()
-        .map_err(|e| format!("list_plugins collect: {}", e))
+    let rows = rows
+        .collect::<Result<Vec<_>, _>>()
+        .map_err(|e| format!("list_plugins collect: {}", e))?;
+
+    let versions: HashMap<String, Option<String>> = rows
+        .iter()
+        .map(|(plugin, _)| (plugin.slug.clone(), plugin.version.clone()))
+        .collect();
+    let mut required_by: HashMap<String, Vec<String>> = HashMap::new();
+    let mut plugins = Vec::with_capacity(rows.len());
+    for (mut plugin, declared) in rows {
+        for (name, version_req) in declared {
+            let slug = slugify_plugin_name(&name);
+            let installed_version = versions.get(&slug);
+            if installed_version.is_some() {
+                required_by
+                    .entry(slug)
+                    .or_default()
+                    .push(plugin.slug.clone());
+            }
+            plugin.dependencies.push(crate::types::PluginDependency {
+                satisfied: installed_version.is_some_and(|v| {
+                    crate::marketplace_manifest::dependency_satisfied(&version_req, v.as_deref())
+                }),
+                installed: installed_version.is_some(),
+                installed_version: installed_version.cloned().flatten(),
+                name,
+                version_req,
+            });
+        }
+        plugins.push(plugin);
+    }
+    for plugin in &mut plugins {
+        plugin.required_by = required_by.remove(&plugin.slug).unwrap_or_default();
+    }
+    Ok(plugins)
+}
+
+fn parse_plugin_dependencies(json: Option<&str>) -> BTreeMap<String, String> {
+    json.and_then(|json| serde_json::from_str(json).ok())
+        .unwrap_or_default()
+}
+
+/// Record the `plugin.json` dependencies of a plugin by slug.
+pub fn set_plugin_dependencies(
+    conn: &Connection,
+    slug: &str,
+    dependencies: &BTreeMap<String, String>,
+) -> Result<(), String> {
+    let json = if dependencies.is_empty() {
+        None
+    } else {
+        Some(
+            serde_json::to_string(dependencies)
+                .map_err(|e| format!("set_plugin_dependencies: {}", e))?,
+        )
+    };
+    conn.execute(
+        "UPDATE plugins SET dependencies = ?2, updated_at = datetime('now') || 'Z' WHERE slug = ?1",
+        rusqlite::params![slug, json],
+    )
+    .map_err(|e| format!("set_plugin_dependencies: {}", e))?;
+    Ok(())
+}
+
+/// Slugs of installed plugins whose declared dependencies name `slug`.
+pub fn list_plugin_dependents(conn: &Connection, slug: &str) -> Result<Vec<String>, String> {
+    Ok(list_plugins(conn)?
+        .into_iter()
+        .find(|p| p.slug == slug)
+        .map(|p| p.required_by)
+        .unwrap_or_default())
 }
 
 /// Set the `upgrade_locked` flag on a plugin by slug.
@@ -183,6 +262,28 @@ pub fn set_plugin_upgrade_locked(
     Ok(())
 }
 
+/// Detach a plugin from the marketplace it was imported from: it becomes a
+/// `local` plugin and its skills stop tracking an upstream source. Used for
+/// plugins restored from a bundle archive, whose import source is a temp dir.
+pub fn mark_plugin_local(conn: &Connection, slug: &str) -> Result<(), String> {
+    conn.execute(
+        "UPDATE plugins SET source_type = 'local', source_url = NULL,
+                updated_at = datetime('now') || 'Z'
+         WHERE slug = ?1",
+        rusqlite::params![slug],
+    )
+    .map_err(|e| format!("mark_plugin_local: {}", e))?;
+    conn.execute(
+        "UPDATE imported_skills SET marketplace_source_url = NULL
+         WHERE skill_master_id IN (
+             SELECT s.id FROM skills s JOIN plugins p ON p.id = s.plugin_id WHERE p.slug = ?1
+         )",
+        rusqlite::params![slug],
+    )
+    .map_err(|e| format!("mark_plugin_local: {}", e))?;
+    Ok(())
+}
+
 /// Lock the plugin that owns `skill_name` against upgrades.
 /// Only applies to marketplace plugins (source_type = 'marketplace').
 /// This is a no-op for builder or non-marketplace plugins.
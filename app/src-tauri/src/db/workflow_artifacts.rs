@@ -749,6 +749,32 @@ pub fn upsert_decisions(
     Ok(())
 }
 
+/// Title and text of every clarification question that has no answer yet.
+///
+/// Used to steer reference-document retrieval toward what is still unknown.
+pub fn read_open_question_texts(
+    conn: &Connection,
+    skill_id: i64,
+) -> Result<Vec<String>, rusqlite::Error> {
+    let mut stmt = conn.prepare(
+        "SELECT title, text FROM clarification_questions
+         WHERE skill_id = ?1
+           AND COALESCE(TRIM(answer_text), '') = ''
+           AND COALESCE(TRIM(answer_choice), '') = ''
+         ORDER BY section_id, ordinal",
+    )?;
+    let rows = stmt
+        .query_map(rusqlite::params![skill_id], |row| {
+            Ok(format!(
+                "{} {}",
+                row.get::<_, String>(0)?,
+                row.get::<_, String>(1)?
+            ))
+        })?
+        .collect::<Result<Vec<_>, _>>()?;
+    Ok(rows)
+}
+
 /// Read the full decisions record for a skill.
 pub fn read_decisions(
     conn: &Connection,
@@ -1473,6 +1499,42 @@ mod tests {
         assert!(read_back.notes[0].note_id.is_some());
     }
 
+    #[test]
+    fn open_question_texts_skip_answered_questions() {
+        let mut conn = create_test_db_for_tests();
+        let skill_id = seed_skill(&conn, "skill-a");
+        let other_id = seed_skill(&conn, "skill-b");
+
+        let mut record = sample_record(&skill_identifier(skill_id));
+        // q1 answered by choice only, q2 blank, q3 whitespace only.
+        record.questions[0].answer_text = None;
+        record.questions[1].answer_text = None;
+        let mut whitespace = record.questions[1].clone();
+        whitespace.question_id = "q3".to_string();
+        whitespace.ordinal = 2;
+        whitespace.title = "Question 3".to_string();
+        whitespace.text = "Who owns it?".to_string();
+        whitespace.answer_text = Some("   ".to_string());
+        record.questions.push(whitespace);
+        // The other skill answered everything.
+        let other = sample_record(&skill_identifier(other_id));
+        let tx = conn.transaction().unwrap();
+        upsert_clarifications(&tx, &record).unwrap();
+        upsert_clarifications(&tx, &other).unwrap();
+        tx.commit().unwrap();
+
+        assert_eq!(
+            read_open_question_texts(&conn, skill_id).unwrap(),
+            vec![
+                "Question 2 Anything else?".to_string(),
+                "Question 3 Who owns it?".to_string(),
+            ]
+        );
+        assert!(read_open_question_texts(&conn, other_id)
+            .unwrap()
+            .is_empty());
+    }
+
     #[test]
     fn roundtrip_clarifications_accepts_builder_library_key_identifier() {
         let mut conn = create_test_db_for_tests();
@@ -2,7 +2,11 @@ use std::path::Path;
 
 use git2::{DiffOptions, Repository, Signature, StatusOptions};
 
-use crate::types::SkillCommit;
+use crate::types::{
+    SkillCommit, SkillDiffHunk, SkillDiffLine, SkillDraft, SkillDraftMerge, SkillFileDiff,
+    SkillMergeConflict, SkillUpdateApplied, SkillUpdatePreview, SkillVersionDiff,
+    SkillVersionRelease, VersionBump, VersionBumpSuggestion,
+};
 
 /// Standard .gitignore for the skills output folder.
 const GITIGNORE_CONTENT: &str = "\
@@ -654,7 +658,7 @@ pub fn restore_version(
 
 /// Parse a semver string "X.Y.Z" into (major, minor, patch).
 /// Returns (0, 0, 0) for unparseable strings.
-fn parse_semver(version: &str) -> (u32, u32, u32) {
+pub(crate) fn parse_semver(version: &str) -> (u32, u32, u32) {
     let parts: Vec<&str> = version.split('.').collect();
     if parts.len() != 3 {
         return (0, 0, 0);
@@ -706,13 +710,291 @@ pub fn latest_skill_semver(
 /// Bump the patch component of a semver string ("X.Y.Z" → "X.Y.Z+1").
 /// Returns "0.0.1" for any input that is not valid three-part semver.
 pub fn bump_patch(version: &str) -> String {
+    bump_version(version, VersionBump::Patch)
+}
+
+/// Increment one semver component and reset the lower ones.
+/// Input that is not valid three-part semver is treated as "0.0.0".
+pub fn bump_version(version: &str, bump: VersionBump) -> String {
     let parts: Vec<&str> = version.split('.').collect();
-    if parts.len() == 3 {
-        if let Ok(patch) = parts[2].parse::<u32>() {
-            return format!("{}.{}.{}", parts[0], parts[1], patch + 1);
+    let (major, minor, patch) =
+        if parts.len() == 3 && parts.iter().all(|part| part.parse::<u32>().is_ok()) {
+            parse_semver(version)
+        } else {
+            (0, 0, 0)
+        };
+    match bump {
+        VersionBump::Major => format!("{}.0.0", major + 1),
+        VersionBump::Minor => format!("{}.{}.0", major, minor + 1),
+        VersionBump::Patch => format!("{}.{}.{}", major, minor, patch + 1),
+    }
+}
+
+/// Changed lines (added + removed, across all files) from which a release is
+/// suggested as minor rather than patch.
+const MINOR_BUMP_CHANGED_LINES: usize = 20;
+
+/// Suggest a bump from the diff between the latest version tag and HEAD:
+/// major when files are removed or SKILL.md loses at least half its lines,
+/// minor when files are added or many lines change, patch otherwise.
+/// A skill with no tags gets its first major version.
+pub fn suggest_version_bump(
+    repo_path: &Path,
+    plugin_slug: &str,
+    skill_name: &str,
+) -> Result<VersionBumpSuggestion, String> {
+    let current_version = latest_skill_semver(repo_path, plugin_slug, skill_name)?;
+    let suggestion = |bump: VersionBump, reasons: Vec<String>| VersionBumpSuggestion {
+        next_version: bump_version(&current_version, bump),
+        current_version: current_version.clone(),
+        bump,
+        reasons,
+    };
+    if current_version == "0.0.0" {
+        return Ok(suggestion(
+            VersionBump::Major,
+            vec!["First tagged version".to_string()],
+        ));
+    }
+
+    let diff = diff_skill_versions(repo_path, plugin_slug, skill_name, &current_version, "HEAD")?;
+    let files: Vec<&SkillFileDiff> = diff
+        .files
+        .iter()
+        .filter(|file| file.path != CHANGELOG_FILE)
+        .collect();
+    if files.is_empty() {
+        return Ok(suggestion(
+            VersionBump::Patch,
+            vec![format!("No changes since v{}", current_version)],
+        ));
+    }
+
+    let removed: Vec<&str> = files
+        .iter()
+        .filter(|file| file.status == "removed")
+        .map(|file| file.path.as_str())
+        .collect();
+    let added: Vec<&str> = files
+        .iter()
+        .filter(|file| file.status == "added")
+        .map(|file| file.path.as_str())
+        .collect();
+    let changed_lines: usize = files
+        .iter()
+        .map(|file| file.additions + file.deletions)
+        .sum();
+
+    let mut major = Vec::new();
+    if !removed.is_empty() {
+        major.push(format!("Removed {}", removed.join(", ")));
+    }
+    if let Some(skill_md) = files
+        .iter()
+        .find(|file| file.path == "SKILL.md" && file.status == "modified")
+    {
+        let old_lines = get_skill_files_at_sha(repo_path, skill_name, plugin_slug, &diff.from_sha)?
+            .into_iter()
+            .find(|(path, _)| path == "SKILL.md")
+            .map(|(_, content)| content.lines().count())
+            .unwrap_or(0);
+        if old_lines > 0 && skill_md.deletions * 2 >= old_lines {
+            major.push(format!(
+                "SKILL.md rewritten ({} of {} lines removed)",
+                skill_md.deletions, old_lines
+            ));
+        }
+    }
+    if !major.is_empty() {
+        return Ok(suggestion(VersionBump::Major, major));
+    }
+
+    let mut minor = Vec::new();
+    if !added.is_empty() {
+        minor.push(format!("Added {}", added.join(", ")));
+    }
+    if changed_lines >= MINOR_BUMP_CHANGED_LINES {
+        minor.push(format!("{} lines changed", changed_lines));
+    }
+    if !minor.is_empty() {
+        return Ok(suggestion(VersionBump::Minor, minor));
+    }
+
+    Ok(suggestion(
+        VersionBump::Patch,
+        vec![format!(
+            "{} lines changed in {} file(s)",
+            changed_lines,
+            files.len()
+        )],
+    ))
+}
+
+/// Changelog maintained at the root of each skill directory.
+pub const CHANGELOG_FILE: &str = "CHANGELOG.md";
+
+/// Tag the next version of a skill. `bump = None` uses `suggest_version_bump`.
+///
+/// CHANGELOG.md is regenerated to include the new version, then it and any
+/// pending changes are committed and the commit is tagged.
+pub fn release_skill_version(
+    repo_path: &Path,
+    plugin_slug: &str,
+    skill_name: &str,
+    bump: Option<VersionBump>,
+) -> Result<SkillVersionRelease, String> {
+    ensure_repo(repo_path)?;
+    let (current_version, bump) = match bump {
+        Some(bump) => (
+            latest_skill_semver(repo_path, plugin_slug, skill_name)?,
+            bump,
+        ),
+        None => {
+            let suggestion = suggest_version_bump(repo_path, plugin_slug, skill_name)?;
+            (suggestion.current_version, suggestion.bump)
+        }
+    };
+    let version = bump_version(&current_version, bump);
+    log::debug!(
+        "[git] release_skill_version: skill='{}' plugin='{}' {} -> {} ({:?})",
+        skill_name,
+        plugin_slug,
+        current_version,
+        version,
+        bump
+    );
+
+    let changelog = {
+        let repo = Repository::open(repo_path)
+            .map_err(|e| format!("Failed to open repo at {}: {}", repo_path.display(), e))?;
+        render_skill_changelog(&repo, plugin_slug, skill_name, Some(&version))?
+    };
+    std::fs::write(repo_path.join(CHANGELOG_FILE), changelog)
+        .map_err(|e| format!("Failed to write {}: {}", CHANGELOG_FILE, e))?;
+    commit_all(
+        repo_path,
+        &format!("{}{}", release_commit_prefix(skill_name), version),
+    )?;
+    let tag = create_skill_version_tag(repo_path, plugin_slug, skill_name, &version)?;
+
+    Ok(SkillVersionRelease {
+        version,
+        previous_version: Some(current_version).filter(|v| v != "0.0.0"),
+        bump,
+        tag,
+    })
+}
+
+fn release_commit_prefix(skill_name: &str) -> String {
+    format!("{}: release v", skill_name)
+}
+
+/// Render CHANGELOG.md from the commit messages between consecutive version
+/// tags, newest first. `pending_version` adds a section for commits after the
+/// latest tag, dated today.
+fn render_skill_changelog(
+    repo: &Repository,
+    plugin_slug: &str,
+    skill_name: &str,
+    pending_version: Option<&str>,
+) -> Result<String, String> {
+    let prefixes = candidate_tag_prefixes(plugin_slug, skill_name);
+    let mut versions: Vec<((u32, u32, u32), String, git2::Oid)> = Vec::new();
+    for tag_name in list_skill_tag_names(repo, plugin_slug, skill_name)? {
+        let Some(version) = extract_version_from_tag_name(&tag_name, &prefixes) else {
+            continue;
+        };
+        let parsed = parse_semver(&version);
+        if parsed == (0, 0, 0) || versions.iter().any(|(v, _, _)| *v == parsed) {
+            continue;
+        }
+        let commit = repo
+            .revparse_single(&format!("refs/tags/{}", tag_name))
+            .and_then(|obj| obj.peel_to_commit())
+            .map_err(|e| format!("Failed to resolve tag '{}': {}", tag_name, e))?;
+        versions.push((parsed, version, commit.id()));
+    }
+    versions.sort_by_key(|(parsed, _, _)| *parsed);
+
+    let mut sections = Vec::new();
+    let mut previous: Option<git2::Oid> = None;
+    for (_, version, oid) in &versions {
+        let date = repo
+            .find_commit(*oid)
+            .ok()
+            .and_then(|c| chrono::DateTime::from_timestamp(c.time().seconds(), 0))
+            .map(|dt| dt.format("%Y-%m-%d").to_string())
+            .unwrap_or_default();
+        let messages = changelog_messages(repo, skill_name, *oid, previous)?;
+        sections.push((version.clone(), date, messages));
+        previous = Some(*oid);
+    }
+    if let Some(version) = pending_version {
+        let head = repo
+            .head()
+            .ok()
+            .and_then(|h| h.target())
+            .ok_or_else(|| "Failed to resolve HEAD for changelog".to_string())?;
+        let messages = changelog_messages(repo, skill_name, head, previous)?;
+        let date = chrono::Utc::now().format("%Y-%m-%d").to_string();
+        sections.push((version.to_string(), date, messages));
+    }
+
+    let mut out =
+        String::from("# Changelog\n\nGenerated from commit messages between version tags.\n");
+    for (version, date, messages) in sections.iter().rev() {
+        out.push_str(&format!("\n## {} - {}\n\n", version, date));
+        if messages.is_empty() {
+            out.push_str("- No recorded changes\n");
+        }
+        for message in messages {
+            out.push_str(&format!("- {}\n", message));
+        }
+    }
+    Ok(out)
+}
+
+/// Commit summaries reachable from `to` but not `from`, oldest first, with the
+/// `"<skill>: "` prefix stripped and release/initial commits omitted.
+fn changelog_messages(
+    repo: &Repository,
+    skill_name: &str,
+    to: git2::Oid,
+    from: Option<git2::Oid>,
+) -> Result<Vec<String>, String> {
+    let mut walk = repo
+        .revwalk()
+        .map_err(|e| format!("Failed to create revwalk: {}", e))?;
+    walk.push(to)
+        .map_err(|e| format!("Failed to walk history: {}", e))?;
+    if let Some(from) = from {
+        walk.hide(from)
+            .map_err(|e| format!("Failed to walk history: {}", e))?;
+    }
+    walk.set_sorting(git2::Sort::TOPOLOGICAL | git2::Sort::REVERSE)
+        .ok();
+
+    let release_prefix = release_commit_prefix(skill_name);
+    let skill_prefix = format!("{}: ", skill_name);
+    let mut messages = Vec::new();
+    for oid in walk {
+        let oid = oid.map_err(|e| format!("Revwalk error: {}", e))?;
+        let commit = repo
+            .find_commit(oid)
+            .map_err(|e| format!("Failed to find commit {}: {}", oid, e))?;
+        let summary = commit.summary().unwrap_or("").trim();
+        if summary.is_empty() || summary == "Initial commit" || summary.starts_with(&release_prefix)
+        {
+            continue;
         }
+        messages.push(
+            summary
+                .strip_prefix(&skill_prefix)
+                .unwrap_or(summary)
+                .to_string(),
+        );
     }
-    "0.0.1".to_string()
+    Ok(messages)
 }
 
 /// Return the second-highest semver tag for a skill in the given plugin namespace (the version before the latest).
@@ -743,6 +1025,91 @@ pub fn prior_skill_tag(path: &Path, plugin_slug: &str, skill_name: &str) -> Opti
     }
 }
 
+/// The commit a skill is at, and the released version it matches.
+#[derive(Debug, Clone, Default, PartialEq, Eq)]
+pub struct SkillRevision {
+    /// Semver of the newest tag, when the skill's files at HEAD equal that tag's.
+    /// `None` for unreleased edits or a skill with no tags.
+    pub version: Option<String>,
+    /// HEAD commit SHA, `None` when the repo has no commits.
+    pub commit: Option<String>,
+    /// The skill's files have uncommitted changes, so neither `commit` nor
+    /// `version` describes what was measured.
+    pub working_copy: bool,
+}
+
+/// Identify what a benchmark measured: the HEAD commit, plus the newest
+/// version tag when HEAD's skill files are unchanged since it. Uncommitted
+/// edits make it a working-copy revision with no version. Benchmark
+/// workspaces under `evals/` are ignored in both checks.
+pub fn skill_revision_at_head(
+    repo_path: &Path,
+    plugin_slug: &str,
+    skill_name: &str,
+) -> Result<SkillRevision, String> {
+    let repo = Repository::open(repo_path).map_err(|e| format!("Failed to open repo: {}", e))?;
+    let Ok(head) = repo.head().and_then(|head| head.peel_to_commit()) else {
+        return Ok(SkillRevision {
+            working_copy: true,
+            ..Default::default()
+        });
+    };
+    let commit = Some(head.id().to_string());
+
+    let prefix = candidate_skill_prefixes(plugin_slug, skill_name)
+        .into_iter()
+        .find(|p| repo_path.join(format!("{}SKILL.md", p)).is_file())
+        .unwrap_or_default();
+    let skill_files = format!("{}*", prefix);
+    let evals = format!("!{}evals", prefix);
+    if has_uncommitted_changes(&repo, &[evals.as_str(), skill_files.as_str()])? {
+        return Ok(SkillRevision {
+            version: None,
+            commit,
+            working_copy: true,
+        });
+    }
+
+    let prefixes = candidate_tag_prefixes(plugin_slug, skill_name);
+    let newest = list_skill_tag_names(&repo, plugin_slug, skill_name)?
+        .into_iter()
+        .filter_map(|tag_name| {
+            let version = extract_version_from_tag_name(&tag_name, &prefixes)?;
+            let parsed = parse_semver(&version);
+            (parsed != (0, 0, 0)).then_some((parsed, version, tag_name))
+        })
+        .max_by_key(|(parsed, _, _)| *parsed);
+    let Some((_, version, tag_name)) = newest else {
+        return Ok(SkillRevision {
+            version: None,
+            commit,
+            working_copy: false,
+        });
+    };
+
+    let tagged = repo
+        .revparse_single(&format!("refs/tags/{}", tag_name))
+        .and_then(|object| object.peel_to_commit())
+        .map_err(|e| format!("Tag '{}' does not point to a commit: {}", tag_name, e))?;
+    let released = tagged.id() == head.id() || {
+        let skill_files = |commit: &git2::Commit| {
+            skill_blobs_at_commit(&repo, commit, plugin_slug, skill_name).map(|blobs| {
+                blobs
+                    .into_iter()
+                    .filter(|(path, _)| !path.starts_with("evals/"))
+                    .collect::<std::collections::BTreeMap<_, _>>()
+            })
+        };
+        skill_files(&tagged)? == skill_files(&head)?
+    };
+
+    Ok(SkillRevision {
+        version: released.then_some(version),
+        commit,
+        working_copy: false,
+    })
+}
+
 /// Extract a skill's files at a given tag into `dest_dir`.
 /// Uses git2 tree walk to read blobs without touching the working directory.
 pub fn extract_skill_at_tag(
@@ -769,14 +1136,66 @@ pub fn extract_skill_at_tag(
     let commit = reference
         .peel(git2::ObjectType::Commit)
         .map_err(|e| format!("Failed to peel tag '{}' to commit: {}", tag_name, e))?;
-    let tree = commit
+    let commit = commit
         .as_commit()
-        .ok_or_else(|| format!("Tag '{}' does not point to a commit", tag_name))?
+        .ok_or_else(|| format!("Tag '{}' does not point to a commit", tag_name))?;
+
+    write_skill_at_commit(&repo, commit, plugin_slug, skill_name, tag_name, dest_dir)
+}
+
+/// What a ref resolved to when its snapshot was extracted.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct SkillSnapshot {
+    pub commit: String,
+    /// Semver when the ref named a version tag.
+    pub version: Option<String>,
+}
+
+/// Extract a skill's files at a version, version tag or commit SHA into
+/// `dest_dir`. Tags go through [`extract_skill_at_tag`].
+pub fn extract_skill_at_ref(
+    repo_path: &Path,
+    plugin_slug: &str,
+    skill_name: &str,
+    reference: &str,
+    dest_dir: &Path,
+) -> Result<SkillSnapshot, String> {
+    let repo = Repository::open(repo_path).map_err(|e| format!("Failed to open repo: {}", e))?;
+    let commit = resolve_skill_commit(&repo, plugin_slug, skill_name, reference)?;
+    let tag_name = resolve_tag_name(&repo, plugin_slug, skill_name, reference.trim());
+    let version = tag_name.as_deref().and_then(|tag_name| {
+        extract_version_from_tag_name(tag_name, &candidate_tag_prefixes(plugin_slug, skill_name))
+    });
+    match &tag_name {
+        Some(tag_name) => {
+            extract_skill_at_tag(repo_path, plugin_slug, skill_name, tag_name, dest_dir)?
+        }
+        None => {
+            write_skill_at_commit(&repo, &commit, plugin_slug, skill_name, reference, dest_dir)?
+        }
+    }
+    Ok(SkillSnapshot {
+        commit: commit.id().to_string(),
+        version,
+    })
+}
+
+/// Write a skill's files from `commit` into `dest_dir`, replacing its contents.
+/// `label` names the tag or branch in errors and logs.
+fn write_skill_at_commit(
+    repo: &Repository,
+    commit: &git2::Commit,
+    plugin_slug: &str,
+    skill_name: &str,
+    label: &str,
+    dest_dir: &Path,
+) -> Result<(), String> {
+    let tree = commit
         .tree()
-        .map_err(|e| format!("Failed to get tree for tag '{}': {}", tag_name, e))?;
+        .map_err(|e| format!("Failed to get tree for '{}': {}", label, e))?;
 
     let prefixes = candidate_skill_prefixes(plugin_slug, skill_name);
-    let all_entries = collect_tree_blobs(&repo, &tree)?;
+    let all_entries = collect_tree_blobs(repo, &tree)?;
 
     let prefix = prefixes
         .iter()
@@ -787,8 +1206,8 @@ pub fn extract_skill_at_tag(
         .cloned()
         .ok_or_else(|| {
             format!(
-                "No SKILL.md found in tag '{}' for skill '{}' (plugin '{}')",
-                tag_name, skill_name, plugin_slug
+                "No SKILL.md found in '{}' for skill '{}' (plugin '{}')",
+                label, skill_name, plugin_slug
             )
         })?;
 
@@ -813,9 +1232,9 @@ pub fn extract_skill_at_tag(
     }
 
     log::info!(
-        "[git] Extracted '{}' at tag '{}' to {}",
+        "[git] Extracted '{}' at '{}' to {}",
         skill_name,
-        tag_name,
+        label,
         dest_dir.display()
     );
     Ok(())
@@ -915,16 +1334,77 @@ pub fn get_skill_files_at_sha(
     Ok(files)
 }
 
+/// Compare a skill between two refs.
+///
+/// Each ref may be a version (`1.2.0`, `v1.2.0`), a full version tag name as
+/// created by `create_skill_version_tag`, or anything `git rev-parse`
+/// understands (full/short SHA, `HEAD`, branch). All skill files except
+/// dotfiles are compared; unchanged files are omitted. A side where the skill
+/// did not exist yet contributes no files, so everything shows as added or
+/// removed.
+pub fn diff_skill_versions(
+    repo_path: &Path,
+    plugin_slug: &str,
+    skill_name: &str,
+    from_ref: &str,
+    to_ref: &str,
+) -> Result<SkillVersionDiff, String> {
+    log::debug!(
+        "[git] diff_skill_versions: skill='{}' plugin='{}' {}..{}",
+        skill_name,
+        plugin_slug,
+        from_ref,
+        to_ref
+    );
+    let repo = Repository::open(repo_path).map_err(|e| format!("Failed to open repo: {}", e))?;
+
+    let from_commit = resolve_skill_commit(&repo, plugin_slug, skill_name, from_ref)?;
+    let to_commit = resolve_skill_commit(&repo, plugin_slug, skill_name, to_ref)?;
+    let from_files = skill_blobs_at_commit(&repo, &from_commit, plugin_slug, skill_name)?;
+    let to_files = skill_blobs_at_commit(&repo, &to_commit, plugin_slug, skill_name)?;
+
+    let mut paths: Vec<&String> = from_files.keys().chain(to_files.keys()).collect();
+    paths.sort_by(|a, b| match (a.as_str(), b.as_str()) {
+        ("SKILL.md", "SKILL.md") => std::cmp::Ordering::Equal,
+        ("SKILL.md", _) => std::cmp::Ordering::Less,
+        (_, "SKILL.md") => std::cmp::Ordering::Greater,
+        _ => a.cmp(b),
+    });
+    paths.dedup();
+
+    let mut files = Vec::new();
+    for path in paths {
+        let old = from_files.get(path).map(Vec::as_slice);
+        let new = to_files.get(path).map(Vec::as_slice);
+        if let Some(file_diff) = diff_skill_file(path, old, new)? {
+            files.push(file_diff);
+        }
+    }
+
+    log::debug!(
+        "[git] diff_skill_versions: {} changed files for '{}'",
+        files.len(),
+        skill_name
+    );
+    Ok(SkillVersionDiff {
+        from_sha: from_commit.id().to_string(),
+        to_sha: to_commit.id().to_string(),
+        files,
+    })
+}
+
 /// Result of auto-detecting the benchmark baseline from git tags.
 pub struct BenchmarkBaseline {
-    /// `"prior_version"` or `"no_skill"`
+    /// `"draft_base"`, `"prior_version"` or `"no_skill"`
     pub mode: String,
-    /// Path to extracted snapshot directory (set when mode == `"prior_version"`)
+    /// Path to extracted snapshot directory (set unless mode == `"no_skill"`)
     pub snapshot_dir: Option<String>,
 }
 
-/// Determine the benchmark baseline by checking git for a prior version tag.
+/// Determine the benchmark baseline by checking git for a draft or a prior version tag.
 ///
+/// While a draft is checked out → `draft_base`, a snapshot of the base branch tip,
+/// so the draft is benchmarked against what merging it would replace.
 /// If a prior tag exists and extraction succeeds → `prior_version` with snapshot.
 /// Otherwise → `no_skill` (benchmark skill vs no-skill baseline).
 pub fn resolve_benchmark_baseline(
@@ -933,6 +1413,11 @@ pub fn resolve_benchmark_baseline(
     skill_name: &str,
     skill_dir: &Path,
 ) -> BenchmarkBaseline {
+    if let Some(baseline) =
+        draft_benchmark_baseline(skills_repo_path, plugin_slug, skill_name, skill_dir)
+    {
+        return baseline;
+    }
     match prior_skill_tag(skills_repo_path, plugin_slug, skill_name) {
         Some(tag) => {
             let dest = skill_dir.join("skill-snapshot");
@@ -976,93 +1461,1168 @@ pub fn resolve_benchmark_baseline(
     }
 }
 
-// --- Helpers ---
-
-/// Candidate path prefixes used to locate a skill's files inside a git tree.
-///
-/// Tried in priority order:
-/// 1. `{plugin}/skills/{name}/`  — canonical plugin layout
-/// 2. `{plugin}/{name}/`         — legacy plugin layout
-/// 3. `skills/{name}/`           — legacy default-plugin alias
-/// 4. `{name}/`                  — legacy flat layout
-/// 5. `""`                       — per-skill repo (files at tree root)
-fn candidate_skill_prefixes(plugin_slug: &str, skill_name: &str) -> Vec<String> {
-    let mut prefixes = vec![
-        format!("{}/skills/{}/", plugin_slug, skill_name),
-        format!("{}/{}/", plugin_slug, skill_name),
-    ];
-    if plugin_slug == crate::skill_paths::DEFAULT_PLUGIN_SLUG {
-        prefixes.push(format!("skills/{}/", skill_name));
+fn draft_benchmark_baseline(
+    skills_repo_path: &Path,
+    plugin_slug: &str,
+    skill_name: &str,
+    skill_dir: &Path,
+) -> Option<BenchmarkBaseline> {
+    let repo = Repository::open(skills_repo_path).ok()?;
+    let branch = current_draft_branch(&repo)?;
+    let dest = skill_dir.join("skill-snapshot");
+    let extracted = draft_base_branch(&repo, &branch).and_then(|base_branch| {
+        let base = branch_tip(&repo, &base_branch)?;
+        write_skill_at_commit(&repo, &base, plugin_slug, skill_name, &base_branch, &dest)
+    });
+    match extracted {
+        Ok(()) => {
+            let snapshot_str = dest.to_string_lossy().replace('\\', "/");
+            log::info!(
+                "[git] resolve_benchmark_baseline: skill='{}' draft='{}' snapshot={}",
+                skill_name,
+                branch,
+                snapshot_str
+            );
+            Some(BenchmarkBaseline {
+                mode: "draft_base".to_string(),
+                snapshot_dir: Some(snapshot_str),
+            })
+        }
+        Err(e) => {
+            log::warn!(
+                "[git] resolve_benchmark_baseline: skill='{}' draft base extraction failed: {}",
+                skill_name,
+                e
+            );
+            None
+        }
     }
-    prefixes.push(format!("{}/", skill_name));
-    prefixes.push(String::new());
-    dedupe_strings(prefixes)
 }
 
-fn candidate_tag_prefixes(plugin_slug: &str, skill_name: &str) -> Vec<String> {
-    let mut prefixes = vec![crate::skill_paths::skill_tag_prefix(
-        plugin_slug,
-        skill_name,
-    )];
-    prefixes.push(format!("{}/{}/v", plugin_slug, skill_name));
-    if plugin_slug == crate::skill_paths::DEFAULT_PLUGIN_SLUG {
-        prefixes.push(format!("skills/{}/v", skill_name));
-    }
-    prefixes.push("v".to_string());
-    dedupe_strings(prefixes)
-}
+// --- Draft branches ---
 
-fn candidate_tag_names(plugin_slug: &str, skill_name: &str, version: &str) -> Vec<String> {
-    let normalized = version.trim_start_matches('v');
-    candidate_tag_prefixes(plugin_slug, skill_name)
-        .into_iter()
-        .map(|prefix| format!("{prefix}{normalized}"))
-        .collect()
-}
+/// Branch namespace for refine drafts. While a draft is checked out, every
+/// `commit_all`/`commit_path` lands on it instead of the skill's main history.
+pub const DRAFT_BRANCH_PREFIX: &str = "draft/";
 
-fn list_skill_tag_names(
-    repo: &Repository,
+/// Check out a new `draft/<draft_name>` branch from the current branch.
+/// Pending changes are committed to the current branch first so the draft
+/// starts from exactly what is on disk.
+pub fn start_skill_draft(
+    repo_path: &Path,
     plugin_slug: &str,
     skill_name: &str,
-) -> Result<Vec<String>, String> {
-    let mut names = Vec::new();
-    let mut seen = std::collections::HashSet::new();
+    draft_name: &str,
+) -> Result<SkillDraft, String> {
+    let draft_name = draft_name.trim();
+    let branch = format!("{}{}", DRAFT_BRANCH_PREFIX, draft_name);
+    if draft_name.is_empty() || !git2::Branch::name_is_valid(&branch).unwrap_or(false) {
+        return Err(format!("Invalid draft name '{}'", draft_name));
+    }
+    log::debug!(
+        "[git] start_skill_draft: skill='{}' plugin='{}' branch='{}'",
+        skill_name,
+        plugin_slug,
+        branch
+    );
 
-    for glob in candidate_tag_prefixes(plugin_slug, skill_name)
-        .into_iter()
-        .map(|prefix| format!("{prefix}*"))
-    {
-        let tags = repo
-            .tag_names(Some(&glob))
-            .map_err(|e| format!("Failed to list tags: {}", e))?;
-        for tag_name in tags.iter().flatten() {
-            let tag_name = tag_name.to_string();
-            if seen.insert(tag_name.clone()) {
-                names.push(tag_name);
-            }
-        }
+    let repo = ensure_repo(repo_path)?;
+    let base_branch = current_branch_name(&repo)
+        .ok_or_else(|| "Cannot start a draft: HEAD is not on a branch".to_string())?;
+    if let Some(in_progress) = base_branch.strip_prefix(DRAFT_BRANCH_PREFIX) {
+        return Err(format!(
+            "Draft '{}' is already in progress; merge or discard it first",
+            in_progress
+        ));
+    }
+    if repo.find_branch(&branch, git2::BranchType::Local).is_ok() {
+        return Err(format!("Draft '{}' already exists", draft_name));
     }
 
-    Ok(names)
-}
+    commit_all(
+        repo_path,
+        &format!("{}: checkpoint before draft {}", skill_name, draft_name),
+    )?;
+    let head = repo
+        .head()
+        .and_then(|h| h.peel_to_commit())
+        .map_err(|e| format!("Failed to resolve HEAD: {}", e))?;
+    repo.branch(&branch, &head, false)
+        .map_err(|e| format!("Failed to create branch '{}': {}", branch, e))?;
+    repo.config()
+        .and_then(|mut config| config.set_str(&draft_base_config_key(&branch), &base_branch))
+        .map_err(|e| format!("Failed to record base branch for '{}': {}", branch, e))?;
+    repo.set_head(&format!("refs/heads/{}", branch))
+        .map_err(|e| format!("Failed to check out '{}': {}", branch, e))?;
 
-fn extract_version_from_tag_name(tag_name: &str, prefixes: &[String]) -> Option<String> {
-    prefixes.iter().find_map(|prefix| {
-        tag_name
-            .strip_prefix(prefix)
-            .filter(|suffix| suffix.matches('.').count() == 2)
-            .map(ToString::to_string)
-    })
+    log::info!(
+        "[git] Started draft '{}' for '{}' from {}",
+        branch,
+        skill_name,
+        base_branch
+    );
+    skill_draft_status(repo_path, plugin_slug, skill_name)?
+        .ok_or_else(|| format!("Draft '{}' was not checked out", branch))
 }
 
-fn resolve_tag_name(
-    repo: &Repository,
+/// The draft currently checked out in a skill's repo, or `None` when HEAD is
+/// not on a draft branch.
+pub fn skill_draft_status(
+    repo_path: &Path,
     plugin_slug: &str,
     skill_name: &str,
-    requested_tag_name: &str,
-) -> Option<String> {
-    if repo
-        .find_reference(&format!("refs/tags/{}", requested_tag_name))
+) -> Result<Option<SkillDraft>, String> {
+    if !repo_path.join(".git").exists() {
+        return Ok(None);
+    }
+    let repo = Repository::open(repo_path)
+        .map_err(|e| format!("Failed to open repo at {}: {}", repo_path.display(), e))?;
+    let Some(branch) = current_draft_branch(&repo) else {
+        return Ok(None);
+    };
+    let base_branch = draft_base_branch(&repo, &branch)?;
+    let base = branch_tip(&repo, &base_branch)?;
+    let head = branch_tip(&repo, &branch)?;
+    let (ahead, behind) = repo.graph_ahead_behind(head.id(), base.id()).map_err(|e| {
+        format!(
+            "Failed to compare '{}' with '{}': {}",
+            branch, base_branch, e
+        )
+    })?;
+    let diff = diff_skill_versions(
+        repo_path,
+        plugin_slug,
+        skill_name,
+        &base.id().to_string(),
+        &head.id().to_string(),
+    )?;
+
+    Ok(Some(SkillDraft {
+        name: branch[DRAFT_BRANCH_PREFIX.len()..].to_string(),
+        branch,
+        base_branch,
+        base_sha: base.id().to_string(),
+        head_sha: head.id().to_string(),
+        ahead,
+        behind,
+        files: diff.files,
+    }))
+}
+
+/// Merge the checked-out draft into its base branch, tag the result as a new
+/// version (`bump = None` uses `suggest_version_bump`) and delete the draft.
+///
+/// Fast-forwards when the base branch has not moved; otherwise creates a merge
+/// commit, refusing when the two sides conflict.
+pub fn merge_skill_draft(
+    repo_path: &Path,
+    plugin_slug: &str,
+    skill_name: &str,
+    bump: Option<VersionBump>,
+) -> Result<SkillDraftMerge, String> {
+    let repo = Repository::open(repo_path)
+        .map_err(|e| format!("Failed to open repo at {}: {}", repo_path.display(), e))?;
+    let branch =
+        current_draft_branch(&repo).ok_or_else(|| "No draft is checked out".to_string())?;
+    let draft_name = branch[DRAFT_BRANCH_PREFIX.len()..].to_string();
+    let base_branch = draft_base_branch(&repo, &branch)?;
+
+    commit_all(
+        repo_path,
+        &format!(
+            "{}: checkpoint before merging draft {}",
+            skill_name, draft_name
+        ),
+    )?;
+    let draft = branch_tip(&repo, &branch)?;
+    let base = branch_tip(&repo, &base_branch)?;
+
+    let fast_forward = draft.id() == base.id()
+        || repo
+            .graph_descendant_of(draft.id(), base.id())
+            .map_err(|e| {
+                format!(
+                    "Failed to compare '{}' with '{}': {}",
+                    branch, base_branch, e
+                )
+            })?;
+    let merged = if fast_forward {
+        draft.id()
+    } else {
+        let mut index = repo
+            .merge_commits(&base, &draft, None)
+            .map_err(|e| format!("Failed to merge '{}' into '{}': {}", branch, base_branch, e))?;
+        if index.has_conflicts() {
+            let mut paths: Vec<String> = index
+                .conflicts()
+                .map_err(|e| format!("Failed to read merge conflicts: {}", e))?
+                .filter_map(|conflict| conflict.ok())
+                .filter_map(|conflict| conflict.our.or(conflict.their))
+                .map(|entry| String::from_utf8_lossy(&entry.path).to_string())
+                .collect();
+            paths.sort();
+            paths.dedup();
+            return Err(format!(
+                "Draft '{}' conflicts with '{}' in: {}",
+                draft_name,
+                base_branch,
+                paths.join(", ")
+            ));
+        }
+        let tree_id = index
+            .write_tree_to(&repo)
+            .map_err(|e| format!("Failed to write merge tree: {}", e))?;
+        let tree = repo
+            .find_tree(tree_id)
+            .map_err(|e| format!("Failed to find merge tree: {}", e))?;
+        let sig = default_signature(&repo)?;
+        repo.commit(
+            None,
+            &sig,
+            &sig,
+            &format!("{}: merge draft {}", skill_name, draft_name),
+            &tree,
+            &[&base, &draft],
+        )
+        .map_err(|e| format!("Failed to commit merge of '{}': {}", branch, e))?
+    };
+
+    repo.reference(
+        &format!("refs/heads/{}", base_branch),
+        merged,
+        true,
+        &format!("merge {}", branch),
+    )
+    .map_err(|e| format!("Failed to update '{}': {}", base_branch, e))?;
+    leave_draft(&repo, &branch, &base_branch)?;
+    log::info!(
+        "[git] Merged draft '{}' into {} ({})",
+        branch,
+        base_branch,
+        if fast_forward {
+            "fast-forward"
+        } else {
+            "merge commit"
+        }
+    );
+
+    let release = release_skill_version(repo_path, plugin_slug, skill_name, bump)?;
+    Ok(SkillDraftMerge {
+        base_branch,
+        merged_sha: merged.to_string(),
+        fast_forward,
+        release,
+    })
+}
+
+/// Check out the base branch and delete the draft, dropping all of its
+/// commits and any uncommitted changes. Returns the base branch name.
+pub fn discard_skill_draft(repo_path: &Path) -> Result<String, String> {
+    let repo = Repository::open(repo_path)
+        .map_err(|e| format!("Failed to open repo at {}: {}", repo_path.display(), e))?;
+    let branch =
+        current_draft_branch(&repo).ok_or_else(|| "No draft is checked out".to_string())?;
+    let base_branch = draft_base_branch(&repo, &branch)?;
+    leave_draft(&repo, &branch, &base_branch)?;
+    log::info!("[git] Discarded draft '{}'", branch);
+    Ok(base_branch)
+}
+
+/// Force-checkout `base_branch` (removing untracked files) and delete `branch`.
+fn leave_draft(repo: &Repository, branch: &str, base_branch: &str) -> Result<(), String> {
+    repo.set_head(&format!("refs/heads/{}", base_branch))
+        .map_err(|e| format!("Failed to switch to '{}': {}", base_branch, e))?;
+    repo.checkout_head(Some(
+        git2::build::CheckoutBuilder::new()
+            .force()
+            .remove_untracked(true),
+    ))
+    .map_err(|e| format!("Failed to check out '{}': {}", base_branch, e))?;
+    // Deleting the branch also removes its `branch.<name>.*` config section.
+    repo.find_branch(branch, git2::BranchType::Local)
+        .and_then(|mut b| b.delete())
+        .map_err(|e| format!("Failed to delete '{}': {}", branch, e))
+}
+
+fn draft_base_config_key(branch: &str) -> String {
+    format!("branch.{}.skillbuilderbase", branch)
+}
+
+fn draft_base_branch(repo: &Repository, branch: &str) -> Result<String, String> {
+    repo.config()
+        .and_then(|config| config.get_string(&draft_base_config_key(branch)))
+        .map_err(|_| format!("Draft '{}' has no recorded base branch", branch))
+}
+
+/// Name of the checked-out branch, or `None` when HEAD is detached or unborn.
+fn current_branch_name(repo: &Repository) -> Option<String> {
+    let head = repo.head().ok()?;
+    if !head.is_branch() {
+        return None;
+    }
+    head.shorthand().map(ToString::to_string)
+}
+
+fn current_draft_branch(repo: &Repository) -> Option<String> {
+    current_branch_name(repo).filter(|branch| branch.starts_with(DRAFT_BRANCH_PREFIX))
+}
+
+fn branch_tip<'r>(repo: &'r Repository, branch: &str) -> Result<git2::Commit<'r>, String> {
+    repo.find_branch(branch, git2::BranchType::Local)
+        .and_then(|b| b.get().peel_to_commit())
+        .map_err(|e| format!("Failed to resolve branch '{}': {}", branch, e))
+}
+
+/// Branch holding the pristine content of an imported skill as it came from
+/// its marketplace source. It is the merge base for applying upstream updates
+/// on top of local edits.
+pub const UPSTREAM_BRANCH: &str = "upstream";
+
+/// Commit-message trailer recording the marketplace commit an upstream update came from.
+const SOURCE_COMMIT_TRAILER: &str = "Source-Commit: ";
+
+/// Point the `upstream` branch at HEAD, right after an import commit.
+pub fn record_upstream_import(repo_path: &Path) -> Result<(), String> {
+    let repo = ensure_repo(repo_path)?;
+    let head = repo
+        .head()
+        .and_then(|h| h.peel_to_commit())
+        .map_err(|e| format!("Failed to resolve HEAD: {}", e))?;
+    repo.branch(UPSTREAM_BRANCH, &head, true)
+        .map_err(|e| format!("Failed to update '{}': {}", UPSTREAM_BRANCH, e))?;
+    log::debug!(
+        "[git] Recorded upstream import at {}",
+        &head.id().to_string()[..8]
+    );
+    Ok(())
+}
+
+/// Commit the files in `incoming_dir` on top of the `upstream` branch tip so
+/// they can be merged into the skill. Returns the upstream commit SHA. The
+/// commit is unreferenced: the branch only moves when the merge is applied,
+/// and neither HEAD nor the working copy is touched.
+///
+/// Skills imported before the branch existed fall back to the tag of
+/// `imported_version`, which points at the original import commit. The
+/// marketplace commit the files came from is kept as a `Source-Commit:` trailer.
+pub fn stage_upstream_update(
+    repo_path: &Path,
+    plugin_slug: &str,
+    skill_name: &str,
+    incoming_dir: &Path,
+    version: &str,
+    imported_version: Option<&str>,
+    source_commit: Option<&str>,
+) -> Result<String, String> {
+    let repo = ensure_repo(repo_path)?;
+    if let Some(branch) = current_draft_branch(&repo) {
+        return Err(format!(
+            "Draft '{}' is in progress; merge or discard it before updating",
+            &branch[DRAFT_BRANCH_PREFIX.len()..]
+        ));
+    }
+
+    let parent = match branch_tip(&repo, UPSTREAM_BRANCH) {
+        Ok(tip) => tip,
+        Err(_) => imported_version
+            .and_then(|v| resolve_skill_commit(&repo, plugin_slug, skill_name, v).ok())
+            .ok_or_else(|| {
+                format!(
+                    "No imported baseline found for '{}'; cannot merge the update",
+                    skill_name
+                )
+            })?,
+    };
+    let parent_tree = parent
+        .tree()
+        .map_err(|e| format!("Failed to read upstream tree: {}", e))?;
+
+    let mut index = git2::Index::new().map_err(|e| format!("Failed to create index: {}", e))?;
+    let mut files = Vec::new();
+    collect_dir_files(incoming_dir, incoming_dir, &mut files)?;
+    // The incoming copy is a plain download; keep the repo's own .gitignore.
+    if !files.iter().any(|(path, _)| path == ".gitignore") {
+        if let Ok(entry) = parent_tree.get_path(Path::new(".gitignore")) {
+            let blob = repo
+                .find_blob(entry.id())
+                .map_err(|e| format!("Failed to read .gitignore: {}", e))?;
+            files.push((".gitignore".to_string(), blob.content().to_vec()));
+        }
+    }
+    for (path, content) in &files {
+        let oid = repo
+            .blob(content)
+            .map_err(|e| format!("Failed to store '{}': {}", path, e))?;
+        let tracked_mode = || {
+            parent_tree
+                .get_path(Path::new(path))
+                .ok()
+                .map(|entry| entry.filemode() as u32)
+        };
+        let mode = disk_file_mode(&incoming_dir.join(path))
+            .or_else(tracked_mode)
+            .unwrap_or(REGULAR_FILE_MODE);
+        index
+            .add(&blob_index_entry(path, oid, content.len(), mode))
+            .map_err(|e| format!("Failed to stage '{}': {}", path, e))?;
+    }
+    let tree_id = index
+        .write_tree_to(&repo)
+        .map_err(|e| format!("Failed to write upstream tree: {}", e))?;
+    if tree_id == parent_tree.id() {
+        return Ok(parent.id().to_string());
+    }
+
+    let tree = repo
+        .find_tree(tree_id)
+        .map_err(|e| format!("Failed to find upstream tree: {}", e))?;
+    let sig = default_signature(&repo)?;
+    let mut message = format!("{}: upstream {}", skill_name, version);
+    if let Some(sha) = source_commit {
+        message.push_str(&format!("\n\n{}{}", SOURCE_COMMIT_TRAILER, sha));
+    }
+    let oid = repo
+        .commit(None, &sig, &sig, &message, &tree, &[&parent])
+        .map_err(|e| format!("Failed to commit upstream {}: {}", version, e))?;
+    log::info!(
+        "[git] Staged upstream {} for '{}' ({})",
+        version,
+        skill_name,
+        &oid.to_string()[..8]
+    );
+    Ok(oid.to_string())
+}
+
+/// Tree of the working copy as `commit_all` would stage it, built without
+/// saving the index.
+fn working_copy_tree(repo: &Repository) -> Result<git2::Tree<'_>, String> {
+    let mut index = repo
+        .index()
+        .map_err(|e| format!("Failed to get index: {}", e))?;
+    let pathspecs = ["*", ".*"];
+    index
+        .add_all(pathspecs.iter(), git2::IndexAddOption::DEFAULT, None)
+        .map_err(|e| format!("Failed to stage files: {}", e))?;
+    index
+        .update_all(pathspecs.iter(), None)
+        .map_err(|e| format!("Failed to stage deletions: {}", e))?;
+    let tree_id = index
+        .write_tree()
+        .map_err(|e| format!("Failed to write tree: {}", e))?;
+    // The repo shares this index; drop the in-memory staging.
+    index
+        .read(true)
+        .map_err(|e| format!("Failed to reload index: {}", e))?;
+    repo.find_tree(tree_id)
+        .map_err(|e| format!("Failed to find tree: {}", e))
+}
+
+/// HEAD plus any local edits, as an unreferenced checkpoint commit on top of
+/// HEAD. Returns HEAD itself when the working copy is clean.
+fn working_copy_commit<'r>(
+    repo: &'r Repository,
+    message: &str,
+) -> Result<git2::Commit<'r>, String> {
+    let head = repo
+        .head()
+        .and_then(|h| h.peel_to_commit())
+        .map_err(|e| format!("Failed to resolve HEAD: {}", e))?;
+    let tree = working_copy_tree(repo)?;
+    if tree.id() == head.tree_id() {
+        return Ok(head);
+    }
+    let sig = default_signature(repo)?;
+    let oid = repo
+        .commit(None, &sig, &sig, message, &tree, &[&head])
+        .map_err(|e| format!("Failed to checkpoint local edits: {}", e))?;
+    repo.find_commit(oid)
+        .map_err(|e| format!("Failed to find checkpoint: {}", e))
+}
+
+/// Three-way merge of `upstream_sha` into the local copy, uncommitted edits
+/// included, without moving any ref or touching the working tree. Files only
+/// one side changed merge automatically; overlapping edits come back as
+/// conflicts with git-style markers for review.
+pub fn preview_skill_update_merge(
+    repo_path: &Path,
+    skill_name: &str,
+    version: &str,
+    upstream_sha: &str,
+) -> Result<SkillUpdatePreview, String> {
+    let repo = Repository::open(repo_path)
+        .map_err(|e| format!("Failed to open repo at {}: {}", repo_path.display(), e))?;
+    let head = working_copy_commit(
+        &repo,
+        &format!(
+            "{}: checkpoint before upstream update {}",
+            skill_name, version
+        ),
+    )?;
+    let upstream = find_commit(&repo, upstream_sha)?;
+    let base_id = repo
+        .merge_base(head.id(), upstream.id())
+        .map_err(|e| format!("No merge base between local and upstream: {}", e))?;
+    let base = repo
+        .find_commit(base_id)
+        .map_err(|e| format!("Failed to find merge base: {}", e))?;
+
+    let index = repo
+        .merge_commits(&head, &upstream, None)
+        .map_err(|e| format!("Failed to merge upstream {}: {}", version, e))?;
+    let conflicts = merge_conflicts(&repo, &index)?;
+
+    let diff = repo
+        .diff_tree_to_tree(
+            Some(&base.tree().map_err(|e| e.to_string())?),
+            Some(&upstream.tree().map_err(|e| e.to_string())?),
+            None,
+        )
+        .map_err(|e| format!("Failed to diff upstream changes: {}", e))?;
+    let auto_merged = diff
+        .deltas()
+        .filter_map(|d| d.new_file().path().or_else(|| d.old_file().path()))
+        .map(|p| p.to_string_lossy().to_string())
+        .filter(|p| !conflicts.iter().any(|c| c.path == *p))
+        .collect();
+
+    Ok(SkillUpdatePreview {
+        skill_name: skill_name.to_string(),
+        version: version.to_string(),
+        head_sha: head.id().to_string(),
+        upstream_sha: upstream.id().to_string(),
+        base_sha: base.id().to_string(),
+        auto_merged,
+        conflicts,
+    })
+}
+
+/// Commit the merge of `upstream_sha` into HEAD, tag it as `version` and
+/// move the `upstream` branch to `upstream_sha`. Local edits the preview
+/// included are committed first as the checkpoint `head_sha`.
+///
+/// Every conflicted path needs an entry in `resolutions`: the final content,
+/// or `None` to delete the file. Fails if HEAD or the working copy changed
+/// since `head_sha` was previewed.
+pub fn apply_skill_update_merge(
+    repo_path: &Path,
+    plugin_slug: &str,
+    skill_name: &str,
+    version: &str,
+    head_sha: &str,
+    upstream_sha: &str,
+    resolutions: &std::collections::HashMap<String, Option<String>>,
+) -> Result<SkillUpdateApplied, String> {
+    let repo = Repository::open(repo_path)
+        .map_err(|e| format!("Failed to open repo at {}: {}", repo_path.display(), e))?;
+    let current = repo
+        .head()
+        .and_then(|h| h.peel_to_commit())
+        .map_err(|e| format!("Failed to resolve HEAD: {}", e))?;
+    let head = find_commit(&repo, head_sha)?;
+    // `head_sha` is HEAD, or the checkpoint of local edits on top of it.
+    let is_checkpoint = head.parent_count() == 1 && head.parent_id(0).ok() == Some(current.id());
+    if head.id() != current.id() && !is_checkpoint {
+        return Err(format!(
+            "'{}' changed since the update was previewed; preview it again",
+            skill_name
+        ));
+    }
+    // The checkout below forces the merged tree over the working copy and
+    // removes untracked files, so refuse rather than discard edits made
+    // after the preview.
+    if working_copy_tree(&repo)?.id() != head.tree_id() {
+        return Err(format!(
+            "'{}' has uncommitted changes made after the preview; preview the update again",
+            skill_name
+        ));
+    }
+    if skill_version_tag_exists(repo_path, plugin_slug, skill_name, version)? {
+        return Err(format!(
+            "Tag '{}' already exists",
+            skill_version_tag_name(plugin_slug, skill_name, version)
+        ));
+    }
+    let upstream = find_commit(&repo, upstream_sha)?;
+
+    let mut index = repo
+        .merge_commits(&head, &upstream, None)
+        .map_err(|e| format!("Failed to merge upstream {}: {}", version, e))?;
+    let conflicted: Vec<String> = merge_conflicts(&repo, &index)?
+        .into_iter()
+        .map(|c| c.path)
+        .collect();
+    if let Some(extra) = resolutions.keys().find(|p| !conflicted.contains(p)) {
+        return Err(format!("'{}' is not a conflicted file", extra));
+    }
+    let conflict_modes: std::collections::HashMap<String, u32> = index
+        .conflicts()
+        .map_err(|e| format!("Failed to read merge conflicts: {}", e))?
+        .filter_map(Result::ok)
+        .filter_map(|conflict| {
+            let entry = conflict.our.or(conflict.their)?;
+            Some((String::from_utf8_lossy(&entry.path).to_string(), entry.mode))
+        })
+        .collect();
+    for path in &conflicted {
+        let resolution = resolutions
+            .get(path)
+            .ok_or_else(|| format!("Unresolved conflict in '{}'", path))?;
+        index
+            .conflict_remove(Path::new(path))
+            .map_err(|e| format!("Failed to clear conflict for '{}': {}", path, e))?;
+        let Some(content) = resolution else {
+            continue;
+        };
+        if content.lines().any(|line| line.starts_with("<<<<<<<")) {
+            return Err(format!("'{}' still contains conflict markers", path));
+        }
+        let oid = repo
+            .blob(content.as_bytes())
+            .map_err(|e| format!("Failed to store '{}': {}", path, e))?;
+        let mode = conflict_modes
+            .get(path)
+            .copied()
+            .unwrap_or(REGULAR_FILE_MODE);
+        index
+            .add(&blob_index_entry(path, oid, content.len(), mode))
+            .map_err(|e| format!("Failed to stage '{}': {}", path, e))?;
+    }
+
+    let tree_id = index
+        .write_tree_to(&repo)
+        .map_err(|e| format!("Failed to write merge tree: {}", e))?;
+    let tree = repo
+        .find_tree(tree_id)
+        .map_err(|e| format!("Failed to find merge tree: {}", e))?;
+    if is_checkpoint {
+        repo.head()
+            .and_then(|mut r| r.set_target(head.id(), "checkpoint before upstream update"))
+            .map_err(|e| format!("Failed to commit local edits: {}", e))?;
+    }
+    let sig = default_signature(&repo)?;
+    let merged = repo
+        .commit(
+            Some("HEAD"),
+            &sig,
+            &sig,
+            &format!("{}: merge upstream {}", skill_name, version),
+            &tree,
+            &[&head, &upstream],
+        )
+        .map_err(|e| format!("Failed to commit upstream merge: {}", e))?;
+    repo.branch(UPSTREAM_BRANCH, &upstream, true)
+        .map_err(|e| format!("Failed to update '{}': {}", UPSTREAM_BRANCH, e))?;
+    repo.checkout_head(Some(
+        git2::build::CheckoutBuilder::new()
+            .force()
+            .remove_untracked(true),
+    ))
+    .map_err(|e| format!("Failed to check out merged files: {}", e))?;
+    let tag = create_skill_version_tag(repo_path, plugin_slug, skill_name, version)?;
+    log::info!(
+        "[git] Merged upstream {} into '{}' ({}, {} conflict(s) resolved)",
+        version,
+        skill_name,
+        &merged.to_string()[..8],
+        conflicted.len()
+    );
+
+    Ok(SkillUpdateApplied {
+        version: version.to_string(),
+        merged_sha: merged.to_string(),
+        tag,
+    })
+}
+
+/// Content of `path` at commit `sha`, or `None` if the file is absent.
+pub fn read_file_at_commit(
+    repo_path: &Path,
+    sha: &str,
+    path: &str,
+) -> Result<Option<Vec<u8>>, String> {
+    let repo = Repository::open(repo_path)
+        .map_err(|e| format!("Failed to open repo at {}: {}", repo_path.display(), e))?;
+    let tree = find_commit(&repo, sha)?
+        .tree()
+        .map_err(|e| format!("Failed to read tree at '{}': {}", sha, e))?;
+    let Ok(entry) = tree.get_path(Path::new(path)) else {
+        return Ok(None);
+    };
+    let blob = repo
+        .find_blob(entry.id())
+        .map_err(|e| format!("Failed to read '{}' at '{}': {}", path, sha, e))?;
+    Ok(Some(blob.content().to_vec()))
+}
+
+/// The `Source-Commit:` trailer of an upstream commit, if it has one.
+pub fn upstream_source_commit(repo_path: &Path, sha: &str) -> Result<Option<String>, String> {
+    let repo = Repository::open(repo_path)
+        .map_err(|e| format!("Failed to open repo at {}: {}", repo_path.display(), e))?;
+    let commit = find_commit(&repo, sha)?;
+    Ok(commit.message().and_then(|message| {
+        message
+            .lines()
+            .find_map(|line| line.strip_prefix(SOURCE_COMMIT_TRAILER))
+            .map(|sha| sha.trim().to_string())
+    }))
+}
+
+/// SHA-256 hex digest of every file in the tree at `sha`, keyed by path.
+pub fn file_digests_at_commit(
+    repo_path: &Path,
+    sha: &str,
+) -> Result<std::collections::BTreeMap<String, String>, String> {
+    use sha2::Digest;
+
+    let repo = Repository::open(repo_path)
+        .map_err(|e| format!("Failed to open repo at {}: {}", repo_path.display(), e))?;
+    let tree = find_commit(&repo, sha)?
+        .tree()
+        .map_err(|e| format!("Failed to read tree at '{}': {}", sha, e))?;
+    let mut digests = std::collections::BTreeMap::new();
+    let mut failed = None;
+    tree.walk(git2::TreeWalkMode::PreOrder, |dir, entry| {
+        if entry.kind() != Some(git2::ObjectType::Blob) {
+            return git2::TreeWalkResult::Ok;
+        }
+        let path = format!("{}{}", dir, entry.name().unwrap_or_default());
+        match repo.find_blob(entry.id()) {
+            Ok(blob) => {
+                digests.insert(path, hex::encode(sha2::Sha256::digest(blob.content())));
+                git2::TreeWalkResult::Ok
+            }
+            Err(e) => {
+                failed = Some(format!("Failed to read '{}' at '{}': {}", path, sha, e));
+                git2::TreeWalkResult::Abort
+            }
+        }
+    })
+    .map_err(|e| failed.clone().unwrap_or_else(|| e.to_string()))?;
+    match failed {
+        Some(e) => Err(e),
+        None => Ok(digests),
+    }
+}
+
+fn find_commit<'r>(repo: &'r Repository, sha: &str) -> Result<git2::Commit<'r>, String> {
+    git2::Oid::from_str(sha)
+        .and_then(|oid| repo.find_commit(oid))
+        .map_err(|e| format!("Unknown commit '{}': {}", sha, e))
+}
+
+/// Conflicts left in a merge index, with each side's text and a merged
+/// rendering that marks the overlapping hunks.
+fn merge_conflicts(
+    repo: &Repository,
+    index: &git2::Index,
+) -> Result<Vec<SkillMergeConflict>, String> {
+    let read = |entry: &Option<git2::IndexEntry>| -> Result<Option<String>, String> {
+        entry
+            .as_ref()
+            .map(|e| {
+                repo.find_blob(e.id)
+                    .map(|b| String::from_utf8_lossy(b.content()).to_string())
+                    .map_err(|err| format!("Failed to read conflict blob: {}", err))
+            })
+            .transpose()
+    };
+
+    let mut conflicts = Vec::new();
+    for conflict in index
+        .conflicts()
+        .map_err(|e| format!("Failed to read merge conflicts: {}", e))?
+    {
+        let conflict = conflict.map_err(|e| format!("Failed to read merge conflict: {}", e))?;
+        let Some(path) = [&conflict.our, &conflict.their, &conflict.ancestor]
+            .into_iter()
+            .flatten()
+            .map(|e| String::from_utf8_lossy(&e.path).to_string())
+            .next()
+        else {
+            continue;
+        };
+        let local = read(&conflict.our)?;
+        let upstream = read(&conflict.their)?;
+        let merged = match (&conflict.ancestor, &conflict.our, &conflict.their) {
+            (Some(base), Some(ours), Some(theirs)) => {
+                let mut opts = git2::MergeFileOptions::new();
+                opts.our_label("local").their_label("upstream");
+                let result = repo
+                    .merge_file_from_index(base, ours, theirs, Some(&mut opts))
+                    .map_err(|e| format!("Failed to merge '{}': {}", path, e))?;
+                String::from_utf8_lossy(result.content()).to_string()
+            }
+            _ => local
+                .clone()
+                .or_else(|| upstream.clone())
+                .unwrap_or_default(),
+        };
+        conflicts.push(SkillMergeConflict {
+            base: read(&conflict.ancestor)?,
+            local,
+            upstream,
+            merged,
+            path,
+        });
+    }
+    Ok(conflicts)
+}
+
+/// Regular files under `dir` as `(relative_path, content)`, skipping `.git`.
+fn collect_dir_files(
+    root: &Path,
+    dir: &Path,
+    out: &mut Vec<(String, Vec<u8>)>,
+) -> Result<(), String> {
+    for entry in
+        std::fs::read_dir(dir).map_err(|e| format!("Failed to read {}: {}", dir.display(), e))?
+    {
+        let path = entry
+            .map_err(|e| format!("Failed to read entry: {}", e))?
+            .path();
+        if path.file_name().is_some_and(|n| n == ".git") {
+            continue;
+        }
+        if path.is_dir() {
+            collect_dir_files(root, &path, out)?;
+        } else if path.is_file() {
+            let relative = path
+                .strip_prefix(root)
+                .map_err(|e| format!("Failed to relativize {}: {}", path.display(), e))?
+                .to_string_lossy()
+                .replace('\\', "/");
+            let content = std::fs::read(&path)
+                .map_err(|e| format!("Failed to read {}: {}", path.display(), e))?;
+            out.push((relative, content));
+        }
+    }
+    Ok(())
+}
+
+const REGULAR_FILE_MODE: u32 = 0o100644;
+const EXECUTABLE_FILE_MODE: u32 = 0o100755;
+
+/// Git filemode for a file on disk: executable when any execute bit is set.
+/// `None` where the filesystem has no execute bit to read.
+#[cfg(unix)]
+fn disk_file_mode(path: &Path) -> Option<u32> {
+    use std::os::unix::fs::PermissionsExt;
+    let mode = std::fs::metadata(path).ok()?.permissions().mode();
+    Some(if mode & 0o111 != 0 {
+        EXECUTABLE_FILE_MODE
+    } else {
+        REGULAR_FILE_MODE
+    })
+}
+
+#[cfg(not(unix))]
+fn disk_file_mode(_path: &Path) -> Option<u32> {
+    None
+}
+
+/// A stage-0 index entry for a file blob with the given git filemode.
+fn blob_index_entry(path: &str, id: git2::Oid, len: usize, mode: u32) -> git2::IndexEntry {
+    git2::IndexEntry {
+        ctime: git2::IndexTime::new(0, 0),
+        mtime: git2::IndexTime::new(0, 0),
+        dev: 0,
+        ino: 0,
+        mode,
+        uid: 0,
+        gid: 0,
+        file_size: len as u32,
+        id,
+        flags: path.len().min(0xfff) as u16,
+        flags_extended: 0,
+        path: path.as_bytes().to_vec(),
+    }
+}
+
+// --- History bundles ---
+
+const BUNDLE_SIGNATURE: &str = "# v2 git bundle\n";
+
+/// File name a skill package uses for its bundled git history.
+pub const HISTORY_BUNDLE_FILE: &str = ".skill-history.bundle";
+
+/// Write a skill repo's branches and tags to a standard v2 git bundle at `dest`.
+///
+/// Draft branches are left out because their base-branch bookkeeping lives in
+/// the repo config, which does not travel with the bundle. When a draft is
+/// checked out, the bundle's HEAD names the draft's base branch instead.
+pub fn write_history_bundle(repo_path: &Path, dest: &Path) -> Result<(), String> {
+    let repo = Repository::open(repo_path).map_err(|e| format!("Failed to open repo: {}", e))?;
+
+    let mut refs: Vec<(String, git2::Oid)> = Vec::new();
+    for reference in repo
+        .references()
+        .map_err(|e| format!("Failed to list references: {}", e))?
+        .flatten()
+    {
+        let Some(name) = reference.name() else {
+            continue;
+        };
+        let keep = name.starts_with("refs/tags/")
+            || name
+                .strip_prefix("refs/heads/")
+                .is_some_and(|branch| !branch.starts_with(DRAFT_BRANCH_PREFIX));
+        if let (true, Some(oid)) = (keep, reference.target()) {
+            refs.push((name.to_string(), oid));
+        }
+    }
+    if refs.is_empty() {
+        return Err("Skill repo has no branches or tags to bundle".to_string());
+    }
+    refs.sort();
+
+    let head_branch = match current_draft_branch(&repo) {
+        Some(draft) => draft_base_branch(&repo, &draft)?,
+        None => current_branch_name(&repo)
+            .ok_or_else(|| "Cannot bundle history: HEAD is not on a branch".to_string())?,
+    };
+    let head_oid = branch_tip(&repo, &head_branch)?.id();
+
+    let mut walk = repo
+        .revwalk()
+        .map_err(|e| format!("Failed to create revwalk: {}", e))?;
+    let mut builder = repo
+        .packbuilder()
+        .map_err(|e| format!("Failed to create pack builder: {}", e))?;
+    for (name, oid) in &refs {
+        let object = repo
+            .find_object(*oid, None)
+            .map_err(|e| format!("Failed to resolve '{}': {}", name, e))?;
+        if object.kind() == Some(git2::ObjectType::Tag) {
+            builder
+                .insert_object(*oid, None)
+                .map_err(|e| format!("Failed to pack tag '{}': {}", name, e))?;
+        }
+        let commit = object
+            .peel(git2::ObjectType::Commit)
+            .map_err(|e| format!("Failed to peel '{}' to a commit: {}", name, e))?;
+        walk.push(commit.id())
+            .map_err(|e| format!("Failed to walk '{}': {}", name, e))?;
+    }
+    builder
+        .insert_walk(&mut walk)
+        .map_err(|e| format!("Failed to pack history: {}", e))?;
+    let mut pack = git2::Buf::new();
+    builder
+        .write_buf(&mut pack)
+        .map_err(|e| format!("Failed to write pack: {}", e))?;
+
+    let mut out = String::from(BUNDLE_SIGNATURE);
+    out.push_str(&format!("{} HEAD\n", head_oid));
+    for (name, oid) in &refs {
+        out.push_str(&format!("{} {}\n", oid, name));
+    }
+    out.push('\n');
+    let mut bytes = out.into_bytes();
+    bytes.extend_from_slice(&pack);
+    std::fs::write(dest, bytes)
+        .map_err(|e| format!("Failed to write bundle '{}': {}", dest.display(), e))?;
+
+    log::info!(
+        "[git] Bundled {} refs from {} into {}",
+        refs.len(),
+        repo_path.display(),
+        dest.display()
+    );
+    Ok(())
+}
+
+/// Initialize a repo at `repo_path` from a bundle written by `write_history_bundle`.
+///
+/// Branches and tags are recreated and HEAD is pointed at the bundled HEAD
+/// branch. Only the index is reset, so files already on disk are left as they
+/// are and show up as changes for the next `commit_all`. Returns the number of
+/// tags restored.
+pub fn restore_history_bundle(repo_path: &Path, bundle: &Path) -> Result<usize, String> {
+    if repo_path.join(".git").exists() {
+        return Err(format!(
+            "Cannot restore history: {} already has a git repo",
+            repo_path.display()
+        ));
+    }
+    let bytes = std::fs::read(bundle)
+        .map_err(|e| format!("Failed to read bundle '{}': {}", bundle.display(), e))?;
+    let (head_oid, refs, pack) = parse_history_bundle(&bytes)?;
+
+    let repo = Repository::init(repo_path)
+        .map_err(|e| format!("Failed to init git repo at {}: {}", repo_path.display(), e))?;
+    {
+        let odb = repo
+            .odb()
+            .map_err(|e| format!("Failed to open object database: {}", e))?;
+        let mut writer = odb
+            .packwriter()
+            .map_err(|e| format!("Failed to open pack writer: {}", e))?;
+        std::io::Write::write_all(&mut writer, pack)
+            .map_err(|e| format!("Failed to write pack: {}", e))?;
+        writer
+            .commit()
+            .map_err(|e| format!("Failed to index pack: {}", e))?;
+    }
+
+    let mut tags = 0;
+    for (name, oid) in &refs {
+        repo.reference(name, *oid, true, "restore from bundle")
+            .map_err(|e| format!("Failed to restore '{}': {}", name, e))?;
+        if name.starts_with("refs/tags/") {
+            tags += 1;
+        }
+    }
+
+    let head_ref = refs
+        .iter()
+        .filter(|(name, _)| name.starts_with("refs/heads/"))
+        .find(|(name, oid)| Some(*oid) == head_oid && name.as_str() != "refs/heads/upstream")
+        .or_else(|| {
+            refs.iter()
+                .find(|(name, _)| name.starts_with("refs/heads/"))
+        })
+        .map(|(name, _)| name.clone())
+        .ok_or_else(|| "Bundle contains no branches".to_string())?;
+    repo.set_head(&head_ref)
+        .map_err(|e| format!("Failed to set HEAD to '{}': {}", head_ref, e))?;
+    let head = repo
+        .head()
+        .and_then(|h| h.peel(git2::ObjectType::Commit))
+        .map_err(|e| format!("Failed to resolve restored HEAD: {}", e))?;
+    repo.reset(&head, git2::ResetType::Mixed, None)
+        .map_err(|e| format!("Failed to reset index: {}", e))?;
+
+    log::info!(
+        "[git] Restored {} refs ({} tags) into {}",
+        refs.len(),
+        tags,
+        repo_path.display()
+    );
+    Ok(tags)
+}
+
+type BundleParts<'a> = (Option<git2::Oid>, Vec<(String, git2::Oid)>, &'a [u8]);
+
+fn parse_history_bundle(bytes: &[u8]) -> Result<BundleParts<'_>, String> {
+    let mut rest = bytes
+        .strip_prefix(BUNDLE_SIGNATURE.as_bytes())
+        .ok_or_else(|| "Not a v2 git bundle".to_string())?;
+    let mut head = None;
+    let mut refs = Vec::new();
+    loop {
+        let end = rest
+            .iter()
+            .position(|b| *b == b'\n')
+            .ok_or_else(|| "Truncated git bundle header".to_string())?;
+        let line = std::str::from_utf8(&rest[..end])
+            .map_err(|_| "Invalid git bundle header".to_string())?;
+        rest = &rest[end + 1..];
+        if line.is_empty() {
+            break;
+        }
+        if line.starts_with('-') {
+            return Err("Bundle is incomplete: it depends on commits it does not contain".into());
+        }
+        let (oid, name) = line
+            .split_once(' ')
+            .ok_or_else(|| format!("Invalid git bundle ref line '{}'", line))?;
+        let oid = git2::Oid::from_str(oid).map_err(|e| format!("Invalid oid in bundle: {}", e))?;
+        if name == "HEAD" {
+            head = Some(oid);
+        } else {
+            refs.push((name.to_string(), oid));
+        }
+    }
+    Ok((head, refs, rest))
+}
+
+// --- Helpers ---
+
+/// Candidate path prefixes used to locate a skill's files inside a git tree.
+///
+/// Tried in priority order:
+/// 1. `{plugin}/skills/{name}/`  — canonical plugin layout
+/// 2. `{plugin}/{name}/`         — legacy plugin layout
+/// 3. `skills/{name}/`           — legacy default-plugin alias
+/// 4. `{name}/`                  — legacy flat layout
+/// 5. `""`                       — per-skill repo (files at tree root)
+fn candidate_skill_prefixes(plugin_slug: &str, skill_name: &str) -> Vec<String> {
+    let mut prefixes = vec![
+        format!("{}/skills/{}/", plugin_slug, skill_name),
+        format!("{}/{}/", plugin_slug, skill_name),
+    ];
+    if plugin_slug == crate::skill_paths::DEFAULT_PLUGIN_SLUG {
+        prefixes.push(format!("skills/{}/", skill_name));
+    }
+    prefixes.push(format!("{}/", skill_name));
+    prefixes.push(String::new());
+    dedupe_strings(prefixes)
+}
+
+fn candidate_tag_prefixes(plugin_slug: &str, skill_name: &str) -> Vec<String> {
+    let mut prefixes = vec![crate::skill_paths::skill_tag_prefix(
+        plugin_slug,
+        skill_name,
+    )];
+    prefixes.push(format!("{}/{}/v", plugin_slug, skill_name));
+    if plugin_slug == crate::skill_paths::DEFAULT_PLUGIN_SLUG {
+        prefixes.push(format!("skills/{}/v", skill_name));
+    }
+    prefixes.push("v".to_string());
+    dedupe_strings(prefixes)
+}
+
+fn candidate_tag_names(plugin_slug: &str, skill_name: &str, version: &str) -> Vec<String> {
+    let normalized = version.trim_start_matches('v');
+    candidate_tag_prefixes(plugin_slug, skill_name)
+        .into_iter()
+        .map(|prefix| format!("{prefix}{normalized}"))
+        .collect()
+}
+
+fn list_skill_tag_names(
+    repo: &Repository,
+    plugin_slug: &str,
+    skill_name: &str,
+) -> Result<Vec<String>, String> {
+    let mut names = Vec::new();
+    let mut seen = std::collections::HashSet::new();
+
+    for glob in candidate_tag_prefixes(plugin_slug, skill_name)
+        .into_iter()
+        .map(|prefix| format!("{prefix}*"))
+    {
+        let tags = repo
+            .tag_names(Some(&glob))
+            .map_err(|e| format!("Failed to list tags: {}", e))?;
+        for tag_name in tags.iter().flatten() {
+            let tag_name = tag_name.to_string();
+            if seen.insert(tag_name.clone()) {
+                names.push(tag_name);
+            }
+        }
+    }
+
+    Ok(names)
+}
+
+fn extract_version_from_tag_name(tag_name: &str, prefixes: &[String]) -> Option<String> {
+    prefixes.iter().find_map(|prefix| {
+        tag_name
+            .strip_prefix(prefix)
+            .filter(|suffix| suffix.matches('.').count() == 2)
+            .map(ToString::to_string)
+    })
+}
+
+fn resolve_tag_name(
+    repo: &Repository,
+    plugin_slug: &str,
+    skill_name: &str,
+    requested_tag_name: &str,
+) -> Option<String> {
+    if repo
+        .find_reference(&format!("refs/tags/{}", requested_tag_name))
         .is_ok()
     {
         return Some(requested_tag_name.to_string());
@@ -1082,6 +2642,152 @@ fn resolve_tag_name(
         })
 }
 
+/// Resolve a version, version tag name or revision to a commit.
+fn resolve_skill_commit<'r>(
+    repo: &'r Repository,
+    plugin_slug: &str,
+    skill_name: &str,
+    reference: &str,
+) -> Result<git2::Commit<'r>, String> {
+    let reference = reference.trim();
+    if reference.is_empty() {
+        return Err("Empty version or commit reference".to_string());
+    }
+    let object = match resolve_tag_name(repo, plugin_slug, skill_name, reference) {
+        Some(tag_name) => repo.revparse_single(&format!("refs/tags/{}", tag_name)),
+        None => repo.revparse_single(reference),
+    }
+    .map_err(|e| format!("Unknown version or commit '{}': {}", reference, e))?;
+    object
+        .peel_to_commit()
+        .map_err(|e| format!("'{}' does not point to a commit: {}", reference, e))
+}
+
+/// All of a skill's files at `commit` as `(relative_path, content)`, keyed by
+/// path. Uses the first candidate prefix whose tree contains `SKILL.md`;
+/// returns an empty map when the skill is absent. Dotfiles are skipped.
+fn skill_blobs_at_commit(
+    repo: &Repository,
+    commit: &git2::Commit,
+    plugin_slug: &str,
+    skill_name: &str,
+) -> Result<std::collections::BTreeMap<String, Vec<u8>>, String> {
+    let tree = commit
+        .tree()
+        .map_err(|e| format!("Failed to get tree for commit '{}': {}", commit.id(), e))?;
+    let all_entries = collect_tree_blobs(repo, &tree)?;
+
+    let Some(prefix) = candidate_skill_prefixes(plugin_slug, skill_name)
+        .into_iter()
+        .find(|p| {
+            let skill_md = format!("{}SKILL.md", p);
+            all_entries.iter().any(|(path, _)| *path == skill_md)
+        })
+    else {
+        return Ok(std::collections::BTreeMap::new());
+    };
+
+    Ok(all_entries
+        .into_iter()
+        .filter_map(|(path, content)| {
+            let relative = path.strip_prefix(prefix.as_str())?.to_string();
+            if relative.split('/').any(|part| part.starts_with('.')) {
+                return None;
+            }
+            Some((relative, content))
+        })
+        .collect())
+}
+
+/// Diff one file between two sides. Returns `None` when the content is
+/// identical (or the file is absent on both sides).
+fn diff_skill_file(
+    path: &str,
+    old: Option<&[u8]>,
+    new: Option<&[u8]>,
+) -> Result<Option<SkillFileDiff>, String> {
+    let status = match (old, new) {
+        (Some(a), Some(b)) if a == b => return Ok(None),
+        (Some(_), Some(_)) => "modified",
+        (None, Some(_)) => "added",
+        (Some(_), None) => "removed",
+        (None, None) => return Ok(None),
+    };
+
+    let mut opts = DiffOptions::new();
+    opts.context_lines(3);
+    let mut patch = git2::Patch::from_buffers(
+        old.unwrap_or_default(),
+        old.map(|_| Path::new(path)),
+        new.unwrap_or_default(),
+        new.map(|_| Path::new(path)),
+        Some(&mut opts),
+    )
+    .map_err(|e| format!("Failed to diff '{}': {}", path, e))?;
+
+    let binary = patch.delta().flags().is_binary();
+    let (_, additions, deletions) = patch
+        .line_stats()
+        .map_err(|e| format!("Failed to count changes in '{}': {}", path, e))?;
+
+    let mut hunks = Vec::new();
+    for hunk_idx in 0..patch.num_hunks() {
+        let (hunk, line_count) = patch
+            .hunk(hunk_idx)
+            .map_err(|e| format!("Failed to read hunk in '{}': {}", path, e))?;
+        let mut lines = Vec::with_capacity(line_count);
+        for line_idx in 0..line_count {
+            let line = patch
+                .line_in_hunk(hunk_idx, line_idx)
+                .map_err(|e| format!("Failed to read diff line in '{}': {}", path, e))?;
+            let kind = match line.origin() {
+                '+' => "added",
+                '-' => "removed",
+                ' ' => "context",
+                // EOF-newline markers (`=`, `>`, `<`)
+                _ => continue,
+            };
+            lines.push(SkillDiffLine {
+                kind: kind.to_string(),
+                content: String::from_utf8_lossy(line.content())
+                    .trim_end_matches(['\n', '\r'])
+                    .to_string(),
+                old_lineno: line.old_lineno(),
+                new_lineno: line.new_lineno(),
+            });
+        }
+        hunks.push(SkillDiffHunk {
+            header: String::from_utf8_lossy(hunk.header())
+                .trim_end()
+                .to_string(),
+            old_start: hunk.old_start(),
+            old_lines: hunk.old_lines(),
+            new_start: hunk.new_start(),
+            new_lines: hunk.new_lines(),
+            lines,
+        });
+    }
+
+    let diff = if binary {
+        String::new()
+    } else {
+        let buf = patch
+            .to_buf()
+            .map_err(|e| format!("Failed to format diff for '{}': {}", path, e))?;
+        String::from_utf8_lossy(&buf).into_owned()
+    };
+
+    Ok(Some(SkillFileDiff {
+        path: path.to_string(),
+        status: status.to_string(),
+        binary,
+        additions,
+        deletions,
+        diff,
+        hunks,
+    }))
+}
+
 fn dedupe_strings(values: Vec<String>) -> Vec<String> {
     let mut seen = std::collections::HashSet::new();
     values
@@ -1133,6 +2839,20 @@ fn default_signature(repo: &Repository) -> Result<Signature<'static>, String> {
         .map_err(|e| format!("Failed to create signature: {}", e))
 }
 
+/// Whether the working copy differs from HEAD, counting untracked but not
+/// ignored files. `pathspecs` limit the check; a `!` pattern excludes paths.
+fn has_uncommitted_changes(repo: &Repository, pathspecs: &[&str]) -> Result<bool, String> {
+    let mut opts = StatusOptions::new();
+    opts.include_untracked(true).recurse_untracked_dirs(true);
+    for pathspec in pathspecs {
+        opts.pathspec(pathspec);
+    }
+    let statuses = repo
+        .statuses(Some(&mut opts))
+        .map_err(|e| format!("Failed to get statuses: {}", e))?;
+    Ok(!statuses.is_empty())
+}
+
 fn ensure_no_unrelated_staged_changes(repo: &Repository, relative: &str) -> Result<(), String> {
     let prefix = format!("{}/", relative.trim_end_matches('/'));
     let mut opts = StatusOptions::new();
@@ -1465,6 +3185,14 @@ mod tests {
         assert_eq!(bump_patch("1.2"), "0.0.1");
     }
 
+    #[test]
+    fn test_bump_version_resets_lower_components() {
+        assert_eq!(bump_version("1.4.2", VersionBump::Major), "2.0.0");
+        assert_eq!(bump_version("1.4.2", VersionBump::Minor), "1.5.0");
+        assert_eq!(bump_version("1.4.2", VersionBump::Patch), "1.4.3");
+        assert_eq!(bump_version("x.4.2", VersionBump::Minor), "0.1.0");
+    }
+
     // --- latest_skill_semver ---
 
     #[test]
@@ -1990,34 +3718,117 @@ mod per_skill_repo_tests {
     }
 
     #[test]
-    fn test_extract_skill_at_tag_works_for_per_skill_repo() {
+    fn test_extract_skill_at_tag_works_for_per_skill_repo() {
+        let dir = tempdir().unwrap();
+        let skill_dir = dir.path();
+        ensure_repo(skill_dir).unwrap();
+        std::fs::write(skill_dir.join("SKILL.md"), "# Tagged version").unwrap();
+        commit_all(skill_dir, "generated skill").unwrap();
+        create_skill_version_tag(
+            skill_dir,
+            crate::skill_paths::DEFAULT_PLUGIN_SLUG,
+            "my-skill",
+            "1.0.0",
+        )
+        .unwrap();
+
+        let dest = dir.path().parent().unwrap().join("dest");
+        extract_skill_at_tag(
+            skill_dir,
+            crate::skill_paths::DEFAULT_PLUGIN_SLUG,
+            "my-skill",
+            &skill_version_tag_name(crate::skill_paths::DEFAULT_PLUGIN_SLUG, "my-skill", "1.0.0"),
+            &dest,
+        )
+        .unwrap();
+
+        assert_eq!(
+            std::fs::read_to_string(dest.join("SKILL.md")).unwrap(),
+            "# Tagged version"
+        );
+    }
+
+    #[test]
+    fn test_skill_revision_at_head_matches_tag_until_skill_changes() {
+        let dir = tempdir().unwrap();
+        let skill_dir = dir.path();
+        let plugin = crate::skill_paths::DEFAULT_PLUGIN_SLUG;
+        let first = init_per_skill_repo(skill_dir, "# V1");
+
+        let untagged = skill_revision_at_head(skill_dir, plugin, "my-skill").unwrap();
+        assert_eq!(untagged.version, None);
+        assert_eq!(untagged.commit.as_deref(), Some(first.as_str()));
+
+        create_skill_version_tag(skill_dir, plugin, "my-skill", "1.0.0").unwrap();
+        std::fs::create_dir_all(skill_dir.join("evals/workspace")).unwrap();
+        std::fs::write(skill_dir.join("evals/workspace/benchmark.json"), "{}").unwrap();
+        commit_all(skill_dir, "benchmark").unwrap();
+        let released = skill_revision_at_head(skill_dir, plugin, "my-skill").unwrap();
+        assert_eq!(released.version.as_deref(), Some("1.0.0"));
+        assert_ne!(released.commit.as_deref(), Some(first.as_str()));
+
+        std::fs::write(skill_dir.join("SKILL.md"), "# V2").unwrap();
+        commit_all(skill_dir, "edit").unwrap();
+        let edited = skill_revision_at_head(skill_dir, plugin, "my-skill").unwrap();
+        assert_eq!(edited.version, None);
+    }
+
+    #[test]
+    fn test_skill_revision_at_head_flags_uncommitted_skill_edits() {
+        let dir = tempdir().unwrap();
+        let skill_dir = dir.path();
+        let plugin = crate::skill_paths::DEFAULT_PLUGIN_SLUG;
+        let first = init_per_skill_repo(skill_dir, "# V1");
+        create_skill_version_tag(skill_dir, plugin, "my-skill", "1.0.0").unwrap();
+
+        std::fs::create_dir_all(skill_dir.join("evals/workspace")).unwrap();
+        std::fs::write(skill_dir.join("evals/workspace/benchmark.json"), "{}").unwrap();
+        let clean = skill_revision_at_head(skill_dir, plugin, "my-skill").unwrap();
+        assert!(!clean.working_copy);
+        assert_eq!(clean.version.as_deref(), Some("1.0.0"));
+
+        std::fs::write(skill_dir.join("SKILL.md"), "# V2 draft").unwrap();
+        let edited = skill_revision_at_head(skill_dir, plugin, "my-skill").unwrap();
+        assert!(edited.working_copy);
+        assert_eq!(edited.version, None);
+        assert_eq!(edited.commit.as_deref(), Some(first.as_str()));
+
+        std::fs::write(skill_dir.join("SKILL.md"), "# V1").unwrap();
+        std::fs::create_dir_all(skill_dir.join("references")).unwrap();
+        std::fs::write(skill_dir.join("references/notes.md"), "new").unwrap();
+        let added = skill_revision_at_head(skill_dir, plugin, "my-skill").unwrap();
+        assert!(added.working_copy);
+    }
+
+    #[test]
+    fn test_extract_skill_at_ref_accepts_versions_and_shas() {
         let dir = tempdir().unwrap();
-        let skill_dir = dir.path();
-        ensure_repo(skill_dir).unwrap();
-        std::fs::write(skill_dir.join("SKILL.md"), "# Tagged version").unwrap();
-        commit_all(skill_dir, "generated skill").unwrap();
-        create_skill_version_tag(
-            skill_dir,
-            crate::skill_paths::DEFAULT_PLUGIN_SLUG,
-            "my-skill",
-            "1.0.0",
-        )
-        .unwrap();
+        let skill_dir = dir.path().join("repo");
+        std::fs::create_dir_all(&skill_dir).unwrap();
+        let plugin = crate::skill_paths::DEFAULT_PLUGIN_SLUG;
+        let first = init_per_skill_repo(&skill_dir, "# V1");
+        create_skill_version_tag(&skill_dir, plugin, "my-skill", "1.0.0").unwrap();
+        std::fs::write(skill_dir.join("SKILL.md"), "# V2").unwrap();
+        commit_all(&skill_dir, "edit").unwrap();
 
-        let dest = dir.path().parent().unwrap().join("dest");
-        extract_skill_at_tag(
-            skill_dir,
-            crate::skill_paths::DEFAULT_PLUGIN_SLUG,
-            "my-skill",
-            &skill_version_tag_name(crate::skill_paths::DEFAULT_PLUGIN_SLUG, "my-skill", "1.0.0"),
-            &dest,
-        )
-        .unwrap();
+        let dest = dir.path().join("tagged");
+        let tagged = extract_skill_at_ref(&skill_dir, plugin, "my-skill", "v1.0.0", &dest).unwrap();
+        assert_eq!(tagged.version.as_deref(), Some("1.0.0"));
+        assert_eq!(tagged.commit, first);
+        assert_eq!(
+            std::fs::read_to_string(dest.join("SKILL.md")).unwrap(),
+            "# V1"
+        );
 
+        let dest = dir.path().join("head");
+        let head = extract_skill_at_ref(&skill_dir, plugin, "my-skill", "HEAD", &dest).unwrap();
+        assert_eq!(head.version, None);
         assert_eq!(
             std::fs::read_to_string(dest.join("SKILL.md")).unwrap(),
-            "# Tagged version"
+            "# V2"
         );
+
+        assert!(extract_skill_at_ref(&skill_dir, plugin, "my-skill", "9.9.9", &dest).is_err());
     }
 
     #[test]
@@ -2096,4 +3907,657 @@ mod per_skill_repo_tests {
         let err = result.unwrap_err();
         assert!(err.contains("No SKILL.md found"), "unexpected error: {err}");
     }
+
+    #[test]
+    fn test_diff_skill_versions_reports_modified_added_and_removed_files() {
+        let dir = tempdir().unwrap();
+        let skill_dir = dir.path();
+        std::fs::create_dir_all(skill_dir.join("references")).unwrap();
+        std::fs::write(skill_dir.join("references").join("old.md"), "old ref\n").unwrap();
+        let from = init_per_skill_repo(skill_dir, "# Skill\n\nStep one\nStep two\n");
+
+        std::fs::write(
+            skill_dir.join("SKILL.md"),
+            "# Skill\n\nStep one\nStep 2\nStep three\n",
+        )
+        .unwrap();
+        std::fs::remove_file(skill_dir.join("references").join("old.md")).unwrap();
+        std::fs::write(skill_dir.join("references").join("new.md"), "new ref\n").unwrap();
+        let to = commit_all(skill_dir, "edit skill").unwrap().unwrap();
+
+        let diff = diff_skill_versions(
+            skill_dir,
+            crate::skill_paths::DEFAULT_PLUGIN_SLUG,
+            "my-skill",
+            &from,
+            &to,
+        )
+        .unwrap();
+        assert_eq!(diff.from_sha, from);
+        assert_eq!(diff.to_sha, to);
+
+        let paths: Vec<_> = diff.files.iter().map(|f| f.path.as_str()).collect();
+        assert_eq!(
+            paths,
+            vec!["SKILL.md", "references/new.md", "references/old.md"]
+        );
+
+        let skill_md = &diff.files[0];
+        assert_eq!(skill_md.status, "modified");
+        assert_eq!(skill_md.additions, 2);
+        assert_eq!(skill_md.deletions, 1);
+        assert_eq!(skill_md.hunks.len(), 1);
+        let hunk = &skill_md.hunks[0];
+        assert_eq!(hunk.old_start, 1);
+        assert!(hunk
+            .lines
+            .iter()
+            .any(|l| l.kind == "removed" && l.content == "Step two" && l.old_lineno == Some(4)));
+        assert!(hunk
+            .lines
+            .iter()
+            .any(|l| l.kind == "added" && l.content == "Step three" && l.new_lineno == Some(5)));
+        assert!(skill_md.diff.contains("-Step two"));
+        assert!(skill_md.diff.contains("+Step 2"));
+
+        assert_eq!(diff.files[1].status, "added");
+        assert_eq!(diff.files[1].additions, 1);
+        assert_eq!(diff.files[2].status, "removed");
+        assert_eq!(diff.files[2].deletions, 1);
+    }
+
+    #[test]
+    fn test_diff_skill_versions_resolves_version_tags() {
+        let dir = tempdir().unwrap();
+        let skill_dir = dir.path();
+        init_per_skill_repo(skill_dir, "# V1\n");
+        create_skill_version_tag(
+            skill_dir,
+            crate::skill_paths::DEFAULT_PLUGIN_SLUG,
+            "my-skill",
+            "1.0.0",
+        )
+        .unwrap();
+        std::fs::write(skill_dir.join("SKILL.md"), "# V2\n").unwrap();
+        commit_all(skill_dir, "v2").unwrap();
+        create_skill_version_tag(
+            skill_dir,
+            crate::skill_paths::DEFAULT_PLUGIN_SLUG,
+            "my-skill",
+            "1.1.0",
+        )
+        .unwrap();
+
+        let diff = diff_skill_versions(
+            skill_dir,
+            crate::skill_paths::DEFAULT_PLUGIN_SLUG,
+            "my-skill",
+            "1.0.0",
+            "1.1.0",
+        )
+        .unwrap();
+        assert_eq!(diff.files.len(), 1);
+        assert_eq!(diff.files[0].path, "SKILL.md");
+        assert_eq!(diff.files[0].additions, 1);
+        assert_eq!(diff.files[0].deletions, 1);
+
+        let same = diff_skill_versions(
+            skill_dir,
+            crate::skill_paths::DEFAULT_PLUGIN_SLUG,
+            "my-skill",
+            "1.1.0",
+            "HEAD",
+        )
+        .unwrap();
+        assert!(
+            same.files.is_empty(),
+            "identical versions should have no files"
+        );
+    }
+
+    #[test]
+    fn test_diff_skill_versions_rejects_unknown_ref() {
+        let dir = tempdir().unwrap();
+        let skill_dir = dir.path();
+        let sha = init_per_skill_repo(skill_dir, "# V1\n");
+
+        let err = diff_skill_versions(
+            skill_dir,
+            crate::skill_paths::DEFAULT_PLUGIN_SLUG,
+            "my-skill",
+            &sha,
+            "9.9.9",
+        )
+        .unwrap_err();
+        assert!(
+            err.contains("Unknown version or commit"),
+            "unexpected error: {err}"
+        );
+    }
+
+    fn suggest(skill_dir: &std::path::Path) -> VersionBumpSuggestion {
+        suggest_version_bump(
+            skill_dir,
+            crate::skill_paths::DEFAULT_PLUGIN_SLUG,
+            "my-skill",
+        )
+        .unwrap()
+    }
+
+    fn skill_body(lines: usize, label: &str) -> String {
+        let mut body = String::from("# Skill\n");
+        for i in 0..lines {
+            body.push_str(&format!("{} line {}\n", label, i));
+        }
+        body
+    }
+
+    #[test]
+    fn test_suggest_version_bump_from_diff_since_latest_tag() {
+        let dir = tempdir().unwrap();
+        let skill_dir = dir.path();
+        init_per_skill_repo(skill_dir, &skill_body(10, "original"));
+
+        let first = suggest(skill_dir);
+        assert_eq!(first.bump, VersionBump::Major);
+        assert_eq!(first.next_version, "1.0.0");
+
+        let plugin = crate::skill_paths::DEFAULT_PLUGIN_SLUG;
+        create_skill_version_tag(skill_dir, plugin, "my-skill", "1.0.0").unwrap();
+        let unchanged = suggest(skill_dir);
+        assert_eq!(unchanged.bump, VersionBump::Patch);
+        assert!(unchanged.reasons[0].contains("No changes"));
+
+        let mut small_edit = skill_body(10, "original");
+        small_edit.push_str("one more line\n");
+        std::fs::write(skill_dir.join("SKILL.md"), &small_edit).unwrap();
+        commit_all(skill_dir, "my-skill: tweak").unwrap();
+        let patch = suggest(skill_dir);
+        assert_eq!(patch.bump, VersionBump::Patch);
+        assert_eq!(patch.next_version, "1.0.1");
+
+        std::fs::create_dir_all(skill_dir.join("references")).unwrap();
+        std::fs::write(skill_dir.join("references").join("api.md"), "# API\n").unwrap();
+        commit_all(skill_dir, "my-skill: add api reference").unwrap();
+        let minor = suggest(skill_dir);
+        assert_eq!(minor.bump, VersionBump::Minor);
+        assert_eq!(minor.next_version, "1.1.0");
+        assert!(minor.reasons[0].contains("references/api.md"));
+
+        std::fs::write(skill_dir.join("SKILL.md"), skill_body(10, "rewritten")).unwrap();
+        commit_all(skill_dir, "my-skill: rewrite").unwrap();
+        let major = suggest(skill_dir);
+        assert_eq!(major.bump, VersionBump::Major);
+        assert_eq!(major.next_version, "2.0.0");
+        assert!(major.reasons[0].contains("SKILL.md rewritten"));
+    }
+
+    #[test]
+    fn test_release_skill_version_tags_and_writes_changelog() {
+        let dir = tempdir().unwrap();
+        let skill_dir = dir.path();
+        let plugin = crate::skill_paths::DEFAULT_PLUGIN_SLUG;
+        init_per_skill_repo(skill_dir, "# V1\n");
+
+        let first = release_skill_version(skill_dir, plugin, "my-skill", None).unwrap();
+        assert_eq!(first.version, "1.0.0");
+        assert_eq!(first.previous_version, None);
+
+        std::fs::write(skill_dir.join("SKILL.md"), "# V1\n\nMore detail\n").unwrap();
+        commit_all(skill_dir, "my-skill: clarify steps").unwrap();
+        std::fs::write(skill_dir.join("notes.md"), "notes\n").unwrap();
+        commit_all(skill_dir, "add notes").unwrap();
+
+        let second =
+            release_skill_version(skill_dir, plugin, "my-skill", Some(VersionBump::Minor)).unwrap();
+        assert_eq!(second.version, "1.1.0");
+        assert_eq!(second.previous_version.as_deref(), Some("1.0.0"));
+        assert_eq!(second.bump, VersionBump::Minor);
+        assert!(skill_version_tag_exists(skill_dir, plugin, "my-skill", "1.1.0").unwrap());
+
+        let changelog = std::fs::read_to_string(skill_dir.join(CHANGELOG_FILE)).unwrap();
+        let newest = changelog.find("## 1.1.0").expect("1.1.0 section");
+        let oldest = changelog.find("## 1.0.0").expect("1.0.0 section");
+        assert!(newest < oldest, "newest version first:\n{changelog}");
+        let section_1_1 = &changelog[newest..oldest];
+        assert!(section_1_1.contains("- clarify steps\n"));
+        assert!(section_1_1.contains("- add notes\n"));
+        assert!(
+            !changelog.contains("release v"),
+            "release commits are omitted:\n{changelog}"
+        );
+        assert!(changelog[oldest..].contains("- generated skill\n"));
+
+        // The tagged commit includes the updated changelog.
+        let repo = Repository::open(skill_dir).unwrap();
+        let blob = repo
+            .revparse_single(&format!("{}:{}", second.tag, CHANGELOG_FILE))
+            .unwrap()
+            .peel_to_blob()
+            .unwrap();
+        assert!(String::from_utf8_lossy(blob.content()).contains("## 1.1.0"));
+    }
+
+    #[test]
+    fn test_skill_draft_merge_fast_forwards_and_tags() {
+        let dir = tempdir().unwrap();
+        let skill_dir = dir.path();
+        let plugin = crate::skill_paths::DEFAULT_PLUGIN_SLUG;
+        init_per_skill_repo(skill_dir, "# V1\n");
+        release_skill_version(skill_dir, plugin, "my-skill", Some(VersionBump::Major)).unwrap();
+        let base_branch = current_branch_name(&Repository::open(skill_dir).unwrap()).unwrap();
+
+        let draft = start_skill_draft(skill_dir, plugin, "my-skill", "tighten-steps").unwrap();
+        assert_eq!(draft.branch, "draft/tighten-steps");
+        assert_eq!(draft.base_branch, base_branch);
+        assert_eq!(draft.ahead, 0);
+        assert!(start_skill_draft(skill_dir, plugin, "my-skill", "other")
+            .unwrap_err()
+            .contains("already in progress"));
+
+        std::fs::write(skill_dir.join("SKILL.md"), "# V1\n\nTighter steps\n").unwrap();
+        commit_all(skill_dir, "my-skill: refine turn").unwrap();
+        let status = skill_draft_status(skill_dir, plugin, "my-skill")
+            .unwrap()
+            .unwrap();
+        assert_eq!(status.ahead, 1);
+        assert_eq!(status.files.len(), 1);
+        assert_eq!(status.files[0].path, "SKILL.md");
+
+        // The draft benchmarks against the base branch tip, not the tags.
+        let workspace = tempdir().unwrap();
+        let baseline = resolve_benchmark_baseline(skill_dir, plugin, "my-skill", workspace.path());
+        assert_eq!(baseline.mode, "draft_base");
+        assert_eq!(
+            std::fs::read_to_string(workspace.path().join("skill-snapshot/SKILL.md")).unwrap(),
+            "# V1\n"
+        );
+
+        let merge =
+            merge_skill_draft(skill_dir, plugin, "my-skill", Some(VersionBump::Minor)).unwrap();
+        assert!(merge.fast_forward);
+        assert_eq!(merge.release.version, "1.1.0");
+        assert!(skill_draft_status(skill_dir, plugin, "my-skill")
+            .unwrap()
+            .is_none());
+        let repo = Repository::open(skill_dir).unwrap();
+        assert_eq!(current_branch_name(&repo).unwrap(), base_branch);
+        assert!(repo
+            .find_branch("draft/tighten-steps", git2::BranchType::Local)
+            .is_err());
+        assert!(std::fs::read_to_string(skill_dir.join(CHANGELOG_FILE))
+            .unwrap()
+            .contains("- refine turn\n"));
+    }
+
+    #[test]
+    fn test_skill_draft_merge_creates_merge_commit_when_base_moved() {
+        let dir = tempdir().unwrap();
+        let skill_dir = dir.path();
+        let plugin = crate::skill_paths::DEFAULT_PLUGIN_SLUG;
+        init_per_skill_repo(skill_dir, "# V1\n");
+
+        let draft = start_skill_draft(skill_dir, plugin, "my-skill", "refs").unwrap();
+        std::fs::write(skill_dir.join("guide.md"), "guide\n").unwrap();
+        commit_all(skill_dir, "my-skill: add guide").unwrap();
+
+        // Advance the base branch behind the draft's back.
+        let repo = Repository::open(skill_dir).unwrap();
+        let base = branch_tip(&repo, &draft.base_branch).unwrap();
+        let sig = default_signature(&repo).unwrap();
+        let mut builder = repo.treebuilder(Some(&base.tree().unwrap())).unwrap();
+        let blob = repo.blob(b"notes\n").unwrap();
+        builder.insert("notes.md", blob, 0o100644).unwrap();
+        let tree = repo.find_tree(builder.write().unwrap()).unwrap();
+        repo.commit(
+            Some(&format!("refs/heads/{}", draft.base_branch)),
+            &sig,
+            &sig,
+            "my-skill: add notes",
+            &tree,
+            &[&base],
+        )
+        .unwrap();
+
+        let merge = merge_skill_draft(skill_dir, plugin, "my-skill", None).unwrap();
+        assert!(!merge.fast_forward);
+        assert_eq!(
+            repo.find_commit(git2::Oid::from_str(&merge.merged_sha).unwrap())
+                .unwrap()
+                .parent_count(),
+            2
+        );
+        assert!(skill_dir.join("guide.md").exists());
+        assert!(skill_dir.join("notes.md").exists());
+    }
+
+    #[test]
+    fn test_discard_skill_draft_restores_base_branch() {
+        let dir = tempdir().unwrap();
+        let skill_dir = dir.path();
+        let plugin = crate::skill_paths::DEFAULT_PLUGIN_SLUG;
+        let base_sha = init_per_skill_repo(skill_dir, "# V1\n");
+
+        start_skill_draft(skill_dir, plugin, "my-skill", "experiment").unwrap();
+        std::fs::write(skill_dir.join("SKILL.md"), "# Rewritten\n").unwrap();
+        commit_all(skill_dir, "my-skill: rewrite").unwrap();
+        std::fs::write(skill_dir.join("scratch.md"), "uncommitted\n").unwrap();
+
+        discard_skill_draft(skill_dir).unwrap();
+
+        let repo = Repository::open(skill_dir).unwrap();
+        assert_eq!(repo.head().unwrap().target().unwrap().to_string(), base_sha);
+        assert_eq!(
+            std::fs::read_to_string(skill_dir.join("SKILL.md")).unwrap(),
+            "# V1\n"
+        );
+        assert!(!skill_dir.join("scratch.md").exists());
+        assert!(repo
+            .find_branch("draft/experiment", git2::BranchType::Local)
+            .is_err());
+        assert!(discard_skill_draft(skill_dir)
+            .unwrap_err()
+            .contains("No draft"));
+        assert!(!get_history(skill_dir, "my-skill", plugin, 10)
+            .unwrap()
+            .iter()
+            .any(|c| c.message.contains("rewrite")));
+    }
+
+    const UPSTREAM_SKILL_V1: &str =
+        "# Skill\n\nIntro\n\nContext\nmore\ncontext\n\n## Steps\n1. a\n";
+
+    fn write_incoming(dir: &std::path::Path, files: &[(&str, &str)]) {
+        for (path, content) in files {
+            let target = dir.join(path);
+            std::fs::create_dir_all(target.parent().unwrap()).unwrap();
+            std::fs::write(target, content).unwrap();
+        }
+    }
+
+    #[test]
+    fn test_upstream_update_merges_non_overlapping_changes() {
+        let dir = tempdir().unwrap();
+        let skill_dir = dir.path();
+        let plugin = crate::skill_paths::DEFAULT_PLUGIN_SLUG;
+        ensure_repo(skill_dir).unwrap();
+        write_incoming(
+            skill_dir,
+            &[("SKILL.md", UPSTREAM_SKILL_V1), ("guide.md", "guide\n")],
+        );
+        commit_all(skill_dir, "my-skill: import from marketplace").unwrap();
+        create_skill_version_tag(skill_dir, plugin, "my-skill", "1.0.0").unwrap();
+        record_upstream_import(skill_dir).unwrap();
+
+        // Local edit near the top, left uncommitted.
+        std::fs::write(
+            skill_dir.join("SKILL.md"),
+            UPSTREAM_SKILL_V1.replace("Intro", "Intro, customized"),
+        )
+        .unwrap();
+
+        let incoming = tempdir().unwrap();
+        write_incoming(
+            incoming.path(),
+            &[
+                ("SKILL.md", &format!("{}2. b\n", UPSTREAM_SKILL_V1)),
+                ("guide.md", "guide\n"),
+                ("references/api.md", "api\n"),
+            ],
+        );
+        let upstream_sha = stage_upstream_update(
+            skill_dir,
+            plugin,
+            "my-skill",
+            incoming.path(),
+            "1.1.0",
+            None,
+            Some("0123456789abcdef0123456789abcdef01234567"),
+        )
+        .unwrap();
+        assert_eq!(
+            upstream_source_commit(skill_dir, &upstream_sha)
+                .unwrap()
+                .as_deref(),
+            Some("0123456789abcdef0123456789abcdef01234567")
+        );
+        let digests = file_digests_at_commit(skill_dir, &upstream_sha).unwrap();
+        assert!(digests.contains_key("SKILL.md"));
+        assert!(digests.contains_key("references/api.md"));
+        let preview =
+            preview_skill_update_merge(skill_dir, "my-skill", "1.1.0", &upstream_sha).unwrap();
+        assert!(preview.conflicts.is_empty());
+        assert!(preview.auto_merged.contains(&"SKILL.md".to_string()));
+        assert!(preview
+            .auto_merged
+            .contains(&"references/api.md".to_string()));
+        assert!(!preview.auto_merged.contains(&".gitignore".to_string()));
+        // Previewing moves no ref and leaves the local edit uncommitted.
+        let repo = Repository::open(skill_dir).unwrap();
+        let imported = repo.head().unwrap().peel_to_commit().unwrap();
+        assert_eq!(
+            imported.message(),
+            Some("my-skill: import from marketplace")
+        );
+        assert_eq!(
+            branch_tip(&repo, UPSTREAM_BRANCH).unwrap().id(),
+            imported.id()
+        );
+        assert!(has_uncommitted_changes(&repo, &[]).unwrap());
+        assert_ne!(preview.head_sha, imported.id().to_string());
+
+        let applied = apply_skill_update_merge(
+            skill_dir,
+            plugin,
+            "my-skill",
+            "1.1.0",
+            &preview.head_sha,
+            &upstream_sha,
+            &std::collections::HashMap::new(),
+        )
+        .unwrap();
+        assert_eq!(
+            applied.tag,
+            skill_version_tag_name(plugin, "my-skill", "1.1.0")
+        );
+        let merged = std::fs::read_to_string(skill_dir.join("SKILL.md")).unwrap();
+        assert!(merged.contains("Intro, customized"));
+        assert!(merged.contains("2. b"));
+        assert!(skill_dir.join("references/api.md").exists());
+        assert!(skill_dir.join(".gitignore").exists());
+        let head = repo.head().unwrap().peel_to_commit().unwrap();
+        assert_eq!(head.parent_count(), 2);
+        // The previewed checkpoint became the local parent of the merge.
+        let checkpoint = head.parent(0).unwrap();
+        assert_eq!(checkpoint.id().to_string(), preview.head_sha);
+        assert_eq!(checkpoint.parent_id(0).unwrap(), imported.id());
+        assert_eq!(
+            branch_tip(&repo, UPSTREAM_BRANCH).unwrap().id().to_string(),
+            upstream_sha
+        );
+        assert!(!has_uncommitted_changes(&repo, &[]).unwrap());
+    }
+
+    #[test]
+    fn test_upstream_update_surfaces_conflicts_for_review() {
+        let dir = tempdir().unwrap();
+        let skill_dir = dir.path();
+        let plugin = crate::skill_paths::DEFAULT_PLUGIN_SLUG;
+        // Imported before the upstream branch existed: only the version tag
+        // marks the original import.
+        init_per_skill_repo(skill_dir, UPSTREAM_SKILL_V1);
+        create_skill_version_tag(skill_dir, plugin, "my-skill", "1.0.0").unwrap();
+        std::fs::write(
+            skill_dir.join("SKILL.md"),
+            UPSTREAM_SKILL_V1.replace("1. a", "1. local"),
+        )
+        .unwrap();
+        #[cfg(unix)]
+        {
+            use std::os::unix::fs::PermissionsExt;
+            std::fs::set_permissions(
+                skill_dir.join("SKILL.md"),
+                std::fs::Permissions::from_mode(0o755),
+            )
+            .unwrap();
+        }
+        commit_all(skill_dir, "my-skill: local step").unwrap();
+
+        let incoming = tempdir().unwrap();
+        write_incoming(
+            incoming.path(),
+            &[(
+                "SKILL.md",
+                &UPSTREAM_SKILL_V1.replace("1. a", "1. upstream"),
+            )],
+        );
+        assert!(stage_upstream_update(
+            skill_dir,
+            plugin,
+            "my-skill",
+            incoming.path(),
+            "1.1.0",
+            None,
+            None
+        )
+        .unwrap_err()
+        .contains("No imported baseline"));
+        let upstream_sha = stage_upstream_update(
+            skill_dir,
+            plugin,
+            "my-skill",
+            incoming.path(),
+            "1.1.0",
+            Some("1.0.0"),
+            None,
+        )
+        .unwrap();
+        let preview =
+            preview_skill_update_merge(skill_dir, "my-skill", "1.1.0", &upstream_sha).unwrap();
+        assert_eq!(preview.conflicts.len(), 1);
+        let conflict = &preview.conflicts[0];
+        assert_eq!(conflict.path, "SKILL.md");
+        assert!(conflict.local.as_deref().unwrap().contains("1. local"));
+        assert!(conflict
+            .upstream
+            .as_deref()
+            .unwrap()
+            .contains("1. upstream"));
+        assert!(conflict.merged.contains("<<<<<<< local"));
+        assert!(conflict.merged.contains(">>>>>>> upstream"));
+
+        let apply = |resolutions: &[(&str, Option<&str>)]| {
+            apply_skill_update_merge(
+                skill_dir,
+                plugin,
+                "my-skill",
+                "1.1.0",
+                &preview.head_sha,
+                &upstream_sha,
+                &resolutions
+                    .iter()
+                    .map(|(p, c)| (p.to_string(), c.map(str::to_string)))
+                    .collect(),
+            )
+        };
+        assert!(apply(&[]).unwrap_err().contains("Unresolved conflict"));
+        assert!(apply(&[("SKILL.md", Some(&conflict.merged))])
+            .unwrap_err()
+            .contains("conflict markers"));
+        // Nothing was committed or tagged by the rejected attempts.
+        assert!(!skill_version_tag_exists(skill_dir, plugin, "my-skill", "1.1.0").unwrap());
+
+        let resolved = UPSTREAM_SKILL_V1.replace("1. a", "1. local\n2. upstream");
+        // Work saved after the preview would be wiped by the forced checkout.
+        std::fs::write(skill_dir.join("scratch.md"), "unsaved\n").unwrap();
+        assert!(apply(&[("SKILL.md", Some(&resolved))])
+            .unwrap_err()
+            .contains("uncommitted changes"));
+        assert!(skill_dir.join("scratch.md").exists());
+        std::fs::remove_file(skill_dir.join("scratch.md")).unwrap();
+
+        apply(&[("SKILL.md", Some(&resolved))]).unwrap();
+        assert_eq!(
+            std::fs::read_to_string(skill_dir.join("SKILL.md")).unwrap(),
+            resolved
+        );
+        #[cfg(unix)]
+        {
+            let repo = Repository::open(skill_dir).unwrap();
+            let head = repo.head().unwrap().peel_to_commit().unwrap();
+            let entry = head
+                .tree()
+                .unwrap()
+                .get_path(Path::new("SKILL.md"))
+                .unwrap();
+            assert_eq!(entry.filemode() as u32, EXECUTABLE_FILE_MODE);
+        }
+        assert!(skill_version_tag_exists(skill_dir, plugin, "my-skill", "1.1.0").unwrap());
+        assert!(apply(&[("SKILL.md", Some(&resolved))])
+            .unwrap_err()
+            .contains("changed since"));
+    }
+
+    #[test]
+    fn test_history_bundle_round_trips_branches_and_tags_but_not_drafts() {
+        let dir = tempdir().unwrap();
+        let skill_dir = dir.path().join("source");
+        let plugin = crate::skill_paths::DEFAULT_PLUGIN_SLUG;
+        init_per_skill_repo(&skill_dir, "# V1\n");
+        release_skill_version(&skill_dir, plugin, "my-skill", Some(VersionBump::Major)).unwrap();
+        std::fs::write(skill_dir.join("SKILL.md"), "# V2\n").unwrap();
+        commit_all(&skill_dir, "my-skill: second pass").unwrap();
+        release_skill_version(&skill_dir, plugin, "my-skill", Some(VersionBump::Minor)).unwrap();
+        let base_branch = current_branch_name(&Repository::open(&skill_dir).unwrap()).unwrap();
+        let released: Vec<String> = get_history(&skill_dir, "my-skill", plugin, 50)
+            .unwrap()
+            .into_iter()
+            .map(|c| c.sha)
+            .collect();
+        start_skill_draft(&skill_dir, plugin, "my-skill", "wip").unwrap();
+        std::fs::write(skill_dir.join("SKILL.md"), "# Draft\n").unwrap();
+        commit_all(&skill_dir, "my-skill: draft turn").unwrap();
+
+        let bundle = dir.path().join(HISTORY_BUNDLE_FILE);
+        write_history_bundle(&skill_dir, &bundle).unwrap();
+        assert!(std::fs::read(&bundle)
+            .unwrap()
+            .starts_with(BUNDLE_SIGNATURE.as_bytes()));
+
+        // The receiving side has the exported files on disk but no repo yet.
+        let restored = dir.path().join("restored");
+        std::fs::create_dir_all(&restored).unwrap();
+        for file in ["SKILL.md", ".gitignore", CHANGELOG_FILE] {
+            std::fs::copy(skill_dir.join(file), restored.join(file)).unwrap();
+        }
+        assert_eq!(restore_history_bundle(&restored, &bundle).unwrap(), 2);
+        assert!(restore_history_bundle(&restored, &bundle)
+            .unwrap_err()
+            .contains("already has a git repo"));
+
+        // HEAD lands on the draft's base branch; the draft itself stays behind.
+        let repo = Repository::open(&restored).unwrap();
+        assert_eq!(current_branch_name(&repo).unwrap(), base_branch);
+        assert!(repo
+            .find_branch("draft/wip", git2::BranchType::Local)
+            .is_err());
+        let history = get_history(&restored, "my-skill", plugin, 50).unwrap();
+        assert_eq!(
+            history.iter().map(|c| c.sha.clone()).collect::<Vec<_>>(),
+            released
+        );
+        assert_eq!(history[0].version.as_deref(), Some("1.1.0"));
+        assert!(skill_version_tag_exists(&restored, plugin, "my-skill", "1.0.0").unwrap());
+
+        // The draft content on disk is left as a pending change.
+        assert!(commit_all(&restored, "my-skill: import").unwrap().is_some());
+
+        let workspace = tempdir().unwrap();
+        let baseline = resolve_benchmark_baseline(&restored, plugin, "my-skill", workspace.path());
+        assert_eq!(baseline.mode, "prior_version");
+        assert_eq!(
+            std::fs::read_to_string(workspace.path().join("skill-snapshot/SKILL.md")).unwrap(),
+            "# V1\n"
+        );
+    }
 }
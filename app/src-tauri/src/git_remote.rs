@@ -0,0 +1,544 @@
+//! Push, fetch and fast-forward pull for per-skill git repos.
+//!
+//! Each skill repo talks to a single remote named `origin`. The URL comes from
+//! `skill_git_remotes` and is written into the repo config on every sync, so
+//! changing it in the app takes effect on the next operation. Diverged
+//! histories are reported, never merged or force-pushed.
+
+use std::collections::HashMap;
+use std::path::Path;
+
+use git2::{
+    AutotagOption, Cred, CredentialType, FetchOptions, Oid, PushOptions, RemoteCallbacks,
+    Repository, StatusOptions,
+};
+
+use crate::types::SkillSyncResult;
+
+const REMOTE_NAME: &str = "origin";
+
+/// Fetch the remote branch and tags, then report ahead/behind counts without
+/// touching the working tree.
+pub fn fetch_skill_remote(
+    repo_path: &Path,
+    remote_url: &str,
+    github_token: Option<&str>,
+) -> Result<SkillSyncResult, String> {
+    let repo = open_skill_repo(repo_path)?;
+    let (branch, local) = current_branch(&repo)?;
+    let remote_tags = fetch_remote(&repo, remote_url, &branch, github_token)?;
+    let remote = remote_branch_tip(&repo, &branch);
+    let mut result = sync_state(&repo, &branch, local, remote)?;
+    if result.status == "diverged" {
+        result.conflicts.push(diverged_message(&branch, &result));
+    }
+    log::debug!(
+        "[git_remote] fetched {} ({} remote tags): {}",
+        remote_url,
+        remote_tags.len(),
+        result.status
+    );
+    Ok(result)
+}
+
+/// Fetch, then fast-forward the local branch when it is strictly behind.
+pub fn pull_skill_remote(
+    repo_path: &Path,
+    remote_url: &str,
+    github_token: Option<&str>,
+) -> Result<SkillSyncResult, String> {
+    let repo = open_skill_repo(repo_path)?;
+    let (branch, local) = current_branch(&repo)?;
+    fetch_remote(&repo, remote_url, &branch, github_token)?;
+    let remote = remote_branch_tip(&repo, &branch);
+    let mut result = sync_state(&repo, &branch, local, remote)?;
+
+    match result.status.as_str() {
+        "diverged" => {
+            result.conflicts.push(diverged_message(&branch, &result));
+            return Ok(result);
+        }
+        "behind" => {}
+        _ => return Ok(result),
+    }
+
+    ensure_clean_worktree(&repo)?;
+    let target_oid = remote.ok_or_else(|| format!("No remote tip for '{}' to pull", branch))?;
+    let target = repo
+        .find_commit(target_oid)
+        .map_err(|e| format!("Failed to find remote commit {}: {}", target_oid, e))?;
+    repo.checkout_tree(
+        target.as_object(),
+        Some(git2::build::CheckoutBuilder::new().safe()),
+    )
+    .map_err(|e| format!("Failed to check out {}: {}", target_oid, e))?;
+    repo.find_reference(&format!("refs/heads/{}", branch))
+        .and_then(|mut reference| reference.set_target(target_oid, "pull: fast-forward"))
+        .map_err(|e| format!("Failed to fast-forward '{}': {}", branch, e))?;
+
+    log::info!(
+        "[git_remote] fast-forwarded {} to {} ({} commits)",
+        branch,
+        &target_oid.to_string()[..8],
+        result.behind
+    );
+    result.status = "fast_forwarded".to_string();
+    result.local_sha = target_oid.to_string();
+    result.behind = 0;
+    Ok(result)
+}
+
+/// Push the current branch and any version tags the remote is missing.
+///
+/// Refuses when the remote branch has commits that are not local, and reports
+/// tags that exist remotely at a different commit instead of overwriting them.
+pub fn push_skill_remote(
+    repo_path: &Path,
+    remote_url: &str,
+    github_token: Option<&str>,
+) -> Result<SkillSyncResult, String> {
+    let repo = open_skill_repo(repo_path)?;
+    let (branch, local) = current_branch(&repo)?;
+    let remote_tags = fetch_remote(&repo, remote_url, &branch, github_token)?;
+    let remote = remote_branch_tip(&repo, &branch);
+    let mut result = sync_state(&repo, &branch, local, remote)?;
+
+    if result.behind > 0 {
+        if result.status == "diverged" {
+            result.conflicts.push(diverged_message(&branch, &result));
+        } else {
+            result.conflicts.push(format!(
+                "Remote '{}' has {} commit(s) not in the local repo; pull before pushing",
+                branch, result.behind
+            ));
+        }
+        return Ok(result);
+    }
+
+    let mut refspecs = Vec::new();
+    if result.ahead > 0 {
+        refspecs.push(format!("refs/heads/{0}:refs/heads/{0}", branch));
+    }
+    let mut new_tags = Vec::new();
+    for (tag, oid) in local_tags(&repo)? {
+        match remote_tags.get(&tag) {
+            None => {
+                refspecs.push(format!("refs/tags/{0}:refs/tags/{0}", tag));
+                new_tags.push(tag);
+            }
+            Some(remote_oid) if *remote_oid != oid => result.conflicts.push(format!(
+                "Tag '{}' points to a different commit on the remote",
+                tag
+            )),
+            Some(_) => {}
+        }
+    }
+
+    if refspecs.is_empty() {
+        return Ok(result);
+    }
+
+    let mut rejected = Vec::new();
+    {
+        let mut remote_handle = repo
+            .find_remote(REMOTE_NAME)
+            .map_err(|e| format!("Failed to open remote: {}", e))?;
+        let mut callbacks = remote_callbacks(github_token);
+        callbacks.push_update_reference(|reference, status| {
+            if let Some(message) = status {
+                rejected.push(format!("{} rejected: {}", reference, message));
+            }
+            Ok(())
+        });
+        let mut options = PushOptions::new();
+        options.remote_callbacks(callbacks);
+        remote_handle
+            .push(&refspecs, Some(&mut options))
+            .map_err(|e| format!("Failed to push to {}: {}", remote_url, e))?;
+    }
+
+    result.pushed_tags = new_tags
+        .into_iter()
+        .filter(|tag| {
+            !rejected
+                .iter()
+                .any(|r| r.starts_with(&format!("refs/tags/{} ", tag)))
+        })
+        .collect();
+    let branch_rejected = rejected
+        .iter()
+        .any(|r| r.starts_with(&format!("refs/heads/{} ", branch)));
+    result.conflicts.extend(rejected);
+    let branch_pushed = result.ahead > 0 && !branch_rejected;
+    if branch_pushed {
+        result.remote_sha = Some(result.local_sha.clone());
+        result.ahead = 0;
+    }
+    if branch_pushed || (!branch_rejected && !result.pushed_tags.is_empty()) {
+        result.status = "pushed".to_string();
+    }
+
+    log::info!(
+        "[git_remote] pushed {} to {} ({} tags, {} conflicts)",
+        branch,
+        remote_url,
+        result.pushed_tags.len(),
+        result.conflicts.len()
+    );
+    Ok(result)
+}
+
+fn open_skill_repo(repo_path: &Path) -> Result<Repository, String> {
+    if !repo_path.join(".git").exists() {
+        return Err(format!(
+            "No git repository for this skill at {}",
+            repo_path.display()
+        ));
+    }
+    Repository::open(repo_path)
+        .map_err(|e| format!("Failed to open git repo at {}: {}", repo_path.display(), e))
+}
+
+fn current_branch(repo: &Repository) -> Result<(String, Oid), String> {
+    let head = repo
+        .head()
+        .map_err(|e| format!("Failed to resolve HEAD: {}", e))?;
+    if !head.is_branch() {
+        return Err("HEAD is detached; check out a branch before syncing".to_string());
+    }
+    let branch = head
+        .shorthand()
+        .ok_or_else(|| "Branch name is not valid UTF-8".to_string())?
+        .to_string();
+    let oid = head
+        .target()
+        .ok_or_else(|| format!("Branch '{}' has no commits", branch))?;
+    Ok((branch, oid))
+}
+
+/// Point `origin` at `remote_url` and fetch the branch plus tags. Returns the
+/// remote's tags (name → target).
+fn fetch_remote(
+    repo: &Repository,
+    remote_url: &str,
+    branch: &str,
+    github_token: Option<&str>,
+) -> Result<HashMap<String, Oid>, String> {
+    let mut remote = match repo.find_remote(REMOTE_NAME) {
+        Ok(existing) if existing.url() == Some(remote_url) => existing,
+        Ok(_) => {
+            repo.remote_set_url(REMOTE_NAME, remote_url)
+                .map_err(|e| format!("Failed to update remote URL: {}", e))?;
+            repo.find_remote(REMOTE_NAME)
+                .map_err(|e| format!("Failed to open remote: {}", e))?
+        }
+        Err(_) => repo
+            .remote(REMOTE_NAME, remote_url)
+            .map_err(|e| format!("Failed to add remote: {}", e))?,
+    };
+
+    // Remote tags are mirrored under refs/remotes/origin/tags/ so push can see
+    // where each one points without trusting (or overwriting) local tags.
+    // Clear the mirror first so tags deleted remotely do not linger.
+    let tag_mirror = format!("refs/remotes/{}/tags/", REMOTE_NAME);
+    for mut reference in repo
+        .references_glob(&format!("{}*", tag_mirror))
+        .map_err(|e| format!("Failed to list remote tags: {}", e))?
+        .flatten()
+    {
+        let _ = reference.delete();
+    }
+
+    let mut options = FetchOptions::new();
+    options.remote_callbacks(remote_callbacks(github_token));
+    options.download_tags(AutotagOption::None);
+    let refspecs = [
+        format!("+refs/heads/{0}:refs/remotes/{1}/{0}", branch, REMOTE_NAME),
+        format!("+refs/tags/*:{}*", tag_mirror),
+    ];
+    remote
+        .fetch(&refspecs, Some(&mut options), None)
+        .map_err(|e| format!("Failed to fetch from {}: {}", remote_url, e))?;
+
+    let remote_tags: HashMap<String, Oid> = repo
+        .references_glob(&format!("{}*", tag_mirror))
+        .map_err(|e| format!("Failed to list remote tags: {}", e))?
+        .flatten()
+        .filter_map(|reference| {
+            let name = reference.name()?.strip_prefix(&tag_mirror)?.to_string();
+            Some((name, reference.target()?))
+        })
+        .collect();
+
+    // Bring in version tags created elsewhere; never move an existing local tag.
+    for (tag, oid) in &remote_tags {
+        let reference = format!("refs/tags/{}", tag);
+        if repo.find_reference(&reference).is_err() {
+            repo.reference(&reference, *oid, false, "fetch: remote tag")
+                .map_err(|e| format!("Failed to create tag '{}': {}", tag, e))?;
+        }
+    }
+    Ok(remote_tags)
+}
+
+fn remote_branch_tip(repo: &Repository, branch: &str) -> Option<Oid> {
+    repo.find_reference(&format!("refs/remotes/{}/{}", REMOTE_NAME, branch))
+        .ok()
+        .and_then(|reference| reference.target())
+}
+
+fn sync_state(
+    repo: &Repository,
+    branch: &str,
+    local: Oid,
+    remote: Option<Oid>,
+) -> Result<SkillSyncResult, String> {
+    let (ahead, behind) = match remote {
+        Some(remote) => repo
+            .graph_ahead_behind(local, remote)
+            .map_err(|e| format!("Failed to compare with remote: {}", e))?,
+        None => (count_commits(repo, local)?, 0),
+    };
+    let status = match (ahead, behind) {
+        (0, 0) => "up_to_date",
+        (_, 0) => "ahead",
+        (0, _) => "behind",
+        _ => "diverged",
+    };
+    Ok(SkillSyncResult {
+        status: status.to_string(),
+        branch: branch.to_string(),
+        local_sha: local.to_string(),
+        remote_sha: remote.map(|oid| oid.to_string()),
+        ahead,
+        behind,
+        pushed_tags: Vec::new(),
+        conflicts: Vec::new(),
+    })
+}
+
+fn diverged_message(branch: &str, result: &SkillSyncResult) -> String {
+    format!(
+        "Local and remote '{}' have diverged ({} local, {} remote commit(s)); resolve outside the app",
+        branch, result.ahead, result.behind
+    )
+}
+
+fn count_commits(repo: &Repository, from: Oid) -> Result<usize, String> {
+    let mut walk = repo
+        .revwalk()
+        .map_err(|e| format!("Failed to walk history: {}", e))?;
+    walk.push(from)
+        .map_err(|e| format!("Failed to walk history: {}", e))?;
+    Ok(walk.count())
+}
+
+fn local_tags(repo: &Repository) -> Result<Vec<(String, Oid)>, String> {
+    let names = repo
+        .tag_names(None)
+        .map_err(|e| format!("Failed to list tags: {}", e))?;
+    Ok(names
+        .iter()
+        .flatten()
+        .filter_map(|name| {
+            repo.find_reference(&format!("refs/tags/{}", name))
+                .ok()
+                .and_then(|reference| reference.target())
+                .map(|oid| (name.to_string(), oid))
+        })
+        .collect())
+}
+
+fn ensure_clean_worktree(repo: &Repository) -> Result<(), String> {
+    let mut opts = StatusOptions::new();
+    opts.include_untracked(false);
+    let statuses = repo
+        .statuses(Some(&mut opts))
+        .map_err(|e| format!("Failed to get statuses: {}", e))?;
+    let dirty: Vec<String> = statuses
+        .iter()
+        .filter(|entry| entry.status() != git2::Status::CURRENT)
+        .filter_map(|entry| entry.path().map(str::to_string))
+        .collect();
+    if dirty.is_empty() {
+        Ok(())
+    } else {
+        Err(format!(
+            "Commit or discard local changes before pulling: {}",
+            dirty.join(", ")
+        ))
+    }
+}
+
+/// SSH URLs authenticate through the agent. Callers pass a GitHub token only
+/// for HTTPS URLs on the host that issued it (see `GitHubAccess::token_for_url`).
+pub(crate) fn remote_callbacks(token: Option<&str>) -> RemoteCallbacks<'_> {
+    let mut attempts = 0;
+    let mut callbacks = RemoteCallbacks::new();
+    callbacks.credentials(move |url, username, allowed| {
+        attempts += 1;
+        if attempts > 1 {
+            return Err(git2::Error::from_str(&format!(
+                "Authentication failed for {}",
+                url
+            )));
+        }
+        if allowed.contains(CredentialType::SSH_KEY) {
+            return Cred::ssh_key_from_agent(username.unwrap_or("git"));
+        }
+        if let (Some(token), true) = (token, allowed.contains(CredentialType::USER_PASS_PLAINTEXT))
+        {
+            return Cred::userpass_plaintext("x-access-token", token);
+        }
+        Cred::default()
+    });
+    callbacks
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::git::{commit_all, create_skill_version_tag, ensure_repo};
+    use tempfile::tempdir;
+
+    const PLUGIN: &str = crate::skill_paths::DEFAULT_PLUGIN_SLUG;
+
+    fn init_skill(dir: &Path, content: &str) {
+        ensure_repo(dir).unwrap();
+        std::fs::write(dir.join("SKILL.md"), content).unwrap();
+        commit_all(dir, "generated skill").unwrap();
+    }
+
+    fn init_bare(dir: &Path) -> String {
+        Repository::init_bare(dir).unwrap();
+        dir.to_string_lossy().to_string()
+    }
+
+    #[test]
+    fn test_push_to_empty_bare_remote_sends_branch_and_tags() {
+        let dir = tempdir().unwrap();
+        let skill_dir = dir.path().join("skill");
+        let remote = init_bare(&dir.path().join("remote.git"));
+        init_skill(&skill_dir, "# V1");
+        let tag = create_skill_version_tag(&skill_dir, PLUGIN, "my-skill", "1.0.0").unwrap();
+
+        let result = push_skill_remote(&skill_dir, &remote, None).unwrap();
+        assert_eq!(result.status, "pushed");
+        assert_eq!(result.ahead, 0);
+        assert_eq!(result.pushed_tags, vec![tag.clone()]);
+        assert!(result.conflicts.is_empty(), "{:?}", result.conflicts);
+
+        let bare = Repository::open_bare(&remote).unwrap();
+        let remote_head = bare
+            .find_reference(&format!("refs/heads/{}", result.branch))
+            .unwrap()
+            .target()
+            .unwrap();
+        assert_eq!(remote_head.to_string(), result.local_sha);
+        assert!(bare.find_reference(&format!("refs/tags/{}", tag)).is_ok());
+
+        let again = push_skill_remote(&skill_dir, &remote, None).unwrap();
+        assert_eq!(again.status, "up_to_date");
+        assert!(again.pushed_tags.is_empty());
+    }
+
+    #[test]
+    fn test_fetch_and_pull_fast_forward_from_another_clone() {
+        let dir = tempdir().unwrap();
+        let skill_dir = dir.path().join("skill");
+        let remote = init_bare(&dir.path().join("remote.git"));
+        init_skill(&skill_dir, "# V1");
+        push_skill_remote(&skill_dir, &remote, None).unwrap();
+
+        let teammate = dir.path().join("teammate");
+        Repository::clone(&remote, &teammate).unwrap();
+        std::fs::write(teammate.join("SKILL.md"), "# V2 from teammate").unwrap();
+        commit_all(&teammate, "teammate edit").unwrap();
+        let tag = create_skill_version_tag(&teammate, PLUGIN, "my-skill", "1.1.0").unwrap();
+        push_skill_remote(&teammate, &remote, None).unwrap();
+
+        let fetched = fetch_skill_remote(&skill_dir, &remote, None).unwrap();
+        assert_eq!(fetched.status, "behind");
+        assert_eq!(fetched.behind, 1);
+        assert!(Repository::open(&skill_dir)
+            .unwrap()
+            .find_reference(&format!("refs/tags/{}", tag))
+            .is_ok());
+        assert_eq!(
+            std::fs::read_to_string(skill_dir.join("SKILL.md")).unwrap(),
+            "# V1",
+            "fetch must not touch the working tree"
+        );
+
+        let pulled = pull_skill_remote(&skill_dir, &remote, None).unwrap();
+        assert_eq!(pulled.status, "fast_forwarded");
+        assert_eq!(pulled.behind, 0);
+        assert_eq!(Some(pulled.local_sha.clone()), pulled.remote_sha);
+        assert_eq!(
+            std::fs::read_to_string(skill_dir.join("SKILL.md")).unwrap(),
+            "# V2 from teammate"
+        );
+    }
+
+    #[test]
+    fn test_diverged_history_is_reported_not_merged() {
+        let dir = tempdir().unwrap();
+        let skill_dir = dir.path().join("skill");
+        let remote = init_bare(&dir.path().join("remote.git"));
+        init_skill(&skill_dir, "# V1");
+        push_skill_remote(&skill_dir, &remote, None).unwrap();
+
+        let teammate = dir.path().join("teammate");
+        Repository::clone(&remote, &teammate).unwrap();
+        std::fs::write(teammate.join("SKILL.md"), "# Remote edit").unwrap();
+        commit_all(&teammate, "remote edit").unwrap();
+        push_skill_remote(&teammate, &remote, None).unwrap();
+
+        std::fs::write(skill_dir.join("SKILL.md"), "# Local edit").unwrap();
+        let local_sha = commit_all(&skill_dir, "local edit").unwrap().unwrap();
+
+        let pushed = push_skill_remote(&skill_dir, &remote, None).unwrap();
+        assert_eq!(pushed.status, "diverged");
+        assert_eq!((pushed.ahead, pushed.behind), (1, 1));
+        assert!(pushed.conflicts[0].contains("diverged"));
+
+        let pulled = pull_skill_remote(&skill_dir, &remote, None).unwrap();
+        assert_eq!(pulled.status, "diverged");
+        assert_eq!(pulled.local_sha, local_sha);
+        assert_eq!(
+            std::fs::read_to_string(skill_dir.join("SKILL.md")).unwrap(),
+            "# Local edit"
+        );
+    }
+
+    #[test]
+    fn test_push_reports_tag_that_moved_on_remote() {
+        let dir = tempdir().unwrap();
+        let skill_dir = dir.path().join("skill");
+        let remote = init_bare(&dir.path().join("remote.git"));
+        init_skill(&skill_dir, "# V1");
+        let tag = create_skill_version_tag(&skill_dir, PLUGIN, "my-skill", "1.0.0").unwrap();
+        push_skill_remote(&skill_dir, &remote, None).unwrap();
+
+        // Re-tag 1.0.0 locally at a newer commit.
+        let repo = Repository::open(&skill_dir).unwrap();
+        repo.tag_delete(&tag).unwrap();
+        std::fs::write(skill_dir.join("SKILL.md"), "# V1 again").unwrap();
+        commit_all(&skill_dir, "retag").unwrap();
+        create_skill_version_tag(&skill_dir, PLUGIN, "my-skill", "1.0.0").unwrap();
+
+        let result = push_skill_remote(&skill_dir, &remote, None).unwrap();
+        assert_eq!(result.status, "pushed", "branch still pushes");
+        assert!(result.pushed_tags.is_empty());
+        assert_eq!(result.conflicts.len(), 1);
+        assert!(result.conflicts[0].contains(&tag));
+    }
+
+    #[test]
+    fn test_sync_requires_skill_repo() {
+        let dir = tempdir().unwrap();
+        let remote = init_bare(&dir.path().join("remote.git"));
+        let err = fetch_skill_remote(&dir.path().join("missing"), &remote, None).unwrap_err();
+        assert!(err.contains("No git repository"), "unexpected error: {err}");
+    }
+}
@@ -0,0 +1,269 @@
+//! GitHub endpoints for github.com or a GitHub Enterprise Server instance.
+//!
+//! `AppSettings::github_host` holds a bare host name (`github.example.com`);
+//! `None` means github.com. Every GitHub call — device flow, REST API, raw file
+//! downloads, feedback issues — builds its URLs from a [`GitHubHost`].
+
+use crate::types::{AppSettings, SourceHostToken};
+
+pub(crate) const PUBLIC_GITHUB_HOST: &str = "github.com";
+
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub(crate) struct GitHubHost {
+    /// Host name without scheme, e.g. `github.com` or `github.example.com`.
+    pub host: String,
+    /// Web root used for the OAuth device flow and clone URLs.
+    pub web_base: String,
+    /// REST API root (`https://api.github.com` or `https://{host}/api/v3`).
+    pub api_base: String,
+    /// Root for raw file downloads, followed by `/{owner}/{repo}/{ref}/{path}`.
+    pub raw_base: String,
+}
+
+impl GitHubHost {
+    pub(crate) fn public() -> Self {
+        GitHubHost {
+            host: PUBLIC_GITHUB_HOST.to_string(),
+            web_base: "https://github.com".to_string(),
+            api_base: "https://api.github.com".to_string(),
+            raw_base: "https://raw.githubusercontent.com".to_string(),
+        }
+    }
+
+    /// A GitHub Enterprise Server instance. GHES serves the REST API under
+    /// `/api/v3` and raw files under `/raw` on the same host.
+    pub(crate) fn enterprise(host: &str) -> Self {
+        let web_base = format!("https://{}", host);
+        GitHubHost {
+            host: host.to_string(),
+            api_base: format!("{}/api/v3", web_base),
+            raw_base: format!("{}/raw", web_base),
+            web_base,
+        }
+    }
+
+    /// Resolve the `github_host` setting. Empty or `github.com` means public GitHub.
+    pub(crate) fn from_setting(host: Option<&str>) -> Self {
+        match host.map(str::trim).filter(|h| !h.is_empty()) {
+            Some(h) if !h.eq_ignore_ascii_case(PUBLIC_GITHUB_HOST) => Self::enterprise(h),
+            _ => Self::public(),
+        }
+    }
+
+    pub(crate) fn from_settings(settings: &AppSettings) -> Self {
+        Self::from_setting(settings.github_host.as_deref())
+    }
+
+    /// Every endpoint rooted at a mock server.
+    #[cfg(test)]
+    pub(crate) fn mock(base_url: &str) -> Self {
+        let base = base_url.trim_end_matches('/');
+        GitHubHost {
+            host: base
+                .split_once("://")
+                .map_or(base, |(_, rest)| rest)
+                .to_string(),
+            web_base: base.to_string(),
+            api_base: base.to_string(),
+            raw_base: base.to_string(),
+        }
+    }
+
+    pub(crate) fn is_public(&self) -> bool {
+        self.host == PUBLIC_GITHUB_HOST
+    }
+
+    /// Whether `host` (as found in a URL, case-insensitive) is this GitHub host.
+    pub(crate) fn matches_host(&self, host: &str) -> bool {
+        host.eq_ignore_ascii_case(&self.host)
+    }
+}
+
+/// The configured GitHub host together with the OAuth token it issued, plus
+/// the tokens configured for other git hosts.
+#[derive(Clone)]
+pub(crate) struct GitHubAccess {
+    pub host: GitHubHost,
+    pub token: Option<String>,
+    pub source_tokens: Vec<SourceHostToken>,
+}
+
+impl GitHubAccess {
+    pub(crate) fn from_settings(settings: &AppSettings) -> Self {
+        GitHubAccess {
+            host: GitHubHost::from_settings(settings),
+            token: settings.github_oauth_token.clone(),
+            source_tokens: settings.source_host_tokens.clone(),
+        }
+    }
+
+    /// Anonymous access to `host`.
+    #[cfg(test)]
+    pub(crate) fn anonymous(host: GitHubHost) -> Self {
+        GitHubAccess {
+            host,
+            token: None,
+            source_tokens: vec![],
+        }
+    }
+
+    /// The token configured for a non-GitHub host (as found in a URL,
+    /// case-insensitive).
+    pub(crate) fn source_token_for(&self, host: &str) -> Option<&str> {
+        self.source_tokens
+            .iter()
+            .find(|entry| entry.host.eq_ignore_ascii_case(host))
+            .map(|entry| entry.token.as_str())
+    }
+
+    /// The token, but only when talking to the host that issued it.
+    pub(crate) fn token_for(&self, host: &GitHubHost) -> Option<&str> {
+        self.token.as_deref().filter(|_| *host == self.host)
+    }
+
+    /// The token for an HTTPS git URL on the issuing host, `None` for any other
+    /// URL so it never leaks to a different server.
+    pub(crate) fn token_for_url(&self, url: &str) -> Option<&str> {
+        let prefix = format!("{}/", self.host.web_base);
+        self.token.as_deref().filter(|_| {
+            url.get(..prefix.len())
+                .is_some_and(|head| head.eq_ignore_ascii_case(&prefix))
+        })
+    }
+}
+
+/// Normalize a user-entered host: strip scheme, trailing slashes and `/api/v3`,
+/// and map github.com to `None`. Rejects anything with a path, query or spaces.
+pub(crate) fn normalize_github_host(input: Option<&str>) -> Result<Option<String>, String> {
+    let Some(raw) = input.map(str::trim).filter(|h| !h.is_empty()) else {
+        return Ok(None);
+    };
+    let without_scheme = raw
+        .strip_prefix("https://")
+        .or_else(|| raw.strip_prefix("http://"))
+        .unwrap_or(raw);
+    let trimmed = without_scheme.trim_end_matches('/');
+    let host = trimmed
+        .strip_suffix("/api/v3")
+        .unwrap_or(trimmed)
+        .to_ascii_lowercase();
+    if host.is_empty()
+        || host
+            .chars()
+            .any(|c| c.is_whitespace() || matches!(c, '/' | '?' | '#' | '@'))
+    {
+        return Err(format!(
+            "Invalid GitHub host '{}': enter a host name such as github.example.com",
+            raw
+        ));
+    }
+    if host == PUBLIC_GITHUB_HOST || host == "api.github.com" {
+        return Ok(None);
+    }
+    Ok(Some(host))
+}
+
+/// Normalize a user-entered feedback repository to `owner/repo`, dropping a
+/// trailing `.git` or slash. Blank means the default repository.
+pub(crate) fn normalize_feedback_repo(input: Option<&str>) -> Result<Option<String>, String> {
+    let Some(raw) = input.map(str::trim).filter(|r| !r.is_empty()) else {
+        return Ok(None);
+    };
+    let trimmed = raw.trim_end_matches('/');
+    let repo = trimmed.strip_suffix(".git").unwrap_or(trimmed);
+    let valid = repo.split_once('/').is_some_and(|(owner, name)| {
+        [owner, name].iter().all(|part| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_alphanumeric() || matches!(c, '-' | '_' | '.'))
+        })
+    });
+    if !valid {
+        return Err(format!(
+            "Invalid feedback repository '{}': enter it as owner/repo",
+            raw
+        ));
+    }
+    Ok(Some(repo.to_string()))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn public_and_enterprise_endpoints() {
+        let public = GitHubHost::from_setting(None);
+        assert!(public.is_public());
+        assert_eq!(public.api_base, "https://api.github.com");
+        assert_eq!(GitHubHost::from_setting(Some("GitHub.com")), public);
+
+        let ghes = GitHubHost::from_setting(Some("github.example.com"));
+        assert!(!ghes.is_public());
+        assert_eq!(ghes.web_base, "https://github.example.com");
+        assert_eq!(ghes.api_base, "https://github.example.com/api/v3");
+        assert_eq!(ghes.raw_base, "https://github.example.com/raw");
+        assert!(ghes.matches_host("GITHUB.example.com"));
+    }
+
+    #[test]
+    fn normalize_github_host_accepts_urls_and_rejects_paths() {
+        assert_eq!(normalize_github_host(None).unwrap(), None);
+        assert_eq!(normalize_github_host(Some("  ")).unwrap(), None);
+        assert_eq!(
+            normalize_github_host(Some("https://github.com/")).unwrap(),
+            None
+        );
+        assert_eq!(
+            normalize_github_host(Some("https://GHE.corp.example/api/v3/")).unwrap(),
+            Some("ghe.corp.example".to_string())
+        );
+        assert_eq!(
+            normalize_github_host(Some("ghe.corp.example:8443")).unwrap(),
+            Some("ghe.corp.example:8443".to_string())
+        );
+        assert!(normalize_github_host(Some("ghe.corp.example/org")).is_err());
+        assert!(normalize_github_host(Some("user@ghe.corp.example")).is_err());
+    }
+
+    #[test]
+    fn normalize_feedback_repo_requires_owner_and_name() {
+        assert_eq!(normalize_feedback_repo(None).unwrap(), None);
+        assert_eq!(normalize_feedback_repo(Some(" ")).unwrap(), None);
+        assert_eq!(
+            normalize_feedback_repo(Some(" platform/skill-feedback.git/ ")).unwrap(),
+            Some("platform/skill-feedback".to_string())
+        );
+        assert!(normalize_feedback_repo(Some("skill-feedback")).is_err());
+        assert!(normalize_feedback_repo(Some("platform/")).is_err());
+        assert!(normalize_feedback_repo(Some("a/b/c")).is_err());
+        assert!(normalize_feedback_repo(Some("https://ghe.corp/a/b")).is_err());
+    }
+
+    #[test]
+    fn token_is_only_offered_to_the_configured_host() {
+        let ghes = GitHubAccess {
+            host: GitHubHost::enterprise("ghe.corp.example"),
+            token: Some("tok".to_string()),
+            source_tokens: vec![],
+        };
+        assert_eq!(
+            ghes.token_for_url("https://ghe.corp.example/acme/skill.git"),
+            Some("tok")
+        );
+        assert_eq!(
+            ghes.token_for_url("https://github.com/acme/skill.git"),
+            None
+        );
+        assert_eq!(
+            ghes.token_for_url("https://ghe.corp.example.evil.io/x.git"),
+            None
+        );
+        assert_eq!(ghes.token_for(&GitHubHost::public()), None);
+        assert_eq!(
+            ghes.token_for(&GitHubHost::enterprise("ghe.corp.example")),
+            Some("tok")
+        );
+    }
+}
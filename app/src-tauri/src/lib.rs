@@ -7,8 +7,10 @@ mod fs_utils;
 mod fs_validation;
 pub mod generated;
 pub mod git;
+mod git_remote;
+mod github_host;
 mod logging;
-mod marketplace_manifest;
+pub mod marketplace_manifest;
 mod reconciliation;
 mod services;
 mod skill_paths;
@@ -260,6 +262,7 @@ pub fn run() {
             commands::skill::release_lock,
             commands::skill::get_externally_locked_skills,
             commands::skill::export_skill_as_file,
+            commands::skill::export_skill_to_target,
             commands::files::list_skill_files,
             commands::files::read_file,
             commands::files::write_file,
@@ -292,14 +295,18 @@ pub fn run() {
             commands::imported_skills::lifecycle::delete_imported_skill,
             commands::imported_skills::lifecycle::list_plugins,
             commands::imported_skills::lifecycle::delete_plugin,
+            commands::imported_skills::lifecycle::plugin_dependents,
             commands::imported_skills::lifecycle::create_plugin_from_skills,
             commands::imported_skills::lifecycle::move_skill_to_plugin,
             commands::imported_skills::lifecycle::remove_skill_from_plugin,
             commands::imported_skills::lifecycle::set_plugin_upgrade_lock,
+            commands::imported_skills::lifecycle::lint_plugin_manifests,
             commands::feedback::create_github_issue,
             commands::github_import::url::parse_github_url,
+            commands::github_import::url::parse_skill_source,
             commands::github_import::commands::check_marketplace_url,
             commands::github_import::commands::list_github_plugins,
+            commands::github_import::commands::list_marketplace_plugins,
             commands::github_import::commands::list_github_skills,
             commands::github_auth::github_start_device_flow,
             commands::github_auth::github_poll_for_token,
@@ -310,6 +317,11 @@ pub fn run() {
             commands::github_import::commands::get_dashboard_skill_names,
             commands::github_import::updates::check_marketplace_updates,
             commands::github_import::commands::check_skill_customized,
+            commands::github_import::merge::preview_imported_skill_update,
+            commands::github_import::merge::apply_imported_skill_update,
+            commands::github_import::integrity::verify_imported_skills,
+            commands::github_import::bundle::export_plugin_bundle,
+            commands::github_import::bundle::import_plugin_bundle,
             commands::usage::get_usage_summary,
             commands::usage::get_usage_by_step,
             commands::usage::get_usage_by_model,
@@ -321,7 +333,19 @@ pub fn run() {
             commands::usage::get_workflow_skill_names,
             commands::git::get_skill_history,
             commands::git::restore_skill_version,
+            commands::git::suggest_skill_version_bump,
+            commands::git::release_skill_version,
             commands::git::get_skill_files_at_sha,
+            commands::git::diff_skill_versions,
+            commands::git::start_skill_draft,
+            commands::git::get_skill_draft,
+            commands::git::merge_skill_draft,
+            commands::git::discard_skill_draft,
+            commands::git::get_skill_git_remote,
+            commands::git::set_skill_git_remote,
+            commands::git::push_skill_remote,
+            commands::git::fetch_skill_remote,
+            commands::git::pull_skill_remote,
             commands::files::get_skill_content_at_path,
             commands::files::get_selected_skill_content,
             commands::skill_session::select_skill_openhands_session,
@@ -330,20 +354,37 @@ pub fn run() {
             commands::workflow::evaluation::read_latest_benchmark,
             commands::imported_skills::upload::parse_skill_file,
             commands::imported_skills::upload::import_skill_from_file,
+            commands::imported_skills::upload::preview_skill_archive,
+            commands::imported_skills::upload::import_skill_archive,
             commands::eval_workbench::list_scenarios,
             commands::eval_workbench::load_scenario,
             commands::eval_workbench::create_scenario,
             commands::eval_workbench::save_scenario,
             commands::eval_workbench::delete_scenario,
             commands::eval_workbench::define_eval_scenario,
+            commands::eval_workbench::run::run_scenario,
+            commands::eval_workbench::trigger::run_trigger_eval,
+            commands::eval_workbench::history::list_scenario_runs,
+            commands::eval_workbench::history::get_scenario_pass_rates,
+            commands::eval_workbench::history::detect_scenario_regression,
+            commands::eval_workbench::compare::run_benchmark_comparison,
+            commands::eval_workbench::compare::export_benchmark_comparison,
+            commands::eval_workbench::promptfoo::import_promptfoo_config,
+            commands::eval_workbench::promptfoo::export_promptfoo_config,
 
             commands::documents::list_documents,
             commands::documents::list_skills_for_documents,
             commands::documents::add_document_file,
+            commands::documents::add_document_path,
             commands::documents::add_document_url,
             commands::documents::add_document_folder,
+            commands::documents::crawl::add_document_site,
             commands::documents::update_document,
             commands::documents::delete_document,
+            commands::documents::refresh::refresh_document,
+            commands::documents::refresh::refresh_url_documents,
+            commands::documents::refresh::list_document_versions,
+            commands::documents::refresh::list_skills_with_changed_documents,
 
             commands::model_catalog::refresh_model_catalog,
             commands::model_catalog::get_cached_model_catalog,
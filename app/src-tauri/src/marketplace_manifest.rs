@@ -1,7 +1,11 @@
+use std::collections::BTreeMap;
 use std::fs;
 use std::path::Path;
 
 use serde::{Deserialize, Serialize};
+use serde_json::Value;
+
+use crate::types::{ManifestLintFinding, ManifestLintReport, ManifestLintSeverity};
 
 // ─── JSON types for local marketplace manifests ─────────────────────────────
 
@@ -34,6 +38,9 @@ struct PluginJson {
     description: Option<String>,
     #[serde(skip_serializing_if = "Option::is_none")]
     version: Option<String>,
+    /// Plugin name → semver range, e.g. `{"data-glossary": "^1.2"}`.
+    #[serde(default, skip_serializing_if = "BTreeMap::is_empty")]
+    dependencies: BTreeMap<String, String>,
 }
 
 // ─── Public API ─────────────────────────────────────────────────────────────
@@ -169,12 +176,14 @@ pub fn write_plugin_json(
             name: plugin_slug.to_string(),
             description: None,
             version: None,
+            dependencies: BTreeMap::new(),
         })
     } else {
         PluginJson {
             name: plugin_slug.to_string(),
             description: None,
             version: None,
+            dependencies: BTreeMap::new(),
         }
     };
 
@@ -195,6 +204,43 @@ pub fn write_plugin_json(
     Ok(())
 }
 
+/// Read the `dependencies` a plugin declares in its `plugin.json`: plugin
+/// name → semver range. Entries that aren't strings are skipped.
+pub fn read_plugin_dependencies(plugin_dir: &Path) -> BTreeMap<String, String> {
+    let pj_path = plugin_dir.join(".claude-plugin").join("plugin.json");
+    let Ok(content) = fs::read_to_string(&pj_path) else {
+        return BTreeMap::new();
+    };
+    let Ok(pj) = serde_json::from_str::<Value>(&content) else {
+        return BTreeMap::new();
+    };
+    pj["dependencies"]
+        .as_object()
+        .map(|deps| {
+            deps.iter()
+                .filter_map(|(name, range)| Some((name.clone(), range.as_str()?.to_string())))
+                .collect()
+        })
+        .unwrap_or_default()
+}
+
+/// Whether `version` satisfies the semver range `version_req`. An empty range
+/// or `*` accepts any installed version, including an unversioned one; an
+/// unparseable range or version never matches.
+pub fn dependency_satisfied(version_req: &str, version: Option<&str>) -> bool {
+    let version_req = version_req.trim();
+    if version_req.is_empty() || version_req == "*" {
+        return true;
+    }
+    match (
+        semver::VersionReq::parse(version_req),
+        version.map(|v| semver::Version::parse(v.trim())),
+    ) {
+        (Ok(req), Some(Ok(version))) => req.matches(&version),
+        _ => false,
+    }
+}
+
 /// Scan `root/` for plugin directories and write `.claude-plugin/marketplace.json` at the root.
 /// A directory is a plugin if it contains a `skills/` subdirectory or `.claude-plugin/plugin.json`.
 pub fn write_marketplace_json(root: &Path) -> Result<(), String> {
@@ -223,24 +269,7 @@ pub fn write_marketplace_json(root: &Path) -> Result<(), String> {
                 continue;
             }
 
-            // Read existing plugin.json for metadata
-            let pj_path = dir.join(".claude-plugin").join("plugin.json");
-            let (description, version) = if pj_path.is_file() {
-                let content = fs::read_to_string(&pj_path).unwrap_or_default();
-                match serde_json::from_str::<PluginJson>(&content) {
-                    Ok(pj) => (pj.description, pj.version),
-                    Err(_) => (None, None),
-                }
-            } else {
-                (None, None)
-            };
-
-            plugin_entries.push(MarketplacePluginEntry {
-                name: slug.clone(),
-                source: format!("./{}", slug),
-                description,
-                version,
-            });
+            plugin_entries.push(marketplace_entry(&dir, &slug));
         }
     }
 
@@ -264,6 +293,43 @@ pub fn write_marketplace_json(root: &Path) -> Result<(), String> {
     Ok(())
 }
 
+/// Marketplace entry for the plugin in `plugin_dir`, with description and
+/// version taken from its `plugin.json` when present.
+fn marketplace_entry(plugin_dir: &Path, slug: &str) -> MarketplacePluginEntry {
+    let pj_path = plugin_dir.join(".claude-plugin").join("plugin.json");
+    let (description, version) = if pj_path.is_file() {
+        let content = fs::read_to_string(&pj_path).unwrap_or_default();
+        match serde_json::from_str::<PluginJson>(&content) {
+            Ok(pj) => (pj.description, pj.version),
+            Err(_) => (None, None),
+        }
+    } else {
+        (None, None)
+    };
+    MarketplacePluginEntry {
+        name: slug.to_string(),
+        source: format!("./{}", slug),
+        description,
+        version,
+    }
+}
+
+/// Serialize a `marketplace.json` listing only the plugin in `plugin_dir`,
+/// sourced from `./{slug}`. Used for bundle archives, which place the plugin
+/// next to this manifest so Claude Code can add the extracted folder as a
+/// marketplace.
+pub fn single_plugin_marketplace_json(plugin_dir: &Path, slug: &str) -> Result<String, String> {
+    let marketplace = LocalMarketplaceJson {
+        name: slug.to_string(),
+        owner: MarketplaceOwner {
+            name: "Skill Builder".to_string(),
+        },
+        plugins: vec![marketplace_entry(plugin_dir, slug)],
+    };
+    serde_json::to_string_pretty(&marketplace)
+        .map_err(|e| format!("Failed to serialize marketplace.json: {}", e))
+}
+
 /// Regenerate all manifests: ensure each plugin under `root/` has a
 /// `plugin.json`, then rewrite `marketplace.json`.
 pub fn regenerate_all_manifests(root: &Path) -> Result<(), String> {
@@ -302,6 +368,635 @@ pub fn regenerate_all_manifests(root: &Path) -> Result<(), String> {
     write_marketplace_json(root)
 }
 
+// ─── Linting ────────────────────────────────────────────────────────────────
+
+const MARKETPLACE_JSON_PATH: &str = ".claude-plugin/marketplace.json";
+
+/// Plugin entry from marketplace.json, kept for cross-checks against disk.
+struct ListedPlugin {
+    name: String,
+    /// Directory name for `./<slug>` sources; `None` for remote sources.
+    slug: Option<String>,
+    version: Option<String>,
+}
+
+#[derive(Default)]
+struct Linter {
+    findings: Vec<ManifestLintFinding>,
+}
+
+impl Linter {
+    fn push(
+        &mut self,
+        severity: ManifestLintSeverity,
+        code: &str,
+        path: &str,
+        plugin: Option<&str>,
+        message: String,
+    ) {
+        self.findings.push(ManifestLintFinding {
+            severity,
+            code: code.to_string(),
+            path: path.to_string(),
+            plugin: plugin.map(ToString::to_string),
+            message,
+        });
+    }
+
+    fn error(&mut self, code: &str, path: &str, plugin: Option<&str>, message: String) {
+        self.push(ManifestLintSeverity::Error, code, path, plugin, message);
+    }
+
+    fn warning(&mut self, code: &str, path: &str, plugin: Option<&str>, message: String) {
+        self.push(ManifestLintSeverity::Warning, code, path, plugin, message);
+    }
+
+    /// Require `key` to be a non-empty string; returns it when valid.
+    fn required_string(
+        &mut self,
+        object: &serde_json::Map<String, Value>,
+        key: &str,
+        path: &str,
+        plugin: Option<&str>,
+    ) -> Option<String> {
+        match object.get(key) {
+            Some(Value::String(value)) if !value.trim().is_empty() => Some(value.clone()),
+            Some(Value::String(_)) | None => {
+                self.error(
+                    "missing_field",
+                    path,
+                    plugin,
+                    format!("Required field '{}' is missing or empty", key),
+                );
+                None
+            }
+            Some(_) => {
+                self.error(
+                    "invalid_field",
+                    path,
+                    plugin,
+                    format!("Field '{}' must be a string", key),
+                );
+                None
+            }
+        }
+    }
+
+    /// Check an optional string field; returns it when present and valid.
+    fn optional_string(
+        &mut self,
+        object: &serde_json::Map<String, Value>,
+        key: &str,
+        path: &str,
+        plugin: Option<&str>,
+    ) -> Option<String> {
+        match object.get(key) {
+            Some(Value::String(value)) => Some(value.clone()),
+            None | Some(Value::Null) => None,
+            Some(_) => {
+                self.error(
+                    "invalid_field",
+                    path,
+                    plugin,
+                    format!("Field '{}' must be a string", key),
+                );
+                None
+            }
+        }
+    }
+
+    fn check_name(&mut self, name: &str, path: &str, plugin: Option<&str>) {
+        if !is_kebab_case(name) {
+            self.warning(
+                "invalid_name",
+                path,
+                plugin,
+                format!(
+                    "Name '{}' should be kebab-case (lowercase, no spaces)",
+                    name
+                ),
+            );
+        }
+    }
+
+    fn check_version(&mut self, version: &str, path: &str, plugin: Option<&str>) {
+        if parse_semver(version).is_none() {
+            self.warning(
+                "invalid_version",
+                path,
+                plugin,
+                format!("Version '{}' is not semver (X.Y.Z)", version),
+            );
+        }
+    }
+}
+
+/// Lint `marketplace.json` and every plugin's `plugin.json` under `root`.
+///
+/// Checks required fields and types from the Claude Code plugin schema,
+/// kebab-case names, semver versions, `source` paths, duplicate names,
+/// plugins on disk that the marketplace does not list (and vice versa), and
+/// version drift between the marketplace entry, `plugin.json` and the
+/// SKILL.md frontmatter of the plugin's skills.
+pub fn lint_manifests(root: &Path) -> ManifestLintReport {
+    let mut linter = Linter::default();
+    let listed = lint_marketplace_json(root, &mut linter);
+
+    let mut slugs = plugin_dirs_on_disk(root);
+    for listed_slug in listed.iter().filter_map(|p| p.slug.as_ref()) {
+        if root.join(listed_slug).is_dir() && !slugs.contains(listed_slug) {
+            slugs.push(listed_slug.clone());
+        }
+    }
+    slugs.sort();
+
+    let mut plugin_names: Vec<(String, String)> = Vec::new();
+    for slug in &slugs {
+        let entry = listed
+            .iter()
+            .find(|p| p.slug.as_deref() == Some(slug.as_str()));
+        if entry.is_none()
+            && root
+                .join(".claude-plugin")
+                .join("marketplace.json")
+                .is_file()
+        {
+            linter.warning(
+                "plugin_not_listed",
+                MARKETPLACE_JSON_PATH,
+                Some(slug),
+                format!(
+                    "Plugin directory '{}' is not listed in marketplace.json",
+                    slug
+                ),
+            );
+        }
+        if let Some(name) = lint_plugin_json(root, slug, entry, &mut linter) {
+            plugin_names.push((name, slug.clone()));
+        }
+    }
+
+    plugin_names.sort();
+    for pair in plugin_names.windows(2) {
+        if pair[0].0 == pair[1].0 {
+            linter.error(
+                "duplicate_name",
+                &format!("{}/.claude-plugin/plugin.json", pair[1].1),
+                Some(&pair[1].1),
+                format!(
+                    "plugin.json name '{}' is also used by '{}'",
+                    pair[1].0, pair[0].1
+                ),
+            );
+        }
+    }
+
+    let findings = linter.findings;
+    let error_count = findings
+        .iter()
+        .filter(|f| f.severity == ManifestLintSeverity::Error)
+        .count();
+    log::debug!(
+        "[marketplace_manifest] lint root={} plugins={} findings={}",
+        root.display(),
+        slugs.len(),
+        findings.len()
+    );
+    ManifestLintReport {
+        root: root.to_string_lossy().replace('\\', "/"),
+        plugins_checked: slugs.len(),
+        error_count,
+        warning_count: findings.len() - error_count,
+        findings,
+    }
+}
+
+fn lint_marketplace_json(root: &Path, linter: &mut Linter) -> Vec<ListedPlugin> {
+    let path = MARKETPLACE_JSON_PATH;
+    let file = root.join(".claude-plugin").join("marketplace.json");
+    if !file.is_file() {
+        linter.error(
+            "missing_file",
+            path,
+            None,
+            "marketplace.json does not exist".to_string(),
+        );
+        return Vec::new();
+    }
+    let Some(object) = read_json_object(&file, path, None, linter) else {
+        return Vec::new();
+    };
+
+    if let Some(name) = linter.required_string(&object, "name", path, None) {
+        linter.check_name(&name, path, None);
+    }
+    match object.get("owner") {
+        Some(Value::Object(owner)) => {
+            linter.required_string(owner, "name", path, None);
+        }
+        None => linter.error(
+            "missing_field",
+            path,
+            None,
+            "Required field 'owner' is missing".to_string(),
+        ),
+        Some(_) => linter.error(
+            "invalid_field",
+            path,
+            None,
+            "Field 'owner' must be an object with a 'name'".to_string(),
+        ),
+    }
+    let entries = match object.get("plugins") {
+        Some(Value::Array(entries)) => entries,
+        None => {
+            linter.error(
+                "missing_field",
+                path,
+                None,
+                "Required field 'plugins' is missing".to_string(),
+            );
+            return Vec::new();
+        }
+        Some(_) => {
+            linter.error(
+                "invalid_field",
+                path,
+                None,
+                "Field 'plugins' must be an array".to_string(),
+            );
+            return Vec::new();
+        }
+    };
+
+    let mut listed: Vec<ListedPlugin> = Vec::new();
+    for (index, entry) in entries.iter().enumerate() {
+        let Value::Object(entry) = entry else {
+            linter.error(
+                "invalid_field",
+                path,
+                None,
+                format!("plugins[{}] must be an object", index),
+            );
+            continue;
+        };
+        let name = linter.required_string(entry, "name", path, None);
+        let label = name
+            .clone()
+            .unwrap_or_else(|| format!("plugins[{}]", index));
+        if let Some(name) = &name {
+            linter.check_name(name, path, Some(name));
+            if listed.iter().any(|p| &p.name == name) {
+                linter.error(
+                    "duplicate_name",
+                    path,
+                    Some(name),
+                    format!("Plugin name '{}' is listed more than once", name),
+                );
+            }
+        }
+        linter.optional_string(entry, "description", path, Some(&label));
+        let version = linter.optional_string(entry, "version", path, Some(&label));
+        if let Some(version) = &version {
+            linter.check_version(version, path, Some(&label));
+        }
+
+        let slug = lint_plugin_source(root, entry.get("source"), &label, linter);
+        lint_plugin_checksums(entry.get("checksums"), &label, linter);
+        if let Some(slug) = &slug {
+            if listed.iter().any(|p| p.slug.as_ref() == Some(slug)) {
+                linter.warning(
+                    "duplicate_source",
+                    path,
+                    Some(&label),
+                    format!("Source './{}' is used by more than one plugin", slug),
+                );
+            }
+        }
+        listed.push(ListedPlugin {
+            name: label,
+            slug,
+            version,
+        });
+    }
+    listed
+}
+
+/// Validate a marketplace entry's optional `checksums`: an object mapping
+/// plugin-relative file paths to `sha256:<hex>` digests.
+fn lint_plugin_checksums(checksums: Option<&Value>, label: &str, linter: &mut Linter) {
+    let path = MARKETPLACE_JSON_PATH;
+    let Some(checksums) = checksums else {
+        return;
+    };
+    let Value::Object(checksums) = checksums else {
+        linter.error(
+            "invalid_field",
+            path,
+            Some(label),
+            "Field 'checksums' must be an object of file paths to SHA-256 digests".to_string(),
+        );
+        return;
+    };
+    for (file, digest) in checksums {
+        let hex = digest
+            .as_str()
+            .map(|d| d.strip_prefix("sha256:").unwrap_or(d));
+        if !hex.is_some_and(|h| h.len() == 64 && h.bytes().all(|b| b.is_ascii_hexdigit())) {
+            linter.error(
+                "invalid_checksum",
+                path,
+                Some(label),
+                format!(
+                    "Checksum for '{}' must be 'sha256:' followed by 64 hex digits",
+                    file
+                ),
+            );
+        }
+    }
+}
+
+/// Validate a marketplace entry's `source`. Returns the plugin directory name
+/// for local `./<dir>` sources.
+fn lint_plugin_source(
+    root: &Path,
+    source: Option<&Value>,
+    label: &str,
+    linter: &mut Linter,
+) -> Option<String> {
+    let path = MARKETPLACE_JSON_PATH;
+    match source {
+        None => {
+            linter.error(
+                "missing_field",
+                path,
+                Some(label),
+                "Required field 'source' is missing".to_string(),
+            );
+            None
+        }
+        Some(Value::String(source)) => {
+            let relative = source.strip_prefix("./").map(|s| s.trim_end_matches('/'));
+            let valid = relative.filter(|rel| {
+                !rel.is_empty()
+                    && !rel.contains('\\')
+                    && rel
+                        .split('/')
+                        .all(|segment| !segment.is_empty() && segment != "." && segment != "..")
+            });
+            let Some(relative) = valid else {
+                linter.error(
+                    "invalid_source",
+                    path,
+                    Some(label),
+                    format!(
+                        "Source '{}' must be a relative path starting with './' inside the marketplace",
+                        source
+                    ),
+                );
+                return None;
+            };
+            if !root.join(relative).is_dir() {
+                linter.error(
+                    "plugin_missing_on_disk",
+                    path,
+                    Some(label),
+                    format!("Source '{}' does not exist on disk", source),
+                );
+            }
+            Some(relative.to_string())
+        }
+        Some(Value::Object(source)) => {
+            // Same rules as the importer, so the linter never rejects a source
+            // that would import or accepts one that wouldn't.
+            let kind = source.get("source").and_then(Value::as_str).unwrap_or("");
+            let extra = Value::Object(source.clone());
+            if let Err(e) = crate::commands::github_import::resolve_external_source(kind, &extra) {
+                linter.error(
+                    "invalid_source",
+                    path,
+                    Some(label),
+                    format!("Source object is not importable: {}", e),
+                );
+            }
+            None
+        }
+        Some(_) => {
+            linter.error(
+                "invalid_source",
+                path,
+                Some(label),
+                "Field 'source' must be a path string or a source object".to_string(),
+            );
+            None
+        }
+    }
+}
+
+/// Lint one plugin's `plugin.json` and the versions of its skills. Returns the
+/// plugin name for duplicate detection.
+fn lint_plugin_json(
+    root: &Path,
+    slug: &str,
+    listed: Option<&ListedPlugin>,
+    linter: &mut Linter,
+) -> Option<String> {
+    let path = format!("{}/.claude-plugin/plugin.json", slug);
+    let file = root.join(slug).join(".claude-plugin").join("plugin.json");
+    if !file.is_file() {
+        linter.warning(
+            "missing_plugin_json",
+            &path,
+            Some(slug),
+            format!("Plugin '{}' has no plugin.json", slug),
+        );
+        return None;
+    }
+    let object = read_json_object(&file, &path, Some(slug), linter)?;
+
+    let name = linter.required_string(&object, "name", &path, Some(slug));
+    if let Some(name) = &name {
+        linter.check_name(name, &path, Some(slug));
+    }
+    linter.optional_string(&object, "description", &path, Some(slug));
+    let version = linter.optional_string(&object, "version", &path, Some(slug));
+    if let Some(version) = &version {
+        linter.check_version(version, &path, Some(slug));
+    }
+
+    lint_plugin_dependencies(object.get("dependencies"), &path, slug, linter);
+
+    if let Some(listed_version) = listed.and_then(|p| p.version.as_deref()) {
+        if version.as_deref() != Some(listed_version) {
+            linter.warning(
+                "version_mismatch",
+                MARKETPLACE_JSON_PATH,
+                Some(slug),
+                format!(
+                    "marketplace.json lists v{} but plugin.json has {}",
+                    listed_version,
+                    version
+                        .as_deref()
+                        .map(|v| format!("v{}", v))
+                        .unwrap_or_else(|| "no version".to_string())
+                ),
+            );
+        }
+    }
+
+    if let Some(plugin_version) = version.as_deref().and_then(parse_semver) {
+        for (skill, skill_version) in skill_versions(&root.join(slug)) {
+            if parse_semver(&skill_version).is_some_and(|v| v > plugin_version) {
+                linter.warning(
+                    "version_mismatch",
+                    &format!("{}/skills/{}/SKILL.md", slug, skill),
+                    Some(slug),
+                    format!(
+                        "Skill '{}' is at v{} but plugin.json is at v{}",
+                        skill,
+                        skill_version,
+                        version.as_deref().unwrap_or_default()
+                    ),
+                );
+            }
+        }
+    }
+    name
+}
+
+/// Validate `plugin.json` `dependencies`: an object mapping plugin names to
+/// semver ranges.
+fn lint_plugin_dependencies(
+    dependencies: Option<&Value>,
+    path: &str,
+    slug: &str,
+    linter: &mut Linter,
+) {
+    let Some(dependencies) = dependencies else {
+        return;
+    };
+    let Value::Object(dependencies) = dependencies else {
+        linter.error(
+            "invalid_field",
+            path,
+            Some(slug),
+            "Field 'dependencies' must be an object of plugin names to version ranges".to_string(),
+        );
+        return;
+    };
+    for (name, range) in dependencies {
+        let valid = range.as_str().is_some_and(|range| {
+            let range = range.trim();
+            range.is_empty() || range == "*" || semver::VersionReq::parse(range).is_ok()
+        });
+        if !valid {
+            linter.error(
+                "invalid_dependency",
+                path,
+                Some(slug),
+                format!(
+                    "Dependency '{}' must be a semver range such as \"^1.2\"",
+                    name
+                ),
+            );
+        } else if crate::db::slugify_plugin_name(name) == slug {
+            linter.error(
+                "invalid_dependency",
+                path,
+                Some(slug),
+                format!("Plugin '{}' cannot depend on itself", name),
+            );
+        }
+    }
+}
+
+fn read_json_object(
+    file: &Path,
+    path: &str,
+    plugin: Option<&str>,
+    linter: &mut Linter,
+) -> Option<serde_json::Map<String, Value>> {
+    let parsed = fs::read_to_string(file)
+        .map_err(|e| e.to_string())
+        .and_then(|content| serde_json::from_str::<Value>(&content).map_err(|e| e.to_string()));
+    match parsed {
+        Ok(Value::Object(object)) => Some(object),
+        Ok(_) => {
+            linter.error(
+                "invalid_json",
+                path,
+                plugin,
+                "Manifest must be a JSON object".to_string(),
+            );
+            None
+        }
+        Err(e) => {
+            linter.error(
+                "invalid_json",
+                path,
+                plugin,
+                format!("Failed to parse: {}", e),
+            );
+            None
+        }
+    }
+}
+
+/// Plugin directories under `root`, using the same rule as `write_marketplace_json`.
+fn plugin_dirs_on_disk(root: &Path) -> Vec<String> {
+    let Ok(entries) = fs::read_dir(root) else {
+        return Vec::new();
+    };
+    entries
+        .filter_map(|e| e.ok())
+        .filter(|e| e.path().is_dir())
+        .map(|e| e.file_name().to_string_lossy().to_string())
+        .filter(|slug| !slug.starts_with('.'))
+        .filter(|slug| {
+            let dir = root.join(slug);
+            dir.join("skills").is_dir() || dir.join(".claude-plugin").join("plugin.json").is_file()
+        })
+        .collect()
+}
+
+/// `(skill_dir_name, frontmatter_version)` for each skill under `plugin_dir/skills`.
+fn skill_versions(plugin_dir: &Path) -> Vec<(String, String)> {
+    let Ok(entries) = fs::read_dir(plugin_dir.join("skills")) else {
+        return Vec::new();
+    };
+    let mut versions: Vec<(String, String)> = entries
+        .filter_map(|e| e.ok())
+        .filter_map(|e| {
+            let content = fs::read_to_string(e.path().join("SKILL.md")).ok()?;
+            let version =
+                crate::commands::imported_skills::parse_frontmatter_full(&content).version?;
+            Some((e.file_name().to_string_lossy().to_string(), version))
+        })
+        .collect();
+    versions.sort();
+    versions
+}
+
+fn is_kebab_case(name: &str) -> bool {
+    !name.is_empty()
+        && name.split('-').all(|part| {
+            !part.is_empty()
+                && part
+                    .chars()
+                    .all(|c| c.is_ascii_lowercase() || c.is_ascii_digit())
+        })
+}
+
+fn parse_semver(version: &str) -> Option<(u64, u64, u64)> {
+    let mut parts = version.trim().split('.').map(|p| p.parse::<u64>().ok());
+    match (parts.next(), parts.next(), parts.next(), parts.next()) {
+        (Some(Some(major)), Some(Some(minor)), Some(Some(patch)), None) => {
+            Some((major, minor, patch))
+        }
+        _ => None,
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -337,7 +1032,7 @@ mod tests {
         fs::create_dir_all(&config_dir).unwrap();
         fs::write(
             config_dir.join("plugin.json"),
-            r#"{"name":"analytics","description":"existing desc","version":"2.0.0"}"#,
+            r#"{"name":"analytics","description":"existing desc","version":"2.0.0","dependencies":{"data-glossary":"^1.2"}}"#,
         )
         .unwrap();
 
@@ -350,6 +1045,22 @@ mod tests {
         assert_eq!(content["name"], "Analytics Updated");
         assert_eq!(content["description"], "existing desc");
         assert_eq!(content["version"], "2.0.0");
+        assert_eq!(
+            read_plugin_dependencies(&tmp.path().join("analytics")),
+            BTreeMap::from([("data-glossary".to_string(), "^1.2".to_string())])
+        );
+    }
+
+    #[test]
+    fn dependency_satisfied_matches_semver_ranges() {
+        assert!(dependency_satisfied("^1.2", Some("1.4.0")));
+        assert!(!dependency_satisfied("^1.2", Some("2.0.0")));
+        assert!(!dependency_satisfied("^1.2", Some("1.1.9")));
+        assert!(dependency_satisfied(">=1.0, <3", Some("2.5.1")));
+        assert!(!dependency_satisfied("^1.2", None));
+        assert!(!dependency_satisfied("not a range", Some("1.2.0")));
+        assert!(dependency_satisfied("*", None));
+        assert!(dependency_satisfied("", Some("0.1.0")));
     }
 
     #[test]
@@ -647,4 +1358,158 @@ mod tests {
             tmp.path().join("analytics").join("skills").join("report")
         );
     }
+
+    // ── lint_manifests tests ──
+
+    fn codes(report: &ManifestLintReport) -> Vec<(&str, Option<&str>)> {
+        report
+            .findings
+            .iter()
+            .map(|f| (f.code.as_str(), f.plugin.as_deref()))
+            .collect()
+    }
+
+    #[test]
+    fn lint_manifests_accepts_generated_manifests() {
+        let tmp = tempfile::tempdir().unwrap();
+        let skill_dir = tmp.path().join("analytics").join("skills").join("report");
+        fs::create_dir_all(&skill_dir).unwrap();
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: report\nmetadata:\n  version: 1.0.0\n---\n# Report\n",
+        )
+        .unwrap();
+        write_plugin_json(tmp.path(), "analytics", "analytics", None, Some("1.0.0")).unwrap();
+        write_marketplace_json(tmp.path()).unwrap();
+
+        let report = lint_manifests(tmp.path());
+        assert!(report.findings.is_empty(), "{:?}", report.findings);
+        assert_eq!(report.plugins_checked, 1);
+    }
+
+    #[test]
+    fn lint_manifests_accepts_sources_the_importer_accepts() {
+        let tmp = tempfile::tempdir().unwrap();
+        let config = tmp.path().join(".claude-plugin");
+        fs::create_dir_all(&config).unwrap();
+        fs::write(
+            config.join("marketplace.json"),
+            r#"{
+              "name": "remote-plugins",
+              "owner": {"name": "Acme"},
+              "plugins": [
+                {"name": "hub", "source": {"source": "github", "repo": "acme/hub", "ref": "v1"}},
+                {"name": "lab", "source": {"source": "git", "url": "https://gitlab.com/acme/tools.git",
+                 "sha": "0123abcd", "path": "plugins/lab"}},
+                {"name": "tea", "source": {"source": "url", "url": "https://gitea.example.com/acme/tea"}}
+              ]
+            }"#,
+        )
+        .unwrap();
+
+        let report = lint_manifests(tmp.path());
+        assert!(report.findings.is_empty(), "{:?}", report.findings);
+    }
+
+    #[test]
+    fn lint_manifests_reports_schema_disk_and_version_problems() {
+        let tmp = tempfile::tempdir().unwrap();
+        let root = tmp.path();
+
+        // Listed and on disk, but the skill is newer than plugin.json and the
+        // marketplace entry disagrees with plugin.json.
+        let skill_dir = root.join("analytics").join("skills").join("report");
+        fs::create_dir_all(&skill_dir).unwrap();
+        fs::write(
+            skill_dir.join("SKILL.md"),
+            "---\nname: report\nmetadata:\n  version: 2.1.0\n---\n",
+        )
+        .unwrap();
+        write_plugin_json(root, "analytics", "analytics", None, Some("2.0.0")).unwrap();
+
+        // On disk only, with the same plugin.json name as analytics and a
+        // dependency range that isn't semver.
+        fs::create_dir_all(root.join("devops").join("skills")).unwrap();
+        write_plugin_json(root, "devops", "analytics", None, None).unwrap();
+        fs::write(
+            root.join("devops/.claude-plugin/plugin.json"),
+            r#"{"name": "analytics", "dependencies": {"glossary": "one point oh"}}"#,
+        )
+        .unwrap();
+
+        let config = root.join(".claude-plugin");
+        fs::create_dir_all(&config).unwrap();
+        fs::write(
+            config.join("marketplace.json"),
+            r#"{
+              "name": "My Marketplace",
+              "owner": {},
+              "plugins": [
+                {"name": "analytics", "source": "./analytics", "version": "1.0.0",
+                 "checksums": {"skills/report/SKILL.md": "md5:d41d8cd9"}},
+                {"name": "analytics", "source": "../outside"},
+                {"name": "ghost", "source": "./ghost"},
+                {"name": "remote", "source": {"source": "github", "repo": "owner"}},
+                {"name": "escape", "source": {"source": "git", "url": "https://gitlab.com/acme/tools.git", "path": "../up"}}
+              ]
+            }"#,
+        )
+        .unwrap();
+
+        let report = lint_manifests(root);
+        let found = codes(&report);
+        for expected in [
+            ("invalid_name", None),
+            ("missing_field", None),
+            ("duplicate_name", Some("analytics")),
+            ("invalid_source", Some("analytics")),
+            ("invalid_checksum", Some("analytics")),
+            ("plugin_missing_on_disk", Some("ghost")),
+            ("invalid_source", Some("remote")),
+            ("invalid_source", Some("escape")),
+            ("plugin_not_listed", Some("devops")),
+            ("version_mismatch", Some("analytics")),
+            ("duplicate_name", Some("devops")),
+            ("invalid_dependency", Some("devops")),
+        ] {
+            assert!(
+                found.contains(&expected),
+                "missing {:?} in {:?}",
+                expected,
+                found
+            );
+        }
+        let skill_mismatch = report
+            .findings
+            .iter()
+            .find(|f| f.path == "analytics/skills/report/SKILL.md")
+            .expect("skill version finding");
+        assert_eq!(skill_mismatch.severity, ManifestLintSeverity::Warning);
+        assert!(skill_mismatch.message.contains("v2.1.0"));
+        assert_eq!(
+            report.error_count + report.warning_count,
+            report.findings.len()
+        );
+        assert!(report.error_count >= 5);
+    }
+
+    #[test]
+    fn lint_manifests_reports_missing_and_malformed_files() {
+        let tmp = tempfile::tempdir().unwrap();
+        fs::create_dir_all(tmp.path().join("analytics").join(".claude-plugin")).unwrap();
+        fs::write(
+            tmp.path()
+                .join("analytics")
+                .join(".claude-plugin")
+                .join("plugin.json"),
+            "{ not json",
+        )
+        .unwrap();
+
+        let report = lint_manifests(tmp.path());
+        assert_eq!(
+            codes(&report),
+            vec![("missing_file", None), ("invalid_json", Some("analytics"))]
+        );
+    }
 }
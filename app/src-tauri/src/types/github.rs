@@ -65,3 +65,25 @@ pub struct GitHubRepoInfo {
     #[serde(default)]
     pub subpath: Option<String>,
 }
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkillSourceKind {
+    Github,
+    Gitlab,
+    Gitea,
+    Git,
+    Local,
+}
+
+/// A marketplace source URL classified by where its files are read from.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillSourceInfo {
+    pub kind: SkillSourceKind,
+    /// Form stored in settings: `owner/repo[#branch]` for GitHub, the URL or path otherwise.
+    pub canonical_url: String,
+    /// Short human-readable name such as `owner/repo` or the folder name.
+    pub label: String,
+    pub branch: Option<String>,
+    pub subpath: Option<String>,
+}
@@ -26,6 +26,11 @@ pub struct MarketplacePlugin {
     pub author: Option<MarketplaceAuthor>,
     pub category: Option<String>,
     pub tags: Option<Vec<String>>,
+    /// Expected SHA-256 of files in the plugin, keyed by path relative to the
+    /// plugin directory; values are `sha256:<hex>` or bare hex. Downloads that
+    /// don't match fail the import.
+    #[serde(default)]
+    pub checksums: Option<HashMap<String, String>>,
 }
 
 #[derive(Debug, Deserialize)]
@@ -56,6 +61,23 @@ pub struct MarketplaceRegistry {
     pub enabled: bool,
 }
 
+/// Access token for a GitLab or Gitea host, sent with marketplace reads from it.
+#[derive(Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct SourceHostToken {
+    /// Host name without scheme, e.g. `gitlab.example.com`.
+    pub host: String,
+    pub token: String,
+}
+
+impl std::fmt::Debug for SourceHostToken {
+    fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+        f.debug_struct("SourceHostToken")
+            .field("host", &self.host)
+            .field("token", &"[REDACTED]")
+            .finish()
+    }
+}
+
 // ─── App settings ───────────────────────────────────────────────────────────
 
 fn default_log_level() -> String {
@@ -66,6 +88,10 @@ fn default_max_dimensions() -> u32 {
     5
 }
 
+fn default_document_token_budget() -> u32 {
+    crate::db::DEFAULT_DOCUMENT_TOKEN_BUDGET
+}
+
 fn default_true() -> bool {
     true
 }
@@ -368,8 +394,24 @@ pub struct AppSettings {
     pub github_user_avatar: Option<String>,
     #[serde(default)]
     pub github_user_email: Option<String>,
+    /// GitHub Enterprise Server host name (e.g. `github.example.com`).
+    /// `None` means github.com.
+    #[serde(default)]
+    pub github_host: Option<String>,
+    /// OAuth app client ID for the device flow. Required on GitHub Enterprise;
+    /// `None` uses Skill Builder's github.com app.
+    #[serde(default)]
+    pub github_oauth_client_id: Option<String>,
+    /// `owner/repo` that receives in-app feedback issues, on the configured
+    /// GitHub host. Required on GitHub Enterprise; `None` uses Skill Builder's
+    /// own github.com repository.
+    #[serde(default)]
+    pub feedback_repo: Option<String>,
     #[serde(default)]
     pub marketplace_registries: Vec<MarketplaceRegistry>,
+    /// Tokens for private GitLab and Gitea marketplaces, one per host.
+    #[serde(default)]
+    pub source_host_tokens: Vec<SourceHostToken>,
     #[serde(default = "default_max_dimensions")]
     pub max_dimensions: u32,
     #[serde(default)]
@@ -382,6 +424,14 @@ pub struct AppSettings {
     /// Automatically apply marketplace updates at startup (default: false).
     #[serde(default)]
     pub auto_update: bool,
+    /// Estimated-token budget for reference-document chunks injected into a
+    /// single workflow or scope-review prompt (default: 8000).
+    #[serde(default = "default_document_token_budget")]
+    pub document_token_budget: u32,
+    /// Serve marketplace catalogs from the local cache without contacting the
+    /// network (default: false).
+    #[serde(default)]
+    pub marketplace_offline: bool,
 }
 
 impl std::fmt::Debug for AppSettings {
@@ -398,12 +448,18 @@ impl std::fmt::Debug for AppSettings {
             .field("github_user_login", &self.github_user_login)
             .field("github_user_avatar", &self.github_user_avatar)
             .field("github_user_email", &self.github_user_email)
+            .field("github_host", &self.github_host)
+            .field("github_oauth_client_id", &self.github_oauth_client_id)
+            .field("feedback_repo", &self.feedback_repo)
             .field("marketplace_registries", &self.marketplace_registries)
+            .field("source_host_tokens", &self.source_host_tokens)
             .field("max_dimensions", &self.max_dimensions)
             .field("industry", &self.industry)
             .field("function_role", &self.function_role)
             .field("dashboard_view_mode", &self.dashboard_view_mode)
             .field("auto_update", &self.auto_update)
+            .field("document_token_budget", &self.document_token_budget)
+            .field("marketplace_offline", &self.marketplace_offline)
             .finish()
     }
 }
@@ -422,12 +478,18 @@ impl Default for AppSettings {
             github_user_login: None,
             github_user_avatar: None,
             github_user_email: None,
+            github_host: None,
+            github_oauth_client_id: None,
+            feedback_repo: None,
             marketplace_registries: vec![],
+            source_host_tokens: vec![],
             max_dimensions: 5,
             industry: None,
             function_role: None,
             dashboard_view_mode: None,
             auto_update: false,
+            document_token_budget: crate::db::DEFAULT_DOCUMENT_TOKEN_BUDGET,
+            marketplace_offline: false,
         }
     }
 }
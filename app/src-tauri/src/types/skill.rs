@@ -106,6 +106,26 @@ pub struct LibraryPlugin {
     pub is_default: bool,
     #[serde(default)]
     pub upgrade_locked: bool,
+    /// Plugins this one declares in `plugin.json` `dependencies`.
+    #[serde(default)]
+    pub dependencies: Vec<PluginDependency>,
+    /// Slugs of installed plugins that declare a dependency on this one.
+    #[serde(default)]
+    pub required_by: Vec<String>,
+}
+
+/// One `plugin.json` dependency and whether an installed plugin satisfies it.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct PluginDependency {
+    pub name: String,
+    /// Semver range, e.g. `^1.2`; `*` accepts any version.
+    pub version_req: String,
+    /// Version of the installed plugin with this name, if any.
+    #[serde(default)]
+    pub installed_version: Option<String>,
+    #[serde(default)]
+    pub installed: bool,
+    pub satisfied: bool,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -117,6 +137,25 @@ pub struct SkillFileMeta {
     pub disable_model_invocation: Option<bool>,
 }
 
+/// One skill found inside an uploaded archive.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ArchiveSkillMeta {
+    /// Directory of the skill inside the archive (`""` at the root, else ending in `/`).
+    pub path: String,
+    /// Frontmatter; `name` falls back to the directory name.
+    pub meta: SkillFileMeta,
+}
+
+/// Skills found in an uploaded archive, plus the archive's plugin metadata
+/// when it is a full plugin zip with `.claude-plugin/plugin.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillArchivePreview {
+    pub plugin_name: Option<String>,
+    pub plugin_description: Option<String>,
+    pub plugin_version: Option<String>,
+    pub skills: Vec<ArchiveSkillMeta>,
+}
+
 #[derive(Clone, Serialize, Deserialize)]
 pub struct SkillLock {
     pub skill_id: i64,
@@ -166,6 +205,24 @@ pub struct AvailablePlugin {
     pub version: Option<String>,
     pub skill_count: usize,
     pub skill_names: Vec<String>,
+    /// Set when the marketplace entry points at another repository instead of
+    /// a directory inside the marketplace repo. `path` is then relative to that repo.
+    #[serde(default)]
+    pub external_source: Option<ExternalPluginSource>,
+}
+
+/// A marketplace plugin hosted in its own repository
+/// (`{"source": "github" | "git" | "url", ...}` entries).
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct ExternalPluginSource {
+    pub owner: String,
+    pub repo: String,
+    /// Repo URL for plugins outside GitHub; `None` means `owner/repo` on the
+    /// marketplace's GitHub host.
+    #[serde(default)]
+    pub url: Option<String>,
+    /// Pinned commit SHA, tag or branch. `None` means the repo's default branch.
+    pub git_ref: Option<String>,
 }
 
 #[derive(Debug, Clone, Serialize, Deserialize, Default)]
@@ -186,3 +243,262 @@ pub struct SkillCommit {
     /// Semver version (e.g. "1.0.0") if this commit is tagged, None otherwise.
     pub version: Option<String>,
 }
+
+/// Per-file changes to a skill between two commits or version tags.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillVersionDiff {
+    pub from_sha: String,
+    pub to_sha: String,
+    pub files: Vec<SkillFileDiff>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillFileDiff {
+    /// Path relative to the skill directory.
+    pub path: String,
+    /// `"added"`, `"removed"` or `"modified"`.
+    pub status: String,
+    pub binary: bool,
+    pub additions: usize,
+    pub deletions: usize,
+    /// Unified diff text for the file (empty for binary files).
+    pub diff: String,
+    pub hunks: Vec<SkillDiffHunk>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillDiffHunk {
+    /// The `@@ -a,b +c,d @@` header line.
+    pub header: String,
+    pub old_start: u32,
+    pub old_lines: u32,
+    pub new_start: u32,
+    pub new_lines: u32,
+    pub lines: Vec<SkillDiffLine>,
+}
+
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillDiffLine {
+    /// `"context"`, `"added"` or `"removed"`.
+    pub kind: String,
+    /// Line text without the trailing newline.
+    pub content: String,
+    pub old_lineno: Option<u32>,
+    pub new_lineno: Option<u32>,
+}
+
+/// Which semver component a new skill version increments.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum VersionBump {
+    Major,
+    Minor,
+    Patch,
+}
+
+/// Non-Claude format a skill can be exported to. The SKILL.md description
+/// becomes each target's trigger metadata.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkillExportTarget {
+    /// A marked section for an `AGENTS.md` file, replaced in place on re-export.
+    AgentsMd,
+    /// A Cursor `.mdc` project rule, applied by the agent from its description.
+    CursorRule,
+    /// One Markdown prompt with the description and references inlined.
+    FlattenedMarkdown,
+}
+
+/// Bump derived from the changes since the latest version tag.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct VersionBumpSuggestion {
+    /// Latest tagged version, or `"0.0.0"` when the skill has no tags.
+    pub current_version: String,
+    pub bump: VersionBump,
+    pub next_version: String,
+    /// Human-readable explanation of why this bump was chosen.
+    pub reasons: Vec<String>,
+}
+
+/// A newly tagged skill version.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillVersionRelease {
+    pub version: String,
+    pub previous_version: Option<String>,
+    pub bump: VersionBump,
+    pub tag: String,
+}
+
+/// Remote configuration for a skill's git repo.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillGitRemote {
+    /// URL configured for this skill only.
+    pub skill_url: Option<String>,
+    /// Plugin-wide URL (may contain `{skill}`).
+    pub plugin_url: Option<String>,
+    /// URL used for push/fetch/pull after applying the plugin fallback.
+    pub resolved_url: Option<String>,
+}
+
+/// Outcome of a push, fetch or pull against a skill's remote.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillSyncResult {
+    /// `"up_to_date"`, `"pushed"`, `"fast_forwarded"`, `"ahead"`, `"behind"`
+    /// or `"diverged"`.
+    pub status: String,
+    pub branch: String,
+    pub local_sha: String,
+    pub remote_sha: Option<String>,
+    /// Local commits the remote does not have.
+    pub ahead: usize,
+    /// Remote commits the local branch does not have.
+    pub behind: usize,
+    pub pushed_tags: Vec<String>,
+    /// Refs the remote rejected, or the reason the branch could not be synced.
+    pub conflicts: Vec<String>,
+}
+
+/// A refine draft: a `draft/<name>` branch checked out in a skill's repo.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillDraft {
+    /// Draft name without the `draft/` prefix.
+    pub name: String,
+    pub branch: String,
+    /// Branch the draft was started from and merges back into.
+    pub base_branch: String,
+    pub base_sha: String,
+    pub head_sha: String,
+    /// Draft commits not on the base branch.
+    pub ahead: usize,
+    /// Base branch commits made after the draft started.
+    pub behind: usize,
+    /// Skill file changes from the base branch tip to the draft tip.
+    pub files: Vec<SkillFileDiff>,
+}
+
+/// Outcome of merging a draft back into its base branch.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillDraftMerge {
+    pub base_branch: String,
+    /// Commit the base branch now points to, before the release commit.
+    pub merged_sha: String,
+    /// `false` when the base branch moved on and a merge commit was created.
+    pub fast_forward: bool,
+    pub release: SkillVersionRelease,
+}
+
+/// A file that both the local copy and an upstream update changed in
+/// overlapping places.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillMergeConflict {
+    pub path: String,
+    /// Content at the last merged upstream version; `None` if the file is new.
+    pub base: Option<String>,
+    /// Local content; `None` if deleted locally.
+    pub local: Option<String>,
+    /// Incoming content; `None` if deleted upstream.
+    pub upstream: Option<String>,
+    /// Both sides merged, with `<<<<<<< local` / `>>>>>>> upstream` markers
+    /// around the overlapping hunks.
+    pub merged: String,
+}
+
+/// A pending three-way merge of an upstream update into an imported skill.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillUpdatePreview {
+    pub skill_name: String,
+    pub version: String,
+    /// Local commit the merge was computed against.
+    pub head_sha: String,
+    /// Commit on the `upstream` branch holding the incoming version.
+    pub upstream_sha: String,
+    /// Last upstream version merged locally.
+    pub base_sha: String,
+    /// Files changed upstream that merge without conflicts.
+    pub auto_merged: Vec<String>,
+    pub conflicts: Vec<SkillMergeConflict>,
+}
+
+/// How an imported skill on disk compares to what was downloaded.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum SkillIntegrityStatus {
+    /// Every file matches the recorded manifest.
+    Verified,
+    /// Files were changed, removed or added since the import.
+    Drifted,
+    /// The skill directory no longer exists.
+    Missing,
+    /// Imported before file manifests were recorded; re-import to pin it.
+    Unpinned,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum FileIntegrityChange {
+    Modified,
+    Removed,
+    Added,
+}
+
+/// One file that no longer matches the import manifest.
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct FileIntegrityIssue {
+    pub path: String,
+    pub change: FileIntegrityChange,
+    /// SHA-256 recorded at import; `None` for added files.
+    pub expected: Option<String>,
+    /// SHA-256 on disk now; `None` for removed files.
+    pub actual: Option<String>,
+}
+
+/// Result of checking one imported skill against its import manifest.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillIntegrityReport {
+    pub skill_name: String,
+    pub plugin_slug: String,
+    pub source_url: String,
+    pub version: Option<String>,
+    /// Marketplace commit the files were downloaded from, when the source could name one.
+    pub source_commit: Option<String>,
+    pub status: SkillIntegrityStatus,
+    pub files: Vec<FileIntegrityIssue>,
+}
+
+/// Outcome of committing an upstream update merge.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SkillUpdateApplied {
+    pub version: String,
+    pub merged_sha: String,
+    pub tag: String,
+}
+
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Serialize, Deserialize)]
+#[serde(rename_all = "snake_case")]
+pub enum ManifestLintSeverity {
+    Error,
+    Warning,
+}
+
+/// One problem found in `marketplace.json` or a plugin's `plugin.json`.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestLintFinding {
+    pub severity: ManifestLintSeverity,
+    /// Stable identifier such as `"missing_field"` or `"plugin_not_listed"`.
+    pub code: String,
+    /// File the finding applies to, relative to the skills root.
+    pub path: String,
+    /// Plugin slug or marketplace entry name, when the finding is about one plugin.
+    pub plugin: Option<String>,
+    pub message: String,
+}
+
+/// Result of linting the manifests under a skills root.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ManifestLintReport {
+    pub root: String,
+    pub plugins_checked: usize,
+    pub error_count: usize,
+    pub warning_count: usize,
+    pub findings: Vec<ManifestLintFinding>,
+}
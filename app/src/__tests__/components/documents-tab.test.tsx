@@ -5,19 +5,27 @@ import type { Document } from "@/lib/types";
 import { renderWithQueryClient } from "@/test/query-test-utils";
 
 const mocks = vi.hoisted(() => ({
+  addDocumentSite: vi.fn(),
   addDocumentUrl: vi.fn(),
   deleteDocument: vi.fn(),
   listDocuments: vi.fn(),
   listSkillsForDocuments: vi.fn(),
+  listSkillsWithChangedDocuments: vi.fn(),
+  refreshDocument: vi.fn(),
 }));
 
 vi.mock("@/lib/tauri", () => ({
   addDocumentFile: vi.fn(),
   addDocumentFolder: vi.fn(),
+  addDocumentPath: vi.fn(),
+  addDocumentSite: mocks.addDocumentSite,
   addDocumentUrl: mocks.addDocumentUrl,
   deleteDocument: mocks.deleteDocument,
   listDocuments: mocks.listDocuments,
   listSkillsForDocuments: mocks.listSkillsForDocuments,
+  listSkillsWithChangedDocuments: mocks.listSkillsWithChangedDocuments,
+  refreshDocument: mocks.refreshDocument,
+  refreshUrlDocuments: vi.fn(),
   updateDocument: vi.fn(),
 }));
 
@@ -42,11 +50,15 @@ const releaseNotes: Document = {
 
 describe("DocumentsTab", () => {
   beforeEach(() => {
+    mocks.addDocumentSite.mockReset();
     mocks.addDocumentUrl.mockReset();
     mocks.deleteDocument.mockReset();
     mocks.listDocuments.mockReset();
     mocks.listSkillsForDocuments.mockReset();
     mocks.listSkillsForDocuments.mockResolvedValue([]);
+    mocks.listSkillsWithChangedDocuments.mockReset();
+    mocks.listSkillsWithChangedDocuments.mockResolvedValue([]);
+    mocks.refreshDocument.mockReset();
   });
 
   it("renders the empty state from query data", async () => {
@@ -75,6 +87,32 @@ describe("DocumentsTab", () => {
     expect(mocks.addDocumentUrl).toHaveBeenCalledWith("API Docs", "https://api.example.com/docs", "all", []);
   });
 
+  it("crawls a site with limits and filters when crawl mode is on", async () => {
+    const intro = { ...releaseNotes, id: 3, name: "Data Docs/guide/intro", source_url: "https://docs.example.com/guide/intro" };
+    mocks.listDocuments.mockResolvedValue([]);
+    mocks.addDocumentSite.mockResolvedValue([intro]);
+    const user = userEvent.setup();
+
+    renderWithQueryClient(<DocumentsTab />);
+
+    await user.click(await screen.findByRole("button", { name: "Add URL" }));
+    await user.type(screen.getByPlaceholderText("e.g. Fabric Release Notes"), "Data Docs");
+    await user.type(screen.getByPlaceholderText("https://..."), "https://docs.example.com/");
+    await user.click(screen.getByLabelText("Crawl linked pages on this site"));
+    await user.type(screen.getByLabelText("Include URLs matching"), "*/guide/*");
+    await user.click(screen.getByRole("button", { name: "Crawl & Add" }));
+
+    expect(await screen.findByText("Data Docs/guide/intro")).toBeInTheDocument();
+    expect(mocks.addDocumentSite).toHaveBeenCalledWith(
+      "Data Docs",
+      "https://docs.example.com/",
+      { max_depth: 2, max_pages: 50, include_pattern: "*/guide/*", exclude_pattern: null },
+      "all",
+      [],
+    );
+    expect(mocks.addDocumentUrl).not.toHaveBeenCalled();
+  });
+
   it("deletes a document and removes the row from query data", async () => {
     mocks.listDocuments.mockResolvedValue([releaseNotes]);
     mocks.deleteDocument.mockResolvedValue(undefined);
@@ -89,4 +127,33 @@ describe("DocumentsTab", () => {
     expect(screen.getByText(/No documents added yet/)).toBeInTheDocument();
     expect(mocks.deleteDocument.mock.calls[0]?.[0]).toBe(1);
   });
+
+  it("refreshes a URL document and shows skills needing regeneration", async () => {
+    const staleSkill = {
+      skill_id: 7,
+      skill_name: "release-planning",
+      plugin_slug: "skill-builder",
+      generated_at: now,
+      changed_documents: ["Release Notes"],
+    };
+    mocks.listDocuments.mockResolvedValue([releaseNotes]);
+    mocks.refreshDocument.mockResolvedValue({
+      document: { ...releaseNotes, content_changed_at: "2026-02-01T00:00:00+00:00" },
+      changed: true,
+      stale_skills: [staleSkill],
+    });
+    const user = userEvent.setup();
+
+    renderWithQueryClient(<DocumentsTab />);
+
+    expect(await screen.findByText("Release Notes")).toBeInTheDocument();
+    expect(screen.queryByText(/Documents changed since/)).not.toBeInTheDocument();
+
+    mocks.listSkillsWithChangedDocuments.mockResolvedValue([staleSkill]);
+    await user.click(screen.getByRole("button", { name: "Refresh from URL" }));
+
+    expect(mocks.refreshDocument.mock.calls[0]?.[0]).toBe(1);
+    expect(await screen.findByText(/Documents changed since/)).toBeInTheDocument();
+    expect(screen.getByText("release-planning")).toBeInTheDocument();
+  });
 });
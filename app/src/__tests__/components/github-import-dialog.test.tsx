@@ -12,7 +12,13 @@ import type { AvailablePlugin } from "@/lib/types";
 import GitHubImportDialog from "@/components/github-import-dialog";
 import { toast } from "@/lib/toast";
 
-const DEFAULT_REPO_INFO = { owner: "acme", repo: "skills", branch: "main", subpath: null };
+const DEFAULT_SOURCE_INFO = {
+  kind: "github",
+  canonical_url: "acme/skills",
+  label: "acme/skills",
+  branch: "main",
+  subpath: null,
+};
 
 const samplePlugins: AvailablePlugin[] = [
   {
@@ -65,24 +71,24 @@ describe("GitHubImportDialog", () => {
   describe("Error state", () => {
     it("shows error message and Retry button after browse fails", async () => {
       mockInvoke.mockImplementation((cmd: string) => {
-        if (cmd === "parse_github_url") return Promise.reject(new Error("Invalid GitHub URL"));
+        if (cmd === "parse_skill_source") return Promise.reject(new Error("Source URL cannot be empty"));
         return Promise.reject(new Error(`Unmocked command: ${cmd}`));
       });
 
       renderDialog();
 
       await waitFor(() => {
-        expect(screen.getByText("Invalid GitHub URL")).toBeInTheDocument();
+        expect(screen.getByText("Source URL cannot be empty")).toBeInTheDocument();
       });
       expect(screen.getByRole("button", { name: /Retry/i })).toBeInTheDocument();
     });
   });
 
   describe("Empty state", () => {
-    it("shows no plugin rows when list_github_plugins returns empty array", async () => {
+    it("shows no plugin rows when list_marketplace_plugins returns empty array", async () => {
       mockInvokeCommands({
-        parse_github_url: DEFAULT_REPO_INFO,
-        list_github_plugins: [],
+        parse_skill_source: DEFAULT_SOURCE_INFO,
+        list_marketplace_plugins: [],
         list_plugins: [],
         list_skills: [],
       });
@@ -100,8 +106,8 @@ describe("GitHubImportDialog", () => {
     beforeEach(() => {
       resetTauriMocks();
       mockInvokeCommands({
-        parse_github_url: DEFAULT_REPO_INFO,
-        list_github_plugins: samplePlugins,
+        parse_skill_source: DEFAULT_SOURCE_INFO,
+        list_marketplace_plugins: samplePlugins,
         list_plugins: [],
         list_skills: [],
       });
@@ -135,8 +141,8 @@ describe("GitHubImportDialog", () => {
 
     it("shows Installed badge when plugin already exists locally", async () => {
       mockInvokeCommands({
-        parse_github_url: DEFAULT_REPO_INFO,
-        list_github_plugins: samplePlugins,
+        parse_skill_source: DEFAULT_SOURCE_INFO,
+        list_marketplace_plugins: samplePlugins,
         list_plugins: [
           {
             id: 1,
@@ -167,8 +173,8 @@ describe("GitHubImportDialog", () => {
       resetTauriMocks();
       onImported.mockReset().mockResolvedValue(undefined);
       mockInvokeCommands({
-        parse_github_url: DEFAULT_REPO_INFO,
-        list_github_plugins: samplePlugins,
+        parse_skill_source: DEFAULT_SOURCE_INFO,
+        list_marketplace_plugins: samplePlugins,
         list_plugins: [],
         list_skills: [],
       });
@@ -177,8 +183,8 @@ describe("GitHubImportDialog", () => {
     it("calls import_marketplace_plugin_to_library when install is clicked", async () => {
       const user = userEvent.setup();
       mockInvokeCommands({
-        parse_github_url: DEFAULT_REPO_INFO,
-        list_github_plugins: samplePlugins,
+        parse_skill_source: DEFAULT_SOURCE_INFO,
+        list_marketplace_plugins: samplePlugins,
         list_plugins: [],
         list_skills: [],
         import_marketplace_plugin_to_library: [{ skill_name: "analytics-helper", success: true, error: null }],
@@ -203,11 +209,48 @@ describe("GitHubImportDialog", () => {
       });
     });
 
+    it("passes the external source for plugins hosted in another repo", async () => {
+      const user = userEvent.setup();
+      const externalSource = { owner: "acme", repo: "remote-plugin", git_ref: "abc123def456" };
+      mockInvokeCommands({
+        parse_skill_source: DEFAULT_SOURCE_INFO,
+        list_marketplace_plugins: [
+          {
+            path: "",
+            name: "remote-pack",
+            description: null,
+            version: null,
+            skill_count: 0,
+            skill_names: [],
+            external_source: externalSource,
+          },
+        ],
+        list_plugins: [],
+        list_skills: [],
+        import_marketplace_plugin_to_library: [{ skill_name: "remote-helper", success: true, error: null }],
+      });
+
+      renderDialog({ onImported });
+
+      await waitFor(() => {
+        expect(screen.getByText("acme/remote-plugin@abc123def456")).toBeInTheDocument();
+      });
+
+      await user.click(screen.getByLabelText("Install remote-pack"));
+
+      await waitFor(() => {
+        expect(mockInvoke).toHaveBeenCalledWith(
+          "import_marketplace_plugin_to_library",
+          expect.objectContaining({ pluginPath: "", pluginName: "remote-pack", externalSource }),
+        );
+      });
+    });
+
     it("calls onImported and fires success toast on successful plugin import", async () => {
       const user = userEvent.setup();
       mockInvokeCommands({
-        parse_github_url: DEFAULT_REPO_INFO,
-        list_github_plugins: samplePlugins,
+        parse_skill_source: DEFAULT_SOURCE_INFO,
+        list_marketplace_plugins: samplePlugins,
         list_plugins: [],
         list_skills: [],
         import_marketplace_plugin_to_library: [{ skill_name: "analytics-helper", success: true, error: null }],
@@ -227,11 +270,44 @@ describe("GitHubImportDialog", () => {
       expect(toast.success).toHaveBeenCalledWith('Imported plugin "analytics-pack"');
     });
 
+    it("reports imported and unresolved plugin dependencies", async () => {
+      const user = userEvent.setup();
+      mockInvokeCommands({
+        parse_skill_source: DEFAULT_SOURCE_INFO,
+        list_marketplace_plugins: samplePlugins,
+        list_plugins: [],
+        list_skills: [],
+        import_marketplace_plugin_to_library: [
+          { skill_name: "analytics-helper", success: true, error: null },
+          { skill_name: "glossary", success: true, error: null, dependency_of: "analytics-pack" },
+          {
+            skill_name: "charts",
+            success: false,
+            error: "Dependency 'charts' (^2) of 'analytics-pack': not found in any enabled marketplace",
+            dependency_of: "analytics-pack",
+          },
+        ],
+      });
+
+      renderDialog({ onImported });
+
+      await user.click(await screen.findByLabelText("Install analytics-pack"));
+
+      await waitFor(() => {
+        expect(onImported).toHaveBeenCalledOnce();
+      });
+      expect(toast.success).toHaveBeenCalledWith('Imported plugin "analytics-pack" and its dependencies');
+      expect(toast.warning).toHaveBeenCalledWith(
+        "Dependency 'charts' (^2) of 'analytics-pack': not found in any enabled marketplace",
+        { duration: Infinity },
+      );
+    });
+
     it("shows Installed when plugin import only returns already-exists failures", async () => {
       const user = userEvent.setup();
       mockInvokeCommands({
-        parse_github_url: DEFAULT_REPO_INFO,
-        list_github_plugins: samplePlugins,
+        parse_skill_source: DEFAULT_SOURCE_INFO,
+        list_marketplace_plugins: samplePlugins,
         list_plugins: [],
         list_skills: [],
         import_marketplace_plugin_to_library: [{ skill_name: "analytics-helper", success: false, error: "already exists" }],
@@ -252,11 +328,39 @@ describe("GitHubImportDialog", () => {
     });
   });
 
+  describe("Non-GitHub sources", () => {
+    it("lists plugins from a local folder registry by its source URL", async () => {
+      mockInvokeCommands({
+        parse_skill_source: {
+          kind: "local",
+          canonical_url: "/mnt/shared/team-skills",
+          label: "team-skills",
+          branch: null,
+          subpath: null,
+        },
+        list_marketplace_plugins: samplePlugins,
+        list_plugins: [],
+        list_skills: [],
+      });
+
+      renderDialog({
+        registries: [{ name: "Shared Drive", source_url: "/mnt/shared/team-skills", enabled: true }],
+      });
+
+      await waitFor(() => {
+        expect(screen.getByText("2 plugins in team-skills")).toBeInTheDocument();
+      });
+      expect(mockInvoke).toHaveBeenCalledWith("list_marketplace_plugins", {
+        sourceUrl: "/mnt/shared/team-skills",
+      });
+    });
+  });
+
   describe("Multi-registry tabs", () => {
     it("renders one tab per registry", async () => {
       mockInvokeCommands({
-        parse_github_url: DEFAULT_REPO_INFO,
-        list_github_plugins: samplePlugins,
+        parse_skill_source: DEFAULT_SOURCE_INFO,
+        list_marketplace_plugins: samplePlugins,
         list_plugins: [],
         list_skills: [],
       });
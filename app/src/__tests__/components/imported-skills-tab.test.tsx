@@ -2,12 +2,13 @@ import { describe, it, expect, beforeEach, vi } from "vitest";
 import { screen, waitFor } from "@testing-library/react";
 import userEvent from "@testing-library/user-event";
 import {
+  mockDialogSave,
   mockInvoke,
   resetTauriMocks,
 } from "@/test/mocks/tauri";
 import { open as mockOpen } from "@tauri-apps/plugin-dialog";
 import { useSettingsStore } from "@/stores/settings-store";
-import type { AppSettings, LibraryPlugin } from "@/lib/types";
+import type { AppSettings, LibraryPlugin, ManifestLintReport, SkillIntegrityReport } from "@/lib/types";
 import { renderWithQueryClient as render } from "@/test/query-test-utils";
 
 vi.mock("@/lib/toast", () => ({
@@ -89,12 +90,93 @@ const samplePlugins: LibraryPlugin[] = [
   },
 ];
 
+const sampleLintReport: ManifestLintReport = {
+  root: "/home/user/skills",
+  plugins_checked: 2,
+  error_count: 1,
+  warning_count: 1,
+  findings: [
+    {
+      severity: "error",
+      code: "plugin_missing_on_disk",
+      path: ".claude-plugin/marketplace.json",
+      plugin: "ghost",
+      message: "Source './ghost' does not exist on disk",
+    },
+    {
+      severity: "warning",
+      code: "plugin_not_listed",
+      path: ".claude-plugin/marketplace.json",
+      plugin: "local-tools",
+      message: "Plugin directory 'local-tools' is not listed in marketplace.json",
+    },
+  ],
+};
+
+const sampleIntegrityReports: SkillIntegrityReport[] = [
+  {
+    skill_name: "standup",
+    plugin_slug: "eng",
+    source_url: "acme/skills",
+    version: "1.0.0",
+    source_commit: "0123456789abcdef0123456789abcdef01234567",
+    status: "drifted",
+    files: [{ path: "scripts/run.sh", change: "added", expected: null, actual: "ab" }],
+  },
+  {
+    skill_name: "report",
+    plugin_slug: "eng",
+    source_url: "acme/skills",
+    version: "1.0.0",
+    source_commit: "0123456789abcdef0123456789abcdef01234567",
+    status: "verified",
+    files: [],
+  },
+];
+
+const sampleArchivePreview = {
+  plugin_name: "Team Analytics",
+  plugin_description: "Shared analytics skills",
+  plugin_version: "1.0.0",
+  skills: [
+    {
+      path: "team-analytics/skills/churn/",
+      meta: { name: "churn", description: "Churn analysis", version: null, user_invocable: null, disable_model_invocation: null },
+    },
+    {
+      path: "team-analytics/skills/weekly-report/",
+      meta: { name: "weekly-report", description: "Weekly report", version: "1.2.0", user_invocable: null, disable_model_invocation: null },
+    },
+  ],
+};
+
 function setupMocks(plugins: LibraryPlugin[] = samplePlugins) {
   mockInvoke.mockImplementation((cmd: string) => {
     if (cmd === "get_settings") return Promise.resolve(defaultSettings);
     if (cmd === "list_plugins") return Promise.resolve(plugins);
-    if (cmd === "delete_plugin") return Promise.resolve(undefined);
-    if (cmd === "parse_skill_file") return Promise.resolve({ name: "test-skill", description: "desc", version: "1.0.0", user_invocable: null, disable_model_invocation: null });
+    if (cmd === "plugin_dependents") return Promise.resolve([]);
+    if (cmd === "delete_plugin") return Promise.resolve([]);
+    if (cmd === "lint_plugin_manifests") return Promise.resolve(sampleLintReport);
+    if (cmd === "verify_imported_skills") return Promise.resolve(sampleIntegrityReports);
+    if (cmd === "export_plugin_bundle") return Promise.resolve();
+    if (cmd === "import_plugin_bundle") {
+      return Promise.resolve([
+        { skill_name: "revenue", success: true, error: null },
+        {
+          skill_name: "data-glossary",
+          success: false,
+          error: "Dependency 'data-glossary' (^1.2) of 'analytics-pack': not found in any enabled marketplace",
+          dependency_of: "analytics-pack",
+        },
+      ]);
+    }
+    if (cmd === "preview_skill_archive") return Promise.resolve(sampleArchivePreview);
+    if (cmd === "import_skill_archive") {
+      return Promise.resolve([
+        { skill_name: "weekly-report", success: true, error: null },
+        { skill_name: "churn", success: true, error: null },
+      ]);
+    }
     return Promise.reject(new Error(`Unmocked command: ${cmd}`));
   });
 }
@@ -206,7 +288,7 @@ describe("ImportedSkillsTab", () => {
     expect(screen.getByLabelText("Delete Local Tools")).toBeInTheDocument();
   });
 
-  it("delete plugin shows success toast and refreshes list", async () => {
+  it("delete plugin confirms, shows success toast and refreshes list", async () => {
     const user = userEvent.setup();
     setupMocks();
     const { queryClient } = render(<ImportedSkillsTab />);
@@ -217,14 +299,17 @@ describe("ImportedSkillsTab", () => {
     });
 
     await user.click(screen.getByLabelText("Delete Analytics Pack"));
+    expect(mockInvoke).not.toHaveBeenCalledWith("delete_plugin", expect.anything());
+
+    await user.click(await screen.findByRole("button", { name: "Delete Plugin" }));
 
     await waitFor(() => {
-      expect(mockInvoke).toHaveBeenCalledWith("delete_plugin", { pluginSlug: "analytics-pack" });
+      expect(mockInvoke).toHaveBeenCalledWith("delete_plugin", {
+        pluginSlug: "analytics-pack",
+        force: false,
+      });
     });
-    expect(toast.success).toHaveBeenCalledWith(
-      'Deleted plugin "Analytics Pack"',
-      expect.anything(),
-    );
+    expect(toast.success).toHaveBeenCalledWith('Deleted plugin "Analytics Pack"');
     expect(invalidateSpy).toHaveBeenCalledWith({ queryKey: queryKeys.skills.all });
   });
 
@@ -282,4 +367,146 @@ describe("ImportedSkillsTab", () => {
     await new Promise((r) => setTimeout(r, 50));
     expect(mockInvoke).not.toHaveBeenCalledWith("import_skill_from_file", expect.anything());
   });
+
+  it("Validate shows manifest lint findings", async () => {
+    const user = userEvent.setup();
+    setupMocks();
+    render(<ImportedSkillsTab />);
+
+    await user.click(await screen.findByRole("button", { name: /Validate/ }));
+
+    expect(await screen.findByTestId("manifest-lint-summary")).toHaveTextContent(
+      "2 plugin(s) checked: 1 error(s), 1 warning(s)",
+    );
+    expect(screen.getByText("Source './ghost' does not exist on disk")).toBeInTheDocument();
+    expect(
+      screen.getByText("Plugin directory 'local-tools' is not listed in marketplace.json"),
+    ).toBeInTheDocument();
+    expect(mockInvoke).toHaveBeenCalledWith("lint_plugin_manifests", {});
+  });
+
+  it("Verify reports skills whose files drifted since import", async () => {
+    const user = userEvent.setup();
+    setupMocks();
+    render(<ImportedSkillsTab />);
+
+    await user.click(await screen.findByRole("button", { name: /Verify/ }));
+
+    expect(await screen.findByTestId("skill-integrity-summary")).toHaveTextContent(
+      "2 skill(s) checked: 1 verified, 1 need attention",
+    );
+    expect(screen.getByText("added: scripts/run.sh")).toBeInTheDocument();
+    expect(screen.queryByText("report")).not.toBeInTheDocument();
+    expect(mockInvoke).toHaveBeenCalledWith("verify_imported_skills", {});
+  });
+
+  it("shows plugin dependencies and confirms before deleting a required plugin", async () => {
+    const user = userEvent.setup();
+    const plugins: LibraryPlugin[] = samplePlugins.map((plugin) =>
+      plugin.slug === "analytics-pack"
+        ? {
+            ...plugin,
+            dependencies: [
+              { name: "local-tools", version_req: "^2.0", installed_version: "2.0.0", installed: true, satisfied: true },
+              { name: "data-glossary", version_req: "^1.2", installed_version: null, installed: false, satisfied: false },
+            ],
+          }
+        : plugin.slug === "local-tools"
+          ? { ...plugin, required_by: ["analytics-pack"] }
+          : plugin,
+    );
+    mockInvoke.mockImplementation((cmd: string) => {
+      if (cmd === "get_settings") return Promise.resolve(defaultSettings);
+      if (cmd === "list_plugins") return Promise.resolve(plugins);
+      if (cmd === "plugin_dependents") return Promise.resolve(["analytics-pack"]);
+      if (cmd === "delete_plugin") return Promise.resolve(["analytics-pack"]);
+      return Promise.reject(new Error(`Unmocked command: ${cmd}`));
+    });
+    render(<ImportedSkillsTab />);
+
+    const deps = await screen.findByLabelText("Dependencies of Analytics Pack");
+    expect(deps).toHaveTextContent("local-tools ^2.0");
+    expect(deps).toHaveTextContent("data-glossary ^1.2 (missing)");
+
+    await user.click(screen.getByLabelText("Delete Local Tools"));
+
+    expect(await screen.findByText(/Required by analytics-pack/)).toBeInTheDocument();
+    await user.click(screen.getByRole("button", { name: "Delete Anyway" }));
+
+    await waitFor(() => {
+      expect(mockInvoke).toHaveBeenCalledWith("delete_plugin", {
+        pluginSlug: "local-tools",
+        force: true,
+      });
+    });
+  });
+
+  it("exports a plugin bundle without eval scenarios when unchecked", async () => {
+    const user = userEvent.setup();
+    setupMocks();
+    mockDialogSave.mockResolvedValue("/tmp/analytics-pack.zip");
+    render(<ImportedSkillsTab />);
+
+    await user.click(await screen.findByLabelText("Export Analytics Pack"));
+    await user.click(screen.getByLabelText("Include eval scenarios"));
+    await user.click(screen.getByRole("button", { name: "Export" }));
+
+    await waitFor(() => {
+      expect(mockInvoke).toHaveBeenCalledWith("export_plugin_bundle", {
+        pluginSlug: "analytics-pack",
+        destPath: "/tmp/analytics-pack.zip",
+        includeEvals: false,
+      });
+    });
+    await waitFor(() => {
+      expect(toast.success).toHaveBeenCalledWith('Exported plugin "Analytics Pack"');
+    });
+  });
+
+  it("imports a plugin bundle and warns about unresolved dependencies", async () => {
+    const user = userEvent.setup();
+    setupMocks();
+    (mockOpen as ReturnType<typeof vi.fn>).mockResolvedValue("/tmp/analytics-pack.zip");
+    render(<ImportedSkillsTab />);
+
+    await user.click(await screen.findByRole("button", { name: "Bundle" }));
+
+    await waitFor(() => {
+      expect(mockInvoke).toHaveBeenCalledWith("import_plugin_bundle", {
+        filePath: "/tmp/analytics-pack.zip",
+      });
+    });
+    await waitFor(() => {
+      expect(toast.success).toHaveBeenCalledWith("Imported 1 skill(s) from bundle", { id: "toast-id" });
+    });
+    expect(toast.warning).toHaveBeenCalledWith(
+      "Dependency 'data-glossary' (^1.2) of 'analytics-pack': not found in any enabled marketplace",
+    );
+  });
+
+  it("previews a plugin archive and imports its skills into a new plugin", async () => {
+    const user = userEvent.setup();
+    setupMocks();
+    (mockOpen as ReturnType<typeof vi.fn>).mockResolvedValue("/tmp/team-analytics.zip");
+    render(<ImportedSkillsTab />);
+
+    await user.click(await screen.findByRole("button", { name: "Upload" }));
+
+    expect(await screen.findByText("Import Skills")).toBeInTheDocument();
+    expect(screen.getByText("Churn analysis")).toBeInTheDocument();
+    expect(screen.getByLabelText("New plugin name")).toHaveValue("Team Analytics");
+
+    await user.click(screen.getByRole("checkbox", { name: "Import churn" }));
+    await user.click(screen.getByRole("button", { name: "Import 1 skill(s)" }));
+
+    await waitFor(() => {
+      expect(mockInvoke).toHaveBeenCalledWith("import_skill_archive", {
+        filePath: "/tmp/team-analytics.zip",
+        skillPaths: ["team-analytics/skills/weekly-report/"],
+        pluginSlug: null,
+        newPluginName: "Team Analytics",
+      });
+    });
+    expect(mockInvoke).not.toHaveBeenCalledWith("import_skill_from_file", expect.anything());
+  });
 });
@@ -31,7 +31,12 @@ function makeMenuState(overrides: Partial<SkillMenuState> = {}): SkillMenuState
   };
 }
 
-function renderMenu(skill: UnifiedSkill, menuState: SkillMenuState, onExport = vi.fn()) {
+function renderMenu(
+  skill: UnifiedSkill,
+  menuState: SkillMenuState,
+  onExport = vi.fn(),
+  onExportToTarget = vi.fn(),
+) {
   return render(
     <SkillContextMenu
       skill={skill}
@@ -48,6 +53,7 @@ function renderMenu(skill: UnifiedSkill, menuState: SkillMenuState, onExport = v
       onMoveToPlugin={vi.fn()}
       onRemoveFromPlugin={vi.fn()}
       onExport={onExport}
+      onExportToTarget={onExportToTarget}
       pluginOptions={[]}
     >
       <div data-testid="skill-row">{skill.name}</div>
@@ -93,6 +99,34 @@ describe("SkillContextMenu", () => {
     renderMenu(skill, makeMenuState(), onExport);
     fireEvent.contextMenu(screen.getByTestId("skill-row"));
     await user.click(screen.getByText("Export as .skill"));
-    expect(onExport).toHaveBeenCalledWith(skill);
+    expect(onExport).toHaveBeenCalledWith(skill, false);
+  });
+
+  it("asks for git history when Export as .skill with git history is selected", async () => {
+    const user = userEvent.setup();
+    const onExport = vi.fn();
+    const skill = makeSkill();
+    renderMenu(skill, makeMenuState(), onExport);
+    fireEvent.contextMenu(screen.getByTestId("skill-row"));
+    await user.click(screen.getByText("Export as .skill with git history"));
+    expect(onExport).toHaveBeenCalledWith(skill, true);
+  });
+
+  it("calls onExportToTarget with the chosen format", async () => {
+    const user = userEvent.setup();
+    const onExportToTarget = vi.fn();
+    const skill = makeSkill();
+    renderMenu(skill, makeMenuState(), vi.fn(), onExportToTarget);
+    fireEvent.contextMenu(screen.getByTestId("skill-row"));
+    expect(screen.getByText("Export as AGENTS.md section")).toBeInTheDocument();
+    expect(screen.getByText("Export as flattened Markdown")).toBeInTheDocument();
+    await user.click(screen.getByText("Export as Cursor rule"));
+    expect(onExportToTarget).toHaveBeenCalledWith(skill, "cursor_rule");
+  });
+
+  it("does not offer other-tool exports for a marketplace skill", async () => {
+    renderMenu(makeSkill({ source: "marketplace" }), makeMenuState({ isBuilder: false }));
+    fireEvent.contextMenu(screen.getByTestId("skill-row"));
+    expect(screen.queryByText("Export as Cursor rule")).not.toBeInTheDocument();
   });
 });
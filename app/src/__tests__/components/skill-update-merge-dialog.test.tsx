@@ -0,0 +1,77 @@
+import { describe, it, expect, vi, beforeEach } from "vitest";
+import { screen, waitFor } from "@testing-library/react";
+import userEvent from "@testing-library/user-event";
+import { renderWithQueryClient as render } from "@/test/query-test-utils";
+import type { SkillUpdatePreview } from "@/lib/types";
+
+vi.mock("@/lib/toast", () => ({
+  toast: {
+    success: vi.fn(),
+    error: vi.fn(),
+  },
+}));
+
+vi.mock("@/lib/tauri", () => ({
+  applyImportedSkillUpdate: vi.fn(),
+}));
+
+import { SkillUpdateMergeDialog } from "@/components/skill-update-merge-dialog";
+import { applyImportedSkillUpdate } from "@/lib/tauri";
+import { useSettingsStore } from "@/stores/settings-store";
+
+const preview: SkillUpdatePreview = {
+  skill_name: "crm",
+  version: "1.1.0",
+  head_sha: "abc",
+  upstream_sha: "def",
+  base_sha: "012",
+  auto_merged: ["references/api.md"],
+  conflicts: [
+    {
+      path: "SKILL.md",
+      base: "1. a\n",
+      local: "1. local\n",
+      upstream: "1. upstream\n",
+      merged: "<<<<<<< local\n1. local\n=======\n1. upstream\n>>>>>>> upstream\n",
+    },
+  ],
+};
+
+describe("SkillUpdateMergeDialog", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+    useSettingsStore.getState().reset();
+  });
+
+  it("renders nothing without pending merges", () => {
+    render(<SkillUpdateMergeDialog />);
+    expect(screen.queryByText(/Merge crm/)).not.toBeInTheDocument();
+  });
+
+  it("requires conflicts to be resolved before committing the merge", async () => {
+    const user = userEvent.setup();
+    vi.mocked(applyImportedSkillUpdate).mockResolvedValue({
+      version: "1.1.0",
+      merged_sha: "fff",
+      tag: "skills/crm/v1.1.0",
+    });
+    useSettingsStore.getState().setPendingSkillMerges([{ preview, sourceUrl: "acme/skills" }]);
+    render(<SkillUpdateMergeDialog />);
+
+    expect(screen.getByText("Merge crm 1.1.0")).toBeInTheDocument();
+    expect(screen.getByText(/Merged automatically: references\/api.md/)).toBeInTheDocument();
+    expect(screen.getByLabelText("Resolution for SKILL.md")).toHaveValue(preview.conflicts[0].merged);
+    expect(screen.getByRole("button", { name: /Commit merge/ })).toBeDisabled();
+
+    await user.click(screen.getByRole("button", { name: "Keep local" }));
+    expect(screen.getByLabelText("Resolution for SKILL.md")).toHaveValue("1. local\n");
+    await user.click(screen.getByRole("button", { name: /Commit merge/ }));
+
+    await waitFor(() =>
+      expect(applyImportedSkillUpdate).toHaveBeenCalledWith(preview, "acme/skills", {
+        "SKILL.md": "1. local\n",
+      }),
+    );
+    await waitFor(() => expect(useSettingsStore.getState().pendingSkillMerges).toEqual([]));
+  });
+});
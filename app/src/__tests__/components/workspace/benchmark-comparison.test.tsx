@@ -0,0 +1,69 @@
+import { describe, expect, it } from "vitest";
+import { render, screen } from "@testing-library/react";
+import { BenchmarkComparisonResults } from "@/components/workspace/eval-workbench/benchmark-comparison";
+import type { BenchmarkComparison, BenchmarkRun } from "@/lib/eval-workbench";
+
+function run(overrides: Partial<BenchmarkRun>): BenchmarkRun {
+  return {
+    passed: true,
+    passedAssertions: 2,
+    decidedAssertions: 2,
+    passRate: 1,
+    totalTokens: null,
+    totalCostUsd: null,
+    numTurns: null,
+    durationMs: 1000,
+    error: null,
+    outcomes: [],
+    ...overrides,
+  };
+}
+
+const report: BenchmarkComparison = {
+  pluginSlug: "skills",
+  skillName: "forecast-skill",
+  model: "sonnet",
+  generatedAt: "2026-10-01T00:00:00Z",
+  baseline: { reference: "no-skill", label: "No skill", commit: null, version: null },
+  candidate: { reference: "1.1.0", label: "v1.1.0", commit: "abc1234", version: "1.1.0" },
+  scenarios: [
+    {
+      scenarioName: "Forecast",
+      prompt: "Forecast Q3 ARR",
+      baseline: run({ passed: false, passedAssertions: 1, passRate: 0.5, totalCostUsd: 0.01 }),
+      candidate: run({ totalCostUsd: 0.03 }),
+      passRateDelta: 0.5,
+      tokenDelta: null,
+      costDeltaUsd: 0.02,
+      durationDeltaMs: 0,
+    },
+    {
+      scenarioName: "Edge",
+      prompt: "Empty data",
+      baseline: run({ error: "timed out", passed: false }),
+      candidate: run({ passed: null, decidedAssertions: 0, passedAssertions: 0, passRate: null }),
+      passRateDelta: null,
+      tokenDelta: null,
+      costDeltaUsd: null,
+      durationDeltaMs: 0,
+    },
+  ],
+  baselinePassRate: 0,
+  candidatePassRate: 1,
+};
+
+describe("BenchmarkComparisonResults", () => {
+  it("shows both sides and per-scenario deltas", () => {
+    render(<BenchmarkComparisonResults report={report} />);
+
+    expect(screen.getByText(/of graded scenarios pass/)).toHaveTextContent(
+      "No skill 0% → v1.1.0 100% of graded scenarios pass",
+    );
+    const [, forecast, edge] = screen.getAllByRole("row");
+    expect(forecast).toHaveTextContent("fail (1/2)");
+    expect(forecast).toHaveTextContent("+50 pts");
+    expect(forecast).toHaveTextContent("+$0.0200");
+    expect(edge).toHaveTextContent("error");
+    expect(edge).toHaveTextContent("ungraded (0/0)");
+  });
+});
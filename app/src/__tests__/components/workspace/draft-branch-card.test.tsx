@@ -0,0 +1,108 @@
+import { describe, it, expect, vi, beforeEach } from "vitest";
+import { render, screen, waitFor } from "@testing-library/react";
+import userEvent from "@testing-library/user-event";
+import type { SkillDraft } from "@/lib/types";
+
+vi.mock("@/lib/toast", () => ({
+  toast: {
+    success: vi.fn(),
+    error: vi.fn(),
+    warning: vi.fn(),
+  },
+}));
+
+vi.mock("@/lib/tauri", () => ({
+  getSkillDraft: vi.fn(),
+  startSkillDraft: vi.fn(),
+  mergeSkillDraft: vi.fn(),
+  discardSkillDraft: vi.fn().mockResolvedValue(undefined),
+}));
+
+const setLatestVersion = vi.fn();
+vi.mock("@/stores/skill-store", () => ({
+  useSkillStore: { getState: () => ({ setLatestVersion }) },
+}));
+
+import { DraftBranchCard } from "@/components/workspace/draft-branch-card";
+import { discardSkillDraft, getSkillDraft, mergeSkillDraft, startSkillDraft } from "@/lib/tauri";
+
+const draft = (overrides: Partial<SkillDraft> = {}): SkillDraft => ({
+  name: "tighten",
+  branch: "draft/tighten",
+  base_branch: "main",
+  base_sha: "abc",
+  head_sha: "def",
+  ahead: 2,
+  behind: 0,
+  files: [
+    {
+      path: "SKILL.md",
+      status: "modified",
+      binary: false,
+      additions: 4,
+      deletions: 1,
+      diff: "",
+      hunks: [],
+    },
+  ],
+  ...overrides,
+});
+
+describe("DraftBranchCard", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it("starts a named draft", async () => {
+    const user = userEvent.setup();
+    const onChanged = vi.fn();
+    vi.mocked(getSkillDraft).mockResolvedValue(null);
+    vi.mocked(startSkillDraft).mockResolvedValue(draft({ ahead: 0, files: [] }));
+    render(<DraftBranchCard skillName="crm" pluginSlug="sales" onChanged={onChanged} />);
+
+    await waitFor(() => expect(getSkillDraft).toHaveBeenCalledWith("crm", "sales"));
+    await user.type(screen.getByLabelText("Draft name"), "tighten");
+    await user.click(screen.getByRole("button", { name: "Start Draft" }));
+
+    expect(startSkillDraft).toHaveBeenCalledWith("crm", "sales", "tighten");
+    expect(await screen.findByTestId("skill-draft-status")).toHaveTextContent(
+      "On draft/tighten — 0 commit(s) ahead of main",
+    );
+    expect(screen.getByText("No changes yet")).toBeInTheDocument();
+    expect(onChanged).toHaveBeenCalledTimes(1);
+  });
+
+  it("previews changes and merges with a version tag", async () => {
+    const user = userEvent.setup();
+    vi.mocked(getSkillDraft).mockResolvedValue(draft());
+    vi.mocked(mergeSkillDraft).mockResolvedValue({
+      base_branch: "main",
+      merged_sha: "def",
+      fast_forward: true,
+      release: { version: "1.1.0", previous_version: "1.0.0", bump: "minor", tag: "sales/crm/v1.1.0" },
+    });
+    render(<DraftBranchCard skillName="crm" pluginSlug="sales" />);
+
+    expect(await screen.findByText("SKILL.md")).toBeInTheDocument();
+    expect(screen.getByText("+4 −1")).toBeInTheDocument();
+
+    await user.click(screen.getByRole("button", { name: "Merge & Tag" }));
+
+    await waitFor(() => expect(setLatestVersion).toHaveBeenCalledWith("1.1.0"));
+    expect(mergeSkillDraft).toHaveBeenCalledWith("crm", "sales");
+    expect(screen.getByRole("button", { name: "Start Draft" })).toBeInTheDocument();
+  });
+
+  it("requires confirmation before discarding", async () => {
+    const user = userEvent.setup();
+    vi.mocked(getSkillDraft).mockResolvedValue(draft());
+    render(<DraftBranchCard skillName="crm" pluginSlug="sales" />);
+
+    await user.click(await screen.findByRole("button", { name: "Discard" }));
+    expect(discardSkillDraft).not.toHaveBeenCalled();
+
+    await user.click(screen.getByRole("button", { name: "Confirm Discard" }));
+    await waitFor(() => expect(discardSkillDraft).toHaveBeenCalledWith("crm", "sales"));
+    expect(screen.getByRole("button", { name: "Start Draft" })).toBeInTheDocument();
+  });
+});
@@ -0,0 +1,64 @@
+import { describe, it, expect, vi, beforeEach } from "vitest";
+import { render, screen, waitFor } from "@testing-library/react";
+import userEvent from "@testing-library/user-event";
+
+vi.mock("@/lib/toast", () => ({
+  toast: {
+    success: vi.fn(),
+    error: vi.fn(),
+  },
+}));
+
+vi.mock("@/lib/tauri", () => ({
+  suggestSkillVersionBump: vi.fn(),
+  releaseSkillVersion: vi.fn(),
+}));
+
+const setLatestVersion = vi.fn();
+vi.mock("@/stores/skill-store", () => ({
+  useSkillStore: { getState: () => ({ setLatestVersion }) },
+}));
+
+import NewVersionDialog, { bumpVersion } from "@/components/workspace/new-version-dialog";
+import { releaseSkillVersion, suggestSkillVersionBump } from "@/lib/tauri";
+
+describe("bumpVersion", () => {
+  it("resets lower components and treats invalid input as 0.0.0", () => {
+    expect(bumpVersion("1.4.2", "major")).toBe("2.0.0");
+    expect(bumpVersion("1.4.2", "minor")).toBe("1.5.0");
+    expect(bumpVersion("1.4.2", "patch")).toBe("1.4.3");
+    expect(bumpVersion("not-semver", "patch")).toBe("0.0.1");
+  });
+});
+
+describe("NewVersionDialog", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it("shows the suggested bump and tags the release", async () => {
+    const user = userEvent.setup();
+    const onOpenChange = vi.fn();
+    vi.mocked(suggestSkillVersionBump).mockResolvedValue({
+      current_version: "1.2.3",
+      bump: "minor",
+      next_version: "1.3.0",
+      reasons: ["Added references/api.md"],
+    });
+    vi.mocked(releaseSkillVersion).mockResolvedValue({
+      version: "1.3.0",
+      previous_version: "1.2.3",
+      bump: "minor",
+      tag: "skills/crm/v1.3.0",
+    });
+
+    render(<NewVersionDialog skillName="crm" pluginSlug="skills" open onOpenChange={onOpenChange} />);
+
+    expect(await screen.findByText("Added references/api.md")).toBeInTheDocument();
+    await user.click(screen.getByRole("button", { name: "Tag v1.3.0" }));
+
+    await waitFor(() => expect(releaseSkillVersion).toHaveBeenCalledWith("crm", "skills", "minor"));
+    expect(setLatestVersion).toHaveBeenCalledWith("1.3.0");
+    expect(onOpenChange).toHaveBeenCalledWith(false);
+  });
+});
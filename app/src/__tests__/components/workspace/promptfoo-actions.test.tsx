@@ -0,0 +1,62 @@
+import { beforeEach, describe, expect, it, vi } from "vitest";
+import { fireEvent, screen, waitFor } from "@testing-library/react";
+import { open as mockOpen } from "@tauri-apps/plugin-dialog";
+import { mockDialogSave, mockInvoke, resetTauriMocks } from "@/test/mocks/tauri";
+import { renderWithQueryClient as render } from "@/test/query-test-utils";
+import { toast } from "@/lib/toast";
+import { PromptfooActions } from "@/components/workspace/eval-workbench/promptfoo-actions";
+
+vi.mock("@/lib/toast", () => ({
+  toast: {
+    success: vi.fn(),
+    error: vi.fn(),
+    warning: vi.fn(),
+  },
+}));
+
+describe("PromptfooActions", () => {
+  beforeEach(() => {
+    resetTauriMocks();
+    vi.mocked(toast.success).mockClear();
+    vi.mocked(toast.warning).mockClear();
+  });
+
+  it("imports a chosen config and surfaces skipped assertions", async () => {
+    vi.mocked(mockOpen).mockResolvedValue("/tmp/promptfooconfig.yaml");
+    mockInvoke.mockResolvedValue({
+      scenarios: [{ name: "Churn", tags: ["performance"] }],
+      warnings: ["'Churn': skipped assertion: javascript assertions have no workbench equivalent"],
+    });
+
+    render(<PromptfooActions skillName="forecast-skill" pluginSlug="skills" canExport />);
+    fireEvent.click(screen.getByRole("button", { name: "Import promptfoo" }));
+
+    await waitFor(() =>
+      expect(toast.warning).toHaveBeenCalledWith(
+        "Imported 1 scenario (1 warning)",
+        expect.objectContaining({ description: expect.stringContaining("javascript") }),
+      ),
+    );
+    expect(mockInvoke).toHaveBeenCalledWith("import_promptfoo_config", {
+      pluginSlug: "skills",
+      skillName: "forecast-skill",
+      sourcePath: "/tmp/promptfooconfig.yaml",
+    });
+  });
+
+  it("exports to the chosen path", async () => {
+    mockDialogSave.mockResolvedValue("/tmp/promptfooconfig.yaml");
+    mockInvoke.mockResolvedValue({ scenarioCount: 2, warnings: [] });
+
+    render(<PromptfooActions skillName="forecast-skill" pluginSlug="skills" canExport />);
+    fireEvent.click(screen.getByRole("button", { name: "Export promptfoo" }));
+
+    await waitFor(() => expect(toast.success).toHaveBeenCalledWith("Exported 2 scenarios"));
+  });
+
+  it("disables export without performance scenarios", () => {
+    render(<PromptfooActions skillName="forecast-skill" pluginSlug="skills" canExport={false} />);
+
+    expect(screen.getByRole("button", { name: "Export promptfoo" })).toBeDisabled();
+  });
+});
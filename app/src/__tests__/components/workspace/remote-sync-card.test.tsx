@@ -0,0 +1,89 @@
+import { describe, it, expect, vi, beforeEach } from "vitest";
+import { render, screen, waitFor } from "@testing-library/react";
+import userEvent from "@testing-library/user-event";
+import type { SkillSyncResult } from "@/lib/types";
+
+vi.mock("@/lib/toast", () => ({
+  toast: {
+    success: vi.fn(),
+    error: vi.fn(),
+    warning: vi.fn(),
+  },
+}));
+
+vi.mock("@/lib/tauri", () => ({
+  getSkillGitRemote: vi.fn(),
+  setSkillGitRemote: vi.fn().mockResolvedValue(undefined),
+  fetchSkillRemote: vi.fn(),
+  pullSkillRemote: vi.fn(),
+  pushSkillRemote: vi.fn(),
+}));
+
+import { RemoteSyncCard } from "@/components/workspace/remote-sync-card";
+import { getSkillGitRemote, pullSkillRemote, pushSkillRemote, setSkillGitRemote } from "@/lib/tauri";
+import { toast } from "@/lib/toast";
+
+const syncResult = (overrides: Partial<SkillSyncResult>): SkillSyncResult => ({
+  status: "up_to_date",
+  branch: "main",
+  local_sha: "abc",
+  remote_sha: "abc",
+  ahead: 0,
+  behind: 0,
+  pushed_tags: [],
+  conflicts: [],
+  ...overrides,
+});
+
+describe("RemoteSyncCard", () => {
+  beforeEach(() => {
+    vi.clearAllMocks();
+  });
+
+  it("disables sync until a remote is configured and saves a plugin-wide URL", async () => {
+    const user = userEvent.setup();
+    vi.mocked(getSkillGitRemote).mockResolvedValue({ skill_url: null, plugin_url: null, resolved_url: null });
+    render(<RemoteSyncCard skillName="crm" pluginSlug="sales" />);
+
+    await waitFor(() => expect(getSkillGitRemote).toHaveBeenCalledWith("crm", "sales"));
+    expect(screen.getByRole("button", { name: "Push" })).toBeDisabled();
+
+    await user.type(screen.getByLabelText("Remote URL"), "/srv/git/{{skill}.git");
+    await user.click(screen.getByRole("switch"));
+    await user.click(screen.getByRole("button", { name: "Save" }));
+
+    await waitFor(() => expect(setSkillGitRemote).toHaveBeenCalledWith("sales", null, "/srv/git/{skill}.git"));
+  });
+
+  it("reports diverged pushes and refreshes after a fast-forward pull", async () => {
+    const user = userEvent.setup();
+    const onPulled = vi.fn();
+    vi.mocked(getSkillGitRemote).mockResolvedValue({
+      skill_url: "/srv/git/crm.git",
+      plugin_url: null,
+      resolved_url: "/srv/git/crm.git",
+    });
+    vi.mocked(pushSkillRemote).mockResolvedValue(
+      syncResult({
+        status: "diverged",
+        ahead: 1,
+        behind: 2,
+        conflicts: ["Local and remote 'main' have diverged"],
+      }),
+    );
+    vi.mocked(pullSkillRemote).mockResolvedValue(syncResult({ status: "fast_forwarded" }));
+    render(<RemoteSyncCard skillName="crm" pluginSlug="sales" onPulled={onPulled} />);
+
+    await waitFor(() => expect(screen.getByText("Syncing with /srv/git/crm.git")).toBeInTheDocument());
+    await user.click(screen.getByRole("button", { name: "Push" }));
+
+    expect(await screen.findByTestId("remote-sync-status")).toHaveTextContent("Diverged: 1 local, 2 remote commit(s)");
+    expect(screen.getByText("Local and remote 'main' have diverged")).toBeInTheDocument();
+    expect(toast.warning).toHaveBeenCalled();
+    expect(onPulled).not.toHaveBeenCalled();
+
+    await user.click(screen.getByRole("button", { name: "Pull" }));
+    await waitFor(() => expect(onPulled).toHaveBeenCalledTimes(1));
+    expect(screen.getByTestId("remote-sync-status")).toHaveTextContent("Pulled latest main");
+  });
+});
@@ -0,0 +1,45 @@
+import { describe, expect, it } from "vitest";
+import { render, screen } from "@testing-library/react";
+import { ScenarioRunResults } from "@/components/workspace/eval-workbench/scenario-run";
+import type { BenchmarkRun } from "@/lib/eval-workbench";
+
+function run(overrides: Partial<BenchmarkRun>): BenchmarkRun {
+  return {
+    passed: false,
+    passedAssertions: 1,
+    decidedAssertions: 2,
+    passRate: 0.5,
+    totalTokens: null,
+    totalCostUsd: 0.012,
+    numTurns: 4,
+    durationMs: 1000,
+    error: null,
+    outcomes: [
+      { assertion: { type: "contains", value: "ARR" }, passed: true, reason: "Output contains 'ARR'" },
+      { assertion: "Explains the forecast method", passed: false, reason: "No method given" },
+    ],
+    ...overrides,
+  };
+}
+
+describe("ScenarioRunResults", () => {
+  it("lists every assertion with its verdict and reason", () => {
+    render(<ScenarioRunResults run={run({})} />);
+
+    expect(screen.getByTestId("scenario-run-results")).toHaveTextContent(
+      "Failed · 1/2 decided assertions pass · 4 turns · $0.0120",
+    );
+    expect(screen.getByText("contains")).toBeInTheDocument();
+    expect(screen.getByText("Explains the forecast method")).toBeInTheDocument();
+    expect(screen.getByText("No method given")).toBeInTheDocument();
+    expect(screen.getByLabelText("Passed")).toBeInTheDocument();
+    expect(screen.getByLabelText("Failed")).toBeInTheDocument();
+  });
+
+  it("shows the run error instead of outcomes", () => {
+    render(<ScenarioRunResults run={run({ error: "timed out", outcomes: [] })} />);
+
+    expect(screen.getByRole("alert")).toHaveTextContent("timed out");
+    expect(screen.queryByTestId("scenario-run-results")).not.toBeInTheDocument();
+  });
+});
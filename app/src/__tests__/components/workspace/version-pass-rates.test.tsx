@@ -0,0 +1,61 @@
+import { describe, expect, it } from "vitest";
+import { render, screen } from "@testing-library/react";
+import { VersionPassRates } from "@/components/workspace/eval-workbench/version-pass-rates";
+import type { VersionPassRate } from "@/lib/eval-workbench";
+
+function rate(skillVersion: string, passRate: number): VersionPassRate {
+  return {
+    skillVersion,
+    model: "claude-sonnet-4-5",
+    runs: 4,
+    gradedRuns: 4,
+    passedRuns: Math.round(passRate * 4),
+    passRate,
+    avgCostUsd: 0.02,
+    avgDurationMs: 1500,
+    lastRunAt: "2026-10-01T00:00:00Z",
+    scenarios: [],
+  };
+}
+
+describe("VersionPassRates", () => {
+  it("lists the newest version first", () => {
+    render(
+      <VersionPassRates
+        rates={[rate("1.0.0", 1), rate("1.1.0", 0.75)]}
+        regression={null}
+        models={["claude-sonnet-4-5"]}
+        model="claude-sonnet-4-5"
+        onModelChange={() => {}}
+      />,
+    );
+
+    const items = screen.getAllByRole("listitem");
+    expect(items[0]).toHaveTextContent("v1.1.0");
+    expect(items[0]).toHaveTextContent("75%");
+    expect(items[1]).toHaveTextContent("v1.0.0");
+    expect(screen.queryByRole("alert")).not.toBeInTheDocument();
+  });
+
+  it("warns when the newest version regressed", () => {
+    render(
+      <VersionPassRates
+        rates={[rate("1.0.0", 1), rate("1.1.0", 0.5)]}
+        models={["claude-sonnet-4-5", "claude-haiku-4-5"]}
+        model="claude-sonnet-4-5"
+        onModelChange={() => {}}
+        regression={{
+          model: "claude-sonnet-4-5",
+          previousVersion: "1.0.0",
+          currentVersion: "1.1.0",
+          previousPassRate: 1,
+          currentPassRate: 0.5,
+          scenarios: [{ scenarioName: "Edge", previousPassRate: 1, currentPassRate: 0 }],
+        }}
+      />,
+    );
+
+    expect(screen.getByRole("alert")).toHaveTextContent("Worse on Edge.");
+    expect(screen.getByRole("combobox", { name: "Model" })).toHaveTextContent("claude-sonnet-4-5");
+  });
+});
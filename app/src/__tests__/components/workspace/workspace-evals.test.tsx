@@ -56,6 +56,15 @@ const alternatePerformanceScenario = {
   assertions: ["Summarizes the main pipeline blockers."],
 };
 
+const triggerScenario = {
+  id: "case-3",
+  name: "Trigger 1",
+  tags: ["trigger"] as const,
+  prompt: "What did the west region book last quarter?",
+  assertions: [],
+  shouldTrigger: true,
+};
+
 describe("WorkspaceEvals", () => {
   beforeEach(() => {
     resetTauriMocks();
@@ -146,4 +155,73 @@ describe("WorkspaceEvals", () => {
 
     expect(screen.getByText(/no scenarios yet/i)).toBeInTheDocument();
   });
+
+  it("edits the should-trigger label instead of assertions for trigger scenarios", async () => {
+    const user = userEvent.setup();
+    const onSaveScenario = vi.fn().mockResolvedValue(triggerScenario);
+
+    render(
+      <WorkspaceEvals
+        skill={skill}
+        workspacePath={null}
+        scenario={triggerScenario}
+        hasScenarios={true}
+        onStartNewScenario={vi.fn()}
+        onSaveScenario={onSaveScenario}
+        onDefineEvalScenario={vi.fn()}
+      />,
+    );
+
+    expect(screen.queryByText("Assertions")).not.toBeInTheDocument();
+    expect(screen.queryByRole("button", { name: /suggest/i })).not.toBeInTheDocument();
+    await user.click(screen.getByRole("switch", { name: /should trigger this skill/i }));
+    await user.click(screen.getByRole("button", { name: /save scenario/i }));
+
+    await waitFor(() =>
+      expect(onSaveScenario).toHaveBeenCalledWith(
+        expect.objectContaining({ shouldTrigger: false, tags: ["trigger"] }),
+        { previousScenarioName: "Trigger 1" },
+      ),
+    );
+  });
+
+  it("edits typed assertions next to rubrics", async () => {
+    const user = userEvent.setup();
+    const scenario = {
+      ...performanceScenario,
+      assertions: [
+        "Explains the forecast assumptions.",
+        { type: "file-contains" as const, path: "report.md", value: "ARR" },
+      ],
+    };
+    const onSaveScenario = vi.fn().mockResolvedValue(scenario);
+
+    render(
+      <WorkspaceEvals
+        skill={skill}
+        workspacePath={null}
+        scenario={scenario}
+        hasScenarios={true}
+        onStartNewScenario={vi.fn()}
+        onSaveScenario={onSaveScenario}
+      />,
+    );
+
+    const path = screen.getByRole("textbox", { name: "Assertion 2 path" });
+    await user.clear(path);
+    await user.type(path, "out/report.md");
+    await user.click(screen.getByRole("button", { name: /save scenario/i }));
+
+    await waitFor(() =>
+      expect(onSaveScenario).toHaveBeenCalledWith(
+        expect.objectContaining({
+          assertions: [
+            "Explains the forecast assumptions.",
+            { type: "file-contains", path: "out/report.md", value: "ARR" },
+          ],
+        }),
+        { previousScenarioName: performanceScenario.name },
+      ),
+    );
+  });
 });
@@ -25,6 +25,20 @@ vi.mock("@/components/workspace/benchmark-overview-card", () => ({
   BenchmarkOverviewCard: () => <div data-testid="benchmark-overview-card" />,
 }));
 
+vi.mock("@/components/workspace/new-version-dialog", () => ({
+  default: ({ open }: { open: boolean }) => (
+    <div data-testid="new-version-dialog" data-open={String(open)} />
+  ),
+}));
+
+vi.mock("@/components/workspace/remote-sync-card", () => ({
+  RemoteSyncCard: () => <div data-testid="remote-sync-card" />,
+}));
+
+vi.mock("@/components/workspace/draft-branch-card", () => ({
+  DraftBranchCard: () => <div data-testid="draft-branch-card" />,
+}));
+
 import { WorkspaceOverview } from "@/components/workspace/workspace-overview";
 import { getSkillHistory, readLatestBenchmark } from "@/lib/tauri";
 
@@ -126,6 +140,20 @@ describe("WorkspaceOverview", () => {
     expect(dialog).toHaveAttribute("data-open", "true");
   });
 
+  it("opens NewVersionDialog from the version history card", async () => {
+    const user = userEvent.setup();
+    render(
+      <WorkspaceOverview
+        skill={baseSkill}
+        skillType="builder"
+      />,
+    );
+
+    expect(screen.getByTestId("new-version-dialog")).toHaveAttribute("data-open", "false");
+    await user.click(screen.getByRole("button", { name: "New Version" }));
+    expect(screen.getByTestId("new-version-dialog")).toHaveAttribute("data-open", "true");
+  });
+
   it("renders version history without selectable checkboxes", async () => {
     vi.mocked(getSkillHistory).mockResolvedValueOnce([
       {
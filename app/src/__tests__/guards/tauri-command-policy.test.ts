@@ -67,6 +67,7 @@ describe("Tauri command policy", () => {
     "update_skill_metadata",
     "rename_skill",
     "export_skill_as_file",
+    "export_skill_to_target",
     "review_skill_scope",
     "get_dashboard_skill_names",
     "list_skills",
@@ -74,20 +75,30 @@ describe("Tauri command policy", () => {
     "delete_imported_skill",
     "list_plugins",
     "delete_plugin",
+    "plugin_dependents",
     "set_plugin_upgrade_lock",
     "create_plugin_from_skills",
     "move_skill_to_plugin",
     "remove_skill_from_plugin",
     "parse_github_url",
+    "parse_skill_source",
     "check_marketplace_url",
     "list_github_skills",
     "list_github_plugins",
+    "list_marketplace_plugins",
     "import_marketplace_to_library",
     "import_marketplace_plugin_to_library",
     "check_marketplace_updates",
     "check_skill_customized",
+    "preview_imported_skill_update",
+    "apply_imported_skill_update",
+    "verify_imported_skills",
+    "export_plugin_bundle",
+    "import_plugin_bundle",
     "parse_skill_file",
     "import_skill_from_file",
+    "preview_skill_archive",
+    "import_skill_archive",
   ];
 
   it("detects raw Tauri command bypasses by AST structure", () => {
@@ -4,12 +4,12 @@ import { useSettingsStore } from "@/stores/settings-store";
 
 const mocks = vi.hoisted(() => ({
   checkMarketplaceUrl: vi.fn(),
-  parseGitHubUrl: vi.fn(),
+  parseSkillSource: vi.fn(),
 }));
 
 vi.mock("@/lib/tauri", () => ({
   checkMarketplaceUrl: mocks.checkMarketplaceUrl,
-  parseGitHubUrl: mocks.parseGitHubUrl,
+  parseSkillSource: mocks.parseSkillSource,
 }));
 
 vi.mock("@/lib/toast", () => ({
@@ -24,7 +24,7 @@ describe("useMarketplaceRegistries", () => {
   beforeEach(() => {
     mockAutoSave.mockReset();
     mocks.checkMarketplaceUrl.mockReset();
-    mocks.parseGitHubUrl.mockReset();
+    mocks.parseSkillSource.mockReset();
     useSettingsStore.getState().reset();
     useSettingsStore.getState().setSettings({
       marketplaceRegistries: [
@@ -87,6 +87,54 @@ describe("useMarketplaceRegistries", () => {
     expect(result.current.isDuplicateUrl).toBe(false);
   });
 
+  it("addRegistry stores a non-GitHub source as entered", async () => {
+    mocks.parseSkillSource.mockResolvedValue({
+      kind: "gitlab",
+      canonical_url: "https://gitlab.example.com/team/skills",
+      label: "team/skills",
+      branch: null,
+      subpath: null,
+    });
+    mocks.checkMarketplaceUrl.mockResolvedValue("Team Skills");
+    const { result } = renderHook(() => useMarketplaceRegistries(mockAutoSave));
+
+    act(() => {
+      result.current.setNewRegistryUrl("https://gitlab.example.com/team/skills");
+    });
+    await act(async () => {
+      await result.current.addRegistry();
+    });
+
+    expect(mockAutoSave).toHaveBeenCalledTimes(1);
+    const updated = mockAutoSave.mock.calls[0][0].marketplaceRegistries;
+    expect(updated[2]).toEqual({
+      name: "Team Skills",
+      source_url: "https://gitlab.example.com/team/skills",
+      enabled: true,
+    });
+  });
+
+  it("addRegistry rejects a GitHub repo already registered on another branch", async () => {
+    mocks.parseSkillSource.mockResolvedValue({
+      kind: "github",
+      canonical_url: "org/custom-skills#dev",
+      label: "org/custom-skills",
+      branch: "dev",
+      subpath: null,
+    });
+    const { result } = renderHook(() => useMarketplaceRegistries(mockAutoSave));
+
+    act(() => {
+      result.current.setNewRegistryUrl("org/custom-skills#dev");
+    });
+    await act(async () => {
+      await result.current.addRegistry();
+    });
+
+    expect(mocks.checkMarketplaceUrl).not.toHaveBeenCalled();
+    expect(mockAutoSave).not.toHaveBeenCalled();
+  });
+
   it("cancelAdd resets add state", () => {
     const { result } = renderHook(() => useMarketplaceRegistries(mockAutoSave));
 
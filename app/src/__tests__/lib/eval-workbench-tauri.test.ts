@@ -1,12 +1,24 @@
 import { beforeEach, describe, expect, it } from "vitest";
 import { mockInvoke, resetTauriMocks } from "@/test/mocks/tauri";
 import {
+  createAssertion,
   createScenario,
   deleteScenario,
   listScenarios,
   loadScenario,
   saveScenario,
   defineEvalScenario,
+  detectScenarioRegression,
+  exportBenchmarkComparison,
+  exportPromptfooConfig,
+  getScenarioPassRates,
+  importPromptfooConfig,
+  listScenarioRuns,
+  runBenchmarkComparison,
+  runScenario,
+  runTriggerEval,
+  validateScenarioForEvaluation,
+  type BenchmarkComparison,
 } from "@/lib/eval-workbench";
 
 describe("Eval Workbench Tauri wrappers", () => {
@@ -70,6 +82,7 @@ describe("Eval Workbench Tauri wrappers", () => {
     expect(mockInvoke).toHaveBeenCalledWith("create_scenario", {
       pluginSlug: "skills",
       skillName: "forecast-skill",
+      mode: "performance",
     });
     expect(mockInvoke).toHaveBeenCalledWith("define_eval_scenario", {
       pluginSlug: "skills",
@@ -82,4 +95,109 @@ describe("Eval Workbench Tauri wrappers", () => {
       scenarioName: "Regression",
     });
   });
+
+  it("creates trigger scenarios and runs the trigger eval", async () => {
+    await createScenario("skills", "forecast-skill", "trigger");
+    await runTriggerEval("skills", "forecast-skill");
+
+    expect(mockInvoke).toHaveBeenCalledWith("create_scenario", {
+      pluginSlug: "skills",
+      skillName: "forecast-skill",
+      mode: "trigger",
+    });
+    expect(mockInvoke).toHaveBeenCalledWith("run_trigger_eval", {
+      pluginSlug: "skills",
+      skillName: "forecast-skill",
+    });
+  });
+
+  it("does not require assertions for trigger scenarios", () => {
+    const base = { id: "case-1", name: "Trigger 1", prompt: "Q3 ARR?", assertions: [] };
+
+    expect(validateScenarioForEvaluation({ ...base, tags: ["trigger"], shouldTrigger: false })).toBeNull();
+    expect(validateScenarioForEvaluation({ ...base, tags: ["performance"] })).toBe(
+      "Performance scenarios need at least one assertion.",
+    );
+  });
+
+  it("runs one scenario by name", async () => {
+    await runScenario("skills", "forecast-skill", "Regression");
+
+    expect(mockInvoke).toHaveBeenCalledWith("run_scenario", {
+      pluginSlug: "skills",
+      skillName: "forecast-skill",
+      scenarioName: "Regression",
+    });
+  });
+
+  it("counts typed assertions and keeps text when switching kinds", () => {
+    const base = { id: "case-1", name: "Regression", prompt: "Q3 ARR?", tags: ["performance" as const] };
+
+    expect(
+      validateScenarioForEvaluation({ ...base, assertions: [{ type: "max-turns", value: 5 }] }),
+    ).toBeNull();
+    expect(validateScenarioForEvaluation({ ...base, assertions: ["  "] })).toBe(
+      "Performance scenarios need at least one assertion.",
+    );
+    expect(createAssertion("regex", "ARR")).toEqual({ type: "regex", value: "ARR" });
+    expect(createAssertion("llm-rubric", { type: "contains", value: "ARR" })).toBe("ARR");
+    expect(createAssertion("file-exists", "ARR")).toEqual({ type: "file-exists", path: "" });
+  });
+
+  it("reads run and version history through the typed contract", async () => {
+    await listScenarioRuns("skills", "forecast-skill", "Regression");
+    await getScenarioPassRates("skills", "forecast-skill");
+    await detectScenarioRegression("skills", "forecast-skill", "claude-sonnet-4-5");
+
+    expect(mockInvoke).toHaveBeenCalledWith("list_scenario_runs", {
+      pluginSlug: "skills",
+      skillName: "forecast-skill",
+      scenarioName: "Regression",
+      limit: null,
+    });
+    expect(mockInvoke).toHaveBeenCalledWith("get_scenario_pass_rates", {
+      pluginSlug: "skills",
+      skillName: "forecast-skill",
+      model: null,
+    });
+    expect(mockInvoke).toHaveBeenCalledWith("detect_scenario_regression", {
+      pluginSlug: "skills",
+      skillName: "forecast-skill",
+      model: "claude-sonnet-4-5",
+    });
+  });
+
+  it("compares two refs and exports the report through the typed contract", async () => {
+    await runBenchmarkComparison("skills", "forecast-skill", "no-skill", "1.2.0");
+    const report = { skillName: "forecast-skill", scenarios: [] } as unknown as BenchmarkComparison;
+    await exportBenchmarkComparison(report, "markdown", "/tmp/comparison.md");
+
+    expect(mockInvoke).toHaveBeenCalledWith("run_benchmark_comparison", {
+      pluginSlug: "skills",
+      skillName: "forecast-skill",
+      baselineRef: "no-skill",
+      candidateRef: "1.2.0",
+    });
+    expect(mockInvoke).toHaveBeenCalledWith("export_benchmark_comparison", {
+      report,
+      format: "markdown",
+      destPath: "/tmp/comparison.md",
+    });
+  });
+
+  it("imports and exports promptfoo configs through the typed contract", async () => {
+    await importPromptfooConfig("skills", "forecast-skill", "/tmp/promptfooconfig.yaml");
+    await exportPromptfooConfig("skills", "forecast-skill", "/tmp/out/promptfooconfig.json");
+
+    expect(mockInvoke).toHaveBeenCalledWith("import_promptfoo_config", {
+      pluginSlug: "skills",
+      skillName: "forecast-skill",
+      sourcePath: "/tmp/promptfooconfig.yaml",
+    });
+    expect(mockInvoke).toHaveBeenCalledWith("export_promptfoo_config", {
+      pluginSlug: "skills",
+      skillName: "forecast-skill",
+      destPath: "/tmp/out/promptfooconfig.json",
+    });
+  });
 });
@@ -62,7 +62,7 @@ describe("plugin query hooks", () => {
 
   it("invalidates plugins after create or delete mutations", async () => {
     mocks.createPluginFromSkills.mockResolvedValue("analytics");
-    mocks.deletePlugin.mockResolvedValue(undefined);
+    mocks.deletePlugin.mockResolvedValue([]);
     const { Wrapper, queryClient } = wrapper();
     const invalidateSpy = vi.spyOn(queryClient, "invalidateQueries");
 
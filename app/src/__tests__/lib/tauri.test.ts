@@ -4,24 +4,35 @@ import {
   checkMarketplaceUpdates,
   checkMarketplaceUrl,
   checkSkillCustomized,
+  previewImportedSkillUpdate,
+  applyImportedSkillUpdate,
+  verifyImportedSkills,
+  exportPluginBundle,
+  importPluginBundle,
   createPluginFromSkills,
   deleteImportedSkill,
   deletePlugin,
+  pluginDependents,
   deleteSkill,
   exportSkillAsFile,
+  exportSkillToTarget,
   getDashboardSkillNames,
   importMarketplacePluginToLibrary,
   importMarketplaceToLibrary,
+  importSkillArchive,
   importSkillFromFile,
   getSelectedSkillContent,
   listGitHubPlugins,
   listImportedSkills,
+  listMarketplacePlugins,
   listPlugins,
   listSkills,
   moveSkillToPlugin,
   parseGitHubUrl,
   pauseOpenHandsSession,
   parseSkillFile,
+  previewSkillArchive,
+  parseSkillSource,
   removeSkillFromPlugin,
   renameSkill,
   reviewSkillScope,
@@ -87,7 +98,34 @@ describe("VU-1138 typed Tauri wrapper contracts", () => {
       name: "exportSkillAsFile",
       call: () => exportSkillAsFile("demo-skill", "analytics-pack", "/tmp/demo.md"),
       command: "export_skill_as_file",
-      args: { skillName: "demo-skill", pluginSlug: "analytics-pack", destPath: "/tmp/demo.md" },
+      args: {
+        skillName: "demo-skill",
+        pluginSlug: "analytics-pack",
+        destPath: "/tmp/demo.md",
+        includeHistory: false,
+      },
+    },
+    {
+      name: "exportSkillAsFile with history",
+      call: () => exportSkillAsFile("demo-skill", "analytics-pack", "/tmp/demo.skill", true),
+      command: "export_skill_as_file",
+      args: {
+        skillName: "demo-skill",
+        pluginSlug: "analytics-pack",
+        destPath: "/tmp/demo.skill",
+        includeHistory: true,
+      },
+    },
+    {
+      name: "exportSkillToTarget",
+      call: () => exportSkillToTarget("demo-skill", "analytics-pack", "cursor_rule", "/tmp/demo.mdc"),
+      command: "export_skill_to_target",
+      args: {
+        skillName: "demo-skill",
+        pluginSlug: "analytics-pack",
+        target: "cursor_rule",
+        destPath: "/tmp/demo.mdc",
+      },
     },
     {
       name: "reviewSkillScope",
@@ -168,6 +206,12 @@ describe("VU-1138 typed Tauri wrapper contracts", () => {
       name: "deletePlugin",
       call: () => deletePlugin("analytics-pack"),
       command: "delete_plugin",
+      args: { pluginSlug: "analytics-pack", force: false },
+    },
+    {
+      name: "pluginDependents",
+      call: () => pluginDependents("analytics-pack"),
+      command: "plugin_dependents",
       args: { pluginSlug: "analytics-pack" },
     },
     {
@@ -200,6 +244,12 @@ describe("VU-1138 typed Tauri wrapper contracts", () => {
       command: "parse_github_url",
       args: { url: "https://github.com/acme/skills/tree/main/plugins" },
     },
+    {
+      name: "parseSkillSource",
+      call: () => parseSkillSource("https://gitlab.example.com/team/skills"),
+      command: "parse_skill_source",
+      args: { url: "https://gitlab.example.com/team/skills" },
+    },
     {
       name: "checkMarketplaceUrl",
       call: () => checkMarketplaceUrl("https://github.com/acme/skills"),
@@ -212,6 +262,12 @@ describe("VU-1138 typed Tauri wrapper contracts", () => {
       command: "list_github_plugins",
       args: { owner: "acme", repo: "skills", branch: "main", subpath: null },
     },
+    {
+      name: "listMarketplacePlugins",
+      call: () => listMarketplacePlugins("/mnt/shared/skills"),
+      command: "list_marketplace_plugins",
+      args: { sourceUrl: "/mnt/shared/skills" },
+    },
     {
       name: "importMarketplaceToLibrary",
       call: () => importMarketplaceToLibrary(["plugins/analytics/skills/demo"], "https://github.com/acme/skills"),
@@ -230,6 +286,7 @@ describe("VU-1138 typed Tauri wrapper contracts", () => {
         sourceUrl: "https://github.com/acme/skills",
         pluginPath: "plugins/analytics",
         pluginName: "Analytics Pack",
+        externalSource: null,
       },
     },
     {
@@ -244,6 +301,67 @@ describe("VU-1138 typed Tauri wrapper contracts", () => {
       command: "check_skill_customized",
       args: { skillName: "demo-skill" },
     },
+    {
+      name: "previewImportedSkillUpdate",
+      call: () =>
+        previewImportedSkillUpdate({
+          name: "demo-skill",
+          path: "skills/demo-skill",
+          version: "1.1.0",
+          source_url: "acme/skills",
+        }),
+      command: "preview_imported_skill_update",
+      args: {
+        skillName: "demo-skill",
+        sourceUrl: "acme/skills",
+        skillPath: "skills/demo-skill",
+        version: "1.1.0",
+      },
+    },
+    {
+      name: "applyImportedSkillUpdate",
+      call: () =>
+        applyImportedSkillUpdate(
+          {
+            skill_name: "demo-skill",
+            version: "1.1.0",
+            head_sha: "abc",
+            upstream_sha: "def",
+            base_sha: "012",
+            auto_merged: [],
+            conflicts: [],
+          },
+          "acme/skills",
+          { "SKILL.md": "resolved" },
+        ),
+      command: "apply_imported_skill_update",
+      args: {
+        skillName: "demo-skill",
+        sourceUrl: "acme/skills",
+        version: "1.1.0",
+        headSha: "abc",
+        upstreamSha: "def",
+        resolutions: { "SKILL.md": "resolved" },
+      },
+    },
+    {
+      name: "verifyImportedSkills",
+      call: () => verifyImportedSkills(),
+      command: "verify_imported_skills",
+      args: {},
+    },
+    {
+      name: "exportPluginBundle",
+      call: () => exportPluginBundle("analytics-pack", "/tmp/analytics-pack.zip", true),
+      command: "export_plugin_bundle",
+      args: { pluginSlug: "analytics-pack", destPath: "/tmp/analytics-pack.zip", includeEvals: true },
+    },
+    {
+      name: "importPluginBundle",
+      call: () => importPluginBundle("/tmp/analytics-pack.zip"),
+      command: "import_plugin_bundle",
+      args: { filePath: "/tmp/analytics-pack.zip" },
+    },
     {
       name: "parseSkillFile",
       call: () => parseSkillFile("/tmp/demo.md"),
@@ -269,6 +387,28 @@ describe("VU-1138 typed Tauri wrapper contracts", () => {
         disableModelInvocation: null,
       },
     },
+    {
+      name: "previewSkillArchive",
+      call: () => previewSkillArchive("/tmp/skills.zip"),
+      command: "preview_skill_archive",
+      args: { filePath: "/tmp/skills.zip" },
+    },
+    {
+      name: "importSkillArchive",
+      call: () =>
+        importSkillArchive({
+          filePath: "/tmp/skills.zip",
+          skillPaths: ["alpha/", "beta/"],
+          newPluginName: "Team Skills",
+        }),
+      command: "import_skill_archive",
+      args: {
+        filePath: "/tmp/skills.zip",
+        skillPaths: ["alpha/", "beta/"],
+        pluginSlug: null,
+        newPluginName: "Team Skills",
+      },
+    },
   ])("$name invokes $command with the typed wrapper contract", async ({ call, command, args }) => {
     await call();
 
@@ -300,6 +300,15 @@ vi.mock("@/lib/tauri", () => ({
       subpath: null,
     }),
   ),
+  parseSkillSource: vi.fn(() =>
+    Promise.resolve({
+      kind: "github",
+      canonical_url: "test/repo",
+      label: "test/repo",
+      branch: "main",
+      subpath: null,
+    }),
+  ),
   githubStartDeviceFlow: vi.fn(),
   githubPollForToken: vi.fn(),
   githubGetUser: vi.fn(() => Promise.resolve(null)),
@@ -1451,6 +1460,56 @@ describe("SettingsPage", () => {
     ).not.toBeInTheDocument();
   });
 
+  it("saves the GitHub Enterprise host and refreshes the account on blur", async () => {
+    const user = userEvent.setup();
+    setupDefaultMocks();
+    renderWithQueryClient(<SettingsPage />);
+
+    await waitFor(() => {
+      expect(screen.getByText("Settings")).toBeInTheDocument();
+    });
+
+    await switchToSection(/GitHub/i);
+    await waitFor(() => {
+      expect(_githubGetUser).toHaveBeenCalledTimes(1);
+    });
+
+    const card = getSettingsCard("GitHub Enterprise");
+    await user.type(card.getByLabelText("Host"), "  ghe.example.com ");
+    await user.tab();
+
+    await waitFor(() => {
+      expect(_updateUserSettings).toHaveBeenCalledWith(
+        expect.objectContaining({ github_host: "ghe.example.com" }),
+      );
+    });
+    await waitFor(() => {
+      expect(_githubGetUser).toHaveBeenCalledTimes(2);
+    });
+
+    await user.type(card.getByLabelText("OAuth app client ID"), "Iv1.corp");
+    await user.tab();
+    await waitFor(() => {
+      expect(_updateUserSettings).toHaveBeenLastCalledWith(
+        expect.objectContaining({
+          github_host: "ghe.example.com",
+          github_oauth_client_id: "Iv1.corp",
+        }),
+      );
+    });
+
+    await user.type(card.getByLabelText("Feedback repository"), " platform/skill-feedback ");
+    await user.tab();
+    await waitFor(() => {
+      expect(_updateUserSettings).toHaveBeenLastCalledWith(
+        expect.objectContaining({
+          github_host: "ghe.example.com",
+          feedback_repo: "platform/skill-feedback",
+        }),
+      );
+    });
+  });
+
   it("shows user info when logged in", async () => {
     vi.mocked(_githubGetUser).mockResolvedValue({
       login: "octocat",
@@ -1511,4 +1570,51 @@ describe("SettingsPage", () => {
 
     expect(screen.getByText("Registries")).toBeInTheDocument();
   });
+
+  it("saves marketplace offline mode from the Marketplace tab", async () => {
+    setupDefaultMocks();
+    const user = userEvent.setup();
+    renderWithQueryClient(<SettingsPage />);
+
+    await waitFor(() => {
+      expect(screen.getByText("Settings")).toBeInTheDocument();
+    });
+
+    await switchToSection(/Marketplace/i);
+    await user.click(screen.getByRole("switch", { name: "Enable offline mode" }));
+
+    const { updateUserSettings } = await import("@/lib/tauri");
+    await waitFor(() => {
+      expect(updateUserSettings).toHaveBeenCalledWith(
+        expect.objectContaining({ marketplace_offline: true }),
+      );
+    });
+    expect(useSettingsStore.getState().marketplaceOffline).toBe(true);
+  });
+
+  it("saves a host token from the Marketplace tab without displaying it", async () => {
+    setupDefaultMocks();
+    const user = userEvent.setup();
+    renderWithQueryClient(<SettingsPage />);
+
+    await waitFor(() => {
+      expect(screen.getByText("Settings")).toBeInTheDocument();
+    });
+
+    await switchToSection(/Marketplace/i);
+    await user.type(screen.getByLabelText("Host"), "GitLab.Example.com");
+    await user.type(screen.getByLabelText("Token"), "glpat-secret");
+    await user.click(screen.getByRole("button", { name: "Add" }));
+
+    const { updateUserSettings } = await import("@/lib/tauri");
+    await waitFor(() => {
+      expect(updateUserSettings).toHaveBeenCalledWith(
+        expect.objectContaining({
+          source_host_tokens: [{ host: "gitlab.example.com", token: "glpat-secret" }],
+        }),
+      );
+    });
+    expect(await screen.findByText("gitlab.example.com")).toBeInTheDocument();
+    expect(screen.queryByText("glpat-secret")).not.toBeInTheDocument();
+  });
 });
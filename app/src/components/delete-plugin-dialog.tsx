@@ -0,0 +1,106 @@
+import { useEffect, useState } from "react"
+import { deletePlugin, pluginDependents } from "@/lib/tauri"
+import { toast } from "@/lib/toast"
+import { AlertTriangle, Loader2 } from "lucide-react"
+import {
+  AlertDialog,
+  AlertDialogAction,
+  AlertDialogCancel,
+  AlertDialogContent,
+  AlertDialogDescription,
+  AlertDialogFooter,
+  AlertDialogHeader,
+  AlertDialogTitle,
+} from "@/components/ui/alert-dialog"
+
+export interface DeletePluginTarget {
+  slug: string
+  displayName: string
+}
+
+interface DeletePluginDialogProps {
+  plugin: DeletePluginTarget | null
+  /** Skills deleted along with the plugin, when the caller knows. */
+  skillCount?: number
+  onOpenChange: (open: boolean) => void
+  onDeleted: () => void | Promise<void>
+}
+
+export default function DeletePluginDialog({
+  plugin,
+  skillCount,
+  onOpenChange,
+  onDeleted,
+}: DeletePluginDialogProps) {
+  const [dependents, setDependents] = useState<string[] | null>(null)
+  const [loading, setLoading] = useState(false)
+
+  useEffect(() => {
+    setDependents(null)
+    if (!plugin) return
+    let cancelled = false
+    pluginDependents(plugin.slug)
+      .then((slugs) => { if (!cancelled) setDependents(slugs) })
+      .catch((err) => {
+        console.error("[delete-plugin] plugin_dependents failed:", err)
+        if (!cancelled) setDependents([])
+      })
+    return () => { cancelled = true }
+  }, [plugin])
+
+  const handleDelete = async () => {
+    if (!plugin || dependents === null) return
+    setLoading(true)
+    try {
+      await deletePlugin(plugin.slug, dependents.length > 0)
+      console.log(`[plugin] Deleted plugin "${plugin.slug}"`)
+      toast.success(`Deleted plugin "${plugin.displayName}"`)
+      onOpenChange(false)
+      await onDeleted()
+    } catch (err) {
+      toast.error(
+        `Failed to delete plugin: ${err instanceof Error ? err.message : String(err)}`,
+        { duration: Infinity },
+      )
+    } finally {
+      setLoading(false)
+    }
+  }
+
+  return (
+    <AlertDialog open={plugin !== null}>
+      <AlertDialogContent>
+        <AlertDialogHeader>
+          <AlertDialogTitle>Delete Plugin</AlertDialogTitle>
+          <AlertDialogDescription>
+            This will permanently delete the plugin{" "}
+            <span className="font-medium text-foreground">{plugin?.displayName}</span>
+            {skillCount
+              ? ` and all ${skillCount} skill${skillCount === 1 ? "" : "s"} inside it, including their files.`
+              : "."}{" "}
+            This cannot be undone.
+          </AlertDialogDescription>
+        </AlertDialogHeader>
+        {dependents !== null && dependents.length > 0 && (
+          <div className="flex items-center gap-2 rounded-md border border-amber-500/50 bg-amber-50 px-3 py-2 text-sm text-amber-800 dark:bg-amber-950/20 dark:text-amber-300">
+            <AlertTriangle className="size-4 shrink-0" />
+            Required by {dependents.join(", ")}. Those plugins will be left with a missing dependency.
+          </div>
+        )}
+        <AlertDialogFooter>
+          <AlertDialogCancel onClick={() => onOpenChange(false)} disabled={loading}>
+            Cancel
+          </AlertDialogCancel>
+          <AlertDialogAction
+            variant="destructive"
+            onClick={handleDelete}
+            disabled={loading || dependents === null}
+          >
+            {loading && <Loader2 className="size-4 animate-spin" />}
+            {dependents !== null && dependents.length > 0 ? "Delete Anyway" : "Delete Plugin"}
+          </AlertDialogAction>
+        </AlertDialogFooter>
+      </AlertDialogContent>
+    </AlertDialog>
+  )
+}
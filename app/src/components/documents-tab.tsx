@@ -1,5 +1,5 @@
-import { useMemo, useRef, useState } from "react"
-import { Trash2, Link, FolderOpen, Upload, Check, Loader2 } from "lucide-react"
+import { useMemo, useState } from "react"
+import { Trash2, Link, FolderOpen, Upload, Check, Loader2, RefreshCw, AlertTriangle } from "lucide-react"
 import { Button } from "@/components/ui/button"
 import { Input } from "@/components/ui/input"
 import { Label } from "@/components/ui/label"
@@ -8,14 +8,19 @@ import { Checkbox } from "@/components/ui/checkbox"
 import { Dialog, DialogContent, DialogHeader, DialogTitle } from "@/components/ui/dialog"
 import type { SkillIdName } from "@/lib/tauri"
 import type { Document } from "@/lib/types"
+import { toast } from "@/lib/toast"
 import { open as openFileDialog } from "@tauri-apps/plugin-dialog"
 import {
-  useAddDocumentFileMutation,
+  useAddDocumentPathMutation,
   useAddDocumentFolderMutation,
+  useAddDocumentSiteMutation,
   useAddDocumentUrlMutation,
   useDeleteDocumentMutation,
   useDocumentsQuery,
   useDocumentSkillOptionsQuery,
+  useRefreshDocumentMutation,
+  useRefreshUrlDocumentsMutation,
+  useStaleDocumentSkillsQuery,
   useUpdateDocumentMutation,
 } from "@/lib/queries/documents"
 
@@ -34,8 +39,14 @@ function AddUrlDialog({ skills, onClose }: AddUrlDialogProps) {
   const [scope, setScope] = useState<"all" | "skill">("all")
   const [selectedSkillIds, setSelectedSkillIds] = useState<number[]>([])
   const [error, setError] = useState<string | null>(null)
+  const [crawl, setCrawl] = useState(false)
+  const [maxDepth, setMaxDepth] = useState(2)
+  const [maxPages, setMaxPages] = useState(50)
+  const [includePattern, setIncludePattern] = useState("")
+  const [excludePattern, setExcludePattern] = useState("")
   const addDocumentUrlMutation = useAddDocumentUrlMutation()
-  const loading = addDocumentUrlMutation.isPending
+  const addDocumentSiteMutation = useAddDocumentSiteMutation()
+  const loading = addDocumentUrlMutation.isPending || addDocumentSiteMutation.isPending
 
   const toggle = (id: number) =>
     setSelectedSkillIds((prev) =>
@@ -45,13 +56,29 @@ function AddUrlDialog({ skills, onClose }: AddUrlDialogProps) {
   const handleSubmit = async () => {
     if (!name.trim() || !url.trim()) return
     setError(null)
+    const skillIds = scope === "skill" ? selectedSkillIds : []
     try {
-      await addDocumentUrlMutation.mutateAsync({
-        name: name.trim(),
-        url: url.trim(),
-        scope,
-        skillIds: scope === "skill" ? selectedSkillIds : [],
-      })
+      if (crawl) {
+        await addDocumentSiteMutation.mutateAsync({
+          name: name.trim(),
+          url: url.trim(),
+          options: {
+            max_depth: maxDepth,
+            max_pages: maxPages,
+            include_pattern: includePattern.trim() || null,
+            exclude_pattern: excludePattern.trim() || null,
+          },
+          scope,
+          skillIds,
+        })
+      } else {
+        await addDocumentUrlMutation.mutateAsync({
+          name: name.trim(),
+          url: url.trim(),
+          scope,
+          skillIds,
+        })
+      }
       onClose()
     } catch (e) {
       setError(String(e))
@@ -73,6 +100,64 @@ function AddUrlDialog({ skills, onClose }: AddUrlDialogProps) {
           <Input value={url} onChange={(e) => setUrl(e.target.value)} placeholder="https://..." />
         </div>
 
+        <div className="space-y-3">
+          <div className="flex items-center justify-between">
+            <Label htmlFor="crawl-site-toggle" className="cursor-pointer">Crawl linked pages on this site</Label>
+            <Switch id="crawl-site-toggle" checked={crawl} onCheckedChange={setCrawl} />
+          </div>
+          {crawl && (
+            <div className="space-y-3 rounded-md border p-3">
+              <div className="flex gap-4">
+                <div className="space-y-1">
+                  <Label htmlFor="crawl-depth" className="text-xs">Link depth</Label>
+                  <Input
+                    id="crawl-depth"
+                    type="number"
+                    min={0}
+                    max={10}
+                    value={maxDepth}
+                    onChange={(e) => setMaxDepth(Math.max(0, Math.min(10, parseInt(e.target.value) || 0)))}
+                    className="w-20"
+                  />
+                </div>
+                <div className="space-y-1">
+                  <Label htmlFor="crawl-max-pages" className="text-xs">Max pages</Label>
+                  <Input
+                    id="crawl-max-pages"
+                    type="number"
+                    min={1}
+                    max={500}
+                    value={maxPages}
+                    onChange={(e) => setMaxPages(Math.max(1, Math.min(500, parseInt(e.target.value) || 1)))}
+                    className="w-24"
+                  />
+                </div>
+              </div>
+              <div className="space-y-1">
+                <Label htmlFor="crawl-include" className="text-xs">Include URLs matching</Label>
+                <Input
+                  id="crawl-include"
+                  value={includePattern}
+                  onChange={(e) => setIncludePattern(e.target.value)}
+                  placeholder="e.g. */guide/*, */reference/*"
+                />
+              </div>
+              <div className="space-y-1">
+                <Label htmlFor="crawl-exclude" className="text-xs">Exclude URLs matching</Label>
+                <Input
+                  id="crawl-exclude"
+                  value={excludePattern}
+                  onChange={(e) => setExcludePattern(e.target.value)}
+                  placeholder="e.g. changelog, */v1/*"
+                />
+              </div>
+              <p className="text-xs text-muted-foreground">
+                Only same-site links are followed. Each page is added as a separate document under this name.
+              </p>
+            </div>
+          )}
+        </div>
+
         <AssignmentPicker scope={scope} setScope={setScope} selectedSkillIds={selectedSkillIds} toggle={toggle} skills={skills} inline />
 
         {error && <p className="text-xs text-destructive">{error}</p>}
@@ -81,7 +166,7 @@ function AddUrlDialog({ skills, onClose }: AddUrlDialogProps) {
           <Button variant="outline" size="sm" onClick={onClose} disabled={loading}>Cancel</Button>
           <Button size="sm" onClick={handleSubmit} disabled={loading || !name.trim() || !url.trim()}>
             {loading ? <Loader2 className="h-3 w-3 animate-spin mr-1" /> : null}
-            Fetch &amp; Add
+            {crawl ? "Crawl & Add" : "Fetch & Add"}
           </Button>
         </div>
       </div>
@@ -253,32 +338,49 @@ function AssignmentCell({ doc, skills }: AssignmentCellProps) {
 export function DocumentsTab() {
   const documentsQuery = useDocumentsQuery()
   const skillOptionsQuery = useDocumentSkillOptionsQuery()
-  const addDocumentFileMutation = useAddDocumentFileMutation()
+  const addDocumentPathMutation = useAddDocumentPathMutation()
   const addDocumentFolderMutation = useAddDocumentFolderMutation()
   const deleteDocumentMutation = useDeleteDocumentMutation()
+  const refreshDocumentMutation = useRefreshDocumentMutation()
+  const refreshUrlDocumentsMutation = useRefreshUrlDocumentsMutation()
+  const staleSkillsQuery = useStaleDocumentSkillsQuery()
   const documents = documentsQuery.data ?? []
+  const staleSkills = staleSkillsQuery.data ?? []
+  const hasUrlDocuments = documents.some((doc) => doc.source_type === "url")
   const skills = skillOptionsQuery.data ?? []
   const isLoading = documentsQuery.isLoading || skillOptionsQuery.isLoading
   const [showUrlDialog, setShowUrlDialog] = useState(false)
   const [uploading, setUploading] = useState(false)
-  const fileInputRef = useRef<HTMLInputElement>(null)
+  const [refreshingId, setRefreshingId] = useState<number | null>(null)
 
   // ---------------------------------------------------------------------------
   // Upload file
   // ---------------------------------------------------------------------------
-  const handleFileUpload = async (e: React.ChangeEvent<HTMLInputElement>) => {
-    const file = e.target.files?.[0]
-    if (!file) return
+  const handleFileUpload = async () => {
     setUploading(true)
     try {
-      const content = await file.text()
-      const name = file.name.replace(/\.(md|txt|pdf)$/i, "")
-      await addDocumentFileMutation.mutateAsync({ name, content, scope: "all", skillIds: [] })
+      const selected = await openFileDialog({
+        multiple: false,
+        filters: [
+          {
+            name: "Documents",
+            extensions: ["md", "markdown", "txt", "html", "htm", "pdf", "docx"],
+          },
+        ],
+      })
+      if (!selected || typeof selected !== "string") return
+      const fileName = selected.split(/[\\/]/).pop() ?? "Document"
+      const name = fileName.replace(/\.[^.]+$/, "")
+      await addDocumentPathMutation.mutateAsync({
+        name,
+        filePath: selected,
+        scope: "all",
+        skillIds: [],
+      })
     } catch (err) {
-      console.error("event=add_document_file_failed error=%s", err)
+      console.error("event=add_document_path_failed error=%s", err)
     } finally {
       setUploading(false)
-      if (fileInputRef.current) fileInputRef.current.value = ""
     }
   }
 
@@ -301,6 +403,39 @@ export function DocumentsTab() {
     }
   }
 
+  // ---------------------------------------------------------------------------
+  // Refresh URL documents
+  // ---------------------------------------------------------------------------
+  const handleRefresh = async (doc: Document) => {
+    setRefreshingId(doc.id)
+    try {
+      const result = await refreshDocumentMutation.mutateAsync(doc.id)
+      toast.success(result.changed ? `"${doc.name}" updated` : `"${doc.name}" is up to date`)
+    } catch (err) {
+      console.error("event=refresh_document_failed id=%d error=%s", doc.id, err)
+      toast.error(`Failed to refresh "${doc.name}": ${err}`)
+    } finally {
+      setRefreshingId(null)
+    }
+  }
+
+  const handleRefreshAll = async () => {
+    try {
+      const result = await refreshUrlDocumentsMutation.mutateAsync()
+      const changed = result.refreshed.filter((r) => r.changed).length
+      if (result.failed.length > 0) {
+        toast.error(
+          `Refreshed ${result.refreshed.length} URL documents (${changed} changed); ${result.failed.length} failed: ${result.failed.map((f) => f.name).join(", ")}`,
+        )
+      } else {
+        toast.success(`Refreshed ${result.refreshed.length} URL documents (${changed} changed)`)
+      }
+    } catch (err) {
+      console.error("event=refresh_url_documents_failed error=%s", err)
+      toast.error(`Failed to refresh URL documents: ${err}`)
+    }
+  }
+
   // ---------------------------------------------------------------------------
   // Delete
   // ---------------------------------------------------------------------------
@@ -340,19 +475,12 @@ export function DocumentsTab() {
         <Button
           variant="outline"
           size="sm"
-          onClick={() => fileInputRef.current?.click()}
+          onClick={handleFileUpload}
           disabled={uploading}
         >
           {uploading ? <Loader2 className="h-3.5 w-3.5 mr-1.5 animate-spin" /> : <Upload className="h-3.5 w-3.5 mr-1.5" />}
           Upload file
         </Button>
-        <input
-          ref={fileInputRef}
-          type="file"
-          accept=".md,.txt,.pdf"
-          className="hidden"
-          onChange={handleFileUpload}
-        />
         <Button variant="outline" size="sm" onClick={() => setShowUrlDialog(true)}>
           <Link className="h-3.5 w-3.5 mr-1.5" />
           Add URL
@@ -361,8 +489,41 @@ export function DocumentsTab() {
           <FolderOpen className="h-3.5 w-3.5 mr-1.5" />
           Add folder
         </Button>
+        {hasUrlDocuments && (
+          <Button
+            variant="outline"
+            size="sm"
+            onClick={handleRefreshAll}
+            disabled={refreshUrlDocumentsMutation.isPending}
+          >
+            {refreshUrlDocumentsMutation.isPending ? (
+              <Loader2 className="h-3.5 w-3.5 mr-1.5 animate-spin" />
+            ) : (
+              <RefreshCw className="h-3.5 w-3.5 mr-1.5" />
+            )}
+            Refresh URLs
+          </Button>
+        )}
       </div>
 
+      {staleSkills.length > 0 && (
+        <div className="flex gap-2 rounded-md border border-amber-500/40 bg-amber-500/10 px-3 py-2 text-sm">
+          <AlertTriangle className="h-4 w-4 mt-0.5 shrink-0 text-amber-600" />
+          <div className="space-y-1">
+            <p className="font-medium">Documents changed since these skills were generated</p>
+            <ul className="text-xs text-muted-foreground space-y-0.5">
+              {staleSkills.map((s) => (
+                <li key={s.skill_id}>
+                  <span className="font-medium text-foreground">{s.skill_name}</span>
+                  {" — "}
+                  {s.changed_documents.join(", ")}
+                </li>
+              ))}
+            </ul>
+          </div>
+        </div>
+      )}
+
       {/* Table */}
       {isLoading ? (
         <div className="flex items-center gap-2 text-sm text-muted-foreground py-4">
@@ -382,7 +543,7 @@ export function DocumentsTab() {
                 <th className="text-left px-4 py-2.5 font-medium text-muted-foreground w-16">Source</th>
                 <th className="text-left px-4 py-2.5 font-medium text-muted-foreground">Assigned to</th>
                 <th className="text-left px-4 py-2.5 font-medium text-muted-foreground w-20">Added</th>
-                <th className="w-10" />
+                <th className="w-16" />
               </tr>
             </thead>
             <tbody>
@@ -394,7 +555,23 @@ export function DocumentsTab() {
                     <AssignmentCell doc={doc} skills={skills} />
                   </td>
                   <td className="px-4 py-2.5 text-muted-foreground text-xs">{formatDate(doc.created_at)}</td>
-                  <td className="px-2 py-2.5">
+                  <td className="px-2 py-2.5 whitespace-nowrap">
+                    {doc.source_type === "url" && (
+                      <Button
+                        variant="ghost"
+                        size="icon"
+                        className="h-7 w-7 text-muted-foreground"
+                        title="Refresh from URL"
+                        onClick={() => handleRefresh(doc)}
+                        disabled={refreshingId === doc.id}
+                      >
+                        {refreshingId === doc.id ? (
+                          <Loader2 className="h-3.5 w-3.5 animate-spin" />
+                        ) : (
+                          <RefreshCw className="h-3.5 w-3.5" />
+                        )}
+                      </Button>
+                    )}
                     <Button
                       variant="ghost"
                       size="icon"
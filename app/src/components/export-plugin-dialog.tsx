@@ -0,0 +1,83 @@
+import { useState } from "react"
+import { save } from "@tauri-apps/plugin-dialog"
+import { Loader2 } from "lucide-react"
+import { toast } from "@/lib/toast"
+import { Button } from "@/components/ui/button"
+import { Checkbox } from "@/components/ui/checkbox"
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from "@/components/ui/dialog"
+import { Label } from "@/components/ui/label"
+import { exportPluginBundle } from "@/lib/tauri"
+import type { LibraryPlugin } from "@/lib/types"
+
+interface ExportPluginDialogProps {
+  plugin: LibraryPlugin | null
+  onOpenChange: (open: boolean) => void
+}
+
+export function ExportPluginDialog({ plugin, onOpenChange }: ExportPluginDialogProps) {
+  const [includeEvals, setIncludeEvals] = useState(true)
+  const [exporting, setExporting] = useState(false)
+
+  const handleExport = async () => {
+    if (!plugin) return
+    const destPath = await save({
+      title: "Export Plugin",
+      defaultPath: `${plugin.slug}.zip`,
+      filters: [{ name: "Plugin Bundle", extensions: ["zip"] }],
+    })
+    if (!destPath) return
+    setExporting(true)
+    try {
+      await exportPluginBundle(plugin.slug, destPath, includeEvals)
+      toast.success(`Exported plugin "${plugin.display_name}"`)
+      onOpenChange(false)
+    } catch (err) {
+      toast.error(`Export failed: ${err instanceof Error ? err.message : String(err)}`, {
+        duration: Infinity,
+      })
+    } finally {
+      setExporting(false)
+    }
+  }
+
+  return (
+    <Dialog open={plugin !== null} onOpenChange={onOpenChange}>
+      <DialogContent className="sm:max-w-md">
+        <DialogHeader>
+          <DialogTitle>Export Plugin</DialogTitle>
+          <DialogDescription>
+            Saves {plugin?.display_name ?? "the plugin"} as a zip archive laid out as a single-plugin
+            marketplace. Claude Code can add the extracted folder as a marketplace, and Skill Builder
+            can import the archive directly.
+          </DialogDescription>
+        </DialogHeader>
+        <div className="flex items-center gap-2 py-2">
+          <Checkbox
+            id="export-include-evals"
+            checked={includeEvals}
+            onCheckedChange={(checked) => setIncludeEvals(checked === true)}
+          />
+          <Label htmlFor="export-include-evals" className="text-sm font-normal">
+            Include eval scenarios
+          </Label>
+        </div>
+        <DialogFooter>
+          <Button variant="outline" onClick={() => onOpenChange(false)} disabled={exporting}>
+            Cancel
+          </Button>
+          <Button onClick={handleExport} disabled={exporting}>
+            {exporting && <Loader2 className="size-4 animate-spin" />}
+            Export
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  )
+}
@@ -11,8 +11,8 @@ import {
 import { Button } from "@/components/ui/button"
 import { Badge } from "@/components/ui/badge"
 import { Tabs, TabsList, TabsTrigger, TabsContent } from "@/components/ui/tabs"
-import { parseGitHubUrl, listGitHubPlugins, importMarketplacePluginToLibrary, listPlugins } from "@/lib/tauri"
-import type { AvailablePlugin, GitHubRepoInfo, MarketplaceRegistry } from "@/lib/types"
+import { parseSkillSource, listMarketplacePlugins, importMarketplacePluginToLibrary, listPlugins } from "@/lib/tauri"
+import type { AvailablePlugin, MarketplaceRegistry, SkillSourceInfo } from "@/lib/types"
 
 interface GitHubImportDialogProps {
   open: boolean
@@ -29,10 +29,20 @@ type TabState = {
   error: string | null
   plugins: AvailablePlugin[]
   pluginStates: Map<string, PluginState>
-  repoInfo: GitHubRepoInfo | null
+  sourceInfo: SkillSourceInfo | null
 }
 
-const EMPTY_TAB: TabState = { loading: false, error: null, plugins: [], pluginStates: new Map(), repoInfo: null }
+const EMPTY_TAB: TabState = { loading: false, error: null, plugins: [], pluginStates: new Map(), sourceInfo: null }
+
+/** Repo-relative path, or `owner/repo[@ref][:path]` for plugins hosted in another repo. */
+function pluginSourceLabel(plugin: AvailablePlugin): string {
+  const external = plugin.external_source
+  if (!external) return plugin.path
+  const ref = external.git_ref ? `@${external.git_ref.slice(0, 12)}` : ""
+  const path = plugin.path ? `:${plugin.path}` : ""
+  const repo = [external.owner, external.repo].filter(Boolean).join("/")
+  return `${repo}${ref}${path}`
+}
 
 export default function GitHubImportDialog({
   open,
@@ -52,18 +62,18 @@ export default function GitHubImportDialog({
   const plugins = currentTab.plugins
   const error = currentTab.error
   const pluginStates = currentTab.pluginStates
-  const repoInfo = currentTab.repoInfo
+  const sourceInfo = currentTab.sourceInfo
   const topLevelDescription = registries.length === 0
     ? "No enabled registries. Configure registries in Settings -> Marketplace."
-    : repoInfo
-      ? `${plugins.length} plugin${plugins.length !== 1 ? "s" : ""} in ${repoInfo.owner}/${repoInfo.repo}`
+    : sourceInfo
+      ? `${plugins.length} plugin${plugins.length !== 1 ? "s" : ""} in ${sourceInfo.label}`
       : "Browse and import plugins from your configured registries."
 
-  function setPluginState(path: string, state: PluginState): void {
+  function setPluginState(pluginName: string, state: PluginState): void {
     const tabKey = activeTabRef.current
     setTabStates((prev) => {
       const tab = prev[tabKey] ?? EMPTY_TAB
-      const newPluginStates = new Map(tab.pluginStates).set(path, state)
+      const newPluginStates = new Map(tab.pluginStates).set(pluginName, state)
       return { ...prev, [tabKey]: { ...tab, pluginStates: newPluginStates } }
     })
   }
@@ -88,13 +98,8 @@ export default function GitHubImportDialog({
       [tabKey]: { ...EMPTY_TAB, loading: true }
     }))
     try {
-      const info = await parseGitHubUrl(registry.source_url.trim())
-      const available = await listGitHubPlugins(
-        info.owner,
-        info.repo,
-        info.branch,
-        info.subpath ?? undefined,
-      )
+      const info = await parseSkillSource(registry.source_url.trim())
+      const available = await listMarketplacePlugins(registry.source_url.trim())
 
       // Check which plugins are already installed by slug (not display name)
       const dbPlugins = await listPlugins()
@@ -104,14 +109,14 @@ export default function GitHubImportDialog({
         // Derive slug from plugin name (same logic as backend slugify_plugin_name)
         const slug = plugin.name.toLowerCase().replace(/[^a-z0-9]+/g, "-").replace(/^-|-$/g, "")
         if (installedSlugs.has(slug)) {
-          preStates.set(plugin.path, "exists")
+          preStates.set(plugin.name, "exists")
         }
       }
 
       const finalError = available.length === 0 ? "No plugins found in this marketplace." : null
       setTabStates(prev => ({
         ...prev,
-        [tabKey]: { loading: false, error: finalError, plugins: available, pluginStates: preStates, repoInfo: info }
+        [tabKey]: { loading: false, error: finalError, plugins: available, pluginStates: preStates, sourceInfo: info }
       }))
     } catch (err) {
       console.error("[github-import] Failed to browse registry:", err)
@@ -140,17 +145,23 @@ export default function GitHubImportDialog({
   }, [registries, tabStates, browseRegistry])
 
   const handleImportPlugin = useCallback(async (plugin: AvailablePlugin) => {
-    setPluginState(plugin.path, "importing")
+    setPluginState(plugin.name, "importing")
     try {
-      const results = await importMarketplacePluginToLibrary(plugin.path, plugin.name, activeTabRef.current)
-      const failures = results.filter((result) => !result.success)
+      const results = await importMarketplacePluginToLibrary(
+        plugin.path,
+        plugin.name,
+        activeTabRef.current,
+        plugin.external_source,
+      )
+      const dependencyResults = results.filter((result) => result.dependency_of)
+      const failures = results.filter((result) => !result.success && !result.dependency_of)
       const nonExistsFailures = failures.filter((result) => {
         const error = result.error?.toLowerCase() ?? ""
         return !error.includes("already exists")
       })
 
       if (nonExistsFailures.length > 0) {
-        setPluginState(plugin.path, "idle")
+        setPluginState(plugin.name, "idle")
         toast.error(nonExistsFailures[0].error ?? `Failed to import plugin "${plugin.name}"`, {
           duration: Infinity,
           cause: nonExistsFailures[0].error,
@@ -159,15 +170,27 @@ export default function GitHubImportDialog({
         return
       }
 
-      const nextState: PluginState = results.some((result) => result.success) ? "imported" : "exists"
-      setPluginState(plugin.path, nextState)
+      const nextState: PluginState = results.some((result) => result.success && !result.dependency_of)
+        ? "imported"
+        : "exists"
+      setPluginState(plugin.name, nextState)
       if (nextState === "imported") {
-        toast.success(`Imported plugin "${plugin.name}"`)
+        toast.success(
+          dependencyResults.some((result) => result.success)
+            ? `Imported plugin "${plugin.name}" and its dependencies`
+            : `Imported plugin "${plugin.name}"`,
+        )
+        const unresolved = dependencyResults.filter((result) => !result.success)
+        if (unresolved.length > 0) {
+          toast.warning(unresolved.map((result) => result.error ?? result.skill_name).join("\n"), {
+            duration: Infinity,
+          })
+        }
         await onImported()
       }
     } catch (err) {
       console.error("[github-import] import_marketplace_plugin_to_library failed:", err)
-      setPluginState(plugin.path, "idle")
+      setPluginState(plugin.name, "idle")
       toast.error(err instanceof Error ? err.message : String(err), {
         duration: Infinity,
         cause: err,
@@ -199,7 +222,7 @@ export default function GitHubImportDialog({
       )
     }
 
-    if (plugins.length > 0 && repoInfo) {
+    if (plugins.length > 0 && sourceInfo) {
       return (
         <div className="flex flex-col flex-1 min-h-0 overflow-hidden">
           <div className="flex-1 min-h-0 overflow-y-auto rounded-md border">
@@ -220,13 +243,13 @@ export default function GitHubImportDialog({
               </thead>
               <tbody>
                 {plugins.map((plugin) => {
-                  const state = pluginStates.get(plugin.path) ?? "idle"
+                  const state = pluginStates.get(plugin.name) ?? "idle"
                   const isImporting = state === "importing"
                   const isDisabled = state === "exists"
 
                   return (
                     <tr
-                      key={plugin.path}
+                      key={plugin.name}
                       className="hover:bg-muted/30 transition-colors"
                     >
                       <td className="pl-4 py-2.5 border-b overflow-hidden">
@@ -257,7 +280,7 @@ export default function GitHubImportDialog({
                         )}
                       </td>
                       <td className="pl-4 py-2.5 border-b">
-                        <span className="text-xs text-muted-foreground truncate">{plugin.path}</span>
+                        <span className="text-xs text-muted-foreground truncate">{pluginSourceLabel(plugin)}</span>
                       </td>
                       <td className="pr-4 py-2.5 border-b">
                         <div className="flex items-center justify-end">
@@ -0,0 +1,182 @@
+import { useState, useCallback, useEffect, useMemo } from "react"
+import { toast } from "@/lib/toast"
+import { Loader2 } from "lucide-react"
+import { Button } from "@/components/ui/button"
+import { Checkbox } from "@/components/ui/checkbox"
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from "@/components/ui/dialog"
+import { Input } from "@/components/ui/input"
+import { Label } from "@/components/ui/label"
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select"
+import { importSkillArchive } from "@/lib/tauri"
+import { usePluginsQuery } from "@/lib/queries/plugins"
+import type { SkillArchivePreview } from "@/lib/types"
+
+const NEW_PLUGIN = "__new__"
+
+interface ImportSkillArchiveDialogProps {
+  open: boolean
+  onOpenChange: (open: boolean) => void
+  filePath: string
+  preview: SkillArchivePreview | null
+  onImported: () => void
+}
+
+export function ImportSkillArchiveDialog({
+  open,
+  onOpenChange,
+  filePath,
+  preview,
+  onImported,
+}: ImportSkillArchiveDialogProps) {
+  const { data: allPlugins = [], refetch: refetchPlugins } = usePluginsQuery()
+  const [selectedPaths, setSelectedPaths] = useState<string[]>([])
+  const [target, setTarget] = useState<string>(NEW_PLUGIN)
+  const [newPluginName, setNewPluginName] = useState("")
+  const [submitting, setSubmitting] = useState(false)
+
+  const plugins = useMemo(
+    () => allPlugins.filter((p) => p.source_type !== "marketplace"),
+    [allPlugins],
+  )
+  const skills = useMemo(() => preview?.skills ?? [], [preview])
+
+  useEffect(() => {
+    if (!open || !preview) return
+    refetchPlugins()
+    setSelectedPaths(preview.skills.map((s) => s.path))
+    setTarget(NEW_PLUGIN)
+    setNewPluginName(preview.plugin_name ?? "")
+    setSubmitting(false)
+  }, [open, preview, refetchPlugins])
+
+  const togglePath = (path: string, checked: boolean) => {
+    setSelectedPaths((prev) =>
+      checked ? [...prev, path] : prev.filter((p) => p !== path),
+    )
+  }
+
+  const canSubmit =
+    selectedPaths.length > 0 &&
+    (target !== NEW_PLUGIN || newPluginName.trim() !== "") &&
+    !submitting
+
+  const handleSubmit = useCallback(async () => {
+    setSubmitting(true)
+    try {
+      const results = await importSkillArchive({
+        filePath,
+        skillPaths: selectedPaths,
+        pluginSlug: target === NEW_PLUGIN ? null : target,
+        newPluginName: target === NEW_PLUGIN ? newPluginName.trim() : null,
+      })
+      const failed = results.filter((r) => !r.success)
+      const imported = results.length - failed.length
+      if (imported > 0) {
+        toast.success(`Imported ${imported} skill(s)`)
+      }
+      if (failed.length > 0) {
+        toast.warning(failed.map((r) => `${r.skill_name}: ${r.error ?? "failed"}`).join("\n"))
+      }
+      onOpenChange(false)
+      onImported()
+    } catch (err) {
+      console.error("[import-skill-archive-dialog] import failed:", err)
+      toast.error(`Import failed: ${err instanceof Error ? err.message : String(err)}`, {
+        duration: Infinity,
+      })
+    } finally {
+      setSubmitting(false)
+    }
+  }, [filePath, selectedPaths, target, newPluginName, onOpenChange, onImported])
+
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogContent className="sm:max-w-lg">
+        <DialogHeader>
+          <DialogTitle>Import Skills</DialogTitle>
+          <DialogDescription>
+            {preview?.plugin_name
+              ? `This archive contains the plugin "${preview.plugin_name}" with ${skills.length} skill(s).`
+              : `This archive contains ${skills.length} skills.`}{" "}
+            Choose which to import and where to put them.
+          </DialogDescription>
+        </DialogHeader>
+
+        <div className="max-h-64 space-y-2 overflow-y-auto py-2">
+          {skills.map((s) => (
+            <label
+              key={s.path}
+              className="flex items-start gap-3 rounded-md border px-3 py-2 cursor-pointer hover:bg-muted/30 transition-colors"
+            >
+              <Checkbox
+                aria-label={`Import ${s.meta.name ?? s.path}`}
+                checked={selectedPaths.includes(s.path)}
+                onCheckedChange={(checked) => togglePath(s.path, checked === true)}
+                className="mt-0.5"
+              />
+              <div className="min-w-0">
+                <div className="text-sm font-medium">
+                  {s.meta.name ?? s.path}
+                  {s.meta.version && (
+                    <span className="ml-2 text-xs text-muted-foreground">v{s.meta.version}</span>
+                  )}
+                </div>
+                {s.meta.description && (
+                  <div className="line-clamp-2 text-xs text-muted-foreground">{s.meta.description}</div>
+                )}
+              </div>
+            </label>
+          ))}
+        </div>
+
+        <div className="space-y-2">
+          <Label htmlFor="archive-target-plugin">Plugin</Label>
+          <Select value={target} onValueChange={setTarget}>
+            <SelectTrigger id="archive-target-plugin" className="w-full">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              <SelectItem value={NEW_PLUGIN}>New plugin…</SelectItem>
+              {plugins.map((p) => (
+                <SelectItem key={p.slug} value={p.slug}>
+                  {p.display_name}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+          {target === NEW_PLUGIN && (
+            <Input
+              aria-label="New plugin name"
+              placeholder="Plugin name"
+              value={newPluginName}
+              onChange={(e) => setNewPluginName(e.target.value)}
+            />
+          )}
+        </div>
+
+        <DialogFooter>
+          <Button variant="ghost" onClick={() => onOpenChange(false)}>
+            Cancel
+          </Button>
+          <Button onClick={handleSubmit} disabled={!canSubmit}>
+            {submitting && <Loader2 className="size-4 animate-spin" />}
+            Import {selectedPaths.length} skill(s)
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  )
+}
@@ -1,7 +1,7 @@
 import { useCallback, useState } from "react"
 import { open } from "@tauri-apps/plugin-dialog"
 import { toast } from "@/lib/toast"
-import { FolderInput, Package, FolderTree, Trash2, Lock, LockOpen } from "lucide-react"
+import { FolderInput, Package, PackageOpen, FolderTree, Trash2, Lock, LockOpen, ShieldCheck, Fingerprint, Download } from "lucide-react"
 import { Github } from "@/components/icons/github"
 import {
   Card,
@@ -17,8 +17,13 @@ import { useInvalidateSkillQueries } from "@/lib/queries/skills"
 import GitHubImportDialog from "@/components/github-import-dialog"
 import { ImportSkillDialog } from "@/components/import-skill-dialog"
 import { CreatePluginDialog } from "@/components/create-plugin-dialog"
-import { deletePlugin, parseSkillFile, setPluginUpgradeLock } from "@/lib/tauri"
-import type { LibraryPlugin, SkillFileMeta } from "@/lib/types"
+import { ManifestLintDialog } from "@/components/manifest-lint-dialog"
+import { SkillIntegrityDialog } from "@/components/skill-integrity-dialog"
+import { ExportPluginDialog } from "@/components/export-plugin-dialog"
+import { ImportSkillArchiveDialog } from "@/components/import-skill-archive-dialog"
+import DeletePluginDialog, { type DeletePluginTarget } from "@/components/delete-plugin-dialog"
+import { importPluginBundle, previewSkillArchive, setPluginUpgradeLock } from "@/lib/tauri"
+import type { LibraryPlugin, SkillArchivePreview, SkillFileMeta } from "@/lib/types"
 
 export function ImportedSkillsTab() {
   const { data: plugins = [], isFetching, refetch: refetchPlugins } = usePluginsQuery()
@@ -35,12 +40,17 @@ export function ImportedSkillsTab() {
   const hasEnabledRegistry = marketplaceRegistries.some(r => r.enabled)
   const [showGitHubImport, setShowGitHubImport] = useState(false)
   const [createPluginOpen, setCreatePluginOpen] = useState(false)
+  const [lintOpen, setLintOpen] = useState(false)
+  const [verifyOpen, setVerifyOpen] = useState(false)
+  const [exportPlugin, setExportPlugin] = useState<LibraryPlugin | null>(null)
+  const [deletePluginTarget, setDeletePluginTarget] = useState<DeletePluginTarget | null>(null)
   const [importOpen, setImportOpen] = useState(false)
   const [importFile, setImportFile] = useState("")
   const [importMeta, setImportMeta] = useState<SkillFileMeta>({
     name: null, description: null, version: null,
     user_invocable: null, disable_model_invocation: null,
   })
+  const [archivePreview, setArchivePreview] = useState<SkillArchivePreview | null>(null)
 
   const handleImport = useCallback(async () => {
     const filePath = await open({
@@ -50,10 +60,14 @@ export function ImportedSkillsTab() {
     if (!filePath) return
 
     try {
-      const meta = await parseSkillFile(filePath)
+      const preview = await previewSkillArchive(filePath)
       setImportFile(filePath)
-      setImportMeta(meta)
-      setImportOpen(true)
+      if (preview.skills.length === 1 && !preview.plugin_name) {
+        setImportMeta(preview.skills[0].meta)
+        setImportOpen(true)
+      } else {
+        setArchivePreview(preview)
+      }
     } catch (err) {
       console.error("[imported-skills] parse failed:", err)
       toast.error(
@@ -63,6 +77,31 @@ export function ImportedSkillsTab() {
     }
   }, [])
 
+  const handleImportBundle = useCallback(async () => {
+    const filePath = await open({
+      title: "Import Plugin Bundle",
+      filters: [{ name: "Plugin Bundle", extensions: ["zip"] }],
+    })
+    if (!filePath) return
+
+    const toastId = toast.loading("Importing plugin bundle...")
+    try {
+      const results = await importPluginBundle(filePath)
+      await refreshPlugins()
+      const own = results.filter((r) => !r.dependency_of)
+      const failed = results.filter((r) => !r.success)
+      toast.success(`Imported ${own.filter((r) => r.success).length} skill(s) from bundle`, { id: toastId })
+      if (failed.length > 0) {
+        toast.warning(failed.map((r) => r.error ?? r.skill_name).join("\n"))
+      }
+    } catch (err) {
+      toast.error(
+        `Bundle import failed: ${err instanceof Error ? err.message : String(err)}`,
+        { id: toastId, duration: Infinity },
+      )
+    }
+  }, [refreshPlugins])
+
   const handleToggleLock = useCallback(async (plugin: LibraryPlugin) => {
     const newLocked = !plugin.upgrade_locked
     try {
@@ -81,19 +120,9 @@ export function ImportedSkillsTab() {
     }
   }, [refreshPlugins])
 
-  const handleDeletePlugin = useCallback(async (plugin: LibraryPlugin) => {
-    const toastId = toast.loading(`Deleting plugin "${plugin.display_name}"...`)
-    try {
-      await deletePlugin(plugin.slug)
-      await refreshPlugins()
-      toast.success(`Deleted plugin "${plugin.display_name}"`, { id: toastId })
-    } catch (err) {
-      toast.error(
-        `Delete failed: ${err instanceof Error ? err.message : String(err)}`,
-        { id: toastId, duration: Infinity },
-      )
-    }
-  }, [refreshPlugins])
+  const handleDeletePlugin = useCallback((plugin: LibraryPlugin) => {
+    setDeletePluginTarget({ slug: plugin.slug, displayName: plugin.display_name })
+  }, [])
 
   const displayPlugins = plugins.filter((p) => !p.is_default)
 
@@ -121,6 +150,18 @@ export function ImportedSkillsTab() {
           <FolderInput className="size-4" />
           Upload
         </Button>
+        <Button variant="outline" className="w-36" onClick={handleImportBundle}>
+          <PackageOpen className="size-4" />
+          Bundle
+        </Button>
+        <Button variant="outline" className="w-36" onClick={() => setLintOpen(true)}>
+          <ShieldCheck className="size-4" />
+          Validate
+        </Button>
+        <Button variant="outline" className="w-36" onClick={() => setVerifyOpen(true)}>
+          <Fingerprint className="size-4" />
+          Verify
+        </Button>
       </div>
 
       {showLoading ? (
@@ -162,6 +203,26 @@ export function ImportedSkillsTab() {
                 <td className="py-2.5 pr-4">
                   <div className="font-medium">{plugin.display_name}</div>
                   <div className="text-xs text-muted-foreground">{plugin.slug}</div>
+                  {plugin.dependencies && plugin.dependencies.length > 0 && (
+                    <div className="mt-1 flex flex-wrap gap-1" aria-label={`Dependencies of ${plugin.display_name}`}>
+                      {plugin.dependencies.map((dep) => (
+                        <span
+                          key={dep.name}
+                          className={`rounded border px-1.5 py-0.5 font-mono text-[11px] ${dep.satisfied ? "text-muted-foreground" : "border-destructive/50 text-destructive"}`}
+                          title={
+                            dep.satisfied
+                              ? `Satisfied by v${dep.installed_version ?? "?"}`
+                              : dep.installed
+                                ? `Installed v${dep.installed_version ?? "?"} does not match ${dep.version_req}`
+                                : "Not installed"
+                          }
+                        >
+                          {dep.name} {dep.version_req}
+                          {!dep.satisfied && (dep.installed ? " (mismatch)" : " (missing)")}
+                        </span>
+                      ))}
+                    </div>
+                  )}
                 </td>
                 <td className="py-2.5 pr-4 text-muted-foreground font-mono text-xs">
                   {plugin.version ?? "\u2014"}
@@ -198,10 +259,24 @@ export function ImportedSkillsTab() {
                         <LockOpen className="size-3.5" />
                       </button>
                     )}
+                    <button
+                      type="button"
+                      className="text-muted-foreground hover:text-foreground transition-colors"
+                      aria-label={`Export ${plugin.display_name}`}
+                      title="Export as bundle"
+                      onClick={() => setExportPlugin(plugin)}
+                    >
+                      <Download className="size-3.5" />
+                    </button>
                     <button
                       type="button"
                       className="text-muted-foreground hover:text-destructive transition-colors"
                       aria-label={`Delete ${plugin.display_name}`}
+                      title={
+                        plugin.required_by && plugin.required_by.length > 0
+                          ? `Required by ${plugin.required_by.join(", ")}`
+                          : undefined
+                      }
                       onClick={() => handleDeletePlugin(plugin)}
                     >
                       <Trash2 className="size-3.5" />
@@ -229,12 +304,31 @@ export function ImportedSkillsTab() {
         onImported={refreshPlugins}
       />
 
+      <ImportSkillArchiveDialog
+        open={archivePreview !== null}
+        onOpenChange={(isOpen) => { if (!isOpen) setArchivePreview(null) }}
+        filePath={importFile}
+        preview={archivePreview}
+        onImported={refreshPlugins}
+      />
 
       <CreatePluginDialog
         open={createPluginOpen}
         onOpenChange={setCreatePluginOpen}
         onCreated={refreshPlugins}
       />
+
+      <ManifestLintDialog open={lintOpen} onOpenChange={setLintOpen} />
+      <SkillIntegrityDialog open={verifyOpen} onOpenChange={setVerifyOpen} />
+      <ExportPluginDialog
+        plugin={exportPlugin}
+        onOpenChange={(isOpen) => { if (!isOpen) setExportPlugin(null) }}
+      />
+      <DeletePluginDialog
+        plugin={deletePluginTarget}
+        onOpenChange={(isOpen) => { if (!isOpen) setDeletePluginTarget(null) }}
+        onDeleted={refreshPlugins}
+      />
     </div>
   )
 }
@@ -8,6 +8,7 @@ import { CloseGuard } from "@/components/close-guard";
 import { SplashScreen } from "@/components/splash-screen";
 import { SetupScreen } from "@/components/setup-screen";
 import ReconciliationAckDialog from "@/components/reconciliation-ack-dialog";
+import { SkillUpdateMergeDialog } from "@/components/skill-update-merge-dialog";
 import { useSettingsStore } from "@/stores/settings-store";
 import { toast } from "@/lib/toast";
 import { useSkillStore } from "@/stores/skill-store";
@@ -387,6 +388,7 @@ export function AppLayout() {
         {ready && isConfigured ? <Outlet /> : null}
       </main>
       <CloseGuard />
+      <SkillUpdateMergeDialog />
       {pendingSkillSwitch && (
         <Dialog open onOpenChange={(open) => { if (!open) handleSkillSwitchStay(); }}>
           <DialogContent showCloseButton={false}>
@@ -0,0 +1,103 @@
+import { useEffect, useState } from "react"
+import { AlertCircle, AlertTriangle, CheckCircle2, Loader2 } from "lucide-react"
+import { Button } from "@/components/ui/button"
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from "@/components/ui/dialog"
+import { lintPluginManifests } from "@/lib/tauri"
+import type { ManifestLintReport } from "@/lib/types"
+
+interface ManifestLintDialogProps {
+  open: boolean
+  onOpenChange: (open: boolean) => void
+}
+
+export function ManifestLintDialog({ open, onOpenChange }: ManifestLintDialogProps) {
+  const [report, setReport] = useState<ManifestLintReport | null>(null)
+  const [error, setError] = useState<string | null>(null)
+  const [running, setRunning] = useState(false)
+
+  const runLint = async () => {
+    setRunning(true)
+    setError(null)
+    try {
+      setReport(await lintPluginManifests())
+    } catch (err) {
+      console.error("event=manifest_lint_failed error=%s", err)
+      setError(err instanceof Error ? err.message : String(err))
+    } finally {
+      setRunning(false)
+    }
+  }
+
+  useEffect(() => {
+    if (!open) return
+    setReport(null)
+    void runLint()
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [open])
+
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogContent className="sm:max-w-2xl">
+        <DialogHeader>
+          <DialogTitle>Validate Manifests</DialogTitle>
+          <DialogDescription>
+            Checks marketplace.json and each plugin.json against the Claude plugin schema and the plugins on disk.
+          </DialogDescription>
+        </DialogHeader>
+
+        {error ? (
+          <p className="text-sm text-destructive">{error}</p>
+        ) : !report ? (
+          <div className="flex items-center justify-center gap-2 py-6 text-sm text-muted-foreground">
+            <Loader2 className="size-4 animate-spin" />
+            Validating…
+          </div>
+        ) : (
+          <div className="space-y-3">
+            <p className="text-sm" data-testid="manifest-lint-summary">
+              {report.plugins_checked} plugin(s) checked: {report.error_count} error(s), {report.warning_count} warning(s)
+            </p>
+            {report.findings.length === 0 ? (
+              <div className="flex items-center gap-2 text-sm text-muted-foreground">
+                <CheckCircle2 className="size-4 text-green-600" />
+                No problems found
+              </div>
+            ) : (
+              <ul className="max-h-80 space-y-2 overflow-y-auto">
+                {report.findings.map((finding, index) => (
+                  <li key={`${finding.path}-${finding.code}-${index}`} className="flex gap-2 text-sm">
+                    {finding.severity === "error" ? (
+                      <AlertCircle className="mt-0.5 size-4 shrink-0 text-destructive" aria-label="Error" />
+                    ) : (
+                      <AlertTriangle className="mt-0.5 size-4 shrink-0 text-amber-600" aria-label="Warning" />
+                    )}
+                    <div className="min-w-0">
+                      <div>{finding.message}</div>
+                      <div className="text-xs text-muted-foreground font-mono truncate">
+                        {finding.path} · {finding.code}
+                      </div>
+                    </div>
+                  </li>
+                ))}
+              </ul>
+            )}
+          </div>
+        )}
+
+        <DialogFooter>
+          <Button variant="outline" onClick={runLint} disabled={running}>
+            {running ? "Validating…" : "Re-run"}
+          </Button>
+          <Button onClick={() => onOpenChange(false)}>Close</Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  )
+}
@@ -1,4 +1,5 @@
 import { Loader2, LogOut } from "lucide-react"
+import { useQueryClient } from "@tanstack/react-query"
 import { Github } from "@/components/icons/github"
 import { Button } from "@/components/ui/button"
 import {
@@ -9,14 +10,34 @@ import {
   CardTitle,
 } from "@/components/ui/card"
 import { Badge } from "@/components/ui/badge"
+import { Input } from "@/components/ui/input"
+import { Label } from "@/components/ui/label"
 import { Avatar, AvatarImage, AvatarFallback } from "@/components/ui/avatar"
 import { useGithubLogoutMutation, useGithubUserQuery } from "@/lib/queries/auth"
+import { queryKeys } from "@/lib/queries/query-keys"
 
 interface GitHubSectionProps {
   onLoginOpen: () => void
+  githubHost: string
+  setGithubHost: (v: string) => void
+  githubOauthClientId: string
+  setGithubOauthClientId: (v: string) => void
+  feedbackRepo: string
+  setFeedbackRepo: (v: string) => void
+  autoSave: (overrides: Record<string, unknown>) => void | Promise<void>
 }
 
-export function GitHubSection({ onLoginOpen }: GitHubSectionProps) {
+export function GitHubSection({
+  onLoginOpen,
+  githubHost,
+  setGithubHost,
+  githubOauthClientId,
+  setGithubOauthClientId,
+  feedbackRepo,
+  setFeedbackRepo,
+  autoSave,
+}: GitHubSectionProps) {
+  const queryClient = useQueryClient()
   const { data: user = null, isLoading: isAuthLoading, dataUpdatedAt } = useGithubUserQuery()
   const logoutMutation = useGithubLogoutMutation()
   const isLoggedIn = Boolean(user)
@@ -85,6 +106,67 @@ export function GitHubSection({ onLoginOpen }: GitHubSectionProps) {
           )}
         </CardContent>
       </Card>
+
+      <Card>
+        <CardHeader>
+          <CardTitle>GitHub Enterprise</CardTitle>
+          <CardDescription>
+            Sign in, import marketplaces and file feedback against a GitHub Enterprise Server
+            instance instead of github.com. Changing the host signs you out.
+          </CardDescription>
+        </CardHeader>
+        <CardContent className="flex flex-col gap-4">
+          <div className="flex flex-col gap-2">
+            <Label htmlFor="github-host">Host</Label>
+            <Input
+              id="github-host"
+              placeholder="github.com"
+              value={githubHost}
+              onChange={(e) => setGithubHost(e.target.value)}
+              onBlur={async () => {
+                const host = githubHost.trim()
+                setGithubHost(host)
+                await autoSave({ githubHost: host || null })
+                queryClient.invalidateQueries({ queryKey: queryKeys.auth.githubUser })
+              }}
+            />
+          </div>
+          <div className="flex flex-col gap-2">
+            <Label htmlFor="github-oauth-client-id">OAuth app client ID</Label>
+            <Input
+              id="github-oauth-client-id"
+              placeholder="Required for GitHub Enterprise"
+              value={githubOauthClientId}
+              onChange={(e) => setGithubOauthClientId(e.target.value)}
+              onBlur={() => {
+                const clientId = githubOauthClientId.trim()
+                setGithubOauthClientId(clientId)
+                autoSave({ githubOauthClientId: clientId || null })
+              }}
+            />
+            <p className="text-xs text-muted-foreground">
+              Register an OAuth app with device flow enabled on your instance and paste its client ID.
+            </p>
+          </div>
+          <div className="flex flex-col gap-2">
+            <Label htmlFor="feedback-repo">Feedback repository</Label>
+            <Input
+              id="feedback-repo"
+              placeholder="owner/repo"
+              value={feedbackRepo}
+              onChange={(e) => setFeedbackRepo(e.target.value)}
+              onBlur={() => {
+                const repo = feedbackRepo.trim()
+                setFeedbackRepo(repo)
+                autoSave({ feedbackRepo: repo || null })
+              }}
+            />
+            <p className="text-xs text-muted-foreground">
+              Repository on your instance that receives feedback issues. Required for GitHub Enterprise.
+            </p>
+          </div>
+        </CardContent>
+      </Card>
     </div>
   )
 }
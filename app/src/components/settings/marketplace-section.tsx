@@ -1,3 +1,4 @@
+import { useState } from "react"
 import { Loader2, CheckCircle2, XCircle, PlugZap, Trash2, Plus } from "lucide-react"
 import { Button } from "@/components/ui/button"
 import {
@@ -12,6 +13,7 @@ import { Input } from "@/components/ui/input"
 import { Label } from "@/components/ui/label"
 import { Switch } from "@/components/ui/switch"
 import { cn } from "@/lib/utils"
+import { useSettingsStore } from "@/stores/settings-store"
 import { useMarketplaceRegistries, DEFAULT_MARKETPLACE_URL, type RegistryTestResult } from "@/hooks/use-marketplace-registries"
 
 function RegistryTestIcon({ state }: { state: RegistryTestResult | undefined }) {
@@ -21,15 +23,98 @@ function RegistryTestIcon({ state }: { state: RegistryTestResult | undefined })
   return <PlugZap className="size-3.5" />
 }
 
+function SourceHostTokensCard({ autoSave }: { autoSave: (overrides: Record<string, unknown>) => void }) {
+  const tokens = useSettingsStore((s) => s.sourceHostTokens)
+  const [host, setHost] = useState("")
+  const [token, setToken] = useState("")
+
+  const normalizedHost = host.trim().toLowerCase()
+  const canAdd = normalizedHost.length > 0 && token.trim().length > 0
+
+  const save = (next: typeof tokens) => autoSave({ sourceHostTokens: next })
+
+  const addToken = () => {
+    if (!canAdd) return
+    save([
+      ...tokens.filter((t) => t.host !== normalizedHost),
+      { host: normalizedHost, token: token.trim() },
+    ])
+    setHost("")
+    setToken("")
+  }
+
+  return (
+    <Card>
+      <CardHeader>
+        <CardTitle>Host tokens</CardTitle>
+        <CardDescription>
+          Access tokens for private GitLab and Gitea registries, keyed by host. Tokens are stored locally and never shown again.
+        </CardDescription>
+      </CardHeader>
+      <CardContent className="flex flex-col gap-4">
+        {tokens.length > 0 && (
+          <div className="rounded-md border">
+            {tokens.map((t) => (
+              <div
+                key={t.host}
+                className="flex items-center gap-4 border-b last:border-b-0 px-4 py-2"
+              >
+                <span className="flex-1 truncate text-sm font-mono text-muted-foreground">{t.host}</span>
+                <button
+                  type="button"
+                  className="text-muted-foreground hover:text-destructive transition-colors"
+                  aria-label={`Remove token for ${t.host}`}
+                  onClick={() => save(tokens.filter((other) => other.host !== t.host))}
+                >
+                  <Trash2 className="size-3.5" />
+                </button>
+              </div>
+            ))}
+          </div>
+        )}
+        <div className="flex items-end gap-2">
+          <div className="flex flex-1 flex-col gap-1.5">
+            <Label htmlFor="source-token-host">Host</Label>
+            <Input
+              id="source-token-host"
+              placeholder="gitlab.example.com"
+              value={host}
+              onChange={(e) => setHost(e.target.value)}
+            />
+          </div>
+          <div className="flex flex-1 flex-col gap-1.5">
+            <Label htmlFor="source-token-value">Token</Label>
+            <Input
+              id="source-token-value"
+              type="password"
+              autoComplete="off"
+              value={token}
+              onChange={(e) => setToken(e.target.value)}
+            />
+          </div>
+          <Button size="sm" variant="outline" disabled={!canAdd} onClick={addToken}>
+            <Plus className="size-4" />
+            Add
+          </Button>
+        </div>
+      </CardContent>
+    </Card>
+  )
+}
+
 interface MarketplaceSectionProps {
   autoUpdate: boolean
   setAutoUpdate: (v: boolean) => void
+  marketplaceOffline: boolean
+  setMarketplaceOffline: (v: boolean) => void
   autoSave: (overrides: Record<string, unknown>) => void
 }
 
 export function MarketplaceSection({
   autoUpdate,
   setAutoUpdate,
+  marketplaceOffline,
+  setMarketplaceOffline,
   autoSave,
 }: MarketplaceSectionProps) {
   const reg = useMarketplaceRegistries(autoSave)
@@ -116,13 +201,16 @@ export function MarketplaceSection({
           ) : (
             <div className="flex flex-col gap-3 rounded-md border p-4">
               <div className="flex flex-col gap-1.5">
-                <Label htmlFor="new-registry-url">GitHub repository</Label>
+                <Label htmlFor="new-registry-url">Repository or folder</Label>
                 <Input
                   id="new-registry-url"
-                  placeholder="owner/repo or owner/repo#branch"
+                  placeholder="owner/repo, a GitLab/Gitea/git URL, or a local folder"
                   value={reg.newRegistryUrl}
                   onChange={(e) => reg.setNewRegistryUrl(e.target.value)}
                 />
+                <p className="text-xs text-muted-foreground">
+                  Append #branch to pick a branch. Prefix self-hosted instances with gitlab+ or gitea+.
+                </p>
                 {reg.isDuplicateUrl && (
                   <p className="text-xs text-destructive">This registry is already added.</p>
                 )}
@@ -148,6 +236,8 @@ export function MarketplaceSection({
         </CardContent>
       </Card>
 
+      <SourceHostTokensCard autoSave={autoSave} />
+
       <Card>
         <CardHeader>
           <CardTitle>Auto-update</CardTitle>
@@ -164,6 +254,23 @@ export function MarketplaceSection({
           />
         </CardContent>
       </Card>
+
+      <Card>
+        <CardHeader>
+          <CardTitle>Offline mode</CardTitle>
+          <CardDescription>
+            Browse registries from the last cached catalog without contacting GitHub or other hosts. Imports still need a connection.
+          </CardDescription>
+        </CardHeader>
+        <CardContent>
+          <Switch
+            id="marketplace-offline"
+            checked={marketplaceOffline}
+            onCheckedChange={(checked) => { setMarketplaceOffline(checked); autoSave({ marketplaceOffline: checked }); }}
+            aria-label="Enable offline mode"
+          />
+        </CardContent>
+      </Card>
     </div>
   )
 }
@@ -46,9 +46,12 @@ interface ModelsSectionProps {
   setRefinePromptSuggestions: (v: boolean) => void;
   maxDimensions: number;
   setMaxDimensions: (v: number) => void;
+  documentTokenBudget: number;
+  setDocumentTokenBudget: (v: number) => void;
   autoSave: (overrides: {
     refinePromptSuggestions?: boolean;
     maxDimensions?: number;
+    documentTokenBudget?: number;
   }) => void | Promise<void>;
 }
 
@@ -140,6 +143,8 @@ export function ModelsSection({
   setRefinePromptSuggestions,
   maxDimensions,
   setMaxDimensions,
+  documentTokenBudget,
+  setDocumentTokenBudget,
   autoSave,
 }: ModelsSectionProps) {
   const [showApiKey, setShowApiKey] = useState(false);
@@ -779,6 +784,40 @@ export function ModelsSection({
           </div>
         </CardContent>
       </Card>
+
+      <Card>
+        <CardHeader>
+          <CardTitle>Reference Document Budget</CardTitle>
+          <CardDescription>
+            Approximate number of tokens of reference-document excerpts sent to
+            agents. The most relevant sections are picked first; set to 0 to
+            omit reference documents entirely.
+          </CardDescription>
+        </CardHeader>
+        <CardContent>
+          <div className="flex items-center gap-4">
+            <Label htmlFor="document-token-budget">Token budget</Label>
+            <Input
+              id="document-token-budget"
+              type="number"
+              min={0}
+              max={100000}
+              step={1000}
+              value={documentTokenBudget}
+              onChange={(e) => {
+                const parsed = parseInt(e.target.value);
+                const val = Math.max(
+                  0,
+                  Math.min(100000, Number.isNaN(parsed) ? 8000 : parsed),
+                );
+                setDocumentTokenBudget(val);
+              }}
+              onBlur={() => autoSave({ documentTokenBudget })}
+              className="w-28"
+            />
+          </div>
+        </CardContent>
+      </Card>
     </div>
   );
 }
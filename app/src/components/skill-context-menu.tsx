@@ -9,6 +9,13 @@ import {
   ContextMenuTrigger,
 } from "@/components/ui/context-menu";
 import type { UnifiedSkill, SkillMenuState } from "@/hooks/use-unified-skills";
+import type { SkillExportTarget } from "@/lib/types";
+
+export const SKILL_EXPORT_TARGETS: { target: SkillExportTarget; label: string }[] = [
+  { target: "agents_md", label: "Export as AGENTS.md section" },
+  { target: "cursor_rule", label: "Export as Cursor rule" },
+  { target: "flattened_markdown", label: "Export as flattened Markdown" },
+];
 
 export interface SkillContextMenuProps {
   skill: UnifiedSkill;
@@ -24,7 +31,8 @@ export interface SkillContextMenuProps {
   onCreatePlugin: (skill: UnifiedSkill) => void;
   onMoveToPlugin: (skill: UnifiedSkill) => void;
   onRemoveFromPlugin: (skill: UnifiedSkill) => void;
-  onExport: (skill: UnifiedSkill) => void;
+  onExport: (skill: UnifiedSkill, includeHistory: boolean) => void;
+  onExportToTarget: (skill: UnifiedSkill, target: SkillExportTarget) => void;
   pluginOptions: [string, string][];
 }
 
@@ -43,6 +51,7 @@ export function SkillContextMenu({
   onMoveToPlugin,
   onRemoveFromPlugin,
   onExport,
+  onExportToTarget,
   pluginOptions,
 }: SkillContextMenuProps) {
   return (
@@ -82,10 +91,21 @@ export function SkillContextMenu({
               </ContextMenuItem>
             )}
             {skill.source !== "marketplace" && (
-              <ContextMenuItem onSelect={() => onExport(skill)}>
-                Export as .skill
-              </ContextMenuItem>
+              <>
+                <ContextMenuItem onSelect={() => onExport(skill, false)}>
+                  Export as .skill
+                </ContextMenuItem>
+                <ContextMenuItem onSelect={() => onExport(skill, true)}>
+                  Export as .skill with git history
+                </ContextMenuItem>
+              </>
             )}
+            {skill.source !== "marketplace" &&
+              SKILL_EXPORT_TARGETS.map(({ target, label }) => (
+                <ContextMenuItem key={target} onSelect={() => onExportToTarget(skill, target)}>
+                  {label}
+                </ContextMenuItem>
+              ))}
             {skill.source !== "marketplace" && (
               <>
                 <ContextMenuSeparator />
@@ -0,0 +1,120 @@
+import { useEffect, useState } from "react"
+import { AlertCircle, AlertTriangle, CheckCircle2, Loader2 } from "lucide-react"
+import { Button } from "@/components/ui/button"
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from "@/components/ui/dialog"
+import { verifyImportedSkills } from "@/lib/tauri"
+import type { SkillIntegrityReport } from "@/lib/types"
+
+interface SkillIntegrityDialogProps {
+  open: boolean
+  onOpenChange: (open: boolean) => void
+}
+
+const STATUS_LABEL: Record<SkillIntegrityReport["status"], string> = {
+  verified: "Verified",
+  drifted: "Files changed since import",
+  missing: "Missing on disk",
+  unpinned: "Imported before integrity tracking; re-import to pin",
+}
+
+export function SkillIntegrityDialog({ open, onOpenChange }: SkillIntegrityDialogProps) {
+  const [reports, setReports] = useState<SkillIntegrityReport[] | null>(null)
+  const [error, setError] = useState<string | null>(null)
+  const [running, setRunning] = useState(false)
+
+  const runVerify = async () => {
+    setRunning(true)
+    setError(null)
+    try {
+      setReports(await verifyImportedSkills())
+    } catch (err) {
+      console.error("event=verify_imported_skills_failed error=%s", err)
+      setError(err instanceof Error ? err.message : String(err))
+    } finally {
+      setRunning(false)
+    }
+  }
+
+  useEffect(() => {
+    if (!open) return
+    setReports(null)
+    void runVerify()
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [open])
+
+  const problems = reports?.filter((r) => r.status !== "verified") ?? []
+
+  return (
+    <Dialog open={open} onOpenChange={onOpenChange}>
+      <DialogContent className="sm:max-w-2xl">
+        <DialogHeader>
+          <DialogTitle>Verify Imported Skills</DialogTitle>
+          <DialogDescription>
+            Compares each marketplace skill on disk with the file checksums recorded when it was imported.
+          </DialogDescription>
+        </DialogHeader>
+
+        {error ? (
+          <p className="text-sm text-destructive">{error}</p>
+        ) : !reports ? (
+          <div className="flex items-center justify-center gap-2 py-6 text-sm text-muted-foreground">
+            <Loader2 className="size-4 animate-spin" />
+            Verifying…
+          </div>
+        ) : (
+          <div className="space-y-3">
+            <p className="text-sm" data-testid="skill-integrity-summary">
+              {reports.length} skill(s) checked: {reports.length - problems.length} verified, {problems.length} need attention
+            </p>
+            {problems.length === 0 ? (
+              <div className="flex items-center gap-2 text-sm text-muted-foreground">
+                <CheckCircle2 className="size-4 text-green-600" />
+                All imported skills match their recorded checksums
+              </div>
+            ) : (
+              <ul className="max-h-80 space-y-2 overflow-y-auto">
+                {problems.map((report) => (
+                  <li key={`${report.plugin_slug}/${report.skill_name}`} className="flex gap-2 text-sm">
+                    {report.status === "unpinned" ? (
+                      <AlertTriangle className="mt-0.5 size-4 shrink-0 text-amber-600" aria-label="Warning" />
+                    ) : (
+                      <AlertCircle className="mt-0.5 size-4 shrink-0 text-destructive" aria-label="Error" />
+                    )}
+                    <div className="min-w-0">
+                      <div>
+                        <span className="font-medium">{report.skill_name}</span> — {STATUS_LABEL[report.status]}
+                      </div>
+                      <div className="text-xs text-muted-foreground font-mono truncate">
+                        {report.source_url}
+                        {report.source_commit ? ` @ ${report.source_commit.slice(0, 12)}` : ""}
+                      </div>
+                      {report.files.map((file) => (
+                        <div key={file.path} className="text-xs font-mono">
+                          {file.change}: {file.path}
+                        </div>
+                      ))}
+                    </div>
+                  </li>
+                ))}
+              </ul>
+            )}
+          </div>
+        )}
+
+        <DialogFooter>
+          <Button variant="outline" onClick={runVerify} disabled={running}>
+            {running ? "Verifying…" : "Re-run"}
+          </Button>
+          <Button onClick={() => onOpenChange(false)}>Close</Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  )
+}
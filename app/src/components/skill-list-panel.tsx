@@ -17,8 +17,10 @@ import {
 import { toast } from "@/lib/toast";
 import SkillDialog from "@/components/skill-dialog";
 import DeleteSkillDialog from "@/components/delete-skill-dialog";
+import DeletePluginDialog, { type DeletePluginTarget } from "@/components/delete-plugin-dialog";
 import RestoreVersionDialog from "@/components/workspace/restore-version-dialog";
 import { ImportSkillDialog } from "@/components/import-skill-dialog";
+import { ImportSkillArchiveDialog } from "@/components/import-skill-archive-dialog";
 import { CreatePluginDialog } from "@/components/create-plugin-dialog";
 import { MoveToPluginDialog } from "@/components/move-to-plugin-dialog";
 import { SkillRow } from "@/components/skill-row";
@@ -31,15 +33,15 @@ import type { UnifiedSkill } from "@/hooks/use-unified-skills";
 import type { SkillSummary } from "@/lib/types";
 import { open, save } from "@tauri-apps/plugin-dialog";
 import {
-  deletePlugin,
   exportSkillAsFile,
+  exportSkillToTarget,
   getExternallyLockedSkills,
-  parseSkillFile,
+  previewSkillArchive,
   removeSkillFromPlugin,
   resetWorkflowStep,
 } from "@/lib/tauri";
 import { restartSkillOpenHandsSession } from "@/lib/skill-openhands-session";
-import type { SkillFileMeta } from "@/lib/types";
+import type { SkillArchivePreview, SkillExportTarget, SkillFileMeta } from "@/lib/types";
 import { cn } from "@/lib/utils";
 import {
   useBuilderSkillsQuery,
@@ -48,6 +50,27 @@ import {
   useInvalidateSkillQueries,
 } from "@/lib/queries/skills";
 
+const EXPORT_TARGET_FILES: Record<
+  SkillExportTarget,
+  { title: string; defaultPath: (name: string) => string; filter: { name: string; extensions: string[] } }
+> = {
+  agents_md: {
+    title: "Export to AGENTS.md",
+    defaultPath: () => "AGENTS.md",
+    filter: { name: "Markdown", extensions: ["md"] },
+  },
+  cursor_rule: {
+    title: "Export Cursor Rule",
+    defaultPath: (name) => `${name}.mdc`,
+    filter: { name: "Cursor Rule", extensions: ["mdc"] },
+  },
+  flattened_markdown: {
+    title: "Export Flattened Markdown",
+    defaultPath: (name) => `${name}.md`,
+    filter: { name: "Markdown", extensions: ["md"] },
+  },
+};
+
 export interface SkillListPanelProps {
   onSelectSkill?: (
     name: string,
@@ -73,6 +96,7 @@ export function SkillListPanel({
     name: null, description: null, version: null,
     user_invocable: null, disable_model_invocation: null,
   });
+  const [uploadArchive, setUploadArchive] = useState<SkillArchivePreview | null>(null);
   const [search, setSearch] = useState("");
   const [deleteTarget, setDeleteTarget] = useState<SkillSummary | null>(null);
   const [deleteOpen, setDeleteOpen] = useState(false);
@@ -82,8 +106,7 @@ export function SkillListPanel({
   const setLockedSkills = useSkillStore((s) => s.setLockedSkills);
   const [moveTarget, setMoveTarget] = useState<UnifiedSkill | null>(null);
   const [createPluginTarget, setCreatePluginTarget] = useState<UnifiedSkill | null>(null);
-  const [deletePluginTarget, setDeletePluginTarget] = useState<{ slug: string; displayName: string } | null>(null);
-  const [deletingPlugin, setDeletingPlugin] = useState(false);
+  const [deletePluginTarget, setDeletePluginTarget] = useState<DeletePluginTarget | null>(null);
 
   const workspacePath = useSettingsStore((s) => s.workspacePath);
   const selectedSkillId = useSkillStore((s) => s.activeSkillId);
@@ -255,25 +278,6 @@ export function SkillListPanel({
     setDeletePluginTarget({ slug: pluginSlug, displayName: pluginDisplayName });
   }
 
-  async function confirmDeletePlugin() {
-    if (!deletePluginTarget) return;
-    setDeletingPlugin(true);
-    const toastId = toast.loading(`Deleting plugin "${deletePluginTarget.displayName}"...`);
-    try {
-      await deletePlugin(deletePluginTarget.slug);
-      toast.success(`Deleted plugin "${deletePluginTarget.displayName}"`, { id: toastId });
-      setDeletePluginTarget(null);
-      await refreshSkillLists();
-    } catch (err) {
-      toast.error(
-        `Failed to delete plugin: ${err instanceof Error ? err.message : String(err)}`,
-        { id: toastId },
-      );
-    } finally {
-      setDeletingPlugin(false);
-    }
-  }
-
   function handleCreatePlugin(skill: UnifiedSkill) {
     setCreatePluginTarget(skill)
   }
@@ -289,10 +293,14 @@ export function SkillListPanel({
     });
     if (!filePath) return;
     try {
-      const meta = await parseSkillFile(filePath);
+      const preview = await previewSkillArchive(filePath);
       setUploadFile(filePath);
-      setUploadMeta(meta);
-      setUploadOpen(true);
+      if (preview.skills.length === 1 && !preview.plugin_name) {
+        setUploadMeta(preview.skills[0].meta);
+        setUploadOpen(true);
+      } else {
+        setUploadArchive(preview);
+      }
     } catch (err) {
       console.error("[skill-list-panel] parse failed:", err);
       toast.error("Import failed: not a valid skill package.", {
@@ -315,7 +323,7 @@ export function SkillListPanel({
     }
   }
 
-  async function handleExportAsSkill(skill: UnifiedSkill) {
+  async function handleExportAsSkill(skill: UnifiedSkill, includeHistory: boolean) {
     const destPath = await save({
       title: "Export Skill",
       defaultPath: `${skill.name}.skill`,
@@ -323,7 +331,25 @@ export function SkillListPanel({
     });
     if (!destPath) return;
     try {
-      await exportSkillAsFile(skill.name, skill.pluginSlug, destPath);
+      await exportSkillAsFile(skill.name, skill.pluginSlug, destPath, includeHistory);
+      toast.success(`Exported "${skill.name}"`);
+    } catch (err) {
+      toast.error(`Export failed: ${err instanceof Error ? err.message : String(err)}`, {
+        duration: Infinity,
+      });
+    }
+  }
+
+  async function handleExportToTarget(skill: UnifiedSkill, target: SkillExportTarget) {
+    const { title, defaultPath, filter } = EXPORT_TARGET_FILES[target];
+    const destPath = await save({
+      title,
+      defaultPath: defaultPath(skill.name),
+      filters: [filter],
+    });
+    if (!destPath) return;
+    try {
+      await exportSkillToTarget(skill.name, skill.pluginSlug, target, destPath);
       toast.success(`Exported "${skill.name}"`);
     } catch (err) {
       toast.error(`Export failed: ${err instanceof Error ? err.message : String(err)}`, {
@@ -417,6 +443,7 @@ export function SkillListPanel({
               onMoveToPlugin={handleMoveToPlugin}
               onRemoveFromPlugin={handleRemoveFromPlugin}
               onExport={handleExportAsSkill}
+              onExportToTarget={handleExportToTarget}
               onDeletePlugin={handleDeletePlugin}
               pluginOptions={pluginOptions}
             />
@@ -446,6 +473,16 @@ export function SkillListPanel({
         }}
       />
 
+      <ImportSkillArchiveDialog
+        open={uploadArchive !== null}
+        onOpenChange={(isOpen) => { if (!isOpen) setUploadArchive(null); }}
+        filePath={uploadFile}
+        preview={uploadArchive}
+        onImported={() => {
+          invalidateSkillQueries().catch(() => {});
+        }}
+      />
+
       <Dialog open={redoTarget !== null} onOpenChange={(open) => { if (!open) setRedoTarget(null); }}>
         <DialogContent>
           <DialogHeader>
@@ -474,43 +511,16 @@ export function SkillListPanel({
         </DialogContent>
       </Dialog>
 
-      <Dialog
-        open={deletePluginTarget !== null}
-        onOpenChange={(open) => { if (!open && !deletingPlugin) setDeletePluginTarget(null); }}
-      >
-        <DialogContent>
-          <DialogHeader>
-            <DialogTitle>Delete Plugin?</DialogTitle>
-            <DialogDescription>
-              {(() => {
-                if (!deletePluginTarget) return null;
-                const skillCount = unifiedSkills.filter(
-                  (s) => s.pluginSlug === deletePluginTarget.slug,
-                ).length;
-                return skillCount > 0
-                  ? `This will permanently delete the plugin "${deletePluginTarget.displayName}" and all ${skillCount} skill${skillCount === 1 ? "" : "s"} inside it, including their files. This cannot be undone.`
-                  : `This will permanently delete the plugin "${deletePluginTarget.displayName}". This cannot be undone.`;
-              })()}
-            </DialogDescription>
-          </DialogHeader>
-          <DialogFooter>
-            <Button
-              variant="ghost"
-              onClick={() => setDeletePluginTarget(null)}
-              disabled={deletingPlugin}
-            >
-              Cancel
-            </Button>
-            <Button
-              variant="destructive"
-              onClick={confirmDeletePlugin}
-              disabled={deletingPlugin}
-            >
-              {deletingPlugin ? "Deleting…" : "Delete Plugin"}
-            </Button>
-          </DialogFooter>
-        </DialogContent>
-      </Dialog>
+      <DeletePluginDialog
+        plugin={deletePluginTarget}
+        skillCount={
+          deletePluginTarget
+            ? unifiedSkills.filter((s) => s.pluginSlug === deletePluginTarget.slug).length
+            : undefined
+        }
+        onOpenChange={(open) => { if (!open) setDeletePluginTarget(null); }}
+        onDeleted={refreshSkillLists}
+      />
 
       <ImportSkillDialog
         open={uploadOpen}
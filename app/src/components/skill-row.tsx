@@ -7,7 +7,7 @@ import {
 } from "@/hooks/use-unified-skills";
 import type { UnifiedSkill } from "@/hooks/use-unified-skills";
 import { PURPOSE_SHORT_LABELS } from "@/lib/types";
-import type { Purpose } from "@/lib/types";
+import type { Purpose, SkillExportTarget } from "@/lib/types";
 import { cn } from "@/lib/utils";
 
 export interface SkillRowProps {
@@ -27,7 +27,8 @@ export interface SkillRowProps {
   onCreatePlugin: (skill: UnifiedSkill) => void;
   onMoveToPlugin: (skill: UnifiedSkill) => void;
   onRemoveFromPlugin: (skill: UnifiedSkill) => void;
-  onExport: (skill: UnifiedSkill) => void;
+  onExport: (skill: UnifiedSkill, includeHistory: boolean) => void;
+  onExportToTarget: (skill: UnifiedSkill, target: SkillExportTarget) => void;
   onDeletePlugin: (pluginSlug: string, pluginDisplayName: string) => void;
   pluginOptions: [string, string][];
 }
@@ -63,6 +64,7 @@ export function SkillRow({
   onMoveToPlugin,
   onRemoveFromPlugin,
   onExport,
+  onExportToTarget,
   onDeletePlugin,
   pluginOptions,
 }: SkillRowProps) {
@@ -156,6 +158,7 @@ export function SkillRow({
           onMoveToPlugin={onMoveToPlugin}
           onRemoveFromPlugin={onRemoveFromPlugin}
           onExport={onExport}
+          onExportToTarget={onExportToTarget}
           pluginOptions={pluginOptions}
         >
           {rowContent}
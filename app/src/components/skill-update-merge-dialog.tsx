@@ -0,0 +1,142 @@
+import { useEffect, useState } from "react"
+import { GitMerge, Loader2 } from "lucide-react"
+import { toast } from "@/lib/toast"
+import { Button } from "@/components/ui/button"
+import {
+  Dialog,
+  DialogContent,
+  DialogDescription,
+  DialogFooter,
+  DialogHeader,
+  DialogTitle,
+} from "@/components/ui/dialog"
+import { Textarea } from "@/components/ui/textarea"
+import { applyImportedSkillUpdate } from "@/lib/tauri"
+import { useInvalidateSkillQueries } from "@/lib/queries/skills"
+import { useSettingsStore } from "@/stores/settings-store"
+
+const CONFLICT_MARKER = /^<<<<<<< /m
+
+/**
+ * Review conflicts from merging an upstream update into a customized skill.
+ * Works through `pendingSkillMerges` one skill at a time; each conflicted file
+ * starts as the merged text with conflict markers and must be resolved before
+ * the merge is committed and tagged.
+ */
+export function SkillUpdateMergeDialog() {
+  const pending = useSettingsStore((s) => s.pendingSkillMerges)
+  const setPending = useSettingsStore((s) => s.setPendingSkillMerges)
+  const invalidateSkillQueries = useInvalidateSkillQueries()
+  const current = pending[0] ?? null
+  const [resolutions, setResolutions] = useState<Record<string, string | null>>({})
+  const [applying, setApplying] = useState(false)
+  const [error, setError] = useState<string | null>(null)
+
+  useEffect(() => {
+    setError(null)
+    setResolutions(
+      Object.fromEntries(current?.preview.conflicts.map((c) => [c.path, c.merged]) ?? []),
+    )
+  }, [current])
+
+  if (!current) return null
+  const { preview, sourceUrl } = current
+  const unresolved = preview.conflicts.filter((c) => {
+    const value = resolutions[c.path]
+    return value !== null && (value === undefined || CONFLICT_MARKER.test(value))
+  })
+
+  const skip = () => setPending(pending.slice(1))
+
+  const apply = async () => {
+    setApplying(true)
+    setError(null)
+    try {
+      const applied = await applyImportedSkillUpdate(preview, sourceUrl, resolutions)
+      toast.success(`Updated ${preview.skill_name} to ${applied.version}`)
+      void invalidateSkillQueries()
+      skip()
+    } catch (err) {
+      console.error("event=skill_update_merge_failed skill=%s error=%s", preview.skill_name, err)
+      setError(err instanceof Error ? err.message : String(err))
+    } finally {
+      setApplying(false)
+    }
+  }
+
+  return (
+    <Dialog open onOpenChange={(open) => { if (!open) setPending([]) }}>
+      <DialogContent className="sm:max-w-3xl">
+        <DialogHeader>
+          <DialogTitle className="flex items-center gap-2">
+            <GitMerge className="size-4" />
+            Merge {preview.skill_name} {preview.version}
+          </DialogTitle>
+          <DialogDescription>
+            The update overlaps with local edits. Resolve each file, then commit the merge.
+            {preview.auto_merged.length > 0 &&
+              ` Merged automatically: ${preview.auto_merged.join(", ")}.`}
+          </DialogDescription>
+        </DialogHeader>
+
+        <div className="max-h-[60vh] space-y-4 overflow-y-auto">
+          {preview.conflicts.map((conflict) => {
+            const value = resolutions[conflict.path]
+            return (
+              <div key={conflict.path} className="space-y-2">
+                <div className="flex items-center justify-between gap-2">
+                  <span className="font-mono text-sm">{conflict.path}</span>
+                  <div className="flex gap-1">
+                    <Button
+                      size="sm"
+                      variant="outline"
+                      onClick={() => setResolutions((r) => ({ ...r, [conflict.path]: conflict.local }))}
+                    >
+                      Keep local
+                    </Button>
+                    <Button
+                      size="sm"
+                      variant="outline"
+                      onClick={() => setResolutions((r) => ({ ...r, [conflict.path]: conflict.upstream }))}
+                    >
+                      Take upstream
+                    </Button>
+                  </div>
+                </div>
+                {value === null ? (
+                  <p className="text-sm text-muted-foreground">File will be deleted.</p>
+                ) : (
+                  <Textarea
+                    aria-label={`Resolution for ${conflict.path}`}
+                    className="max-h-80 font-mono text-xs"
+                    value={value ?? ""}
+                    onChange={(e) =>
+                      setResolutions((r) => ({ ...r, [conflict.path]: e.target.value }))
+                    }
+                  />
+                )}
+              </div>
+            )
+          })}
+        </div>
+
+        {error && <p className="text-sm text-destructive">{error}</p>}
+
+        <DialogFooter>
+          <span className="mr-auto text-sm text-muted-foreground">
+            {unresolved.length > 0
+              ? `${unresolved.length} file(s) still have conflict markers`
+              : "All conflicts resolved"}
+          </span>
+          <Button variant="outline" onClick={skip} disabled={applying}>
+            Skip
+          </Button>
+          <Button onClick={apply} disabled={applying || unresolved.length > 0}>
+            {applying && <Loader2 className="size-4 animate-spin" />}
+            Commit merge
+          </Button>
+        </DialogFooter>
+      </DialogContent>
+    </Dialog>
+  )
+}
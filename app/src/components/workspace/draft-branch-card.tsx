@@ -0,0 +1,138 @@
+import { useState, useEffect } from "react";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { Label } from "@/components/ui/label";
+import { discardSkillDraft, getSkillDraft, mergeSkillDraft, startSkillDraft } from "@/lib/tauri";
+import { useSkillStore } from "@/stores/skill-store";
+import { toast } from "@/lib/toast";
+import type { SkillDraft } from "@/lib/types";
+
+type DraftOperation = "start" | "merge" | "discard";
+
+interface DraftBranchCardProps {
+  skillName: string;
+  pluginSlug: string;
+  /** Called after a draft is started, merged or discarded. */
+  onChanged?: () => void;
+}
+
+export function DraftBranchCard({ skillName, pluginSlug, onChanged }: DraftBranchCardProps) {
+  const [draft, setDraft] = useState<SkillDraft | null>(null);
+  const [draftName, setDraftName] = useState("");
+  const [running, setRunning] = useState<DraftOperation | null>(null);
+  const [confirmDiscard, setConfirmDiscard] = useState(false);
+
+  const loadDraft = () =>
+    getSkillDraft(skillName, pluginSlug)
+      .then((result) => setDraft(result))
+      .catch((err) => {
+        console.warn("event=skill_draft_fetch_failed skill=%s plugin=%s error=%s", skillName, pluginSlug, err);
+      });
+
+  useEffect(() => {
+    setConfirmDiscard(false);
+    void loadDraft();
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [skillName, pluginSlug]);
+
+  const run = async (operation: DraftOperation, action: () => Promise<void>) => {
+    setRunning(operation);
+    try {
+      await action();
+      onChanged?.();
+    } catch (err) {
+      console.error("event=skill_draft_%s_failed skill=%s error=%s", operation, skillName, err);
+      toast.error(`Failed to ${operation} draft: ${String(err)}`, { duration: Infinity });
+    } finally {
+      setRunning(null);
+      setConfirmDiscard(false);
+    }
+  };
+
+  const handleStart = () =>
+    run("start", async () => {
+      const started = await startSkillDraft(skillName, pluginSlug, draftName.trim() || undefined);
+      setDraft(started);
+      setDraftName("");
+      toast.success(`Refine turns now commit to ${started.branch}`);
+    });
+
+  const handleMerge = () =>
+    run("merge", async () => {
+      const merge = await mergeSkillDraft(skillName, pluginSlug);
+      setDraft(null);
+      useSkillStore.getState().setLatestVersion(merge.release.version);
+      toast.success(`Merged into ${merge.base_branch} and tagged v${merge.release.version}`);
+    });
+
+  const handleDiscard = () =>
+    run("discard", async () => {
+      await discardSkillDraft(skillName, pluginSlug);
+      setDraft(null);
+      toast.success("Draft discarded");
+    });
+
+  const busy = running !== null;
+
+  return (
+    <div className="rounded-lg border bg-card p-4">
+      <h3 className="text-sm font-semibold mb-3">Draft</h3>
+      {!draft ? (
+        <div className="space-y-1.5">
+          <Label htmlFor="skill-draft-name">Draft name</Label>
+          <div className="flex gap-2">
+            <Input
+              id="skill-draft-name"
+              value={draftName}
+              placeholder="Optional — defaults to a timestamp"
+              onChange={(e) => setDraftName(e.target.value)}
+              disabled={busy}
+            />
+            <Button variant="outline" size="sm" onClick={handleStart} disabled={busy}>
+              {running === "start" ? "Starting…" : "Start Draft"}
+            </Button>
+          </div>
+          <p className="text-xs text-muted-foreground">
+            Refine turns and regenerations commit to the draft until it is merged or discarded.
+          </p>
+        </div>
+      ) : (
+        <div className="space-y-3">
+          <p className="text-sm" data-testid="skill-draft-status">
+            On <span className="font-medium">{draft.branch}</span> — {draft.ahead} commit(s) ahead of{" "}
+            {draft.base_branch}
+            {draft.behind > 0 ? `, ${draft.behind} behind` : ""}
+          </p>
+          {draft.files.length === 0 ? (
+            <p className="text-xs text-muted-foreground">No changes yet</p>
+          ) : (
+            <ul className="space-y-0.5 text-xs font-mono">
+              {draft.files.map((file) => (
+                <li key={file.path} className="flex justify-between gap-2">
+                  <span className="truncate">{file.path}</span>
+                  <span className="shrink-0 text-muted-foreground">
+                    +{file.additions} −{file.deletions}
+                  </span>
+                </li>
+              ))}
+            </ul>
+          )}
+          <div className="flex gap-2">
+            <Button size="sm" onClick={handleMerge} disabled={busy}>
+              {running === "merge" ? "Merging…" : "Merge & Tag"}
+            </Button>
+            {confirmDiscard ? (
+              <Button variant="destructive" size="sm" onClick={handleDiscard} disabled={busy}>
+                {running === "discard" ? "Discarding…" : "Confirm Discard"}
+              </Button>
+            ) : (
+              <Button variant="outline" size="sm" onClick={() => setConfirmDiscard(true)} disabled={busy}>
+                Discard
+              </Button>
+            )}
+          </div>
+        </div>
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,148 @@
+import { useState } from "react";
+import { Input } from "@/components/ui/input";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { Textarea } from "@/components/ui/textarea";
+import {
+  assertionKind,
+  createAssertion,
+  type Assertion,
+  type AssertionKind,
+} from "@/lib/eval-workbench";
+
+const KIND_LABELS: Record<AssertionKind, string> = {
+  "llm-rubric": "LLM rubric",
+  contains: "Contains",
+  "not-contains": "Does not contain",
+  regex: "Matches regex",
+  "json-schema": "JSON schema",
+  "file-exists": "File exists",
+  "file-contains": "File contains",
+  "max-turns": "Max turns",
+  "max-cost": "Max cost (USD)",
+};
+
+function JsonSchemaInput({
+  value,
+  onChange,
+  label,
+}: {
+  value: unknown;
+  onChange: (value: unknown) => void;
+  label: string;
+}) {
+  const [text, setText] = useState(() => JSON.stringify(value, null, 2));
+  const [invalid, setInvalid] = useState(false);
+
+  return (
+    <div className="space-y-1">
+      <Textarea
+        aria-label={label}
+        className="font-mono text-xs"
+        value={text}
+        onChange={(event) => {
+          setText(event.target.value);
+          try {
+            onChange(JSON.parse(event.target.value));
+            setInvalid(false);
+          } catch {
+            setInvalid(true);
+          }
+        }}
+      />
+      {invalid ? (
+        <p className="text-xs text-destructive">Schema is not valid JSON yet.</p>
+      ) : null}
+    </div>
+  );
+}
+
+export function AssertionEditor({
+  assertion,
+  index,
+  onChange,
+}: {
+  assertion: Assertion;
+  index: number;
+  onChange: (assertion: Assertion) => void;
+}) {
+  const kind = assertionKind(assertion);
+  const label = `Assertion ${index + 1}`;
+
+  return (
+    <div className="space-y-2">
+      <Select
+        value={kind}
+        onValueChange={(value) =>
+          onChange(createAssertion(value as AssertionKind, assertion))
+        }
+      >
+        <SelectTrigger className="h-8 w-48" aria-label={`${label} type`}>
+          <SelectValue />
+        </SelectTrigger>
+        <SelectContent>
+          {(Object.keys(KIND_LABELS) as AssertionKind[]).map((option) => (
+            <SelectItem key={option} value={option}>
+              {KIND_LABELS[option]}
+            </SelectItem>
+          ))}
+        </SelectContent>
+      </Select>
+      {typeof assertion === "string" ? (
+        <Textarea
+          aria-label={label}
+          value={assertion}
+          onChange={(event) => onChange(event.target.value)}
+          placeholder="Describe the assertion the answer should satisfy."
+        />
+      ) : assertion.type === "json-schema" ? (
+        <JsonSchemaInput
+          key={index}
+          label={label}
+          value={assertion.value}
+          onChange={(value) => onChange({ ...assertion, value })}
+        />
+      ) : assertion.type === "max-turns" || assertion.type === "max-cost" ? (
+        <Input
+          aria-label={label}
+          type="number"
+          min={0}
+          step={assertion.type === "max-cost" ? 0.01 : 1}
+          value={assertion.value}
+          onChange={(event) =>
+            onChange({ ...assertion, value: Number(event.target.value) })
+          }
+        />
+      ) : assertion.type === "file-exists" || assertion.type === "file-contains" ? (
+        <div className="space-y-2">
+          <Input
+            aria-label={`${label} path`}
+            value={assertion.path}
+            onChange={(event) => onChange({ ...assertion, path: event.target.value })}
+            placeholder="report.md"
+          />
+          {assertion.type === "file-contains" ? (
+            <Input
+              aria-label={label}
+              value={assertion.value}
+              onChange={(event) => onChange({ ...assertion, value: event.target.value })}
+              placeholder="Text the file should contain"
+            />
+          ) : null}
+        </div>
+      ) : (
+        <Input
+          aria-label={label}
+          value={assertion.value}
+          onChange={(event) => onChange({ ...assertion, value: event.target.value })}
+          placeholder={assertion.type === "regex" ? "\\d+%" : "Expected text"}
+        />
+      )}
+    </div>
+  );
+}
@@ -0,0 +1,174 @@
+import { useState } from "react";
+import { save } from "@tauri-apps/plugin-dialog";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import {
+  exportBenchmarkComparison,
+  getErrorMessage,
+  NO_SKILL_REF,
+  type BenchmarkComparison as BenchmarkComparisonReport,
+  type BenchmarkExportFormat,
+  type BenchmarkRun,
+} from "@/lib/eval-workbench";
+import { useRunBenchmarkComparison } from "@/lib/queries/eval-scenarios";
+import { toast } from "@/lib/toast";
+
+const EXPORT_FILES: Record<BenchmarkExportFormat, { extension: string; name: string }> = {
+  markdown: { extension: "md", name: "Markdown" },
+  json: { extension: "json", name: "JSON" },
+};
+
+function formatRate(value: number | null): string {
+  return value === null ? "n/a" : `${Math.round(value * 100)}%`;
+}
+
+function formatDelta(value: number | null, format: (value: number) => string): string {
+  if (value === null) return "n/a";
+  return `${value > 0 ? "+" : ""}${format(value)}`;
+}
+
+function formatRun(run: BenchmarkRun): string {
+  if (run.error) return "error";
+  const verdict = run.passed === null ? "ungraded" : run.passed ? "pass" : "fail";
+  return `${verdict} (${run.passedAssertions}/${run.decidedAssertions})`;
+}
+
+export function BenchmarkComparisonResults({ report }: { report: BenchmarkComparisonReport }) {
+  return (
+    <div className="mt-3 space-y-2" data-testid="benchmark-comparison-results">
+      <p className="text-xs">
+        <span className="font-medium">{report.baseline.label}</span>{" "}
+        {formatRate(report.baselinePassRate)} →{" "}
+        <span className="font-medium">{report.candidate.label}</span>{" "}
+        {formatRate(report.candidatePassRate)} of graded scenarios pass
+      </p>
+      <table className="w-full text-left text-xs">
+        <thead className="text-muted-foreground">
+          <tr>
+            <th className="py-1 pr-2 font-medium">Scenario</th>
+            <th className="py-1 pr-2 font-medium">{report.baseline.label}</th>
+            <th className="py-1 pr-2 font-medium">{report.candidate.label}</th>
+            <th className="py-1 pr-2 font-medium">Δ pass rate</th>
+            <th className="py-1 pr-2 font-medium">Δ tokens</th>
+            <th className="py-1 font-medium">Δ cost</th>
+          </tr>
+        </thead>
+        <tbody>
+          {report.scenarios.map((scenario) => (
+            <tr key={scenario.scenarioName} className="border-t">
+              <td className="py-1 pr-2 font-medium">{scenario.scenarioName}</td>
+              <td className="py-1 pr-2" title={scenario.baseline.error ?? undefined}>
+                {formatRun(scenario.baseline)}
+              </td>
+              <td className="py-1 pr-2" title={scenario.candidate.error ?? undefined}>
+                {formatRun(scenario.candidate)}
+              </td>
+              <td className="py-1 pr-2">
+                {formatDelta(scenario.passRateDelta, (v) => `${Math.round(v * 100)} pts`)}
+              </td>
+              <td className="py-1 pr-2">
+                {formatDelta(scenario.tokenDelta, (v) => v.toLocaleString())}
+              </td>
+              <td className="py-1">
+                {formatDelta(scenario.costDeltaUsd, (v) => `$${v.toFixed(4)}`)}
+              </td>
+            </tr>
+          ))}
+        </tbody>
+      </table>
+      <p className="text-xs text-muted-foreground">
+        Tokens and cost show n/a when the runtime does not report usage.
+      </p>
+    </div>
+  );
+}
+
+export function BenchmarkComparison({
+  skillName,
+  pluginSlug,
+}: {
+  skillName: string;
+  pluginSlug: string;
+}) {
+  const [baselineRef, setBaselineRef] = useState(NO_SKILL_REF);
+  const [candidateRef, setCandidateRef] = useState("HEAD");
+  const [report, setReport] = useState<BenchmarkComparisonReport | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const compareMutation = useRunBenchmarkComparison(skillName, pluginSlug);
+
+  async function handleCompare() {
+    setError(null);
+    try {
+      setReport(await compareMutation.mutateAsync({ baselineRef, candidateRef }));
+    } catch (err) {
+      setReport(null);
+      setError(getErrorMessage(err));
+    }
+  }
+
+  async function handleExport(format: BenchmarkExportFormat) {
+    if (!report) return;
+    const { extension, name } = EXPORT_FILES[format];
+    const destPath = await save({
+      title: "Export Comparison",
+      defaultPath: `${skillName}-comparison.${extension}`,
+      filters: [{ name, extensions: [extension] }],
+    });
+    if (!destPath) return;
+    try {
+      await exportBenchmarkComparison(report, format, destPath);
+      toast.success("Exported comparison");
+    } catch (err) {
+      toast.error(`Export failed: ${getErrorMessage(err)}`, { duration: Infinity });
+    }
+  }
+
+  return (
+    <div className="mt-4 rounded-md border bg-background/70 p-3" data-testid="benchmark-comparison">
+      <h3 className="text-xs font-semibold">Compare versions</h3>
+      <p className="text-xs text-muted-foreground">
+        Runs every performance scenario against both refs. Use a version, tag, commit
+        SHA, or {NO_SKILL_REF}.
+      </p>
+      <div className="mt-2 flex flex-wrap items-center gap-2">
+        <Input
+          aria-label="Baseline ref"
+          className="h-8 w-40"
+          value={baselineRef}
+          onChange={(event) => setBaselineRef(event.target.value)}
+        />
+        <span className="text-xs text-muted-foreground">vs</span>
+        <Input
+          aria-label="Candidate ref"
+          className="h-8 w-40"
+          value={candidateRef}
+          onChange={(event) => setCandidateRef(event.target.value)}
+        />
+        <Button
+          size="sm"
+          variant="outline"
+          disabled={compareMutation.isPending || !baselineRef.trim() || !candidateRef.trim()}
+          onClick={() => void handleCompare()}
+        >
+          {compareMutation.isPending ? "Comparing…" : "Compare"}
+        </Button>
+        {report ? (
+          <>
+            <Button size="sm" variant="ghost" onClick={() => void handleExport("markdown")}>
+              Export Markdown
+            </Button>
+            <Button size="sm" variant="ghost" onClick={() => void handleExport("json")}>
+              Export JSON
+            </Button>
+          </>
+        ) : null}
+      </div>
+      {error ? (
+        <p role="alert" className="mt-2 text-sm text-destructive">
+          {error}
+        </p>
+      ) : null}
+      {report ? <BenchmarkComparisonResults report={report} /> : null}
+    </div>
+  );
+}
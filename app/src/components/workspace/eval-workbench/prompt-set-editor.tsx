@@ -2,8 +2,14 @@ import { AlertTriangle, Loader2, Plus, Sparkles, Trash2 } from "lucide-react";
 import { Button } from "@/components/ui/button";
 import { Input } from "@/components/ui/input";
 import { Label } from "@/components/ui/label";
+import { Switch } from "@/components/ui/switch";
 import { Textarea } from "@/components/ui/textarea";
-import { type SaveScenario } from "@/lib/eval-workbench";
+import {
+  isTriggerScenario,
+  type Assertion,
+  type SaveScenario,
+} from "@/lib/eval-workbench";
+import { AssertionEditor } from "./assertion-editor";
 
 interface PromptSetEditorProps {
   draft: SaveScenario;
@@ -37,7 +43,9 @@ export function PromptSetEditor({
   showNew = true,
   footerStatus = null,
 }: PromptSetEditorProps) {
-  function updateAssertions(nextAssertions: string[]) {
+  const trigger = isTriggerScenario(draft);
+
+  function updateAssertions(nextAssertions: Assertion[]) {
     onChange({ ...draft, assertions: nextAssertions });
   }
 
@@ -47,7 +55,9 @@ export function PromptSetEditor({
         <div>
           <h2 className="text-sm font-semibold">Scenario</h2>
           <p className="text-xs text-muted-foreground">
-            Git-backed eval cases for this skill.
+            {trigger
+              ? "Checks whether this prompt makes the model pick the skill from its description."
+              : "Git-backed eval cases for this skill."}
           </p>
         </div>
         <div className="flex gap-2">
@@ -105,66 +115,81 @@ export function PromptSetEditor({
               />
             </div>
 
-            <div className="space-y-2">
-              <div className="flex items-center justify-between gap-2">
-                <Label>Assertions</Label>
-                <Button
-                  type="button"
-                  variant="outline"
-                  size="sm"
-                  onClick={() =>
-                    updateAssertions([...draft.assertions, ""])
+            {trigger ? (
+              <div className="flex items-center gap-2">
+                <Switch
+                  id="scenario-should-trigger"
+                  checked={draft.shouldTrigger ?? true}
+                  onCheckedChange={(checked) =>
+                    onChange({ ...draft, shouldTrigger: checked })
                   }
-                >
-                  <Plus className="mr-1 size-3.5" />
-                  Add assertion
-                </Button>
+                />
+                <Label htmlFor="scenario-should-trigger">
+                  Should trigger this skill
+                </Label>
               </div>
-              {draft.assertions.length > 0 ? (
-                <div className="space-y-2">
-                  {draft.assertions.map((assertion, assertionIndex) => (
-                    <div
-                      key={`${draft.id}-assertion-${assertionIndex}`}
-                      className="rounded border p-2"
-                    >
-                      <div className="mb-2 flex items-center justify-between gap-2">
-                        <span className="text-xs font-medium text-muted-foreground">
-                          Assertion {assertionIndex + 1}
-                        </span>
-                        <Button
-                          type="button"
-                          variant="ghost"
-                          size="sm"
-                          onClick={() =>
-                            updateAssertions(
-                              draft.assertions.filter(
-                                (_value, index) => index !== assertionIndex,
-                              ),
-                            )
-                          }
-                          aria-label={`Delete assertion ${assertionIndex + 1}`}
-                        >
-                          <Trash2 className="size-3.5" />
-                        </Button>
-                      </div>
-                      <Textarea
-                        value={assertion}
-                        onChange={(event) => {
-                          const next = [...draft.assertions];
-                          next[assertionIndex] = event.target.value;
-                          updateAssertions(next);
-                        }}
-                        placeholder="Describe the assertion the answer should satisfy."
-                      />
-                    </div>
-                  ))}
+            ) : (
+              <div className="space-y-2">
+                <div className="flex items-center justify-between gap-2">
+                  <Label>Assertions</Label>
+                  <Button
+                    type="button"
+                    variant="outline"
+                    size="sm"
+                    onClick={() =>
+                      updateAssertions([...draft.assertions, ""])
+                    }
+                  >
+                    <Plus className="mr-1 size-3.5" />
+                    Add assertion
+                  </Button>
                 </div>
-              ) : (
-                <p className="text-xs text-muted-foreground">
-                  No assertions yet. Add one or use Suggest.
-                </p>
-              )}
-            </div>
+                {draft.assertions.length > 0 ? (
+                  <div className="space-y-2">
+                    {draft.assertions.map((assertion, assertionIndex) => (
+                      <div
+                        key={`${draft.id}-assertion-${assertionIndex}`}
+                        className="rounded border p-2"
+                      >
+                        <div className="mb-2 flex items-center justify-between gap-2">
+                          <span className="text-xs font-medium text-muted-foreground">
+                            Assertion {assertionIndex + 1}
+                          </span>
+                          <Button
+                            type="button"
+                            variant="ghost"
+                            size="sm"
+                            onClick={() =>
+                              updateAssertions(
+                                draft.assertions.filter(
+                                  (_value, index) => index !== assertionIndex,
+                                ),
+                              )
+                            }
+                            aria-label={`Delete assertion ${assertionIndex + 1}`}
+                          >
+                            <Trash2 className="size-3.5" />
+                          </Button>
+                        </div>
+                        <AssertionEditor
+                          assertion={assertion}
+                          index={assertionIndex}
+                          onChange={(nextAssertion) => {
+                            const next = [...draft.assertions];
+                            next[assertionIndex] = nextAssertion;
+                            updateAssertions(next);
+                          }}
+                        />
+                      </div>
+                    ))}
+                  </div>
+                ) : (
+                  <p className="text-xs text-muted-foreground">
+                    No assertions yet. Add one or use Suggest.
+                  </p>
+                )}
+              </div>
+            )}
           </div>
         </div>
 
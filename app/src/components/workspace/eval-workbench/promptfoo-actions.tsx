@@ -0,0 +1,80 @@
+import { open, save } from "@tauri-apps/plugin-dialog";
+import { Button } from "@/components/ui/button";
+import { exportPromptfooConfig, getErrorMessage } from "@/lib/eval-workbench";
+import { useImportPromptfooConfig } from "@/lib/queries/eval-scenarios";
+import { toast } from "@/lib/toast";
+
+const PROMPTFOO_FILTERS = [{ name: "Promptfoo config", extensions: ["yaml", "yml", "json"] }];
+
+function plural(count: number, noun: string): string {
+  return `${count} ${noun}${count === 1 ? "" : "s"}`;
+}
+
+function reportOutcome(summary: string, warnings: string[]) {
+  if (warnings.length === 0) {
+    toast.success(summary);
+    return;
+  }
+  toast.warning(`${summary} (${plural(warnings.length, "warning")})`, {
+    description: warnings.join("\n"),
+    duration: Infinity,
+  });
+}
+
+export function PromptfooActions({
+  skillName,
+  pluginSlug,
+  canExport,
+}: {
+  skillName: string;
+  pluginSlug: string;
+  canExport: boolean;
+}) {
+  const importMutation = useImportPromptfooConfig(skillName, pluginSlug);
+
+  async function handleImport() {
+    const sourcePath = await open({
+      title: "Import Promptfoo Config",
+      multiple: false,
+      filters: PROMPTFOO_FILTERS,
+    });
+    if (!sourcePath || Array.isArray(sourcePath)) return;
+    try {
+      const result = await importMutation.mutateAsync(sourcePath);
+      reportOutcome(`Imported ${plural(result.scenarios.length, "scenario")}`, result.warnings);
+    } catch (err) {
+      toast.error(`Import failed: ${getErrorMessage(err)}`, { duration: Infinity });
+    }
+  }
+
+  async function handleExport() {
+    const destPath = await save({
+      title: "Export Promptfoo Config",
+      defaultPath: "promptfooconfig.yaml",
+      filters: PROMPTFOO_FILTERS,
+    });
+    if (!destPath) return;
+    try {
+      const result = await exportPromptfooConfig(pluginSlug, skillName, destPath);
+      reportOutcome(`Exported ${plural(result.scenarioCount, "scenario")}`, result.warnings);
+    } catch (err) {
+      toast.error(`Export failed: ${getErrorMessage(err)}`, { duration: Infinity });
+    }
+  }
+
+  return (
+    <>
+      <Button
+        size="sm"
+        variant="ghost"
+        disabled={importMutation.isPending}
+        onClick={() => void handleImport()}
+      >
+        Import promptfoo
+      </Button>
+      <Button size="sm" variant="ghost" disabled={!canExport} onClick={() => void handleExport()}>
+        Export promptfoo
+      </Button>
+    </>
+  );
+}
@@ -0,0 +1,108 @@
+import { useState } from "react";
+import { CheckCircle2, CircleHelp, XCircle } from "lucide-react";
+import { Button } from "@/components/ui/button";
+import {
+  assertionKind,
+  getErrorMessage,
+  type AssertionOutcome,
+  type BenchmarkRun,
+} from "@/lib/eval-workbench";
+import { useRunScenario } from "@/lib/queries/eval-scenarios";
+
+function OutcomeIcon({ passed }: { passed: boolean | null }) {
+  if (passed === null) {
+    return <CircleHelp className="mt-0.5 size-3.5 shrink-0 text-muted-foreground" aria-label="Undecided" />;
+  }
+  return passed ? (
+    <CheckCircle2 className="mt-0.5 size-3.5 shrink-0 text-green-600" aria-label="Passed" />
+  ) : (
+    <XCircle className="mt-0.5 size-3.5 shrink-0 text-destructive" aria-label="Failed" />
+  );
+}
+
+function describeAssertion({ assertion }: AssertionOutcome): string {
+  return typeof assertion === "string" ? assertion : assertionKind(assertion);
+}
+
+export function ScenarioRunResults({ run }: { run: BenchmarkRun }) {
+  if (run.error) {
+    return (
+      <p role="alert" className="mt-2 text-sm text-destructive">
+        {run.error}
+      </p>
+    );
+  }
+  const verdict = run.passed === null ? "Undecided" : run.passed ? "Passed" : "Failed";
+  return (
+    <div className="mt-3 space-y-2" data-testid="scenario-run-results">
+      <p className="text-xs">
+        <span className="font-medium">{verdict}</span> · {run.passedAssertions}/
+        {run.decidedAssertions} decided assertions pass
+        {run.numTurns !== null ? ` · ${run.numTurns} turns` : ""}
+        {run.totalCostUsd !== null ? ` · $${run.totalCostUsd.toFixed(4)}` : ""}
+      </p>
+      <ul className="space-y-1">
+        {run.outcomes.map((outcome, index) => (
+          <li key={index} className="flex items-start gap-2 text-xs">
+            <OutcomeIcon passed={outcome.passed} />
+            <div className="min-w-0">
+              <p className="font-medium">{describeAssertion(outcome)}</p>
+              <p className="text-muted-foreground">{outcome.reason}</p>
+            </div>
+          </li>
+        ))}
+      </ul>
+    </div>
+  );
+}
+
+export function ScenarioRun({
+  skillName,
+  pluginSlug,
+  scenarioName,
+}: {
+  skillName: string;
+  pluginSlug: string;
+  scenarioName: string;
+}) {
+  const [run, setRun] = useState<BenchmarkRun | null>(null);
+  const [error, setError] = useState<string | null>(null);
+  const runMutation = useRunScenario(skillName, pluginSlug);
+
+  async function handleRun() {
+    setError(null);
+    try {
+      setRun(await runMutation.mutateAsync(scenarioName));
+    } catch (err) {
+      setRun(null);
+      setError(getErrorMessage(err));
+    }
+  }
+
+  return (
+    <div className="mt-4 rounded-md border bg-background/70 p-3" data-testid="scenario-run">
+      <div className="flex items-center justify-between gap-3">
+        <div>
+          <h3 className="text-xs font-semibold">Run {scenarioName}</h3>
+          <p className="text-xs text-muted-foreground">
+            Answers the prompt with the skill's working copy and grades every assertion.
+          </p>
+        </div>
+        <Button
+          size="sm"
+          variant="outline"
+          disabled={runMutation.isPending}
+          onClick={() => void handleRun()}
+        >
+          {runMutation.isPending ? "Running…" : "Run scenario"}
+        </Button>
+      </div>
+      {error ? (
+        <p role="alert" className="mt-2 text-sm text-destructive">
+          {error}
+        </p>
+      ) : null}
+      {run ? <ScenarioRunResults run={run} /> : null}
+    </div>
+  );
+}
@@ -0,0 +1,44 @@
+import { CheckCircle2, XCircle } from "lucide-react";
+import type { TriggerEvalReport } from "@/lib/eval-workbench";
+
+function formatRate(value: number | null): string {
+  return value === null ? "n/a" : `${Math.round(value * 100)}%`;
+}
+
+export function TriggerEvalResults({ report }: { report: TriggerEvalReport }) {
+  return (
+    <div className="mt-4 rounded-md border bg-background/70 p-3" data-testid="trigger-eval-results">
+      <div className="flex flex-wrap items-baseline gap-x-6 gap-y-1">
+        <p className="text-sm">
+          <span className="font-medium">Precision</span> {formatRate(report.precision)}
+        </p>
+        <p className="text-sm">
+          <span className="font-medium">Recall</span> {formatRate(report.recall)}
+        </p>
+        <p className="text-xs text-muted-foreground">
+          {report.distractors.length > 0
+            ? `Against ${report.distractors.join(", ")}`
+            : "No other skills in this plugin to compete with"}
+        </p>
+      </div>
+      <ul className="mt-3 space-y-1">
+        {report.cases.map((item) => (
+          <li key={item.scenarioName} className="flex items-start gap-2 text-xs">
+            {item.passed ? (
+              <CheckCircle2 className="mt-0.5 size-3.5 shrink-0 text-green-600" aria-label="Passed" />
+            ) : (
+              <XCircle className="mt-0.5 size-3.5 shrink-0 text-destructive" aria-label="Failed" />
+            )}
+            <div className="min-w-0">
+              <p className="font-medium">{item.scenarioName}</p>
+              <p className="text-muted-foreground">
+                {item.shouldTrigger ? "Should trigger" : "Should not trigger"} · picked{" "}
+                {item.selectedSkill ?? "no skill"}
+              </p>
+            </div>
+          </li>
+        ))}
+      </ul>
+    </div>
+  );
+}
@@ -0,0 +1,77 @@
+import { AlertTriangle } from "lucide-react";
+import { Select, SelectContent, SelectItem, SelectTrigger, SelectValue } from "@/components/ui/select";
+import type { VersionPassRate, VersionRegression } from "@/lib/eval-workbench";
+
+function formatRate(value: number | null): string {
+  return value === null ? "n/a" : `${Math.round(value * 100)}%`;
+}
+
+export function VersionPassRates({
+  rates,
+  regression,
+  models,
+  model,
+  onModelChange,
+}: {
+  /** Rates for `model` only. */
+  rates: VersionPassRate[];
+  regression: VersionRegression | null;
+  models: string[];
+  model: string;
+  onModelChange: (model: string) => void;
+}) {
+  return (
+    <div className="mt-4 rounded-md border bg-background/70 p-3" data-testid="version-pass-rates">
+      <div className="flex items-center justify-between gap-2">
+        <h3 className="text-xs font-semibold">Pass rate by version</h3>
+        {models.length > 1 ? (
+          <Select value={model} onValueChange={onModelChange}>
+            <SelectTrigger aria-label="Model" className="h-7 w-auto gap-1.5 text-xs">
+              <SelectValue />
+            </SelectTrigger>
+            <SelectContent>
+              {models.map((option) => (
+                <SelectItem key={option} value={option} className="text-xs">
+                  {option}
+                </SelectItem>
+              ))}
+            </SelectContent>
+          </Select>
+        ) : (
+          <span className="text-xs text-muted-foreground">{model}</span>
+        )}
+      </div>
+      {regression ? (
+        <div
+          role="alert"
+          className="mt-2 flex items-start gap-2 rounded-md border border-destructive/30 bg-destructive/5 px-3 py-2 text-xs text-destructive"
+        >
+          <AlertTriangle className="mt-0.5 size-3.5 shrink-0" />
+          <span>
+            v{regression.currentVersion} passes {formatRate(regression.currentPassRate)} of
+            runs, down from {formatRate(regression.previousPassRate)} in v
+            {regression.previousVersion}
+            {regression.scenarios.length > 0
+              ? `. Worse on ${regression.scenarios.map((s) => s.scenarioName).join(", ")}.`
+              : "."}
+          </span>
+        </div>
+      ) : null}
+      <ul className="mt-2 space-y-1">
+        {[...rates].reverse().map((rate) => (
+          <li key={rate.skillVersion} className="flex flex-wrap items-baseline gap-x-3 text-xs">
+            <span className="font-medium">v{rate.skillVersion}</span>
+            <span>{formatRate(rate.passRate)}</span>
+            <span className="text-muted-foreground">
+              {rate.passedRuns}/{rate.gradedRuns} graded runs
+              {rate.avgCostUsd !== null ? ` · avg $${rate.avgCostUsd.toFixed(4)}` : ""}
+              {rate.avgDurationMs !== null
+                ? ` · avg ${(rate.avgDurationMs / 1000).toFixed(1)}s`
+                : ""}
+            </span>
+          </li>
+        ))}
+      </ul>
+    </div>
+  );
+}
@@ -0,0 +1,145 @@
+import { useState, useEffect } from "react";
+import {
+  AlertDialog,
+  AlertDialogCancel,
+  AlertDialogContent,
+  AlertDialogDescription,
+  AlertDialogFooter,
+  AlertDialogHeader,
+  AlertDialogTitle,
+} from "@/components/ui/alert-dialog";
+import { Button } from "@/components/ui/button";
+import { Label } from "@/components/ui/label";
+import {
+  Select,
+  SelectContent,
+  SelectItem,
+  SelectTrigger,
+  SelectValue,
+} from "@/components/ui/select";
+import { releaseSkillVersion, suggestSkillVersionBump } from "@/lib/tauri";
+import { useSkillStore } from "@/stores/skill-store";
+import { toast } from "@/lib/toast";
+import type { VersionBump, VersionBumpSuggestion } from "@/lib/types";
+
+interface NewVersionDialogProps {
+  skillName: string;
+  pluginSlug: string;
+  open: boolean;
+  onOpenChange: (open: boolean) => void;
+}
+
+const BUMP_LABELS: Record<VersionBump, string> = {
+  major: "Major",
+  minor: "Minor",
+  patch: "Patch",
+};
+
+/** Mirrors `git::bump_version` so the dialog can preview each choice. */
+export function bumpVersion(version: string, bump: VersionBump): string {
+  const parts = version.split(".").map((part) => Number.parseInt(part, 10));
+  const [major, minor, patch] =
+    parts.length === 3 && parts.every((part) => Number.isInteger(part) && part >= 0) ? parts : [0, 0, 0];
+  if (bump === "major") return `${major + 1}.0.0`;
+  if (bump === "minor") return `${major}.${minor + 1}.0`;
+  return `${major}.${minor}.${patch + 1}`;
+}
+
+export default function NewVersionDialog({ skillName, pluginSlug, open, onOpenChange }: NewVersionDialogProps) {
+  const [suggestion, setSuggestion] = useState<VersionBumpSuggestion | null>(null);
+  const [bump, setBump] = useState<VersionBump | null>(null);
+  const [releasing, setReleasing] = useState(false);
+
+  useEffect(() => {
+    if (!open) return;
+    setSuggestion(null);
+    setBump(null);
+    suggestSkillVersionBump(skillName, pluginSlug)
+      .then((result) => {
+        setSuggestion(result);
+        setBump(result.bump);
+      })
+      .catch((err) => {
+        console.error("event=version_bump_suggest_failed skill=%s error=%s", skillName, err);
+        toast.error("Failed to inspect changes since the last version", { duration: Infinity });
+      });
+  }, [open, skillName, pluginSlug]);
+
+  const nextVersion = suggestion && bump ? bumpVersion(suggestion.current_version, bump) : null;
+
+  const handleRelease = async () => {
+    if (!bump) return;
+    setReleasing(true);
+    try {
+      const release = await releaseSkillVersion(skillName, pluginSlug, bump);
+      useSkillStore.getState().setLatestVersion(release.version);
+      toast.success(`Tagged v${release.version}`);
+      onOpenChange(false);
+    } catch (err) {
+      console.error("event=release_version_failed skill=%s bump=%s error=%s", skillName, bump, err);
+      toast.error("Failed to tag new version", { duration: Infinity });
+    } finally {
+      setReleasing(false);
+    }
+  };
+
+  return (
+    <AlertDialog open={open} onOpenChange={onOpenChange}>
+      <AlertDialogContent>
+        <AlertDialogHeader>
+          <AlertDialogTitle>Tag New Version</AlertDialogTitle>
+          <AlertDialogDescription>
+            Tags the current state of <span className="font-medium">{skillName}</span> and adds its
+            commits to CHANGELOG.md.
+          </AlertDialogDescription>
+        </AlertDialogHeader>
+
+        {!suggestion ? (
+          <div className="flex items-center justify-center py-6 text-sm text-muted-foreground">
+            Inspecting changes…
+          </div>
+        ) : (
+          <div className="space-y-3">
+            <div className="space-y-1.5">
+              <Label htmlFor="version-bump">Version bump</Label>
+              <Select value={bump ?? undefined} onValueChange={(value) => setBump(value as VersionBump)}>
+                <SelectTrigger id="version-bump" className="w-64">
+                  <SelectValue />
+                </SelectTrigger>
+                <SelectContent>
+                  {(["major", "minor", "patch"] as const).map((option) => (
+                    <SelectItem key={option} value={option}>
+                      {BUMP_LABELS[option]} → v{bumpVersion(suggestion.current_version, option)}
+                      {option === suggestion.bump ? " (suggested)" : ""}
+                    </SelectItem>
+                  ))}
+                </SelectContent>
+              </Select>
+            </div>
+            <div className="space-y-1">
+              <p className="text-xs text-muted-foreground">
+                {suggestion.current_version === "0.0.0"
+                  ? "No tagged versions yet"
+                  : `Current version v${suggestion.current_version}`}
+                {" — suggested "}
+                {BUMP_LABELS[suggestion.bump].toLowerCase()} because:
+              </p>
+              <ul className="list-disc pl-5 text-xs text-muted-foreground">
+                {suggestion.reasons.map((reason) => (
+                  <li key={reason}>{reason}</li>
+                ))}
+              </ul>
+            </div>
+          </div>
+        )}
+
+        <AlertDialogFooter>
+          <AlertDialogCancel disabled={releasing}>Cancel</AlertDialogCancel>
+          <Button onClick={handleRelease} disabled={!nextVersion || releasing}>
+            {releasing ? "Tagging…" : nextVersion ? `Tag v${nextVersion}` : "Tag"}
+          </Button>
+        </AlertDialogFooter>
+      </AlertDialogContent>
+    </AlertDialog>
+  );
+}
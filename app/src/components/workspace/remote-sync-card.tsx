@@ -0,0 +1,186 @@
+import { useState, useEffect } from "react";
+import { Button } from "@/components/ui/button";
+import { Input } from "@/components/ui/input";
+import { Label } from "@/components/ui/label";
+import { Switch } from "@/components/ui/switch";
+import {
+  fetchSkillRemote,
+  getSkillGitRemote,
+  pullSkillRemote,
+  pushSkillRemote,
+  setSkillGitRemote,
+} from "@/lib/tauri";
+import { toast } from "@/lib/toast";
+import type { SkillGitRemote, SkillSyncResult } from "@/lib/types";
+
+type SyncOperation = "fetch" | "pull" | "push";
+
+interface RemoteSyncCardProps {
+  skillName: string;
+  pluginSlug: string;
+  /** Called after a pull fast-forwards the local repo. */
+  onPulled?: () => void;
+}
+
+const SYNC_COMMANDS: Record<SyncOperation, (skillName: string, pluginSlug: string) => Promise<SkillSyncResult>> = {
+  fetch: fetchSkillRemote,
+  pull: pullSkillRemote,
+  push: pushSkillRemote,
+};
+
+const SYNC_LABELS: Record<SyncOperation, { idle: string; running: string }> = {
+  fetch: { idle: "Fetch", running: "Fetching…" },
+  pull: { idle: "Pull", running: "Pulling…" },
+  push: { idle: "Push", running: "Pushing…" },
+};
+
+function describeSyncResult(result: SkillSyncResult): string {
+  switch (result.status) {
+    case "pushed":
+      return result.pushed_tags.length > 0
+        ? `Pushed ${result.branch} and ${result.pushed_tags.length} tag(s)`
+        : `Pushed ${result.branch}`;
+    case "fast_forwarded":
+      return `Pulled latest ${result.branch}`;
+    case "ahead":
+      return `${result.ahead} local commit(s) not pushed`;
+    case "behind":
+      return `${result.behind} remote commit(s) to pull`;
+    case "diverged":
+      return `Diverged: ${result.ahead} local, ${result.behind} remote commit(s)`;
+    default:
+      return "Up to date with remote";
+  }
+}
+
+export function RemoteSyncCard({ skillName, pluginSlug, onPulled }: RemoteSyncCardProps) {
+  const [remote, setRemote] = useState<SkillGitRemote | null>(null);
+  const [remoteUrl, setRemoteUrl] = useState("");
+  const [pluginWide, setPluginWide] = useState(false);
+  const [saving, setSaving] = useState(false);
+  const [running, setRunning] = useState<SyncOperation | null>(null);
+  const [lastResult, setLastResult] = useState<SkillSyncResult | null>(null);
+
+  const loadRemote = () =>
+    getSkillGitRemote(skillName, pluginSlug)
+      .then((result) => {
+        setRemote(result);
+        setPluginWide(!result.skill_url && !!result.plugin_url);
+        setRemoteUrl(result.skill_url ?? result.plugin_url ?? "");
+      })
+      .catch((err) => {
+        console.warn("event=skill_remote_fetch_failed skill=%s plugin=%s error=%s", skillName, pluginSlug, err);
+      });
+
+  useEffect(() => {
+    setLastResult(null);
+    void loadRemote();
+    // eslint-disable-next-line react-hooks/exhaustive-deps
+  }, [skillName, pluginSlug]);
+
+  const handleSave = async () => {
+    setSaving(true);
+    try {
+      // Switching scope clears the other entry so the saved URL is the one used.
+      if (pluginWide) {
+        if (remote?.skill_url) await setSkillGitRemote(pluginSlug, skillName, "");
+        await setSkillGitRemote(pluginSlug, null, remoteUrl);
+      } else {
+        await setSkillGitRemote(pluginSlug, skillName, remoteUrl);
+      }
+      toast.success(remoteUrl.trim() ? "Remote saved" : "Remote cleared");
+      await loadRemote();
+    } catch (err) {
+      console.error("event=skill_remote_save_failed skill=%s error=%s", skillName, err);
+      toast.error(`Failed to save remote: ${String(err)}`, { duration: Infinity });
+    } finally {
+      setSaving(false);
+    }
+  };
+
+  const handleSync = async (operation: SyncOperation) => {
+    setRunning(operation);
+    try {
+      const result = await SYNC_COMMANDS[operation](skillName, pluginSlug);
+      setLastResult(result);
+      if (result.conflicts.length > 0) {
+        toast.warning(describeSyncResult(result));
+      } else {
+        toast.success(describeSyncResult(result));
+      }
+      if (result.status === "fast_forwarded") onPulled?.();
+    } catch (err) {
+      console.error("event=skill_remote_%s_failed skill=%s error=%s", operation, skillName, err);
+      toast.error(`Failed to ${operation}: ${String(err)}`, { duration: Infinity });
+    } finally {
+      setRunning(null);
+    }
+  };
+
+  const busy = saving || running !== null;
+  const resolvedUrl = remote?.resolved_url ?? null;
+
+  return (
+    <div className="rounded-lg border bg-card p-4">
+      <h3 className="text-sm font-semibold mb-3">Remote</h3>
+      <div className="space-y-3">
+        <div className="space-y-1.5">
+          <Label htmlFor="skill-remote-url">Remote URL</Label>
+          <div className="flex gap-2">
+            <Input
+              id="skill-remote-url"
+              value={remoteUrl}
+              placeholder={pluginWide ? "git@github.com:team/{skill}.git" : "git@github.com:team/my-skill.git"}
+              onChange={(e) => setRemoteUrl(e.target.value)}
+              disabled={busy}
+            />
+            <Button variant="outline" size="sm" onClick={handleSave} disabled={busy}>
+              {saving ? "Saving…" : "Save"}
+            </Button>
+          </div>
+          <div className="flex items-center gap-2">
+            <Switch
+              id="skill-remote-plugin-wide"
+              checked={pluginWide}
+              onCheckedChange={setPluginWide}
+              disabled={busy}
+            />
+            <Label htmlFor="skill-remote-plugin-wide" className="text-xs font-normal text-muted-foreground">
+              Use for every skill in this plugin ({"{skill}"} is replaced by the skill name)
+            </Label>
+          </div>
+          {resolvedUrl && (
+            <p className="text-xs text-muted-foreground break-all">Syncing with {resolvedUrl}</p>
+          )}
+        </div>
+
+        <div className="flex gap-2">
+          {(["fetch", "pull", "push"] as const).map((operation) => (
+            <Button
+              key={operation}
+              variant="outline"
+              size="sm"
+              disabled={!resolvedUrl || busy}
+              onClick={() => handleSync(operation)}
+            >
+              {running === operation ? SYNC_LABELS[operation].running : SYNC_LABELS[operation].idle}
+            </Button>
+          ))}
+        </div>
+
+        {lastResult && (
+          <div className="space-y-1">
+            <p className="text-sm" data-testid="remote-sync-status">
+              {describeSyncResult(lastResult)}
+            </p>
+            {lastResult.conflicts.map((conflict) => (
+              <p key={conflict} className="text-xs text-amber-700 dark:text-amber-400">
+                {conflict}
+              </p>
+            ))}
+          </div>
+        )}
+      </div>
+    </div>
+  );
+}
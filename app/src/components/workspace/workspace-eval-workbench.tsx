@@ -1,7 +1,8 @@
 import { useEffect, useState } from "react";
+import { Badge } from "@/components/ui/badge";
 import { Button } from "@/components/ui/button";
-import type { ScenarioDto } from "@/lib/eval-workbench";
-import { getErrorMessage } from "@/lib/eval-workbench";
+import type { ScenarioDto, ScenarioTag, TriggerEvalReport } from "@/lib/eval-workbench";
+import { getErrorMessage, isTriggerScenario } from "@/lib/eval-workbench";
 import {
   useCreateScenario,
   useDeleteScenario,
@@ -9,8 +10,16 @@ import {
   useScenario,
   useScenarios,
   useDefineEvalScenario,
+  useRunTriggerEval,
+  useScenarioPassRates,
+  useScenarioRegression,
 } from "@/lib/queries/eval-scenarios";
 import type { ImportedSkill, SkillSummary } from "@/lib/types";
+import { BenchmarkComparison } from "./eval-workbench/benchmark-comparison";
+import { PromptfooActions } from "./eval-workbench/promptfoo-actions";
+import { ScenarioRun } from "./eval-workbench/scenario-run";
+import { TriggerEvalResults } from "./eval-workbench/trigger-eval-results";
+import { VersionPassRates } from "./eval-workbench/version-pass-rates";
 import { WorkspaceEvals } from "./workspace-evals";
 
 interface WorkspaceEvalWorkbenchProps {
@@ -41,7 +50,26 @@ export function WorkspaceEvalWorkbench({
   const saveScenarioMutation = useSaveScenario(skillName, pluginSlug);
   const defineEvalScenarioMutation = useDefineEvalScenario(skillName, pluginSlug);
   const deleteScenarioMutation = useDeleteScenario(skillName, pluginSlug);
+  const runTriggerEvalMutation = useRunTriggerEval(skillName, pluginSlug);
+  const passRatesQuery = useScenarioPassRates(skillName, pluginSlug);
+  const passRates = passRatesQuery.data ?? [];
+  // Pass rates are only comparable within one model; default to the model
+  // used most recently.
+  const passRateModels = [...passRates]
+    .sort((a, b) => b.lastRunAt.localeCompare(a.lastRunAt))
+    .map((rate) => rate.model)
+    .filter((model, index, models) => models.indexOf(model) === index);
+  const [selectedPassRateModel, setSelectedPassRateModel] = useState<string | null>(null);
+  const passRateModel =
+    selectedPassRateModel && passRateModels.includes(selectedPassRateModel)
+      ? selectedPassRateModel
+      : (passRateModels[0] ?? null);
+  const regressionQuery = useScenarioRegression(skillName, pluginSlug, passRateModel);
+  const [triggerReport, setTriggerReport] = useState<TriggerEvalReport | null>(null);
+  const [triggerError, setTriggerError] = useState<string | null>(null);
   const scenarios = scenariosQuery.data ?? [];
+  const hasTriggerScenarios = scenarios.some(isTriggerScenario);
+  const hasPerformanceScenarios = scenarios.some((scenario) => !isTriggerScenario(scenario));
   const selectedScenarioQuery = useScenario(
     skillName,
     pluginSlug,
@@ -77,8 +105,8 @@ export function WorkspaceEvalWorkbench({
     return savedScenario;
   }
 
-  async function handleCreateScenario() {
-    const createdScenario = await createScenarioMutation.mutateAsync();
+  async function handleCreateScenario(mode?: ScenarioTag) {
+    const createdScenario = await createScenarioMutation.mutateAsync(mode);
     setSelectedScenarioName(createdScenario.name);
     return createdScenario;
   }
@@ -94,6 +122,16 @@ export function WorkspaceEvalWorkbench({
     setSelectedScenarioName(null);
   }
 
+  async function handleRunTriggerEval() {
+    setTriggerError(null);
+    try {
+      setTriggerReport(await runTriggerEvalMutation.mutateAsync());
+    } catch (err) {
+      setTriggerReport(null);
+      setTriggerError(getErrorMessage(err));
+    }
+  }
+
   function handleStartNewScenario() {
     setSelectedScenarioName(null);
   }
@@ -104,17 +142,33 @@ export function WorkspaceEvalWorkbench({
         <div>
           <h2 className="text-sm font-semibold">Scenarios</h2>
           <p className="text-xs text-muted-foreground">
-            Create and edit performance evaluation scenarios for this skill.
+            Performance scenarios check what the skill does once loaded. Trigger
+            scenarios check whether its description gets it picked.
           </p>
         </div>
-        <Button
-          size="sm"
-          variant="outline"
-          disabled={createScenarioMutation.isPending}
-          onClick={() => void handleCreateScenario()}
-        >
-          New scenario
-        </Button>
+        <div className="flex shrink-0 gap-2">
+          <Button
+            size="sm"
+            variant="outline"
+            disabled={createScenarioMutation.isPending}
+            onClick={() => void handleCreateScenario()}
+          >
+            New scenario
+          </Button>
+          <Button
+            size="sm"
+            variant="outline"
+            disabled={createScenarioMutation.isPending}
+            onClick={() => void handleCreateScenario("trigger")}
+          >
+            New trigger scenario
+          </Button>
+          <PromptfooActions
+            skillName={skillName}
+            pluginSlug={pluginSlug}
+            canExport={hasPerformanceScenarios}
+          />
+        </div>
       </div>
 
       {scenariosQuery.isLoading ? (
@@ -158,8 +212,11 @@ export function WorkspaceEvalWorkbench({
                   );
                 }}
               >
-                <div className="min-w-0">
+                <div className="flex min-w-0 items-center gap-2">
                   <p className="truncate text-sm font-medium">{scenario.name}</p>
+                  {isTriggerScenario(scenario) ? (
+                    <Badge variant="outline">Trigger</Badge>
+                  ) : null}
                 </div>
               </Button>
             ))}
@@ -191,6 +248,48 @@ export function WorkspaceEvalWorkbench({
           </div>
         ) : null
       ) : null}
+
+      {selectedScenario && !isTriggerScenario(selectedScenario) ? (
+        <ScenarioRun
+          key={selectedScenario.name}
+          skillName={skillName}
+          pluginSlug={pluginSlug}
+          scenarioName={selectedScenario.name}
+        />
+      ) : null}
+
+      {hasTriggerScenarios ? (
+        <div className="mt-4">
+          <Button
+            size="sm"
+            variant="outline"
+            disabled={runTriggerEvalMutation.isPending}
+            onClick={() => void handleRunTriggerEval()}
+          >
+            {runTriggerEvalMutation.isPending ? "Running trigger eval…" : "Run trigger eval"}
+          </Button>
+          {triggerError ? (
+            <p role="alert" className="mt-2 text-sm text-destructive">
+              {triggerError}
+            </p>
+          ) : null}
+          {triggerReport ? <TriggerEvalResults report={triggerReport} /> : null}
+        </div>
+      ) : null}
+
+      {passRateModel ? (
+        <VersionPassRates
+          rates={passRates.filter((rate) => rate.model === passRateModel)}
+          regression={regressionQuery.data ?? null}
+          models={passRateModels}
+          model={passRateModel}
+          onModelChange={setSelectedPassRateModel}
+        />
+      ) : null}
+
+      {hasPerformanceScenarios ? (
+        <BenchmarkComparison skillName={skillName} pluginSlug={pluginSlug} />
+      ) : null}
     </section>
   );
 
@@ -205,7 +304,7 @@ export function WorkspaceEvalWorkbench({
           hasScenarios={scenarios.length > 0}
           scenarioLoading={selectedScenarioQuery.isLoading}
           onStartNewScenario={handleStartNewScenario}
-          onCreateScenario={handleCreateScenario}
+          onCreateScenario={() => handleCreateScenario()}
           onSaveScenario={handleSaveScenario}
           onDefineEvalScenario={handleDefineEvalScenario}
           onDeleteScenario={handleDeleteScenario}
@@ -4,6 +4,7 @@ import type { SaveScenario, ScenarioDto } from "@/lib/eval-workbench";
 import {
   createDraftScenario,
   getErrorMessage,
+  isTriggerScenario,
   normalizeScenario,
   scenarioToDraft,
 } from "@/lib/eval-workbench";
@@ -174,7 +175,7 @@ export function WorkspaceEvals({
               suggestDisabled={defineEvalScenarioPending}
               deleteDisabled={deleteScenarioPending}
               showDelete={true}
-              showSuggest={Boolean(onDefineEvalScenario)}
+              showSuggest={Boolean(onDefineEvalScenario) && !isTriggerScenario(draft)}
               suggestBusy={suggestingScenario || defineEvalScenarioPending}
               showNew={Boolean(onCreateScenario)}
               footerStatus={
@@ -3,6 +3,9 @@ import { Button } from "@/components/ui/button";
 import { Badge } from "@/components/ui/badge";
 import { Skeleton } from "@/components/ui/skeleton";
 import SkillDialog from "@/components/skill-dialog";
+import NewVersionDialog from "@/components/workspace/new-version-dialog";
+import { RemoteSyncCard } from "@/components/workspace/remote-sync-card";
+import { DraftBranchCard } from "@/components/workspace/draft-branch-card";
 import { useSettingsStore } from "@/stores/settings-store";
 import { getSkillHistory } from "@/lib/tauri";
 import { useSkillStore } from "@/stores/skill-store";
@@ -48,8 +51,10 @@ function formatCommitMessage(message: string): string {
 
 export function WorkspaceOverview({ skill, skillType, isLoading }: WorkspaceOverviewProps) {
   const [editDialogOpen, setEditDialogOpen] = useState(false);
+  const [newVersionOpen, setNewVersionOpen] = useState(false);
   const [commits, setCommits] = useState<SkillCommit[]>([]);
   const [showAllCommits, setShowAllCommits] = useState(false);
+  const [historyRevision, setHistoryRevision] = useState(0);
   const workspacePath = useSettingsStore((s) => s.workspacePath);
   const latestVersion = useSkillStore((s) => s.latestVersion);
   const invalidateSkillQueries = useInvalidateSkillQueries();
@@ -67,7 +72,7 @@ export function WorkspaceOverview({ skill, skillType, isLoading }: WorkspaceOver
       .catch((err) => {
         console.warn("event=skill_history_fetch_failed skill=%s plugin=%s error=%s", skillName, pluginSlug, err);
       });
-  }, [workspacePath, skillName, pluginSlug, latestVersion]);
+  }, [workspacePath, skillName, pluginSlug, latestVersion, historyRevision]);
 
   const purpose = skill.purpose;
   const description = isBuilderSkill ? skill.description : skill.description;
@@ -183,7 +188,14 @@ export function WorkspaceOverview({ skill, skillType, isLoading }: WorkspaceOver
 
       {/* Version History card */}
       <div className="rounded-lg border bg-card p-4">
-        <h3 className="text-sm font-semibold mb-3">Version History</h3>
+        <div className="flex items-center justify-between mb-3">
+          <h3 className="text-sm font-semibold">Version History</h3>
+          {canEdit && pluginSlug && (
+            <Button variant="outline" size="sm" onClick={() => setNewVersionOpen(true)}>
+              New Version
+            </Button>
+          )}
+        </div>
         {commits.length === 0 ? (
           <p className="text-sm text-muted-foreground">No version history yet</p>
         ) : (
@@ -221,6 +233,37 @@ export function WorkspaceOverview({ skill, skillType, isLoading }: WorkspaceOver
         )}
       </div>
 
+      {canEdit && pluginSlug && (
+        <DraftBranchCard
+          skillName={skillName}
+          pluginSlug={pluginSlug}
+          onChanged={() => {
+            setHistoryRevision((revision) => revision + 1);
+            invalidateSkillQueries().catch(() => {});
+          }}
+        />
+      )}
+
+      {canEdit && pluginSlug && (
+        <RemoteSyncCard
+          skillName={skillName}
+          pluginSlug={pluginSlug}
+          onPulled={() => {
+            setHistoryRevision((revision) => revision + 1);
+            invalidateSkillQueries().catch(() => {});
+          }}
+        />
+      )}
+
+      {canEdit && pluginSlug && (
+        <NewVersionDialog
+          skillName={skillName}
+          pluginSlug={pluginSlug}
+          open={newVersionOpen}
+          onOpenChange={setNewVersionOpen}
+        />
+      )}
+
       {canEdit && editDialogOpen && (
         <SkillDialog
           mode="edit"
@@ -43,14 +43,20 @@ export function settingsToStorePatch(s: AppSettings) {
     logLevel: s.log_level,
     refinePromptSuggestions: s.refine_prompt_suggestions ?? true,
     maxDimensions: s.max_dimensions ?? 5,
+    documentTokenBudget: s.document_token_budget ?? 8000,
     industry: s.industry,
     functionRole: s.function_role,
     autoUpdate: s.auto_update ?? false,
+    marketplaceOffline: s.marketplace_offline ?? false,
     githubOauthToken: s.github_oauth_token,
     githubUserLogin: s.github_user_login,
     githubUserAvatar: s.github_user_avatar,
     githubUserEmail: s.github_user_email,
+    githubHost: s.github_host ?? null,
+    githubOauthClientId: s.github_oauth_client_id ?? null,
+    feedbackRepo: s.feedback_repo ?? null,
     marketplaceRegistries: s.marketplace_registries ?? [],
+    sourceHostTokens: s.source_host_tokens ?? [],
     dashboardViewMode: s.dashboard_view_mode,
   };
 }
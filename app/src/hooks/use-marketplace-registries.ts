@@ -2,7 +2,7 @@ import { useState, useCallback } from "react"
 import { toast } from "@/lib/toast"
 import type { MarketplaceRegistry } from "@/lib/types"
 import { useSettingsStore } from "@/stores/settings-store"
-import { checkMarketplaceUrl, parseGitHubUrl } from "@/lib/tauri"
+import { checkMarketplaceUrl, parseSkillSource } from "@/lib/tauri"
 
 /** Must match DEFAULT_MARKETPLACE_URL in app/src-tauri/src/commands/settings.rs */
 export const DEFAULT_MARKETPLACE_URL = "hbanerjee74/skills"
@@ -51,25 +51,22 @@ export function useMarketplaceRegistries(
 
     setNewRegistryAdding(true)
 
-    let info: Awaited<ReturnType<typeof parseGitHubUrl>>
+    let info: Awaited<ReturnType<typeof parseSkillSource>>
     try {
-      info = await parseGitHubUrl(url)
+      info = await parseSkillSource(url)
     } catch {
-      toast.error("Invalid GitHub repository format — use owner/repo or owner/repo#branch.", { duration: Infinity })
+      toast.error("Invalid marketplace source — use owner/repo, a git URL or a local folder path.", { duration: Infinity })
       setNewRegistryAdding(false)
       return
     }
-    const canonicalUrl = info.branch === "main"
-      ? `${info.owner}/${info.repo}`
-      : `${info.owner}/${info.repo}#${info.branch}`
+    const canonicalUrl = info.canonical_url
 
+    // GitHub registries are the same repo regardless of branch; other sources compare as entered.
+    const repoKey = (sourceUrl: string) => (info.kind === "github" ? sourceUrl.split("#")[0] : sourceUrl)
     const currentRegistries = useSettingsStore.getState().marketplaceRegistries
-    const isDuplicate = currentRegistries.some(r => {
-      const m = r.source_url.match(/^([^/]+)\/([^/#]+)/)
-      return m && m[1] === info.owner && m[2] === info.repo
-    })
+    const isDuplicate = currentRegistries.some(r => repoKey(r.source_url) === repoKey(canonicalUrl))
     if (isDuplicate) {
-      toast.error(`${info.owner}/${info.repo} is already in your registries.`, { duration: Infinity })
+      toast.error(`${info.label} is already in your registries.`, { duration: Infinity })
       setNewRegistryAdding(false)
       return
     }
@@ -80,7 +77,7 @@ export function useMarketplaceRegistries(
     } catch (err) {
       console.error(`[settings] add registry check failed for ${url}:`, err)
       setNewRegistryAdding(false)
-      toast.error("Could not reach marketplace.json — check it is a public GitHub repository with a .claude-plugin/marketplace.json file.", { duration: Infinity })
+      toast.error("Could not reach marketplace.json — check the source is reachable and has a .claude-plugin/marketplace.json file.", { duration: Infinity })
       return
     }
     console.log(`[settings] registry added: name=${name}, url=${canonicalUrl}`)
@@ -1,18 +1,55 @@
 import { useRouter } from "@tanstack/react-router";
 import { toast } from "@/lib/toast";
 import { useSettingsStore } from "@/stores/settings-store";
-import { checkMarketplaceUpdates, importMarketplaceToLibrary, checkSkillCustomized, listPlugins } from "@/lib/tauri";
+import {
+  checkMarketplaceUpdates,
+  importMarketplaceToLibrary,
+  checkSkillCustomized,
+  listPlugins,
+  previewImportedSkillUpdate,
+  applyImportedSkillUpdate,
+} from "@/lib/tauri";
 import type { AppSettings, SkillUpdateInfo } from "@/lib/types";
+import type { PendingSkillMerge } from "@/stores/settings-store";
 
-/** Filter out customized skills, returning only those safe to auto-update. */
-export async function filterNonCustomized(skills: SkillUpdateInfo[]): Promise<SkillUpdateInfo[]> {
-  const results = await Promise.all(
-    skills.map(async (skill) => {
-      const customized = await checkSkillCustomized(skill.name).catch(() => false);
-      return customized ? null : skill;
-    })
+/** Split skills into those safe to overwrite and those with local edits. */
+export async function partitionCustomized(
+  skills: SkillUpdateInfo[],
+): Promise<{ pristine: SkillUpdateInfo[]; customized: SkillUpdateInfo[] }> {
+  const flags = await Promise.all(
+    skills.map((skill) => checkSkillCustomized(skill.name).catch(() => false)),
   );
-  return results.filter((s): s is SkillUpdateInfo => s !== null);
+  return {
+    pristine: skills.filter((_, i) => !flags[i]),
+    customized: skills.filter((_, i) => flags[i]),
+  };
+}
+
+/**
+ * Three-way merge upstream updates into customized skills. Clean merges are
+ * committed and tagged right away; merges with conflicts are returned for review.
+ */
+export async function mergeCustomizedUpdates(
+  skills: SkillUpdateInfo[],
+): Promise<{ merged: string[]; conflicted: PendingSkillMerge[] }> {
+  const merged: string[] = [];
+  const conflicted: PendingSkillMerge[] = [];
+  for (const skill of skills) {
+    const sourceUrl = skill.source_url?.trim();
+    if (!sourceUrl) continue;
+    try {
+      const preview = await previewImportedSkillUpdate({ ...skill, source_url: sourceUrl });
+      if (preview.conflicts.length > 0) {
+        conflicted.push({ preview, sourceUrl });
+        continue;
+      }
+      await applyImportedSkillUpdate(preview, sourceUrl, {});
+      merged.push(skill.name);
+    } catch (err) {
+      console.warn(`[app-layout] Merging update for ${skill.name} failed:`, err);
+    }
+  }
+  return { merged, conflicted };
 }
 
 /**
@@ -79,7 +116,9 @@ export async function handleAutoUpdate(
     return grouped;
   };
 
-  const libFiltered = await filterNonCustomized(await filterUpgradeLockedPlugins(library));
+  const { pristine: libFiltered, customized } = await partitionCustomized(
+    await filterUpgradeLockedPlugins(library),
+  );
   if (cancelledRef.current) return;
 
   const libBySource = groupBySource(libFiltered);
@@ -93,15 +132,33 @@ export async function handleAutoUpdate(
   );
   if (cancelledRef.current) return;
 
-  if (libFiltered.length > 0) {
+  const { merged, conflicted } = await mergeCustomizedUpdates(customized);
+  if (cancelledRef.current) return;
+
+  const updated = [...libFiltered.map((s) => s.name), ...merged];
+  if (updated.length > 0) {
     toast.success(
       <div className="space-y-1">
-        <p className="font-medium">Auto-updated {libFiltered.length} skill{libFiltered.length !== 1 ? "s" : ""}</p>
-        <p>• Dashboard: {libFiltered.map((s) => s.name).join(", ")}</p>
+        <p className="font-medium">Auto-updated {updated.length} skill{updated.length !== 1 ? "s" : ""}</p>
+        <p>• Dashboard: {updated.join(", ")}</p>
+        {merged.length > 0 && <p>• Merged with local edits: {merged.join(", ")}</p>}
       </div>,
       { duration: Infinity }
     );
   }
+  if (conflicted.length > 0) {
+    const names = conflicted.map((m) => m.preview.skill_name);
+    toast.info(
+      `Update conflicts with local edits in ${names.join(", ")}`,
+      {
+        duration: Infinity,
+        action: {
+          label: "Review",
+          onClick: () => useSettingsStore.getState().setPendingSkillMerges(conflicted),
+        },
+      }
+    );
+  }
 }
 
 /** Show persistent notification toasts for available skill updates. */
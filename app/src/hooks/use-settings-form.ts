@@ -1,6 +1,6 @@
 import { useState, useCallback, useRef } from "react"
 import { toast } from "@/lib/toast"
-import type { AppSettings, MarketplaceRegistry, ModelSettings } from "@/lib/types"
+import type { AppSettings, MarketplaceRegistry, ModelSettings, SourceHostToken } from "@/lib/types"
 import { useSettingsStore } from "@/stores/settings-store"
 import { updateUserSettings } from "@/lib/tauri"
 
@@ -17,9 +17,14 @@ export interface SettingsFormFields {
   logLevel: string
   refinePromptSuggestions: boolean
   maxDimensions: number
+  documentTokenBudget: number
   industry: string | null
   functionRole: string | null
   autoUpdate: boolean
+  marketplaceOffline: boolean
+  githubHost: string | null
+  githubOauthClientId: string | null
+  feedbackRepo: string | null
 }
 
 export type ModelSettingsPatch = Partial<ModelSettings>
@@ -27,6 +32,7 @@ export type ModelSettingsPatch = Partial<ModelSettings>
 export type AutoSaveOverrides = Partial<
   Omit<SettingsFormFields, "modelSettings"> & {
     marketplaceRegistries: MarketplaceRegistry[]
+    sourceHostTokens: SourceHostToken[]
   }
 >
 
@@ -50,6 +56,9 @@ function formatSavedOverride(key: string, value: unknown): string {
       )
       .join(", ")
   }
+  if (key === "sourceHostTokens" && Array.isArray(value)) {
+    return `${key}=[${(value as SourceHostToken[]).map((t) => t.host).join(", ")}]`
+  }
   if (SECRET_OVERRIDE_KEYS.has(key)) {
     return `${key}=${value ? "[redacted]" : "null"}`
   }
@@ -67,9 +76,14 @@ export function useSettingsForm() {
   const [logLevel, setLogLevel] = useState(store.logLevel ?? "info")
   const [refinePromptSuggestions, setRefinePromptSuggestions] = useState(store.refinePromptSuggestions ?? true)
   const [maxDimensions, setMaxDimensions] = useState(store.maxDimensions ?? 5)
+  const [documentTokenBudget, setDocumentTokenBudget] = useState(store.documentTokenBudget ?? 8000)
   const [industry, setIndustry] = useState(store.industry ?? "")
   const [functionRole, setFunctionRole] = useState(store.functionRole ?? "")
   const [autoUpdate, setAutoUpdate] = useState(store.autoUpdate ?? false)
+  const [marketplaceOffline, setMarketplaceOffline] = useState(store.marketplaceOffline ?? false)
+  const [githubHost, setGithubHost] = useState(store.githubHost ?? "")
+  const [githubOauthClientId, setGithubOauthClientId] = useState(store.githubOauthClientId ?? "")
+  const [feedbackRepo, setFeedbackRepo] = useState(store.feedbackRepo ?? "")
   const [saved, setSaved] = useState(false)
 
   const workspacePath = store.workspacePath ?? null
@@ -92,16 +106,22 @@ export function useSettingsForm() {
       log_level: resolve("logLevel", logLevel),
       refine_prompt_suggestions: resolve("refinePromptSuggestions", refinePromptSuggestions),
       max_dimensions: resolve("maxDimensions", maxDimensions),
+      document_token_budget: resolve("documentTokenBudget", documentTokenBudget),
       splash_shown: false,
       github_oauth_token: storeSnapshot.githubOauthToken ?? null,
       github_user_login: storeSnapshot.githubUserLogin ?? null,
       github_user_avatar: storeSnapshot.githubUserAvatar ?? null,
       github_user_email: storeSnapshot.githubUserEmail ?? null,
+      github_host: resolve("githubHost", githubHost) || null,
+      github_oauth_client_id: resolve("githubOauthClientId", githubOauthClientId) || null,
+      feedback_repo: resolve("feedbackRepo", feedbackRepo) || null,
       marketplace_registries: resolve("marketplaceRegistries", storeSnapshot.marketplaceRegistries ?? []),
+      source_host_tokens: resolve("sourceHostTokens", storeSnapshot.sourceHostTokens ?? []),
       industry: resolve("industry", industry) || null,
       function_role: resolve("functionRole", functionRole) || null,
       dashboard_view_mode: storeSnapshot.dashboardViewMode ?? null,
       auto_update: resolve("autoUpdate", autoUpdate),
+      marketplace_offline: resolve("marketplaceOffline", marketplaceOffline),
     }
 
     try {
@@ -114,10 +134,16 @@ export function useSettingsForm() {
         logLevel: settings.log_level,
         refinePromptSuggestions: settings.refine_prompt_suggestions,
         maxDimensions: settings.max_dimensions,
+        documentTokenBudget: settings.document_token_budget,
         marketplaceRegistries: settings.marketplace_registries,
+        sourceHostTokens: settings.source_host_tokens ?? [],
         industry: settings.industry,
         functionRole: settings.function_role,
         autoUpdate: settings.auto_update,
+        marketplaceOffline: settings.marketplace_offline ?? false,
+        githubHost: settings.github_host ?? null,
+        githubOauthClientId: settings.github_oauth_client_id ?? null,
+        feedbackRepo: settings.feedback_repo ?? null,
       })
       const changedEntries: Array<[string, unknown]> = [
         ...Object.entries(overrides),
@@ -140,7 +166,7 @@ export function useSettingsForm() {
         context: { operation: "settings_auto_save" },
       })
     }
-  }, [skillsPath, logLevel, refinePromptSuggestions, maxDimensions, industry, functionRole, autoUpdate, workspacePath, setStoreSettings])
+  }, [skillsPath, logLevel, refinePromptSuggestions, maxDimensions, documentTokenBudget, industry, functionRole, autoUpdate, marketplaceOffline, githubHost, githubOauthClientId, feedbackRepo, workspacePath, setStoreSettings])
 
   const autoSave = useCallback(async (overrides: AutoSaveOverrides) => {
     await persistSettings(overrides, modelSettings)
@@ -167,12 +193,22 @@ export function useSettingsForm() {
     setRefinePromptSuggestions,
     maxDimensions,
     setMaxDimensions,
+    documentTokenBudget,
+    setDocumentTokenBudget,
     industry,
     setIndustry,
     functionRole,
     setFunctionRole,
     autoUpdate,
     setAutoUpdate,
+    marketplaceOffline,
+    setMarketplaceOffline,
+    githubHost,
+    setGithubHost,
+    githubOauthClientId,
+    setGithubOauthClientId,
+    feedbackRepo,
+    setFeedbackRepo,
     autoSave,
     saved,
   }
@@ -1,13 +1,88 @@
 import { invokeCommand } from "@/lib/tauri";
 
-export type ScenarioTag = "performance";
+export type ScenarioTag = "performance" | "trigger";
+
+/** Deterministic assertion checked in Rust against a run's output. */
+export type TypedAssertion =
+  | { type: "contains" | "not-contains" | "regex"; value: string }
+  | { type: "json-schema"; value: unknown }
+  | { type: "file-exists"; path: string }
+  | { type: "file-contains"; path: string; value: string }
+  | { type: "max-turns" | "max-cost"; value: number };
+
+/** A bare string is an LLM-graded rubric. */
+export type Assertion = string | TypedAssertion;
+
+export type AssertionKind = "llm-rubric" | TypedAssertion["type"];
+
+export interface AssertionOutcome {
+  assertion: Assertion;
+  /** `null` when the LLM grader or an unreported usage figure must decide. */
+  passed: boolean | null;
+  reason: string;
+}
+
+export interface ScenarioRun {
+  id: string;
+  pluginSlug: string;
+  skillName: string;
+  scenarioName: string;
+  /** Released version measured; `null` for unreleased edits. */
+  skillVersion: string | null;
+  skillCommit: string | null;
+  /** The run measured uncommitted edits on top of `skillCommit`. */
+  workingCopy: boolean;
+  model: string;
+  /** `null` while a rubric or usage limit is undecided. */
+  passed: boolean | null;
+  totalCostUsd: number | null;
+  durationMs: number | null;
+  numTurns: number | null;
+  createdAt: string;
+  assertions: AssertionOutcome[];
+}
+
+export interface ScenarioPassRate {
+  scenarioName: string;
+  gradedRuns: number;
+  passedRuns: number;
+  passRate: number | null;
+}
+
+export interface VersionPassRate {
+  skillVersion: string;
+  model: string;
+  runs: number;
+  gradedRuns: number;
+  passedRuns: number;
+  passRate: number | null;
+  avgCostUsd: number | null;
+  avgDurationMs: number | null;
+  lastRunAt: string;
+  scenarios: ScenarioPassRate[];
+}
+
+export interface VersionRegression {
+  model: string;
+  previousVersion: string;
+  currentVersion: string;
+  previousPassRate: number;
+  currentPassRate: number;
+  scenarios: {
+    scenarioName: string;
+    previousPassRate: number;
+    currentPassRate: number;
+  }[];
+}
 
 export interface Scenario {
   id: string;
   name: string;
   prompt: string;
-  assertions: string[];
+  assertions: Assertion[];
   tags?: ScenarioTag[];
+  /** Trigger scenarios only: whether the prompt should select the skill. */
+  shouldTrigger?: boolean | null;
 }
 
 export interface ScenarioSummary {
@@ -16,6 +91,91 @@ export interface ScenarioSummary {
   tags?: ScenarioTag[];
 }
 
+export interface TriggerEvalCase {
+  scenarioName: string;
+  prompt: string;
+  shouldTrigger: boolean;
+  selectedSkill: string | null;
+  triggered: boolean;
+  passed: boolean;
+}
+
+export interface TriggerEvalReport {
+  skillName: string;
+  distractors: string[];
+  cases: TriggerEvalCase[];
+  truePositives: number;
+  falsePositives: number;
+  falseNegatives: number;
+  trueNegatives: number;
+  precision: number | null;
+  recall: number | null;
+}
+
+/** Ref value that runs scenarios with no skill installed. */
+export const NO_SKILL_REF = "no-skill";
+
+export interface BenchmarkSide {
+  reference: string;
+  label: string;
+  commit: string | null;
+  version: string | null;
+}
+
+export interface BenchmarkRun {
+  /** `null` while any assertion is undecided, e.g. an ungraded rubric. */
+  passed: boolean | null;
+  passedAssertions: number;
+  decidedAssertions: number;
+  passRate: number | null;
+  /** `null` when the runtime did not report usage. */
+  totalTokens: number | null;
+  totalCostUsd: number | null;
+  numTurns: number | null;
+  durationMs: number;
+  error: string | null;
+  outcomes: AssertionOutcome[];
+}
+
+export interface BenchmarkScenarioComparison {
+  scenarioName: string;
+  prompt: string;
+  baseline: BenchmarkRun;
+  candidate: BenchmarkRun;
+  /** Candidate minus baseline; `null` when either side lacks the value. */
+  passRateDelta: number | null;
+  tokenDelta: number | null;
+  costDeltaUsd: number | null;
+  durationDeltaMs: number;
+}
+
+export interface BenchmarkComparison {
+  pluginSlug: string;
+  skillName: string;
+  model: string;
+  generatedAt: string;
+  baseline: BenchmarkSide;
+  candidate: BenchmarkSide;
+  scenarios: BenchmarkScenarioComparison[];
+  baselinePassRate: number | null;
+  candidatePassRate: number | null;
+}
+
+export type BenchmarkExportFormat = "markdown" | "json";
+
+export interface PromptfooImportResult {
+  /** Scenarios written, including ones that replaced a scenario of the same name. */
+  scenarios: ScenarioSummary[];
+  /** Tests and assertions that could not be imported, with the reason. */
+  warnings: string[];
+}
+
+export interface PromptfooExportResult {
+  scenarioCount: number;
+  /** Scenarios and assertions left out of the config, with the reason. */
+  warnings: string[];
+}
+
 export type ScenarioDto = Scenario;
 export type ScenarioListItem = ScenarioSummary;
 export type SaveScenario = Scenario;
@@ -45,10 +205,12 @@ export const loadScenario = (
 export const createScenario = (
   pluginSlug: string,
   skillName: string,
+  mode: ScenarioTag = "performance",
 ) =>
   invokeCommand("create_scenario", {
     pluginSlug,
     skillName,
+    mode,
   });
 
 export const saveScenario = (
@@ -81,6 +243,109 @@ export const defineEvalScenario = (
     scenarioName,
   });
 
+export const runTriggerEval = (pluginSlug: string, skillName: string) =>
+  invokeCommand("run_trigger_eval", { pluginSlug, skillName });
+
+/** Runs one performance scenario against the skill's working copy, grades it and records it. */
+export const runScenario = (pluginSlug: string, skillName: string, scenarioName: string) =>
+  invokeCommand("run_scenario", { pluginSlug, skillName, scenarioName });
+
+export const listScenarioRuns = (
+  pluginSlug: string,
+  skillName: string,
+  scenarioName: string | null = null,
+  limit: number | null = null,
+) =>
+  invokeCommand("list_scenario_runs", {
+    pluginSlug,
+    skillName,
+    scenarioName,
+    limit,
+  });
+
+export const getScenarioPassRates = (
+  pluginSlug: string,
+  skillName: string,
+  model: string | null = null,
+) => invokeCommand("get_scenario_pass_rates", { pluginSlug, skillName, model });
+
+export const detectScenarioRegression = (
+  pluginSlug: string,
+  skillName: string,
+  model: string | null = null,
+) => invokeCommand("detect_scenario_regression", { pluginSlug, skillName, model });
+
+export const runBenchmarkComparison = (
+  pluginSlug: string,
+  skillName: string,
+  baselineRef: string,
+  candidateRef: string,
+) =>
+  invokeCommand("run_benchmark_comparison", {
+    pluginSlug,
+    skillName,
+    baselineRef,
+    candidateRef,
+  });
+
+export const exportBenchmarkComparison = (
+  report: BenchmarkComparison,
+  format: BenchmarkExportFormat,
+  destPath: string,
+) => invokeCommand("export_benchmark_comparison", { report, format, destPath });
+
+export const importPromptfooConfig = (
+  pluginSlug: string,
+  skillName: string,
+  sourcePath: string,
+) => invokeCommand("import_promptfoo_config", { pluginSlug, skillName, sourcePath });
+
+export const exportPromptfooConfig = (
+  pluginSlug: string,
+  skillName: string,
+  destPath: string,
+) => invokeCommand("export_promptfoo_config", { pluginSlug, skillName, destPath });
+
+export function assertionKind(assertion: Assertion): AssertionKind {
+  return typeof assertion === "string" ? "llm-rubric" : assertion.type;
+}
+
+/** Starting value for an assertion of `kind`, keeping text where it fits. */
+export function createAssertion(kind: AssertionKind, previous?: Assertion): Assertion {
+  const text =
+    typeof previous === "string"
+      ? previous
+      : previous && "value" in previous && typeof previous.value === "string"
+        ? previous.value
+        : "";
+  switch (kind) {
+    case "llm-rubric":
+      return text;
+    case "contains":
+    case "not-contains":
+    case "regex":
+      return { type: kind, value: text };
+    case "json-schema":
+      return { type: kind, value: { type: "object" } };
+    case "file-exists":
+      return { type: kind, path: "" };
+    case "file-contains":
+      return { type: kind, path: "", value: text };
+    case "max-turns":
+      return { type: kind, value: 10 };
+    case "max-cost":
+      return { type: kind, value: 1 };
+  }
+}
+
+function isBlankAssertion(assertion: Assertion): boolean {
+  return typeof assertion === "string" && assertion.trim().length === 0;
+}
+
+export function isTriggerScenario(scenario: Pick<Scenario, "tags">): boolean {
+  return scenario.tags?.includes("trigger") ?? false;
+}
+
 export function createDraftScenario(name = ""): SaveScenario {
   return {
     id: `case-${crypto.randomUUID().slice(0, 8)}`,
@@ -100,6 +365,9 @@ export function scenarioToDraft(scenario: Scenario): SaveScenario {
       ? scenario.assertions
       : [],
     ...(scenario.tags ? { tags: [...scenario.tags] } : {}),
+    ...(scenario.shouldTrigger != null
+      ? { shouldTrigger: scenario.shouldTrigger }
+      : {}),
   };
 }
 
@@ -109,11 +377,16 @@ export function normalizeScenario(draft: SaveScenario): SaveScenario {
     name: draft.name.trim(),
     prompt: draft.prompt.trim(),
     assertions: Array.isArray(draft.assertions)
-      ? draft.assertions.map((assertion) => assertion.trim())
+      ? draft.assertions.map((assertion) =>
+          typeof assertion === "string" ? assertion.trim() : assertion,
+        )
       : [],
     ...(draft.tags && draft.tags.length > 0
       ? { tags: Array.from(new Set(draft.tags)) }
       : {}),
+    ...(draft.shouldTrigger != null
+      ? { shouldTrigger: draft.shouldTrigger }
+      : {}),
   };
 }
 
@@ -131,9 +404,12 @@ export function validateScenarioForEvaluation(draft: SaveScenario): string | nul
   if (!draft.prompt.trim()) {
     return "Scenario prompt is required.";
   }
+  if (isTriggerScenario(draft)) {
+    return null;
+  }
   if (
     !Array.isArray(draft.assertions) ||
-    draft.assertions.filter((assertion) => assertion.trim().length > 0)
+    draft.assertions.filter((assertion) => !isBlankAssertion(assertion))
       .length === 0
   ) {
     return "Performance scenarios need at least one assertion.";
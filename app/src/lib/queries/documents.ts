@@ -2,13 +2,18 @@ import { useMutation, useQuery, useQueryClient } from "@tanstack/react-query";
 import {
   addDocumentFile,
   addDocumentFolder,
+  addDocumentPath,
+  addDocumentSite,
   addDocumentUrl,
   deleteDocument,
   listDocuments,
   listSkillsForDocuments,
+  listSkillsWithChangedDocuments,
+  refreshDocument,
+  refreshUrlDocuments,
   updateDocument,
 } from "@/lib/tauri";
-import type { Document } from "@/lib/types";
+import type { CrawlOptions, Document } from "@/lib/types";
 import { queryKeys } from "./query-keys";
 
 interface AddDocumentFileInput {
@@ -18,6 +23,13 @@ interface AddDocumentFileInput {
   skillIds: number[];
 }
 
+interface AddDocumentPathInput {
+  name: string;
+  filePath: string;
+  scope: "all" | "skill";
+  skillIds: number[];
+}
+
 interface AddDocumentUrlInput {
   name: string;
   url: string;
@@ -32,6 +44,14 @@ interface AddDocumentFolderInput {
   skillIds: number[];
 }
 
+interface AddDocumentSiteInput {
+  name: string;
+  url: string;
+  options: CrawlOptions;
+  scope: "all" | "skill";
+  skillIds: number[];
+}
+
 interface UpdateDocumentInput {
   id: number;
   scope: "all" | "skill";
@@ -54,6 +74,14 @@ export function useDocumentSkillOptionsQuery() {
   });
 }
 
+export function useStaleDocumentSkillsQuery() {
+  return useQuery({
+    queryKey: queryKeys.documents.staleSkills,
+    queryFn: async () => (await listSkillsWithChangedDocuments()) ?? [],
+    placeholderData: [],
+  });
+}
+
 function useDocumentCacheUpdates() {
   const queryClient = useQueryClient();
   return {
@@ -86,6 +114,15 @@ export function useAddDocumentFileMutation() {
   });
 }
 
+export function useAddDocumentPathMutation() {
+  const documentsCache = useDocumentCacheUpdates();
+  return useMutation({
+    mutationFn: ({ name, filePath, scope, skillIds }: AddDocumentPathInput) =>
+      addDocumentPath(name, filePath, scope, skillIds),
+    onSuccess: documentsCache.append,
+  });
+}
+
 export function useAddDocumentUrlMutation() {
   const documentsCache = useDocumentCacheUpdates();
   return useMutation({
@@ -104,6 +141,15 @@ export function useAddDocumentFolderMutation() {
   });
 }
 
+export function useAddDocumentSiteMutation() {
+  const documentsCache = useDocumentCacheUpdates();
+  return useMutation({
+    mutationFn: ({ name, url, options, scope, skillIds }: AddDocumentSiteInput) =>
+      addDocumentSite(name, url, options, scope, skillIds),
+    onSuccess: documentsCache.append,
+  });
+}
+
 export function useUpdateDocumentMutation() {
   const documentsCache = useDocumentCacheUpdates();
   return useMutation({
@@ -114,9 +160,36 @@ export function useUpdateDocumentMutation() {
 }
 
 export function useDeleteDocumentMutation() {
+  const queryClient = useQueryClient();
   const documentsCache = useDocumentCacheUpdates();
   return useMutation({
     mutationFn: deleteDocument,
-    onSuccess: (_result, id) => documentsCache.remove(id),
+    onSuccess: (_result, id) => {
+      documentsCache.remove(id);
+      void queryClient.invalidateQueries({ queryKey: queryKeys.documents.staleSkills });
+    },
+  });
+}
+
+export function useRefreshDocumentMutation() {
+  const queryClient = useQueryClient();
+  const documentsCache = useDocumentCacheUpdates();
+  return useMutation({
+    mutationFn: refreshDocument,
+    onSuccess: (result) => {
+      documentsCache.update(result.document);
+      void queryClient.invalidateQueries({ queryKey: queryKeys.documents.staleSkills });
+    },
+  });
+}
+
+export function useRefreshUrlDocumentsMutation() {
+  const queryClient = useQueryClient();
+  return useMutation({
+    mutationFn: refreshUrlDocuments,
+    onSuccess: (result) => {
+      void queryClient.invalidateQueries({ queryKey: queryKeys.documents.list });
+      queryClient.setQueryData(queryKeys.documents.staleSkills, result.stale_skills);
+    },
   });
 }
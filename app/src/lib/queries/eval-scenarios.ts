@@ -3,10 +3,17 @@ import {
   createScenario,
   deleteScenario,
   defineEvalScenario,
+  detectScenarioRegression,
+  getScenarioPassRates,
+  importPromptfooConfig,
   loadScenario,
   listScenarios,
+  runBenchmarkComparison,
+  runScenario,
+  runTriggerEval,
   saveScenario,
   type ScenarioDto,
+  type ScenarioTag,
 } from "@/lib/eval-workbench";
 
 export const evalScenarioKeys = {
@@ -17,6 +24,8 @@ export const evalScenarioKeys = {
     pluginSlug: string,
     scenarioName: string,
   ) => ["eval-scenario", skillName, pluginSlug, scenarioName] as const,
+  history: (skillName: string, pluginSlug: string) =>
+    ["eval-scenario-history", skillName, pluginSlug] as const,
 };
 
 type SaveScenarioMutationInput = {
@@ -90,7 +99,7 @@ export function useCreateScenario(skillName: string | null, pluginSlug: string)
   const queryClient = useQueryClient();
 
   return useMutation({
-    mutationFn: () => createScenario(pluginSlug, skillName!),
+    mutationFn: (mode?: ScenarioTag) => createScenario(pluginSlug, skillName!, mode),
     onSuccess: (createdScenario) => {
       void queryClient.invalidateQueries({
         queryKey: evalScenarioKeys.list(skillName ?? "", pluginSlug),
@@ -148,3 +157,73 @@ export function useDeleteScenario(skillName: string | null, pluginSlug: string)
     },
   });
 }
+
+export function useRunTriggerEval(skillName: string | null, pluginSlug: string) {
+  return useMutation({
+    mutationFn: () => runTriggerEval(pluginSlug, skillName!),
+  });
+}
+
+export function useRunScenario(skillName: string | null, pluginSlug: string) {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (scenarioName: string) => runScenario(pluginSlug, skillName!, scenarioName),
+    onSuccess: () => {
+      void queryClient.invalidateQueries({
+        queryKey: evalScenarioKeys.history(skillName ?? "", pluginSlug),
+      });
+    },
+  });
+}
+
+export function useScenarioPassRates(skillName: string | null, pluginSlug: string) {
+  return useQuery({
+    queryKey: [...evalScenarioKeys.history(skillName ?? "", pluginSlug), "pass-rates"],
+    queryFn: () => getScenarioPassRates(pluginSlug, skillName!),
+    enabled: Boolean(skillName),
+  });
+}
+
+export function useScenarioRegression(
+  skillName: string | null,
+  pluginSlug: string,
+  model: string | null,
+) {
+  return useQuery({
+    queryKey: [...evalScenarioKeys.history(skillName ?? "", pluginSlug), "regression", model],
+    queryFn: () => detectScenarioRegression(pluginSlug, skillName!, model),
+    enabled: Boolean(skillName && model),
+  });
+}
+
+export function useRunBenchmarkComparison(skillName: string | null, pluginSlug: string) {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: ({ baselineRef, candidateRef }: { baselineRef: string; candidateRef: string }) =>
+      runBenchmarkComparison(pluginSlug, skillName!, baselineRef, candidateRef),
+    // Runs on versioned snapshots land in the history.
+    onSuccess: () => {
+      void queryClient.invalidateQueries({
+        queryKey: evalScenarioKeys.history(skillName ?? "", pluginSlug),
+      });
+    },
+  });
+}
+
+export function useImportPromptfooConfig(skillName: string | null, pluginSlug: string) {
+  const queryClient = useQueryClient();
+
+  return useMutation({
+    mutationFn: (sourcePath: string) =>
+      importPromptfooConfig(pluginSlug, skillName!, sourcePath),
+    onSuccess: () => {
+      void queryClient.invalidateQueries({
+        queryKey: evalScenarioKeys.list(skillName ?? "", pluginSlug),
+      });
+      // Re-imported scenarios replace ones that may be open in the editor.
+      void queryClient.invalidateQueries({ queryKey: ["eval-scenario", skillName ?? "", pluginSlug] });
+    },
+  });
+}
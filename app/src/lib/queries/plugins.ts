@@ -27,7 +27,7 @@ export function useCreatePluginMutation() {
 export function useDeletePluginMutation() {
   const queryClient = useQueryClient();
   return useMutation({
-    mutationFn: deletePlugin,
+    mutationFn: (pluginSlug: string) => deletePlugin(pluginSlug),
     onSuccess: () => queryClient.invalidateQueries({ queryKey: queryKeys.plugins.all }),
   });
 }
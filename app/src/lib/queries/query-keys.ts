@@ -27,6 +27,7 @@ export const queryKeys = {
     all: ["documents"] as const,
     list: ["documents", "list"] as const,
     skills: ["documents", "skills"] as const,
+    staleSkills: ["documents", "stale-skills"] as const,
   },
   plugins: {
     all: ["plugins"] as const,
@@ -1,12 +1,17 @@
 import type {
   ConversationRunRecord,
+  CrawlOptions,
   AnswerEvaluationOutput,
   AppSettings,
   AvailablePlugin,
+  ExternalPluginSource,
   DeviceFlowResponse,
   Document,
+  DocumentRefreshResult,
+  DocumentVersion,
   GitHubAuthResult,
   GitHubRepoInfo,
+  SkillSourceInfo,
   GitHubUser,
   ImportedSkill,
   LibraryPlugin,
@@ -14,21 +19,50 @@ import type {
   MarketplaceUpdateResult,
   ModelSettings,
   ReconciliationResult,
+  RefreshAllDocumentsResult,
   SkillSessionInfo,
   SkillCommit,
   SkillFileContent,
   SkillFileEntry,
   SkillFileMeta,
+  SkillArchivePreview,
   SkillMetadataOverride,
+  ManifestLintReport,
+  SkillDraft,
+  SkillDraftMerge,
+  SkillExportTarget,
+  SkillGitRemote,
+  SkillIntegrityReport,
   SkillSummary,
+  SkillSyncResult,
+  SkillUpdateApplied,
+  SkillUpdatePreview,
+  SkillVersionDiff,
+  SkillVersionRelease,
+  StaleDocumentSkill,
   StartupResult,
   UsageByDay,
   UsageByModel,
   UsageByStep,
   UsageSummary,
+  VersionBump,
+  VersionBumpSuggestion,
   WorkflowSessionRecord,
 } from "@/lib/types";
-import type { ScenarioListItem, ScenarioDto } from "@/lib/eval-workbench";
+import type {
+  BenchmarkComparison,
+  BenchmarkExportFormat,
+  BenchmarkRun,
+  PromptfooExportResult,
+  PromptfooImportResult,
+  ScenarioRun,
+  ScenarioListItem,
+  ScenarioDto,
+  ScenarioTag,
+  TriggerEvalReport,
+  VersionPassRate,
+  VersionRegression,
+} from "@/lib/eval-workbench";
 import type {
   ClarificationVerdictUpdate,
   ClarificationsDto,
@@ -202,7 +236,11 @@ export interface TauriCommandMap {
     result: void;
   };
   export_skill_as_file: {
-    args: { skillName: string; pluginSlug: string; destPath: string };
+    args: { skillName: string; pluginSlug: string; destPath: string; includeHistory: boolean };
+    result: void;
+  };
+  export_skill_to_target: {
+    args: { skillName: string; pluginSlug: string; target: SkillExportTarget; destPath: string };
     result: void;
   };
   review_skill_scope: {
@@ -345,11 +383,13 @@ export interface TauriCommandMap {
   };
   delete_imported_skill: { args: { skillId: number }; result: void };
   list_plugins: { args: NoArgs; result: LibraryPlugin[] };
-  delete_plugin: { args: { pluginSlug: string }; result: void };
+  plugin_dependents: { args: { pluginSlug: string }; result: string[] };
+  delete_plugin: { args: { pluginSlug: string; force: boolean }; result: string[] };
   set_plugin_upgrade_lock: {
     args: { pluginSlug: string; locked: boolean };
     result: void;
   };
+  lint_plugin_manifests: { args: NoArgs; result: ManifestLintReport };
   create_plugin_from_skills: {
     args: { pluginName: string; skillKeys: string[] };
     result: string;
@@ -360,6 +400,7 @@ export interface TauriCommandMap {
   };
   remove_skill_from_plugin: { args: { skillKey: string }; result: void };
   parse_github_url: { args: { url: string }; result: GitHubRepoInfo };
+  parse_skill_source: { args: { url: string }; result: SkillSourceInfo };
   check_marketplace_url: { args: { url: string }; result: string };
   list_github_plugins: {
     args: {
@@ -370,6 +411,7 @@ export interface TauriCommandMap {
     };
     result: AvailablePlugin[];
   };
+  list_marketplace_plugins: { args: { sourceUrl: string }; result: AvailablePlugin[] };
   import_marketplace_to_library: {
     args: {
       sourceUrl: string;
@@ -379,11 +421,40 @@ export interface TauriCommandMap {
     result: MarketplaceImportResult[];
   };
   import_marketplace_plugin_to_library: {
-    args: { sourceUrl: string; pluginPath: string; pluginName: string };
+    args: {
+      sourceUrl: string;
+      pluginPath: string;
+      pluginName: string;
+      externalSource: ExternalPluginSource | null;
+    };
     result: MarketplaceImportResult[];
   };
   check_marketplace_updates: { args: NoArgs; result: MarketplaceUpdateResult };
   check_skill_customized: { args: { skillName: string }; result: boolean };
+  preview_imported_skill_update: {
+    args: { skillName: string; sourceUrl: string; skillPath: string; version: string };
+    result: SkillUpdatePreview;
+  };
+  apply_imported_skill_update: {
+    args: {
+      skillName: string;
+      sourceUrl: string;
+      version: string;
+      headSha: string;
+      upstreamSha: string;
+      resolutions: Record<string, string | null>;
+    };
+    result: SkillUpdateApplied;
+  };
+  verify_imported_skills: { args: NoArgs; result: SkillIntegrityReport[] };
+  export_plugin_bundle: {
+    args: { pluginSlug: string; destPath: string; includeEvals: boolean };
+    result: void;
+  };
+  import_plugin_bundle: {
+    args: { filePath: string };
+    result: MarketplaceImportResult[];
+  };
   get_skill_content_at_path: {
     args: { path: string };
     result: SkillFileContent[];
@@ -434,9 +505,18 @@ export interface TauriCommandMap {
       skillName: string;
       pluginSlug: string;
       sha: string;
+      bump?: VersionBump;
     };
     result: string;
   };
+  suggest_skill_version_bump: {
+    args: { skillName: string; pluginSlug: string };
+    result: VersionBumpSuggestion;
+  };
+  release_skill_version: {
+    args: { skillName: string; pluginSlug: string; bump?: VersionBump };
+    result: SkillVersionRelease;
+  };
   get_skill_files_at_sha: {
     args: {
       workspacePath: string;
@@ -446,6 +526,51 @@ export interface TauriCommandMap {
     };
     result: SkillFileContent[];
   };
+  diff_skill_versions: {
+    args: {
+      skillName: string;
+      pluginSlug: string;
+      fromRef: string;
+      toRef: string;
+    };
+    result: SkillVersionDiff;
+  };
+  start_skill_draft: {
+    args: { skillName: string; pluginSlug: string; draftName?: string };
+    result: SkillDraft;
+  };
+  get_skill_draft: {
+    args: { skillName: string; pluginSlug: string };
+    result: SkillDraft | null;
+  };
+  merge_skill_draft: {
+    args: { skillName: string; pluginSlug: string; bump?: VersionBump };
+    result: SkillDraftMerge;
+  };
+  discard_skill_draft: {
+    args: { skillName: string; pluginSlug: string };
+    result: void;
+  };
+  get_skill_git_remote: {
+    args: { skillName: string; pluginSlug: string };
+    result: SkillGitRemote;
+  };
+  set_skill_git_remote: {
+    args: { pluginSlug: string; skillName: string | null; remoteUrl: string };
+    result: void;
+  };
+  push_skill_remote: {
+    args: { skillName: string; pluginSlug: string };
+    result: SkillSyncResult;
+  };
+  fetch_skill_remote: {
+    args: { skillName: string; pluginSlug: string };
+    result: SkillSyncResult;
+  };
+  pull_skill_remote: {
+    args: { skillName: string; pluginSlug: string };
+    result: SkillSyncResult;
+  };
   run_answer_evaluator: {
     args: { skillId: number; skillName: string; workspacePath: string };
     result: string;
@@ -515,6 +640,16 @@ export interface TauriCommandMap {
     };
     result: string;
   };
+  preview_skill_archive: { args: { filePath: string }; result: SkillArchivePreview };
+  import_skill_archive: {
+    args: {
+      filePath: string;
+      skillPaths: string[];
+      pluginSlug: string | null;
+      newPluginName: string | null;
+    };
+    result: MarketplaceImportResult[];
+  };
   create_skill: {
     args: {
       name: string;
@@ -541,7 +676,7 @@ export interface TauriCommandMap {
     result: ScenarioDto | null;
   };
   create_scenario: {
-    args: { pluginSlug: string; skillName: string };
+    args: { pluginSlug: string; skillName: string; mode: ScenarioTag };
     result: ScenarioDto;
   };
   save_scenario: {
@@ -561,6 +696,56 @@ export interface TauriCommandMap {
     args: { pluginSlug: string; skillName: string; scenarioName: string };
     result: ScenarioDto;
   };
+  run_trigger_eval: {
+    args: { pluginSlug: string; skillName: string };
+    result: TriggerEvalReport;
+  };
+  run_scenario: {
+    args: { pluginSlug: string; skillName: string; scenarioName: string };
+    result: BenchmarkRun;
+  };
+  list_scenario_runs: {
+    args: {
+      pluginSlug: string;
+      skillName: string;
+      scenarioName: string | null;
+      limit: number | null;
+    };
+    result: ScenarioRun[];
+  };
+  get_scenario_pass_rates: {
+    args: { pluginSlug: string; skillName: string; model: string | null };
+    result: VersionPassRate[];
+  };
+  detect_scenario_regression: {
+    args: { pluginSlug: string; skillName: string; model: string | null };
+    result: VersionRegression | null;
+  };
+  run_benchmark_comparison: {
+    args: {
+      pluginSlug: string;
+      skillName: string;
+      baselineRef: string;
+      candidateRef: string;
+    };
+    result: BenchmarkComparison;
+  };
+  export_benchmark_comparison: {
+    args: {
+      report: BenchmarkComparison;
+      format: BenchmarkExportFormat;
+      destPath: string;
+    };
+    result: void;
+  };
+  import_promptfoo_config: {
+    args: { pluginSlug: string; skillName: string; sourcePath: string };
+    result: PromptfooImportResult;
+  };
+  export_promptfoo_config: {
+    args: { pluginSlug: string; skillName: string; destPath: string };
+    result: PromptfooExportResult;
+  };
   list_documents: { args: NoArgs; result: Document[] };
   list_skills_for_documents: { args: NoArgs; result: SkillIdName[] };
   add_document_file: {
@@ -572,6 +757,15 @@ export interface TauriCommandMap {
     };
     result: Document;
   };
+  add_document_path: {
+    args: {
+      name: string;
+      filePath: string;
+      scope: DocumentScope;
+      skillIds: number[];
+    };
+    result: Document;
+  };
   add_document_url: {
     args: {
       name: string;
@@ -590,11 +784,25 @@ export interface TauriCommandMap {
     };
     result: Document[];
   };
+  add_document_site: {
+    args: {
+      name: string;
+      url: string;
+      options: CrawlOptions;
+      scope: DocumentScope;
+      skillIds: number[];
+    };
+    result: Document[];
+  };
   update_document: {
     args: { id: number; scope: DocumentScope; skillIds: number[] };
     result: Document;
   };
   delete_document: { args: { id: number }; result: void };
+  refresh_document: { args: { id: number }; result: DocumentRefreshResult };
+  refresh_url_documents: { args: NoArgs; result: RefreshAllDocumentsResult };
+  list_document_versions: { args: { id: number }; result: DocumentVersion[] };
+  list_skills_with_changed_documents: { args: NoArgs; result: StaleDocumentSkill[] };
   // Model catalog commands (PR 3)
   refresh_model_catalog: { args: NoArgs; result: ModelCatalogEntry[] };
   get_cached_model_catalog: { args: NoArgs; result: ModelCatalogEntry[] };
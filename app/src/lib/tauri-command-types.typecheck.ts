@@ -100,6 +100,7 @@ void invokeCommand("load_scenario", {
 void invokeCommand("create_scenario", {
   pluginSlug: "skills",
   skillName: "demo",
+  mode: "trigger",
 });
 
 void invokeCommand("save_scenario", {
@@ -114,3 +115,46 @@ void invokeCommand("define_eval_scenario", {
   skillName: "demo",
   scenarioName: "Regression",
 });
+
+void invokeCommand("run_trigger_eval", {
+  pluginSlug: "skills",
+  skillName: "demo",
+});
+
+void invokeCommand("run_scenario", {
+  pluginSlug: "skills",
+  skillName: "demo",
+  scenarioName: "Regression",
+});
+
+void invokeCommand("list_scenario_runs", {
+  pluginSlug: "skills",
+  skillName: "demo",
+  scenarioName: null,
+  limit: 20,
+});
+
+void invokeCommand("get_scenario_pass_rates", {
+  pluginSlug: "skills",
+  skillName: "demo",
+  model: null,
+});
+
+void invokeCommand("detect_scenario_regression", {
+  pluginSlug: "skills",
+  skillName: "demo",
+  model: "claude-sonnet-4-5",
+});
+
+void invokeCommand("run_benchmark_comparison", {
+  pluginSlug: "skills",
+  skillName: "demo",
+  baselineRef: "no-skill",
+  candidateRef: "1.2.0",
+});
+
+void invokeCommand("import_promptfoo_config", {
+  pluginSlug: "skills",
+  skillName: "demo",
+  sourcePath: "/tmp/promptfooconfig.yaml",
+});
@@ -6,7 +6,10 @@ import type {
   SkillMetadataOverride,
   SkillFileMeta,
   AnswerEvaluationOutput,
+  CrawlOptions,
   ModelSettings,
+  SkillUpdateInfo,
+  SkillUpdatePreview,
 } from "@/lib/types";
 import type {
   TauriCommandInvocation,
@@ -47,8 +50,18 @@ export type {
   ImportedSkill,
   GitHubRepoInfo,
   AvailablePlugin,
+  ExternalPluginSource,
   AvailableSkill,
   SkillFileContent,
+  SkillVersionDiff,
+  SkillDraft,
+  SkillDraftMerge,
+  ManifestLintReport,
+  SkillGitRemote,
+  SkillSyncResult,
+  SkillVersionRelease,
+  VersionBump,
+  VersionBumpSuggestion,
   RefineDiff,
   RefineFinalizeResult,
   SkillSessionInfo,
@@ -57,7 +70,13 @@ export type {
   MarketplaceUpdateResult,
   SkillMetadataOverride,
   SkillUpdateInfo,
+  SkillUpdatePreview,
+  SkillUpdateApplied,
+  SkillIntegrityReport,
+  SkillExportTarget,
+  SkillMergeConflict,
   SkillFileMeta,
+  SkillArchivePreview,
   ModelInfo,
   StartupDeps,
   ResearchStepOutput,
@@ -144,7 +163,15 @@ export const exportSkillAsFile = (
   skillName: string,
   pluginSlug: string,
   destPath: string,
-) => invokeCommand("export_skill_as_file", { skillName, pluginSlug, destPath });
+  includeHistory = false,
+) => invokeCommand("export_skill_as_file", { skillName, pluginSlug, destPath, includeHistory });
+
+export const exportSkillToTarget = (
+  skillName: string,
+  pluginSlug: string,
+  target: SkillExportTarget,
+  destPath: string,
+) => invokeCommand("export_skill_to_target", { skillName, pluginSlug, target, destPath });
 
 export const reviewSkillScope = (
   skillName: string,
@@ -451,12 +478,18 @@ export const deleteImportedSkill = (skillId: number) =>
 
 export const listPlugins = () => invokeCommand("list_plugins", {});
 
-export const deletePlugin = (pluginSlug: string) =>
-  invokeCommand("delete_plugin", { pluginSlug });
+export const pluginDependents = (pluginSlug: string) =>
+  invokeCommand("plugin_dependents", { pluginSlug });
+
+/** Fails while other plugins depend on this one unless `force` is set. */
+export const deletePlugin = (pluginSlug: string, force = false) =>
+  invokeCommand("delete_plugin", { pluginSlug, force });
 
 export const setPluginUpgradeLock = (pluginSlug: string, locked: boolean) =>
   invokeCommand("set_plugin_upgrade_lock", { pluginSlug, locked });
 
+export const lintPluginManifests = () => invokeCommand("lint_plugin_manifests", {});
+
 export const createPluginFromSkills = (
   pluginName: string,
   skillKeys: string[],
@@ -484,6 +517,10 @@ export const removeSkillFromPlugin = (skillKey: string) =>
 export const parseGitHubUrl = (url: string) =>
   invokeCommand("parse_github_url", { url });
 
+/** Classifies a registry as GitHub, GitLab, Gitea, a git remote or a local folder. */
+export const parseSkillSource = (url: string) =>
+  invokeCommand("parse_skill_source", { url });
+
 /** Validates the URL and returns the registry name from marketplace.json. */
 export const checkMarketplaceUrl = (url: string) =>
   invokeCommand("check_marketplace_url", { url });
@@ -501,6 +538,9 @@ export const listGitHubPlugins = (
     subpath: subpath ?? null,
   });
 
+export const listMarketplacePlugins = (sourceUrl: string) =>
+  invokeCommand("list_marketplace_plugins", { sourceUrl });
+
 // --- Marketplace Import ---
 
 export const importMarketplaceToLibrary = (
@@ -518,11 +558,13 @@ export const importMarketplacePluginToLibrary = (
   pluginPath: string,
   pluginName: string,
   sourceUrl: string,
+  externalSource?: ExternalPluginSource | null,
 ) =>
   invokeCommand("import_marketplace_plugin_to_library", {
     sourceUrl,
     pluginPath,
     pluginName,
+    externalSource: externalSource ?? null,
   });
 
 export const checkMarketplaceUpdates = (): Promise<MarketplaceUpdateResult> =>
@@ -531,6 +573,40 @@ export const checkMarketplaceUpdates = (): Promise<MarketplaceUpdateResult> =>
 export const checkSkillCustomized = (skillName: string): Promise<boolean> =>
   invokeCommand("check_skill_customized", { skillName });
 
+export const previewImportedSkillUpdate = (update: SkillUpdateInfo & { source_url: string }) =>
+  invokeCommand("preview_imported_skill_update", {
+    skillName: update.name,
+    sourceUrl: update.source_url,
+    skillPath: update.path,
+    version: update.version,
+  });
+
+export const applyImportedSkillUpdate = (
+  preview: SkillUpdatePreview,
+  sourceUrl: string,
+  resolutions: Record<string, string | null>,
+) =>
+  invokeCommand("apply_imported_skill_update", {
+    skillName: preview.skill_name,
+    sourceUrl,
+    version: preview.version,
+    headSha: preview.head_sha,
+    upstreamSha: preview.upstream_sha,
+    resolutions,
+  });
+
+export const verifyImportedSkills = (): Promise<SkillIntegrityReport[]> =>
+  invokeCommand("verify_imported_skills", {});
+
+export const exportPluginBundle = (
+  pluginSlug: string,
+  destPath: string,
+  includeEvals: boolean,
+) => invokeCommand("export_plugin_bundle", { pluginSlug, destPath, includeEvals });
+
+export const importPluginBundle = (filePath: string) =>
+  invokeCommand("import_plugin_bundle", { filePath });
+
 // --- Selected Skill Session ---
 
 export const getSkillContentAtPath = (path: string) =>
@@ -606,6 +682,16 @@ export const restoreSkillVersion = (
     sha,
   });
 
+export const suggestSkillVersionBump = (skillName: string, pluginSlug: string) =>
+  invokeCommand("suggest_skill_version_bump", { skillName, pluginSlug });
+
+/** Tag the next version and update CHANGELOG.md. Omit `bump` to use the suggestion. */
+export const releaseSkillVersion = (
+  skillName: string,
+  pluginSlug: string,
+  bump?: VersionBump,
+) => invokeCommand("release_skill_version", { skillName, pluginSlug, bump });
+
 export const getSkillFilesAtSha = (
   workspacePath: string,
   skillName: string,
@@ -619,6 +705,56 @@ export const getSkillFilesAtSha = (
     sha,
   });
 
+export const diffSkillVersions = (
+  skillName: string,
+  pluginSlug: string,
+  fromRef: string,
+  toRef: string,
+) =>
+  invokeCommand("diff_skill_versions", {
+    skillName,
+    pluginSlug,
+    fromRef,
+    toRef,
+  });
+
+// --- Draft Branches ---
+
+/** Check out a draft branch for refine turns. Omit `draftName` for a timestamped name. */
+export const startSkillDraft = (skillName: string, pluginSlug: string, draftName?: string) =>
+  invokeCommand("start_skill_draft", { skillName, pluginSlug, draftName });
+
+export const getSkillDraft = (skillName: string, pluginSlug: string) =>
+  invokeCommand("get_skill_draft", { skillName, pluginSlug });
+
+/** Merge the draft into its base branch and tag a version. Omit `bump` to use the suggestion. */
+export const mergeSkillDraft = (skillName: string, pluginSlug: string, bump?: VersionBump) =>
+  invokeCommand("merge_skill_draft", { skillName, pluginSlug, bump });
+
+export const discardSkillDraft = (skillName: string, pluginSlug: string) =>
+  invokeCommand("discard_skill_draft", { skillName, pluginSlug });
+
+// --- Git Remotes ---
+
+export const getSkillGitRemote = (skillName: string, pluginSlug: string) =>
+  invokeCommand("get_skill_git_remote", { skillName, pluginSlug });
+
+/** Omit `skillName` to set the plugin-wide remote. An empty URL clears it. */
+export const setSkillGitRemote = (
+  pluginSlug: string,
+  skillName: string | null,
+  remoteUrl: string,
+) => invokeCommand("set_skill_git_remote", { pluginSlug, skillName, remoteUrl });
+
+export const pushSkillRemote = (skillName: string, pluginSlug: string) =>
+  invokeCommand("push_skill_remote", { skillName, pluginSlug });
+
+export const fetchSkillRemote = (skillName: string, pluginSlug: string) =>
+  invokeCommand("fetch_skill_remote", { skillName, pluginSlug });
+
+export const pullSkillRemote = (skillName: string, pluginSlug: string) =>
+  invokeCommand("pull_skill_remote", { skillName, pluginSlug });
+
 // --- Answer Evaluation (Transition Gate) ---
 
 export const runAnswerEvaluator = (
@@ -667,6 +803,22 @@ export const importSkillFromFile = (params: {
     disableModelInvocation: params.disableModelInvocation ?? null,
   });
 
+export const previewSkillArchive = (filePath: string): Promise<SkillArchivePreview> =>
+  invokeCommand("preview_skill_archive", { filePath });
+
+export const importSkillArchive = (params: {
+  filePath: string;
+  skillPaths: string[];
+  pluginSlug?: string | null;
+  newPluginName?: string | null;
+}): Promise<MarketplaceImportResult[]> =>
+  invokeCommand("import_skill_archive", {
+    filePath: params.filePath,
+    skillPaths: params.skillPaths,
+    pluginSlug: params.pluginSlug ?? null,
+    newPluginName: params.newPluginName ?? null,
+  });
+
 // --- Additional typed wrappers ---
 
 export const createSkill = (params: {
@@ -727,6 +879,13 @@ export const addDocumentFile = (
   skillIds: number[],
 ) => invokeCommand("add_document_file", { name, content, scope, skillIds });
 
+export const addDocumentPath = (
+  name: string,
+  filePath: string,
+  scope: "all" | "skill",
+  skillIds: number[],
+) => invokeCommand("add_document_path", { name, filePath, scope, skillIds });
+
 export const addDocumentUrl = (
   name: string,
   url: string,
@@ -742,6 +901,14 @@ export const addDocumentFolder = (
 ) =>
   invokeCommand("add_document_folder", { name, folderPath, scope, skillIds });
 
+export const addDocumentSite = (
+  name: string,
+  url: string,
+  options: CrawlOptions,
+  scope: "all" | "skill",
+  skillIds: number[],
+) => invokeCommand("add_document_site", { name, url, options, scope, skillIds });
+
 export const updateDocument = (
   id: number,
   scope: "all" | "skill",
@@ -751,6 +918,18 @@ export const updateDocument = (
 export const deleteDocument = (id: number) =>
   invokeCommand("delete_document", { id });
 
+export const refreshDocument = (id: number) =>
+  invokeCommand("refresh_document", { id });
+
+export const refreshUrlDocuments = () =>
+  invokeCommand("refresh_url_documents", {});
+
+export const listDocumentVersions = (id: number) =>
+  invokeCommand("list_document_versions", { id });
+
+export const listSkillsWithChangedDocuments = () =>
+  invokeCommand("list_skills_with_changed_documents", {});
+
 // --- Model Catalog ---
 
 export const refreshModelCatalog = (): Promise<ModelCatalogEntry[]> =>
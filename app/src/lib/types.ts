@@ -47,6 +47,12 @@ export interface MarketplaceRegistry {
   enabled: boolean;
 }
 
+/** Access token for a self-hosted GitLab or Gitea host. */
+export interface SourceHostToken {
+  host: string;
+  token: string;
+}
+
 export interface ProviderOverride {
   api_key: string | null;
   base_url_override: string | null;
@@ -80,12 +86,21 @@ export interface AppSettings {
   github_user_login: string | null;
   github_user_avatar: string | null;
   github_user_email: string | null;
+  /** GitHub Enterprise Server host; null means github.com. */
+  github_host?: string | null;
+  github_oauth_client_id?: string | null;
+  /** owner/repo for feedback issues; null means Skill Builder's github.com repo. */
+  feedback_repo?: string | null;
   marketplace_registries: MarketplaceRegistry[];
+  source_host_tokens?: SourceHostToken[];
   max_dimensions: number;
+  document_token_budget?: number;
   industry: string | null;
   function_role: string | null;
   dashboard_view_mode: string | null;
   auto_update: boolean;
+  /** Serve marketplace catalogs from the local cache without network requests. */
+  marketplace_offline?: boolean;
 }
 
 export interface Document {
@@ -98,6 +113,51 @@ export interface Document {
   skill_ids: number[];
   created_at: string;
   updated_at: string;
+  etag?: string | null;
+  last_modified?: string | null;
+  content_hash?: string | null;
+  fetched_at?: string | null;
+  content_changed_at?: string | null;
+}
+
+/** Limits and URL filters for crawling a documentation site. */
+export interface CrawlOptions {
+  max_depth: number;
+  max_pages: number;
+  include_pattern: string | null;
+  exclude_pattern: string | null;
+}
+
+export interface DocumentVersion {
+  id: number;
+  document_id: number;
+  file_path: string;
+  content_hash: string | null;
+  etag: string | null;
+  last_modified: string | null;
+  fetched_at: string | null;
+  replaced_at: string;
+}
+
+/** A generated skill whose assigned documents changed after its last generation. */
+export interface StaleDocumentSkill {
+  skill_id: number;
+  skill_name: string;
+  plugin_slug: string;
+  generated_at: string;
+  changed_documents: string[];
+}
+
+export interface DocumentRefreshResult {
+  document: Document;
+  changed: boolean;
+  stale_skills: StaleDocumentSkill[];
+}
+
+export interface RefreshAllDocumentsResult {
+  refreshed: DocumentRefreshResult[];
+  failed: { document_id: number; name: string; error: string }[];
+  stale_skills: StaleDocumentSkill[];
 }
 
 export interface SkillUpdateInfo {
@@ -107,6 +167,52 @@ export interface SkillUpdateInfo {
   source_url?: string;
 }
 
+export interface SkillMergeConflict {
+  path: string;
+  base: string | null;
+  local: string | null;
+  upstream: string | null;
+  /** Both sides merged, with `<<<<<<< local` / `>>>>>>> upstream` markers. */
+  merged: string;
+}
+
+export interface SkillUpdatePreview {
+  skill_name: string;
+  version: string;
+  head_sha: string;
+  upstream_sha: string;
+  base_sha: string;
+  auto_merged: string[];
+  conflicts: SkillMergeConflict[];
+}
+
+export interface SkillUpdateApplied {
+  version: string;
+  merged_sha: string;
+  tag: string;
+}
+
+export type SkillIntegrityStatus = "verified" | "drifted" | "missing" | "unpinned";
+
+export interface FileIntegrityIssue {
+  path: string;
+  change: "modified" | "removed" | "added";
+  /** SHA-256 recorded at import; null for files added since. */
+  expected: string | null;
+  /** SHA-256 on disk now; null for removed files. */
+  actual: string | null;
+}
+
+export interface SkillIntegrityReport {
+  skill_name: string;
+  plugin_slug: string;
+  source_url: string;
+  version: string | null;
+  source_commit: string | null;
+  status: SkillIntegrityStatus;
+  files: FileIntegrityIssue[];
+}
+
 export interface MarketplaceUpdateResult {
   library: SkillUpdateInfo[];
   workspace: SkillUpdateInfo[];
@@ -171,6 +277,110 @@ export interface SkillFileContent {
   content: string;
 }
 
+export interface SkillDiffLine {
+  kind: "context" | "added" | "removed";
+  content: string;
+  old_lineno: number | null;
+  new_lineno: number | null;
+}
+
+export interface SkillDiffHunk {
+  header: string;
+  old_start: number;
+  old_lines: number;
+  new_start: number;
+  new_lines: number;
+  lines: SkillDiffLine[];
+}
+
+export interface SkillFileDiff {
+  path: string;
+  status: "added" | "removed" | "modified";
+  binary: boolean;
+  additions: number;
+  deletions: number;
+  diff: string;
+  hunks: SkillDiffHunk[];
+}
+
+export interface SkillVersionDiff {
+  from_sha: string;
+  to_sha: string;
+  files: SkillFileDiff[];
+}
+
+export type VersionBump = "major" | "minor" | "patch";
+
+/** Non-Claude format a skill can be exported to. */
+export type SkillExportTarget = "agents_md" | "cursor_rule" | "flattened_markdown";
+
+export interface VersionBumpSuggestion {
+  current_version: string;
+  bump: VersionBump;
+  next_version: string;
+  reasons: string[];
+}
+
+export interface SkillVersionRelease {
+  version: string;
+  previous_version: string | null;
+  bump: VersionBump;
+  tag: string;
+}
+
+export interface SkillDraft {
+  name: string;
+  branch: string;
+  base_branch: string;
+  base_sha: string;
+  head_sha: string;
+  ahead: number;
+  behind: number;
+  files: SkillFileDiff[];
+}
+
+export interface SkillDraftMerge {
+  base_branch: string;
+  merged_sha: string;
+  fast_forward: boolean;
+  release: SkillVersionRelease;
+}
+
+export type ManifestLintSeverity = "error" | "warning";
+
+export interface ManifestLintFinding {
+  severity: ManifestLintSeverity;
+  code: string;
+  path: string;
+  plugin: string | null;
+  message: string;
+}
+
+export interface ManifestLintReport {
+  root: string;
+  plugins_checked: number;
+  error_count: number;
+  warning_count: number;
+  findings: ManifestLintFinding[];
+}
+
+export interface SkillGitRemote {
+  skill_url: string | null;
+  plugin_url: string | null;
+  resolved_url: string | null;
+}
+
+export interface SkillSyncResult {
+  status: "up_to_date" | "pushed" | "fast_forwarded" | "ahead" | "behind" | "diverged";
+  branch: string;
+  local_sha: string;
+  remote_sha: string | null;
+  ahead: number;
+  behind: number;
+  pushed_tags: string[];
+  conflicts: string[];
+}
+
 export interface RefineFileDiff {
   path: string;
   status: string;
@@ -414,6 +624,19 @@ export interface LibraryPlugin {
   source_url: string | null;
   is_default: boolean;
   upgrade_locked: boolean;
+  /** Plugins this one declares in plugin.json `dependencies`. */
+  dependencies?: PluginDependency[];
+  /** Slugs of installed plugins that depend on this one. */
+  required_by?: string[];
+}
+
+export interface PluginDependency {
+  name: string;
+  /** Semver range, e.g. `^1.2`; `*` accepts any version. */
+  version_req: string;
+  installed_version: string | null;
+  installed: boolean;
+  satisfied: boolean;
 }
 
 /**
@@ -463,6 +686,18 @@ export interface GitHubRepoInfo {
   subpath: string | null;
 }
 
+export type SkillSourceKind = "github" | "gitlab" | "gitea" | "git" | "local";
+
+/** A marketplace registry URL classified by where its files are read from. */
+export interface SkillSourceInfo {
+  kind: SkillSourceKind;
+  /** `owner/repo[#branch]` for GitHub; the URL or folder path as entered otherwise. */
+  canonical_url: string;
+  label: string;
+  branch: string | null;
+  subpath: string | null;
+}
+
 export interface AvailableSkill {
   path: string;
   name: string;
@@ -482,6 +717,17 @@ export interface AvailablePlugin {
   version: string | null;
   skill_count: number;
   skill_names: string[];
+  /** Set when the plugin lives in another repo; `path` is then relative to it. */
+  external_source?: ExternalPluginSource | null;
+}
+
+export interface ExternalPluginSource {
+  owner: string;
+  repo: string;
+  /** Repo URL for plugins outside GitHub; null means `owner/repo` on the marketplace's GitHub host. */
+  url?: string | null;
+  /** Pinned commit SHA, tag or branch; null means the repo's default branch. */
+  git_ref: string | null;
 }
 
 export interface SkillMetadataOverride {
@@ -497,6 +743,10 @@ export interface MarketplaceImportResult {
   skill_name: string;
   success: boolean;
   error: string | null;
+  /** Set when the result belongs to a plugin imported as a dependency of this plugin slug. */
+  dependency_of?: string;
+  /** Set instead of `skill_name` on a failed dependency: the plugin that could not be installed. */
+  missing_dependency?: string;
 }
 
 export interface SkillFileEntry {
@@ -516,6 +766,20 @@ export interface SkillFileMeta {
   disable_model_invocation: boolean | null;
 }
 
+export interface ArchiveSkillMeta {
+  /** Directory prefix of the skill inside the archive ("" for a root-level SKILL.md). */
+  path: string;
+  meta: SkillFileMeta;
+}
+
+export interface SkillArchivePreview {
+  /** Set when the archive contains a `.claude-plugin/plugin.json`. */
+  plugin_name: string | null;
+  plugin_description: string | null;
+  plugin_version: string | null;
+  skills: ArchiveSkillMeta[];
+}
+
 // ─── Workflow command DTOs ───────────────────────────────────────────────────
 
 export interface StepResetPreview {
@@ -120,6 +120,8 @@ export default function SettingsPage() {
               setRefinePromptSuggestions={form.setRefinePromptSuggestions}
               maxDimensions={form.maxDimensions}
               setMaxDimensions={form.setMaxDimensions}
+              documentTokenBudget={form.documentTokenBudget}
+              setDocumentTokenBudget={form.setDocumentTokenBudget}
               autoSave={form.autoSave}
             />
           )}
@@ -134,12 +136,23 @@ export default function SettingsPage() {
             <MarketplaceSection
               autoUpdate={form.autoUpdate}
               setAutoUpdate={form.setAutoUpdate}
+              marketplaceOffline={form.marketplaceOffline}
+              setMarketplaceOffline={form.setMarketplaceOffline}
               autoSave={form.autoSave}
             />
           )}
 
           {activeSection === "github" && (
-            <GitHubSection onLoginOpen={() => setLoginDialogOpen(true)} />
+            <GitHubSection
+              onLoginOpen={() => setLoginDialogOpen(true)}
+              githubHost={form.githubHost}
+              setGithubHost={form.setGithubHost}
+              githubOauthClientId={form.githubOauthClientId}
+              setGithubOauthClientId={form.setGithubOauthClientId}
+              feedbackRepo={form.feedbackRepo}
+              setFeedbackRepo={form.setFeedbackRepo}
+              autoSave={form.autoSave}
+            />
           )}
 
           {activeSection === "advanced" && (
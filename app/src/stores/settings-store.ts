@@ -1,8 +1,13 @@
 import { create } from "zustand";
-import type { MarketplaceRegistry, ModelSettings } from "@/lib/types";
+import type { MarketplaceRegistry, ModelSettings, SkillUpdatePreview, SourceHostToken } from "@/lib/types";
 
 export type { ModelInfo } from "@/lib/types";
 
+export interface PendingSkillMerge {
+  preview: SkillUpdatePreview;
+  sourceUrl: string;
+}
+
 interface SettingsState {
   modelSettings: ModelSettings;
   workspacePath: string | null;
@@ -13,16 +18,25 @@ interface SettingsState {
   githubUserLogin: string | null;
   githubUserAvatar: string | null;
   githubUserEmail: string | null;
+  githubHost: string | null;
+  githubOauthClientId: string | null;
+  feedbackRepo: string | null;
   marketplaceRegistries: MarketplaceRegistry[];
+  sourceHostTokens: SourceHostToken[];
   maxDimensions: number;
+  documentTokenBudget: number;
   industry: string | null;
   functionRole: string | null;
   dashboardViewMode: string | null;
   autoUpdate: boolean;
+  marketplaceOffline: boolean;
   isConfigured: boolean;
   pendingUpgradeOpen: { skills: string[] } | null;
-  setSettings: (settings: Partial<Omit<SettingsState, "isConfigured" | "setSettings" | "reset" | "setPendingUpgradeOpen">>) => void;
+  /** Upstream updates of customized skills whose merge conflicts await review. */
+  pendingSkillMerges: PendingSkillMerge[];
+  setSettings: (settings: Partial<Omit<SettingsState, "isConfigured" | "setSettings" | "reset" | "setPendingUpgradeOpen" | "setPendingSkillMerges">>) => void;
   setPendingUpgradeOpen: (value: { skills: string[] } | null) => void;
+  setPendingSkillMerges: (value: PendingSkillMerge[]) => void;
   reset: () => void;
 }
 
@@ -40,14 +54,21 @@ const initialState = {
   githubUserLogin: null,
   githubUserAvatar: null,
   githubUserEmail: null,
+  githubHost: null,
+  githubOauthClientId: null,
+  feedbackRepo: null,
   marketplaceRegistries: [] as MarketplaceRegistry[],
+  sourceHostTokens: [] as SourceHostToken[],
   maxDimensions: 5,
+  documentTokenBudget: 8000,
   industry: null,
   functionRole: null,
   dashboardViewMode: null,
   autoUpdate: false,
+  marketplaceOffline: false,
   isConfigured: false,
   pendingUpgradeOpen: null as { skills: string[] } | null,
+  pendingSkillMerges: [] as PendingSkillMerge[],
 };
 
 export const useSettingsStore = create<SettingsState>((set) => ({
@@ -68,5 +89,6 @@ export const useSettingsStore = create<SettingsState>((set) => ({
       };
     }),
   setPendingUpgradeOpen: (value) => set({ pendingUpgradeOpen: value }),
+  setPendingSkillMerges: (value) => set({ pendingSkillMerges: value }),
   reset: () => set(initialState),
 }));
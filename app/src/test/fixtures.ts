@@ -13,12 +13,18 @@ export function makeAppSettings(overrides?: Partial<AppSettings>): AppSettings {
     github_user_login: null,
     github_user_avatar: null,
     github_user_email: null,
+    github_host: null,
+    github_oauth_client_id: null,
+    feedback_repo: null,
     marketplace_registries: [],
+    source_host_tokens: [],
     max_dimensions: 8,
+    document_token_budget: 8000,
     industry: null,
     function_role: null,
     dashboard_view_mode: null,
     auto_update: false,
+    marketplace_offline: false,
     ...overrides,
   };
 }
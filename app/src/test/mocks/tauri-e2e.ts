@@ -25,6 +25,17 @@ const defaultPerformanceScenario = {
   assertions: [],
 };
 
+const defaultSkillSyncResult = {
+  status: "up_to_date",
+  branch: "main",
+  local_sha: "",
+  remote_sha: null,
+  ahead: 0,
+  behind: 0,
+  pushed_tags: [],
+  conflicts: [],
+};
+
 
 
 const defaultSettings = {
@@ -84,6 +95,20 @@ const mockResponses: Record<string, unknown> = {
   read_file: "",
   check_workspace_path: true,
   check_marketplace_updates: { library: [], workspace: [], registry_name: null, registry_names: [] },
+  preview_imported_skill_update: {
+    skill_name: "e2e-skill",
+    version: "1.1.0",
+    head_sha: "",
+    upstream_sha: "",
+    base_sha: "",
+    auto_merged: [],
+    conflicts: [],
+  },
+  apply_imported_skill_update: { version: "1.1.0", merged_sha: "", tag: "skills/e2e-skill/v1.1.0" },
+  verify_imported_skills: [],
+  export_plugin_bundle: undefined,
+  export_skill_to_target: undefined,
+  import_plugin_bundle: [{ skill_name: "analytics", success: true, error: null }],
   refresh_model_catalog: [],
   "plugin:opener|open_url": undefined,
   has_running_agents: false,
@@ -149,6 +174,13 @@ const mockResponses: Record<string, unknown> = {
   list_user_repos: [],
   validate_remote_repo: undefined,
   // Plugins
+  lint_plugin_manifests: {
+    root: "/tmp/skills",
+    plugins_checked: 0,
+    error_count: 0,
+    warning_count: 0,
+    findings: [],
+  },
   list_plugins: [
     { id: 1, slug: "skills", display_name: "Skills", version: null, source_type: "synthetic", source_url: null, is_default: true },
   ],
@@ -158,6 +190,37 @@ const mockResponses: Record<string, unknown> = {
   // Skill history (WorkspaceOverview version history)
   get_skill_history: [],
   restore_skill_version: "1",
+  suggest_skill_version_bump: {
+    current_version: "1.0.0",
+    bump: "patch",
+    next_version: "1.0.1",
+    reasons: ["No changes since v1.0.0"],
+  },
+  release_skill_version: { version: "1.0.1", previous_version: "1.0.0", bump: "patch", tag: "skills/e2e/v1.0.1" },
+  diff_skill_versions: { from_sha: "", to_sha: "", files: [] },
+  start_skill_draft: {
+    name: "refine-e2e",
+    branch: "draft/refine-e2e",
+    base_branch: "main",
+    base_sha: "",
+    head_sha: "",
+    ahead: 0,
+    behind: 0,
+    files: [],
+  },
+  get_skill_draft: null,
+  merge_skill_draft: {
+    base_branch: "main",
+    merged_sha: "",
+    fast_forward: true,
+    release: { version: "1.0.1", previous_version: "1.0.0", bump: "patch", tag: "skills/e2e/v1.0.1" },
+  },
+  discard_skill_draft: undefined,
+  get_skill_git_remote: { skill_url: null, plugin_url: null, resolved_url: null },
+  set_skill_git_remote: undefined,
+  push_skill_remote: defaultSkillSyncResult,
+  fetch_skill_remote: defaultSkillSyncResult,
+  pull_skill_remote: defaultSkillSyncResult,
   get_externally_locked_skills: [],
   upload_skill: {
     skill_id: "skill-001",
@@ -171,15 +234,27 @@ const mockResponses: Record<string, unknown> = {
     is_bundled: false,
   },
   delete_imported_skill: undefined,
-  delete_plugin: undefined,
+  plugin_dependents: [],
+  delete_plugin: [],
 get_skill_content: "# Test Skill\n\nThis is a test skill.\n\n## Instructions\n\nFollow these steps...",
   list_skill_files: [],
   // GitHub import
   parse_github_url: { owner: "test-owner", repo: "test-repo", branch: "main", subpath: null },
+  parse_skill_source: {
+    kind: "github",
+    canonical_url: "test-owner/test-repo",
+    label: "test-owner/test-repo",
+    branch: "main",
+    subpath: null,
+  },
   list_github_plugins: [
     { path: "plugins/analytics", name: "analytics", description: "Analytics plugin", version: "1.0.0", skill_count: 0, skill_names: [] },
     { path: "plugins/reporting", name: "reporting", description: "Reporting plugin", version: "1.0.0", skill_count: 0, skill_names: [] },
   ],
+  list_marketplace_plugins: [
+    { path: "plugins/analytics", name: "analytics", description: "Analytics plugin", version: "1.0.0", skill_count: 0, skill_names: [] },
+    { path: "plugins/reporting", name: "reporting", description: "Reporting plugin", version: "1.0.0", skill_count: 0, skill_names: [] },
+  ],
   list_github_skills: [
     { path: "skills/analytics", name: "analytics", domain: "Data", description: "Analytics skill" },
     { path: "skills/reporting", name: "reporting", domain: "Data", description: "Reporting skill" },
@@ -207,14 +282,38 @@ get_skill_content: "# Test Skill\n\nThis is a test skill.\n\n## Instructions\n\n
     disable_model_invocation: false,
   },
   import_skill_from_file: "imported-skill",
+  preview_skill_archive: {
+    plugin_name: null,
+    plugin_description: null,
+    plugin_version: null,
+    skills: [
+      {
+        path: "",
+        meta: {
+          name: "imported-skill",
+          description: "A skill imported from a file",
+          version: "1.2.0",
+          user_invocable: false,
+          disable_model_invocation: false,
+        },
+      },
+    ],
+  },
+  import_skill_archive: [],
   // Documents
   list_documents: [],
   list_skills_for_documents: [],
   add_document_file: { id: 1, name: "doc", source_type: "file", source_url: null, file_path: "", scope: "all", skill_ids: [], created_at: new Date().toISOString(), updated_at: new Date().toISOString() },
+  add_document_path: { id: 1, name: "doc", source_type: "file", source_url: null, file_path: "", scope: "all", skill_ids: [], created_at: new Date().toISOString(), updated_at: new Date().toISOString() },
   add_document_url: { id: 1, name: "doc", source_type: "url", source_url: "https://example.com", file_path: "", scope: "all", skill_ids: [], created_at: new Date().toISOString(), updated_at: new Date().toISOString() },
   add_document_folder: [],
+  add_document_site: [],
   update_document: { id: 1, name: "doc", source_type: "url", source_url: "https://example.com", file_path: "", scope: "all", skill_ids: [], created_at: new Date().toISOString(), updated_at: new Date().toISOString() },
   delete_document: undefined,
+  refresh_document: { document: { id: 1, name: "doc", source_type: "url", source_url: "https://example.com", file_path: "", scope: "all", skill_ids: [], created_at: new Date().toISOString(), updated_at: new Date().toISOString() }, changed: false, stale_skills: [] },
+  refresh_url_documents: { refreshed: [], failed: [], stale_skills: [] },
+  list_document_versions: [],
+  list_skills_with_changed_documents: [],
   // Usage
   get_usage_summary: { total_cost: 0, total_runs: 0, avg_cost_per_run: 0 },
   get_recent_workflow_sessions: [],
@@ -244,6 +343,46 @@ get_skill_content: "# Test Skill\n\nThis is a test skill.\n\n## Instructions\n\n
   load_scenario: defaultPerformanceScenario,
   save_scenario: defaultPerformanceScenario,
   delete_scenario: undefined,
+  run_trigger_eval: {
+    skillName: "e2e-skill",
+    distractors: [],
+    cases: [],
+    truePositives: 0,
+    falsePositives: 0,
+    falseNegatives: 0,
+    trueNegatives: 0,
+    precision: null,
+    recall: null,
+  },
+  run_scenario: {
+    passed: true,
+    passedAssertions: 0,
+    decidedAssertions: 0,
+    passRate: null,
+    totalTokens: null,
+    totalCostUsd: null,
+    numTurns: null,
+    durationMs: 0,
+    error: null,
+    outcomes: [],
+  },
+  list_scenario_runs: [],
+  get_scenario_pass_rates: [],
+  detect_scenario_regression: null,
+  run_benchmark_comparison: {
+    pluginSlug: "skills",
+    skillName: "e2e-skill",
+    model: "e2e-model",
+    generatedAt: "2026-01-01T00:00:00Z",
+    baseline: { reference: "no-skill", label: "No skill", commit: null, version: null },
+    candidate: { reference: "1.0.0", label: "v1.0.0", commit: "0000000", version: "1.0.0" },
+    scenarios: [],
+    baselinePassRate: null,
+    candidatePassRate: null,
+  },
+  export_benchmark_comparison: undefined,
+  import_promptfoo_config: { scenarios: [], warnings: [] },
+  export_promptfoo_config: { scenarioCount: 1, warnings: [] },
 };
 
 function normalizeListSkills(value: unknown): unknown {